@@ -10,7 +10,7 @@ mod casing;
 
 use crate::casing::{transform_field_casing, transform_variant_casing};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, FromMeta)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, FromMeta)]
 pub(crate) enum RenameAll {
     #[darling(rename = "lowercase")]
     Lower,
@@ -19,6 +19,7 @@ pub(crate) enum RenameAll {
     #[darling(rename = "PascalCase")]
     Pascal,
     #[darling(rename = "camelCase")]
+    #[default]
     Camel,
     #[darling(rename = "snake_case")]
     Snake,
@@ -30,12 +31,6 @@ pub(crate) enum RenameAll {
     ScreamingKebab,
 }
 
-impl Default for RenameAll {
-    fn default() -> RenameAll {
-        RenameAll::Camel
-    }
-}
-
 #[derive(Debug, Clone, PartialEq, Eq, FromDeriveInput)]
 #[darling(attributes(datastore), supports(struct_named, enum_unit))]
 struct Container {
@@ -44,15 +39,14 @@ struct Container {
     // pub generics: syn::Generics,
     pub data: darling::ast::Data<VariantContainer, FieldContainer>,
     // pub attrs: Vec<syn::Attribute>,
-    #[darling(default)]
-    pub rename_all: RenameAll,
+    pub rename_all: Option<RenameAll>,
+    pub kind: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, FromVariant)]
 #[darling(attributes(datastore))]
 struct VariantContainer {
     pub ident: syn::Ident,
-    #[darling(default)]
     pub rename: Option<String>,
 }
 
@@ -60,20 +54,50 @@ struct VariantContainer {
 #[darling(attributes(datastore))]
 struct FieldContainer {
     pub ident: Option<syn::Ident>,
-    #[darling(default)]
     pub rename: Option<String>,
+    //? Optional flags, rather than defaulted ones, since the code generated for the latter trips clippy.
+    pub key: Option<bool>,
+    pub exclude_from_indexes: Option<bool>,
 }
 
-fn derive_into_value_struct(
-    ident: syn::Ident,
-    fields: Vec<FieldContainer>,
-    rename_all: RenameAll,
-) -> TokenStream {
-    let idents: Vec<syn::Ident> = fields
+impl FieldContainer {
+    /// Is the field the key of the entity (`#[datastore(key)]`) ?
+    fn is_key(&self) -> bool {
+        self.key.unwrap_or(false)
+    }
+
+    /// Is the field excluded from indexes (`#[datastore(exclude_from_indexes)]`) ?
+    fn is_excluded(&self) -> bool {
+        self.exclude_from_indexes.unwrap_or(false)
+    }
+}
+
+/// Splits the key field (if any) from the fields stored as properties.
+fn split_key_field(fields: Vec<FieldContainer>) -> (Option<syn::Ident>, Vec<FieldContainer>) {
+    let (keys, fields): (Vec<_>, Vec<_>) = fields.into_iter().partition(FieldContainer::is_key);
+    (
+        keys.into_iter().next().and_then(|field| field.ident),
+        fields,
+    )
+}
+
+/// The expressions converting the fields to property values, excluded from indexes if marked so.
+fn property_values(fields: &[FieldContainer]) -> Vec<syn::Expr> {
+    fields
         .iter()
-        .map(|field| field.ident.clone().unwrap())
-        .collect();
-    let names: Vec<syn::LitStr> = fields
+        .map(|field| {
+            let ident = field.ident.clone().unwrap();
+            if field.is_excluded() {
+                syn::parse_quote! { self.#ident.into_value().exclude_from_indexes() }
+            } else {
+                syn::parse_quote! { self.#ident.into_value() }
+            }
+        })
+        .collect()
+}
+
+fn property_names(fields: Vec<FieldContainer>, rename_all: RenameAll) -> Vec<syn::LitStr> {
+    fields
         .into_iter()
         .map(|field| {
             let renamed = field.rename;
@@ -82,7 +106,17 @@ fn derive_into_value_struct(
             let name = renamed.unwrap_or_else(|| transform_field_casing(field, rename_all));
             syn::LitStr::new(name.as_str(), span)
         })
-        .collect();
+        .collect()
+}
+
+fn derive_into_value_struct(
+    ident: syn::Ident,
+    fields: Vec<FieldContainer>,
+    rename_all: RenameAll,
+) -> TokenStream {
+    let (_, fields) = split_key_field(fields);
+    let values = property_values(&fields);
+    let names = property_names(fields, rename_all);
 
     let capacity = names.len();
 
@@ -90,7 +124,7 @@ fn derive_into_value_struct(
         impl ::google_cloud::datastore::IntoValue for #ident {
             fn into_value(self) -> ::google_cloud::datastore::Value {
                 let mut props = ::std::collections::HashMap::with_capacity(#capacity);
-                #(props.insert(::std::string::String::from(#names), self.#idents.into_value());)*
+                #(props.insert(::std::string::String::from(#names), #values);)*
                 ::google_cloud::datastore::Value::EntityValue(props)
             }
         }
@@ -138,7 +172,7 @@ pub fn derive_into_value(input: TokenStream) -> TokenStream {
     let container = Container::from_derive_input(&input).unwrap();
 
     let ident = container.ident;
-    let rename_all = container.rename_all;
+    let rename_all = container.rename_all.unwrap_or_default();
 
     match container.data {
         darling::ast::Data::Enum(variants) => derive_into_value_enum(ident, variants, rename_all),
@@ -153,26 +187,38 @@ fn derive_from_value_struct(
     fields: Vec<FieldContainer>,
     rename_all: RenameAll,
 ) -> TokenStream {
+    //? The key is not one of the properties, so the key field gets its default value,
+    //? unless decoding along with the key of the entity.
+    let (key, fields) = split_key_field(fields);
+    let from_entity = key.clone().map(|key| {
+        quote! {
+            fn from_entity_with(key: &::google_cloud::datastore::Key, properties: ::google_cloud::datastore::Value, options: &::google_cloud::datastore::DecodeOptions) -> ::std::result::Result<#ident, ::google_cloud::error::ConvertError> {
+                let mut value: #ident = ::google_cloud::datastore::FromValue::from_value_with(properties, options)?;
+                value.#key = ::google_cloud::datastore::FromKeyID::from_key_id(key.get_id().clone())?;
+                ::std::result::Result::Ok(value)
+            }
+        }
+    });
+    let key = key.into_iter();
     let idents: Vec<syn::Ident> = fields
         .iter()
         .map(|field| field.ident.clone().unwrap())
         .collect();
-    let names: Vec<syn::LitStr> = fields
-        .into_iter()
-        .map(|field| {
-            let renamed = field.rename;
-            let field = field.ident.unwrap();
-            let span = field.span();
-            let name = renamed.unwrap_or_else(|| transform_field_casing(field, rename_all));
-            syn::LitStr::new(name.as_str(), span)
-        })
-        .collect();
+    let names = property_names(fields, rename_all);
 
     let tokens = quote! {
         impl ::google_cloud::datastore::FromValue for #ident {
             fn from_value(value: ::google_cloud::datastore::Value) -> ::std::result::Result<#ident, ::google_cloud::error::ConvertError> {
+                let options = ::google_cloud::datastore::DecodeOptions::default();
+                ::google_cloud::datastore::FromValue::from_value_with(value, &options)
+            }
+
+            fn from_value_with(value: ::google_cloud::datastore::Value, options: &::google_cloud::datastore::DecodeOptions) -> ::std::result::Result<#ident, ::google_cloud::error::ConvertError> {
                 let mut props = match value {
                     ::google_cloud::datastore::Value::EntityValue(props) => props,
+                    ::google_cloud::datastore::Value::IndexedValue(value, _) => {
+                        return ::google_cloud::datastore::FromValue::from_value_with(*value, options);
+                    }
                     _ => return ::std::result::Result::Err(
                         ::google_cloud::error::ConvertError::UnexpectedPropertyType {
                             expected: ::std::string::String::from("entity"),
@@ -187,12 +233,15 @@ fn derive_from_value_struct(
                             .ok_or_else(|| {
                                 ::google_cloud::error::ConvertError::MissingProperty(::std::string::String::from(#names))
                             })?;
-                        let value = ::google_cloud::datastore::FromValue::from_value(prop)?;
+                        let value = ::google_cloud::datastore::FromValue::from_value_with(prop, options)?;
                         value
                     },)*
+                    #(#key: ::std::default::Default::default(),)*
                 };
                 ::std::result::Result::Ok(value)
             }
+
+            #from_entity
         }
     };
 
@@ -224,16 +273,21 @@ fn derive_from_value_enum(
             fn from_value(value: ::google_cloud::datastore::Value) -> ::std::result::Result<#ident, ::google_cloud::error::ConvertError> {
                 let value = match value {
                     ::google_cloud::datastore::Value::StringValue(value) => value,
+                    ::google_cloud::datastore::Value::IndexedValue(value, _) => {
+                        return ::google_cloud::datastore::FromValue::from_value(*value);
+                    }
                     _ => return ::std::result::Result::Err(
                         ::google_cloud::error::ConvertError::UnexpectedPropertyType {
-                            expected: ::std::string::String::from("entity"),
+                            expected: ::std::string::String::from("string"),
                             got: ::std::string::String::from(value.type_name()),
                         }
                     ),
                 };
                 match value.as_str() {
                     #(#names => ::std::result::Result::Ok(#ident::#idents),)*
-                    _ => todo!("[datastore-derive] unknown enum variant encountered"),
+                    _ => ::std::result::Result::Err(
+                        ::google_cloud::error::ConvertError::UnknownVariant(value)
+                    ),
                 }
             }
         }
@@ -248,7 +302,7 @@ pub fn derive_from_value(input: TokenStream) -> TokenStream {
     let container = Container::from_derive_input(&input).unwrap();
 
     let ident = container.ident;
-    let rename_all = container.rename_all;
+    let rename_all = container.rename_all.unwrap_or_default();
 
     match container.data {
         darling::ast::Data::Enum(variants) => derive_from_value_enum(ident, variants, rename_all),
@@ -257,3 +311,79 @@ pub fn derive_from_value(input: TokenStream) -> TokenStream {
         }
     }
 }
+
+fn derive_into_entity_struct(
+    ident: syn::Ident,
+    fields: Vec<FieldContainer>,
+    rename_all: RenameAll,
+    kind: String,
+) -> TokenStream {
+    if fields.iter().filter(|field| field.is_key()).count() > 1 {
+        let message = "only one field can be marked with `#[datastore(key)]`";
+        return syn::Error::new(ident.span(), message)
+            .to_compile_error()
+            .into();
+    }
+    let (key, fields) = split_key_field(fields);
+    let key = match key {
+        Some(key) => key,
+        None => {
+            let message = "a `#[datastore(key)]` field is required to derive `DatastoreEntity`";
+            return syn::Error::new(ident.span(), message)
+                .to_compile_error()
+                .into();
+        }
+    };
+    let values = property_values(&fields);
+    let names = property_names(fields, rename_all);
+
+    let capacity = names.len();
+
+    let tokens = quote! {
+        impl ::google_cloud::datastore::IntoEntity for #ident {
+            fn into_entity(self) -> ::std::result::Result<::google_cloud::datastore::Entity, ::google_cloud::error::ConvertError> {
+                use ::google_cloud::datastore::IntoValue;
+                let key = ::google_cloud::datastore::Key::new(#kind).id(self.#key);
+                let mut props = ::std::collections::HashMap::with_capacity(#capacity);
+                #(props.insert(::std::string::String::from(#names), #values);)*
+                ::google_cloud::datastore::Entity::new(key, ::google_cloud::datastore::Value::EntityValue(props))
+            }
+        }
+
+        impl ::google_cloud::datastore::EntityKind for #ident {
+            const KIND: &'static str = #kind;
+
+            fn with_key(mut self, key: &::google_cloud::datastore::Key) -> ::std::result::Result<Self, ::google_cloud::error::ConvertError> {
+                self.#key = ::google_cloud::datastore::FromKeyID::from_key_id(key.get_id().clone())?;
+                ::std::result::Result::Ok(self)
+            }
+        }
+    };
+
+    tokens.into()
+}
+
+#[proc_macro_derive(DatastoreEntity, attributes(datastore))]
+pub fn derive_datastore_entity(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as syn::DeriveInput);
+    let container = Container::from_derive_input(&input).unwrap();
+
+    let ident = container.ident;
+    let rename_all = container.rename_all.unwrap_or_default();
+    let kind = container.kind.unwrap_or_else(|| ident.to_string());
+
+    match container.data {
+        darling::ast::Data::Enum(_) => {
+            let message = "`DatastoreEntity` can only be derived for structs with named fields";
+            syn::Error::new(ident.span(), message)
+                .to_compile_error()
+                .into()
+        }
+        darling::ast::Data::Struct(darling::ast::Fields { fields, .. }) => {
+            let mut tokens =
+                derive_into_entity_struct(ident.clone(), fields.clone(), rename_all, kind);
+            tokens.extend(derive_from_value_struct(ident, fields, rename_all));
+            tokens
+        }
+    }
+}
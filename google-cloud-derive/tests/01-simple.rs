@@ -9,14 +9,14 @@ pub struct Foo {
 }
 
 fn main() {
-    let foo = Foo {
+    let original = Foo {
         bar: String::from("test"),
         baz: 63,
         qux: true,
     };
-    println!("original: {:?}", foo);
+    println!("original: {:?}", original);
 
-    let converted = foo.into_value();
+    let converted = original.into_value();
     println!("converted: {:?}", converted);
 
     let recovered: Result<Foo, ConvertError> = Foo::from_value(converted);
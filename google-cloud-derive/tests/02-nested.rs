@@ -13,15 +13,15 @@ pub struct Bar {
 }
 
 fn main() {
-    let foo = Foo {
+    let original = Foo {
         bar: Bar {
             baz: String::from("test"),
         },
         qux: true,
     };
-    println!("original: {:?}", foo);
+    println!("original: {:?}", original);
 
-    let converted = foo.into_value();
+    let converted = original.into_value();
     println!("converted: {:?}", converted);
 
     let recovered: Result<Foo, ConvertError> = Foo::from_value(converted);
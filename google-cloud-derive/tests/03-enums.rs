@@ -12,10 +12,10 @@ pub enum Foo {
 }
 
 fn main() {
-    let foo = Foo::AreWeThereYet;
-    println!("original: {:?}", foo);
+    let original = Foo::AreWeThereYet;
+    println!("original: {:?}", original);
 
-    let converted = foo.into_value();
+    let converted = original.into_value();
     println!("converted: {:?}", converted);
 
     let recovered: Result<Foo, ConvertError> = Foo::from_value(converted);
@@ -0,0 +1,30 @@
+use google_cloud::datastore::{DecodeOptions, FromValue, IntoValue, Value};
+
+#[derive(Debug, PartialEq, FromValue, IntoValue)]
+pub struct Measure {
+    count: i32,
+    ratio: f64,
+}
+
+fn main() {
+    let mut props = std::collections::HashMap::new();
+    props.insert(String::from("count"), Value::DoubleValue(3.0));
+    props.insert(String::from("ratio"), Value::IntegerValue(1));
+    let value = Value::EntityValue(props);
+
+    //? Strict decoding rejects numbers of the wrong type.
+    assert!(Measure::from_value(value.clone()).is_err());
+
+    //? Relaxed decoding applies to the fields of derived types.
+    let options = DecodeOptions::default()
+        .widen_integers(true)
+        .integral_doubles(true);
+    let recovered = Measure::from_value_with(value, &options).unwrap();
+    assert_eq!(
+        recovered,
+        Measure {
+            count: 3,
+            ratio: 1.0
+        }
+    );
+}
@@ -0,0 +1,66 @@
+use google_cloud::datastore::{DatastoreEntity, EntityKind, FromValue, IntoEntity, Key, KeyID};
+use google_cloud::error::ConvertError;
+
+#[derive(Debug, PartialEq, DatastoreEntity)]
+#[datastore(kind = "User", rename_all = "snake_case")]
+pub struct User {
+    #[datastore(key)]
+    id: Option<i64>,
+    #[datastore(rename = "mail")]
+    email: String,
+    display_name: String,
+}
+
+#[derive(Debug, PartialEq, DatastoreEntity)]
+pub struct Session {
+    #[datastore(key)]
+    token: String,
+    active: bool,
+}
+
+fn main() {
+    let original = User {
+        id: Some(10),
+        email: String::from("jane@example.com"),
+        display_name: String::from("Jane"),
+    };
+    let entity = original.into_entity().unwrap();
+    assert_eq!(entity.key().get_kind(), "User");
+    assert_eq!(entity.key().get_id(), &KeyID::IntID(10));
+    println!("converted: {:?}", entity);
+
+    let recovered: Result<User, ConvertError> = User::from_value(entity.into_properties());
+    let recovered = recovered.unwrap();
+    assert_eq!(recovered.id, None);
+    assert_eq!(recovered.email, "jane@example.com");
+    assert_eq!(recovered.display_name, "Jane");
+
+    assert_eq!(User::KIND, "User");
+    let recovered = recovered.with_key(&Key::new("User").id(10)).unwrap();
+    assert_eq!(recovered.id, Some(10));
+
+    let incomplete = User {
+        id: None,
+        email: String::from("john@example.com"),
+        display_name: String::from("John"),
+    };
+    assert!(incomplete.into_entity().unwrap().key().is_incomplete());
+
+    let session = Session {
+        token: String::from("abc"),
+        active: true,
+    };
+    let entity = session.into_entity().unwrap();
+    assert_eq!(entity.key().get_kind(), "Session");
+    assert_eq!(entity.key().get_id(), &KeyID::StringID(String::from("abc")));
+
+    assert_eq!(Session::KIND, "Session");
+    let recovered = Session::from_value(entity.properties().clone()).unwrap();
+    assert_eq!(recovered.token, "");
+    let recovered = recovered.with_key(entity.key()).unwrap();
+    assert_eq!(recovered.token, "abc");
+    assert!(Session::from_value(entity.properties().clone())
+        .unwrap()
+        .with_key(&Key::new("Session").id(1))
+        .is_err());
+}
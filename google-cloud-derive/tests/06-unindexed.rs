@@ -0,0 +1,43 @@
+use google_cloud::datastore::{DatastoreEntity, FromValue, IntoEntity, IntoValue, Value};
+
+#[derive(Debug, PartialEq, FromValue, IntoValue)]
+pub struct Revision {
+    body: String,
+}
+
+#[derive(Debug, PartialEq, DatastoreEntity)]
+#[datastore(kind = "Article")]
+pub struct Article {
+    #[datastore(key)]
+    id: i64,
+    title: String,
+    #[datastore(exclude_from_indexes)]
+    revisions: Vec<Revision>,
+}
+
+fn main() {
+    let original = Article {
+        id: 10,
+        title: String::from("Hello"),
+        revisions: vec![Revision {
+            body: String::from("Hello, world!"),
+        }],
+    };
+    let entity = original.into_entity().unwrap();
+    let properties = match entity.properties() {
+        Value::EntityValue(properties) => properties,
+        _ => panic!("unexpected properties"),
+    };
+    assert_eq!(
+        properties["title"],
+        Value::StringValue(String::from("Hello"))
+    );
+    let expected = Revision {
+        body: String::from("Hello, world!"),
+    };
+    let expected = Value::ArrayValue(vec![expected.into_value()]).exclude_from_indexes();
+    assert_eq!(properties["revisions"], expected);
+
+    let recovered = Article::from_value(entity.into_properties()).unwrap();
+    assert_eq!(recovered.revisions[0].body, "Hello, world!");
+}
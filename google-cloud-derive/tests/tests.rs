@@ -6,4 +6,7 @@ fn tests() {
     tests.pass("tests/01-simple.rs");
     tests.pass("tests/02-nested.rs");
     tests.pass("tests/03-enums.rs");
+    tests.pass("tests/04-numbers.rs");
+    tests.pass("tests/05-entities.rs");
+    tests.pass("tests/06-unindexed.rs");
 }
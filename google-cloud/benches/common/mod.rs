@@ -0,0 +1,38 @@
+//! Setup shared by the benchmarks, which run against the project of the integration tests:
+//! the `GCP_TEST_PROJECT` and `GCP_TEST_CREDENTIALS` environment variables must be set at runtime,
+//! otherwise the benchmarks are skipped.
+
+use std::env;
+
+use google_cloud::authorize::ApplicationCredentials;
+use google_cloud::testing::Namespace;
+
+/// The target project and credentials of the benchmarks, if configured.
+pub fn setup() -> Option<(String, ApplicationCredentials)> {
+    let (project, creds) = match (
+        env::var("GCP_TEST_PROJECT"),
+        env::var("GCP_TEST_CREDENTIALS"),
+    ) {
+        (Ok(project), Ok(creds)) => (project, creds),
+        _ => {
+            eprintln!("GCP_TEST_PROJECT or GCP_TEST_CREDENTIALS not set, skipping benchmarks");
+            return None;
+        }
+    };
+    let creds = json::from_str(&creds).expect("incorrect application credentials format");
+
+    Some((project, creds))
+}
+
+/// A namespace to create the benchmarked resources into, deleted once the benchmark is over.
+pub fn namespace(project: &str, creds: &ApplicationCredentials) -> Namespace {
+    Namespace::from_credentials(project, creds.clone()).prefix("bench")
+}
+
+/// Builds a runtime to drive the benchmarked futures.
+pub fn runtime() -> tokio::runtime::Runtime {
+    tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()
+        .expect("failed to build the benchmark runtime")
+}
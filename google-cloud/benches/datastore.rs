@@ -0,0 +1,62 @@
+use std::collections::HashMap;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use google_cloud::datastore::{self, Entity, Key, Value};
+
+mod common;
+
+//? A commit holds at most 500 mutations.
+const BATCH_SIZES: [usize; 3] = [1, 100, 500];
+
+/// Measures how many entities per second get written, depending on the size of the batches.
+fn batch_put(c: &mut Criterion) {
+    let (project, creds) = match common::setup() {
+        Some(setup) => setup,
+        None => return,
+    };
+    let runtime = common::runtime();
+    let namespace = common::namespace(&project, &creds);
+    let client = runtime
+        .block_on(datastore::Client::from_credentials(&project, creds.clone()))
+        .unwrap();
+    let kind = namespace.kind("entities");
+
+    let mut group = c.benchmark_group("datastore_put_all");
+    for batch_size in BATCH_SIZES.iter().copied() {
+        let entities: Vec<Entity> = (0..batch_size)
+            .map(|idx| {
+                let key = Key::new(kind.as_str()).id(format!("entity-{}", idx));
+                let mut properties = HashMap::new();
+                properties.insert(String::from("index"), Value::IntegerValue(idx as i64));
+                properties.insert(
+                    String::from("name"),
+                    Value::StringValue(format!("benchmark entity #{}", idx)),
+                );
+                Entity::new(key, Value::EntityValue(properties)).unwrap()
+            })
+            .collect();
+
+        group.throughput(Throughput::Elements(batch_size as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(batch_size),
+            &entities,
+            |b, entities| {
+                b.to_async(&runtime).iter(|| {
+                    let mut client = client.clone();
+                    let entities = entities.clone();
+                    async move { client.put_all(entities).await.unwrap() }
+                })
+            },
+        );
+    }
+    group.finish();
+
+    runtime.block_on(namespace.cleanup()).unwrap();
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(10);
+    targets = batch_put
+}
+criterion_main!(benches);
@@ -0,0 +1,127 @@
+use std::time::{Duration, Instant};
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use google_cloud::pubsub;
+
+mod common;
+
+//? Small messages make the per-request overhead stand out, which is what the presets trade off.
+const MESSAGE_SIZE: usize = 256;
+
+fn presets() -> [(&'static str, pubsub::Preset); 2] {
+    [
+        ("low_latency", pubsub::Preset::low_latency()),
+        ("high_throughput", pubsub::Preset::high_throughput()),
+    ]
+}
+
+/// Measures how many messages per second get published, depending on the batch size of the preset.
+fn publish_throughput(c: &mut Criterion) {
+    let (project, creds) = match common::setup() {
+        Some(setup) => setup,
+        None => return,
+    };
+    let runtime = common::runtime();
+    let namespace = common::namespace(&project, &creds);
+
+    let mut group = c.benchmark_group("pubsub_publish");
+    for (name, preset) in presets().iter() {
+        let batch_size = preset.publish_batch_size();
+        let config = preset.channel_config();
+        let topic = runtime.block_on(async {
+            let mut client =
+                pubsub::Client::from_credentials_with_config(&project, creds.clone(), config)
+                    .await
+                    .unwrap();
+            let id = namespace.topic(&format!("publish-{}", batch_size));
+            let config = pubsub::TopicConfig::default();
+            client.create_topic(&id, config).await.unwrap()
+        });
+        let messages = vec![vec![0u8; MESSAGE_SIZE]; batch_size];
+
+        group.throughput(Throughput::Elements(batch_size as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(name),
+            &messages,
+            |b, messages| {
+                b.to_async(&runtime).iter(|| {
+                    let mut topic = topic.clone();
+                    let messages = messages.clone();
+                    async move { topic.publish_all(messages).await.unwrap() }
+                })
+            },
+        );
+    }
+    group.finish();
+
+    runtime.block_on(namespace.cleanup()).unwrap();
+}
+
+/// Measures how many messages per second get pulled and acknowledged, depending on the receive options of the preset.
+///
+/// The messages are published beforehand, outside of the measured time.
+fn pull_throughput(c: &mut Criterion) {
+    let (project, creds) = match common::setup() {
+        Some(setup) => setup,
+        None => return,
+    };
+    let runtime = common::runtime();
+    let namespace = common::namespace(&project, &creds);
+
+    let mut group = c.benchmark_group("pubsub_pull");
+    for (name, preset) in presets().iter() {
+        let opts = preset.receive_options();
+        let count = opts.max_messages as usize;
+        let config = preset.channel_config();
+        let (topic, subscription) = runtime.block_on(async {
+            let mut client =
+                pubsub::Client::from_credentials_with_config(&project, creds.clone(), config)
+                    .await
+                    .unwrap();
+            let id = namespace.topic(&format!("pull-{}", count));
+            let config = pubsub::TopicConfig::default();
+            let mut topic = client.create_topic(&id, config).await.unwrap();
+            let id = namespace.subscription(&format!("pull-{}", count));
+            let config = preset.subscription_config();
+            let subscription = topic.create_subscription(&id, config).await.unwrap();
+            (topic, subscription)
+        });
+
+        group.throughput(Throughput::Elements(count as u64));
+        group.bench_function(BenchmarkId::from_parameter(name), |b| {
+            b.to_async(&runtime).iter_custom(|iters| {
+                let mut topic = topic.clone();
+                let mut subscription = subscription.clone();
+                let opts = opts.clone();
+                async move {
+                    let mut elapsed = Duration::ZERO;
+                    for _ in 0..iters {
+                        let messages = vec![vec![0u8; MESSAGE_SIZE]; count];
+                        topic.publish_all(messages).await.unwrap();
+
+                        let start = Instant::now();
+                        for _ in 0..count {
+                            let mut message = subscription
+                                .receive_with_options(opts.clone())
+                                .await
+                                .unwrap();
+                            message.ack().await.unwrap();
+                        }
+                        elapsed += start.elapsed();
+                    }
+                    elapsed
+                }
+            })
+        });
+    }
+    group.finish();
+
+    runtime.block_on(namespace.cleanup()).unwrap();
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(10);
+    targets = publish_throughput, pull_throughput
+}
+criterion_main!(benches);
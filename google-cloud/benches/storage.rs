@@ -0,0 +1,55 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use google_cloud::storage;
+
+mod common;
+
+const OBJECT_SIZES: [usize; 3] = [1 << 10, 1 << 20, 8 << 20];
+
+/// Measures how many bytes per second get uploaded, depending on the size of the objects.
+fn upload(c: &mut Criterion) {
+    let (project, creds) = match common::setup() {
+        Some(setup) => setup,
+        None => return,
+    };
+    let runtime = common::runtime();
+    let namespace = common::namespace(&project, &creds);
+    let bucket = runtime.block_on(async {
+        let mut client = storage::Client::from_credentials(&project, creds.clone())
+            .await
+            .unwrap();
+        let name = namespace.bucket("uploads");
+        client.create_bucket(&name).await.unwrap()
+    });
+
+    let mut group = c.benchmark_group("storage_upload");
+    for size in OBJECT_SIZES.iter().copied() {
+        //? Every upload overwrites the same object, so that only one is left to clean up.
+        let name = namespace.object(bucket.name(), &format!("object-{}", size));
+        let data = vec![0u8; size];
+
+        group.throughput(Throughput::Bytes(size as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(size), &data, |b, data| {
+            b.to_async(&runtime).iter(|| {
+                let mut bucket = bucket.clone();
+                let name = name.clone();
+                let data = data.clone();
+                async move {
+                    bucket
+                        .create_object(&name, data, "application/octet-stream")
+                        .await
+                        .unwrap()
+                }
+            })
+        });
+    }
+    group.finish();
+
+    runtime.block_on(namespace.cleanup()).unwrap();
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(10);
+    targets = upload
+}
+criterion_main!(benches);
@@ -1,29 +1,65 @@
 use std::fs;
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let protos = [
-        (["protos/google/pubsub/v1/pubsub.proto"], "src/pubsub/api"),
+    // The third element lists the `bytes` fields to generate as `bytes::Bytes` instead of `Vec<u8>`.
+    let protos: [(&[&str], _, &[&str]); 7] = [
         (
-            ["protos/google/datastore/v1/datastore.proto"],
+            &["protos/google/pubsub/v1/pubsub.proto"],
+            "src/pubsub/api",
+            &[".google.pubsub.v1.PubsubMessage.data"],
+        ),
+        (
+            &[
+                "protos/google/datastore/v1/datastore.proto",
+                "protos/google/datastore/admin/v1/datastore_admin.proto",
+            ],
             "src/datastore/api",
+            &[],
         ),
         (
-            ["protos/google/cloud/vision/v1/image_annotator.proto"],
+            &["protos/google/cloud/vision/v1/image_annotator.proto"],
             "src/vision/api",
+            &[],
+        ),
+        (
+            &[
+                "protos/google/bigtable/v2/bigtable.proto",
+                "protos/google/bigtable/admin/v2/bigtable_table_admin.proto",
+            ],
+            "src/bigtable/api",
+            &[],
+        ),
+        (
+            &["protos/google/cloud/redis/v1/cloud_redis.proto"],
+            "src/redis/api",
+            &[],
+        ),
+        (
+            &["protos/google/devtools/cloudbuild/v1/cloudbuild.proto"],
+            "src/cloudbuild/api",
+            &[],
+        ),
+        (
+            &["protos/google/cloud/deploy/v1/cloud_deploy.proto"],
+            "src/deploy/api",
+            &[],
         ),
     ];
 
-    for (proto_files, out_dir) in protos.iter() {
-        fs::create_dir_all(&out_dir)?;
+    for (proto_files, out_dir, bytes_fields) in protos.iter() {
+        fs::create_dir_all(out_dir)?;
+
+        let mut config = prost_build::Config::new();
+        config.bytes(bytes_fields.iter());
 
         tonic_build::configure()
             .build_client(true)
             .build_server(false)
             //.format(true)
-            .out_dir(&out_dir)
-            .compile(proto_files, &["protos"])?;
+            .out_dir(out_dir)
+            .compile_with_config(config, proto_files, &["protos"])?;
 
-        for file in proto_files {
+        for file in proto_files.iter() {
             println!("cargo:rerun-if-changed={}", &file);
         }
     }
@@ -0,0 +1,156 @@
+use std::env;
+use std::fmt;
+use std::fs::File;
+
+use chrono::offset::Utc;
+use chrono::DateTime;
+
+use crate::authorize::{ApplicationCredentials, TokenManager};
+use crate::error::{AuthError, Error};
+
+/// The kind of application credentials, as detected from their contents.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CredentialsKind {
+    /// The credentials of a service account, which sign their own token requests.
+    ServiceAccount,
+    /// Placeholder credentials for local emulators, which never fetch tokens.
+    Emulator,
+    /// Another kind of credentials (like `authorized_user`), which this crate cannot authenticate with.
+    Other(String),
+}
+
+impl fmt::Display for CredentialsKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CredentialsKind::ServiceAccount => write!(f, "service account"),
+            CredentialsKind::Emulator => write!(f, "emulator"),
+            CredentialsKind::Other(kind) => write!(f, "unsupported ({})", kind),
+        }
+    }
+}
+
+/// Describes credentials, without any of their secrets (see `Credentials::describe`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CredentialsDescription {
+    pub(crate) kind: CredentialsKind,
+    pub(crate) client_email: String,
+    pub(crate) project_id: String,
+    pub(crate) private_key_id: String,
+    pub(crate) scopes: Vec<String>,
+    pub(crate) token_expiry: Option<DateTime<Utc>>,
+}
+
+impl CredentialsDescription {
+    /// The kind of the credentials.
+    pub fn kind(&self) -> &CredentialsKind {
+        &self.kind
+    }
+
+    /// The email address of the service account.
+    pub fn client_email(&self) -> &str {
+        self.client_email.as_str()
+    }
+
+    /// The project the credentials belong to.
+    pub fn project_id(&self) -> &str {
+        self.project_id.as_str()
+    }
+
+    /// The ID of the private key, as listed in the keys of the service account.
+    pub fn private_key_id(&self) -> &str {
+        self.private_key_id.as_str()
+    }
+
+    /// The scopes that tokens are requested for.
+    pub fn scopes(&self) -> &[String] {
+        self.scopes.as_slice()
+    }
+
+    /// The time at which the current token expires (and gets replaced), if one was fetched.
+    pub fn token_expiry(&self) -> Option<DateTime<Utc>> {
+        self.token_expiry
+    }
+}
+
+impl fmt::Display for CredentialsDescription {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "kind: {}", self.kind)?;
+        writeln!(f, "client email: {}", self.client_email)?;
+        writeln!(f, "project: {}", self.project_id)?;
+        writeln!(f, "private key ID: {}", self.private_key_id)?;
+        writeln!(f, "scopes: {}", self.scopes.join(" "))?;
+        match self.token_expiry {
+            Some(expiry) => write!(f, "token expiry: {}", expiry),
+            None => write!(f, "token expiry: no token fetched"),
+        }
+    }
+}
+
+/// Application credentials along with the scopes they request tokens for,
+/// which can be inspected and validated to investigate authentication issues.
+///
+/// ```no_run
+/// # use google_cloud::authorize::Credentials;
+/// # async fn run() -> Result<(), google_cloud::error::Error> {
+/// let mut creds = Credentials::from_env(&["https://www.googleapis.com/auth/cloud-platform"])?;
+/// println!("{}", creds.describe());
+/// match creds.validate().await {
+///     Ok(description) => println!("token valid until {:?}", description.token_expiry()),
+///     Err(err) => println!("authentication failed: {}", err),
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct Credentials {
+    token_manager: TokenManager,
+}
+
+impl Credentials {
+    /// Use the given application credentials, requesting tokens for the given scopes.
+    pub fn new(creds: ApplicationCredentials, scopes: &[&str]) -> Credentials {
+        Credentials {
+            token_manager: TokenManager::new(creds, scopes),
+        }
+    }
+
+    /// Use the application credentials found at the path in the `GOOGLE_APPLICATION_CREDENTIALS` environment variable,
+    /// just like the clients do.
+    #[allow(clippy::result_large_err)]
+    pub fn from_env(scopes: &[&str]) -> Result<Credentials, Error> {
+        let path = env::var("GOOGLE_APPLICATION_CREDENTIALS")?;
+        let file = File::open(path)?;
+        let creds = json::from_reader(file)?;
+
+        Ok(Credentials::new(creds, scopes))
+    }
+
+    /// Describe the credentials, leaving out their secrets.
+    pub fn describe(&self) -> CredentialsDescription {
+        let creds = self.token_manager.creds();
+        let kind = match creds.cred_type.as_str() {
+            _ if creds.token_uri == "EMULATOR" => CredentialsKind::Emulator,
+            "service_account" => CredentialsKind::ServiceAccount,
+            kind => CredentialsKind::Other(String::from(kind)),
+        };
+
+        CredentialsDescription {
+            kind,
+            client_email: creds.client_email.clone(),
+            project_id: creds.project_id.clone(),
+            private_key_id: creds.private_key_id.clone(),
+            scopes: self.token_manager.scopes().map(String::from).collect(),
+            token_expiry: self.token_manager.token_expiry(),
+        }
+    }
+
+    /// Fetch a new token, to check that the credentials are accepted.
+    ///
+    /// Returns the description of the credentials on success, including the expiry of the fetched token.
+    /// Rejected credentials are reported with `AuthError::Rejected`, which holds the explanation of the token endpoint.
+    pub async fn validate(&mut self) -> Result<CredentialsDescription, AuthError> {
+        self.token_manager.invalidate();
+        self.token_manager.token().await?;
+
+        Ok(self.describe())
+    }
+}
@@ -9,6 +9,10 @@ use serde::{Deserialize, Serialize};
 
 use crate::error::AuthError;
 
+mod credentials;
+
+pub use self::credentials::*;
+
 #[allow(unused)]
 pub(crate) const TLS_CERTS: &[u8] = include_bytes!("../../roots.pem");
 
@@ -31,6 +35,24 @@ pub struct ApplicationCredentials {
     pub client_x509_cert_url: String,
 }
 
+impl ApplicationCredentials {
+    /// Placeholder credentials for local emulators, which never fetch tokens.
+    pub fn emulator(project_id: impl Into<String>) -> ApplicationCredentials {
+        ApplicationCredentials {
+            cred_type: String::from("service_account"),
+            project_id: project_id.into(),
+            private_key_id: String::new(),
+            private_key: String::new(),
+            client_email: String::new(),
+            client_id: String::new(),
+            auth_uri: String::new(),
+            token_uri: String::from("EMULATOR"),
+            auth_provider_x509_cert_url: String::new(),
+            client_x509_cert_url: String::new(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub(crate) enum TokenValue {
     Bearer(String),
@@ -63,6 +85,14 @@ struct AuthResponse {
     access_token: String,
 }
 
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+struct AuthRejection {
+    #[serde(default)]
+    error: String,
+    #[serde(default)]
+    error_description: String,
+}
+
 impl TokenManager {
     pub(crate) fn new(creds: ApplicationCredentials, scopes: &[&str]) -> TokenManager {
         TokenManager {
@@ -79,6 +109,24 @@ impl TokenManager {
         }
     }
 
+    pub(crate) fn creds(&self) -> &ApplicationCredentials {
+        &self.creds
+    }
+
+    pub(crate) fn scopes(&self) -> impl Iterator<Item = &str> {
+        self.scopes.split_whitespace()
+    }
+
+    /// The time at which the current token expires (and gets replaced), if one was fetched.
+    pub(crate) fn token_expiry(&self) -> Option<DateTime<Utc>> {
+        self.current_token.as_ref().map(|token| token.expiry)
+    }
+
+    /// Forgets the current token, so that the next one gets fetched anew.
+    pub(crate) fn invalidate(&mut self) {
+        self.current_token = None;
+    }
+
     pub(crate) async fn token(&mut self) -> Result<String, AuthError> {
         if self.creds.token_uri == "EMULATOR" {
             return Ok("Bearer EMULATOR".to_string());
@@ -113,9 +161,17 @@ impl TokenManager {
                     .header("Content-Type", "application/x-www-form-urlencoded")
                     .body(hyper::Body::from(form))?;
 
-                let data = hyper::body::to_bytes(self.client.request(req).await?.into_body())
-                    .await?
-                    .to_vec();
+                let response = self.client.request(req).await?;
+                let status = response.status();
+                let data = hyper::body::to_bytes(response.into_body()).await?.to_vec();
+                if !status.is_success() {
+                    let rejection: AuthRejection = json::from_slice(&data).unwrap_or_default();
+                    return Err(AuthError::Rejected {
+                        status: status.as_u16(),
+                        error: rejection.error,
+                        description: rejection.error_description,
+                    });
+                }
 
                 let ar: AuthResponse = json::from_slice(&data)?;
 
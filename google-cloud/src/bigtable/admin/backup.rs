@@ -0,0 +1,95 @@
+use chrono::NaiveDateTime;
+
+use crate::bigtable::admin::api;
+use crate::types;
+
+/// Represents the state of a backup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BackupState {
+    /// The backup is still being created.
+    Creating,
+    /// The backup is complete and ready to be restored.
+    Ready,
+    /// The state of the backup is unknown.
+    Unknown,
+}
+
+/// Represents a backup of a table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Backup {
+    pub(crate) name: String,
+    pub(crate) source_table: String,
+    pub(crate) expire_time: Option<NaiveDateTime>,
+    pub(crate) start_time: Option<NaiveDateTime>,
+    pub(crate) end_time: Option<NaiveDateTime>,
+    pub(crate) size_bytes: i64,
+    pub(crate) state: BackupState,
+}
+
+impl Backup {
+    /// The full resource name of the backup.
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    /// Returns the unique identifier of the backup within its cluster.
+    pub fn id(&self) -> &str {
+        self.name.rsplit('/').next().unwrap()
+    }
+
+    /// The full resource name of the table the backup was created from.
+    pub fn source_table(&self) -> &str {
+        self.source_table.as_str()
+    }
+
+    /// The time after which the backup gets deleted.
+    pub fn expire_time(&self) -> Option<NaiveDateTime> {
+        self.expire_time
+    }
+
+    /// The time at which the creation of the backup started.
+    pub fn start_time(&self) -> Option<NaiveDateTime> {
+        self.start_time
+    }
+
+    /// The time at which the creation of the backup finished.
+    ///
+    /// The backup contains no data newer than this time.
+    pub fn end_time(&self) -> Option<NaiveDateTime> {
+        self.end_time
+    }
+
+    /// The size of the backup, in bytes.
+    pub fn size_bytes(&self) -> i64 {
+        self.size_bytes
+    }
+
+    /// The current state of the backup.
+    pub fn state(&self) -> BackupState {
+        self.state
+    }
+}
+
+impl From<api::Backup> for Backup {
+    fn from(backup: api::Backup) -> Backup {
+        let state = match api::backup::State::from_i32(backup.state) {
+            Some(api::backup::State::Creating) => BackupState::Creating,
+            Some(api::backup::State::Ready) => BackupState::Ready,
+            _ => BackupState::Unknown,
+        };
+
+        Backup {
+            name: backup.name,
+            source_table: backup.source_table,
+            expire_time: backup.expire_time.and_then(convert_timestamp),
+            start_time: backup.start_time.and_then(convert_timestamp),
+            end_time: backup.end_time.and_then(convert_timestamp),
+            size_bytes: backup.size_bytes,
+            state,
+        }
+    }
+}
+
+fn convert_timestamp(timestamp: prost_types::Timestamp) -> Option<NaiveDateTime> {
+    types::timestamp_to_naive(timestamp).ok()
+}
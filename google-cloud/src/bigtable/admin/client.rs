@@ -0,0 +1,400 @@
+use std::env;
+use std::fs::File;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::NaiveDateTime;
+use prost::Message;
+use tokio::sync::Mutex;
+use tonic::transport::Channel;
+use tonic::{Code, IntoRequest, Request};
+
+use crate::authorize::{ApplicationCredentials, TokenManager};
+use crate::bigtable::admin::api;
+use crate::bigtable::admin::api::bigtable_table_admin_client::BigtableTableAdminClient;
+use crate::bigtable::admin::{Backup, ColumnFamilyModification, Error, TableConfig, TableInfo};
+use crate::bigtable::api::longrunning;
+use crate::bigtable::api::longrunning::operations_client::OperationsClient;
+use crate::cancel::{self, CancellationToken};
+use crate::channel::{self, ChannelConfig};
+use crate::error::ConvertError;
+use crate::types;
+
+/// The Cloud Bigtable table administration client, tied to a specific project and instance.
+#[derive(Clone)]
+pub struct Client {
+    pub(crate) project_name: String,
+    pub(crate) instance_name: String,
+    pub(crate) service: BigtableTableAdminClient<Channel>,
+    pub(crate) operations: OperationsClient<Channel>,
+    pub(crate) token_manager: Arc<Mutex<TokenManager>>,
+    pub(crate) cancel: Option<CancellationToken>,
+}
+
+struct ClientConfiguration {
+    pub endpoint: String,
+}
+
+impl ClientConfiguration {
+    pub fn new() -> ClientConfiguration {
+        ClientConfiguration {
+            endpoint: match env::var("BIGTABLE_EMULATOR_HOST") {
+                Ok(host) => channel::emulator_endpoint(host.as_str()),
+                Err(_) => Client::ENDPOINT.to_string(),
+            },
+        }
+    }
+}
+
+impl Client {
+    pub(crate) const DOMAIN_NAME: &'static str = "bigtableadmin.googleapis.com";
+    pub(crate) const ENDPOINT: &'static str = "https://bigtableadmin.googleapis.com";
+    pub(crate) const SCOPES: [&'static str; 2] = [
+        "https://www.googleapis.com/auth/cloud-platform",
+        "https://www.googleapis.com/auth/bigtable.admin",
+    ];
+
+    //? Bounds of the delay between two checks of a long-running operation.
+    const MIN_POLL_DELAY: Duration = Duration::from_millis(500);
+    const MAX_POLL_DELAY: Duration = Duration::from_secs(10);
+
+    pub(crate) async fn construct_request<T: IntoRequest<T>>(
+        &mut self,
+        request: T,
+    ) -> Result<Request<T>, Error> {
+        let mut request = request.into_request();
+        let token = self.token_manager.lock().await.token().await?;
+        let metadata = request.metadata_mut();
+        metadata.insert("authorization", token.parse().unwrap());
+        Ok(request)
+    }
+
+    /// Create a new client for the specified project and instance.
+    ///
+    /// Credentials are looked up in the `GOOGLE_APPLICATION_CREDENTIALS` environment variable.
+    pub async fn new(
+        project_name: impl Into<String>,
+        instance_name: impl Into<String>,
+    ) -> Result<Client, Error> {
+        let path = env::var("GOOGLE_APPLICATION_CREDENTIALS")?;
+        let file = File::open(path)?;
+        let creds = json::from_reader(file)?;
+
+        Client::from_credentials(project_name, instance_name, creds).await
+    }
+
+    /// Create a new client for the specified project and instance with custom credentials.
+    pub async fn from_credentials(
+        project_name: impl Into<String>,
+        instance_name: impl Into<String>,
+        creds: ApplicationCredentials,
+    ) -> Result<Client, Error> {
+        Client::from_credentials_with_config(
+            project_name,
+            instance_name,
+            creds,
+            ChannelConfig::default(),
+        )
+        .await
+    }
+
+    /// Create a new client for the specified project and instance with custom credentials and channel configuration.
+    pub async fn from_credentials_with_config(
+        project_name: impl Into<String>,
+        instance_name: impl Into<String>,
+        creds: ApplicationCredentials,
+        config: ChannelConfig,
+    ) -> Result<Client, Error> {
+        let client_config = ClientConfiguration::new();
+        let channel =
+            channel::connect(client_config.endpoint, Client::DOMAIN_NAME, &config).await?;
+
+        Ok(Client {
+            project_name: project_name.into(),
+            instance_name: instance_name.into(),
+            service: BigtableTableAdminClient::new(channel.clone()),
+            operations: OperationsClient::new(channel),
+            token_manager: Arc::new(Mutex::new(TokenManager::new(
+                creds,
+                Client::SCOPES.as_ref(),
+            ))),
+            cancel: None,
+        })
+    }
+
+    /// Stop waiting for long-running operations once the given token gets cancelled,
+    /// the waiting calls then failing with a `CANCELLED` status.
+    ///
+    /// The operations themselves keep running on the service.
+    pub fn cancel_on(mut self, token: CancellationToken) -> Client {
+        self.cancel = Some(token);
+        self
+    }
+
+    fn instance(&self) -> String {
+        format!(
+            "projects/{0}/instances/{1}",
+            self.project_name.as_str(),
+            self.instance_name.as_str(),
+        )
+    }
+
+    fn table_name(&self, id: &str) -> String {
+        format!("{0}/tables/{1}", self.instance(), id)
+    }
+
+    fn cluster_name(&self, id: &str) -> String {
+        format!("{0}/clusters/{1}", self.instance(), id)
+    }
+
+    /// Create a new table.
+    pub async fn create_table(
+        &mut self,
+        table_id: &str,
+        config: TableConfig,
+    ) -> Result<TableInfo, Error> {
+        let column_families = config
+            .column_families
+            .into_iter()
+            .map(|(id, gc_rule)| {
+                let family = api::ColumnFamily {
+                    gc_rule: gc_rule.map(api::GcRule::from),
+                };
+                (id, family)
+            })
+            .collect();
+        let request = api::CreateTableRequest {
+            parent: self.instance(),
+            table_id: table_id.to_string(),
+            table: Some(api::Table {
+                column_families,
+                ..api::Table::default()
+            }),
+            initial_splits: config
+                .splits
+                .into_iter()
+                .map(|key| api::create_table_request::Split { key })
+                .collect(),
+        };
+        let request = self.construct_request(request).await?;
+        let response = self.service.create_table(request).await?;
+        let table = response.into_inner();
+
+        Ok(TableInfo::from(table))
+    }
+
+    /// List all existing tables of the instance.
+    pub async fn tables(&mut self) -> Result<Vec<TableInfo>, Error> {
+        let mut tables = Vec::new();
+        let page_size = 25;
+        let mut page_token = String::default();
+
+        loop {
+            let request = api::ListTablesRequest {
+                parent: self.instance(),
+                view: api::table::View::SchemaView as i32,
+                page_size,
+                page_token,
+            };
+            let request = self.construct_request(request).await?;
+            let response = self.service.list_tables(request).await?;
+            let response = response.into_inner();
+            page_token = response.next_page_token;
+            tables.extend(response.tables.into_iter().map(TableInfo::from));
+            if page_token.is_empty() {
+                break;
+            }
+        }
+
+        Ok(tables)
+    }
+
+    /// Get the schema of a specific table.
+    pub async fn table(&mut self, table_id: &str) -> Result<Option<TableInfo>, Error> {
+        let request = api::GetTableRequest {
+            name: self.table_name(table_id),
+            view: api::table::View::SchemaView as i32,
+        };
+        let request = self.construct_request(request).await?;
+        let table = match self.service.get_table(request).await {
+            Ok(response) => response.into_inner(),
+            Err(status) if status.code() == Code::NotFound => return Ok(None),
+            Err(status) => return Err(status.into()),
+        };
+
+        Ok(Some(TableInfo::from(table)))
+    }
+
+    /// Delete a table, along with all of its data.
+    pub async fn delete_table(&mut self, table_id: &str) -> Result<(), Error> {
+        let request = api::DeleteTableRequest {
+            name: self.table_name(table_id),
+        };
+        let request = self.construct_request(request).await?;
+        self.service.delete_table(request).await?;
+
+        Ok(())
+    }
+
+    /// Atomically apply changes to the column families of a table.
+    ///
+    /// Returns the updated schema of the table.
+    pub async fn modify_column_families(
+        &mut self,
+        table_id: &str,
+        modifications: Vec<ColumnFamilyModification>,
+    ) -> Result<TableInfo, Error> {
+        let request = api::ModifyColumnFamiliesRequest {
+            name: self.table_name(table_id),
+            modifications: modifications.into_iter().map(From::from).collect(),
+        };
+        let request = self.construct_request(request).await?;
+        let response = self.service.modify_column_families(request).await?;
+        let table = response.into_inner();
+
+        Ok(TableInfo::from(table))
+    }
+
+    /// Create a backup of a table, stored in the given cluster, and wait for its completion.
+    ///
+    /// The backup gets deleted after its expiration time,
+    /// which must be between 6 hours and 30 days from now.
+    pub async fn create_backup(
+        &mut self,
+        cluster_id: &str,
+        backup_id: &str,
+        table_id: &str,
+        expire_time: NaiveDateTime,
+    ) -> Result<Backup, Error> {
+        let request = api::CreateBackupRequest {
+            parent: self.cluster_name(cluster_id),
+            backup_id: backup_id.to_string(),
+            backup: Some(api::Backup {
+                source_table: self.table_name(table_id),
+                expire_time: Some(types::naive_to_timestamp(expire_time)),
+                ..api::Backup::default()
+            }),
+        };
+        let request = self.construct_request(request).await?;
+        let response = self.service.create_backup(request).await?;
+        let backup: api::Backup = self.wait_operation(response.into_inner()).await?;
+
+        Ok(Backup::from(backup))
+    }
+
+    /// List the backups of the given cluster.
+    ///
+    /// A cluster ID of `-` lists the backups of all the clusters of the instance.
+    pub async fn backups(&mut self, cluster_id: &str) -> Result<Vec<Backup>, Error> {
+        let mut backups = Vec::new();
+        let page_size = 25;
+        let mut page_token = String::default();
+
+        loop {
+            let request = api::ListBackupsRequest {
+                parent: self.cluster_name(cluster_id),
+                filter: String::new(),
+                order_by: String::new(),
+                page_size,
+                page_token,
+            };
+            let request = self.construct_request(request).await?;
+            let response = self.service.list_backups(request).await?;
+            let response = response.into_inner();
+            page_token = response.next_page_token;
+            backups.extend(response.backups.into_iter().map(Backup::from));
+            if page_token.is_empty() {
+                break;
+            }
+        }
+
+        Ok(backups)
+    }
+
+    /// Get a specific backup.
+    pub async fn backup(
+        &mut self,
+        cluster_id: &str,
+        backup_id: &str,
+    ) -> Result<Option<Backup>, Error> {
+        let request = api::GetBackupRequest {
+            name: format!("{0}/backups/{1}", self.cluster_name(cluster_id), backup_id),
+        };
+        let request = self.construct_request(request).await?;
+        let backup = match self.service.get_backup(request).await {
+            Ok(response) => response.into_inner(),
+            Err(status) if status.code() == Code::NotFound => return Ok(None),
+            Err(status) => return Err(status.into()),
+        };
+
+        Ok(Some(Backup::from(backup)))
+    }
+
+    /// Delete a backup.
+    pub async fn delete_backup(&mut self, cluster_id: &str, backup_id: &str) -> Result<(), Error> {
+        let request = api::DeleteBackupRequest {
+            name: format!("{0}/backups/{1}", self.cluster_name(cluster_id), backup_id),
+        };
+        let request = self.construct_request(request).await?;
+        self.service.delete_backup(request).await?;
+
+        Ok(())
+    }
+
+    /// Restore a backup into a new table of the instance, and wait for its completion.
+    pub async fn restore_table(
+        &mut self,
+        table_id: &str,
+        backup: &Backup,
+    ) -> Result<TableInfo, Error> {
+        let request = api::RestoreTableRequest {
+            parent: self.instance(),
+            table_id: table_id.to_string(),
+            source: Some(api::restore_table_request::Source::Backup(
+                backup.name.clone(),
+            )),
+        };
+        let request = self.construct_request(request).await?;
+        let response = self.service.restore_table(request).await?;
+        let table: api::Table = self.wait_operation(response.into_inner()).await?;
+
+        Ok(TableInfo::from(table))
+    }
+
+    /// Polls a long-running operation until it is done, and decodes its response.
+    async fn wait_operation<T: Message + Default>(
+        &mut self,
+        mut operation: longrunning::Operation,
+    ) -> Result<T, Error> {
+        let token = self.cancel.clone();
+        let mut delay = Client::MIN_POLL_DELAY;
+        let poll = async {
+            while !operation.done {
+                tokio::time::sleep(delay).await;
+                delay = Client::MAX_POLL_DELAY.min(delay * 2);
+                let request = longrunning::GetOperationRequest {
+                    name: operation.name.clone(),
+                };
+                let request = self.construct_request(request).await?;
+                let response = self.operations.get_operation(request).await?;
+                operation = response.into_inner();
+            }
+            Ok(())
+        };
+        cancel::cancellable(token.as_ref(), poll).await?;
+
+        match operation.result {
+            Some(longrunning::operation::Result::Response(response)) => {
+                T::decode(response.value.as_slice()).map_err(|err| {
+                    let message = format!("invalid operation response: {}", err);
+                    Error::Status(tonic::Status::internal(message))
+                })
+            }
+            Some(longrunning::operation::Result::Error(status)) => {
+                let code = Code::from(status.code);
+                Err(Error::Status(tonic::Status::new(code, status.message)))
+            }
+            None => Err(ConvertError::MissingField("result".to_string()).into()),
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use crate::bigtable::admin::api;
+use crate::bigtable::admin::api::gc_rule::{Intersection, Rule, Union};
+use crate::types;
+
+/// Represents a garbage collection rule, determining which cells of a column family get deleted.
+///
+/// Garbage collection runs opportunistically in the background,
+/// so reads can still return cells matching the rule for some time.
+///
+/// ```
+/// # use google_cloud::bigtable::admin::GcRule;
+/// // Keep the 3 most recent versions, but never anything older than a week.
+/// let rule = GcRule::Union(vec![
+///     GcRule::MaxVersions(3),
+///     GcRule::MaxAge(chrono::Duration::days(7)),
+/// ]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GcRule {
+    /// Delete all the cells of a column, except the N most recent ones.
+    MaxVersions(i32),
+    /// Delete the cells older than the given age.
+    MaxAge(chrono::Duration),
+    /// Delete the cells matched by every one of the nested rules.
+    Intersection(Vec<GcRule>),
+    /// Delete the cells matched by any of the nested rules.
+    Union(Vec<GcRule>),
+}
+
+impl GcRule {
+    /// Converts an API rule, returning `None` for an empty rule (meaning no garbage collection).
+    pub(crate) fn from_api(rule: api::GcRule) -> Option<GcRule> {
+        let rule = match rule.rule? {
+            Rule::MaxNumVersions(versions) => GcRule::MaxVersions(versions),
+            Rule::MaxAge(age) => GcRule::MaxAge(
+                chrono::Duration::seconds(age.seconds)
+                    + chrono::Duration::nanoseconds(age.nanos.into()),
+            ),
+            Rule::Intersection(intersection) => GcRule::Intersection(
+                intersection
+                    .rules
+                    .into_iter()
+                    .filter_map(GcRule::from_api)
+                    .collect(),
+            ),
+            Rule::Union(union) => GcRule::Union(
+                union
+                    .rules
+                    .into_iter()
+                    .filter_map(GcRule::from_api)
+                    .collect(),
+            ),
+        };
+
+        Some(rule)
+    }
+}
+
+impl From<GcRule> for api::GcRule {
+    fn from(rule: GcRule) -> api::GcRule {
+        let rule = match rule {
+            GcRule::MaxVersions(versions) => Rule::MaxNumVersions(versions),
+            GcRule::MaxAge(age) => Rule::MaxAge(types::chrono_to_duration(age)),
+            GcRule::Intersection(rules) => Rule::Intersection(Intersection {
+                rules: rules.into_iter().map(api::GcRule::from).collect(),
+            }),
+            GcRule::Union(rules) => Rule::Union(Union {
+                rules: rules.into_iter().map(api::GcRule::from).collect(),
+            }),
+        };
+
+        api::GcRule { rule: Some(rule) }
+    }
+}
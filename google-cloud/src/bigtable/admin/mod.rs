@@ -0,0 +1,14 @@
+mod backup;
+mod client;
+mod gc_rule;
+mod table;
+
+use crate::bigtable::api::bigtable::admin::v2 as api;
+
+pub use self::backup::*;
+pub use self::client::*;
+pub use self::gc_rule::*;
+pub use self::table::*;
+
+/// The error type for the Cloud Bigtable administration module.
+pub type Error = crate::error::Error;
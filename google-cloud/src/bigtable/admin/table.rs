@@ -0,0 +1,99 @@
+use std::collections::HashMap;
+
+use crate::bigtable::admin::{api, GcRule};
+
+/// Represents the configuration of a table to create.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct TableConfig {
+    pub(crate) column_families: HashMap<String, Option<GcRule>>,
+    pub(crate) splits: Vec<Vec<u8>>,
+}
+
+impl TableConfig {
+    /// Add a column family to the table, with an optional garbage collection rule.
+    pub fn column_family(
+        mut self,
+        id: impl Into<String>,
+        gc_rule: impl Into<Option<GcRule>>,
+    ) -> TableConfig {
+        self.column_families.insert(id.into(), gc_rule.into());
+        self
+    }
+
+    /// Add an initial split point, pre-splitting the table into multiple tablets at the given row key.
+    pub fn split(mut self, key: impl Into<Vec<u8>>) -> TableConfig {
+        self.splits.push(key.into());
+        self
+    }
+}
+
+/// Represents a change to the column families of a table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ColumnFamilyModification {
+    /// Create a new column family.
+    Create(String, Option<GcRule>),
+    /// Change the garbage collection rule of an existing column family.
+    Update(String, Option<GcRule>),
+    /// Drop an existing column family, along with all of its data.
+    Drop(String),
+}
+
+impl From<ColumnFamilyModification> for api::modify_column_families_request::Modification {
+    fn from(
+        modification: ColumnFamilyModification,
+    ) -> api::modify_column_families_request::Modification {
+        use api::modify_column_families_request::modification::Mod;
+        let family = |gc_rule: Option<GcRule>| api::ColumnFamily {
+            gc_rule: gc_rule.map(api::GcRule::from),
+        };
+        let (id, modification) = match modification {
+            ColumnFamilyModification::Create(id, gc_rule) => (id, Mod::Create(family(gc_rule))),
+            ColumnFamilyModification::Update(id, gc_rule) => (id, Mod::Update(family(gc_rule))),
+            ColumnFamilyModification::Drop(id) => (id, Mod::Drop(true)),
+        };
+
+        api::modify_column_families_request::Modification {
+            id,
+            r#mod: Some(modification),
+        }
+    }
+}
+
+/// Represents the schema of a table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableInfo {
+    pub(crate) name: String,
+    pub(crate) column_families: HashMap<String, Option<GcRule>>,
+}
+
+impl TableInfo {
+    /// The full resource name of the table.
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    /// Returns the unique identifier of the table within its instance.
+    pub fn id(&self) -> &str {
+        self.name.rsplit('/').next().unwrap()
+    }
+
+    /// The column families of the table, along with their garbage collection rules.
+    pub fn column_families(&self) -> &HashMap<String, Option<GcRule>> {
+        &self.column_families
+    }
+}
+
+impl From<api::Table> for TableInfo {
+    fn from(table: api::Table) -> TableInfo {
+        let column_families = table
+            .column_families
+            .into_iter()
+            .map(|(id, family)| (id, family.gc_rule.and_then(GcRule::from_api)))
+            .collect();
+
+        TableInfo {
+            name: table.name,
+            column_families,
+        }
+    }
+}
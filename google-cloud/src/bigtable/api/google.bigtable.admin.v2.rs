@@ -0,0 +1,1552 @@
+/// A collection of user data indexed by row, column, and timestamp.
+/// Each table is served using the resources of its parent cluster.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Table {
+    /// (`OutputOnly`)
+    /// The unique name of the table. Values are of the form
+    /// `projects/<project>/instances/<instance>/tables/\[_a-zA-Z0-9][-_.a-zA-Z0-9\]*`.
+    /// Views: `NAME_ONLY`, `SCHEMA_VIEW`, `REPLICATION_VIEW`, `FULL`
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    /// (`OutputOnly`)
+    /// Map from cluster ID to per-cluster table state.
+    /// If it could not be determined whether or not the table has data in a
+    /// particular cluster (for example, if its zone is unavailable), then
+    /// there will be an entry for the cluster with UNKNOWN `replication_status`.
+    /// Views: `REPLICATION_VIEW`, `FULL`
+    #[prost(map = "string, message", tag = "2")]
+    pub cluster_states: ::std::collections::HashMap<
+        ::prost::alloc::string::String,
+        table::ClusterState,
+    >,
+    /// (`CreationOnly`)
+    /// The column families configured for this table, mapped by column family ID.
+    /// Views: `SCHEMA_VIEW`, `FULL`
+    #[prost(map = "string, message", tag = "3")]
+    pub column_families: ::std::collections::HashMap<
+        ::prost::alloc::string::String,
+        ColumnFamily,
+    >,
+    /// (`CreationOnly`)
+    /// The granularity (i.e. `MILLIS`) at which timestamps are stored in
+    /// this table. Timestamps not matching the granularity will be rejected.
+    /// If unspecified at creation time, the value will be set to `MILLIS`.
+    /// Views: `SCHEMA_VIEW`, `FULL`
+    #[prost(enumeration = "table::TimestampGranularity", tag = "4")]
+    pub granularity: i32,
+}
+/// Nested message and enum types in `Table`.
+pub mod table {
+    /// The state of a table's data in a particular cluster.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ClusterState {
+        /// (`OutputOnly`)
+        /// The state of replication for the table in this cluster.
+        #[prost(enumeration = "cluster_state::ReplicationState", tag = "1")]
+        pub replication_state: i32,
+    }
+    /// Nested message and enum types in `ClusterState`.
+    pub mod cluster_state {
+        /// Table replication states.
+        #[derive(
+            Clone,
+            Copy,
+            Debug,
+            PartialEq,
+            Eq,
+            Hash,
+            PartialOrd,
+            Ord,
+            ::prost::Enumeration
+        )]
+        #[repr(i32)]
+        pub enum ReplicationState {
+            /// The replication state of the table is unknown in this cluster.
+            StateNotKnown = 0,
+            /// The cluster was recently created, and the table must finish copying
+            /// over pre-existing data from other clusters before it can begin
+            /// receiving live replication updates and serving Data API requests.
+            Initializing = 1,
+            /// The table is temporarily unable to serve Data API requests from this
+            /// cluster due to planned internal maintenance.
+            PlannedMaintenance = 2,
+            /// The table is temporarily unable to serve Data API requests from this
+            /// cluster due to unplanned or emergency maintenance.
+            UnplannedMaintenance = 3,
+            /// The table can serve Data API requests from this cluster. Depending on
+            /// replication delay, reads may not immediately reflect the state of the
+            /// table in other clusters.
+            Ready = 4,
+        }
+        impl ReplicationState {
+            /// String value of the enum field names used in the ProtoBuf definition.
+            ///
+            /// The values are not transformed in any way and thus are considered stable
+            /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+            pub fn as_str_name(&self) -> &'static str {
+                match self {
+                    ReplicationState::StateNotKnown => "STATE_NOT_KNOWN",
+                    ReplicationState::Initializing => "INITIALIZING",
+                    ReplicationState::PlannedMaintenance => "PLANNED_MAINTENANCE",
+                    ReplicationState::UnplannedMaintenance => "UNPLANNED_MAINTENANCE",
+                    ReplicationState::Ready => "READY",
+                }
+            }
+            /// Creates an enum from field names used in the ProtoBuf definition.
+            pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+                match value {
+                    "STATE_NOT_KNOWN" => Some(Self::StateNotKnown),
+                    "INITIALIZING" => Some(Self::Initializing),
+                    "PLANNED_MAINTENANCE" => Some(Self::PlannedMaintenance),
+                    "UNPLANNED_MAINTENANCE" => Some(Self::UnplannedMaintenance),
+                    "READY" => Some(Self::Ready),
+                    _ => None,
+                }
+            }
+        }
+    }
+    /// Possible timestamp granularities to use when keeping multiple versions
+    /// of data in a table.
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum TimestampGranularity {
+        /// The user did not specify a granularity. Should not be returned.
+        /// When specified during table creation, MILLIS will be used.
+        Unspecified = 0,
+        /// The table keeps data versioned at a granularity of 1ms.
+        Millis = 1,
+    }
+    impl TimestampGranularity {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                TimestampGranularity::Unspecified => "TIMESTAMP_GRANULARITY_UNSPECIFIED",
+                TimestampGranularity::Millis => "MILLIS",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "TIMESTAMP_GRANULARITY_UNSPECIFIED" => Some(Self::Unspecified),
+                "MILLIS" => Some(Self::Millis),
+                _ => None,
+            }
+        }
+    }
+    /// Defines a view over a table's fields.
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum View {
+        /// Uses the default view for each method as documented in its request.
+        Unspecified = 0,
+        /// Only populates `name`.
+        NameOnly = 1,
+        /// Only populates `name` and fields related to the table's schema.
+        SchemaView = 2,
+        /// Only populates `name` and fields related to the table's
+        /// replication state.
+        ReplicationView = 3,
+        /// Populates all fields.
+        Full = 4,
+    }
+    impl View {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                View::Unspecified => "VIEW_UNSPECIFIED",
+                View::NameOnly => "NAME_ONLY",
+                View::SchemaView => "SCHEMA_VIEW",
+                View::ReplicationView => "REPLICATION_VIEW",
+                View::Full => "FULL",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "VIEW_UNSPECIFIED" => Some(Self::Unspecified),
+                "NAME_ONLY" => Some(Self::NameOnly),
+                "SCHEMA_VIEW" => Some(Self::SchemaView),
+                "REPLICATION_VIEW" => Some(Self::ReplicationView),
+                "FULL" => Some(Self::Full),
+                _ => None,
+            }
+        }
+    }
+}
+/// A set of columns within a table which share a common configuration.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ColumnFamily {
+    /// Garbage collection rule specified as a protobuf.
+    /// Must serialize to at most 500 bytes.
+    ///
+    /// NOTE: Garbage collection executes opportunistically in the background, and
+    /// so it's possible for reads to return a cell even if it matches the active
+    /// GC expression for its family.
+    #[prost(message, optional, tag = "1")]
+    pub gc_rule: ::core::option::Option<GcRule>,
+}
+/// Rule for determining which cells to delete during garbage collection.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GcRule {
+    /// Garbage collection rules.
+    #[prost(oneof = "gc_rule::Rule", tags = "1, 2, 3, 4")]
+    pub rule: ::core::option::Option<gc_rule::Rule>,
+}
+/// Nested message and enum types in `GcRule`.
+pub mod gc_rule {
+    /// A GcRule which deletes cells matching all of the given rules.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Intersection {
+        /// Only delete cells which would be deleted by every element of `rules`.
+        #[prost(message, repeated, tag = "1")]
+        pub rules: ::prost::alloc::vec::Vec<super::GcRule>,
+    }
+    /// A GcRule which deletes cells matching any of the given rules.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Union {
+        /// Delete cells which would be deleted by any element of `rules`.
+        #[prost(message, repeated, tag = "1")]
+        pub rules: ::prost::alloc::vec::Vec<super::GcRule>,
+    }
+    /// Garbage collection rules.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Rule {
+        /// Delete all cells in a column except the most recent N.
+        #[prost(int32, tag = "1")]
+        MaxNumVersions(i32),
+        /// Delete cells in a column older than the given age.
+        /// Values must be at least one millisecond, and will be truncated to
+        /// microsecond granularity.
+        #[prost(message, tag = "2")]
+        MaxAge(::prost_types::Duration),
+        /// Delete cells that would be deleted by every nested rule.
+        #[prost(message, tag = "3")]
+        Intersection(Intersection),
+        /// Delete cells that would be deleted by any nested rule.
+        #[prost(message, tag = "4")]
+        Union(Union),
+    }
+}
+/// A snapshot of a table at a particular time. A snapshot can be used as a
+/// checkpoint for data restoration or a data source for a new table.
+///
+/// Note: This is a private alpha release of Cloud Bigtable snapshots. This
+/// feature is not currently available to most Cloud Bigtable customers. This
+/// feature might be changed in backward-incompatible ways and is not recommended
+/// for production use. It is not subject to any SLA or deprecation policy.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Snapshot {
+    /// (`OutputOnly`)
+    /// The unique name of the snapshot.
+    /// Values are of the form
+    /// `projects/<project>/instances/<instance>/clusters/<cluster>/snapshots/<snapshot>`.
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    /// (`OutputOnly`)
+    /// The source table at the time the snapshot was taken.
+    #[prost(message, optional, tag = "2")]
+    pub source_table: ::core::option::Option<Table>,
+    /// (`OutputOnly`)
+    /// The size of the data in the source table at the time the snapshot was
+    /// taken. In some cases, this value may be computed asynchronously via a
+    /// background process and a placeholder of 0 will be used in the meantime.
+    #[prost(int64, tag = "3")]
+    pub data_size_bytes: i64,
+    /// (`OutputOnly`)
+    /// The time when the snapshot is created.
+    #[prost(message, optional, tag = "4")]
+    pub create_time: ::core::option::Option<::prost_types::Timestamp>,
+    /// (`OutputOnly`)
+    /// The time when the snapshot will be deleted. The maximum amount of time a
+    /// snapshot can stay active is 365 days. If 'ttl' is not specified,
+    /// the default maximum of 365 days will be used.
+    #[prost(message, optional, tag = "5")]
+    pub delete_time: ::core::option::Option<::prost_types::Timestamp>,
+    /// (`OutputOnly`)
+    /// The current state of the snapshot.
+    #[prost(enumeration = "snapshot::State", tag = "6")]
+    pub state: i32,
+    /// (`OutputOnly`)
+    /// Description of the snapshot.
+    #[prost(string, tag = "7")]
+    pub description: ::prost::alloc::string::String,
+}
+/// Nested message and enum types in `Snapshot`.
+pub mod snapshot {
+    /// Possible states of a snapshot.
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum State {
+        /// The state of the snapshot could not be determined.
+        NotKnown = 0,
+        /// The snapshot has been successfully created and can serve all requests.
+        Ready = 1,
+        /// The snapshot is currently being created, and may be destroyed if the
+        /// creation process encounters an error. A snapshot may not be restored to a
+        /// table while it is being created.
+        Creating = 2,
+    }
+    impl State {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                State::NotKnown => "STATE_NOT_KNOWN",
+                State::Ready => "READY",
+                State::Creating => "CREATING",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "STATE_NOT_KNOWN" => Some(Self::NotKnown),
+                "READY" => Some(Self::Ready),
+                "CREATING" => Some(Self::Creating),
+                _ => None,
+            }
+        }
+    }
+}
+/// A backup of a Cloud Bigtable table.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Backup {
+    /// (`OutputOnly`)
+    /// A globally unique identifier for the backup which cannot be
+    /// changed. Values are of the form
+    /// `projects/{project}/instances/{instance}/clusters/{cluster}/backups/{backup}`.
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    /// (`CreationOnly`)
+    /// Name of the table from which this backup was created. This needs
+    /// to be in the same instance as the backup. Values are of the form
+    /// `projects/{project}/instances/{instance}/tables/{source_table}`.
+    #[prost(string, tag = "2")]
+    pub source_table: ::prost::alloc::string::String,
+    /// The expiration time of the backup, with microseconds
+    /// granularity that must be at least 6 hours and at most 30 days
+    /// from the time the request is received. Once the `expire_time`
+    /// has passed, Cloud Bigtable will delete the backup and free the
+    /// resources used by the backup.
+    #[prost(message, optional, tag = "3")]
+    pub expire_time: ::core::option::Option<::prost_types::Timestamp>,
+    /// (`OutputOnly`)
+    /// `start_time` is the time that the backup was started
+    /// (i.e. approximately the time the `CreateBackup` request is received).
+    #[prost(message, optional, tag = "4")]
+    pub start_time: ::core::option::Option<::prost_types::Timestamp>,
+    /// (`OutputOnly`)
+    /// `end_time` is the time that the backup was finished. The row
+    /// data in the backup will be no newer than this timestamp.
+    #[prost(message, optional, tag = "5")]
+    pub end_time: ::core::option::Option<::prost_types::Timestamp>,
+    /// (`OutputOnly`)
+    /// Size of the backup in bytes.
+    #[prost(int64, tag = "6")]
+    pub size_bytes: i64,
+    /// (`OutputOnly`)
+    /// The current state of the backup.
+    #[prost(enumeration = "backup::State", tag = "7")]
+    pub state: i32,
+}
+/// Nested message and enum types in `Backup`.
+pub mod backup {
+    /// Indicates the current state of the backup.
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum State {
+        /// Not specified.
+        Unspecified = 0,
+        /// The pending backup is still being created. Operations on the
+        /// backup may fail with `FAILED_PRECONDITION` in this state.
+        Creating = 1,
+        /// The backup is complete and ready for use.
+        Ready = 2,
+    }
+    impl State {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                State::Unspecified => "STATE_UNSPECIFIED",
+                State::Creating => "CREATING",
+                State::Ready => "READY",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "STATE_UNSPECIFIED" => Some(Self::Unspecified),
+                "CREATING" => Some(Self::Creating),
+                "READY" => Some(Self::Ready),
+                _ => None,
+            }
+        }
+    }
+}
+/// Request message for
+/// \[google.bigtable.admin.v2.BigtableTableAdmin.CreateTable][google.bigtable.admin.v2.BigtableTableAdmin.CreateTable\]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateTableRequest {
+    /// The unique name of the instance in which to create the table.
+    /// Values are of the form `projects/<project>/instances/<instance>`.
+    #[prost(string, tag = "1")]
+    pub parent: ::prost::alloc::string::String,
+    /// The name by which the new table should be referred to within the parent
+    /// instance, e.g., `foobar` rather than `<parent>/tables/foobar`.
+    #[prost(string, tag = "2")]
+    pub table_id: ::prost::alloc::string::String,
+    /// The Table to create.
+    #[prost(message, optional, tag = "3")]
+    pub table: ::core::option::Option<Table>,
+    /// The optional list of row keys that will be used to initially split the
+    /// table into several tablets (tablets are similar to HBase regions).
+    /// Given two split keys, `s1` and `s2`, three tablets will be created,
+    /// spanning the key ranges: `[, s1), [s1, s2), [s2, )`.
+    ///
+    /// Example:
+    ///
+    /// * Row keys := `["a", "apple", "custom", "customer_1", "customer_2",`
+    ///                 `"other", "zz"]`
+    /// * initial_split_keys := `["apple", "customer_1", "customer_2", "other"]`
+    /// * Key assignment:
+    ///      - Tablet 1 `[, apple)                => {"a"}.`
+    ///      - Tablet 2 `[apple, customer_1)      => {"apple", "custom"}.`
+    ///      - Tablet 3 `[customer_1, customer_2) => {"customer_1"}.`
+    ///      - Tablet 4 `[customer_2, other)      => {"customer_2"}.`
+    ///      - Tablet 5 `[other, )                => {"other", "zz"}.`
+    #[prost(message, repeated, tag = "4")]
+    pub initial_splits: ::prost::alloc::vec::Vec<create_table_request::Split>,
+}
+/// Nested message and enum types in `CreateTableRequest`.
+pub mod create_table_request {
+    /// An initial split point for a newly created table.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Split {
+        /// Row key to use as an initial tablet boundary.
+        #[prost(bytes = "vec", tag = "1")]
+        pub key: ::prost::alloc::vec::Vec<u8>,
+    }
+}
+/// Request message for
+/// \[google.bigtable.admin.v2.BigtableTableAdmin.CreateTableFromSnapshot][google.bigtable.admin.v2.BigtableTableAdmin.CreateTableFromSnapshot\]
+///
+/// Note: This is a private alpha release of Cloud Bigtable snapshots. This
+/// feature is not currently available to most Cloud Bigtable customers. This
+/// feature might be changed in backward-incompatible ways and is not recommended
+/// for production use. It is not subject to any SLA or deprecation policy.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateTableFromSnapshotRequest {
+    /// The unique name of the instance in which to create the table.
+    /// Values are of the form `projects/<project>/instances/<instance>`.
+    #[prost(string, tag = "1")]
+    pub parent: ::prost::alloc::string::String,
+    /// The name by which the new table should be referred to within the parent
+    /// instance, e.g., `foobar` rather than `<parent>/tables/foobar`.
+    #[prost(string, tag = "2")]
+    pub table_id: ::prost::alloc::string::String,
+    /// The unique name of the snapshot from which to restore the table. The
+    /// snapshot and the table must be in the same instance.
+    /// Values are of the form
+    /// `projects/<project>/instances/<instance>/clusters/<cluster>/snapshots/<snapshot>`.
+    #[prost(string, tag = "3")]
+    pub source_snapshot: ::prost::alloc::string::String,
+}
+/// Request message for
+/// \[google.bigtable.admin.v2.BigtableTableAdmin.DropRowRange][google.bigtable.admin.v2.BigtableTableAdmin.DropRowRange\]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DropRowRangeRequest {
+    /// The unique name of the table on which to drop a range of rows.
+    /// Values are of the form
+    /// `projects/<project>/instances/<instance>/tables/<table>`.
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    /// Delete all rows or by prefix.
+    #[prost(oneof = "drop_row_range_request::Target", tags = "2, 3")]
+    pub target: ::core::option::Option<drop_row_range_request::Target>,
+}
+/// Nested message and enum types in `DropRowRangeRequest`.
+pub mod drop_row_range_request {
+    /// Delete all rows or by prefix.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Target {
+        /// Delete all rows that start with this row key prefix. Prefix cannot be
+        /// zero length.
+        #[prost(bytes, tag = "2")]
+        RowKeyPrefix(::prost::alloc::vec::Vec<u8>),
+        /// Delete all rows in the table. Setting this to false is a no-op.
+        #[prost(bool, tag = "3")]
+        DeleteAllDataFromTable(bool),
+    }
+}
+/// Request message for
+/// \[google.bigtable.admin.v2.BigtableTableAdmin.ListTables][google.bigtable.admin.v2.BigtableTableAdmin.ListTables\]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListTablesRequest {
+    /// The unique name of the instance for which tables should be listed.
+    /// Values are of the form `projects/<project>/instances/<instance>`.
+    #[prost(string, tag = "1")]
+    pub parent: ::prost::alloc::string::String,
+    /// The view to be applied to the returned tables' fields.
+    /// Defaults to `NAME_ONLY` if unspecified; no others are currently supported.
+    #[prost(enumeration = "table::View", tag = "2")]
+    pub view: i32,
+    /// Maximum number of results per page.
+    /// CURRENTLY UNIMPLEMENTED AND IGNORED.
+    #[prost(int32, tag = "4")]
+    pub page_size: i32,
+    /// The value of `next_page_token` returned by a previous call.
+    #[prost(string, tag = "3")]
+    pub page_token: ::prost::alloc::string::String,
+}
+/// Response message for
+/// \[google.bigtable.admin.v2.BigtableTableAdmin.ListTables][google.bigtable.admin.v2.BigtableTableAdmin.ListTables\]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListTablesResponse {
+    /// The tables present in the requested instance.
+    #[prost(message, repeated, tag = "1")]
+    pub tables: ::prost::alloc::vec::Vec<Table>,
+    /// Set if not all tables could be returned in a single response.
+    /// Pass this value to `page_token` in another request to get the next
+    /// page of results.
+    #[prost(string, tag = "2")]
+    pub next_page_token: ::prost::alloc::string::String,
+}
+/// Request message for
+/// \[google.bigtable.admin.v2.BigtableTableAdmin.GetTable][google.bigtable.admin.v2.BigtableTableAdmin.GetTable\]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetTableRequest {
+    /// The unique name of the requested table.
+    /// Values are of the form
+    /// `projects/<project>/instances/<instance>/tables/<table>`.
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    /// The view to be applied to the returned table's fields.
+    /// Defaults to `SCHEMA_VIEW` if unspecified.
+    #[prost(enumeration = "table::View", tag = "2")]
+    pub view: i32,
+}
+/// Request message for
+/// \[google.bigtable.admin.v2.BigtableTableAdmin.DeleteTable][google.bigtable.admin.v2.BigtableTableAdmin.DeleteTable\]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteTableRequest {
+    /// The unique name of the table to be deleted.
+    /// Values are of the form
+    /// `projects/<project>/instances/<instance>/tables/<table>`.
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+}
+/// Request message for
+/// \[google.bigtable.admin.v2.BigtableTableAdmin.ModifyColumnFamilies][google.bigtable.admin.v2.BigtableTableAdmin.ModifyColumnFamilies\]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ModifyColumnFamiliesRequest {
+    /// The unique name of the table whose families should be modified.
+    /// Values are of the form
+    /// `projects/<project>/instances/<instance>/tables/<table>`.
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    /// Modifications to be atomically applied to the specified table's families.
+    /// Entries are applied in order, meaning that earlier modifications can be
+    /// masked by later ones (in the case of repeated updates to the same family,
+    /// for example).
+    #[prost(message, repeated, tag = "2")]
+    pub modifications: ::prost::alloc::vec::Vec<
+        modify_column_families_request::Modification,
+    >,
+}
+/// Nested message and enum types in `ModifyColumnFamiliesRequest`.
+pub mod modify_column_families_request {
+    /// A create, update, or delete of a particular column family.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Modification {
+        /// The ID of the column family to be modified.
+        #[prost(string, tag = "1")]
+        pub id: ::prost::alloc::string::String,
+        /// Column familiy modifications.
+        #[prost(oneof = "modification::Mod", tags = "2, 3, 4")]
+        pub r#mod: ::core::option::Option<modification::Mod>,
+    }
+    /// Nested message and enum types in `Modification`.
+    pub mod modification {
+        /// Column familiy modifications.
+        #[allow(clippy::derive_partial_eq_without_eq)]
+        #[derive(Clone, PartialEq, ::prost::Oneof)]
+        pub enum Mod {
+            /// Create a new column family with the specified schema, or fail if
+            /// one already exists with the given ID.
+            #[prost(message, tag = "2")]
+            Create(super::super::ColumnFamily),
+            /// Update an existing column family to the specified schema, or fail
+            /// if no column family exists with the given ID.
+            #[prost(message, tag = "3")]
+            Update(super::super::ColumnFamily),
+            /// Drop (delete) the column family with the given ID, or fail if no such
+            /// family exists.
+            #[prost(bool, tag = "4")]
+            Drop(bool),
+        }
+    }
+}
+/// Request message for
+/// \[google.bigtable.admin.v2.BigtableTableAdmin.GenerateConsistencyToken][google.bigtable.admin.v2.BigtableTableAdmin.GenerateConsistencyToken\]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GenerateConsistencyTokenRequest {
+    /// The unique name of the Table for which to create a consistency token.
+    /// Values are of the form
+    /// `projects/<project>/instances/<instance>/tables/<table>`.
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+}
+/// Response message for
+/// \[google.bigtable.admin.v2.BigtableTableAdmin.GenerateConsistencyToken][google.bigtable.admin.v2.BigtableTableAdmin.GenerateConsistencyToken\]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GenerateConsistencyTokenResponse {
+    /// The generated consistency token.
+    #[prost(string, tag = "1")]
+    pub consistency_token: ::prost::alloc::string::String,
+}
+/// Request message for
+/// \[google.bigtable.admin.v2.BigtableTableAdmin.CheckConsistency][google.bigtable.admin.v2.BigtableTableAdmin.CheckConsistency\]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CheckConsistencyRequest {
+    /// The unique name of the Table for which to check replication consistency.
+    /// Values are of the form
+    /// `projects/<project>/instances/<instance>/tables/<table>`.
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    /// The token created using GenerateConsistencyToken for the Table.
+    #[prost(string, tag = "2")]
+    pub consistency_token: ::prost::alloc::string::String,
+}
+/// Response message for
+/// \[google.bigtable.admin.v2.BigtableTableAdmin.CheckConsistency][google.bigtable.admin.v2.BigtableTableAdmin.CheckConsistency\]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CheckConsistencyResponse {
+    /// True only if the token is consistent. A token is consistent if replication
+    /// has caught up with the restrictions specified in the request.
+    #[prost(bool, tag = "1")]
+    pub consistent: bool,
+}
+/// Request message for
+/// \[google.bigtable.admin.v2.BigtableTableAdmin.SnapshotTable][google.bigtable.admin.v2.BigtableTableAdmin.SnapshotTable\]
+///
+/// Note: This is a private alpha release of Cloud Bigtable snapshots. This
+/// feature is not currently available to most Cloud Bigtable customers. This
+/// feature might be changed in backward-incompatible ways and is not recommended
+/// for production use. It is not subject to any SLA or deprecation policy.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SnapshotTableRequest {
+    /// The unique name of the table to have the snapshot taken.
+    /// Values are of the form
+    /// `projects/<project>/instances/<instance>/tables/<table>`.
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    /// The name of the cluster where the snapshot will be created in.
+    /// Values are of the form
+    /// `projects/<project>/instances/<instance>/clusters/<cluster>`.
+    #[prost(string, tag = "2")]
+    pub cluster: ::prost::alloc::string::String,
+    /// The ID by which the new snapshot should be referred to within the parent
+    /// cluster, e.g., `mysnapshot` of the form: `\[_a-zA-Z0-9][-_.a-zA-Z0-9\]*`
+    /// rather than
+    /// `projects/<project>/instances/<instance>/clusters/<cluster>/snapshots/mysnapshot`.
+    #[prost(string, tag = "3")]
+    pub snapshot_id: ::prost::alloc::string::String,
+    /// The amount of time that the new snapshot can stay active after it is
+    /// created. Once 'ttl' expires, the snapshot will get deleted. The maximum
+    /// amount of time a snapshot can stay active is 7 days. If 'ttl' is not
+    /// specified, the default value of 24 hours will be used.
+    #[prost(message, optional, tag = "4")]
+    pub ttl: ::core::option::Option<::prost_types::Duration>,
+    /// Description of the snapshot.
+    #[prost(string, tag = "5")]
+    pub description: ::prost::alloc::string::String,
+}
+/// Request message for
+/// \[google.bigtable.admin.v2.BigtableTableAdmin.GetSnapshot][google.bigtable.admin.v2.BigtableTableAdmin.GetSnapshot\]
+///
+/// Note: This is a private alpha release of Cloud Bigtable snapshots. This
+/// feature is not currently available to most Cloud Bigtable customers. This
+/// feature might be changed in backward-incompatible ways and is not recommended
+/// for production use. It is not subject to any SLA or deprecation policy.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetSnapshotRequest {
+    /// The unique name of the requested snapshot.
+    /// Values are of the form
+    /// `projects/<project>/instances/<instance>/clusters/<cluster>/snapshots/<snapshot>`.
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+}
+/// Request message for
+/// \[google.bigtable.admin.v2.BigtableTableAdmin.ListSnapshots][google.bigtable.admin.v2.BigtableTableAdmin.ListSnapshots\]
+///
+/// Note: This is a private alpha release of Cloud Bigtable snapshots. This
+/// feature is not currently available to most Cloud Bigtable customers. This
+/// feature might be changed in backward-incompatible ways and is not recommended
+/// for production use. It is not subject to any SLA or deprecation policy.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListSnapshotsRequest {
+    /// The unique name of the cluster for which snapshots should be listed.
+    /// Values are of the form
+    /// `projects/<project>/instances/<instance>/clusters/<cluster>`.
+    /// Use `<cluster> = '-'` to list snapshots for all clusters in an instance,
+    /// e.g., `projects/<project>/instances/<instance>/clusters/-`.
+    #[prost(string, tag = "1")]
+    pub parent: ::prost::alloc::string::String,
+    /// The maximum number of snapshots to return per page.
+    /// CURRENTLY UNIMPLEMENTED AND IGNORED.
+    #[prost(int32, tag = "2")]
+    pub page_size: i32,
+    /// The value of `next_page_token` returned by a previous call.
+    #[prost(string, tag = "3")]
+    pub page_token: ::prost::alloc::string::String,
+}
+/// Response message for
+/// \[google.bigtable.admin.v2.BigtableTableAdmin.ListSnapshots][google.bigtable.admin.v2.BigtableTableAdmin.ListSnapshots\]
+///
+/// Note: This is a private alpha release of Cloud Bigtable snapshots. This
+/// feature is not currently available to most Cloud Bigtable customers. This
+/// feature might be changed in backward-incompatible ways and is not recommended
+/// for production use. It is not subject to any SLA or deprecation policy.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListSnapshotsResponse {
+    /// The snapshots present in the requested cluster.
+    #[prost(message, repeated, tag = "1")]
+    pub snapshots: ::prost::alloc::vec::Vec<Snapshot>,
+    /// Set if not all snapshots could be returned in a single response.
+    /// Pass this value to `page_token` in another request to get the next
+    /// page of results.
+    #[prost(string, tag = "2")]
+    pub next_page_token: ::prost::alloc::string::String,
+}
+/// Request message for
+/// \[google.bigtable.admin.v2.BigtableTableAdmin.DeleteSnapshot][google.bigtable.admin.v2.BigtableTableAdmin.DeleteSnapshot\]
+///
+/// Note: This is a private alpha release of Cloud Bigtable snapshots. This
+/// feature is not currently available to most Cloud Bigtable customers. This
+/// feature might be changed in backward-incompatible ways and is not recommended
+/// for production use. It is not subject to any SLA or deprecation policy.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteSnapshotRequest {
+    /// The unique name of the snapshot to be deleted.
+    /// Values are of the form
+    /// `projects/<project>/instances/<instance>/clusters/<cluster>/snapshots/<snapshot>`.
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+}
+/// The metadata for the Operation returned by SnapshotTable.
+///
+/// Note: This is a private alpha release of Cloud Bigtable snapshots. This
+/// feature is not currently available to most Cloud Bigtable customers. This
+/// feature might be changed in backward-incompatible ways and is not recommended
+/// for production use. It is not subject to any SLA or deprecation policy.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SnapshotTableMetadata {
+    /// The request that prompted the initiation of this SnapshotTable operation.
+    #[prost(message, optional, tag = "1")]
+    pub original_request: ::core::option::Option<SnapshotTableRequest>,
+    /// The time at which the original request was received.
+    #[prost(message, optional, tag = "2")]
+    pub request_time: ::core::option::Option<::prost_types::Timestamp>,
+    /// The time at which the operation failed or was completed successfully.
+    #[prost(message, optional, tag = "3")]
+    pub finish_time: ::core::option::Option<::prost_types::Timestamp>,
+}
+/// The metadata for the Operation returned by CreateTableFromSnapshot.
+///
+/// Note: This is a private alpha release of Cloud Bigtable snapshots. This
+/// feature is not currently available to most Cloud Bigtable customers. This
+/// feature might be changed in backward-incompatible ways and is not recommended
+/// for production use. It is not subject to any SLA or deprecation policy.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateTableFromSnapshotMetadata {
+    /// The request that prompted the initiation of this CreateTableFromSnapshot
+    /// operation.
+    #[prost(message, optional, tag = "1")]
+    pub original_request: ::core::option::Option<CreateTableFromSnapshotRequest>,
+    /// The time at which the original request was received.
+    #[prost(message, optional, tag = "2")]
+    pub request_time: ::core::option::Option<::prost_types::Timestamp>,
+    /// The time at which the operation failed or was completed successfully.
+    #[prost(message, optional, tag = "3")]
+    pub finish_time: ::core::option::Option<::prost_types::Timestamp>,
+}
+/// The request for
+/// \[CreateBackup][google.bigtable.admin.v2.BigtableTableAdmin.CreateBackup\].
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateBackupRequest {
+    /// This must be one of the clusters in the instance in which this
+    /// table is located. The backup will be stored in this cluster. Values are
+    /// of the form `projects/{project}/instances/{instance}/clusters/{cluster}`.
+    #[prost(string, tag = "1")]
+    pub parent: ::prost::alloc::string::String,
+    /// The id of the backup to be created. The `backup_id` along with
+    /// the parent `parent` are combined as {parent}/backups/{backup_id} to create
+    /// the full backup name, of the form:
+    /// `projects/{project}/instances/{instance}/clusters/{cluster}/backups/{backup_id}`.
+    /// This string must be between 1 and 50 characters in length and match the
+    /// regex \[_a-zA-Z0-9][-_.a-zA-Z0-9\]*.
+    #[prost(string, tag = "2")]
+    pub backup_id: ::prost::alloc::string::String,
+    /// The backup to create.
+    #[prost(message, optional, tag = "3")]
+    pub backup: ::core::option::Option<Backup>,
+}
+/// The request for
+/// \[GetBackup][google.bigtable.admin.v2.BigtableTableAdmin.GetBackup\].
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetBackupRequest {
+    /// Name of the backup.
+    /// Values are of the form
+    /// `projects/{project}/instances/{instance}/clusters/{cluster}/backups/{backup}`.
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+}
+/// The request for
+/// \[DeleteBackup][google.bigtable.admin.v2.BigtableTableAdmin.DeleteBackup\].
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteBackupRequest {
+    /// Name of the backup to delete.
+    /// Values are of the form
+    /// `projects/{project}/instances/{instance}/clusters/{cluster}/backups/{backup}`.
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+}
+/// The request for
+/// \[ListBackups][google.bigtable.admin.v2.BigtableTableAdmin.ListBackups\].
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListBackupsRequest {
+    /// The cluster to list backups from. Values are of the
+    /// form `projects/{project}/instances/{instance}/clusters/{cluster}`.
+    /// Use `{cluster} = '-'` to list backups for all clusters in an instance,
+    /// e.g., `projects/{project}/instances/{instance}/clusters/-`.
+    #[prost(string, tag = "1")]
+    pub parent: ::prost::alloc::string::String,
+    /// A filter expression that filters backups listed in the response.
+    #[prost(string, tag = "2")]
+    pub filter: ::prost::alloc::string::String,
+    /// An expression for specifying the sort order of the results of the request.
+    #[prost(string, tag = "3")]
+    pub order_by: ::prost::alloc::string::String,
+    /// Number of backups to be returned in the response. If 0 or
+    /// less, defaults to the server's maximum allowed page size.
+    #[prost(int32, tag = "4")]
+    pub page_size: i32,
+    /// If non-empty, `page_token` should contain a
+    /// \[next_page_token][google.bigtable.admin.v2.ListBackupsResponse.next_page_token\]
+    /// from a previous
+    /// \[ListBackupsResponse][google.bigtable.admin.v2.ListBackupsResponse\] to the
+    /// same `parent` and with the same `filter`.
+    #[prost(string, tag = "5")]
+    pub page_token: ::prost::alloc::string::String,
+}
+/// The response for
+/// \[ListBackups][google.bigtable.admin.v2.BigtableTableAdmin.ListBackups\].
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListBackupsResponse {
+    /// The list of matching backups.
+    #[prost(message, repeated, tag = "1")]
+    pub backups: ::prost::alloc::vec::Vec<Backup>,
+    /// `next_page_token` can be sent in a subsequent
+    /// \[ListBackups][google.bigtable.admin.v2.BigtableTableAdmin.ListBackups\] call
+    /// to fetch more of the matching backups.
+    #[prost(string, tag = "2")]
+    pub next_page_token: ::prost::alloc::string::String,
+}
+/// The request for
+/// \[RestoreTable][google.bigtable.admin.v2.BigtableTableAdmin.RestoreTable\].
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RestoreTableRequest {
+    /// The name of the instance in which to create the restored
+    /// table. This instance must be the parent of the source backup. Values are
+    /// of the form `projects/<project>/instances/<instance>`.
+    #[prost(string, tag = "1")]
+    pub parent: ::prost::alloc::string::String,
+    /// The id of the table to create and restore to. This
+    /// table must not already exist. The `table_id` appended to
+    /// `parent` forms the full table name of the form
+    /// `projects/<project>/instances/<instance>/tables/<table_id>`.
+    #[prost(string, tag = "2")]
+    pub table_id: ::prost::alloc::string::String,
+    /// The source from which to restore.
+    #[prost(oneof = "restore_table_request::Source", tags = "3")]
+    pub source: ::core::option::Option<restore_table_request::Source>,
+}
+/// Nested message and enum types in `RestoreTableRequest`.
+pub mod restore_table_request {
+    /// The source from which to restore.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Source {
+        /// Name of the backup from which to restore. Values are of the form
+        /// `projects/<project>/instances/<instance>/clusters/<cluster>/backups/<backup>`.
+        #[prost(string, tag = "3")]
+        Backup(::prost::alloc::string::String),
+    }
+}
+/// Generated client implementations.
+pub mod bigtable_table_admin_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    /// Service for creating, configuring, and deleting Cloud Bigtable tables.
+    ///
+    ///
+    /// Provides access to the table schemas only, not the data stored within
+    /// the tables.
+    #[derive(Debug, Clone)]
+    pub struct BigtableTableAdminClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl BigtableTableAdminClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: std::convert::TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> BigtableTableAdminClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> BigtableTableAdminClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+            >>::Error: Into<StdError> + Send + Sync,
+        {
+            BigtableTableAdminClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Creates a new table in the specified instance.
+        /// The table can be created with a full set of initial column families,
+        /// specified in the request.
+        pub async fn create_table(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CreateTableRequest>,
+        ) -> Result<tonic::Response<super::Table>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/google.bigtable.admin.v2.BigtableTableAdmin/CreateTable",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// Creates a new table from the specified snapshot. The target table must
+        /// not exist. The snapshot and the table must be in the same instance.
+        ///
+        /// Note: This is a private alpha release of Cloud Bigtable snapshots. This
+        /// feature is not currently available to most Cloud Bigtable customers. This
+        /// feature might be changed in backward-incompatible ways and is not
+        /// recommended for production use. It is not subject to any SLA or deprecation
+        /// policy.
+        pub async fn create_table_from_snapshot(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CreateTableFromSnapshotRequest>,
+        ) -> Result<
+            tonic::Response<super::super::super::super::longrunning::Operation>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/google.bigtable.admin.v2.BigtableTableAdmin/CreateTableFromSnapshot",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// Lists all tables served from a specified instance.
+        pub async fn list_tables(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListTablesRequest>,
+        ) -> Result<tonic::Response<super::ListTablesResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/google.bigtable.admin.v2.BigtableTableAdmin/ListTables",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// Gets metadata information about the specified table.
+        pub async fn get_table(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetTableRequest>,
+        ) -> Result<tonic::Response<super::Table>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/google.bigtable.admin.v2.BigtableTableAdmin/GetTable",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// Permanently deletes a specified table and all of its data.
+        pub async fn delete_table(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DeleteTableRequest>,
+        ) -> Result<tonic::Response<()>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/google.bigtable.admin.v2.BigtableTableAdmin/DeleteTable",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// Performs a series of column family modifications on the specified table.
+        /// Either all or none of the modifications will occur before this method
+        /// returns, but data requests received prior to that point may see a table
+        /// where only some modifications have taken effect.
+        pub async fn modify_column_families(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ModifyColumnFamiliesRequest>,
+        ) -> Result<tonic::Response<super::Table>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/google.bigtable.admin.v2.BigtableTableAdmin/ModifyColumnFamilies",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// Permanently drop/delete a row range from a specified table. The request can
+        /// specify whether to delete all rows in a table, or only those that match a
+        /// particular prefix.
+        pub async fn drop_row_range(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DropRowRangeRequest>,
+        ) -> Result<tonic::Response<()>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/google.bigtable.admin.v2.BigtableTableAdmin/DropRowRange",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// Generates a consistency token for a Table, which can be used in
+        /// CheckConsistency to check whether mutations to the table that finished
+        /// before this call started have been replicated. The tokens will be available
+        /// for 90 days.
+        pub async fn generate_consistency_token(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GenerateConsistencyTokenRequest>,
+        ) -> Result<
+            tonic::Response<super::GenerateConsistencyTokenResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/google.bigtable.admin.v2.BigtableTableAdmin/GenerateConsistencyToken",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// Checks replication consistency based on a consistency token, that is, if
+        /// replication has caught up based on the conditions specified in the token
+        /// and the check request.
+        pub async fn check_consistency(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CheckConsistencyRequest>,
+        ) -> Result<tonic::Response<super::CheckConsistencyResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/google.bigtable.admin.v2.BigtableTableAdmin/CheckConsistency",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// Creates a new snapshot in the specified cluster from the specified
+        /// source table. The cluster and the table must be in the same instance.
+        ///
+        /// Note: This is a private alpha release of Cloud Bigtable snapshots. This
+        /// feature is not currently available to most Cloud Bigtable customers. This
+        /// feature might be changed in backward-incompatible ways and is not
+        /// recommended for production use. It is not subject to any SLA or deprecation
+        /// policy.
+        pub async fn snapshot_table(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SnapshotTableRequest>,
+        ) -> Result<
+            tonic::Response<super::super::super::super::longrunning::Operation>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/google.bigtable.admin.v2.BigtableTableAdmin/SnapshotTable",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// Gets metadata information about the specified snapshot.
+        ///
+        /// Note: This is a private alpha release of Cloud Bigtable snapshots. This
+        /// feature is not currently available to most Cloud Bigtable customers. This
+        /// feature might be changed in backward-incompatible ways and is not
+        /// recommended for production use. It is not subject to any SLA or deprecation
+        /// policy.
+        pub async fn get_snapshot(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetSnapshotRequest>,
+        ) -> Result<tonic::Response<super::Snapshot>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/google.bigtable.admin.v2.BigtableTableAdmin/GetSnapshot",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// Lists all snapshots associated with the specified cluster.
+        ///
+        /// Note: This is a private alpha release of Cloud Bigtable snapshots. This
+        /// feature is not currently available to most Cloud Bigtable customers. This
+        /// feature might be changed in backward-incompatible ways and is not
+        /// recommended for production use. It is not subject to any SLA or deprecation
+        /// policy.
+        pub async fn list_snapshots(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListSnapshotsRequest>,
+        ) -> Result<tonic::Response<super::ListSnapshotsResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/google.bigtable.admin.v2.BigtableTableAdmin/ListSnapshots",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// Permanently deletes the specified snapshot.
+        ///
+        /// Note: This is a private alpha release of Cloud Bigtable snapshots. This
+        /// feature is not currently available to most Cloud Bigtable customers. This
+        /// feature might be changed in backward-incompatible ways and is not
+        /// recommended for production use. It is not subject to any SLA or deprecation
+        /// policy.
+        pub async fn delete_snapshot(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DeleteSnapshotRequest>,
+        ) -> Result<tonic::Response<()>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/google.bigtable.admin.v2.BigtableTableAdmin/DeleteSnapshot",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// Starts creating a new Cloud Bigtable Backup. The returned backup
+        /// [long-running operation][google.longrunning.Operation] can be used to
+        /// track creation of the backup.
+        pub async fn create_backup(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CreateBackupRequest>,
+        ) -> Result<
+            tonic::Response<super::super::super::super::longrunning::Operation>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/google.bigtable.admin.v2.BigtableTableAdmin/CreateBackup",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// Gets metadata on a pending or completed Cloud Bigtable Backup.
+        pub async fn get_backup(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetBackupRequest>,
+        ) -> Result<tonic::Response<super::Backup>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/google.bigtable.admin.v2.BigtableTableAdmin/GetBackup",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// Deletes a pending or completed Cloud Bigtable backup.
+        pub async fn delete_backup(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DeleteBackupRequest>,
+        ) -> Result<tonic::Response<()>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/google.bigtable.admin.v2.BigtableTableAdmin/DeleteBackup",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// Lists Cloud Bigtable backups. Returns both completed and pending
+        /// backups.
+        pub async fn list_backups(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListBackupsRequest>,
+        ) -> Result<tonic::Response<super::ListBackupsResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/google.bigtable.admin.v2.BigtableTableAdmin/ListBackups",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// Create a new table by restoring from a completed backup. The new table
+        /// must be in the same instance as the instance containing the backup. The
+        /// returned table [long-running operation][google.longrunning.Operation] can
+        /// be used to track the progress of the operation.
+        pub async fn restore_table(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RestoreTableRequest>,
+        ) -> Result<
+            tonic::Response<super::super::super::super::longrunning::Operation>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/google.bigtable.admin.v2.BigtableTableAdmin/RestoreTable",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// Gets the access control policy for an instance resource. Returns an empty
+        /// policy if an table exists but does not have a policy set.
+        pub async fn get_iam_policy(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::super::super::iam::v1::GetIamPolicyRequest,
+            >,
+        ) -> Result<
+            tonic::Response<super::super::super::super::iam::v1::Policy>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/google.bigtable.admin.v2.BigtableTableAdmin/GetIamPolicy",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// Sets the access control policy on a table resource. Replaces any existing
+        /// policy.
+        pub async fn set_iam_policy(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::super::super::iam::v1::SetIamPolicyRequest,
+            >,
+        ) -> Result<
+            tonic::Response<super::super::super::super::iam::v1::Policy>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/google.bigtable.admin.v2.BigtableTableAdmin/SetIamPolicy",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// Returns permissions that the caller has on the specified table resource.
+        pub async fn test_iam_permissions(
+            &mut self,
+            request: impl tonic::IntoRequest<
+                super::super::super::super::iam::v1::TestIamPermissionsRequest,
+            >,
+        ) -> Result<
+            tonic::Response<
+                super::super::super::super::iam::v1::TestIamPermissionsResponse,
+            >,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/google.bigtable.admin.v2.BigtableTableAdmin/TestIamPermissions",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+    }
+}
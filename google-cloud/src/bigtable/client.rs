@@ -0,0 +1,128 @@
+use std::env;
+use std::fs::File;
+use std::sync::Arc;
+
+use tokio::sync::Mutex;
+use tonic::transport::Channel;
+use tonic::{IntoRequest, Request};
+
+use crate::authorize::{ApplicationCredentials, TokenManager};
+use crate::bigtable::api::bigtable_client::BigtableClient;
+use crate::bigtable::{Error, Table};
+use crate::channel::{self, ChannelConfig};
+
+/// The Cloud Bigtable client, tied to a specific project and instance.
+#[derive(Clone)]
+pub struct Client {
+    pub(crate) project_name: String,
+    pub(crate) instance_name: String,
+    pub(crate) app_profile_id: String,
+    pub(crate) service: BigtableClient<Channel>,
+    pub(crate) token_manager: Arc<Mutex<TokenManager>>,
+}
+
+struct ClientConfiguration {
+    pub endpoint: String,
+}
+
+impl ClientConfiguration {
+    pub fn new() -> ClientConfiguration {
+        ClientConfiguration {
+            endpoint: match env::var("BIGTABLE_EMULATOR_HOST") {
+                Ok(host) => channel::emulator_endpoint(host.as_str()),
+                Err(_) => Client::ENDPOINT.to_string(),
+            },
+        }
+    }
+}
+
+impl Client {
+    pub(crate) const DOMAIN_NAME: &'static str = "bigtable.googleapis.com";
+    pub(crate) const ENDPOINT: &'static str = "https://bigtable.googleapis.com";
+    pub(crate) const SCOPES: [&'static str; 2] = [
+        "https://www.googleapis.com/auth/cloud-platform",
+        "https://www.googleapis.com/auth/bigtable.data",
+    ];
+
+    pub(crate) async fn construct_request<T: IntoRequest<T>>(
+        &mut self,
+        request: T,
+    ) -> Result<Request<T>, Error> {
+        let mut request = request.into_request();
+        let token = self.token_manager.lock().await.token().await?;
+        let metadata = request.metadata_mut();
+        metadata.insert("authorization", token.parse().unwrap());
+        Ok(request)
+    }
+
+    /// Create a new client for the specified project and instance.
+    ///
+    /// Credentials are looked up in the `GOOGLE_APPLICATION_CREDENTIALS` environment variable.
+    pub async fn new(
+        project_name: impl Into<String>,
+        instance_name: impl Into<String>,
+    ) -> Result<Client, Error> {
+        let path = env::var("GOOGLE_APPLICATION_CREDENTIALS")?;
+        let file = File::open(path)?;
+        let creds = json::from_reader(file)?;
+
+        Client::from_credentials(project_name, instance_name, creds).await
+    }
+
+    /// Create a new client for the specified project and instance with custom credentials.
+    pub async fn from_credentials(
+        project_name: impl Into<String>,
+        instance_name: impl Into<String>,
+        creds: ApplicationCredentials,
+    ) -> Result<Client, Error> {
+        Client::from_credentials_with_config(
+            project_name,
+            instance_name,
+            creds,
+            ChannelConfig::default(),
+        )
+        .await
+    }
+
+    /// Create a new client for the specified project and instance with custom credentials and channel configuration.
+    pub async fn from_credentials_with_config(
+        project_name: impl Into<String>,
+        instance_name: impl Into<String>,
+        creds: ApplicationCredentials,
+        config: ChannelConfig,
+    ) -> Result<Client, Error> {
+        let client_config = ClientConfiguration::new();
+        let channel =
+            channel::connect(client_config.endpoint, Client::DOMAIN_NAME, &config).await?;
+
+        Ok(Client {
+            project_name: project_name.into(),
+            instance_name: instance_name.into(),
+            app_profile_id: String::new(),
+            service: BigtableClient::new(channel),
+            token_manager: Arc::new(Mutex::new(TokenManager::new(
+                creds,
+                Client::SCOPES.as_ref(),
+            ))),
+        })
+    }
+
+    /// Set the app profile used to route the requests of this client.
+    ///
+    /// If not set, the instance's default app profile is used.
+    pub fn app_profile(mut self, app_profile_id: impl Into<String>) -> Client {
+        self.app_profile_id = app_profile_id.into();
+        self
+    }
+
+    /// Get a handle to a specific table of the instance.
+    pub fn table(&self, id: &str) -> Table {
+        let name = format!(
+            "projects/{0}/instances/{1}/tables/{2}",
+            self.project_name.as_str(),
+            self.instance_name.as_str(),
+            id,
+        );
+        Table::new(self.clone(), name)
+    }
+}
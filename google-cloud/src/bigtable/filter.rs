@@ -0,0 +1,159 @@
+use std::ops::Bound;
+
+use crate::bigtable::api;
+use crate::bigtable::api::row_filter::{Chain, Condition, Filter, Interleave};
+
+/// Represents a filter, selecting which cells of the rows are returned by a read.
+///
+/// Regular expressions use the RE2 syntax, and are matched against raw bytes.
+///
+/// ```
+/// # use google_cloud::bigtable::RowFilter;
+/// let filter = RowFilter::Chain(vec![
+///     RowFilter::FamilyNameRegex("^stats$".into()),
+///     RowFilter::CellsPerColumnLimit(1),
+/// ]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum RowFilter {
+    /// Apply every filter in sequence, each one on the output of the previous one.
+    Chain(Vec<RowFilter>),
+    /// Apply every filter on the same input, and merge their outputs.
+    Interleave(Vec<RowFilter>),
+    /// Apply the `true` filter if the predicate filter outputs any cell, otherwise apply the `false` filter.
+    ///
+    /// A missing filter outputs no cells.
+    Condition {
+        /// The predicate filter.
+        predicate: Box<RowFilter>,
+        /// The filter applied when the predicate matches.
+        true_filter: Option<Box<RowFilter>>,
+        /// The filter applied when the predicate does not match.
+        false_filter: Option<Box<RowFilter>>,
+    },
+    /// Output all the cells of the input row.
+    PassAll,
+    /// Output no cells.
+    BlockAll,
+    /// Output the cells of the rows whose key matches the regular expression.
+    RowKeyRegex(Vec<u8>),
+    /// Output the cells of a random sample of rows, each row being kept with the given probability.
+    RowSample(f64),
+    /// Output the cells whose family name matches the regular expression.
+    FamilyNameRegex(String),
+    /// Output the cells whose column qualifier matches the regular expression.
+    ColumnQualifierRegex(Vec<u8>),
+    /// Output the cells of the columns of a family whose qualifiers fall within a range.
+    ColumnRange {
+        /// The column family.
+        family: String,
+        /// The start of the range of qualifiers.
+        start: Bound<Vec<u8>>,
+        /// The end of the range of qualifiers.
+        end: Bound<Vec<u8>>,
+    },
+    /// Output the cells whose timestamp (in microseconds) falls within `[start, end)`.
+    ///
+    /// An `end` of zero means no upper bound.
+    TimestampRange {
+        /// The inclusive start of the range.
+        start_micros: i64,
+        /// The exclusive end of the range.
+        end_micros: i64,
+    },
+    /// Output the cells whose value matches the regular expression.
+    ValueRegex(Vec<u8>),
+    /// Output the cells whose value falls within a range.
+    ValueRange {
+        /// The start of the range of values.
+        start: Bound<Vec<u8>>,
+        /// The end of the range of values.
+        end: Bound<Vec<u8>>,
+    },
+    /// Skip the first N cells of each row.
+    CellsPerRowOffset(i32),
+    /// Output at most the first N cells of each row.
+    CellsPerRowLimit(i32),
+    /// Output at most the N most recent cells of each column.
+    CellsPerColumnLimit(i32),
+    /// Replace the value of each cell by the empty string.
+    StripValue,
+    /// Apply the given label to each cell.
+    ApplyLabel(String),
+}
+
+impl From<RowFilter> for api::RowFilter {
+    fn from(filter: RowFilter) -> api::RowFilter {
+        let filter = match filter {
+            RowFilter::Chain(filters) => Filter::Chain(Chain {
+                filters: filters.into_iter().map(api::RowFilter::from).collect(),
+            }),
+            RowFilter::Interleave(filters) => Filter::Interleave(Interleave {
+                filters: filters.into_iter().map(api::RowFilter::from).collect(),
+            }),
+            RowFilter::Condition {
+                predicate,
+                true_filter,
+                false_filter,
+            } => Filter::Condition(Box::new(Condition {
+                predicate_filter: Some(Box::new(api::RowFilter::from(*predicate))),
+                true_filter: true_filter.map(|filter| Box::new(api::RowFilter::from(*filter))),
+                false_filter: false_filter.map(|filter| Box::new(api::RowFilter::from(*filter))),
+            })),
+            RowFilter::PassAll => Filter::PassAllFilter(true),
+            RowFilter::BlockAll => Filter::BlockAllFilter(true),
+            RowFilter::RowKeyRegex(regex) => Filter::RowKeyRegexFilter(regex),
+            RowFilter::RowSample(probability) => Filter::RowSampleFilter(probability),
+            RowFilter::FamilyNameRegex(regex) => Filter::FamilyNameRegexFilter(regex),
+            RowFilter::ColumnQualifierRegex(regex) => Filter::ColumnQualifierRegexFilter(regex),
+            RowFilter::ColumnRange { family, start, end } => {
+                use api::column_range::{EndQualifier, StartQualifier};
+                Filter::ColumnRangeFilter(api::ColumnRange {
+                    family_name: family,
+                    start_qualifier: match start {
+                        Bound::Included(start) => Some(StartQualifier::StartQualifierClosed(start)),
+                        Bound::Excluded(start) => Some(StartQualifier::StartQualifierOpen(start)),
+                        Bound::Unbounded => None,
+                    },
+                    end_qualifier: match end {
+                        Bound::Included(end) => Some(EndQualifier::EndQualifierClosed(end)),
+                        Bound::Excluded(end) => Some(EndQualifier::EndQualifierOpen(end)),
+                        Bound::Unbounded => None,
+                    },
+                })
+            }
+            RowFilter::TimestampRange {
+                start_micros,
+                end_micros,
+            } => Filter::TimestampRangeFilter(api::TimestampRange {
+                start_timestamp_micros: start_micros,
+                end_timestamp_micros: end_micros,
+            }),
+            RowFilter::ValueRegex(regex) => Filter::ValueRegexFilter(regex),
+            RowFilter::ValueRange { start, end } => {
+                use api::value_range::{EndValue, StartValue};
+                Filter::ValueRangeFilter(api::ValueRange {
+                    start_value: match start {
+                        Bound::Included(start) => Some(StartValue::StartValueClosed(start)),
+                        Bound::Excluded(start) => Some(StartValue::StartValueOpen(start)),
+                        Bound::Unbounded => None,
+                    },
+                    end_value: match end {
+                        Bound::Included(end) => Some(EndValue::EndValueClosed(end)),
+                        Bound::Excluded(end) => Some(EndValue::EndValueOpen(end)),
+                        Bound::Unbounded => None,
+                    },
+                })
+            }
+            RowFilter::CellsPerRowOffset(offset) => Filter::CellsPerRowOffsetFilter(offset),
+            RowFilter::CellsPerRowLimit(limit) => Filter::CellsPerRowLimitFilter(limit),
+            RowFilter::CellsPerColumnLimit(limit) => Filter::CellsPerColumnLimitFilter(limit),
+            RowFilter::StripValue => Filter::StripValueTransformer(true),
+            RowFilter::ApplyLabel(label) => Filter::ApplyLabelTransformer(label),
+        };
+
+        api::RowFilter {
+            filter: Some(filter),
+        }
+    }
+}
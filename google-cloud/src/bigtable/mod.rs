@@ -0,0 +1,56 @@
+//? Row merging reports malformed responses as `tonic::Status`, which is large, like the module's `Error` type.
+#![allow(clippy::result_large_err)]
+
+/// Cloud Bigtable table administration (tables, column families and backups).
+pub mod admin;
+mod client;
+mod filter;
+mod mutation;
+mod row;
+mod table;
+pub(crate) mod api {
+    #![allow(
+        dead_code,
+        clippy::enum_variant_names,
+        clippy::doc_lazy_continuation,
+        clippy::doc_overindented_list_items
+    )]
+    pub mod rpc {
+        include!("api/google.rpc.rs");
+    }
+    #[allow(clippy::module_inception)]
+    pub mod api {
+        include!("api/google.api.rs");
+    }
+    pub mod iam {
+        pub mod v1 {
+            include!("api/google.iam.v1.rs");
+        }
+    }
+    pub mod longrunning {
+        include!("api/google.longrunning.rs");
+    }
+    pub mod r#type {
+        include!("api/google.r#type.rs");
+    }
+    pub mod bigtable {
+        pub mod v2 {
+            include!("api/google.bigtable.v2.rs");
+        }
+        pub mod admin {
+            pub mod v2 {
+                include!("api/google.bigtable.admin.v2.rs");
+            }
+        }
+    }
+    pub use self::bigtable::v2::*;
+}
+
+pub use self::client::*;
+pub use self::filter::*;
+pub use self::mutation::*;
+pub use self::row::*;
+pub use self::table::*;
+
+/// The error type for the Cloud Bigtable module.
+pub type Error = crate::error::Error;
@@ -0,0 +1,145 @@
+use crate::bigtable::api;
+use crate::bigtable::api::mutation::{DeleteFromColumn, DeleteFromFamily, DeleteFromRow, SetCell};
+use crate::bigtable::api::read_modify_write_rule::Rule;
+
+/// Represents a change to apply to a row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Mutation {
+    /// Write a value into a cell.
+    SetCell {
+        /// The column family of the cell.
+        family: String,
+        /// The column qualifier of the cell.
+        qualifier: Vec<u8>,
+        /// The timestamp of the cell, in microseconds.
+        ///
+        /// A value of `-1` lets the server use its current time.
+        timestamp_micros: i64,
+        /// The value to write.
+        value: Vec<u8>,
+    },
+    /// Delete the cells of a column, optionally restricted to the timestamps within `[start, end)`.
+    DeleteFromColumn {
+        /// The column family of the column.
+        family: String,
+        /// The column qualifier of the column.
+        qualifier: Vec<u8>,
+        /// The range of timestamps (in microseconds) of the cells to delete.
+        time_range: Option<(i64, i64)>,
+    },
+    /// Delete all the cells of a column family.
+    DeleteFromFamily(String),
+    /// Delete all the cells of the row.
+    DeleteFromRow,
+}
+
+impl Mutation {
+    /// Write a value into a cell, using the server's current time as its timestamp.
+    pub fn set_cell(
+        family: impl Into<String>,
+        qualifier: impl Into<Vec<u8>>,
+        value: impl Into<Vec<u8>>,
+    ) -> Mutation {
+        Mutation::SetCell {
+            family: family.into(),
+            qualifier: qualifier.into(),
+            timestamp_micros: -1,
+            value: value.into(),
+        }
+    }
+
+    /// Delete all the cells of a column.
+    pub fn delete_column(family: impl Into<String>, qualifier: impl Into<Vec<u8>>) -> Mutation {
+        Mutation::DeleteFromColumn {
+            family: family.into(),
+            qualifier: qualifier.into(),
+            time_range: None,
+        }
+    }
+}
+
+impl From<Mutation> for api::Mutation {
+    fn from(mutation: Mutation) -> api::Mutation {
+        use api::mutation::Mutation as Kind;
+        let mutation = match mutation {
+            Mutation::SetCell {
+                family,
+                qualifier,
+                timestamp_micros,
+                value,
+            } => Kind::SetCell(SetCell {
+                family_name: family,
+                column_qualifier: qualifier,
+                timestamp_micros,
+                value,
+            }),
+            Mutation::DeleteFromColumn {
+                family,
+                qualifier,
+                time_range,
+            } => Kind::DeleteFromColumn(DeleteFromColumn {
+                family_name: family,
+                column_qualifier: qualifier,
+                time_range: time_range.map(|(start, end)| api::TimestampRange {
+                    start_timestamp_micros: start,
+                    end_timestamp_micros: end,
+                }),
+            }),
+            Mutation::DeleteFromFamily(family) => Kind::DeleteFromFamily(DeleteFromFamily {
+                family_name: family,
+            }),
+            Mutation::DeleteFromRow => Kind::DeleteFromRow(DeleteFromRow {}),
+        };
+
+        api::Mutation {
+            mutation: Some(mutation),
+        }
+    }
+}
+
+/// Represents an atomic read-modify-write operation on the latest value of a cell.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReadModifyWriteRule {
+    /// Append bytes to the existing value (an unset cell is treated as empty).
+    Append {
+        /// The column family of the cell.
+        family: String,
+        /// The column qualifier of the cell.
+        qualifier: Vec<u8>,
+        /// The bytes to append.
+        value: Vec<u8>,
+    },
+    /// Add an amount to the existing value, interpreted as a 64-bit big-endian signed integer
+    /// (an unset cell is treated as zero).
+    Increment {
+        /// The column family of the cell.
+        family: String,
+        /// The column qualifier of the cell.
+        qualifier: Vec<u8>,
+        /// The amount to add.
+        amount: i64,
+    },
+}
+
+impl From<ReadModifyWriteRule> for api::ReadModifyWriteRule {
+    fn from(rule: ReadModifyWriteRule) -> api::ReadModifyWriteRule {
+        let (family_name, column_qualifier, rule) = match rule {
+            ReadModifyWriteRule::Append {
+                family,
+                qualifier,
+                value,
+            } => (family, qualifier, Rule::AppendValue(value)),
+            ReadModifyWriteRule::Increment {
+                family,
+                qualifier,
+                amount,
+            } => (family, qualifier, Rule::IncrementAmount(amount)),
+        };
+
+        api::ReadModifyWriteRule {
+            family_name,
+            column_qualifier,
+            rule: Some(rule),
+        }
+    }
+}
@@ -0,0 +1,313 @@
+use std::ops::{Bound, RangeBounds};
+
+use crate::bigtable::api;
+use crate::bigtable::api::read_rows_response::cell_chunk::RowStatus;
+use crate::bigtable::api::read_rows_response::CellChunk;
+
+/// Represents a single cell (a timestamped value of a column) of a row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cell {
+    pub(crate) family: String,
+    pub(crate) qualifier: Vec<u8>,
+    pub(crate) timestamp_micros: i64,
+    pub(crate) value: Vec<u8>,
+    pub(crate) labels: Vec<String>,
+}
+
+impl Cell {
+    /// The name of the column family of the cell.
+    pub fn family(&self) -> &str {
+        self.family.as_str()
+    }
+
+    /// The column qualifier of the cell.
+    pub fn qualifier(&self) -> &[u8] {
+        self.qualifier.as_slice()
+    }
+
+    /// The timestamp of the cell, in microseconds.
+    pub fn timestamp_micros(&self) -> i64 {
+        self.timestamp_micros
+    }
+
+    /// The value of the cell.
+    pub fn value(&self) -> &[u8] {
+        self.value.as_slice()
+    }
+
+    /// Move the value of the cell out.
+    pub fn into_value(self) -> Vec<u8> {
+        self.value
+    }
+
+    /// The labels applied to the cell by `RowFilter::ApplyLabel`.
+    pub fn labels(&self) -> &[String] {
+        self.labels.as_slice()
+    }
+}
+
+/// Represents a row of a table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Row {
+    pub(crate) key: Vec<u8>,
+    pub(crate) cells: Vec<Cell>,
+}
+
+impl Row {
+    /// The key of the row.
+    pub fn key(&self) -> &[u8] {
+        self.key.as_slice()
+    }
+
+    /// The cells of the row.
+    ///
+    /// Cells are ordered by family, then by qualifier, then from newest to oldest.
+    pub fn cells(&self) -> &[Cell] {
+        self.cells.as_slice()
+    }
+
+    /// Get the most recent cell of a specific column.
+    pub fn cell(&self, family: &str, qualifier: impl AsRef<[u8]>) -> Option<&Cell> {
+        let qualifier = qualifier.as_ref();
+        self.cells
+            .iter()
+            .find(|cell| cell.family == family && cell.qualifier == qualifier)
+    }
+
+    /// Move the cells of the row out.
+    pub fn into_cells(self) -> Vec<Cell> {
+        self.cells
+    }
+}
+
+impl From<api::Row> for Row {
+    fn from(row: api::Row) -> Row {
+        let cells = row
+            .families
+            .into_iter()
+            .flat_map(|family| {
+                let name = family.name;
+                family.columns.into_iter().flat_map(move |column| {
+                    let family = name.clone();
+                    let qualifier = column.qualifier;
+                    column.cells.into_iter().map(move |cell| Cell {
+                        family: family.clone(),
+                        qualifier: qualifier.clone(),
+                        timestamp_micros: cell.timestamp_micros,
+                        value: cell.value,
+                        labels: cell.labels,
+                    })
+                })
+            })
+            .collect();
+
+        Row {
+            key: row.key,
+            cells,
+        }
+    }
+}
+
+/// Represents a contiguous range of row keys.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RowRange {
+    pub(crate) start: Bound<Vec<u8>>,
+    pub(crate) end: Bound<Vec<u8>>,
+}
+
+impl RowRange {
+    /// Create a range from any range of keys, such as `b"a".to_vec()..b"c".to_vec()`.
+    pub fn new(range: impl RangeBounds<Vec<u8>>) -> RowRange {
+        RowRange {
+            start: range.start_bound().cloned(),
+            end: range.end_bound().cloned(),
+        }
+    }
+
+    /// The range of all the row keys starting with the given prefix.
+    pub fn prefix(prefix: impl Into<Vec<u8>>) -> RowRange {
+        let prefix = prefix.into();
+        //? The end of the range is the prefix with its last non-0xFF byte incremented.
+        let mut end = prefix.clone();
+        while end.last() == Some(&0xFF) {
+            end.pop();
+        }
+        let end = match end.last_mut() {
+            Some(last) => {
+                *last += 1;
+                Bound::Excluded(end)
+            }
+            None => Bound::Unbounded,
+        };
+
+        RowRange {
+            start: Bound::Included(prefix),
+            end,
+        }
+    }
+}
+
+impl From<RowRange> for api::RowRange {
+    fn from(range: RowRange) -> api::RowRange {
+        use api::row_range::{EndKey, StartKey};
+        let start_key = match range.start {
+            Bound::Included(key) => Some(StartKey::StartKeyClosed(key)),
+            Bound::Excluded(key) => Some(StartKey::StartKeyOpen(key)),
+            Bound::Unbounded => None,
+        };
+        let end_key = match range.end {
+            Bound::Included(key) => Some(EndKey::EndKeyClosed(key)),
+            Bound::Excluded(key) => Some(EndKey::EndKeyOpen(key)),
+            Bound::Unbounded => None,
+        };
+
+        api::RowRange { start_key, end_key }
+    }
+}
+
+/// Represents a set of rows to read, made of single row keys and row ranges.
+///
+/// An empty set reads the entire table.
+///
+/// ```
+/// # use google_cloud::bigtable::{RowRange, RowSet};
+/// let rows = RowSet::new()
+///     .key("user#alice")
+///     .range(RowRange::prefix("order#"));
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct RowSet {
+    pub(crate) keys: Vec<Vec<u8>>,
+    pub(crate) ranges: Vec<RowRange>,
+}
+
+impl RowSet {
+    /// Create a new, empty, row set.
+    pub fn new() -> RowSet {
+        RowSet::default()
+    }
+
+    /// Add a single row key to the set.
+    pub fn key(mut self, key: impl Into<Vec<u8>>) -> RowSet {
+        self.keys.push(key.into());
+        self
+    }
+
+    /// Add a range of row keys to the set.
+    pub fn range(mut self, range: RowRange) -> RowSet {
+        self.ranges.push(range);
+        self
+    }
+}
+
+impl From<RowSet> for api::RowSet {
+    fn from(set: RowSet) -> api::RowSet {
+        api::RowSet {
+            row_keys: set.keys,
+            row_ranges: set.ranges.into_iter().map(api::RowRange::from).collect(),
+        }
+    }
+}
+
+/// Reassembles rows from the cell chunks streamed by `ReadRows`.
+#[derive(Debug, Default)]
+pub(crate) struct RowMerger {
+    row: Option<Row>,
+    cell: Option<Cell>,
+    partial: bool,
+    last_key: Option<Vec<u8>>,
+}
+
+impl RowMerger {
+    pub(crate) fn new() -> RowMerger {
+        RowMerger::default()
+    }
+
+    /// Feeds a chunk into the merger, returning the row it completes, if any.
+    pub(crate) fn push(&mut self, chunk: CellChunk) -> Result<Option<Row>, tonic::Status> {
+        if let Some(RowStatus::ResetRow(true)) = chunk.row_status {
+            if self.row.is_none() {
+                return Err(invalid_chunk("reset without a row in progress"));
+            }
+            self.row = None;
+            self.cell = None;
+            self.partial = false;
+            return Ok(None);
+        }
+
+        if !chunk.row_key.is_empty() {
+            match (&self.row, &self.last_key) {
+                (Some(row), _) if row.key != chunk.row_key => {
+                    return Err(invalid_chunk(
+                        "row key changed before the row was committed",
+                    ));
+                }
+                (None, Some(last)) if chunk.row_key <= *last => {
+                    return Err(invalid_chunk("row keys are not increasing"));
+                }
+                (None, _) => {
+                    self.row = Some(Row {
+                        key: chunk.row_key,
+                        cells: Vec::new(),
+                    });
+                }
+                _ => {}
+            }
+        }
+        let row = self
+            .row
+            .as_mut()
+            .ok_or_else(|| invalid_chunk("missing row key"))?;
+
+        if self.partial {
+            let cell = self.cell.as_mut().unwrap();
+            cell.value.extend(chunk.value);
+        } else {
+            let (family, qualifier) = match (chunk.family_name, chunk.qualifier, &self.cell) {
+                (Some(family), Some(qualifier), _) => (family, qualifier),
+                (None, Some(qualifier), Some(previous)) => (previous.family.clone(), qualifier),
+                (None, None, Some(previous)) => {
+                    (previous.family.clone(), previous.qualifier.clone())
+                }
+                (Some(_), None, _) => return Err(invalid_chunk("family without a qualifier")),
+                (None, _, None) => return Err(invalid_chunk("missing column family")),
+            };
+            self.cell = Some(Cell {
+                family,
+                qualifier,
+                timestamp_micros: chunk.timestamp_micros,
+                value: chunk.value,
+                labels: chunk.labels,
+            });
+        }
+
+        self.partial = chunk.value_size > 0;
+        if !self.partial {
+            row.cells.push(self.cell.clone().unwrap());
+        }
+
+        if let Some(RowStatus::CommitRow(true)) = chunk.row_status {
+            if self.partial {
+                return Err(invalid_chunk("commit in the middle of a cell"));
+            }
+            let row = self.row.take().unwrap();
+            self.cell = None;
+            self.last_key = Some(row.key.clone());
+            return Ok(Some(row));
+        }
+
+        Ok(None)
+    }
+
+    /// Checks that the stream did not end in the middle of a row.
+    pub(crate) fn finish(&self) -> Result<(), tonic::Status> {
+        match self.row {
+            Some(_) => Err(invalid_chunk("stream ended before the row was committed")),
+            None => Ok(()),
+        }
+    }
+}
+
+fn invalid_chunk(message: &str) -> tonic::Status {
+    tonic::Status::internal(format!("invalid ReadRows response: {}", message))
+}
@@ -0,0 +1,228 @@
+use futures::stream::{self, Stream, StreamExt, TryStreamExt};
+use tonic::Streaming;
+
+use crate::bigtable::api;
+use crate::bigtable::row::RowMerger;
+use crate::bigtable::{Client, Error, Mutation, ReadModifyWriteRule, Row, RowFilter, RowSet};
+
+/// Represents a table of a Cloud Bigtable instance.
+#[derive(Clone)]
+pub struct Table {
+    pub(crate) client: Client,
+    pub(crate) name: String,
+}
+
+impl Table {
+    pub(crate) fn new(client: Client, name: impl Into<String>) -> Table {
+        Table {
+            client,
+            name: name.into(),
+        }
+    }
+
+    /// Returns the unique identifier of the table within its instance.
+    pub fn id(&self) -> &str {
+        self.name.rsplit('/').next().unwrap()
+    }
+
+    /// Read a single row, by key.
+    pub async fn read_row(
+        &mut self,
+        key: impl Into<Vec<u8>>,
+        filter: Option<RowFilter>,
+    ) -> Result<Option<Row>, Error> {
+        let mut rows = self.read_rows(RowSet::new().key(key), filter, 1).await?;
+        Ok(rows.pop())
+    }
+
+    /// Read the rows of the given set, in key order.
+    ///
+    /// A `limit` of zero reads all the matching rows.
+    pub async fn read_rows(
+        &mut self,
+        rows: RowSet,
+        filter: Option<RowFilter>,
+        limit: i64,
+    ) -> Result<Vec<Row>, Error> {
+        self.read_rows_stream(rows, filter, limit)
+            .await?
+            .try_collect()
+            .await
+    }
+
+    /// Read the rows of the given set, in key order, as a stream.
+    ///
+    /// Rows are yielded as soon as they are fully received, without buffering the whole result set.
+    /// A `limit` of zero reads all the matching rows.
+    pub async fn read_rows_stream(
+        &mut self,
+        rows: RowSet,
+        filter: Option<RowFilter>,
+        limit: i64,
+    ) -> Result<impl Stream<Item = Result<Row, Error>>, Error> {
+        let request = api::ReadRowsRequest {
+            table_name: self.name.clone(),
+            app_profile_id: self.client.app_profile_id.clone(),
+            rows: Some(rows.into()),
+            filter: filter.map(api::RowFilter::from),
+            rows_limit: limit,
+        };
+        let request = self.client.construct_request(request).await?;
+        let response = self.client.service.read_rows(request).await?;
+        let responses = response.into_inner();
+
+        Ok(merge_rows(responses))
+    }
+
+    /// Atomically apply mutations to a single row.
+    pub async fn mutate_row(
+        &mut self,
+        key: impl Into<Vec<u8>>,
+        mutations: Vec<Mutation>,
+    ) -> Result<(), Error> {
+        let request = api::MutateRowRequest {
+            table_name: self.name.clone(),
+            app_profile_id: self.client.app_profile_id.clone(),
+            row_key: key.into(),
+            mutations: mutations.into_iter().map(api::Mutation::from).collect(),
+        };
+        let request = self.client.construct_request(request).await?;
+        self.client.service.mutate_row(request).await?;
+
+        Ok(())
+    }
+
+    /// Apply mutations to multiple rows.
+    ///
+    /// Each row is mutated atomically, but the rows are mutated independently from one another.
+    /// The returned results are in the same order as the given entries.
+    pub async fn mutate_rows<K>(
+        &mut self,
+        entries: impl IntoIterator<Item = (K, Vec<Mutation>)>,
+    ) -> Result<Vec<Result<(), Error>>, Error>
+    where
+        K: Into<Vec<u8>>,
+    {
+        let entries: Vec<_> = entries
+            .into_iter()
+            .map(|(key, mutations)| api::mutate_rows_request::Entry {
+                row_key: key.into(),
+                mutations: mutations.into_iter().map(api::Mutation::from).collect(),
+            })
+            .collect();
+        let mut results: Vec<Result<(), Error>> = entries.iter().map(|_| Ok(())).collect();
+
+        let request = api::MutateRowsRequest {
+            table_name: self.name.clone(),
+            app_profile_id: self.client.app_profile_id.clone(),
+            entries,
+        };
+        let request = self.client.construct_request(request).await?;
+        let response = self.client.service.mutate_rows(request).await?;
+        let mut responses = response.into_inner();
+
+        while let Some(response) = responses.message().await? {
+            for entry in response.entries {
+                let status = match entry.status {
+                    Some(status) if status.code != 0 => status,
+                    _ => continue,
+                };
+                let code = tonic::Code::from(status.code);
+                if let Some(result) = results.get_mut(entry.index as usize) {
+                    *result = Err(Error::Status(tonic::Status::new(code, status.message)));
+                }
+            }
+        }
+
+        Ok(results)
+    }
+
+    /// Atomically apply mutations to a row, depending on whether the predicate filter matches any of its cells.
+    ///
+    /// If no predicate is given, it matches as soon as the row contains any cell.
+    /// Returns whether the predicate matched.
+    pub async fn check_and_mutate_row(
+        &mut self,
+        key: impl Into<Vec<u8>>,
+        predicate: Option<RowFilter>,
+        true_mutations: Vec<Mutation>,
+        false_mutations: Vec<Mutation>,
+    ) -> Result<bool, Error> {
+        let request = api::CheckAndMutateRowRequest {
+            table_name: self.name.clone(),
+            app_profile_id: self.client.app_profile_id.clone(),
+            row_key: key.into(),
+            predicate_filter: predicate.map(api::RowFilter::from),
+            true_mutations: true_mutations
+                .into_iter()
+                .map(api::Mutation::from)
+                .collect(),
+            false_mutations: false_mutations
+                .into_iter()
+                .map(api::Mutation::from)
+                .collect(),
+        };
+        let request = self.client.construct_request(request).await?;
+        let response = self.client.service.check_and_mutate_row(request).await?;
+        let response = response.into_inner();
+
+        Ok(response.predicate_matched)
+    }
+
+    /// Atomically modify the latest values of cells of a row, based on their current values.
+    ///
+    /// Returns the new contents of the modified cells.
+    pub async fn read_modify_write_row(
+        &mut self,
+        key: impl Into<Vec<u8>>,
+        rules: Vec<ReadModifyWriteRule>,
+    ) -> Result<Row, Error> {
+        let request = api::ReadModifyWriteRowRequest {
+            table_name: self.name.clone(),
+            app_profile_id: self.client.app_profile_id.clone(),
+            row_key: key.into(),
+            rules: rules
+                .into_iter()
+                .map(api::ReadModifyWriteRule::from)
+                .collect(),
+        };
+        let request = self.client.construct_request(request).await?;
+        let response = self.client.service.read_modify_write_row(request).await?;
+        let row = response.into_inner().row.unwrap_or_default();
+
+        Ok(Row::from(row))
+    }
+}
+
+/// Turns a stream of `ReadRows` responses into a stream of complete rows.
+fn merge_rows(
+    responses: Streaming<api::ReadRowsResponse>,
+) -> impl Stream<Item = Result<Row, Error>> {
+    let state = Some((responses, RowMerger::new()));
+    stream::unfold(state, |state| async move {
+        let (mut responses, mut merger) = state?;
+        let response = match responses.message().await {
+            Ok(Some(response)) => response,
+            Ok(None) => {
+                return merger
+                    .finish()
+                    .err()
+                    .map(|err| (vec![Err(err.into())], None));
+            }
+            Err(err) => return Some((vec![Err(err.into())], None)),
+        };
+        let mut rows = Vec::new();
+        for chunk in response.chunks {
+            match merger.push(chunk) {
+                Ok(Some(row)) => rows.push(Ok(row)),
+                Ok(None) => {}
+                Err(err) => {
+                    rows.push(Err(err.into()));
+                    return Some((rows, None));
+                }
+            }
+        }
+        Some((rows, Some((responses, merger))))
+    })
+    .flat_map(stream::iter)
+}
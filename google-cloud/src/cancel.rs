@@ -0,0 +1,53 @@
+use std::future::Future;
+
+use futures::future;
+use futures::stream::{Stream, StreamExt};
+
+use crate::error::Error;
+
+pub use tokio_util::sync::CancellationToken;
+
+/// The error of operations interrupted by the cancellation of their token.
+#[allow(unused)]
+pub(crate) fn cancelled() -> Error {
+    tonic::Status::cancelled("the operation was cancelled").into()
+}
+
+/// Runs an operation until it completes, or fails with a `CANCELLED` status once the token (if any) gets cancelled.
+#[allow(unused)]
+pub(crate) async fn cancellable<T, F>(
+    token: Option<&CancellationToken>,
+    operation: F,
+) -> Result<T, Error>
+where
+    F: Future<Output = Result<T, Error>>,
+{
+    match token {
+        Some(token) => {
+            tokio::select! {
+                //? A cancelled token wins over an operation completing at the same time.
+                biased;
+                _ = token.cancelled() => Err(cancelled()),
+                result = operation => result,
+            }
+        }
+        None => operation.await,
+    }
+}
+
+/// Ends a stream once the token (if any) gets cancelled, dropping the request in flight.
+#[allow(unused)]
+pub(crate) fn until_cancelled<S>(
+    stream: S,
+    token: Option<CancellationToken>,
+) -> impl Stream<Item = S::Item>
+where
+    S: Stream,
+{
+    stream.take_until(async move {
+        match token {
+            Some(token) => token.cancelled().await,
+            None => future::pending().await,
+        }
+    })
+}
@@ -0,0 +1,277 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::cdc::Error;
+use crate::datastore::{IntoValue, Key, KeyID, Value};
+use crate::error::ConvertError;
+use crate::pubsub::PublishMessage;
+
+/// Represents the operation carried by a change event.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChangeOp {
+    /// Create the entity, or replace all of its properties.
+    Upsert(HashMap<String, Value>),
+    /// Delete the entity.
+    Delete,
+}
+
+/// Represents a change of a single Datastore entity.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChangeEvent {
+    pub(crate) key: Key,
+    pub(crate) version: i64,
+    pub(crate) op: ChangeOp,
+}
+
+impl ChangeEvent {
+    /// Create an event setting the properties of an entity.
+    ///
+    /// Fails if the properties are not an entity value.
+    pub fn upsert(
+        key: Key,
+        version: i64,
+        properties: impl IntoValue,
+    ) -> Result<ChangeEvent, ConvertError> {
+        match properties.into_value() {
+            Value::EntityValue(properties) => Ok(ChangeEvent {
+                key,
+                version,
+                op: ChangeOp::Upsert(properties),
+            }),
+            value => Err(ConvertError::UnexpectedPropertyType {
+                expected: String::from("entity"),
+                got: String::from(value.type_name()),
+            }),
+        }
+    }
+
+    /// Create an event deleting an entity.
+    pub fn delete(key: Key, version: i64) -> ChangeEvent {
+        ChangeEvent {
+            key,
+            version,
+            op: ChangeOp::Delete,
+        }
+    }
+
+    /// The key of the changed entity.
+    pub fn key(&self) -> &Key {
+        &self.key
+    }
+
+    /// The version of the entity after the change.
+    pub fn version(&self) -> i64 {
+        self.version
+    }
+
+    /// The operation to apply to the entity.
+    pub fn op(&self) -> &ChangeOp {
+        &self.op
+    }
+
+    /// The ordering key serializing the events of the changed entity.
+    ///
+    /// It is derived from the full path of the key, so it is the same for all the events of an entity.
+    pub fn ordering_key(&self) -> String {
+        let mut path = Vec::new();
+        let mut current = Some(&self.key);
+        while let Some(key) = current {
+            let id = match key.get_id() {
+                KeyID::IntID(id) => id.to_string(),
+                KeyID::StringID(name) => format!("'{}'", name),
+                KeyID::Incomplete => String::new(),
+            };
+            path.push(format!("{}:{}", key.get_kind(), id));
+            current = key.get_parent();
+        }
+        path.reverse();
+
+        match self.key.get_namespace().filter(|ns| !ns.is_empty()) {
+            Some(namespace) => format!("{}/{}", namespace, path.join("/")),
+            None => path.join("/"),
+        }
+    }
+
+    /// Decode an event from the JSON payload of a message.
+    ///
+    /// Only JSON-compatible values are supported: `null` properties and array elements are dropped,
+    /// and objects are decoded as entity values.
+    pub fn decode(data: &[u8]) -> Result<ChangeEvent, Error> {
+        let event: EventPayload = json::from_slice(data)?;
+        let key = event.key.into_key()?;
+        let op = match event.op {
+            OpPayload::Upsert => {
+                let properties = event
+                    .properties
+                    .into_iter()
+                    .filter_map(|(name, value)| Some((name, json_to_value(value)?)))
+                    .collect();
+                ChangeOp::Upsert(properties)
+            }
+            OpPayload::Delete => ChangeOp::Delete,
+        };
+
+        Ok(ChangeEvent {
+            key,
+            version: event.version,
+            op,
+        })
+    }
+
+    /// Encode the event as JSON.
+    ///
+    /// Fails for values without a JSON representation (timestamps, keys, blobs and geographic points)
+    /// and for keys with an incomplete ID.
+    pub fn encode(&self) -> Result<Vec<u8>, Error> {
+        let (op, properties) = match &self.op {
+            ChangeOp::Upsert(properties) => {
+                let properties = properties
+                    .iter()
+                    .map(|(name, value)| Ok((name.clone(), value_to_json(value)?)))
+                    .collect::<Result<_, ConvertError>>()?;
+                (OpPayload::Upsert, properties)
+            }
+            ChangeOp::Delete => (OpPayload::Delete, json::Map::new()),
+        };
+        let event = EventPayload {
+            op,
+            key: KeyPayload::from_key(&self.key)?,
+            version: self.version,
+            properties,
+        };
+
+        Ok(json::to_vec(&event)?)
+    }
+
+    /// Encode the event as a message ready to be published, with its entity's ordering key.
+    pub fn into_message(self) -> Result<PublishMessage, Error> {
+        let data = self.encode()?;
+        Ok(PublishMessage::new(data).ordering_key(self.ordering_key()))
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum OpPayload {
+    Upsert,
+    Delete,
+}
+
+#[derive(Serialize, Deserialize)]
+struct EventPayload {
+    op: OpPayload,
+    key: KeyPayload,
+    version: i64,
+    #[serde(default, skip_serializing_if = "json::Map::is_empty")]
+    properties: json::Map<String, json::Value>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct KeyPayload {
+    kind: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    id: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    namespace: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    parent: Option<Box<KeyPayload>>,
+}
+
+impl KeyPayload {
+    fn into_key(self) -> Result<Key, ConvertError> {
+        let id = match (self.id, self.name) {
+            (Some(id), None) => KeyID::IntID(id),
+            (None, Some(name)) => KeyID::StringID(name),
+            (None, None) => return Err(ConvertError::MissingProperty(String::from("key.id"))),
+            (Some(_), Some(_)) => {
+                return Err(ConvertError::UnexpectedPropertyType {
+                    expected: String::from("either an id or a name"),
+                    got: String::from("both"),
+                })
+            }
+        };
+        let mut key = Key::new(self.kind).id(id);
+        if let Some(parent) = self.parent {
+            key = key.parent(parent.into_key()?);
+        }
+        if let Some(namespace) = self.namespace {
+            key = key.namespace(namespace);
+        }
+
+        Ok(key)
+    }
+
+    fn from_key(key: &Key) -> Result<KeyPayload, ConvertError> {
+        let (id, name) = match key.get_id() {
+            KeyID::IntID(id) => (Some(*id), None),
+            KeyID::StringID(name) => (None, Some(name.clone())),
+            KeyID::Incomplete => return Err(ConvertError::MissingProperty(String::from("key.id"))),
+        };
+        let parent = match key.get_parent() {
+            Some(parent) => Some(Box::new(KeyPayload::from_key(parent)?)),
+            None => None,
+        };
+
+        Ok(KeyPayload {
+            kind: key.get_kind().to_string(),
+            id,
+            name,
+            namespace: key.get_namespace().map(String::from),
+            parent,
+        })
+    }
+}
+
+fn json_to_value(value: json::Value) -> Option<Value> {
+    let value = match value {
+        json::Value::Null => return None,
+        json::Value::Bool(value) => Value::BooleanValue(value),
+        json::Value::Number(number) => match number.as_i64() {
+            Some(value) => Value::IntegerValue(value),
+            None => Value::DoubleValue(number.as_f64()?),
+        },
+        json::Value::String(value) => Value::StringValue(value),
+        json::Value::Array(values) => {
+            Value::ArrayValue(values.into_iter().filter_map(json_to_value).collect())
+        }
+        json::Value::Object(properties) => Value::EntityValue(
+            properties
+                .into_iter()
+                .filter_map(|(name, value)| Some((name, json_to_value(value)?)))
+                .collect(),
+        ),
+    };
+
+    Some(value)
+}
+
+fn value_to_json(value: &Value) -> Result<json::Value, ConvertError> {
+    let value = match value {
+        Value::BooleanValue(value) => json::Value::Bool(*value),
+        Value::IntegerValue(value) => json::Value::from(*value),
+        Value::DoubleValue(value) => json::Number::from_f64(*value)
+            .map(json::Value::Number)
+            .ok_or_else(|| ConvertError::UnsupportedValue(String::from("non-finite double")))?,
+        Value::StringValue(value) => json::Value::String(value.clone()),
+        Value::IndexedValue(value, _) => value_to_json(value)?,
+        Value::ArrayValue(values) => {
+            json::Value::Array(values.iter().map(value_to_json).collect::<Result<_, _>>()?)
+        }
+        Value::EntityValue(properties) => json::Value::Object(
+            properties
+                .iter()
+                .map(|(name, value)| Ok((name.clone(), value_to_json(value)?)))
+                .collect::<Result<_, ConvertError>>()?,
+        ),
+        value => {
+            return Err(ConvertError::UnsupportedValue(
+                value.type_name().to_string(),
+            ))
+        }
+    };
+
+    Ok(value)
+}
@@ -0,0 +1,31 @@
+//! Each change event is a JSON object, describing the new state of a single entity:
+//!
+//! ```json
+//! {
+//!     "op": "upsert",
+//!     "key": { "kind": "User", "name": "alice", "parent": { "kind": "Team", "id": 42 } },
+//!     "version": 17,
+//!     "properties": { "email": "alice@example.com", "roles": ["admin"] }
+//! }
+//! ```
+//!
+//! Deletions use `"op": "delete"` and carry no properties.
+//! The `version` must increase with every change of an entity (such as a commit sequence number of the source database).
+//!
+//! The version of the last applied change is stored alongside the entity's properties,
+//! so that redelivered or outdated events get skipped, making their application idempotent.
+//! Events of a same entity are applied in order, one at a time, while distinct entities are processed concurrently.
+//! For Pub/Sub to deliver the events of an entity in order, they must be published with an ordering key
+//! (as done by `ChangeEvent::into_message`) on a subscription with message ordering enabled.
+
+//? The crate-wide `Error` type is large because of `tonic::Status`, but is still used here for consistency.
+#![allow(clippy::result_large_err)]
+
+mod event;
+mod replicator;
+
+pub use self::event::*;
+pub use self::replicator::*;
+
+/// The error type for the change-data-capture module.
+pub type Error = crate::error::Error;
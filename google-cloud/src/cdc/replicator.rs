@@ -0,0 +1,204 @@
+use std::collections::HashMap;
+
+use futures::future;
+
+use crate::cdc::{ChangeEvent, ChangeOp, Error};
+use crate::datastore::{self, Entity, Key, Value};
+use crate::pubsub::{Message, ReceiveOptions, Subscription};
+
+/// The counts of events handled while processing a batch.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BatchStats {
+    /// Events applied to Datastore.
+    pub applied: usize,
+    /// Events skipped because an equal or newer version of their entity was already applied.
+    pub skipped: usize,
+    /// Malformed events, acknowledged without being applied.
+    pub rejected: usize,
+    /// Events that could not be applied (or follow such an event for the same entity), left for redelivery.
+    pub failed: usize,
+}
+
+impl BatchStats {
+    fn merge(mut self, other: BatchStats) -> BatchStats {
+        self.applied += other.applied;
+        self.skipped += other.skipped;
+        self.rejected += other.rejected;
+        self.failed += other.failed;
+        self
+    }
+}
+
+/// Applies the change events of a Pub/Sub subscription to Datastore.
+///
+/// ```no_run
+/// # use google_cloud::{cdc, datastore, pubsub};
+/// # async fn run() -> Result<(), cdc::Error> {
+/// let mut pubsub = pubsub::Client::new("my-project").await?;
+/// let subscription = pubsub.subscription("entity-changes").await?.unwrap();
+/// let datastore = datastore::Client::new("my-project").await?;
+///
+/// let mut replicator = cdc::Replicator::new(subscription, datastore).batch_size(100);
+/// replicator.run().await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct Replicator {
+    pub(crate) subscription: Subscription,
+    pub(crate) datastore: datastore::Client,
+    pub(crate) version_property: String,
+    pub(crate) batch_size: i32,
+}
+
+impl Replicator {
+    /// The default name of the property storing the version of the last applied change.
+    pub const DEFAULT_VERSION_PROPERTY: &'static str = "_cdc_version";
+
+    /// Create a new replicator, tailing the given subscription.
+    pub fn new(subscription: Subscription, datastore: datastore::Client) -> Replicator {
+        Replicator {
+            subscription,
+            datastore,
+            version_property: String::from(Replicator::DEFAULT_VERSION_PROPERTY),
+            batch_size: 50,
+        }
+    }
+
+    /// Set the name of the property storing the version of the last applied change of each entity.
+    pub fn version_property(mut self, name: impl Into<String>) -> Replicator {
+        self.version_property = name.into();
+        self
+    }
+
+    /// Set the maximum number of messages pulled at once.
+    pub fn batch_size(mut self, batch_size: i32) -> Replicator {
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// Apply the change events continuously, until an error occurs.
+    pub async fn run(&mut self) -> Result<(), Error> {
+        loop {
+            self.process_batch().await?;
+        }
+    }
+
+    /// Wait for the next messages of the subscription, and apply their change events.
+    ///
+    /// Fails if a message could not be acknowledged,
+    /// in which case its event is redelivered and skipped then.
+    pub async fn process_batch(&mut self) -> Result<BatchStats, Error> {
+        let opts = ReceiveOptions {
+            return_immediately: false,
+            max_messages: self.batch_size,
+        };
+        let mut messages = Vec::new();
+        messages.extend(self.subscription.receive_with_options(opts).await);
+        while !self.subscription.buffer.is_empty() {
+            messages.extend(self.subscription.receive().await);
+        }
+
+        let mut stats = BatchStats::default();
+        let mut groups: Vec<Vec<(Message, ChangeEvent)>> = Vec::new();
+        let mut group_indices: HashMap<Key, usize> = HashMap::new();
+        for mut message in messages {
+            let event = match ChangeEvent::decode(message.data()) {
+                Ok(event) => event,
+                Err(_) => {
+                    message.ack().await?;
+                    stats.rejected += 1;
+                    continue;
+                }
+            };
+            let idx = *group_indices.entry(event.key.clone()).or_insert_with(|| {
+                groups.push(Vec::new());
+                groups.len() - 1
+            });
+            groups[idx].push((message, event));
+        }
+
+        let results = future::join_all(groups.into_iter().map(|group| {
+            let datastore = self.datastore.clone();
+            apply_group(datastore, self.version_property.as_str(), group)
+        }))
+        .await;
+        for result in results {
+            stats = stats.merge(result?);
+        }
+
+        Ok(stats)
+    }
+}
+
+/// Applies the events of a single entity, in order,
+/// stopping at the first failure to preserve the ordering of the remaining ones.
+async fn apply_group(
+    mut datastore: datastore::Client,
+    version_property: &str,
+    group: Vec<(Message, ChangeEvent)>,
+) -> Result<BatchStats, Error> {
+    let mut stats = BatchStats::default();
+    let mut failed = false;
+    for (mut message, event) in group {
+        if failed {
+            message.nack().await?;
+            stats.failed += 1;
+            continue;
+        }
+        match apply_event(&mut datastore, version_property, event).await {
+            Ok(true) => stats.applied += 1,
+            Ok(false) => stats.skipped += 1,
+            Err(_) => {
+                message.nack().await?;
+                stats.failed += 1;
+                failed = true;
+                continue;
+            }
+        }
+        message.ack().await?;
+    }
+
+    Ok(stats)
+}
+
+/// Applies an event, returning whether it was applied or skipped (for being outdated).
+async fn apply_event(
+    datastore: &mut datastore::Client,
+    version_property: &str,
+    event: ChangeEvent,
+) -> Result<bool, Error> {
+    let stored: Option<Value> = datastore.get(&event.key).await?;
+    if is_outdated(stored.as_ref(), version_property, event.version) {
+        return Ok(false);
+    }
+
+    match event.op {
+        ChangeOp::Upsert(mut properties) => {
+            properties.insert(
+                version_property.to_string(),
+                Value::IntegerValue(event.version),
+            );
+            let entity = Entity::new(event.key, Value::EntityValue(properties))?;
+            datastore.put(entity).await?;
+        }
+        ChangeOp::Delete if stored.is_some() => datastore.delete(&event.key).await?,
+        ChangeOp::Delete => return Ok(false),
+    }
+
+    Ok(true)
+}
+
+/// Whether the stored entity already reflects the given version (or a newer one).
+pub(crate) fn is_outdated(stored: Option<&Value>, version_property: &str, version: i64) -> bool {
+    let stored_version = match stored {
+        Some(Value::EntityValue(properties)) => properties.get(version_property),
+        _ => None,
+    };
+    let stored_version = match stored_version {
+        Some(Value::IndexedValue(value, _)) => value.as_ref(),
+        Some(value) => value,
+        None => return false,
+    };
+
+    matches!(stored_version, Value::IntegerValue(stored) if *stored >= version)
+}
@@ -0,0 +1,220 @@
+use std::collections::HashSet;
+use std::net::SocketAddr;
+use std::time::Duration;
+
+use http::Uri;
+use tonic::transport::{Certificate, Channel, ClientTlsConfig, Endpoint};
+use tower::discover::Change;
+
+use crate::authorize::TLS_CERTS;
+use crate::error::Error;
+use crate::tasks;
+
+/// The strategy used to spread requests across the addresses an endpoint's hostname resolves to.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum LoadBalancing {
+    /// Use a single connection, to the first address that accepts it.
+    ///
+    /// This is the default.
+    #[default]
+    PickFirst,
+    /// Open a connection to every resolved address and spread requests across them,
+    /// sending each request to the least loaded of two randomly picked connections.
+    ///
+    /// The hostname is periodically resolved again, so that backends can be added or removed over time.
+    Balanced,
+}
+
+/// Represents the configuration of the gRPC channels used to reach the Google endpoints.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChannelConfig {
+    pub(crate) load_balancing: LoadBalancing,
+    pub(crate) refresh_interval: Duration,
+    pub(crate) timeout: Option<Duration>,
+    pub(crate) connect_timeout: Option<Duration>,
+}
+
+impl ChannelConfig {
+    /// Set the load balancing strategy.
+    pub fn load_balancing(mut self, load_balancing: LoadBalancing) -> ChannelConfig {
+        self.load_balancing = load_balancing;
+        self
+    }
+
+    /// Set how often the endpoint's hostname is resolved again, when using `LoadBalancing::Balanced`.
+    pub fn refresh_interval(mut self, interval: Duration) -> ChannelConfig {
+        self.refresh_interval = interval;
+        self
+    }
+
+    /// Fail the requests not answered within the given duration (with a `CANCELLED` status).
+    ///
+    /// Streaming requests (like Pub/Sub pulls) are bounded as a whole, so the timeout must exceed their lifetime.
+    pub fn timeout(mut self, timeout: Duration) -> ChannelConfig {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Fail to connect if no connection gets established within the given duration.
+    pub fn connect_timeout(mut self, timeout: Duration) -> ChannelConfig {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Applies the timeouts to an endpoint.
+    fn apply(&self, mut endpoint: Endpoint) -> Endpoint {
+        if let Some(timeout) = self.timeout {
+            endpoint = endpoint.timeout(timeout);
+        }
+        if let Some(timeout) = self.connect_timeout {
+            endpoint = endpoint.connect_timeout(timeout);
+        }
+        endpoint
+    }
+}
+
+impl Default for ChannelConfig {
+    fn default() -> ChannelConfig {
+        ChannelConfig {
+            load_balancing: LoadBalancing::default(),
+            refresh_interval: Duration::from_secs(300),
+            timeout: None,
+            connect_timeout: None,
+        }
+    }
+}
+
+/// The endpoint of a local emulator, given as a host (like `localhost:8081`), reached over plaintext.
+#[cfg(any(feature = "bigtable", feature = "datastore", feature = "pubsub"))]
+pub(crate) fn emulator_endpoint(host: &str) -> String {
+    if host.contains("://") {
+        String::from(host)
+    } else {
+        format!("http://{}", host)
+    }
+}
+
+/// Parses an endpoint URL, which must be either `https://` (secure) or `http://` (insecure, for emulators).
+#[allow(clippy::result_large_err)]
+pub(crate) fn parse_endpoint(endpoint: &str) -> Result<Uri, Error> {
+    let uri: Uri = endpoint
+        .parse()
+        .map_err(|err| Error::Endpoint(format!("`{}` is not a valid URL ({})", endpoint, err)))?;
+    match uri.scheme_str() {
+        Some("https") | Some("http") if uri.host().is_some() => Ok(uri),
+        Some("https") | Some("http") => Err(Error::Endpoint(format!("`{}` has no host", endpoint))),
+        Some(scheme) => Err(Error::Endpoint(format!(
+            "`{}` uses the unsupported `{}` scheme (expected `https` or `http`)",
+            endpoint, scheme
+        ))),
+        //? Emulator hosts are commonly given without a scheme (like `localhost:8081`).
+        None => Err(Error::Endpoint(format!(
+            "`{}` has no scheme (use `http://{}` for an insecure connection to an emulator)",
+            endpoint, endpoint
+        ))),
+    }
+}
+
+/// Connects to the given endpoint, using TLS (with the given domain name) for `https://` endpoints.
+///
+/// Plain `http://` endpoints (such as local emulators) are reached without any TLS setup.
+pub(crate) async fn connect(
+    endpoint: impl Into<String>,
+    domain_name: &str,
+    config: &ChannelConfig,
+) -> Result<Channel, Error> {
+    let uri = parse_endpoint(endpoint.into().as_str())?;
+    let tls_config = if uri.scheme_str() == Some("https") {
+        let tls_config = ClientTlsConfig::new()
+            .ca_certificate(Certificate::from_pem(TLS_CERTS))
+            .domain_name(domain_name);
+        Some(tls_config)
+    } else {
+        None
+    };
+
+    let channel = match config.load_balancing {
+        LoadBalancing::PickFirst => {
+            let mut channel = config.apply(Channel::builder(uri));
+            if let Some(tls_config) = tls_config {
+                channel = channel.tls_config(tls_config)?;
+            }
+            channel.connect().await?
+        }
+        LoadBalancing::Balanced => {
+            let addrs = resolve(&uri).await?;
+            let (channel, sender) = Channel::balance_channel(16);
+            for addr in addrs.iter() {
+                let endpoint = address_endpoint(&uri, *addr, tls_config.clone())?;
+                let endpoint = config.apply(endpoint);
+                let _ = sender.send(Change::Insert(*addr, endpoint)).await;
+            }
+
+            let interval = config.refresh_interval;
+            let config = config.clone();
+            tasks::spawn(tasks::CHANNEL_RESOLVER, async move {
+                let mut known = addrs;
+                loop {
+                    tokio::time::sleep(interval).await;
+                    //? The channel has been dropped, stop refreshing.
+                    if sender.is_closed() {
+                        break;
+                    }
+                    let addrs = match resolve(&uri).await {
+                        Ok(addrs) if !addrs.is_empty() => addrs,
+                        _ => continue,
+                    };
+                    for addr in addrs.difference(&known) {
+                        let endpoint = match address_endpoint(&uri, *addr, tls_config.clone()) {
+                            Ok(endpoint) => config.apply(endpoint),
+                            Err(_) => continue,
+                        };
+                        if sender.send(Change::Insert(*addr, endpoint)).await.is_err() {
+                            return;
+                        }
+                    }
+                    for addr in known.difference(&addrs) {
+                        if sender.send(Change::Remove(*addr)).await.is_err() {
+                            return;
+                        }
+                    }
+                    known = addrs;
+                }
+            });
+
+            channel
+        }
+    };
+
+    Ok(channel)
+}
+
+/// Resolves all the socket addresses of the endpoint's host.
+async fn resolve(uri: &Uri) -> Result<HashSet<SocketAddr>, Error> {
+    let host = uri.host().unwrap_or_default();
+    let host = host.trim_start_matches('[').trim_end_matches(']');
+    let port = uri.port_u16().unwrap_or_else(|| match uri.scheme_str() {
+        Some("https") => 443,
+        _ => 80,
+    });
+    let addrs = tokio::net::lookup_host((host, port)).await?;
+
+    Ok(addrs.collect())
+}
+
+/// Creates an endpoint connecting to a specific address, while still presenting the original authority.
+fn address_endpoint(
+    uri: &Uri,
+    addr: SocketAddr,
+    tls_config: Option<ClientTlsConfig>,
+) -> Result<Endpoint, tonic::transport::Error> {
+    let scheme = uri.scheme_str().unwrap_or("http");
+    let mut endpoint = Channel::from_shared(format!("{}://{}", scheme, addr))
+        .unwrap()
+        .origin(uri.clone());
+    if let Some(tls_config) = tls_config {
+        endpoint = endpoint.tls_config(tls_config)?;
+    }
+
+    Ok(endpoint)
+}
@@ -0,0 +1,431 @@
+use std::collections::HashMap;
+
+use chrono::NaiveDateTime;
+
+use crate::cloudbuild::api;
+use crate::cloudbuild::api::repo_source::Revision;
+use crate::types;
+
+/// Represents the status of a build.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BuildStatus {
+    /// The status is unknown.
+    Unknown,
+    /// The build is waiting to be executed.
+    Queued,
+    /// The build is being executed.
+    Working,
+    /// The build finished successfully.
+    Success,
+    /// The build failed to complete successfully.
+    Failure,
+    /// The build failed due to an internal error.
+    InternalError,
+    /// The build took longer than its timeout.
+    Timeout,
+    /// The build was cancelled by a user.
+    Cancelled,
+}
+
+impl BuildStatus {
+    /// Whether the build has reached a final status, and will not change anymore.
+    pub fn is_finished(self) -> bool {
+        !matches!(
+            self,
+            BuildStatus::Unknown | BuildStatus::Queued | BuildStatus::Working
+        )
+    }
+}
+
+impl From<api::build::Status> for BuildStatus {
+    fn from(status: api::build::Status) -> BuildStatus {
+        match status {
+            api::build::Status::Unknown => BuildStatus::Unknown,
+            api::build::Status::Queued => BuildStatus::Queued,
+            api::build::Status::Working => BuildStatus::Working,
+            api::build::Status::Success => BuildStatus::Success,
+            api::build::Status::Failure => BuildStatus::Failure,
+            api::build::Status::InternalError => BuildStatus::InternalError,
+            api::build::Status::Timeout => BuildStatus::Timeout,
+            api::build::Status::Cancelled => BuildStatus::Cancelled,
+        }
+    }
+}
+
+/// Represents a build, either in progress or finished.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Build {
+    pub(crate) id: String,
+    pub(crate) project_id: String,
+    pub(crate) status: BuildStatus,
+    pub(crate) status_detail: String,
+    pub(crate) create_time: Option<NaiveDateTime>,
+    pub(crate) start_time: Option<NaiveDateTime>,
+    pub(crate) finish_time: Option<NaiveDateTime>,
+    pub(crate) logs_bucket: String,
+    pub(crate) log_url: String,
+    pub(crate) images: Vec<String>,
+    pub(crate) trigger_id: Option<String>,
+    pub(crate) tags: Vec<String>,
+}
+
+impl Build {
+    /// Get the build's unique identifier.
+    pub fn id(&self) -> &str {
+        self.id.as_str()
+    }
+
+    /// Get the identifier of the project running the build.
+    pub fn project_id(&self) -> &str {
+        self.project_id.as_str()
+    }
+
+    /// Get the build's status.
+    pub fn status(&self) -> BuildStatus {
+        self.status
+    }
+
+    /// Get the human-readable explanation of the build's status, if any.
+    pub fn status_detail(&self) -> &str {
+        self.status_detail.as_str()
+    }
+
+    /// Get the time at which the build was requested.
+    pub fn create_time(&self) -> Option<NaiveDateTime> {
+        self.create_time
+    }
+
+    /// Get the time at which the execution of the build started.
+    pub fn start_time(&self) -> Option<NaiveDateTime> {
+        self.start_time
+    }
+
+    /// Get the time at which the execution of the build finished.
+    pub fn finish_time(&self) -> Option<NaiveDateTime> {
+        self.finish_time
+    }
+
+    /// Get the Cloud Storage location of the build logs, of the form `gs://bucket/path`.
+    pub fn logs_bucket(&self) -> &str {
+        self.logs_bucket.as_str()
+    }
+
+    /// Get the URL of the build logs in the Google Cloud Console.
+    pub fn log_url(&self) -> &str {
+        self.log_url.as_str()
+    }
+
+    /// Get the images pushed to the registry upon successful completion of the build.
+    pub fn images(&self) -> &[String] {
+        self.images.as_slice()
+    }
+
+    /// Get the identifier of the trigger that started the build, if any.
+    pub fn trigger_id(&self) -> Option<&str> {
+        self.trigger_id.as_deref()
+    }
+
+    /// Get the tags annotating the build.
+    pub fn tags(&self) -> &[String] {
+        self.tags.as_slice()
+    }
+}
+
+impl From<api::Build> for Build {
+    fn from(build: api::Build) -> Build {
+        let status = api::build::Status::from_i32(build.status)
+            .map(BuildStatus::from)
+            .unwrap_or(BuildStatus::Unknown);
+
+        Build {
+            id: build.id,
+            project_id: build.project_id,
+            status,
+            status_detail: build.status_detail,
+            create_time: build.create_time.and_then(convert_timestamp),
+            start_time: build.start_time.and_then(convert_timestamp),
+            finish_time: build.finish_time.and_then(convert_timestamp),
+            logs_bucket: build.logs_bucket,
+            log_url: build.log_url,
+            images: build.images,
+            trigger_id: Some(build.build_trigger_id).filter(|id| !id.is_empty()),
+            tags: build.tags,
+        }
+    }
+}
+
+/// Represents a single step of a build, executed in a container.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BuildStep {
+    pub(crate) name: String,
+    pub(crate) args: Vec<String>,
+    pub(crate) env: Vec<String>,
+    pub(crate) dir: String,
+    pub(crate) entrypoint: String,
+    pub(crate) id: String,
+    pub(crate) wait_for: Vec<String>,
+}
+
+impl BuildStep {
+    /// Create a new step running the given container image (eg. `gcr.io/cloud-builders/docker`).
+    pub fn new(name: impl Into<String>) -> BuildStep {
+        BuildStep {
+            name: name.into(),
+            args: Vec::new(),
+            env: Vec::new(),
+            dir: String::new(),
+            entrypoint: String::new(),
+            id: String::new(),
+            wait_for: Vec::new(),
+        }
+    }
+
+    /// Append an argument to pass to the container.
+    pub fn arg(mut self, arg: impl Into<String>) -> BuildStep {
+        self.args.push(arg.into());
+        self
+    }
+
+    /// Append multiple arguments to pass to the container.
+    pub fn args<I, S>(mut self, args: I) -> BuildStep
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.args.extend(args.into_iter().map(Into::into));
+        self
+    }
+
+    /// Set an environment variable for the step.
+    pub fn env(mut self, key: &str, value: &str) -> BuildStep {
+        self.env.push(format!("{}={}", key, value));
+        self
+    }
+
+    /// Set the working directory of the step, relative to the source root.
+    pub fn dir(mut self, dir: impl Into<String>) -> BuildStep {
+        self.dir = dir.into();
+        self
+    }
+
+    /// Override the entrypoint of the container image.
+    pub fn entrypoint(mut self, entrypoint: impl Into<String>) -> BuildStep {
+        self.entrypoint = entrypoint.into();
+        self
+    }
+
+    /// Set the identifier of the step, which other steps can refer to in `wait_for`.
+    pub fn id(mut self, id: impl Into<String>) -> BuildStep {
+        self.id = id.into();
+        self
+    }
+
+    /// Only start this step once the step with the given identifier has completed.
+    ///
+    /// An identifier of `-` makes the step start immediately, when the build starts.
+    pub fn wait_for(mut self, id: impl Into<String>) -> BuildStep {
+        self.wait_for.push(id.into());
+        self
+    }
+}
+
+impl From<BuildStep> for api::BuildStep {
+    fn from(step: BuildStep) -> api::BuildStep {
+        api::BuildStep {
+            name: step.name,
+            args: step.args,
+            env: step.env,
+            dir: step.dir,
+            entrypoint: step.entrypoint,
+            id: step.id,
+            wait_for: step.wait_for,
+            ..api::BuildStep::default()
+        }
+    }
+}
+
+/// Represents a revision of a Cloud Source Repository.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RepoSource {
+    pub(crate) project_id: String,
+    pub(crate) repo_name: String,
+    pub(crate) dir: String,
+    pub(crate) revision: Revision,
+}
+
+impl RepoSource {
+    fn new(repo_name: impl Into<String>, revision: Revision) -> RepoSource {
+        RepoSource {
+            project_id: String::new(),
+            repo_name: repo_name.into(),
+            dir: String::new(),
+            revision,
+        }
+    }
+
+    /// Refer to the head of a branch of the given repository.
+    pub fn branch(repo_name: impl Into<String>, branch: impl Into<String>) -> RepoSource {
+        RepoSource::new(repo_name, Revision::BranchName(branch.into()))
+    }
+
+    /// Refer to a tag of the given repository.
+    pub fn tag(repo_name: impl Into<String>, tag: impl Into<String>) -> RepoSource {
+        RepoSource::new(repo_name, Revision::TagName(tag.into()))
+    }
+
+    /// Refer to a specific commit of the given repository.
+    pub fn commit(repo_name: impl Into<String>, sha: impl Into<String>) -> RepoSource {
+        RepoSource::new(repo_name, Revision::CommitSha(sha.into()))
+    }
+
+    /// Run the build from a directory of the repository, instead of its root.
+    pub fn dir(mut self, dir: impl Into<String>) -> RepoSource {
+        self.dir = dir.into();
+        self
+    }
+
+    /// Set the project owning the repository, if it differs from the one running the build.
+    pub fn project_id(mut self, project_id: impl Into<String>) -> RepoSource {
+        self.project_id = project_id.into();
+        self
+    }
+}
+
+impl From<RepoSource> for api::RepoSource {
+    fn from(source: RepoSource) -> api::RepoSource {
+        api::RepoSource {
+            project_id: source.project_id,
+            repo_name: source.repo_name,
+            dir: source.dir,
+            revision: Some(source.revision),
+        }
+    }
+}
+
+/// Represents the location of the source files to build.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BuildSource {
+    /// A gzipped archive stored in Cloud Storage.
+    Storage {
+        /// The bucket containing the archive.
+        bucket: String,
+        /// The name of the archive object.
+        object: String,
+    },
+    /// A revision of a Cloud Source Repository.
+    Repo(RepoSource),
+}
+
+impl From<BuildSource> for api::Source {
+    fn from(source: BuildSource) -> api::Source {
+        let source = match source {
+            BuildSource::Storage { bucket, object } => {
+                api::source::Source::StorageSource(api::StorageSource {
+                    bucket,
+                    object,
+                    generation: 0,
+                })
+            }
+            BuildSource::Repo(source) => api::source::Source::RepoSource(source.into()),
+        };
+
+        api::Source {
+            source: Some(source),
+        }
+    }
+}
+
+/// Represents the configuration of a new build.
+///
+/// ```
+/// # use google_cloud::cloudbuild::{BuildConfig, BuildStep};
+/// let config = BuildConfig::new()
+///     .step(BuildStep::new("gcr.io/cloud-builders/docker").args(vec![
+///         "build",
+///         "-t",
+///         "gcr.io/my-project/my-image",
+///         ".",
+///     ]))
+///     .image("gcr.io/my-project/my-image")
+///     .timeout(chrono::Duration::minutes(20));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BuildConfig {
+    pub(crate) steps: Vec<BuildStep>,
+    pub(crate) images: Vec<String>,
+    pub(crate) timeout: Option<chrono::Duration>,
+    pub(crate) substitutions: HashMap<String, String>,
+    pub(crate) tags: Vec<String>,
+    pub(crate) logs_bucket: Option<String>,
+    pub(crate) source: Option<BuildSource>,
+}
+
+impl BuildConfig {
+    /// Create a new, empty, build configuration.
+    pub fn new() -> BuildConfig {
+        BuildConfig::default()
+    }
+
+    /// Append a step to the build.
+    pub fn step(mut self, step: BuildStep) -> BuildConfig {
+        self.steps.push(step);
+        self
+    }
+
+    /// Push an image to the registry once all the steps have completed successfully.
+    pub fn image(mut self, image: impl Into<String>) -> BuildConfig {
+        self.images.push(image.into());
+        self
+    }
+
+    /// Set the maximum duration of the build, after which it fails with a `Timeout` status.
+    pub fn timeout(mut self, timeout: chrono::Duration) -> BuildConfig {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Define a substitution, replacing `$KEY` in the steps of the build.
+    ///
+    /// User-defined substitution keys must start with an underscore (eg. `_TARGET`).
+    pub fn substitution(mut self, key: impl Into<String>, value: impl Into<String>) -> BuildConfig {
+        self.substitutions.insert(key.into(), value.into());
+        self
+    }
+
+    /// Annotate the build with a tag.
+    pub fn tag(mut self, tag: impl Into<String>) -> BuildConfig {
+        self.tags.push(tag.into());
+        self
+    }
+
+    /// Store the build logs in the given Cloud Storage location (eg. `gs://bucket/path`).
+    pub fn logs_bucket(mut self, logs_bucket: impl Into<String>) -> BuildConfig {
+        self.logs_bucket = Some(logs_bucket.into());
+        self
+    }
+
+    /// Set the location of the source files to build.
+    pub fn source(mut self, source: BuildSource) -> BuildConfig {
+        self.source = Some(source);
+        self
+    }
+}
+
+impl From<BuildConfig> for api::Build {
+    fn from(config: BuildConfig) -> api::Build {
+        api::Build {
+            steps: config.steps.into_iter().map(From::from).collect(),
+            images: config.images,
+            timeout: config.timeout.map(types::chrono_to_duration),
+            substitutions: config.substitutions,
+            tags: config.tags,
+            logs_bucket: config.logs_bucket.unwrap_or_default(),
+            source: config.source.map(From::from),
+            ..api::Build::default()
+        }
+    }
+}
+
+fn convert_timestamp(timestamp: prost_types::Timestamp) -> Option<NaiveDateTime> {
+    types::timestamp_to_naive(timestamp).ok()
+}
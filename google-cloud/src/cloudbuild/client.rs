@@ -0,0 +1,305 @@
+use std::env;
+use std::fs::File;
+use std::sync::Arc;
+use std::time::Duration;
+
+use bytes::Bytes;
+use futures::stream::{self, Stream};
+use prost::Message;
+use reqwest::StatusCode;
+use tokio::sync::Mutex;
+use tonic::transport::Channel;
+use tonic::{Code, IntoRequest, Request};
+
+use crate::authorize::{ApplicationCredentials, TokenManager};
+use crate::cancel::{self, CancellationToken};
+use crate::channel::{self, ChannelConfig};
+use crate::cloudbuild::api;
+use crate::cloudbuild::api::cloud_build_client::CloudBuildClient;
+use crate::cloudbuild::api::longrunning;
+use crate::cloudbuild::{Build, BuildConfig, Error, LogLocation, RepoSource};
+use crate::error::ConvertError;
+
+/// The Cloud Build client, tied to a specific project.
+#[derive(Clone)]
+pub struct Client {
+    pub(crate) project_name: String,
+    pub(crate) service: CloudBuildClient<Channel>,
+    pub(crate) http: Arc<reqwest::Client>,
+    pub(crate) token_manager: Arc<Mutex<TokenManager>>,
+    pub(crate) cancel: Option<CancellationToken>,
+}
+
+impl Client {
+    pub(crate) const DOMAIN_NAME: &'static str = "cloudbuild.googleapis.com";
+    pub(crate) const ENDPOINT: &'static str = "https://cloudbuild.googleapis.com";
+    pub(crate) const STORAGE_ENDPOINT: &'static str = "https://storage.googleapis.com/storage/v1";
+    pub(crate) const SCOPES: [&'static str; 1] = ["https://www.googleapis.com/auth/cloud-platform"];
+
+    //? Bounds of the delay between two checks of a running build.
+    const MIN_POLL_DELAY: Duration = Duration::from_secs(1);
+    const MAX_POLL_DELAY: Duration = Duration::from_secs(10);
+
+    pub(crate) async fn construct_request<T: IntoRequest<T>>(
+        &mut self,
+        request: T,
+    ) -> Result<Request<T>, Error> {
+        let mut request = request.into_request();
+        let token = self.token_manager.lock().await.token().await?;
+        let metadata = request.metadata_mut();
+        metadata.insert("authorization", token.parse().unwrap());
+        Ok(request)
+    }
+
+    /// Create a new client for the specified project.
+    ///
+    /// Credentials are looked up in the `GOOGLE_APPLICATION_CREDENTIALS` environment variable.
+    pub async fn new(project_name: impl Into<String>) -> Result<Client, Error> {
+        let path = env::var("GOOGLE_APPLICATION_CREDENTIALS")?;
+        let file = File::open(path)?;
+        let creds = json::from_reader(file)?;
+
+        Client::from_credentials(project_name, creds).await
+    }
+
+    /// Create a new client for the specified project with custom credentials.
+    pub async fn from_credentials(
+        project_name: impl Into<String>,
+        creds: ApplicationCredentials,
+    ) -> Result<Client, Error> {
+        Client::from_credentials_with_config(project_name, creds, ChannelConfig::default()).await
+    }
+
+    /// Create a new client for the specified project with custom credentials and channel configuration.
+    pub async fn from_credentials_with_config(
+        project_name: impl Into<String>,
+        creds: ApplicationCredentials,
+        config: ChannelConfig,
+    ) -> Result<Client, Error> {
+        let channel = channel::connect(Client::ENDPOINT, Client::DOMAIN_NAME, &config).await?;
+        let http = reqwest::Client::builder().build()?;
+
+        Ok(Client {
+            project_name: project_name.into(),
+            service: CloudBuildClient::new(channel),
+            http: Arc::new(http),
+            token_manager: Arc::new(Mutex::new(TokenManager::new(
+                creds,
+                Client::SCOPES.as_ref(),
+            ))),
+            cancel: None,
+        })
+    }
+
+    /// Stop waiting for builds, and following their logs, once the given token gets cancelled.
+    ///
+    /// Waiting then fails with a `CANCELLED` status, while log streams just end.
+    /// The builds themselves keep running (see `Client::cancel_build`).
+    pub fn cancel_on(mut self, token: CancellationToken) -> Client {
+        self.cancel = Some(token);
+        self
+    }
+
+    /// Start a new build, without waiting for its completion.
+    ///
+    /// Use `wait_build` to wait for the build to finish, or `build_logs` to follow its output.
+    pub async fn create_build(&mut self, config: BuildConfig) -> Result<Build, Error> {
+        let request = api::CreateBuildRequest {
+            project_id: self.project_name.clone(),
+            build: Some(config.into()),
+        };
+        let request = self.construct_request(request).await?;
+        let response = self.service.create_build(request).await?;
+
+        build_from_operation(response.into_inner())
+    }
+
+    /// Run a build trigger against the given source revision, without waiting for its completion.
+    pub async fn run_trigger(
+        &mut self,
+        trigger_id: &str,
+        source: RepoSource,
+    ) -> Result<Build, Error> {
+        let request = api::RunBuildTriggerRequest {
+            project_id: self.project_name.clone(),
+            trigger_id: trigger_id.to_string(),
+            source: Some(source.into()),
+        };
+        let request = self.construct_request(request).await?;
+        let response = self.service.run_build_trigger(request).await?;
+
+        build_from_operation(response.into_inner())
+    }
+
+    /// Get a specific build.
+    pub async fn build(&mut self, id: &str) -> Result<Option<Build>, Error> {
+        let request = api::GetBuildRequest {
+            project_id: self.project_name.clone(),
+            id: id.to_string(),
+        };
+        let request = self.construct_request(request).await?;
+        let build = match self.service.get_build(request).await {
+            Ok(response) => response.into_inner(),
+            Err(status) if status.code() == Code::NotFound => return Ok(None),
+            Err(status) => return Err(status.into()),
+        };
+
+        Ok(Some(Build::from(build)))
+    }
+
+    /// List the builds of the project, most recent first.
+    ///
+    /// The builds can be narrowed down using a filter expression (eg. `status="WORKING"`).
+    pub async fn builds(&mut self, filter: Option<&str>) -> Result<Vec<Build>, Error> {
+        let mut builds = Vec::new();
+        let page_size = 25;
+        let mut page_token = String::default();
+
+        loop {
+            let request = api::ListBuildsRequest {
+                project_id: self.project_name.clone(),
+                page_size,
+                page_token,
+                filter: filter.unwrap_or_default().to_string(),
+            };
+            let request = self.construct_request(request).await?;
+            let response = self.service.list_builds(request).await?;
+            let response = response.into_inner();
+            page_token = response.next_page_token;
+            builds.extend(response.builds.into_iter().map(Build::from));
+            if page_token.is_empty() {
+                break;
+            }
+        }
+
+        Ok(builds)
+    }
+
+    /// Cancel a build in progress.
+    pub async fn cancel_build(&mut self, id: &str) -> Result<Build, Error> {
+        let request = api::CancelBuildRequest {
+            project_id: self.project_name.clone(),
+            id: id.to_string(),
+        };
+        let request = self.construct_request(request).await?;
+        let response = self.service.cancel_build(request).await?;
+        let build = response.into_inner();
+
+        Ok(Build::from(build))
+    }
+
+    /// Wait for a build to finish, and return its final state.
+    pub async fn wait_build(&mut self, id: &str) -> Result<Build, Error> {
+        let token = self.cancel.clone();
+        let mut delay = Client::MIN_POLL_DELAY;
+        let poll = async move {
+            loop {
+                let build = self.fetch_build(id).await?;
+                if build.status().is_finished() {
+                    return Ok(build);
+                }
+                tokio::time::sleep(delay).await;
+                delay = Client::MAX_POLL_DELAY.min(delay * 2);
+            }
+        };
+        cancel::cancellable(token.as_ref(), poll).await
+    }
+
+    /// Follow the logs of a build, as they get written to its logs bucket.
+    ///
+    /// The stream yields the new log contents as they appear, and ends once the build has finished.
+    /// Builds logging to Cloud Logging only (without a logs bucket) cannot be followed.
+    pub fn build_logs(
+        &self,
+        build: &Build,
+    ) -> Result<impl Stream<Item = Result<Bytes, Error>>, Error> {
+        let location = LogLocation::from_build(build)
+            .ok_or_else(|| ConvertError::MissingField("logs_bucket".to_string()))?;
+        let state = LogState {
+            client: self.clone(),
+            build_id: build.id.clone(),
+            uri: location.uri(),
+            offset: 0,
+            finished: build.status().is_finished(),
+            delay: Client::MIN_POLL_DELAY,
+        };
+
+        let logs = stream::try_unfold(state, |mut state| async move {
+            loop {
+                let chunk = state.client.fetch_logs(&state.uri, state.offset).await?;
+                if !chunk.is_empty() {
+                    state.offset += chunk.len();
+                    state.delay = Client::MIN_POLL_DELAY;
+                    return Ok(Some((chunk, state)));
+                }
+                if state.finished {
+                    return Ok(None);
+                }
+                tokio::time::sleep(state.delay).await;
+                state.delay = Client::MAX_POLL_DELAY.min(state.delay * 2);
+                //? Logs can still be flushed after the build has finished, so drain them one last time.
+                let build = state.client.fetch_build(&state.build_id).await?;
+                state.finished = build.status().is_finished();
+            }
+        });
+
+        Ok(cancel::until_cancelled(logs, self.cancel.clone()))
+    }
+
+    async fn fetch_build(&mut self, id: &str) -> Result<Build, Error> {
+        let request = api::GetBuildRequest {
+            project_id: self.project_name.clone(),
+            id: id.to_string(),
+        };
+        let request = self.construct_request(request).await?;
+        let response = self.service.get_build(request).await?;
+
+        Ok(Build::from(response.into_inner()))
+    }
+
+    /// Downloads the log contents past the given offset, if any.
+    async fn fetch_logs(&mut self, uri: &str, offset: usize) -> Result<Bytes, Error> {
+        let token = self.token_manager.lock().await.token().await?;
+        let request = self
+            .http
+            .get(uri)
+            .header("authorization", token)
+            .header("range", format!("bytes={}-", offset))
+            .send();
+        let response = request.await?;
+        match response.status() {
+            StatusCode::NOT_FOUND | StatusCode::RANGE_NOT_SATISFIABLE => return Ok(Bytes::new()),
+            StatusCode::PARTIAL_CONTENT => return Ok(response.bytes().await?),
+            _ => {}
+        }
+        let bytes = response.error_for_status()?.bytes().await?;
+
+        Ok(bytes.slice(offset.min(bytes.len())..))
+    }
+}
+
+struct LogState {
+    client: Client,
+    build_id: String,
+    uri: String,
+    offset: usize,
+    finished: bool,
+    delay: Duration,
+}
+
+/// Extracts the build tracked by a build operation, from its metadata.
+fn build_from_operation(operation: longrunning::Operation) -> Result<Build, Error> {
+    let metadata = operation
+        .metadata
+        .ok_or_else(|| ConvertError::MissingField("metadata".to_string()))?;
+    let metadata =
+        api::BuildOperationMetadata::decode(metadata.value.as_slice()).map_err(|err| {
+            let message = format!("invalid operation metadata: {}", err);
+            Error::Status(tonic::Status::internal(message))
+        })?;
+    let build = metadata
+        .build
+        .ok_or_else(|| ConvertError::MissingField("build".to_string()))?;
+
+    Ok(Build::from(build))
+}
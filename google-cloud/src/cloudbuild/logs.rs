@@ -0,0 +1,49 @@
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+
+use crate::cloudbuild::{Build, Client};
+
+/// The Cloud Storage object receiving the logs of a build.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct LogLocation {
+    pub(crate) bucket: String,
+    pub(crate) object: String,
+}
+
+impl LogLocation {
+    /// Locates the logs of a build, returning `None` if the build has no logs bucket.
+    ///
+    /// The logs are written to `log-{id}.txt`, below the (optional) path of the logs bucket.
+    pub(crate) fn from_build(build: &Build) -> Option<LogLocation> {
+        let location = build.logs_bucket.as_str();
+        let location = location.strip_prefix("gs://").unwrap_or(location);
+        let location = location.trim_end_matches('/');
+        let (bucket, prefix) = match location.find('/') {
+            Some(idx) => (&location[..idx], &location[idx + 1..]),
+            None => (location, ""),
+        };
+        if bucket.is_empty() {
+            return None;
+        }
+
+        let object = if prefix.is_empty() {
+            format!("log-{}.txt", build.id)
+        } else {
+            format!("{}/log-{}.txt", prefix, build.id)
+        };
+
+        Some(LogLocation {
+            bucket: bucket.to_string(),
+            object,
+        })
+    }
+
+    /// The URI to download the contents of the log object from.
+    pub(crate) fn uri(&self) -> String {
+        format!(
+            "{}/b/{}/o/{}?alt=media",
+            Client::STORAGE_ENDPOINT,
+            utf8_percent_encode(&self.bucket, NON_ALPHANUMERIC),
+            utf8_percent_encode(&self.object, NON_ALPHANUMERIC),
+        )
+    }
+}
@@ -0,0 +1,40 @@
+//? `build_logs` and operation decoding return the module's `Error` type synchronously, which is large.
+#![allow(clippy::result_large_err)]
+
+mod build;
+mod client;
+mod logs;
+pub(crate) mod api {
+    #![allow(
+        dead_code,
+        clippy::enum_variant_names,
+        clippy::large_enum_variant,
+        clippy::doc_lazy_continuation,
+        clippy::doc_overindented_list_items
+    )]
+    pub mod rpc {
+        include!("api/google.rpc.rs");
+    }
+    #[allow(clippy::module_inception)]
+    pub mod api {
+        include!("api/google.api.rs");
+    }
+    pub mod longrunning {
+        include!("api/google.longrunning.rs");
+    }
+    pub mod devtools {
+        pub mod cloudbuild {
+            pub mod v1 {
+                include!("api/google.devtools.cloudbuild.v1.rs");
+            }
+        }
+    }
+    pub use self::devtools::cloudbuild::v1::*;
+}
+
+pub use self::build::*;
+pub use self::client::*;
+pub(crate) use self::logs::LogLocation;
+
+/// The error type for the Cloud Build module.
+pub type Error = crate::error::Error;
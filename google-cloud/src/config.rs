@@ -0,0 +1,371 @@
+//? The constructors return the crate-wide `Error` type synchronously, which is large because of `tonic::Status`.
+#![allow(clippy::result_large_err)]
+
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use serde::Deserialize;
+
+use crate::authorize::ApplicationCredentials;
+#[cfg(any(feature = "datastore", feature = "pubsub"))]
+use crate::authorize::TokenManager;
+#[cfg(any(feature = "datastore", feature = "pubsub"))]
+use crate::channel::{self, ChannelConfig};
+use crate::error::Error;
+
+/// Represents a set of configuration profiles, by name (such as `dev`, `staging` or `prod`).
+///
+/// Every top-level table of the TOML source is a profile:
+///
+/// ```
+/// # use google_cloud::config::Config;
+/// let config = Config::from_toml(r#"
+///     [dev]
+///     project = "my-project"
+///     emulator = true
+///     endpoints = { datastore = "localhost:8081", pubsub = "localhost:8085" }
+///
+///     [prod]
+///     project = "my-project"
+///     credentials = "/etc/gcp/service-account.json"
+///     timeout_ms = 30000
+///     retry = { max_attempts = 3 }
+/// "#)?;
+/// let profile = config.profile("dev").expect("no dev profile");
+/// assert_eq!(profile.project(), Some("my-project"));
+/// # Ok::<(), google_cloud::error::Error>(())
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(transparent)]
+pub struct Config {
+    pub(crate) profiles: HashMap<String, Profile>,
+}
+
+impl Config {
+    /// The environment variable naming the configuration file to load profiles from.
+    pub const FILE_VAR: &'static str = "GOOGLE_CLOUD_CONFIG";
+    /// The environment variable naming the active profile.
+    pub const PROFILE_VAR: &'static str = "GOOGLE_CLOUD_PROFILE";
+    /// The name of the profile used when none is selected.
+    pub const DEFAULT_PROFILE: &'static str = "default";
+
+    /// Parse profiles from a TOML document.
+    pub fn from_toml(source: &str) -> Result<Config, Error> {
+        toml::from_str(source).map_err(|err| Error::Config(err.to_string()))
+    }
+
+    /// Read profiles from a TOML file.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Config, Error> {
+        let source = fs::read_to_string(path)?;
+        Config::from_toml(&source)
+    }
+
+    /// Get a profile by name.
+    pub fn profile(&self, name: &str) -> Option<&Profile> {
+        self.profiles.get(name)
+    }
+
+    /// The names of the profiles, in no particular order.
+    pub fn profile_names(&self) -> impl Iterator<Item = &str> {
+        self.profiles.keys().map(String::as_str)
+    }
+}
+
+/// Represents how requests failing with transient errors get retried.
+///
+/// Only Datastore clients retry their requests (see `datastore::RetryConfig`).
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RetryProfile {
+    pub(crate) max_attempts: Option<usize>,
+    pub(crate) initial_backoff_ms: Option<u64>,
+    pub(crate) max_backoff_ms: Option<u64>,
+    pub(crate) multiplier: Option<f64>,
+}
+
+impl RetryProfile {
+    /// Keep the default retry settings.
+    pub fn new() -> RetryProfile {
+        RetryProfile::default()
+    }
+
+    /// Set the maximum number of attempts, the first one included.
+    pub fn max_attempts(mut self, attempts: usize) -> RetryProfile {
+        self.max_attempts = Some(attempts);
+        self
+    }
+
+    /// Set the delay before the first retry.
+    pub fn initial_backoff(mut self, backoff: Duration) -> RetryProfile {
+        self.initial_backoff_ms = Some(backoff.as_millis() as u64);
+        self
+    }
+
+    /// Set the maximum delay between two attempts.
+    pub fn max_backoff(mut self, backoff: Duration) -> RetryProfile {
+        self.max_backoff_ms = Some(backoff.as_millis() as u64);
+        self
+    }
+
+    /// Set the factor by which the delay grows after every retry.
+    pub fn multiplier(mut self, multiplier: f64) -> RetryProfile {
+        self.multiplier = Some(multiplier);
+        self
+    }
+
+    #[cfg(feature = "datastore")]
+    pub(crate) fn apply(
+        &self,
+        mut config: crate::datastore::RetryConfig,
+    ) -> crate::datastore::RetryConfig {
+        if let Some(attempts) = self.max_attempts {
+            config = config.max_attempts(attempts);
+        }
+        if let Some(backoff) = self.initial_backoff_ms {
+            config = config.initial_backoff(Duration::from_millis(backoff));
+        }
+        if let Some(backoff) = self.max_backoff_ms {
+            config = config.max_backoff(Duration::from_millis(backoff));
+        }
+        if let Some(multiplier) = self.multiplier {
+            config = config.multiplier(multiplier);
+        }
+        config
+    }
+}
+
+/// Represents the configuration of an environment, building clients configured consistently.
+///
+/// Profiles are either read from a `Config`, or loaded from the environment with `Profile::load`.
+///
+/// ```no_run
+/// # use google_cloud::config::Profile;
+/// # async fn run() -> Result<(), google_cloud::error::Error> {
+/// let profile = Profile::load()?;
+/// let datastore = profile.datastore().await?;
+/// let pubsub = profile.pubsub().await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Profile {
+    pub(crate) project: Option<String>,
+    pub(crate) credentials: Option<PathBuf>,
+    pub(crate) emulator: bool,
+    pub(crate) endpoints: HashMap<String, String>,
+    pub(crate) timeout_ms: Option<u64>,
+    pub(crate) connect_timeout_ms: Option<u64>,
+    pub(crate) retry: Option<RetryProfile>,
+    pub(crate) scopes: Vec<String>,
+}
+
+impl Profile {
+    /// Create an empty profile, relying on the defaults of every client.
+    pub fn new() -> Profile {
+        Profile::default()
+    }
+
+    /// Load the active profile.
+    ///
+    /// The profile named by `GOOGLE_CLOUD_PROFILE` (or `default`) is read from the file named by
+    /// `GOOGLE_CLOUD_CONFIG`, if set, and the environment variables then override its settings
+    /// (see `Profile::with_env`).
+    pub fn load() -> Result<Profile, Error> {
+        let profile = match env::var_os(Config::FILE_VAR) {
+            Some(path) => {
+                let name = env::var(Config::PROFILE_VAR)
+                    .unwrap_or_else(|_| Config::DEFAULT_PROFILE.to_string());
+                let config = Config::from_file(&path)?;
+                let profile = config.profile(&name).cloned();
+                profile.ok_or_else(|| {
+                    let path = Path::new(&path).display();
+                    Error::Config(format!("no profile named `{}` in {}", name, path))
+                })?
+            }
+            None => Profile::new(),
+        };
+
+        Ok(profile.with_env())
+    }
+
+    /// Override the settings with the standard environment variables, when set:
+    ///
+    /// - `GOOGLE_CLOUD_PROJECT` sets the project.
+    /// - `GOOGLE_APPLICATION_CREDENTIALS` sets the credentials file.
+    /// - `DATASTORE_EMULATOR_HOST` and `PUBSUB_EMULATOR_HOST` set the endpoints, and switch to emulators.
+    pub fn with_env(mut self) -> Profile {
+        if let Ok(project) = env::var("GOOGLE_CLOUD_PROJECT") {
+            self.project = Some(project);
+        }
+        if let Some(path) = env::var_os("GOOGLE_APPLICATION_CREDENTIALS") {
+            self.credentials = Some(PathBuf::from(path));
+        }
+        for (service, var) in [
+            ("datastore", "DATASTORE_EMULATOR_HOST"),
+            ("pubsub", "PUBSUB_EMULATOR_HOST"),
+        ] {
+            if let Ok(host) = env::var(var) {
+                self = self.endpoint(service, host).emulator(true);
+            }
+        }
+        self
+    }
+
+    /// The project the clients are tied to.
+    pub fn project(&self) -> Option<&str> {
+        self.project.as_deref()
+    }
+
+    /// Set the project the clients are tied to.
+    pub fn with_project(mut self, project: impl Into<String>) -> Profile {
+        self.project = Some(project.into());
+        self
+    }
+
+    /// Read the credentials from the given file, instead of `GOOGLE_APPLICATION_CREDENTIALS`.
+    pub fn credentials_file(mut self, path: impl Into<PathBuf>) -> Profile {
+        self.credentials = Some(path.into());
+        self
+    }
+
+    /// Connect to local emulators (at the configured endpoints), without credentials.
+    pub fn emulator(mut self, emulator: bool) -> Profile {
+        self.emulator = emulator;
+        self
+    }
+
+    /// Connect to the given endpoint for a service (such as `datastore` or `pubsub`).
+    ///
+    /// With emulators, the endpoint can be given as a host (like `localhost:8081`).
+    pub fn endpoint(mut self, service: impl Into<String>, endpoint: impl Into<String>) -> Profile {
+        self.endpoints.insert(service.into(), endpoint.into());
+        self
+    }
+
+    /// Fail the requests not answered within the given duration (see `ChannelConfig::timeout`).
+    pub fn timeout(mut self, timeout: Duration) -> Profile {
+        self.timeout_ms = Some(timeout.as_millis() as u64);
+        self
+    }
+
+    /// Fail to connect if no connection gets established within the given duration.
+    pub fn connect_timeout(mut self, timeout: Duration) -> Profile {
+        self.connect_timeout_ms = Some(timeout.as_millis() as u64);
+        self
+    }
+
+    /// Set how requests failing with transient errors get retried.
+    pub fn retry(mut self, retry: RetryProfile) -> Profile {
+        self.retry = Some(retry);
+        self
+    }
+
+    /// Request tokens for the given scope, instead of the default scopes of every client.
+    pub fn scope(mut self, scope: impl Into<String>) -> Profile {
+        self.scopes.push(scope.into());
+        self
+    }
+
+    /// The configuration of the channels of the clients.
+    #[cfg(any(feature = "datastore", feature = "pubsub"))]
+    pub fn channel_config(&self) -> ChannelConfig {
+        let mut config = ChannelConfig::default();
+        if let Some(timeout) = self.timeout_ms {
+            config = config.timeout(Duration::from_millis(timeout));
+        }
+        if let Some(timeout) = self.connect_timeout_ms {
+            config = config.connect_timeout(Duration::from_millis(timeout));
+        }
+        config
+    }
+
+    /// The credentials of the clients: placeholders with emulators, or the configured credentials file
+    /// (falling back to `GOOGLE_APPLICATION_CREDENTIALS`).
+    pub fn credentials(&self) -> Result<ApplicationCredentials, Error> {
+        if self.emulator {
+            return Ok(ApplicationCredentials::emulator(self.project_name()?));
+        }
+        let path = match self.credentials.as_ref() {
+            Some(path) => path.clone(),
+            None => PathBuf::from(env::var("GOOGLE_APPLICATION_CREDENTIALS")?),
+        };
+        let file = fs::File::open(path)?;
+
+        Ok(json::from_reader(file)?)
+    }
+
+    /// Create a Datastore client, configured by this profile.
+    #[cfg(feature = "datastore")]
+    pub async fn datastore(&self) -> Result<crate::datastore::Client, Error> {
+        use crate::datastore::{Client, RetryConfig};
+
+        let creds = self.credentials()?;
+        let endpoint = self.endpoint_of("datastore", Client::ENDPOINT)?;
+        let config = self.channel_config();
+        let mut client =
+            Client::from_endpoint(self.project_name()?, creds.clone(), endpoint, &config).await?;
+        if let Some(token_manager) = self.token_manager(&creds) {
+            client.token_manager = token_manager;
+        }
+        if let Some(retry) = self.retry.as_ref() {
+            client = client.retry(retry.apply(RetryConfig::default()));
+        }
+
+        Ok(client)
+    }
+
+    /// Create a Pub/Sub client, configured by this profile.
+    #[cfg(feature = "pubsub")]
+    pub async fn pubsub(&self) -> Result<crate::pubsub::Client, Error> {
+        use crate::pubsub::Client;
+
+        let creds = self.credentials()?;
+        let endpoint = self.endpoint_of("pubsub", Client::ENDPOINT)?;
+        let config = self.channel_config();
+        let mut client =
+            Client::from_endpoint(self.project_name()?, creds.clone(), endpoint, &config).await?;
+        if let Some(token_manager) = self.token_manager(&creds) {
+            client.token_manager = token_manager;
+        }
+
+        Ok(client)
+    }
+
+    fn project_name(&self) -> Result<String, Error> {
+        let project = self.project.clone();
+        project.ok_or_else(|| Error::Config(String::from("no project configured")))
+    }
+
+    /// The endpoint to connect to for the given service.
+    #[cfg(any(feature = "datastore", feature = "pubsub"))]
+    pub(crate) fn endpoint_of(&self, service: &str, default: &str) -> Result<String, Error> {
+        match (self.endpoints.get(service), self.emulator) {
+            (Some(host), true) => Ok(channel::emulator_endpoint(host)),
+            (Some(endpoint), false) => Ok(endpoint.clone()),
+            (None, false) => Ok(String::from(default)),
+            (None, true) => {
+                let message = format!("no emulator endpoint configured for {}", service);
+                Err(Error::Config(message))
+            }
+        }
+    }
+
+    //? The clients keep their own default scopes unless some are configured.
+    #[cfg(any(feature = "datastore", feature = "pubsub"))]
+    fn token_manager(
+        &self,
+        creds: &ApplicationCredentials,
+    ) -> Option<std::sync::Arc<tokio::sync::Mutex<TokenManager>>> {
+        if self.scopes.is_empty() {
+            return None;
+        }
+        let scopes: Vec<&str> = self.scopes.iter().map(String::as_str).collect();
+        let token_manager = TokenManager::new(creds.clone(), scopes.as_slice());
+
+        Some(std::sync::Arc::new(tokio::sync::Mutex::new(token_manager)))
+    }
+}
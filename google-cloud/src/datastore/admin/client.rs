@@ -0,0 +1,323 @@
+use std::env;
+use std::fs::File;
+use std::sync::Arc;
+use std::time::Duration;
+
+use prost::Message;
+use tokio::sync::Mutex;
+use tonic::transport::Channel;
+use tonic::{Code, IntoRequest, Request};
+
+use crate::authorize::{ApplicationCredentials, TokenManager};
+use crate::cancel::{self, CancellationToken};
+use crate::channel::{self, ChannelConfig};
+use crate::datastore::admin::api;
+use crate::datastore::admin::api::datastore_admin_client::DatastoreAdminClient;
+use crate::datastore::admin::{Error, ExportConfig, ImportConfig, Index, IndexConfig, Operation};
+use crate::datastore::api::longrunning;
+use crate::datastore::api::longrunning::operations_client::OperationsClient;
+use crate::error::{ConvertError, WithContext};
+
+/// The Datastore administration client, tied to a specific project.
+///
+/// It exports and imports entities, and manages the composite indexes.
+///
+/// ```no_run
+/// # use google_cloud::datastore::admin::{Client, Error, ExportConfig, ImportConfig};
+/// # async fn run() -> Result<(), Error> {
+/// let mut client = Client::new("my-project").await?;
+/// let url = client.export_entities(ExportConfig::new("gs://my-backups/datastore")).await?;
+/// client.import_entities(ImportConfig::new(url)).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct Client {
+    pub(crate) project_name: String,
+    pub(crate) service: DatastoreAdminClient<Channel>,
+    pub(crate) operations: OperationsClient<Channel>,
+    pub(crate) token_manager: Arc<Mutex<TokenManager>>,
+    pub(crate) cancel: Option<CancellationToken>,
+}
+
+struct ClientConfiguration {
+    pub endpoint: String,
+}
+
+impl ClientConfiguration {
+    pub fn new() -> ClientConfiguration {
+        ClientConfiguration {
+            endpoint: match env::var("DATASTORE_EMULATOR_HOST") {
+                Ok(host) => channel::emulator_endpoint(host.as_str()),
+                Err(_) => Client::ENDPOINT.to_string(),
+            },
+        }
+    }
+}
+
+impl Client {
+    pub(crate) const DOMAIN_NAME: &'static str = "datastore.googleapis.com";
+    pub(crate) const ENDPOINT: &'static str = "https://datastore.googleapis.com";
+    pub(crate) const SCOPES: [&'static str; 2] = [
+        "https://www.googleapis.com/auth/cloud-platform",
+        "https://www.googleapis.com/auth/datastore",
+    ];
+
+    //? Bounds of the delay between two checks of a long-running operation.
+    const MIN_POLL_DELAY: Duration = Duration::from_secs(1);
+    const MAX_POLL_DELAY: Duration = Duration::from_secs(30);
+
+    pub(crate) async fn construct_request<T: IntoRequest<T>>(
+        &mut self,
+        request: T,
+    ) -> Result<Request<T>, Error> {
+        let mut request = request.into_request();
+        let token = self.token_manager.lock().await.token().await?;
+        let metadata = request.metadata_mut();
+        metadata.insert("authorization", token.parse().unwrap());
+        Ok(request)
+    }
+
+    /// Creates a new client for the specified project.
+    ///
+    /// Credentials are looked up in the `GOOGLE_APPLICATION_CREDENTIALS` environment variable.
+    pub async fn new(project_name: impl Into<String>) -> Result<Client, Error> {
+        let path = env::var("GOOGLE_APPLICATION_CREDENTIALS")?;
+        let file = File::open(path)?;
+        let creds = json::from_reader(file)?;
+
+        Client::from_credentials(project_name, creds).await
+    }
+
+    /// Creates a new client for the specified project with custom credentials.
+    pub async fn from_credentials(
+        project_name: impl Into<String>,
+        creds: ApplicationCredentials,
+    ) -> Result<Client, Error> {
+        Client::from_credentials_with_config(project_name, creds, ChannelConfig::default()).await
+    }
+
+    /// Creates a new client for the specified project with custom credentials and channel configuration.
+    pub async fn from_credentials_with_config(
+        project_name: impl Into<String>,
+        creds: ApplicationCredentials,
+        config: ChannelConfig,
+    ) -> Result<Client, Error> {
+        let client_config = ClientConfiguration::new();
+        let channel =
+            channel::connect(client_config.endpoint, Client::DOMAIN_NAME, &config).await?;
+
+        Ok(Client {
+            project_name: project_name.into(),
+            service: DatastoreAdminClient::new(channel.clone()),
+            operations: OperationsClient::new(channel),
+            token_manager: Arc::new(Mutex::new(TokenManager::new(
+                creds,
+                Client::SCOPES.as_ref(),
+            ))),
+            cancel: None,
+        })
+    }
+
+    /// Stop waiting for long-running operations once the given token gets cancelled,
+    /// the waiting calls then failing with a `CANCELLED` status.
+    ///
+    /// The operations themselves keep running on the service.
+    pub fn cancel_on(mut self, token: CancellationToken) -> Client {
+        self.cancel = Some(token);
+        self
+    }
+
+    /// Start exporting entities to Cloud Storage, without waiting for the export to finish.
+    pub async fn start_export(&mut self, config: ExportConfig) -> Result<Operation, Error> {
+        let request = config.into_api(self.project_name.clone());
+        let request = self.construct_request(request).await?;
+        let response = self.service.export_entities(request).await;
+        let operation = response.context("ExportEntities", &self.project_name)?;
+
+        Ok(Operation::from(operation.into_inner()))
+    }
+
+    /// Start importing entities from a previous export, without waiting for the import to finish.
+    pub async fn start_import(&mut self, config: ImportConfig) -> Result<Operation, Error> {
+        let request = config.into_api(self.project_name.clone());
+        let request = self.construct_request(request).await?;
+        let response = self.service.import_entities(request).await;
+        let operation = response.context("ImportEntities", &self.project_name)?;
+
+        Ok(Operation::from(operation.into_inner()))
+    }
+
+    /// Get the current state of an operation, by name.
+    pub async fn operation(&mut self, name: &str) -> Result<Option<Operation>, Error> {
+        match self.get_operation(name).await {
+            Ok(operation) => Ok(Some(Operation::from(operation))),
+            Err(err) if err.status().map(tonic::Status::code) == Some(Code::NotFound) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Poll an operation until it is done.
+    ///
+    /// Fails with the status of the operation if it did not succeed.
+    pub async fn wait(&mut self, operation: Operation) -> Result<Operation, Error> {
+        let operation = self.wait_operation(operation.name).await?;
+        Ok(Operation::from(operation))
+    }
+
+    /// Export entities to Cloud Storage and wait for the export to finish.
+    ///
+    /// Returns the URL of the export's metadata file, to import it from.
+    pub async fn export_entities(&mut self, config: ExportConfig) -> Result<String, Error> {
+        let operation = self.start_export(config).await?;
+        let operation = self.wait(operation).await?;
+        let url = operation.output_url.filter(|url| !url.is_empty());
+
+        url.ok_or_else(|| ConvertError::MissingField("output_url".to_string()).into())
+    }
+
+    /// Import entities from a previous export and wait for the import to finish.
+    pub async fn import_entities(&mut self, config: ImportConfig) -> Result<(), Error> {
+        let operation = self.start_import(config).await?;
+        self.wait(operation).await?;
+
+        Ok(())
+    }
+
+    /// List the composite indexes of the project.
+    pub async fn indexes(&mut self) -> Result<Vec<Index>, Error> {
+        let mut indexes = Vec::new();
+        let page_size = 25;
+        let mut page_token = String::default();
+
+        loop {
+            let request = api::ListIndexesRequest {
+                project_id: self.project_name.clone(),
+                filter: String::new(),
+                page_size,
+                page_token,
+            };
+            let request = self.construct_request(request).await?;
+            let response = self.service.list_indexes(request).await;
+            let response = response
+                .context("ListIndexes", &self.project_name)?
+                .into_inner();
+            page_token = response.next_page_token;
+            indexes.extend(response.indexes.into_iter().map(Index::from));
+            if page_token.is_empty() {
+                break;
+            }
+        }
+
+        Ok(indexes)
+    }
+
+    /// Get a specific composite index.
+    pub async fn index(&mut self, id: &str) -> Result<Option<Index>, Error> {
+        let request = api::GetIndexRequest {
+            project_id: self.project_name.clone(),
+            index_id: id.to_string(),
+        };
+        let request = self.construct_request(request).await?;
+        let index = match self.service.get_index(request).await {
+            Ok(response) => response.into_inner(),
+            Err(status) if status.code() == Code::NotFound => return Ok(None),
+            Err(status) => return Err(status).context("GetIndex", id),
+        };
+
+        Ok(Some(Index::from(index)))
+    }
+
+    /// Start creating a composite index, without waiting for it to be built.
+    ///
+    /// Fails with an `ALREADY_EXISTS` status if the same index already exists.
+    pub async fn start_create_index(&mut self, config: IndexConfig) -> Result<Operation, Error> {
+        let request = api::CreateIndexRequest {
+            project_id: self.project_name.clone(),
+            index: Some(config.into_api(self.project_name.clone())),
+        };
+        let request = self.construct_request(request).await?;
+        let response = self.service.create_index(request).await;
+        let operation = response.context("CreateIndex", &self.project_name)?;
+
+        Ok(Operation::from(operation.into_inner()))
+    }
+
+    /// Create a composite index and wait for it to be built, which may take a while for kinds with many entities.
+    pub async fn create_index(&mut self, config: IndexConfig) -> Result<Index, Error> {
+        let operation = self.start_create_index(config).await?;
+        let operation = self.wait_operation(operation.name).await?;
+
+        match operation.result {
+            Some(longrunning::operation::Result::Response(response)) => {
+                let index = api::Index::decode(response.value.as_slice()).map_err(|err| {
+                    let message = format!("invalid operation response: {}", err);
+                    Error::Status(tonic::Status::internal(message))
+                })?;
+                Ok(Index::from(index))
+            }
+            _ => Err(ConvertError::MissingField("response".to_string()).into()),
+        }
+    }
+
+    /// Start deleting a composite index, without waiting for it to be removed.
+    ///
+    /// Only indexes in the `Ready` or `Error` states can be deleted.
+    pub async fn start_delete_index(&mut self, id: &str) -> Result<Operation, Error> {
+        let request = api::DeleteIndexRequest {
+            project_id: self.project_name.clone(),
+            index_id: id.to_string(),
+        };
+        let request = self.construct_request(request).await?;
+        let response = self.service.delete_index(request).await;
+        let operation = response.context("DeleteIndex", id)?;
+
+        Ok(Operation::from(operation.into_inner()))
+    }
+
+    /// Delete a composite index and wait for it to be removed.
+    pub async fn delete_index(&mut self, id: &str) -> Result<(), Error> {
+        let operation = self.start_delete_index(id).await?;
+        self.wait(operation).await?;
+
+        Ok(())
+    }
+
+    /// Polls a long-running operation until it is done, failing with its status if it did not succeed.
+    async fn wait_operation(&mut self, mut name: String) -> Result<longrunning::Operation, Error> {
+        let token = self.cancel.clone();
+        let mut delay = Client::MIN_POLL_DELAY;
+        let poll = async {
+            loop {
+                let operation = self.get_operation(name.as_str()).await?;
+                if operation.done {
+                    return Ok(operation);
+                }
+                name = operation.name;
+                tokio::time::sleep(delay).await;
+                delay = Client::MAX_POLL_DELAY.min(delay * 2);
+            }
+        };
+        let operation = cancel::cancellable(token.as_ref(), poll).await?;
+
+        match operation.result.as_ref() {
+            Some(longrunning::operation::Result::Error(status)) => {
+                let code = Code::from(status.code);
+                let status = tonic::Status::new(code, status.message.clone());
+                Err(Error::Status(status)).context("Operation", &operation.name)
+            }
+            Some(longrunning::operation::Result::Response(_)) => Ok(operation),
+            None => Err(ConvertError::MissingField("result".to_string()).into()),
+        }
+    }
+
+    async fn get_operation(&mut self, name: &str) -> Result<longrunning::Operation, Error> {
+        let request = longrunning::GetOperationRequest {
+            name: name.to_string(),
+        };
+        let request = self.construct_request(request).await?;
+        let response = self.operations.get_operation(request).await;
+
+        Ok(response.context("GetOperation", name)?.into_inner())
+    }
+}
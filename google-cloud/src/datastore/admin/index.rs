@@ -0,0 +1,191 @@
+use crate::datastore::admin::api;
+
+/// Represents the order in which a property is indexed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IndexDirection {
+    /// Supports ascending orders (and inequality filters).
+    Ascending,
+    /// Supports descending orders (and inequality filters).
+    Descending,
+}
+
+/// Represents the state of an index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IndexState {
+    /// The index is being built, and can't be used by queries yet.
+    Creating,
+    /// The index is built, and can be used by queries.
+    Ready,
+    /// The index is being deleted.
+    Deleting,
+    /// Building or deleting the index failed (it can be deleted and created again).
+    Error,
+    /// The state of the index is unknown.
+    Unknown,
+}
+
+/// Represents a property of a composite index.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IndexedProperty {
+    pub(crate) name: String,
+    pub(crate) direction: IndexDirection,
+}
+
+impl IndexedProperty {
+    /// The name of the indexed property.
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    /// The order in which the property is indexed.
+    pub fn direction(&self) -> IndexDirection {
+        self.direction
+    }
+}
+
+/// Represents the definition of a composite index to create.
+///
+/// Datastore indexes single properties on its own, only indexes of several properties
+/// (or of a property along with the ancestors of the entities) can be created.
+///
+/// ```
+/// # use google_cloud::datastore::admin::IndexConfig;
+/// let config = IndexConfig::new("Order")
+///     .ascending("customer")
+///     .descending("created_at");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IndexConfig {
+    pub(crate) kind: String,
+    pub(crate) ancestor: bool,
+    pub(crate) properties: Vec<IndexedProperty>,
+}
+
+impl IndexConfig {
+    /// Index the entities of the given kind.
+    pub fn new(kind: impl Into<String>) -> IndexConfig {
+        IndexConfig {
+            kind: kind.into(),
+            ancestor: false,
+            properties: Vec::new(),
+        }
+    }
+
+    /// Also index the ancestors of the entities, for ancestor queries.
+    pub fn ancestor(mut self) -> IndexConfig {
+        self.ancestor = true;
+        self
+    }
+
+    /// Index the given property in ascending order, after the previous ones.
+    pub fn ascending(mut self, name: impl Into<String>) -> IndexConfig {
+        self.properties.push(IndexedProperty {
+            name: name.into(),
+            direction: IndexDirection::Ascending,
+        });
+        self
+    }
+
+    /// Index the given property in descending order, after the previous ones.
+    pub fn descending(mut self, name: impl Into<String>) -> IndexConfig {
+        self.properties.push(IndexedProperty {
+            name: name.into(),
+            direction: IndexDirection::Descending,
+        });
+        self
+    }
+
+    pub(crate) fn into_api(self, project_id: String) -> api::Index {
+        let ancestor = if self.ancestor {
+            api::index::AncestorMode::AllAncestors
+        } else {
+            api::index::AncestorMode::None
+        };
+        let properties = self.properties.into_iter().map(|property| {
+            let direction = match property.direction {
+                IndexDirection::Ascending => api::index::Direction::Ascending,
+                IndexDirection::Descending => api::index::Direction::Descending,
+            };
+            api::index::IndexedProperty {
+                name: property.name,
+                direction: direction as i32,
+            }
+        });
+        api::Index {
+            project_id,
+            index_id: String::new(),
+            kind: self.kind,
+            ancestor: ancestor as i32,
+            properties: properties.collect(),
+            state: api::index::State::Unspecified as i32,
+        }
+    }
+}
+
+/// Represents a composite index.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Index {
+    pub(crate) id: String,
+    pub(crate) kind: String,
+    pub(crate) ancestor: bool,
+    pub(crate) properties: Vec<IndexedProperty>,
+    pub(crate) state: IndexState,
+}
+
+impl Index {
+    /// Returns the unique identifier of the index within its project.
+    pub fn id(&self) -> &str {
+        self.id.as_str()
+    }
+
+    /// The kind of the indexed entities.
+    pub fn kind(&self) -> &str {
+        self.kind.as_str()
+    }
+
+    /// Are the ancestors of the entities indexed ?
+    pub fn ancestor(&self) -> bool {
+        self.ancestor
+    }
+
+    /// The indexed properties, in order.
+    pub fn properties(&self) -> &[IndexedProperty] {
+        self.properties.as_slice()
+    }
+
+    /// The current state of the index.
+    pub fn state(&self) -> IndexState {
+        self.state
+    }
+}
+
+impl From<api::Index> for Index {
+    fn from(index: api::Index) -> Index {
+        let ancestor = api::index::AncestorMode::from_i32(index.ancestor);
+        let properties = index.properties.into_iter().map(|property| {
+            let direction = match api::index::Direction::from_i32(property.direction) {
+                Some(api::index::Direction::Descending) => IndexDirection::Descending,
+                _ => IndexDirection::Ascending,
+            };
+            IndexedProperty {
+                name: property.name,
+                direction,
+            }
+        });
+        let state = match api::index::State::from_i32(index.state) {
+            Some(api::index::State::Creating) => IndexState::Creating,
+            Some(api::index::State::Ready) => IndexState::Ready,
+            Some(api::index::State::Deleting) => IndexState::Deleting,
+            Some(api::index::State::Error) => IndexState::Error,
+            _ => IndexState::Unknown,
+        };
+
+        Index {
+            id: index.index_id,
+            kind: index.kind,
+            ancestor: ancestor == Some(api::index::AncestorMode::AllAncestors),
+            properties: properties.collect(),
+            state,
+        }
+    }
+}
@@ -0,0 +1,12 @@
+mod client;
+mod index;
+mod operation;
+
+use crate::datastore::api::datastore::admin::v1 as api;
+
+pub use self::client::*;
+pub use self::index::*;
+pub use self::operation::*;
+
+/// The error type for the Datastore administration module.
+pub type Error = crate::error::Error;
@@ -0,0 +1,410 @@
+use std::collections::HashMap;
+
+use chrono::NaiveDateTime;
+use prost::Message;
+
+use crate::datastore::admin::api;
+use crate::datastore::api::longrunning;
+use crate::types;
+
+/// Represents which entities an export or an import covers.
+///
+/// No kind (or namespace) means all of them.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct EntityFilter {
+    pub(crate) kinds: Vec<String>,
+    pub(crate) namespaces: Vec<String>,
+}
+
+impl EntityFilter {
+    /// Include the entities of the given kind.
+    pub fn kind(mut self, kind: impl Into<String>) -> EntityFilter {
+        self.kinds.push(kind.into());
+        self
+    }
+
+    /// Include the entities of the given namespace, an empty one referring to the default namespace.
+    pub fn namespace(mut self, namespace: impl Into<String>) -> EntityFilter {
+        self.namespaces.push(namespace.into());
+        self
+    }
+
+    /// The kinds of the included entities.
+    pub fn kinds(&self) -> &[String] {
+        self.kinds.as_slice()
+    }
+
+    /// The namespaces of the included entities.
+    pub fn namespaces(&self) -> &[String] {
+        self.namespaces.as_slice()
+    }
+
+    fn into_api(self) -> Option<api::EntityFilter> {
+        if self.kinds.is_empty() && self.namespaces.is_empty() {
+            return None;
+        }
+        Some(api::EntityFilter {
+            kinds: self.kinds,
+            namespace_ids: self.namespaces,
+        })
+    }
+}
+
+impl From<api::EntityFilter> for EntityFilter {
+    fn from(filter: api::EntityFilter) -> EntityFilter {
+        EntityFilter {
+            kinds: filter.kinds,
+            namespaces: filter.namespace_ids,
+        }
+    }
+}
+
+/// Represents the configuration of an export of entities to Cloud Storage.
+///
+/// ```
+/// # use google_cloud::datastore::admin::ExportConfig;
+/// let config = ExportConfig::new("gs://my-backups/datastore")
+///     .kind("User")
+///     .kind("Order")
+///     .label("trigger", "nightly");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExportConfig {
+    pub(crate) output_url_prefix: String,
+    pub(crate) filter: EntityFilter,
+    pub(crate) labels: HashMap<String, String>,
+}
+
+impl ExportConfig {
+    /// Export the entities under the given Cloud Storage prefix (such as `gs://bucket/path`).
+    ///
+    /// The files get written deeper than the prefix, at the URL returned once the export is done.
+    pub fn new(output_url_prefix: impl Into<String>) -> ExportConfig {
+        ExportConfig {
+            output_url_prefix: output_url_prefix.into(),
+            filter: EntityFilter::default(),
+            labels: HashMap::new(),
+        }
+    }
+
+    /// Only export the entities of the given kind (can be repeated).
+    pub fn kind(mut self, kind: impl Into<String>) -> ExportConfig {
+        self.filter = self.filter.kind(kind);
+        self
+    }
+
+    /// Only export the entities of the given namespace (can be repeated).
+    pub fn namespace(mut self, namespace: impl Into<String>) -> ExportConfig {
+        self.filter = self.filter.namespace(namespace);
+        self
+    }
+
+    /// Attach a label to the export operation.
+    pub fn label(mut self, name: impl Into<String>, value: impl Into<String>) -> ExportConfig {
+        self.labels.insert(name.into(), value.into());
+        self
+    }
+
+    pub(crate) fn into_api(self, project_id: String) -> api::ExportEntitiesRequest {
+        api::ExportEntitiesRequest {
+            project_id,
+            labels: self.labels,
+            entity_filter: self.filter.into_api(),
+            output_url_prefix: self.output_url_prefix,
+        }
+    }
+}
+
+/// Represents the configuration of an import of entities from a previous export.
+///
+/// Imported entities overwrite the existing ones with the same keys.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportConfig {
+    pub(crate) input_url: String,
+    pub(crate) filter: EntityFilter,
+    pub(crate) labels: HashMap<String, String>,
+}
+
+impl ImportConfig {
+    /// Import the entities of the export with the given output URL (the one of its metadata file).
+    pub fn new(input_url: impl Into<String>) -> ImportConfig {
+        ImportConfig {
+            input_url: input_url.into(),
+            filter: EntityFilter::default(),
+            labels: HashMap::new(),
+        }
+    }
+
+    /// Only import the entities of the given kind (can be repeated).
+    ///
+    /// The kinds must have been part of the export.
+    pub fn kind(mut self, kind: impl Into<String>) -> ImportConfig {
+        self.filter = self.filter.kind(kind);
+        self
+    }
+
+    /// Only import the entities of the given namespace (can be repeated).
+    ///
+    /// The namespaces must have been part of the export.
+    pub fn namespace(mut self, namespace: impl Into<String>) -> ImportConfig {
+        self.filter = self.filter.namespace(namespace);
+        self
+    }
+
+    /// Attach a label to the import operation.
+    pub fn label(mut self, name: impl Into<String>, value: impl Into<String>) -> ImportConfig {
+        self.labels.insert(name.into(), value.into());
+        self
+    }
+
+    pub(crate) fn into_api(self, project_id: String) -> api::ImportEntitiesRequest {
+        api::ImportEntitiesRequest {
+            project_id,
+            labels: self.labels,
+            input_url: self.input_url,
+            entity_filter: self.filter.into_api(),
+        }
+    }
+}
+
+/// Represents the kind of an administration operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OperationKind {
+    /// An export of entities.
+    Export,
+    /// An import of entities.
+    Import,
+    /// The creation of an index.
+    CreateIndex,
+    /// The deletion of an index.
+    DeleteIndex,
+    /// Any other operation.
+    Other,
+}
+
+/// Represents the state of an administration operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OperationState {
+    /// The operation is being prepared.
+    Initializing,
+    /// The operation is being processed.
+    Processing,
+    /// The operation is being cancelled.
+    Cancelling,
+    /// The operation has been processed and is being finalized.
+    Finalizing,
+    /// The operation completed successfully.
+    Successful,
+    /// The operation failed.
+    Failed,
+    /// The operation was cancelled.
+    Cancelled,
+    /// The state of the operation is unknown.
+    Unknown,
+}
+
+impl From<i32> for OperationState {
+    fn from(state: i32) -> OperationState {
+        use api::common_metadata::State;
+        match State::from_i32(state) {
+            Some(State::Initializing) => OperationState::Initializing,
+            Some(State::Processing) => OperationState::Processing,
+            Some(State::Cancelling) => OperationState::Cancelling,
+            Some(State::Finalizing) => OperationState::Finalizing,
+            Some(State::Successful) => OperationState::Successful,
+            Some(State::Failed) => OperationState::Failed,
+            Some(State::Cancelled) => OperationState::Cancelled,
+            Some(State::Unspecified) | None => OperationState::Unknown,
+        }
+    }
+}
+
+/// Represents the progress of an operation, as estimated by the service.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Progress {
+    pub(crate) completed: i64,
+    pub(crate) estimated: i64,
+}
+
+impl Progress {
+    /// The amount of work completed so far (which may exceed the estimate).
+    pub fn completed(&self) -> i64 {
+        self.completed
+    }
+
+    /// The estimated amount of work, zero if unavailable.
+    pub fn estimated(&self) -> i64 {
+        self.estimated
+    }
+}
+
+impl From<api::Progress> for Progress {
+    fn from(progress: api::Progress) -> Progress {
+        Progress {
+            completed: progress.work_completed,
+            estimated: progress.work_estimated,
+        }
+    }
+}
+
+/// Represents a long-running administration operation (export or import of entities, or index change).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Operation {
+    pub(crate) name: String,
+    pub(crate) done: bool,
+    pub(crate) kind: OperationKind,
+    pub(crate) state: OperationState,
+    pub(crate) start_time: Option<NaiveDateTime>,
+    pub(crate) end_time: Option<NaiveDateTime>,
+    pub(crate) labels: HashMap<String, String>,
+    pub(crate) filter: EntityFilter,
+    pub(crate) entities: Progress,
+    pub(crate) bytes: Progress,
+    pub(crate) output_url: Option<String>,
+    pub(crate) index_id: Option<String>,
+}
+
+impl Operation {
+    /// The full resource name of the operation, to check on it later on.
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    /// Is the operation finished (either successfully or not) ?
+    pub fn is_done(&self) -> bool {
+        self.done
+    }
+
+    /// The kind of the operation.
+    pub fn kind(&self) -> OperationKind {
+        self.kind
+    }
+
+    /// The current state of the operation.
+    pub fn state(&self) -> OperationState {
+        self.state
+    }
+
+    /// The time at which work began on the operation.
+    pub fn start_time(&self) -> Option<NaiveDateTime> {
+        self.start_time
+    }
+
+    /// The time at which the operation ended.
+    pub fn end_time(&self) -> Option<NaiveDateTime> {
+        self.end_time
+    }
+
+    /// The labels of the operation.
+    pub fn labels(&self) -> &HashMap<String, String> {
+        &self.labels
+    }
+
+    /// The entities covered by the operation.
+    pub fn filter(&self) -> &EntityFilter {
+        &self.filter
+    }
+
+    /// The estimated progress, in entities.
+    pub fn entities(&self) -> Progress {
+        self.entities
+    }
+
+    /// The estimated progress, in bytes.
+    pub fn bytes(&self) -> Progress {
+        self.bytes
+    }
+
+    /// The URL of the metadata file of a successful export, to import it from.
+    pub fn output_url(&self) -> Option<&str> {
+        self.output_url.as_deref()
+    }
+
+    /// The ID of the index an index operation acts on.
+    pub fn index_id(&self) -> Option<&str> {
+        self.index_id.as_deref()
+    }
+}
+
+impl From<longrunning::Operation> for Operation {
+    fn from(operation: longrunning::Operation) -> Operation {
+        let mut converted = Operation {
+            name: operation.name,
+            done: operation.done,
+            kind: OperationKind::Other,
+            state: OperationState::Unknown,
+            start_time: None,
+            end_time: None,
+            labels: HashMap::new(),
+            filter: EntityFilter::default(),
+            entities: Progress::default(),
+            bytes: Progress::default(),
+            output_url: None,
+            index_id: None,
+        };
+
+        //? Exports, imports and index changes have distinct metadata messages, told apart by their type URL.
+        let (common, entities, bytes, filter) = match operation.metadata {
+            Some(any) if any.type_url.ends_with(".ExportEntitiesMetadata") => {
+                let metadata = api::ExportEntitiesMetadata::decode(any.value.as_slice());
+                let metadata = metadata.unwrap_or_default();
+                converted.kind = OperationKind::Export;
+                (
+                    metadata.common,
+                    metadata.progress_entities,
+                    metadata.progress_bytes,
+                    metadata.entity_filter,
+                )
+            }
+            Some(any) if any.type_url.ends_with(".ImportEntitiesMetadata") => {
+                let metadata = api::ImportEntitiesMetadata::decode(any.value.as_slice());
+                let metadata = metadata.unwrap_or_default();
+                converted.kind = OperationKind::Import;
+                (
+                    metadata.common,
+                    metadata.progress_entities,
+                    metadata.progress_bytes,
+                    metadata.entity_filter,
+                )
+            }
+            Some(any) if any.type_url.ends_with(".IndexOperationMetadata") => {
+                let metadata = api::IndexOperationMetadata::decode(any.value.as_slice());
+                let metadata = metadata.unwrap_or_default();
+                let operation_type = metadata.common.as_ref().map(|common| common.operation_type);
+                converted.kind = match operation_type.and_then(api::OperationType::from_i32) {
+                    Some(api::OperationType::CreateIndex) => OperationKind::CreateIndex,
+                    Some(api::OperationType::DeleteIndex) => OperationKind::DeleteIndex,
+                    _ => OperationKind::Other,
+                };
+                converted.index_id = Some(metadata.index_id).filter(|id| !id.is_empty());
+                (metadata.common, metadata.progress_entities, None, None)
+            }
+            _ => (None, None, None, None),
+        };
+        if let Some(common) = common {
+            converted.state = OperationState::from(common.state);
+            converted.start_time = common.start_time.and_then(convert_timestamp);
+            converted.end_time = common.end_time.and_then(convert_timestamp);
+            converted.labels = common.labels;
+        }
+        converted.entities = entities.map(Progress::from).unwrap_or_default();
+        converted.bytes = bytes.map(Progress::from).unwrap_or_default();
+        converted.filter = filter.map(EntityFilter::from).unwrap_or_default();
+
+        if let Some(longrunning::operation::Result::Response(any)) = operation.result {
+            if any.type_url.ends_with(".ExportEntitiesResponse") {
+                let response = api::ExportEntitiesResponse::decode(any.value.as_slice());
+                converted.output_url = response
+                    .ok()
+                    .map(|response| response.output_url)
+                    .filter(|url| !url.is_empty());
+            }
+        }
+
+        converted
+    }
+}
+
+fn convert_timestamp(timestamp: prost_types::Timestamp) -> Option<NaiveDateTime> {
+    types::timestamp_to_naive(timestamp).ok()
+}
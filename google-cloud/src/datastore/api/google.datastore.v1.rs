@@ -23,6 +23,10 @@ pub struct PartitionId {
     /// The ID of the project to which the entities belong.
     #[prost(string, tag="2")]
     pub project_id: ::prost::alloc::string::String,
+    /// If not empty, the ID of the database to which the entities
+    /// belong.
+    #[prost(string, tag="3")]
+    pub database_id: ::prost::alloc::string::String,
     /// If not empty, the ID of the namespace to which the entities belong.
     #[prost(string, tag="4")]
     pub namespace_id: ::prost::alloc::string::String,
@@ -302,6 +306,118 @@ pub struct Query {
     #[prost(message, optional, tag="12")]
     pub limit: ::core::option::Option<i32>,
 }
+/// Datastore query for running an aggregation over a
+/// \[Query][google.datastore.v1.Query\].
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AggregationQuery {
+    /// Optional. Series of aggregations to apply over the results of the
+    /// `nested_query`.
+    ///
+    /// Requires:
+    ///
+    /// * A minimum of one and maximum of five aggregations per query.
+    #[prost(message, repeated, tag="3")]
+    pub aggregations: ::prost::alloc::vec::Vec<aggregation_query::Aggregation>,
+    /// The base query to aggregate over.
+    #[prost(oneof="aggregation_query::QueryType", tags="1")]
+    pub query_type: ::core::option::Option<aggregation_query::QueryType>,
+}
+/// Nested message and enum types in `AggregationQuery`.
+pub mod aggregation_query {
+    /// Defines an aggregation that produces a single result.
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Aggregation {
+        /// Optional. Optional name of the property to store the result of the
+        /// aggregation.
+        ///
+        /// If not provided, Datastore will pick a default name following the format
+        /// `property_<incremental_id++>`.
+        ///
+        /// Requires:
+        ///
+        /// * Must be unique across all aggregation aliases.
+        /// * Conform to entity property name limitations.
+        #[prost(string, tag="7")]
+        pub alias: ::prost::alloc::string::String,
+        /// The type of aggregation to perform, required.
+        #[prost(oneof="aggregation::Operator", tags="1, 2, 3")]
+        pub operator: ::core::option::Option<aggregation::Operator>,
+    }
+    /// Nested message and enum types in `Aggregation`.
+    pub mod aggregation {
+        /// Count of entities that match the query.
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct Count {
+            /// Optional. Optional constraint on the maximum number of entities to
+            /// count.
+            ///
+            /// This provides a way to set an upper bound on the number of entities
+            /// to scan, limiting latency, and cost.
+            ///
+            /// Unspecified is interpreted as no bound.
+            ///
+            /// Requires:
+            ///
+            /// * Must be greater than zero when present.
+            #[prost(message, optional, tag="1")]
+            pub up_to: ::core::option::Option<i64>,
+        }
+        /// Sum of the values of the requested property.
+        ///
+        /// * Only numeric values will be aggregated. All non-numeric values
+        /// including `NULL` are skipped.
+        ///
+        /// * If the aggregated values contain `NaN`, returns `NaN`.
+        ///
+        /// * If the aggregated value set is empty, returns 0.
+        ///
+        /// * Returns a 64-bit integer if all aggregated numbers are integers and the
+        /// sum result does not overflow. Otherwise, the result is returned as a
+        /// double.
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct Sum {
+            /// The property to aggregate on.
+            #[prost(message, optional, tag="1")]
+            pub property: ::core::option::Option<super::super::PropertyReference>,
+        }
+        /// Average of the values of the requested property.
+        ///
+        /// * Only numeric values will be aggregated. All non-numeric values
+        /// including `NULL` are skipped.
+        ///
+        /// * If the aggregated values contain `NaN`, returns `NaN`.
+        ///
+        /// * If the aggregated value set is empty, returns `NULL`.
+        ///
+        /// * Always returns the result as a double.
+        #[derive(Clone, PartialEq, ::prost::Message)]
+        pub struct Avg {
+            /// The property to aggregate on.
+            #[prost(message, optional, tag="1")]
+            pub property: ::core::option::Option<super::super::PropertyReference>,
+        }
+        /// The type of aggregation to perform, required.
+        #[derive(Clone, PartialEq, ::prost::Oneof)]
+        pub enum Operator {
+            /// Count aggregator.
+            #[prost(message, tag="1")]
+            Count(Count),
+            /// Sum aggregator.
+            #[prost(message, tag="2")]
+            Sum(Sum),
+            /// Average aggregator.
+            #[prost(message, tag="3")]
+            Avg(Avg),
+        }
+    }
+    /// The base query to aggregate over.
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum QueryType {
+        /// Nested query for aggregation
+        #[prost(message, tag="1")]
+        NestedQuery(super::Query),
+    }
+}
 /// A representation of a kind.
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct KindExpression {
@@ -646,12 +762,49 @@ pub mod query_result_batch {
         }
     }
 }
+/// The result of a single bucket from a Datastore aggregation query.
+///
+/// The keys of `aggregate_properties` are the same for all results in an
+/// aggregation query, unlike entity queries which can have different fields
+/// present for each result.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AggregationResult {
+    /// The result of the aggregation functions, ex: `COUNT(*) AS total_entities`.
+    ///
+    /// The key is the
+    /// \[alias][google.datastore.v1.AggregationQuery.Aggregation.alias\] assigned to
+    /// the aggregation function on input and the size of this map equals the
+    /// number of aggregation functions in the query.
+    #[prost(map="string, message", tag="2")]
+    pub aggregate_properties: ::std::collections::HashMap<::prost::alloc::string::String, Value>,
+}
+/// A batch of aggregation results produced by an aggregation query.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AggregationResultBatch {
+    /// The aggregation results for this batch.
+    #[prost(message, repeated, tag="1")]
+    pub aggregation_results: ::prost::alloc::vec::Vec<AggregationResult>,
+    /// The state of the query after the current batch.
+    /// Only COUNT(*) aggregations are supported in the initial launch. Therefore,
+    /// expected result type is limited to `NO_MORE_RESULTS`.
+    #[prost(enumeration="query_result_batch::MoreResultsType", tag="2")]
+    pub more_results: i32,
+    /// Read timestamp this batch was returned from.
+    /// In a single transaction, subsequent query result batches for the same query
+    /// can have a greater timestamp. Each batch's read timestamp
+    /// is valid for all preceding batches.
+    #[prost(message, optional, tag="3")]
+    pub read_time: ::core::option::Option<::prost_types::Timestamp>,
+}
 /// The request for \[Datastore.Lookup][google.datastore.v1.Datastore.Lookup\].
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct LookupRequest {
     /// The ID of the project against which to make the request.
     #[prost(string, tag="8")]
     pub project_id: ::prost::alloc::string::String,
+    /// If not empty, the ID of the database against which to make the request.
+    #[prost(string, tag="9")]
+    pub database_id: ::prost::alloc::string::String,
     /// The options for this lookup request.
     #[prost(message, optional, tag="1")]
     pub read_options: ::core::option::Option<ReadOptions>,
@@ -684,6 +837,9 @@ pub struct RunQueryRequest {
     /// The ID of the project against which to make the request.
     #[prost(string, tag="8")]
     pub project_id: ::prost::alloc::string::String,
+    /// If not empty, the ID of the database against which to make the request.
+    #[prost(string, tag="9")]
+    pub database_id: ::prost::alloc::string::String,
     /// Entities are partitioned into subsets, identified by a partition ID.
     /// Queries are scoped to a single partition.
     /// This partition ID is normalized with the standard default context
@@ -696,6 +852,10 @@ pub struct RunQueryRequest {
     /// The type of query.
     #[prost(oneof="run_query_request::QueryType", tags="3, 7")]
     pub query_type: ::core::option::Option<run_query_request::QueryType>,
+    /// Optional. Explain options for the query. If set, additional query
+    /// statistics will be returned. If not, only query results will be returned.
+    #[prost(message, optional, tag="12")]
+    pub explain_options: ::core::option::Option<ExplainOptions>,
 }
 /// Nested message and enum types in `RunQueryRequest`.
 pub mod run_query_request {
@@ -720,6 +880,58 @@ pub struct RunQueryResponse {
     /// The parsed form of the `GqlQuery` from the request, if it was set.
     #[prost(message, optional, tag="2")]
     pub query: ::core::option::Option<Query>,
+    /// Query explain metrics. This is only present when the
+    /// \[RunQueryRequest.explain_options][google.datastore.v1.RunQueryRequest.explain_options\]
+    /// is provided, and it is sent only once with the last response in the stream.
+    #[prost(message, optional, tag="9")]
+    pub explain_metrics: ::core::option::Option<ExplainMetrics>,
+}
+/// The request for
+/// \[Datastore.RunAggregationQuery][google.datastore.v1.Datastore.RunAggregationQuery\].
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RunAggregationQueryRequest {
+    /// The ID of the project against which to make the request.
+    #[prost(string, tag="8")]
+    pub project_id: ::prost::alloc::string::String,
+    /// If not empty, the ID of the database against which to make the request.
+    #[prost(string, tag="9")]
+    pub database_id: ::prost::alloc::string::String,
+    /// Entities are partitioned into subsets, identified by a partition ID.
+    /// Queries are scoped to a single partition.
+    /// This partition ID is normalized with the standard default context
+    /// partition ID.
+    #[prost(message, optional, tag="2")]
+    pub partition_id: ::core::option::Option<PartitionId>,
+    /// The options for this query.
+    #[prost(message, optional, tag="1")]
+    pub read_options: ::core::option::Option<ReadOptions>,
+    /// The type of query.
+    #[prost(oneof="run_aggregation_query_request::QueryType", tags="3, 7")]
+    pub query_type: ::core::option::Option<run_aggregation_query_request::QueryType>,
+}
+/// Nested message and enum types in `RunAggregationQueryRequest`.
+pub mod run_aggregation_query_request {
+    /// The type of query.
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum QueryType {
+        /// The query to run.
+        #[prost(message, tag="3")]
+        AggregationQuery(super::AggregationQuery),
+        /// The GQL query to run. This query must be an aggregation query.
+        #[prost(message, tag="7")]
+        GqlQuery(super::GqlQuery),
+    }
+}
+/// The response for
+/// \[Datastore.RunAggregationQuery][google.datastore.v1.Datastore.RunAggregationQuery\].
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RunAggregationQueryResponse {
+    /// A batch of aggregation results. Always present.
+    #[prost(message, optional, tag="1")]
+    pub batch: ::core::option::Option<AggregationResultBatch>,
+    /// The parsed form of the `GqlQuery` from the request, if it was set.
+    #[prost(message, optional, tag="2")]
+    pub query: ::core::option::Option<AggregationQuery>,
 }
 /// The request for
 /// \[Datastore.BeginTransaction][google.datastore.v1.Datastore.BeginTransaction\].
@@ -728,6 +940,9 @@ pub struct BeginTransactionRequest {
     /// The ID of the project against which to make the request.
     #[prost(string, tag="8")]
     pub project_id: ::prost::alloc::string::String,
+    /// If not empty, the ID of the database against which to make the request.
+    #[prost(string, tag="9")]
+    pub database_id: ::prost::alloc::string::String,
     /// Options for a new transaction.
     #[prost(message, optional, tag="10")]
     pub transaction_options: ::core::option::Option<TransactionOptions>,
@@ -746,6 +961,9 @@ pub struct RollbackRequest {
     /// The ID of the project against which to make the request.
     #[prost(string, tag="8")]
     pub project_id: ::prost::alloc::string::String,
+    /// If not empty, the ID of the database against which to make the request.
+    #[prost(string, tag="9")]
+    pub database_id: ::prost::alloc::string::String,
     /// The transaction identifier, returned by a call to
     /// \[Datastore.BeginTransaction][google.datastore.v1.Datastore.BeginTransaction\].
     #[prost(bytes="vec", tag="1")]
@@ -763,6 +981,9 @@ pub struct CommitRequest {
     /// The ID of the project against which to make the request.
     #[prost(string, tag="8")]
     pub project_id: ::prost::alloc::string::String,
+    /// If not empty, the ID of the database against which to make the request.
+    #[prost(string, tag="9")]
+    pub database_id: ::prost::alloc::string::String,
     /// The type of commit to perform. Defaults to `TRANSACTIONAL`.
     #[prost(enumeration="commit_request::Mode", tag="5")]
     pub mode: i32,
@@ -842,6 +1063,9 @@ pub struct AllocateIdsRequest {
     /// The ID of the project against which to make the request.
     #[prost(string, tag="8")]
     pub project_id: ::prost::alloc::string::String,
+    /// If not empty, the ID of the database against which to make the request.
+    #[prost(string, tag="9")]
+    pub database_id: ::prost::alloc::string::String,
     /// A list of keys with incomplete key paths for which to allocate IDs.
     /// No key may be reserved/read-only.
     #[prost(message, repeated, tag="1")]
@@ -960,7 +1184,7 @@ pub struct ReadOptions {
     /// If not specified, lookups and ancestor queries default to
     /// `read_consistency`=`STRONG`, global queries default to
     /// `read_consistency`=`EVENTUAL`.
-    #[prost(oneof="read_options::ConsistencyType", tags="1, 2")]
+    #[prost(oneof="read_options::ConsistencyType", tags="1, 2, 4")]
     pub consistency_type: ::core::option::Option<read_options::ConsistencyType>,
 }
 /// Nested message and enum types in `ReadOptions`.
@@ -1003,6 +1227,14 @@ pub mod read_options {
         /// \[Datastore.BeginTransaction][google.datastore.v1.Datastore.BeginTransaction\].
         #[prost(bytes, tag="2")]
         Transaction(::prost::alloc::vec::Vec<u8>),
+        /// Reads entities as they were at the given time. This value is only
+        /// supported for Cloud Firestore in Datastore mode.
+        ///
+        /// This must be a microsecond precision timestamp within the past one hour,
+        /// or if Point-in-Time Recovery is enabled, can additionally be a whole
+        /// minute timestamp within the past 7 days.
+        #[prost(message, tag="4")]
+        ReadTime(::prost_types::Timestamp),
     }
 }
 /// Options for beginning a new transaction.
@@ -1044,6 +1276,56 @@ pub mod transaction_options {
         ReadOnly(ReadOnly),
     }
 }
+/// Explain options for the query.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExplainOptions {
+    /// Optional. Whether to execute this query.
+    ///
+    /// When false (the default), the query will be planned, returning only
+    /// metrics from the planning stages.
+    ///
+    /// When true, the query will be planned and executed, returning the full
+    /// query results along with both planning and execution stage metrics.
+    #[prost(bool, tag="1")]
+    pub analyze: bool,
+}
+/// Explain metrics for the query.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExplainMetrics {
+    /// Planning phase information for the query.
+    #[prost(message, optional, tag="1")]
+    pub plan_summary: ::core::option::Option<PlanSummary>,
+    /// Aggregated stats from the execution of the query. Only present when
+    /// \[ExplainOptions.analyze][google.datastore.v1.ExplainOptions.analyze\] is set
+    /// to true.
+    #[prost(message, optional, tag="2")]
+    pub execution_stats: ::core::option::Option<ExecutionStats>,
+}
+/// Planning phase information for the query.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PlanSummary {
+    /// The indexes selected for the query.
+    #[prost(message, repeated, tag="1")]
+    pub indexes_used: ::prost::alloc::vec::Vec<::prost_types::Struct>,
+}
+/// Execution statistics for the query.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExecutionStats {
+    /// Total number of results returned, including documents, projections,
+    /// aggregation results, keys.
+    #[prost(int64, tag="1")]
+    pub results_returned: i64,
+    /// Total time to execute the query in the backend.
+    #[prost(message, optional, tag="3")]
+    pub execution_duration: ::core::option::Option<::prost_types::Duration>,
+    /// Total billable read operations.
+    #[prost(int64, tag="4")]
+    pub read_operations: i64,
+    /// Debugging statistics from the execution of the query. Note that the
+    /// debugging stats are subject to change as Firestore evolves.
+    #[prost(message, optional, tag="5")]
+    pub debug_stats: ::core::option::Option<::prost_types::Struct>,
+}
 /// Generated client implementations.
 pub mod datastore_client {
     #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
@@ -1160,6 +1442,26 @@ pub mod datastore_client {
             );
             self.inner.unary(request.into_request(), path, codec).await
         }
+        /// Runs an aggregation query.
+        pub async fn run_aggregation_query(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RunAggregationQueryRequest>,
+        ) -> Result<tonic::Response<super::RunAggregationQueryResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/google.datastore.v1.Datastore/RunAggregationQuery",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
         /// Begins a new transaction.
         pub async fn begin_transaction(
             &mut self,
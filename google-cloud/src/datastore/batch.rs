@@ -0,0 +1,255 @@
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+use crate::datastore::api;
+use crate::datastore::client::{
+    convert_entity_result, convert_key, delete_mutations, write_mutations, Keys, WriteMode,
+};
+use crate::datastore::{Client, Entity, Error, IntoEntity, Key, MutationResult};
+use crate::error::{ConvertError, WithContext};
+
+/// An operation of a batch, in the order it was added.
+#[derive(Debug, Clone)]
+enum Operation {
+    Get(Key),
+    Put(Key),
+    Delete(Key),
+}
+
+/// Represents a batch of lookups and writes, executed together outside of any transaction.
+///
+/// When executed, all the lookups are made first (in as few requests as possible),
+/// then all the writes are applied in a single commit.
+/// Unlike with a transaction, the lookups do not see a consistent snapshot
+/// and the writes are not guarded against concurrent ones, but no round trip is spent beginning it.
+///
+/// ```no_run
+/// # use google_cloud::datastore::{BatchOutcome, Client, Error, Key};
+/// # use std::collections::HashMap;
+/// # async fn run(mut client: Client) -> Result<(), Error> {
+/// let mut batch = client.batch();
+/// batch.get(Key::new("Order").id("1042"));
+/// batch.put((Key::new("Invoice"), HashMap::<String, i64>::new()))?;
+/// batch.delete(Key::new("Cart").id("1042"));
+/// for outcome in batch.execute().await? {
+///     if let BatchOutcome::Put(result) = outcome {
+///         println!("stored invoice: {:?} (version {})", result.key(), result.version());
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct Batch {
+    pub(crate) client: Client,
+    operations: Vec<Operation>,
+    mutations: Vec<api::Mutation>,
+}
+
+/// The outcome of an operation of a batch.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BatchOutcome {
+    /// A lookup found an entity.
+    Found(Entity),
+    /// A lookup found no entity under this key.
+    Missing(Key),
+    /// An entity was stored, under the key of the result (generated by the store if the entity's key was incomplete).
+    Put(MutationResult),
+    /// The entity under the key of the result was deleted (if there was one).
+    Deleted(MutationResult),
+}
+
+impl Batch {
+    pub(crate) fn new(client: Client) -> Batch {
+        Batch {
+            client,
+            operations: Vec::new(),
+            mutations: Vec::new(),
+        }
+    }
+
+    /// Looks up an entity from a key, before the writes of the batch get applied.
+    pub fn get(&mut self, key: impl Borrow<Key>) {
+        self.get_all(Some(key.borrow()))
+    }
+
+    /// Looks up multiple entities from multiple keys, before the writes of the batch get applied.
+    pub fn get_all<T, I>(&mut self, keys: I)
+    where
+        I: IntoIterator<Item = T>,
+        T: Borrow<Key>,
+    {
+        let operations = keys
+            .into_iter()
+            .map(|key| Operation::Get(key.borrow().clone()));
+        self.operations.extend(operations);
+    }
+
+    /// Inserts an entity, within the commit of the batch.
+    pub fn put(&mut self, entity: impl IntoEntity) -> Result<(), ConvertError> {
+        self.put_all(Some(entity))
+    }
+
+    /// Inserts multiple entities, within the commit of the batch.
+    pub fn put_all<T, I>(&mut self, entities: I) -> Result<(), ConvertError>
+    where
+        I: IntoIterator<Item = T>,
+        T: IntoEntity,
+    {
+        self.write_all(entities, WriteMode::Put)
+    }
+
+    /// Inserts an entity within the commit of the batch,
+    /// making it fail with an `ALREADY_EXISTS` status if an entity already exists under its key.
+    pub fn insert(&mut self, entity: impl IntoEntity) -> Result<(), ConvertError> {
+        self.write_all(Some(entity), WriteMode::Insert)
+    }
+
+    /// Updates an existing entity within the commit of the batch,
+    /// making it fail with a `NOT_FOUND` status if there is no entity under its key.
+    pub fn update(&mut self, entity: impl IntoEntity) -> Result<(), ConvertError> {
+        self.write_all(Some(entity), WriteMode::Update)
+    }
+
+    /// Stores an entity within the commit of the batch, whether an entity already exists under its key or not.
+    pub fn upsert(&mut self, entity: impl IntoEntity) -> Result<(), ConvertError> {
+        self.write_all(Some(entity), WriteMode::Upsert)
+    }
+
+    fn write_all<T, I>(&mut self, entities: I, mode: WriteMode) -> Result<(), ConvertError>
+    where
+        I: IntoIterator<Item = T>,
+        T: IntoEntity,
+    {
+        let entities = entities
+            .into_iter()
+            .map(IntoEntity::into_entity)
+            .collect::<Result<Vec<_>, _>>()?;
+        let partition = self.client.partition();
+        let keys: Vec<Key> = entities
+            .iter()
+            .map(|entity| partition.scope(&entity.key))
+            .collect();
+        let mutations = write_mutations(partition, entities, mode)?;
+        self.operations.extend(keys.into_iter().map(Operation::Put));
+        self.mutations.extend(mutations);
+        Ok(())
+    }
+
+    /// Deletes an entity, within the commit of the batch.
+    pub fn delete(&mut self, key: impl Borrow<Key>) {
+        self.delete_all(Some(key.borrow()))
+    }
+
+    /// Deletes multiple entities, within the commit of the batch.
+    pub fn delete_all<T, I>(&mut self, keys: I)
+    where
+        I: IntoIterator<Item = T>,
+        T: Borrow<Key>,
+    {
+        let partition = self.client.partition();
+        let keys: Vec<Key> = keys
+            .into_iter()
+            .map(|key| partition.scope(key.borrow()))
+            .collect();
+        let mutations = delete_mutations(partition, keys.iter());
+        self.operations
+            .extend(keys.into_iter().map(Operation::Delete));
+        self.mutations.extend(mutations);
+    }
+
+    /// Executes the batch: the lookups first, and then the writes in a single commit.
+    ///
+    /// Returns the outcome of every operation, in the order they were added to the batch,
+    /// the writes reporting the results of their mutations (such as the versions they produced).
+    /// If the lookups fail, no writes are applied.
+    pub async fn execute(mut self) -> Result<Vec<BatchOutcome>, Error> {
+        let found = self.lookup_all().await?;
+
+        let mutations = std::mem::take(&mut self.mutations);
+        let mut written = if mutations.is_empty() {
+            Vec::new()
+        } else {
+            self.client.commit_results(mutations, None).await?
+        }
+        .into_iter();
+
+        let outcomes = self
+            .operations
+            .into_iter()
+            .map(|operation| match operation {
+                Operation::Get(key) => match found.get(&key) {
+                    Some(entity) => Ok(BatchOutcome::Found(entity.clone())),
+                    None => Ok(BatchOutcome::Missing(key)),
+                },
+                Operation::Put(key) => Ok(BatchOutcome::Put(mutation_result(key, written.next())?)),
+                Operation::Delete(key) => {
+                    Ok(BatchOutcome::Deleted(mutation_result(key, written.next())?))
+                }
+            })
+            .collect::<Result<Vec<_>, ConvertError>>()?;
+
+        Ok(outcomes)
+    }
+
+    /// Looks up the entities of all the lookups of the batch, following deferred keys.
+    async fn lookup_all(&mut self) -> Result<HashMap<Key, Entity>, Error> {
+        let partition = self.client.partition();
+        let og_keys: Vec<Key> = self
+            .operations
+            .iter()
+            .filter_map(|operation| match operation {
+                Operation::Get(key) => Some(key.clone()),
+                _ => None,
+            })
+            .collect();
+        let mut keys: Vec<api::Key> = og_keys
+            .iter()
+            .map(|key| convert_key(partition, key))
+            .collect();
+        let mut found = HashMap::new();
+
+        while !keys.is_empty() {
+            let request = api::LookupRequest {
+                keys,
+                project_id: self.client.project_name.clone(),
+                database_id: self.client.database_id.clone(),
+                read_options: None,
+            };
+            let response = self
+                .client
+                .send(request, true, |mut service, request| async move {
+                    service.lookup(request).await
+                })
+                .await;
+            let response = response.context("Lookup", Keys::new(&og_keys))?;
+
+            for result in response.found {
+                let entity = convert_entity_result(result)?;
+                found.insert(entity.key.clone(), entity);
+            }
+            keys = response.deferred;
+        }
+
+        Ok(found)
+    }
+}
+
+/// Pairs the key of a write with the result of its mutation, the key generated by the store replacing incomplete ones.
+fn mutation_result(
+    key: Key,
+    result: Option<api::MutationResult>,
+) -> Result<MutationResult, ConvertError> {
+    let result =
+        result.ok_or_else(|| ConvertError::MissingField(String::from("mutation_results")))?;
+    let key = match result.key {
+        Some(key) => Key::try_from(key)?,
+        None => key,
+    };
+
+    Ok(MutationResult {
+        key,
+        version: result.version,
+        conflict_detected: result.conflict_detected,
+    })
+}
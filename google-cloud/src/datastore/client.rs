@@ -1,27 +1,50 @@
 use std::borrow::Borrow;
 use std::collections::HashMap;
+use std::convert::TryFrom;
 use std::env;
+use std::fmt;
 use std::fs::File;
+use std::future::Future;
 use std::sync::Arc;
 
+use chrono::NaiveDateTime;
+use futures::future::BoxFuture;
+use futures::stream::{self, Stream, StreamExt};
 use tokio::sync::Mutex;
-use tonic::transport::{Certificate, Channel, ClientTlsConfig};
-use tonic::{IntoRequest, Request};
+use tonic::transport::Channel;
+use tonic::{IntoRequest, Request, Response};
 
-use crate::authorize::{ApplicationCredentials, TokenManager, TLS_CERTS};
+use crate::authorize::{ApplicationCredentials, TokenManager};
+use crate::cancel::{self, CancellationToken};
+use crate::channel::{self, ChannelConfig};
+use crate::datastore::admin;
 use crate::datastore::api;
+use crate::datastore::api::datastore::admin::v1::datastore_admin_client::DatastoreAdminClient;
 use crate::datastore::api::datastore_client::DatastoreClient;
+use crate::datastore::api::longrunning::operations_client::OperationsClient;
 use crate::datastore::api::value::ValueType;
 use crate::datastore::{
-    Entity, Error, Filter, FromValue, IntoEntity, Key, KeyID, Order, Query, Value,
+    Aggregation, AggregationQuery, AggregationResults, Batch, CommitResults, Cursor, DecodeOptions,
+    Entity, EntityMeta, Error, ExplainMetrics, ExplainOptions, Filter, FromValue, IntoEntity, Key,
+    KeyID, MoreResults, MutationResult, Order, Query, QueryResults, ReadConsistency,
+    ReadOnlyTransaction, RetryConfig, Transaction, Value,
 };
+use crate::error::{ConvertError, WithContext};
+use crate::types;
 
 /// The Datastore client, tied to a specific project.
 #[derive(Clone)]
 pub struct Client {
     pub(crate) project_name: String,
+    pub(crate) channel: Channel,
     pub(crate) service: DatastoreClient<Channel>,
     pub(crate) token_manager: Arc<Mutex<TokenManager>>,
+    pub(crate) decode_options: DecodeOptions,
+    pub(crate) namespace: Option<String>,
+    pub(crate) database_id: String,
+    pub(crate) concurrency: usize,
+    pub(crate) retry: RetryConfig,
+    pub(crate) cancel: Option<CancellationToken>,
 }
 
 struct ClientConfiguration {
@@ -31,8 +54,10 @@ struct ClientConfiguration {
 impl ClientConfiguration {
     pub fn new() -> ClientConfiguration {
         ClientConfiguration {
-            endpoint: env::var("DATASTORE_EMULATOR_HOST")
-                .unwrap_or_else(|_| Client::ENDPOINT.to_string()),
+            endpoint: match env::var("DATASTORE_EMULATOR_HOST") {
+                Ok(host) => channel::emulator_endpoint(host.as_str()),
+                Err(_) => Client::ENDPOINT.to_string(),
+            },
         }
     }
 }
@@ -45,6 +70,14 @@ impl Client {
         "https://www.googleapis.com/auth/datastore",
     ];
 
+    //? Datastore rejects commits of more mutations, and lookups of more keys, than these.
+    pub(crate) const MAX_MUTATIONS: usize = 500;
+    pub(crate) const MAX_LOOKUP_KEYS: usize = 1000;
+
+    //? The kinds of the metadata queries, whose results are keys named after the kinds and namespaces.
+    const KIND_METADATA_KIND: &'static str = "__kind__";
+    const NAMESPACE_METADATA_KIND: &'static str = "__namespace__";
+
     pub(crate) async fn construct_request<T: IntoRequest<T>>(
         &mut self,
         request: T,
@@ -53,9 +86,53 @@ impl Client {
         let token = self.token_manager.lock().await.token().await?;
         let metadata = request.metadata_mut();
         metadata.insert("authorization", token.parse().unwrap());
+        //? Requests to named databases must be routed to them explicitly.
+        if !self.database_id.is_empty() {
+            let params = format!(
+                "project_id={}&database_id={}",
+                self.project_name, self.database_id
+            );
+            if let Ok(params) = params.parse() {
+                metadata.insert("x-goog-request-params", params);
+            }
+        }
         Ok(request)
     }
 
+    /// Sends a request, retrying it on transient failures if it is `retryable`.
+    ///
+    /// Only idempotent requests are retryable, as a failed attempt may still have been applied.
+    /// Requests made within transactions are not retryable either:
+    /// the whole transaction gets retried instead (see `run_in_transaction`).
+    pub(crate) async fn send<R, T, F, Fut>(
+        &self,
+        request: R,
+        retryable: bool,
+        call: F,
+    ) -> Result<T, Error>
+    where
+        R: Clone,
+        F: Fn(DatastoreClient<Channel>, Request<R>) -> Fut,
+        Fut: Future<Output = Result<Response<T>, tonic::Status>>,
+    {
+        let retry = match retryable {
+            true => self.retry.clone(),
+            false => RetryConfig::disabled(),
+        };
+        let call = &call;
+        retry
+            .run(|| {
+                let mut client = self.clone();
+                let request = request.clone();
+                async move {
+                    let request = client.construct_request(request).await?;
+                    let response = call(client.service.clone(), request).await?;
+                    Ok(response.into_inner())
+                }
+            })
+            .await
+    }
+
     /// Creates a new client for the specified project.
     ///
     /// Credentials are looked up in the `GOOGLE_APPLICATION_CREDENTIALS` environment variable.
@@ -64,270 +141,1775 @@ impl Client {
         let file = File::open(path)?;
         let creds = json::from_reader(file)?;
 
-        Client::from_credentials(project_name, creds).await
+        Client::from_credentials(project_name, creds).await
+    }
+
+    /// Creates a new client for the specified project, using the given namespace by default.
+    ///
+    /// Credentials are looked up in the `GOOGLE_APPLICATION_CREDENTIALS` environment variable.
+    /// See `Client::namespace` for how the namespace applies.
+    pub async fn new_with_namespace(
+        project_name: impl Into<String>,
+        namespace: impl Into<String>,
+    ) -> Result<Client, Error> {
+        let client = Client::new(project_name).await?;
+        Ok(client.namespace(namespace))
+    }
+
+    /// Creates a new client for the specified project with custom credentials.
+    pub async fn from_credentials(
+        project_name: impl Into<String>,
+        creds: ApplicationCredentials,
+    ) -> Result<Client, Error> {
+        Client::from_credentials_with_config(project_name, creds, ChannelConfig::default()).await
+    }
+
+    /// Creates a new client for the specified project with custom credentials and channel configuration.
+    pub async fn from_credentials_with_config(
+        project_name: impl Into<String>,
+        creds: ApplicationCredentials,
+        config: ChannelConfig,
+    ) -> Result<Client, Error> {
+        let client_config = ClientConfiguration::new();
+        Client::from_endpoint(project_name, creds, client_config.endpoint, &config).await
+    }
+
+    /// Creates a new client for the specified project, connected to the given endpoint.
+    pub(crate) async fn from_endpoint(
+        project_name: impl Into<String>,
+        creds: ApplicationCredentials,
+        endpoint: String,
+        config: &ChannelConfig,
+    ) -> Result<Client, Error> {
+        let channel = channel::connect(endpoint, Client::DOMAIN_NAME, config).await?;
+
+        Ok(Client::with_channel(project_name, creds, channel))
+    }
+
+    /// Creates a new client for the specified project, connected to a local emulator (such as `localhost:8081`).
+    ///
+    /// No credentials are needed: requests are sent over a plaintext connection, without fetching any token.
+    ///
+    /// ```no_run
+    /// # use google_cloud::datastore::{Client, Error};
+    /// # async fn run() -> Result<(), Error> {
+    /// let host = std::env::var("DATASTORE_EMULATOR_HOST").unwrap_or_else(|_| String::from("localhost:8081"));
+    /// let client = Client::for_emulator("my-project", host).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn for_emulator(
+        project_name: impl Into<String>,
+        host: impl AsRef<str>,
+    ) -> Result<Client, Error> {
+        let project_name = project_name.into();
+        let endpoint = channel::emulator_endpoint(host.as_ref());
+        let config = ChannelConfig::default();
+        let channel = channel::connect(endpoint, Client::DOMAIN_NAME, &config).await?;
+        let creds = ApplicationCredentials::emulator(project_name.as_str());
+
+        Ok(Client::with_channel(project_name, creds, channel))
+    }
+
+    fn with_channel(
+        project_name: impl Into<String>,
+        creds: ApplicationCredentials,
+        channel: Channel,
+    ) -> Client {
+        Client {
+            project_name: project_name.into(),
+            service: DatastoreClient::new(channel.clone()),
+            channel,
+            token_manager: Arc::new(Mutex::new(TokenManager::new(
+                creds,
+                Client::SCOPES.as_ref(),
+            ))),
+            decode_options: DecodeOptions::default(),
+            namespace: None,
+            database_id: String::new(),
+            concurrency: 4,
+            retry: RetryConfig::default(),
+            cancel: None,
+        }
+    }
+
+    /// Set how the numbers of the fetched entities get decoded (in `get`, `get_all` and `query_as`, as well as within transactions).
+    pub fn decode_options(mut self, options: DecodeOptions) -> Client {
+        self.decode_options = options;
+        self
+    }
+
+    /// Set the namespace of the keys and queries which have none (including within transactions and batches).
+    ///
+    /// Keys and queries with an explicit namespace keep it, an empty one referring to the default namespace of the project.
+    ///
+    /// ```no_run
+    /// # use google_cloud::datastore::{Client, Error, Key, Query};
+    /// # async fn run(client: Client) -> Result<(), Error> {
+    /// let mut client = client.namespace("staging");
+    /// // Looked up within the `staging` namespace.
+    /// let user: Option<google_cloud::datastore::Value> = client.get(Key::new("User").id(1)).await?;
+    /// // Queried within the default namespace.
+    /// let users = client.query(Query::new("User").namespace("")).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn namespace(mut self, namespace: impl Into<String>) -> Client {
+        self.namespace = Some(namespace.into());
+        self
+    }
+
+    /// Get a client for another project, sharing the channel and the credentials of this one.
+    ///
+    /// The other settings of the client (namespace, database, retries...) are kept.
+    /// The credentials must be granted access to the other project.
+    ///
+    /// ```no_run
+    /// # use google_cloud::datastore::{Client, Error, Key, Value};
+    /// # async fn run(client: Client) -> Result<(), Error> {
+    /// for project in ["customer-a", "customer-b"].iter() {
+    ///     let settings: Option<Value> = client.for_project(*project).get(Key::new("Settings").id(1)).await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn for_project(&self, project_name: impl Into<String>) -> Client {
+        let mut client = self.clone();
+        client.project_name = project_name.into();
+        client
+    }
+
+    /// Get a client for another namespace (see `Client::namespace`), sharing the channel and the credentials of this one.
+    ///
+    /// The other settings of the client (project, database, retries...) are kept, which allows a single client
+    /// to serve applications mapping their tenants to namespaces:
+    ///
+    /// ```no_run
+    /// # use google_cloud::datastore::{Client, Error, Key, Value};
+    /// # async fn run(client: Client, tenant: &str) -> Result<(), Error> {
+    /// let mut tenant_client = client.for_namespace(format!("tenant-{}", tenant));
+    /// let settings: Option<Value> = tenant_client.get(Key::new("Settings").id(1)).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn for_namespace(&self, namespace: impl Into<String>) -> Client {
+        self.clone().namespace(namespace)
+    }
+
+    /// The project the client operates on.
+    pub fn project_name(&self) -> &str {
+        self.project_name.as_str()
+    }
+
+    /// The namespace of the keys and queries which have none, if set (see `Client::namespace`).
+    pub fn default_namespace(&self) -> Option<&str> {
+        self.namespace.as_deref()
+    }
+
+    /// Get an administration client for the same project, sharing the channel and the credentials of this one.
+    ///
+    /// Both services are served by the same endpoint with the same scopes, so no other connection gets opened
+    /// and no other token gets fetched.
+    pub fn admin(&self) -> admin::Client {
+        admin::Client {
+            project_name: self.project_name.clone(),
+            service: DatastoreAdminClient::new(self.channel.clone()),
+            operations: OperationsClient::new(self.channel.clone()),
+            token_manager: Arc::clone(&self.token_manager),
+            cancel: self.cancel.clone(),
+        }
+    }
+
+    /// Target a named database (of Firestore in Datastore mode) instead of the default database of the project.
+    ///
+    /// All the requests of the client (including within transactions and batches) and all of its keys
+    /// are then bound to that database. An empty ID refers to the default database.
+    pub fn database(mut self, database_id: impl Into<String>) -> Client {
+        self.database_id = database_id.into();
+        self
+    }
+
+    /// End the query streams of this client (including the ones of its scans) once the given token gets cancelled.
+    ///
+    /// The request in flight is dropped, and no further batch gets fetched.
+    pub fn cancel_on(mut self, token: CancellationToken) -> Client {
+        self.cancel = Some(token);
+        self
+    }
+
+    /// Set how many requests are sent concurrently when splitting large writes and lookups.
+    ///
+    /// Writes of more than 500 entities (outside of transactions) are split into several commits,
+    /// and lookups of more than 1000 keys into several requests.
+    pub fn concurrency(mut self, concurrency: usize) -> Client {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Set how requests failing with `ABORTED` or `UNAVAILABLE` get retried (see `RetryConfig`).
+    ///
+    /// An attempt failing with `UNAVAILABLE` may still have been applied, so retried requests are applied
+    /// at least once, rather than exactly once. For this reason, only the requests which can safely be
+    /// applied again are retried: lookups, queries, ID reservations, beginning or rolling back transactions,
+    /// as well as non-transactional commits made only of upserts and deletions of complete keys.
+    /// Other commits (such as inserts, or puts of entities with incomplete keys) and ID allocations are not retried.
+    ///
+    /// Requests made within a transaction are not retried on their own:
+    /// `run_in_transaction` runs the whole transaction again instead, up to the same number of attempts.
+    pub fn retry(mut self, config: RetryConfig) -> Client {
+        self.retry = config;
+        self
+    }
+
+    /// The partition the keys and queries of the client belong to, unless given another namespace.
+    pub(crate) fn partition(&self) -> Partition<'_> {
+        Partition {
+            project_name: self.project_name.as_str(),
+            database_id: self.database_id.as_str(),
+            namespace: self.namespace.as_deref(),
+        }
+    }
+
+    /// Gets an entity from a key.
+    pub async fn get<T, K>(&mut self, key: K) -> Result<Option<T>, Error>
+    where
+        K: Borrow<Key>,
+        T: FromValue,
+    {
+        let results = self.get_all(Some(key.borrow())).await?;
+        Ok(results.into_iter().next())
+    }
+
+    /// Checks whether an entity exists under a key, without decoding its properties.
+    ///
+    /// This looks the key up like `Client::get` does, so it is strongly consistent (an entity which was
+    /// just written is found), but the properties of the entity are never converted.
+    /// Incomplete keys never name an existing entity.
+    ///
+    /// ```no_run
+    /// # use google_cloud::datastore::{Client, Error, Key};
+    /// # async fn run(mut client: Client) -> Result<(), Error> {
+    /// if !client.exists(Key::new("User").id("alice")).await? {
+    ///     println!("alice is not registered yet");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn exists(&mut self, key: impl Borrow<Key>) -> Result<bool, Error> {
+        let key = key.borrow();
+        if key.is_incomplete() {
+            return Ok(false);
+        }
+        let mut keys = vec![convert_key(self.partition(), key)];
+        //? The key is either found, missing, or deferred (in which case it is looked up again).
+        while !keys.is_empty() {
+            let request = api::LookupRequest {
+                keys,
+                project_id: self.project_name.clone(),
+                database_id: self.database_id.clone(),
+                read_options: None,
+            };
+            let response = self
+                .send(request, true, |mut service, request| async move {
+                    service.lookup(request).await
+                })
+                .await;
+            let response = response.context("Lookup", Keys::new(std::slice::from_ref(key)))?;
+            if !response.found.is_empty() {
+                return Ok(true);
+            }
+            keys = response.deferred;
+        }
+
+        Ok(false)
+    }
+
+    /// Gets multiple entities from multiple keys.
+    pub async fn get_all<T, K, I>(&mut self, keys: I) -> Result<Vec<T>, Error>
+    where
+        I: IntoIterator<Item = K>,
+        K: Borrow<Key>,
+        T: FromValue,
+    {
+        self.lookup(keys, None).await
+    }
+
+    /// Gets multiple entities from multiple keys, by key.
+    ///
+    /// The results of `Client::get_all` skip the missing entities, so they can't be matched to their keys:
+    /// here, every entity found is associated with the key it was requested with, and missing ones are left out.
+    /// The values are decoded along with their keys (see `FromValue::from_entity_with`).
+    ///
+    /// ```no_run
+    /// # use std::collections::HashMap;
+    /// # use google_cloud::datastore::{Client, Error, Key, Value};
+    /// # async fn run(mut client: Client) -> Result<(), Error> {
+    /// let keys = vec![Key::new("User").id("alice"), Key::new("User").id("bob")];
+    /// let users: HashMap<Key, HashMap<String, Value>> = client.get_map(&keys).await?;
+    /// for key in keys.iter().filter(|key| !users.contains_key(key)) {
+    ///     println!("missing user: {:?}", key.get_id());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_map<T, K, I>(&mut self, keys: I) -> Result<HashMap<Key, T>, Error>
+    where
+        I: IntoIterator<Item = K>,
+        K: Borrow<Key>,
+        T: FromValue,
+    {
+        let keys: Vec<Key> = keys.into_iter().map(|key| key.borrow().clone()).collect();
+        //? Keys get the default namespace to match the entities read back, but the map uses them as requested.
+        let partition = self.partition();
+        let scoped: Vec<Key> = keys.iter().map(|key| partition.scope(key)).collect();
+        let mut found = self.lookup_found(&scoped, None).await?;
+
+        let mut values = HashMap::with_capacity(found.len());
+        for (key, scoped) in keys.into_iter().zip(scoped.iter()) {
+            if let Some((value, _)) = found.remove(scoped) {
+                let value = T::from_entity_with(scoped, value, &self.decode_options)?;
+                values.insert(key, value);
+            }
+        }
+
+        Ok(values)
+    }
+
+    /// Gets an entity from a key, along with its metadata (version and update time).
+    ///
+    /// ```no_run
+    /// # use std::collections::HashMap;
+    /// # use google_cloud::datastore::{Client, Error, Key, Value};
+    /// # async fn run(mut client: Client, cached_version: i64) -> Result<(), Error> {
+    /// let key = Key::new("User").id("alice");
+    /// let user: Option<(HashMap<String, Value>, _)> = client.get_with_meta(&key).await?;
+    /// if let Some((user, meta)) = user {
+    ///     if meta.version() != cached_version {
+    ///         println!("alice changed at {:?}: {:?}", meta.update_time(), user);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_with_meta<T, K>(&mut self, key: K) -> Result<Option<(T, EntityMeta)>, Error>
+    where
+        K: Borrow<Key>,
+        T: FromValue,
+    {
+        let results = self.get_all_with_meta(Some(key.borrow())).await?;
+        Ok(results.into_iter().next())
+    }
+
+    /// Gets multiple entities from multiple keys, along with their metadata (version and update time).
+    pub async fn get_all_with_meta<T, K, I>(
+        &mut self,
+        keys: I,
+    ) -> Result<Vec<(T, EntityMeta)>, Error>
+    where
+        I: IntoIterator<Item = K>,
+        K: Borrow<Key>,
+        T: FromValue,
+    {
+        self.lookup_with_meta(keys, None).await
+    }
+
+    /// Gets an entity from a key, as it was at the given time (in UTC).
+    ///
+    /// The time must be within the retention period of the database (the past hour,
+    /// or the past 7 days at whole minutes with point-in-time recovery enabled).
+    ///
+    /// ```no_run
+    /// # use std::collections::HashMap;
+    /// # use google_cloud::datastore::{Client, Error, Key, Value};
+    /// # async fn run(mut client: Client) -> Result<(), Error> {
+    /// let key = Key::new("User").id("alice");
+    /// let an_hour_ago = chrono::Utc::now().naive_utc() - chrono::Duration::minutes(59);
+    /// let before: Option<HashMap<String, Value>> = client.get_at(&key, an_hour_ago).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_at<T, K>(
+        &mut self,
+        key: K,
+        read_time: NaiveDateTime,
+    ) -> Result<Option<T>, Error>
+    where
+        K: Borrow<Key>,
+        T: FromValue,
+    {
+        let results = self.get_all_at(Some(key.borrow()), read_time).await?;
+        Ok(results.into_iter().next())
+    }
+
+    /// Gets multiple entities from multiple keys, as they were at the given time (in UTC).
+    ///
+    /// All the entities are read from the same snapshot, which makes them consistent with each other.
+    pub async fn get_all_at<T, K, I>(
+        &mut self,
+        keys: I,
+        read_time: NaiveDateTime,
+    ) -> Result<Vec<T>, Error>
+    where
+        I: IntoIterator<Item = K>,
+        K: Borrow<Key>,
+        T: FromValue,
+    {
+        self.get_all_with(keys, ReadConsistency::ReadTime(read_time))
+            .await
+    }
+
+    /// Gets an entity from a key, reading with the given consistency.
+    pub async fn get_with<T, K>(
+        &mut self,
+        key: K,
+        consistency: ReadConsistency,
+    ) -> Result<Option<T>, Error>
+    where
+        K: Borrow<Key>,
+        T: FromValue,
+    {
+        let results = self.get_all_with(Some(key.borrow()), consistency).await?;
+        Ok(results.into_iter().next())
+    }
+
+    /// Gets multiple entities from multiple keys, reading with the given consistency.
+    pub async fn get_all_with<T, K, I>(
+        &mut self,
+        keys: I,
+        consistency: ReadConsistency,
+    ) -> Result<Vec<T>, Error>
+    where
+        I: IntoIterator<Item = K>,
+        K: Borrow<Key>,
+        T: FromValue,
+    {
+        let read_options = consistency.into_api();
+        let results = self.lookup_with_meta(keys, Some(read_options)).await?;
+        Ok(results.into_iter().map(|(value, _)| value).collect())
+    }
+
+    /// Gets multiple entities from multiple keys, reading within the given transaction, if any.
+    pub(crate) async fn lookup<T, K, I>(
+        &mut self,
+        keys: I,
+        transaction: Option<&[u8]>,
+    ) -> Result<Vec<T>, Error>
+    where
+        I: IntoIterator<Item = K>,
+        K: Borrow<Key>,
+        T: FromValue,
+    {
+        let read_options = transaction.map(transaction_read_options);
+        let results = self.lookup_with_meta(keys, read_options).await?;
+        Ok(results.into_iter().map(|(value, _)| value).collect())
+    }
+
+    /// Gets multiple entities from multiple keys along with their metadata,
+    /// using the given read options (or strongly consistent reads, if none).
+    pub(crate) async fn lookup_with_meta<T, K, I>(
+        &mut self,
+        keys: I,
+        read_options: Option<api::ReadOptions>,
+    ) -> Result<Vec<(T, EntityMeta)>, Error>
+    where
+        I: IntoIterator<Item = K>,
+        K: Borrow<Key>,
+        T: FromValue,
+    {
+        //? Keys get the default namespace, to match the keys of the entities read back.
+        let partition = self.partition();
+        let og_keys: Vec<Key> = keys
+            .into_iter()
+            .map(|key| partition.scope(key.borrow()))
+            .collect();
+        let mut found = self.lookup_found(&og_keys, read_options).await?;
+
+        let values: Vec<(T, EntityMeta)> = og_keys
+            .iter()
+            .flat_map(|key| found.remove(key))
+            .map(|(value, meta)| Ok((T::from_value_with(value, &self.decode_options)?, meta)))
+            .collect::<Result<Vec<_>, ConvertError>>()?;
+
+        Ok(values)
+    }
+
+    /// Looks up the given (scoped) keys, returning the entities found along with their metadata, by key.
+    pub(crate) async fn lookup_found(
+        &mut self,
+        og_keys: &[Key],
+        read_options: Option<api::ReadOptions>,
+    ) -> Result<HashMap<Key, (Value, EntityMeta)>, Error> {
+        //? Lookups of too many keys are split, and the chunks looked up concurrently.
+        //? The chunks and futures own their data, so that no borrow is held across the lookups
+        //? (which would keep the futures of transactions from being `Send`).
+        let lookups: Vec<_> = split_chunks(og_keys.to_vec(), Client::MAX_LOOKUP_KEYS)
+            .into_iter()
+            .map(|keys| {
+                let mut client = self.clone();
+                let read_options = read_options.clone();
+                async move { client.lookup_chunk(&keys, read_options.as_ref()).await }
+            })
+            .collect();
+        let results: Vec<_> = stream::iter(lookups)
+            .buffered(self.concurrency)
+            .collect()
+            .await;
+        let mut found = HashMap::new();
+        for result in results {
+            found.extend(result?);
+        }
+
+        Ok(found)
+    }
+
+    /// Looks up the given (scoped) keys in a single request, following up on the deferred ones.
+    async fn lookup_chunk(
+        &mut self,
+        og_keys: &[Key],
+        read_options: Option<&api::ReadOptions>,
+    ) -> Result<HashMap<Key, (Value, EntityMeta)>, Error> {
+        let partition = self.partition();
+        let mut keys: Vec<_> = og_keys
+            .iter()
+            .map(|key| convert_key(partition, key))
+            .collect();
+        let mut found = HashMap::new();
+        let transactional = matches!(
+            read_options.and_then(|options| options.consistency_type.as_ref()),
+            Some(api::read_options::ConsistencyType::Transaction(_))
+        );
+
+        while !keys.is_empty() {
+            let request = api::LookupRequest {
+                keys,
+                project_id: self.project_name.clone(),
+                database_id: self.database_id.clone(),
+                read_options: read_options.cloned(),
+            };
+            let response = self
+                .send(request, !transactional, |mut service, request| async move {
+                    service.lookup(request).await
+                })
+                .await;
+            let response = response.context("Lookup", Keys::new(og_keys))?;
+
+            for result in response.found {
+                let meta = EntityMeta::from_api(&result)?;
+                let entity = convert_entity_result(result)?;
+                found.insert(entity.key, (entity.properties, meta));
+            }
+            // let missing = response.missing;
+            keys = response.deferred;
+        }
+
+        Ok(found)
+    }
+
+    /// Inserts a new entity and returns its key.
+    /// If the entity's key is incomplete, the returned key will be one generated by the store for this entity.
+    pub async fn put(&mut self, entity: impl IntoEntity) -> Result<Option<Key>, Error> {
+        let entity = entity.into_entity()?;
+        let result = self.put_all(Some(entity)).await?;
+        Ok(result.into_iter().next().flatten())
+    }
+
+    /// Inserts new entities and returns their keys.
+    /// If an entity's key is incomplete, its returned key will be one generated by the store for this entity.
+    pub async fn put_all<T, I>(&mut self, entities: I) -> Result<Vec<Option<Key>>, Error>
+    where
+        I: IntoIterator<Item = T>,
+        T: IntoEntity,
+    {
+        self.write_all(entities, WriteMode::Put).await
+    }
+
+    /// Inserts a new entity and returns its key, failing if an entity already exists under that key.
+    ///
+    /// ```no_run
+    /// # use std::collections::HashMap;
+    /// # use google_cloud::datastore::{Client, Error, Key};
+    /// # async fn run(mut client: Client) -> Result<(), Error> {
+    /// let entity = (Key::new("User").id("alice"), HashMap::<String, i64>::new());
+    /// match client.insert(entity).await {
+    ///     Ok(_) => println!("registered alice"),
+    ///     Err(err) if err.status().map(|status| status.code()) == Some(tonic::Code::AlreadyExists) => {
+    ///         println!("alice is already registered")
+    ///     }
+    ///     Err(err) => return Err(err),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// If the entity's key is incomplete, the returned key will be one generated by the store for this entity.
+    pub async fn insert(&mut self, entity: impl IntoEntity) -> Result<Option<Key>, Error> {
+        let result = self.insert_all(Some(entity)).await?;
+        Ok(result.into_iter().next().flatten())
+    }
+
+    /// Inserts new entities and returns their keys, failing with an `ALREADY_EXISTS` status
+    /// (and inserting none of them) if an entity already exists under any of their keys.
+    pub async fn insert_all<T, I>(&mut self, entities: I) -> Result<Vec<Option<Key>>, Error>
+    where
+        I: IntoIterator<Item = T>,
+        T: IntoEntity,
+    {
+        self.write_all(entities, WriteMode::Insert).await
+    }
+
+    /// Updates an existing entity, failing with a `NOT_FOUND` status if there is no entity under its key.
+    pub async fn update(&mut self, entity: impl IntoEntity) -> Result<(), Error> {
+        self.update_all(Some(entity)).await
+    }
+
+    /// Updates existing entities, failing with a `NOT_FOUND` status
+    /// (and updating none of them) if there is no entity under any of their keys.
+    pub async fn update_all<T, I>(&mut self, entities: I) -> Result<(), Error>
+    where
+        I: IntoIterator<Item = T>,
+        T: IntoEntity,
+    {
+        self.write_all(entities, WriteMode::Update).await?;
+        Ok(())
+    }
+
+    /// Stores an entity, whether an entity already exists under its key or not, and returns its key.
+    ///
+    /// If the entity's key is incomplete, the returned key will be one generated by the store for this entity.
+    pub async fn upsert(&mut self, entity: impl IntoEntity) -> Result<Option<Key>, Error> {
+        let result = self.upsert_all(Some(entity)).await?;
+        Ok(result.into_iter().next().flatten())
+    }
+
+    /// Stores entities, whether entities already exist under their keys or not, and returns their keys.
+    pub async fn upsert_all<T, I>(&mut self, entities: I) -> Result<Vec<Option<Key>>, Error>
+    where
+        I: IntoIterator<Item = T>,
+        T: IntoEntity,
+    {
+        self.write_all(entities, WriteMode::Upsert).await
+    }
+
+    /// Stores entities non-transactionally, using the given write operation.
+    async fn write_all<T, I>(
+        &mut self,
+        entities: I,
+        mode: WriteMode,
+    ) -> Result<Vec<Option<Key>>, Error>
+    where
+        I: IntoIterator<Item = T>,
+        T: IntoEntity,
+    {
+        let mutations = write_mutations(self.partition(), entities, mode)?;
+        let response = self.commit_chunked(mutations).await?;
+
+        Ok(result_keys(response.mutation_results)?)
+    }
+
+    /// Deletes an entity identified by a key.
+    pub async fn delete(&mut self, key: impl Borrow<Key>) -> Result<(), Error> {
+        self.delete_all(Some(key.borrow())).await?;
+        Ok(())
+    }
+
+    /// Deletes multiple entities identified by multiple keys, returning the results of the deletes in the same order.
+    pub async fn delete_all<T, I>(&mut self, keys: I) -> Result<Vec<MutationResult>, Error>
+    where
+        I: IntoIterator<Item = T>,
+        T: Borrow<Key>,
+    {
+        let results = self.delete_all_with_meta(keys).await?;
+        Ok(results.into_mutation_results())
+    }
+
+    /// Deletes multiple entities identified by multiple keys, returning the results of the deletes in the same order,
+    /// along with the number of index entries the deletes updated.
+    ///
+    /// ```no_run
+    /// # use google_cloud::datastore::{Client, Error, Key};
+    /// # async fn run(mut client: Client) -> Result<(), Error> {
+    /// let keys = vec![Key::new("Session").id("abc"), Key::new("Session").id("def")];
+    /// let results = client.delete_all_with_meta(keys).await?;
+    /// println!("{} index entries updated", results.index_updates());
+    /// for result in results.mutation_results() {
+    ///     println!("{:?} deleted at version {}", result.key().get_id(), result.version());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete_all_with_meta<T, I>(&mut self, keys: I) -> Result<CommitResults, Error>
+    where
+        I: IntoIterator<Item = T>,
+        T: Borrow<Key>,
+    {
+        let keys: Vec<Key> = keys.into_iter().map(|key| key.borrow().clone()).collect();
+        let mutations = delete_mutations(self.partition(), &keys);
+        let response = self.commit_chunked(mutations).await?;
+
+        Ok(CommitResults {
+            mutation_results: MutationResult::zip(keys, response.mutation_results),
+            index_updates: response.index_updates,
+        })
+    }
+
+    /// Deletes an entity, unless it changed since it was read at the given version (see `EntityMeta::version`).
+    ///
+    /// The entity is left untouched if its version differs, which the result reports as a conflict,
+    /// so that concurrent writes don't get lost:
+    ///
+    /// ```no_run
+    /// # use google_cloud::datastore::{Client, Error, Key, Value};
+    /// # async fn run(mut client: Client) -> Result<(), Error> {
+    /// let key = Key::new("Session").id("abc");
+    /// if let Some((_, meta)) = client.get_with_meta::<Value, _>(&key).await? {
+    ///     let result = client.delete_if_version(&key, meta.version()).await?;
+    ///     if result.conflict_detected() {
+    ///         println!("the session was refreshed in the meantime");
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete_if_version(
+        &mut self,
+        key: impl Borrow<Key>,
+        version: i64,
+    ) -> Result<MutationResult, Error> {
+        use api::mutation::ConflictDetectionStrategy;
+
+        let key = key.borrow().clone();
+        let mut mutations = delete_mutations(self.partition(), Some(&key));
+        for mutation in mutations.iter_mut() {
+            mutation.conflict_detection_strategy =
+                Some(ConflictDetectionStrategy::BaseVersion(version));
+        }
+        let results = self.commit_results(mutations, None).await?;
+        let result = MutationResult::zip(Some(key), results).into_iter().next();
+
+        result.ok_or_else(|| ConvertError::MissingField(String::from("mutation_results")).into())
+    }
+
+    /// Starts a batch of lookups and writes, to execute them in as few round trips as possible.
+    pub fn batch(&self) -> Batch {
+        Batch::new(self.clone())
+    }
+
+    /// Allocates IDs for incomplete keys, returning the completed keys in the same order.
+    ///
+    /// The allocated IDs are reserved and never get handed out again by the store,
+    /// which allows to cross-reference entities from one another before storing them.
+    ///
+    /// ```no_run
+    /// # use google_cloud::datastore::{Client, Error, Key};
+    /// # async fn run(mut client: Client) -> Result<(), Error> {
+    /// let keys = client
+    ///     .allocate_ids(vec![Key::new("Order"), Key::new("Invoice")])
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn allocate_ids<T, I>(&mut self, keys: I) -> Result<Vec<Key>, Error>
+    where
+        I: IntoIterator<Item = T>,
+        T: Borrow<Key>,
+    {
+        let keys: Vec<Key> = keys.into_iter().map(|key| key.borrow().clone()).collect();
+        let request = api::AllocateIdsRequest {
+            project_id: self.project_name.clone(),
+            database_id: self.database_id.clone(),
+            keys: keys
+                .iter()
+                .map(|key| convert_key(self.partition(), key))
+                .collect(),
+        };
+        //? Another attempt would allocate other IDs, so failures are left for the caller to retry.
+        let response = self
+            .send(request, false, |mut service, request| async move {
+                service.allocate_ids(request).await
+            })
+            .await;
+        let response = response.context("AllocateIds", Keys::new(&keys))?;
+        let keys = response
+            .keys
+            .into_iter()
+            .map(Key::try_from)
+            .collect::<Result<Vec<Key>, ConvertError>>()?;
+
+        Ok(keys)
+    }
+
+    /// Reserves the IDs of complete keys, so that the store never allocates them to other entities.
+    ///
+    /// This is meant for importing entities which already have numeric IDs (like during a migration),
+    /// which could otherwise collide with the IDs generated for incomplete keys later on.
+    ///
+    /// ```no_run
+    /// # use google_cloud::datastore::{Client, Error, Key};
+    /// # async fn run(mut client: Client) -> Result<(), Error> {
+    /// let imported: Vec<Key> = (1..=100_i64).map(|id| Key::new("Order").id(id)).collect();
+    /// client.reserve_ids(&imported).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn reserve_ids<T, I>(&mut self, keys: I) -> Result<(), Error>
+    where
+        I: IntoIterator<Item = T>,
+        T: Borrow<Key>,
+    {
+        let keys: Vec<T> = keys.into_iter().collect();
+        if keys.iter().any(|key| key.borrow().is_incomplete()) {
+            let message = "cannot reserve the ID of an incomplete key";
+            return Err(tonic::Status::invalid_argument(message).into());
+        }
+        let keys: Vec<Key> = keys.iter().map(|key| key.borrow().clone()).collect();
+        let request = api::ReserveIdsRequest {
+            project_id: self.project_name.clone(),
+            database_id: self.database_id.clone(),
+            keys: keys
+                .iter()
+                .map(|key| convert_key(self.partition(), key))
+                .collect(),
+        };
+        let response = self
+            .send(request, true, |mut service, request| async move {
+                service.reserve_ids(request).await
+            })
+            .await;
+        response.context("ReserveIds", Keys::new(&keys))?;
+
+        Ok(())
+    }
+
+    /// Applies mutations non-transactionally, split into as many commits as needed (applied concurrently).
+    ///
+    /// The commits are independent of each other: if one fails, the others may still have been applied.
+    /// Their responses are merged, the mutation results staying in the order of the mutations.
+    pub(crate) async fn commit_chunked(
+        &mut self,
+        mutations: Vec<api::Mutation>,
+    ) -> Result<api::CommitResponse, Error> {
+        if mutations.len() <= Client::MAX_MUTATIONS {
+            return self.commit_response(mutations, None).await;
+        }
+
+        let chunks = split_chunks(mutations, Client::MAX_MUTATIONS);
+        let commits = chunks.into_iter().map(|chunk| {
+            let mut client = self.clone();
+            async move { client.commit_response(chunk, None).await }
+        });
+        let results: Vec<_> = stream::iter(commits)
+            .buffered(self.concurrency)
+            .collect()
+            .await;
+        let mut merged = api::CommitResponse::default();
+        for result in results {
+            let response = result?;
+            merged.mutation_results.extend(response.mutation_results);
+            merged.index_updates += response.index_updates;
+        }
+
+        Ok(merged)
+    }
+
+    /// Applies mutations, either within the given transaction (committing it) or non-transactionally.
+    ///
+    /// Returns the keys of the mutated entities, when allocated by the store.
+    pub(crate) async fn commit(
+        &mut self,
+        mutations: Vec<api::Mutation>,
+        transaction: Option<Vec<u8>>,
+    ) -> Result<Vec<Option<Key>>, Error> {
+        let results = self.commit_results(mutations, transaction).await?;
+        Ok(result_keys(results)?)
+    }
+
+    /// Applies mutations like `commit`, returning the results of the mutations in the same order.
+    pub(crate) async fn commit_results(
+        &mut self,
+        mutations: Vec<api::Mutation>,
+        transaction: Option<Vec<u8>>,
+    ) -> Result<Vec<api::MutationResult>, Error> {
+        let response = self.commit_response(mutations, transaction).await?;
+        Ok(response.mutation_results)
+    }
+
+    /// Applies mutations like `commit`, returning the whole response of the commit.
+    async fn commit_response(
+        &mut self,
+        mutations: Vec<api::Mutation>,
+        transaction: Option<Vec<u8>>,
+    ) -> Result<api::CommitResponse, Error> {
+        use api::commit_request::{Mode, TransactionSelector};
+
+        let transactional = transaction.is_some();
+        let mode = match transactional {
+            true => Mode::Transactional,
+            false => Mode::NonTransactional,
+        };
+        let keys = Keys::of_mutations(&mutations);
+        let retryable = !transactional && mutations.iter().all(is_idempotent);
+        let request = api::CommitRequest {
+            mutations,
+            mode: mode as i32,
+            transaction_selector: transaction.map(TransactionSelector::Transaction),
+            project_id: self.project_name.clone(),
+            database_id: self.database_id.clone(),
+        };
+        let response = self
+            .send(request, retryable, |mut service, request| async move {
+                service.commit(request).await
+            })
+            .await;
+        let response = response.context("Commit", keys)?;
+
+        Ok(response)
+    }
+
+    /// Lists the kinds of the entities stored within the client's namespace (see `Client::namespace`), in order.
+    ///
+    /// The internal kinds (starting with `__`, such as the ones of the statistics) are left out.
+    ///
+    /// ```no_run
+    /// # use google_cloud::datastore::{Client, Error};
+    /// # async fn run(client: Client) -> Result<(), Error> {
+    /// let mut client = client.namespace("staging");
+    /// for kind in client.list_kinds().await? {
+    ///     println!("{}", kind);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_kinds(&mut self) -> Result<Vec<String>, Error> {
+        let query = Query::new(Client::KIND_METADATA_KIND).keys_only();
+        let entities = self.query(query).await?;
+        let kinds = entities
+            .into_iter()
+            .filter_map(|entity| match entity.into_key().id {
+                KeyID::StringID(kind) => Some(kind),
+                _ => None,
+            })
+            .filter(|kind| !kind.starts_with("__"));
+
+        Ok(kinds.collect())
+    }
+
+    /// Lists the namespaces of the project holding entities, in order, the default namespace being an empty string.
+    pub async fn list_namespaces(&mut self) -> Result<Vec<String>, Error> {
+        //? Namespaces are listed from the default namespace, whatever the client's one.
+        let query = Query::new(Client::NAMESPACE_METADATA_KIND)
+            .keys_only()
+            .namespace("");
+        let entities = self.query(query).await?;
+        let namespaces = entities
+            .into_iter()
+            .filter_map(|entity| match entity.into_key().id {
+                KeyID::StringID(namespace) => Some(namespace),
+                //? The default namespace is the one with a numeric ID (of 1).
+                KeyID::IntID(_) => Some(String::new()),
+                KeyID::Incomplete => None,
+            });
+
+        Ok(namespaces.collect())
+    }
+
+    /// Runs a (potentially) complex query againt Datastore and returns the results.
+    pub async fn query(&mut self, query: Query) -> Result<Vec<Entity>, Error> {
+        let results = self.query_with_meta(query).await?;
+        Ok(results.into_entities())
+    }
+
+    /// Runs a (potentially) complex query againt Datastore and decodes the properties of the results.
+    ///
+    /// The values are decoded along with the keys of their entities (see `FromValue::from_entity_with`),
+    /// so that the `#[datastore(key)]` fields of the derived implementations get restored from them.
+    ///
+    /// This is especially suited to projection queries, whose results only hold the projected properties:
+    ///
+    /// ```no_run
+    /// # use chrono::NaiveDateTime;
+    /// # use google_cloud::datastore::{Client, Error, FromValue, Query, Value};
+    /// # use google_cloud::error::ConvertError;
+    /// struct TitleRow {
+    ///     title: String,
+    ///     created: NaiveDateTime,
+    /// }
+    ///
+    /// impl FromValue for TitleRow {
+    ///     fn from_value(value: Value) -> Result<TitleRow, ConvertError> {
+    ///         let mut properties: std::collections::HashMap<String, Value> = FromValue::from_value(value)?;
+    ///         let mut property = |name: &str| {
+    ///             properties
+    ///                 .remove(name)
+    ///                 .ok_or_else(|| ConvertError::MissingProperty(String::from(name)))
+    ///         };
+    ///         Ok(TitleRow {
+    ///             title: FromValue::from_value(property("title")?)?,
+    ///             created: FromValue::from_value(property("created")?)?,
+    ///         })
+    ///     }
+    /// }
+    ///
+    /// # async fn run(mut client: Client) -> Result<(), Error> {
+    /// let query = Query::new("Post").project(&["title", "created"]);
+    /// let rows = client.query_as::<TitleRow>(query).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn query_as<T: FromValue>(&mut self, query: Query) -> Result<Vec<T>, Error> {
+        let entities = self.query(query).await?;
+        let values = entities
+            .into_iter()
+            .map(|entity| T::from_entity_with(&entity.key, entity.properties, &self.decode_options))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(values)
+    }
+
+    /// Runs a (potentially) complex query against Datastore, as a stream of its results.
+    ///
+    /// Results are fetched batch by batch as the stream gets consumed, without buffering the whole result set,
+    /// which makes it suitable for queries matching a large number of entities.
+    /// The stream ends after the first error, or once the token of the client gets cancelled (see `Client::cancel_on`).
+    pub fn query_stream(&self, query: Query) -> impl Stream<Item = Result<Entity, Error>> {
+        self.query_batches(query).flat_map(|batch| {
+            let entities = match batch {
+                Ok(batch) => batch.entities.into_iter().map(Ok).collect(),
+                Err(err) => vec![Err(err)],
+            };
+            stream::iter(entities)
+        })
+    }
+
+    /// Runs a (potentially) complex query against Datastore, as a stream of the batches of its results,
+    /// along with their metadata (see `Client::query_with_meta`).
+    ///
+    /// The metadata of every batch only describes that batch: its `skipped_results` are the ones skipped
+    /// by that batch, which may take several batches for large offsets, and its `end_cursor` allows
+    /// to resume the query right after it.
+    /// The stream ends after the first error, or once the token of the client gets cancelled (see `Client::cancel_on`).
+    pub fn query_batches(&self, query: Query) -> impl Stream<Item = Result<QueryResults, Error>> {
+        self.run_query_batches(query, None)
+    }
+
+    /// Streams the batches of results of a query, within the given transaction (if any).
+    pub(crate) fn run_query_batches(
+        &self,
+        query: Query,
+        transaction: Option<Vec<u8>>,
+    ) -> impl Stream<Item = Result<QueryResults, Error>> {
+        let state = QueryStreamState {
+            client: self.clone(),
+            transaction,
+            next_query: query.clone(),
+            paging: QueryPaging::new(query),
+        };
+        let batches = stream::unfold(Some(state), |state| async move {
+            let mut state = state?;
+            let batch = state.next_query.clone();
+            let transaction = state.transaction.as_deref();
+            let results = match state.client.run_query_batch(batch, transaction).await {
+                Ok(results) => results,
+                Err(err) => return Some((Err(err), None)),
+            };
+            let next_query = state.paging.advance(&results);
+            let results = match convert_query_batch(results, next_query.is_none()) {
+                Ok(results) => results,
+                Err(err) => return Some((Err(err.into()), None)),
+            };
+            match next_query {
+                Some(next_query) => {
+                    state.next_query = next_query;
+                    Some((Ok(results), Some(state)))
+                }
+                None => Some((Ok(results), None)),
+            }
+        });
+        cancel::until_cancelled(batches, self.cancel.clone())
+    }
+
+    /// Runs a (potentially) complex query againt Datastore and returns the results,
+    /// along with metadata about them (skipped results, snapshot version and whether more results are available).
+    pub async fn query_with_meta(&mut self, query: Query) -> Result<QueryResults, Error> {
+        self.run_query(query, None, None).await
+    }
+
+    /// Explains how Datastore runs a query, such as the indexes it uses, to investigate slow or costly queries.
+    ///
+    /// The metrics are available through `QueryResults::explain_metrics`.
+    /// Unless the options ask to analyze the query, it is only planned and no results are returned.
+    ///
+    /// ```no_run
+    /// # use google_cloud::datastore::{Client, Error, ExplainOptions, Query};
+    /// # async fn run(mut client: Client) -> Result<(), Error> {
+    /// let query = Query::new("Task").limit(100);
+    /// let results = client.explain_query(query, ExplainOptions::new().analyze(true)).await?;
+    /// if let Some(stats) = results.explain_metrics().and_then(|metrics| metrics.execution_stats()) {
+    ///     println!("{} read operations in {:?}", stats.read_operations(), stats.execution_duration());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn explain_query(
+        &mut self,
+        query: Query,
+        options: ExplainOptions,
+    ) -> Result<QueryResults, Error> {
+        self.run_query(query, None, Some(options)).await
+    }
+
+    /// Runs an aggregation query against Datastore, computed server-side without fetching the matched entities.
+    ///
+    /// ```no_run
+    /// # use google_cloud::datastore::{Client, Error, Filter, IntoValue, Query};
+    /// # async fn run(mut client: Client) -> Result<(), Error> {
+    /// let query = Query::new("Order")
+    ///     .filter(Filter::Equal("status".into(), "paid".into_value()))
+    ///     .count()
+    ///     .sum("amount")
+    ///     .avg("amount");
+    /// let results = client.aggregate(query).await?;
+    /// println!("{:?} orders, {:?} on average", results.count(), results.avg("amount"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn aggregate(
+        &mut self,
+        query: AggregationQuery,
+    ) -> Result<AggregationResults, Error> {
+        self.run_aggregation_query(query, None).await
+    }
+
+    /// Counts the results of a query, without fetching the matched entities.
+    ///
+    /// The results are counted server-side with a count aggregation (see `Client::aggregate`).
+    /// Where aggregations are not available (such as with older emulators), they are counted
+    /// from a keys-only version of the query instead, paginated through internally.
+    ///
+    /// ```no_run
+    /// # use google_cloud::datastore::{Client, Error, Filter, IntoValue, Query};
+    /// # async fn run(mut client: Client) -> Result<(), Error> {
+    /// let query = Query::new("Order").filter(Filter::Equal("status".into(), "paid".into_value()));
+    /// println!("{} paid orders", client.count(query).await?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn count(&mut self, query: Query) -> Result<i64, Error> {
+        match self.aggregate(query.clone().count()).await {
+            Ok(results) => {
+                let count = results.count();
+                Ok(count.ok_or_else(|| ConvertError::MissingField(String::from("count")))?)
+            }
+            Err(err)
+                if err.status().map(tonic::Status::code) == Some(tonic::Code::Unimplemented) =>
+            {
+                self.count_keys(query).await
+            }
+            Err(err) => Err(err),
+        }
     }
 
-    /// Creates a new client for the specified project with custom credentials.
-    pub async fn from_credentials(
-        project_name: impl Into<String>,
-        creds: ApplicationCredentials,
-    ) -> Result<Client, Error> {
-        let client_config = ClientConfiguration::new();
-        let mut channel = Channel::from_shared(client_config.endpoint.clone()).unwrap();
-        if client_config.endpoint.starts_with("https://") {
-            let tls_config = ClientTlsConfig::new()
-                .ca_certificate(Certificate::from_pem(TLS_CERTS))
-                .domain_name(Client::DOMAIN_NAME);
-            channel = channel.tls_config(tls_config)?
+    /// Counts the results of a query by paginating through their keys.
+    async fn count_keys(&self, query: Query) -> Result<i64, Error> {
+        //? Projection queries can't be keys-only, but they already fetch only a few properties.
+        let query = if query.projections.is_empty() {
+            query.keys_only()
+        } else {
+            query
+        };
+        let mut batches = Box::pin(self.query_batches(query));
+        let mut count = 0;
+        while let Some(batch) = batches.next().await {
+            count += batch?.entities.len() as i64;
         }
 
-        let channel = channel.connect().await?;
+        Ok(count)
+    }
 
-        Ok(Client {
-            project_name: project_name.into(),
-            service: DatastoreClient::new(channel),
-            token_manager: Arc::new(Mutex::new(TokenManager::new(
-                creds,
-                Client::SCOPES.as_ref(),
-            ))),
-        })
+    /// Begins a new read-write transaction.
+    ///
+    /// Prefer `run_in_transaction`, which also takes care of committing, rolling back and retrying the transaction.
+    pub async fn begin_transaction(&mut self) -> Result<Transaction, Error> {
+        self.begin(Vec::new()).await
     }
 
-    /// Gets an entity from a key.
-    pub async fn get<T, K>(&mut self, key: K) -> Result<Option<T>, Error>
+    /// Runs a closure within a transaction, committing its writes atomically once it returns successfully.
+    ///
+    /// The transaction is rolled back if the closure fails.
+    /// If the transaction conflicts with another one (or the service is unavailable), the closure is run again
+    /// in a new transaction, after backing off and up to the attempts allowed by `Client::retry`,
+    /// so it should not have side effects outside of the transaction.
+    ///
+    /// ```no_run
+    /// # use std::collections::HashMap;
+    /// # use google_cloud::datastore::{Client, Entity, Error, Key};
+    /// # async fn run(mut client: Client) -> Result<(), Error> {
+    /// let key = Key::new("Counter").id("visits");
+    /// let visits = client
+    ///     .run_in_transaction(|tx| {
+    ///         let key = key.clone();
+    ///         Box::pin(async move {
+    ///             let counter: Option<HashMap<String, i64>> = tx.get(&key).await?;
+    ///             let visits = counter.and_then(|counter| counter.get("visits").copied()).unwrap_or(0) + 1;
+    ///             let mut counter = HashMap::new();
+    ///             counter.insert(String::from("visits"), visits);
+    ///             tx.put(Entity::new(key, counter)?)?;
+    ///             Ok(visits)
+    ///         })
+    ///     })
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn run_in_transaction<T, F>(&mut self, mut f: F) -> Result<T, Error>
     where
-        K: Borrow<Key>,
-        T: FromValue,
+        F: for<'a> FnMut(&'a mut Transaction) -> BoxFuture<'a, Result<T, Error>>,
     {
-        let results = self.get_all(Some(key.borrow())).await?;
-        Ok(results.into_iter().next().map(T::from_value).transpose()?)
+        let mut previous = Vec::new();
+        let mut attempt = 1;
+        loop {
+            let mut tx = self.begin(previous).await?;
+            let result = match f(&mut tx).await {
+                Ok(value) => {
+                    let id = tx.id.clone();
+                    tx.commit().await.map(|_| value).map_err(|err| (err, id))
+                }
+                Err(err) => {
+                    let id = tx.id.clone();
+                    //? The closure's error is more relevant than a failure to roll back.
+                    let _ = tx.rollback().await;
+                    Err((err, id))
+                }
+            };
+            match result {
+                Ok(value) => return Ok(value),
+                Err((err, id)) if self.retry.should_retry(attempt, &err) => {
+                    tokio::time::sleep(self.retry.backoff(attempt)).await;
+                    previous = id;
+                    attempt += 1;
+                }
+                Err((err, _)) => return Err(err),
+            }
+        }
     }
 
-    /// Gets multiple entities from multiple keys.
-    pub async fn get_all<T, K, I>(&mut self, keys: I) -> Result<Vec<T>, Error>
-    where
-        I: IntoIterator<Item = K>,
-        K: Borrow<Key>,
-        T: FromValue,
-    {
-        let og_keys: Vec<K> = keys.into_iter().collect();
-        let mut keys: Vec<_> = og_keys
-            .iter()
-            .map(|key| convert_key(self.project_name.as_str(), key.borrow()))
-            .collect();
-        let mut found = HashMap::new();
+    /// Begins a new read-only transaction.
+    ///
+    /// All of its reads observe the same consistent snapshot of the store,
+    /// which makes it suitable for combining the results of several `get_all` and `query` calls.
+    pub async fn begin_read_only_transaction(&mut self) -> Result<ReadOnlyTransaction, Error> {
+        use api::transaction_options::{Mode, ReadOnly};
 
-        while !keys.is_empty() {
-            let request = api::LookupRequest {
-                keys,
-                project_id: self.project_name.clone(),
-                read_options: None,
-            };
-            let request = self.construct_request(request).await?;
-            let response = self.service.lookup(request).await?;
-            let response = response.into_inner();
+        let id = self.begin_with_mode(Mode::ReadOnly(ReadOnly {})).await?;
+        Ok(ReadOnlyTransaction::new(self.clone(), id))
+    }
 
-            found.extend(
-                response
-                    .found
-                    .into_iter()
-                    .map(|val| val.entity.unwrap())
-                    .map(Entity::from)
-                    .map(|entity| (entity.key, entity.properties)),
-            );
-            // let missing = response.missing;
-            keys = response.deferred;
+    async fn begin(&mut self, previous_transaction: Vec<u8>) -> Result<Transaction, Error> {
+        use api::transaction_options::{Mode, ReadWrite};
+
+        let mode = Mode::ReadWrite(ReadWrite {
+            previous_transaction,
+        });
+        let id = self.begin_with_mode(mode).await?;
+        Ok(Transaction::new(self.clone(), id))
+    }
+
+    async fn begin_with_mode(
+        &mut self,
+        mode: api::transaction_options::Mode,
+    ) -> Result<Vec<u8>, Error> {
+        let request = api::BeginTransactionRequest {
+            project_id: self.project_name.clone(),
+            database_id: self.database_id.clone(),
+            transaction_options: Some(api::TransactionOptions { mode: Some(mode) }),
+        };
+        let response = self
+            .send(request, true, |mut service, request| async move {
+                service.begin_transaction(request).await
+            })
+            .await?;
+
+        Ok(response.transaction)
+    }
+
+    /// Runs a query, reading within the given transaction, if any.
+    pub(crate) async fn run_query(
+        &mut self,
+        query: Query,
+        transaction: Option<&[u8]>,
+        explain: Option<ExplainOptions>,
+    ) -> Result<QueryResults, Error> {
+        let mut output = Vec::new();
+        let mut skipped_results = 0;
+        let mut skipped_cursor = None;
+        let mut explain_metrics = None;
+
+        let mut paging = QueryPaging::new(query.clone());
+        let mut cur_query = query;
+        loop {
+            let response = self.send_query(cur_query, transaction, explain).await?;
+            //? The metrics come with the last batch of the query.
+            if let Some(metrics) = response.explain_metrics {
+                explain_metrics = Some(ExplainMetrics::try_from(metrics)?);
+            }
+            let results = response
+                .batch
+                .ok_or_else(|| ConvertError::MissingField(String::from("batch")))?;
+            let next_query = paging.advance(&results);
+            let results = convert_query_batch(results, next_query.is_none())?;
+            output.extend(results.entities);
+            skipped_results += results.skipped_results;
+            if results.skipped_cursor.is_some() {
+                skipped_cursor = results.skipped_cursor;
+            }
+
+            match next_query {
+                Some(next_query) => cur_query = next_query,
+                None => {
+                    break Ok(QueryResults {
+                        entities: output,
+                        skipped_results,
+                        snapshot_version: results.snapshot_version,
+                        more_results: results.more_results,
+                        end_cursor: results.end_cursor,
+                        skipped_cursor,
+                        explain_metrics,
+                    });
+                }
+            }
         }
+    }
 
-        let values: Vec<T> = og_keys
-            .into_iter()
-            .flat_map(|key| found.remove(key.borrow()))
-            .map(FromValue::from_value)
-            .collect::<Result<Vec<_>, _>>()?;
+    /// Runs a query, returning a single batch of results.
+    async fn run_query_batch(
+        &mut self,
+        query: Query,
+        transaction: Option<&[u8]>,
+    ) -> Result<api::QueryResultBatch, Error> {
+        let response = self.send_query(query, transaction, None).await?;
+        let results = response
+            .batch
+            .ok_or_else(|| ConvertError::MissingField(String::from("batch")))?;
 
-        Ok(values)
+        Ok(results)
     }
 
-    /// Inserts a new entity and returns its key.
-    /// If the entity's key is incomplete, the returned key will be one generated by the store for this entity.
-    pub async fn put(&mut self, entity: impl IntoEntity) -> Result<Option<Key>, Error> {
-        let entity = entity.into_entity()?;
-        let result = self.put_all(Some(entity)).await?;
-        Ok(result.into_iter().next().flatten())
+    async fn send_query(
+        &mut self,
+        query: Query,
+        transaction: Option<&[u8]>,
+        explain: Option<ExplainOptions>,
+    ) -> Result<api::RunQueryResponse, Error> {
+        let kind = query.kind.clone();
+        let request = api::RunQueryRequest {
+            partition_id: Some(self.query_partition(query.namespace.clone())),
+            read_options: Some(query_read_options(&query, transaction)),
+            query_type: Some(api::run_query_request::QueryType::Query(
+                self.convert_query(query)?,
+            )),
+            project_id: self.project_name.clone(),
+            database_id: self.database_id.clone(),
+            explain_options: explain.map(ExplainOptions::into_api),
+        };
+        let response = self
+            .send(
+                request,
+                transaction.is_none(),
+                |mut service, request| async move { service.run_query(request).await },
+            )
+            .await;
+
+        response.context("RunQuery", format_args!("Kind={}", kind))
     }
 
-    /// Inserts new entities and returns their keys.
-    /// If an entity's key is incomplete, its returned key will be one generated by the store for this entity.
-    pub async fn put_all<T, I>(&mut self, entities: I) -> Result<Vec<Option<Key>>, Error>
-    where
-        I: IntoIterator<Item = T>,
-        T: IntoEntity,
-    {
-        let entities: Vec<Entity> = entities
-            .into_iter()
-            .map(IntoEntity::into_entity)
-            .collect::<Result<_, _>>()?;
-        let mutations = entities
-            .into_iter()
-            .map(|entity| {
-                let is_incomplete = entity.key.is_incomplete();
-                let entity = convert_entity(self.project_name.as_str(), entity);
-                api::Mutation {
-                    operation: if is_incomplete {
-                        Some(api::mutation::Operation::Insert(entity))
-                    } else {
-                        Some(api::mutation::Operation::Upsert(entity))
-                    },
-                    conflict_detection_strategy: None,
+    /// Runs an aggregation query, reading within the given transaction, if any.
+    pub(crate) async fn run_aggregation_query(
+        &mut self,
+        query: AggregationQuery,
+        transaction: Option<&[u8]>,
+    ) -> Result<AggregationResults, Error> {
+        use api::aggregation_query::aggregation::{Avg, Count, Operator, Sum};
+
+        let kind = query.query.kind.clone();
+        let partition_id = self.query_partition(query.query.namespace.clone());
+        let read_options = query_read_options(&query.query, transaction);
+        let aggregations = query
+            .aggregations
+            .iter()
+            .enumerate()
+            .map(|(idx, aggregation)| {
+                let operator = match aggregation {
+                    Aggregation::Count => Operator::Count(Count { up_to: None }),
+                    Aggregation::Sum(name) => Operator::Sum(Sum {
+                        property: Some(api::PropertyReference { name: name.clone() }),
+                    }),
+                    Aggregation::Avg(name) => Operator::Avg(Avg {
+                        property: Some(api::PropertyReference { name: name.clone() }),
+                    }),
+                };
+                api::aggregation_query::Aggregation {
+                    alias: aggregation_alias(idx),
+                    operator: Some(operator),
                 }
             })
             .collect();
-
-        let request = api::CommitRequest {
-            mutations,
-            mode: api::commit_request::Mode::NonTransactional as i32,
-            transaction_selector: None,
+        let api_query = api::AggregationQuery {
+            aggregations,
+            query_type: Some(api::aggregation_query::QueryType::NestedQuery(
+                self.convert_query(query.query)?,
+            )),
+        };
+        let request = api::RunAggregationQueryRequest {
+            partition_id: Some(partition_id),
+            read_options: Some(read_options),
+            query_type: Some(
+                api::run_aggregation_query_request::QueryType::AggregationQuery(api_query),
+            ),
             project_id: self.project_name.clone(),
+            database_id: self.database_id.clone(),
         };
-        let request = self.construct_request(request).await?;
-        let response = self.service.commit(request).await?;
-        let response = response.into_inner();
-        let keys = response
-            .mutation_results
-            .into_iter()
-            .map(|result| result.key.map(Key::from))
-            .collect();
+        let response = self
+            .send(
+                request,
+                transaction.is_none(),
+                |mut service, request| async move { service.run_aggregation_query(request).await },
+            )
+            .await;
+        let batch = response
+            .context("RunAggregationQuery", format_args!("Kind={}", kind))?
+            .batch
+            .ok_or_else(|| ConvertError::MissingField(String::from("batch")))?;
 
-        Ok(keys)
+        let results = convert_aggregation_result(query.aggregations, batch)?;
+        Ok(results)
     }
 
-    /// Deletes an entity identified by a key.
-    pub async fn delete(&mut self, key: impl Borrow<Key>) -> Result<(), Error> {
-        self.delete_all(Some(key.borrow())).await
+    /// The partition to run a query in, defaulting to the namespace of the client.
+    fn query_partition(&self, namespace: Option<String>) -> api::PartitionId {
+        api::PartitionId {
+            project_id: self.project_name.clone(),
+            database_id: self.database_id.clone(),
+            namespace_id: namespace
+                .or_else(|| self.namespace.clone())
+                .unwrap_or_default(),
+        }
     }
 
-    /// Deletes multiple entities identified by multiple keys.
-    pub async fn delete_all<T, I>(&mut self, keys: I) -> Result<(), Error>
-    where
-        I: IntoIterator<Item = T>,
-        T: Borrow<Key>,
-    {
-        let mutations = keys
+    fn convert_query(&self, query: Query) -> Result<api::Query, ConvertError> {
+        let projection = query
+            .projections
             .into_iter()
-            .map(|key| convert_key(self.project_name.as_str(), key.borrow()))
-            .map(|key| api::Mutation {
-                operation: Some(api::mutation::Operation::Delete(key)),
-                conflict_detection_strategy: None,
+            .map(|name| api::Projection {
+                property: Some(api::PropertyReference { name }),
+            })
+            .collect();
+        let mut filters = query.filters;
+        filters.extend(query.ancestor.map(Filter::HasAncestor));
+        let filter = convert_filter(self.partition(), filters)?;
+        let order = query
+            .ordering
+            .into_iter()
+            .map(|order| {
+                use api::property_order::Direction;
+                let (name, direction) = match order {
+                    Order::Asc(name) => (name, Direction::Ascending),
+                    Order::Desc(name) => (name, Direction::Descending),
+                };
+                api::PropertyOrder {
+                    property: Some(api::PropertyReference { name }),
+                    direction: direction as i32,
+                }
             })
             .collect();
 
-        let request = api::CommitRequest {
-            mutations,
-            mode: api::commit_request::Mode::NonTransactional as i32,
-            transaction_selector: None,
-            project_id: self.project_name.clone(),
+        Ok(api::Query {
+            kind: vec![api::KindExpression { name: query.kind }],
+            projection,
+            filter,
+            order,
+            offset: query.offset,
+            limit: query.limit,
+            start_cursor: query
+                .start_cursor
+                .map(|cursor| cursor.0)
+                .unwrap_or_default(),
+            end_cursor: Vec::new(),
+            distinct_on: query
+                .distinct_on
+                .into_iter()
+                .map(|name| api::PropertyReference { name })
+                .collect(),
+        })
+    }
+}
+
+/// The state of a streamed query, between two batches.
+struct QueryStreamState {
+    client: Client,
+    transaction: Option<Vec<u8>>,
+    next_query: Query,
+    paging: QueryPaging,
+}
+
+/// Tracks the progress of a query through its batches, deriving the query of every batch from the original one.
+#[derive(Debug, Clone)]
+pub(crate) struct QueryPaging {
+    query: Query,
+    skipped_results: i32,
+    returned_results: usize,
+}
+
+impl QueryPaging {
+    pub(crate) fn new(query: Query) -> QueryPaging {
+        QueryPaging {
+            query,
+            skipped_results: 0,
+            returned_results: 0,
+        }
+    }
+
+    /// Accounts for the results skipped and returned by a batch, returning the query fetching the next one.
+    ///
+    /// Returns `None` once the query is finished or its limit is reached, as there is nothing left to fetch.
+    pub(crate) fn advance(&mut self, batch: &api::QueryResultBatch) -> Option<Query> {
+        self.skipped_results += batch.skipped_results;
+        self.returned_results += batch.entity_results.len();
+        if batch.more_results != (api::query_result_batch::MoreResultsType::NotFinished as i32) {
+            return None;
+        }
+
+        //? The next batch resumes where this one ended, so the offset and limit only apply to what is left.
+        let limit = self
+            .query
+            .limit
+            .map(|limit| limit - self.returned_results as i32);
+        if limit.is_some_and(|limit| limit <= 0) {
+            return None;
+        }
+        //? A batch still working through the offset may only return a cursor after the skipped results.
+        let cursor = match batch.end_cursor.is_empty() {
+            true => &batch.skipped_cursor,
+            false => &batch.end_cursor,
         };
-        let request = self.construct_request(request).await?;
-        self.service.commit(request).await?;
+        let mut next = self.query.clone();
+        next.offset = (self.query.offset - self.skipped_results).max(0);
+        next.limit = limit;
+        next.start_cursor = Cursor::from_api(cursor.clone());
+        Some(next)
+    }
+}
 
-        Ok(())
+/// Converts a batch of results on its own, `last` telling whether no batch follows it.
+fn convert_query_batch(
+    batch: api::QueryResultBatch,
+    last: bool,
+) -> Result<QueryResults, ConvertError> {
+    let entities = batch
+        .entity_results
+        .into_iter()
+        .map(convert_entity_result)
+        .collect::<Result<_, _>>()?;
+    let finished =
+        batch.more_results != (api::query_result_batch::MoreResultsType::NotFinished as i32);
+    //? A batch can end right at the limit without the query being reported as finished.
+    let more_results = match (finished, last) {
+        (false, true) => MoreResults::AfterLimit,
+        _ => MoreResults::from(batch.more_results),
+    };
+
+    Ok(QueryResults {
+        entities,
+        skipped_results: batch.skipped_results,
+        snapshot_version: batch.snapshot_version,
+        more_results,
+        end_cursor: Cursor::from_api(batch.end_cursor),
+        skipped_cursor: Cursor::from_api(batch.skipped_cursor),
+        explain_metrics: None,
+    })
+}
+
+/// The project and default namespace that keys and queries get converted into.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Partition<'a> {
+    pub(crate) project_name: &'a str,
+    pub(crate) database_id: &'a str,
+    pub(crate) namespace: Option<&'a str>,
+}
+
+impl Partition<'_> {
+    /// Assigns the default namespace to a key without one, as the store does when reading it back.
+    pub(crate) fn scope(&self, key: &Key) -> Key {
+        match (key.get_namespace(), self.namespace) {
+            (None, Some(namespace)) => key.clone().namespace(namespace),
+            _ => key.clone(),
+        }
     }
+}
 
-    /// Runs a (potentially) complex query againt Datastore and returns the results.
-    pub async fn query(&mut self, query: Query) -> Result<Vec<Entity>, Error> {
-        let mut output = Vec::new();
+/// Splits items into chunks of at most the given size, preserving their order.
+pub(crate) fn split_chunks<T>(items: Vec<T>, size: usize) -> Vec<Vec<T>> {
+    let mut chunks = Vec::with_capacity(items.len().div_ceil(size));
+    let mut items = items.into_iter().peekable();
+    while items.peek().is_some() {
+        chunks.push(items.by_ref().take(size).collect());
+    }
+    chunks
+}
 
-        let mut cur_query = query.clone();
-        let mut cursor = Vec::new();
-        loop {
-            let projection = cur_query
-                .projections
-                .into_iter()
-                .map(|name| api::Projection {
-                    property: Some(api::PropertyReference { name }),
-                })
-                .collect();
-            let filter = convert_filter(self.project_name.as_str(), cur_query.filters);
-            let order = cur_query
-                .ordering
-                .into_iter()
-                .map(|order| {
-                    use api::property_order::Direction;
-                    let (name, direction) = match order {
-                        Order::Asc(name) => (name, Direction::Ascending),
-                        Order::Desc(name) => (name, Direction::Descending),
-                    };
-                    api::PropertyOrder {
-                        property: Some(api::PropertyReference { name }),
-                        direction: direction as i32,
-                    }
-                })
-                .collect();
-            let api_query = api::Query {
-                kind: vec![api::KindExpression {
-                    name: cur_query.kind,
-                }],
-                projection,
-                filter,
-                order,
-                offset: cur_query.offset,
-                limit: cur_query.limit,
-                start_cursor: cursor,
-                end_cursor: Vec::new(),
-                distinct_on: cur_query
-                    .distinct_on
-                    .into_iter()
-                    .map(|name| api::PropertyReference { name })
-                    .collect(),
+/// Describes the keys a request operates on, in the context of its errors.
+pub(crate) struct Keys {
+    first: Option<Key>,
+    count: usize,
+}
+
+impl Keys {
+    pub(crate) fn new(keys: &[Key]) -> Keys {
+        Keys {
+            first: keys.first().cloned(),
+            count: keys.len(),
+        }
+    }
+
+    /// Describes the keys of the entities written or deleted by mutations.
+    pub(crate) fn of_mutations(mutations: &[api::Mutation]) -> Keys {
+        use api::mutation::Operation;
+
+        let first = mutations.first().and_then(|mutation| {
+            let key = match mutation.operation.as_ref()? {
+                Operation::Insert(entity)
+                | Operation::Update(entity)
+                | Operation::Upsert(entity) => entity.key.clone()?,
+                Operation::Delete(key) => key.clone(),
             };
-            let request = api::RunQueryRequest {
-                partition_id: Some(api::PartitionId {
-                    project_id: self.project_name.clone(),
-                    namespace_id: cur_query.namespace.unwrap_or_default(),
-                }),
-                query_type: Some(api::run_query_request::QueryType::Query(api_query)),
-                read_options: Some({
-                    use api::read_options::{ConsistencyType, ReadConsistency};
-                    api::ReadOptions {
-                        consistency_type: Some(ConsistencyType::ReadConsistency(
-                            if cur_query.eventual {
-                                ReadConsistency::Eventual as i32
-                            } else {
-                                ReadConsistency::Strong as i32
-                            },
-                        )),
-                    }
-                }),
-                project_id: self.project_name.clone(),
+            Key::try_from(key).ok()
+        });
+        Keys {
+            first,
+            count: mutations.len(),
+        }
+    }
+}
+
+impl fmt::Display for Keys {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.first.as_ref(), self.count) {
+            (None, 0) => write!(f, "no keys"),
+            (None, count) => write!(f, "{} keys", count),
+            (Some(key), 1) => write!(f, "{}", key),
+            (Some(key), count) => write!(f, "{} and {} more keys", key, count - 1),
+        }
+    }
+}
+
+/// The operation used to store an entity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum WriteMode {
+    /// Inserts entities with incomplete keys, and upserts the others.
+    Put,
+    /// Fails if the entity already exists.
+    Insert,
+    /// Fails if the entity does not exist.
+    Update,
+    /// Creates or replaces the entity.
+    Upsert,
+}
+
+/// Converts entities into the mutations storing them.
+pub(crate) fn write_mutations<T, I>(
+    partition: Partition<'_>,
+    entities: I,
+    mode: WriteMode,
+) -> Result<Vec<api::Mutation>, ConvertError>
+where
+    I: IntoIterator<Item = T>,
+    T: IntoEntity,
+{
+    use api::mutation::Operation;
+
+    entities
+        .into_iter()
+        .map(|entity| {
+            let entity = entity.into_entity()?;
+            let is_incomplete = entity.key.is_incomplete();
+            let entity = convert_entity(partition, entity)?;
+            let operation = match mode {
+                WriteMode::Put if is_incomplete => Operation::Insert(entity),
+                WriteMode::Put => Operation::Upsert(entity),
+                WriteMode::Insert => Operation::Insert(entity),
+                WriteMode::Update => Operation::Update(entity),
+                WriteMode::Upsert => Operation::Upsert(entity),
             };
-            let request = self.construct_request(request).await?;
-            let results = self.service.run_query(request).await?;
-            let results = results.into_inner().batch.unwrap();
+            Ok(api::Mutation {
+                operation: Some(operation),
+                conflict_detection_strategy: None,
+            })
+        })
+        .collect()
+}
 
-            output.extend(
-                results
-                    .entity_results
-                    .into_iter()
-                    .map(|el| Entity::from(el.entity.unwrap())),
-            );
+/// The keys allocated by the store for the mutated entities.
+fn result_keys(results: Vec<api::MutationResult>) -> Result<Vec<Option<Key>>, ConvertError> {
+    results
+        .into_iter()
+        .map(|result| result.key.map(Key::try_from).transpose())
+        .collect()
+}
 
-            if results.more_results
-                != (api::query_result_batch::MoreResultsType::NotFinished as i32)
-            {
-                break Ok(output);
-            }
+/// Converts keys into the mutations deleting their entities.
+pub(crate) fn delete_mutations<T, I>(partition: Partition<'_>, keys: I) -> Vec<api::Mutation>
+where
+    I: IntoIterator<Item = T>,
+    T: Borrow<Key>,
+{
+    keys.into_iter()
+        .map(|key| convert_key(partition, key.borrow()))
+        .map(|key| api::Mutation {
+            operation: Some(api::mutation::Operation::Delete(key)),
+            conflict_detection_strategy: None,
+        })
+        .collect()
+}
 
-            cur_query = query.clone();
-            cursor = results.end_cursor;
-        }
+/// Does applying the mutation again leave the store in the same state (so that its commit can be retried) ?
+///
+/// Only upserts and deletions of complete keys do, and only without conflict detection:
+/// inserts fail once their entity exists, and incomplete keys get a new ID at every attempt.
+pub(crate) fn is_idempotent(mutation: &api::Mutation) -> bool {
+    use api::mutation::Operation;
+
+    let key = match &mutation.operation {
+        Some(Operation::Upsert(entity)) => entity.key.as_ref(),
+        Some(Operation::Delete(key)) => Some(key),
+        _ => None,
+    };
+    let is_complete = |key: &api::Key| key.path.iter().all(|element| element.id_type.is_some());
+    mutation.conflict_detection_strategy.is_none() && key.is_some_and(is_complete)
+}
+
+/// The read options of a query, reading within the given transaction, if any.
+pub(crate) fn query_read_options(query: &Query, transaction: Option<&[u8]>) -> api::ReadOptions {
+    match transaction {
+        Some(transaction) => transaction_read_options(transaction),
+        None => query.consistency.into_api(),
+    }
+}
+
+pub(crate) fn transaction_read_options(transaction: &[u8]) -> api::ReadOptions {
+    use api::read_options::ConsistencyType;
+    api::ReadOptions {
+        consistency_type: Some(ConsistencyType::Transaction(transaction.to_vec())),
     }
 }
 
-fn convert_key(project_name: &str, key: &Key) -> api::Key {
+pub(crate) fn convert_key(partition: Partition<'_>, key: &Key) -> api::Key {
     api::Key {
         partition_id: Some(api::PartitionId {
-            project_id: String::from(project_name),
-            namespace_id: key.get_namespace().map(String::from).unwrap_or_default(),
+            project_id: String::from(partition.project_name),
+            database_id: String::from(partition.database_id),
+            namespace_id: key
+                .get_namespace()
+                .or(partition.namespace)
+                .map(String::from)
+                .unwrap_or_default(),
         }),
         path: {
             let mut key = Some(key);
@@ -351,67 +1933,189 @@ fn convert_key(project_name: &str, key: &Key) -> api::Key {
     }
 }
 
-fn convert_entity(project_name: &str, entity: Entity) -> api::Entity {
-    let key = convert_key(project_name, &entity.key);
+fn convert_entity(partition: Partition<'_>, entity: Entity) -> Result<api::Entity, ConvertError> {
+    let key = convert_key(partition, &entity.key);
     let properties = match entity.properties {
         Value::EntityValue(properties) => properties,
-        _ => panic!("unexpected non-entity datastore value"),
+        properties => {
+            return Err(ConvertError::UnexpectedPropertyType {
+                expected: String::from("entity"),
+                got: String::from(properties.type_name()),
+            })
+        }
     };
     let properties = properties
         .into_iter()
-        .map(|(k, v)| (k, convert_value(project_name, v)))
-        .collect();
-    api::Entity {
+        .map(|(k, v)| Ok((k, convert_value(partition, v)?)))
+        .collect::<Result<_, ConvertError>>()?;
+    Ok(api::Entity {
         key: Some(key),
         properties,
-    }
+    })
+}
+
+pub(crate) fn convert_entity_result(result: api::EntityResult) -> Result<Entity, ConvertError> {
+    let entity = result
+        .entity
+        .ok_or_else(|| ConvertError::MissingField(String::from("entity")))?;
+    Entity::try_from(entity)
+}
+
+/// The alias of the aggregation at the given position, under which its result is returned.
+//? Aliases must be valid property names, which property names being aggregated may not be.
+fn aggregation_alias(idx: usize) -> String {
+    format!("aggregation_{}", idx)
+}
+
+pub(crate) fn convert_aggregation_result(
+    aggregations: Vec<Aggregation>,
+    batch: api::AggregationResultBatch,
+) -> Result<AggregationResults, ConvertError> {
+    //? Without grouping, an aggregation query always yields a single result.
+    let mut properties = batch
+        .aggregation_results
+        .into_iter()
+        .next()
+        .ok_or_else(|| ConvertError::MissingField(String::from("aggregation_results")))?
+        .aggregate_properties;
+    let values = aggregations
+        .into_iter()
+        .enumerate()
+        .map(|(idx, aggregation)| {
+            let alias = aggregation_alias(idx);
+            let value = properties
+                .remove(alias.as_str())
+                .ok_or(ConvertError::MissingField(alias))?;
+            //? Averages over no values are null, which stands for a missing result.
+            let value = match value.value_type {
+                Some(ValueType::NullValue(_)) => None,
+                _ => Some(Value::try_from(value)?),
+            };
+            Ok((aggregation, value))
+        })
+        .collect::<Result<Vec<_>, ConvertError>>()?;
+
+    Ok(AggregationResults { values })
+}
+
+/// The maximum size of an indexed blob, in bytes.
+const MAX_INDEXED_BLOB_SIZE: usize = 1500;
+/// The maximum size of an unindexed blob, in bytes.
+const MAX_BLOB_SIZE: usize = 1_048_487;
+
+pub(crate) fn convert_value(
+    partition: Partition<'_>,
+    value: Value,
+) -> Result<api::Value, ConvertError> {
+    convert_indexed_value(partition, value, None)
 }
 
-fn convert_value(project_name: &str, value: Value) -> api::Value {
-    let mut exclude_from_indexes = false;
+/// Converts a value, `indexed` being the index flag set by an enclosing `Value::IndexedValue`, if any.
+fn convert_indexed_value(
+    partition: Partition<'_>,
+    value: Value,
+    indexed: Option<bool>,
+) -> Result<api::Value, ConvertError> {
+    fn is_array(value: &Value) -> bool {
+        match value {
+            Value::ArrayValue(_) => true,
+            Value::IndexedValue(value, _) => is_array(value),
+            _ => false,
+        }
+    }
+
     let value_type = match value {
         Value::BooleanValue(val) => ValueType::BooleanValue(val),
         Value::IntegerValue(val) => ValueType::IntegerValue(val),
         Value::DoubleValue(val) => ValueType::DoubleValue(val),
-        Value::TimestampValue(val) => ValueType::TimestampValue(prost_types::Timestamp {
-            seconds: val.timestamp(),
-            nanos: val.timestamp_subsec_nanos() as i32,
-        }),
-        Value::KeyValue(key) => ValueType::KeyValue(convert_key(project_name, &key)),
+        Value::TimestampValue(val) => ValueType::TimestampValue(types::naive_to_timestamp(val)),
+        Value::KeyValue(key) => ValueType::KeyValue(convert_key(partition, &key)),
         Value::StringValue(val) => ValueType::StringValue(val),
+        //? The outermost flag takes precedence over nested ones.
         Value::IndexedValue(val, flag) => {
-            exclude_from_indexes = !flag;
-            convert_value(project_name, *val).value_type.unwrap() // cannot fail, return type is always Some(T)
+            return convert_indexed_value(partition, *val, indexed.or(Some(flag)));
+        }
+        Value::BlobValue(val) => {
+            let max = if indexed.unwrap_or(true) {
+                MAX_INDEXED_BLOB_SIZE
+            } else {
+                MAX_BLOB_SIZE
+            };
+            if val.len() > max {
+                return Err(ConvertError::BlobTooLarge {
+                    size: val.len(),
+                    max,
+                });
+            }
+            ValueType::BlobValue(val)
         }
-        Value::BlobValue(val) => ValueType::BlobValue(val),
         Value::GeoPointValue(latitude, longitude) => ValueType::GeoPointValue(api::LatLng {
             latitude,
             longitude,
         }),
+        //? The exclusion of an entity from indexes applies to its properties too (like in `Value::exclude_from_indexes`).
         Value::EntityValue(properties) => ValueType::EntityValue({
+            let excluded = indexed.filter(|indexed| !indexed);
             api::Entity {
                 key: None,
                 properties: properties
                     .into_iter()
-                    .map(|(k, v)| (k, convert_value(project_name, v)))
-                    .collect(),
+                    .map(|(k, v)| Ok((k, convert_indexed_value(partition, v, excluded)?)))
+                    .collect::<Result<_, ConvertError>>()?,
             }
         }),
-        Value::ArrayValue(values) => ValueType::ArrayValue(api::ArrayValue {
-            values: values
+        //? Datastore does not accept index flags on arrays themselves, so apply it to each element instead.
+        Value::ArrayValue(values) => {
+            let values = values
                 .into_iter()
-                .map(|value| convert_value(project_name, value))
-                .collect(),
-        }),
+                .map(|value| {
+                    if is_array(&value) {
+                        return Err(ConvertError::NestedArray);
+                    }
+                    convert_indexed_value(partition, value, indexed)
+                })
+                .collect::<Result<_, _>>()?;
+            return Ok(api::Value {
+                meaning: 0,
+                exclude_from_indexes: false,
+                value_type: Some(ValueType::ArrayValue(api::ArrayValue { values })),
+            });
+        }
     };
-    api::Value {
+    Ok(api::Value {
         meaning: 0,
-        exclude_from_indexes,
+        exclude_from_indexes: !indexed.unwrap_or(true),
         value_type: Some(value_type),
+    })
+}
+
+/// Checks the values of an `In` or `NotIn` filter, which must be a non-empty array of at most 10 values.
+fn validate_in_values(name: &str, value: &Value) -> Result<(), ConvertError> {
+    let invalid = |reason: String| ConvertError::InvalidFilter {
+        property: String::from(name),
+        reason,
+    };
+    match value {
+        Value::ArrayValue(values) if values.is_empty() => {
+            Err(invalid(String::from("no values to match")))
+        }
+        Value::ArrayValue(values) if values.len() > Filter::MAX_IN_VALUES => Err(invalid(format!(
+            "{} values to match, out of at most {}",
+            values.len(),
+            Filter::MAX_IN_VALUES
+        ))),
+        Value::ArrayValue(_) => Ok(()),
+        value => Err(invalid(format!(
+            "expected an array of values to match, got `{}`",
+            value.type_name()
+        ))),
     }
 }
 
-fn convert_filter(project_name: &str, filters: Vec<Filter>) -> Option<api::Filter> {
+pub(crate) fn convert_filter(
+    partition: Partition<'_>,
+    filters: Vec<Filter>,
+) -> Result<Option<api::Filter>, ConvertError> {
     use api::filter::FilterType;
 
     if !filters.is_empty() {
@@ -430,27 +2134,39 @@ fn convert_filter(project_name: &str, filters: Vec<Filter>) -> Option<api::Filte
                         (name, Operator::LessThanOrEqual, value)
                     }
                     Filter::NotEqual(name, value) => (name, Operator::NotEqual, value),
-                    Filter::In(name, value) => (name, Operator::In, value),
-                    Filter::NotIn(name, value) => (name, Operator::NotIn, value),
+                    Filter::In(name, value) => {
+                        validate_in_values(name.as_str(), &value)?;
+                        (name, Operator::In, value)
+                    }
+                    Filter::NotIn(name, value) => {
+                        validate_in_values(name.as_str(), &value)?;
+                        (name, Operator::NotIn, value)
+                    }
+                    //? Ancestors are matched against the special key property.
+                    Filter::HasAncestor(key) => (
+                        String::from("__key__"),
+                        Operator::HasAncestor,
+                        Value::KeyValue(key),
+                    ),
                 };
 
-                api::Filter {
+                Ok(api::Filter {
                     filter_type: Some(FilterType::PropertyFilter(api::PropertyFilter {
                         op: op as i32,
                         property: Some(api::PropertyReference { name }),
-                        value: Some(convert_value(project_name, value)),
+                        value: Some(convert_value(partition, value)?),
                     })),
-                }
+                })
             })
-            .collect();
+            .collect::<Result<_, ConvertError>>()?;
 
-        Some(api::Filter {
+        Ok(Some(api::Filter {
             filter_type: Some(FilterType::CompositeFilter(api::CompositeFilter {
                 op: api::composite_filter::Operator::And as i32,
                 filters,
             })),
-        })
+        }))
     } else {
-        None
+        Ok(None)
     }
 }
@@ -0,0 +1,162 @@
+use crate::datastore::Value;
+use crate::error::ConvertError;
+
+/// Represents how numbers get decoded from Datastore values (see `FromValue::from_value_with`).
+///
+/// By default, integers and doubles are only decoded from values of the exact same type.
+/// Writers in other languages do not always store numbers consistently though,
+/// so decoding can be relaxed to accept other representations of the same numbers.
+///
+/// In all cases, conversions that would lose information (like truncating a fractional part,
+/// rounding an integer or overflowing the target type) are rejected with `ConvertError::LossyNumber`.
+///
+/// ```
+/// # use google_cloud::datastore::{DecodeOptions, FromValue, Value};
+/// let options = DecodeOptions::default()
+///     .widen_integers(true)
+///     .decimal_strings(true);
+///
+/// let value = f64::from_value_with(Value::IntegerValue(3), &options).unwrap();
+/// assert_eq!(value, 3.0);
+///
+/// let value = i64::from_value_with(Value::StringValue(String::from("42")), &options).unwrap();
+/// assert_eq!(value, 42);
+///
+/// assert!(i64::from_value_with(Value::StringValue(String::from("4.2")), &options).is_err());
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct DecodeOptions {
+    pub(crate) widen_integers: bool,
+    pub(crate) integral_doubles: bool,
+    pub(crate) decimal_strings: bool,
+}
+
+impl DecodeOptions {
+    /// Allow decoding doubles from integer values, if they can be represented exactly.
+    pub fn widen_integers(mut self, enabled: bool) -> DecodeOptions {
+        self.widen_integers = enabled;
+        self
+    }
+
+    /// Allow decoding integers from double values, if they have no fractional part.
+    pub fn integral_doubles(mut self, enabled: bool) -> DecodeOptions {
+        self.integral_doubles = enabled;
+        self
+    }
+
+    /// Allow decoding numbers from string values holding a decimal number (like `"-12.50"`).
+    ///
+    /// Exponents are not supported, and doubles are only decoded if they represent the decimal number exactly
+    /// (up to the precision needed to tell it apart from its neighbouring doubles).
+    pub fn decimal_strings(mut self, enabled: bool) -> DecodeOptions {
+        self.decimal_strings = enabled;
+        self
+    }
+}
+
+fn lossy(value: impl ToString, expected: &str) -> ConvertError {
+    ConvertError::LossyNumber {
+        value: value.to_string(),
+        expected: String::from(expected),
+    }
+}
+
+/// Decodes an integer from a value, according to the given options.
+pub(crate) fn decode_integer(value: Value, options: &DecodeOptions) -> Result<i64, ConvertError> {
+    //? The bounds of `i64` as doubles: the upper one is excluded, as it is one more than `i64::MAX`.
+    const MIN: f64 = -9_223_372_036_854_775_808.0;
+    const MAX: f64 = 9_223_372_036_854_775_808.0;
+
+    match value {
+        Value::IndexedValue(value, _) => decode_integer(*value, options),
+        Value::IntegerValue(value) => Ok(value),
+        Value::DoubleValue(value) if options.integral_doubles => {
+            if value.fract() == 0.0 && (MIN..MAX).contains(&value) {
+                Ok(value as i64)
+            } else {
+                Err(lossy(value, "integer"))
+            }
+        }
+        Value::StringValue(value) if options.decimal_strings => {
+            let (integer, fraction) = split_decimal(value.as_str())
+                .ok_or_else(|| ConvertError::InvalidDecimal(value.clone()))?;
+            if fraction.bytes().any(|digit| digit != b'0') {
+                return Err(lossy(value, "integer"));
+            }
+            integer
+                .parse()
+                .map_err(|_| lossy(value.as_str(), "integer"))
+        }
+        _ => Err(ConvertError::UnexpectedPropertyType {
+            expected: String::from("integer"),
+            got: String::from(value.type_name()),
+        }),
+    }
+}
+
+/// Decodes a double from a value, according to the given options.
+pub(crate) fn decode_double(value: Value, options: &DecodeOptions) -> Result<f64, ConvertError> {
+    //? Doubles at or beyond 2^63 do not fit back into an `i64`.
+    const MAX: f64 = 9_223_372_036_854_775_808.0;
+
+    match value {
+        Value::IndexedValue(value, _) => decode_double(*value, options),
+        Value::DoubleValue(value) => Ok(value),
+        Value::IntegerValue(value) if options.widen_integers => {
+            let double = value as f64;
+            if double < MAX && double as i64 == value {
+                Ok(double)
+            } else {
+                Err(lossy(value, "double"))
+            }
+        }
+        Value::StringValue(value) if options.decimal_strings => {
+            let (integer, fraction) = split_decimal(value.as_str())
+                .ok_or_else(|| ConvertError::InvalidDecimal(value.clone()))?;
+            let double: f64 = value
+                .parse()
+                .map_err(|_| ConvertError::InvalidDecimal(value.clone()))?;
+            //? `f64`'s `Display` prints the shortest decimal that parses back to the same double,
+            //? so the decimal is exact if no significant digit of it got lost.
+            let printed = double.to_string();
+            let (printed_integer, printed_fraction) = split_decimal(printed.as_str()).unwrap();
+            let exact = double.is_finite()
+                && normalize(integer, fraction) == normalize(printed_integer, printed_fraction);
+            if exact {
+                Ok(double)
+            } else {
+                Err(lossy(value, "double"))
+            }
+        }
+        _ => Err(ConvertError::UnexpectedPropertyType {
+            expected: String::from("double"),
+            got: String::from(value.type_name()),
+        }),
+    }
+}
+
+/// Splits a decimal number into its (signed) integer part and its fractional digits.
+fn split_decimal(value: &str) -> Option<(&str, &str)> {
+    let (integer, fraction) = match value.find('.') {
+        Some(idx) => (&value[..idx], &value[idx + 1..]),
+        None => (value, ""),
+    };
+    let digits = integer.strip_prefix(['-', '+']).unwrap_or(integer);
+    let is_digits = |part: &str| part.bytes().all(|byte| byte.is_ascii_digit());
+    if digits.is_empty() || !is_digits(digits) || !is_digits(fraction) {
+        return None;
+    }
+
+    Some((integer, fraction))
+}
+
+/// Strips the insignificant zeros and sign of a decimal number, to compare it with another.
+fn normalize<'a>(integer: &'a str, fraction: &'a str) -> (bool, &'a str, &'a str) {
+    let negative = integer.starts_with('-');
+    let integer = integer
+        .trim_start_matches(['-', '+'])
+        .trim_start_matches('0');
+    let fraction = fraction.trim_end_matches('0');
+
+    (negative, integer, fraction)
+}
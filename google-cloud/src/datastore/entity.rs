@@ -1,6 +1,15 @@
+use std::convert::TryFrom;
+
+use chrono::NaiveDateTime;
+
 use crate::datastore::api;
 use crate::datastore::{IntoValue, Key, Value};
 use crate::error::ConvertError;
+use crate::types;
+
+#[cfg(feature = "datastore-derive")]
+#[doc(hidden)]
+pub use google_cloud_derive::DatastoreEntity;
 
 /// Represents a Datastore entity.
 #[derive(Debug, Clone, PartialEq)]
@@ -46,9 +55,140 @@ impl Entity {
     pub fn properties_mut(&mut self) -> &mut Value {
         &mut self.properties
     }
+
+    /// Exclude a property from indexes, recursively (see `Value::exclude_from_indexes`).
+    ///
+    /// Does nothing if the entity has no such property.
+    pub fn exclude_from_indexes(mut self, property: &str) -> Entity {
+        if let Value::EntityValue(properties) = &mut self.properties {
+            if let Some(value) = properties.remove(property) {
+                properties.insert(property.to_string(), value.exclude_from_indexes());
+            }
+        }
+        self
+    }
+}
+
+/// Represents the metadata of a stored entity, read along with it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EntityMeta {
+    pub(crate) version: i64,
+    pub(crate) update_time: Option<NaiveDateTime>,
+}
+
+impl EntityMeta {
+    /// The version of the entity, which increases with every change to it.
+    ///
+    /// Comparing it to the version of a previous read tells whether the entity changed in between,
+    /// which makes it suitable for caching and optimistic locking.
+    pub fn version(&self) -> i64 {
+        self.version
+    }
+
+    /// The time (in UTC) of the last change to the entity, if known.
+    pub fn update_time(&self) -> Option<NaiveDateTime> {
+        self.update_time
+    }
+
+    pub(crate) fn from_api(result: &api::EntityResult) -> Result<EntityMeta, ConvertError> {
+        let update_time = result.update_time.clone();
+        Ok(EntityMeta {
+            version: result.version,
+            update_time: update_time.map(types::timestamp_to_naive).transpose()?,
+        })
+    }
+}
+
+/// Represents the result of a mutation of an entity, such as a delete.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MutationResult {
+    pub(crate) key: Key,
+    pub(crate) version: i64,
+    pub(crate) conflict_detected: bool,
+}
+
+impl MutationResult {
+    /// The key of the mutated entity.
+    pub fn key(&self) -> &Key {
+        &self.key
+    }
+
+    /// The version of the entity after the mutation.
+    ///
+    /// For a delete, it is the version at which the entity ceased to exist (or the current version of the entity,
+    /// if the delete was not applied because of a conflict).
+    pub fn version(&self) -> i64 {
+        self.version
+    }
+
+    /// Whether the mutation was not applied because the entity changed since the expected version
+    /// (see `Client::delete_if_version`).
+    pub fn conflict_detected(&self) -> bool {
+        self.conflict_detected
+    }
+
+    /// Pairs the keys of the mutations with their results, which come in the same order.
+    pub(crate) fn zip(
+        keys: impl IntoIterator<Item = Key>,
+        results: Vec<api::MutationResult>,
+    ) -> Vec<MutationResult> {
+        keys.into_iter()
+            .zip(results)
+            .map(|(key, result)| MutationResult {
+                key,
+                version: result.version,
+                conflict_detected: result.conflict_detected,
+            })
+            .collect()
+    }
+}
+
+/// Represents the results of the mutations of a commit, along with its cost in index updates.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommitResults {
+    pub(crate) mutation_results: Vec<MutationResult>,
+    pub(crate) index_updates: i32,
+}
+
+impl CommitResults {
+    /// The results of the mutations, in their order.
+    pub fn mutation_results(&self) -> &[MutationResult] {
+        self.mutation_results.as_slice()
+    }
+
+    /// Move the results of the mutations out.
+    pub fn into_mutation_results(self) -> Vec<MutationResult> {
+        self.mutation_results
+    }
+
+    /// The number of index entries updated by the mutations (summed over all the commits, if they were split).
+    pub fn index_updates(&self) -> i32 {
+        self.index_updates
+    }
 }
 
 /// Trait for converting a type to a Datastore entity (key + value).
+///
+/// With the `datastore-derive` feature, it can be derived for structs (along with `FromValue`),
+/// using one of their fields as the ID of the key:
+///
+/// ```ignore
+/// use google_cloud::datastore::{DatastoreEntity, IntoEntity};
+///
+/// #[derive(DatastoreEntity)]
+/// #[datastore(kind = "User", rename_all = "snake_case")]
+/// pub struct User {
+///     // Not stored as a property, and left to `None` when read back with `FromValue`.
+///     #[datastore(key)]
+///     id: Option<i64>,
+///     #[datastore(rename = "mail")]
+///     email: String,
+///     display_name: String,
+///     // Excluded from indexes, along with anything nested in it.
+///     #[datastore(exclude_from_indexes)]
+///     biography: String,
+/// }
+/// ```
 pub trait IntoEntity {
     /// Attempts to convert the type to a Datastore entity.
     /// Fails if the top level value is not a `Value::EntityValue`.
@@ -71,17 +211,22 @@ where
     }
 }
 
-impl From<api::Entity> for Entity {
-    fn from(entity: api::Entity) -> Entity {
-        let key = Key::from(entity.key.unwrap());
+impl TryFrom<api::Entity> for Entity {
+    type Error = ConvertError;
+
+    fn try_from(entity: api::Entity) -> Result<Entity, ConvertError> {
+        let key = entity
+            .key
+            .ok_or_else(|| ConvertError::MissingField(String::from("key")))?;
+        let key = Key::try_from(key)?;
         let properties = entity.properties;
 
         let properties = properties
             .into_iter()
-            .map(|(k, v)| (k, Value::from(v.value_type.unwrap())))
-            .collect();
+            .map(|(k, v)| Ok((k, Value::try_from(v)?)))
+            .collect::<Result<_, ConvertError>>()?;
         let properties = Value::EntityValue(properties);
 
-        Entity { key, properties }
+        Ok(Entity { key, properties })
     }
 }
@@ -0,0 +1,152 @@
+use std::convert::TryFrom;
+use std::time::Duration;
+
+use crate::datastore::api;
+use crate::error::ConvertError;
+use crate::types;
+
+/// Represents how a query gets explained (see `Client::explain_query`).
+///
+/// By default, the query is only planned: no results are returned, only the indexes it would use.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ExplainOptions {
+    pub(crate) analyze: bool,
+}
+
+impl ExplainOptions {
+    /// Only plan the query, without running it.
+    pub fn new() -> ExplainOptions {
+        ExplainOptions::default()
+    }
+
+    /// Also run the query, returning its results along with execution statistics (see `ExecutionStats`).
+    ///
+    /// The query is then billed as usual.
+    pub fn analyze(mut self, analyze: bool) -> ExplainOptions {
+        self.analyze = analyze;
+        self
+    }
+
+    pub(crate) fn into_api(self) -> api::ExplainOptions {
+        api::ExplainOptions {
+            analyze: self.analyze,
+        }
+    }
+}
+
+/// Represents the planning and execution metrics of an explained query.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExplainMetrics {
+    pub(crate) indexes_used: Vec<json::Value>,
+    pub(crate) execution_stats: Option<ExecutionStats>,
+}
+
+impl ExplainMetrics {
+    /// The indexes selected for the query, such as `{"query_scope": "Collection", "properties": "(done ASC, __name__ ASC)"}`.
+    pub fn indexes_used(&self) -> &[json::Value] {
+        self.indexes_used.as_slice()
+    }
+
+    /// The statistics of the execution of the query, if it was run (see `ExplainOptions::analyze`).
+    pub fn execution_stats(&self) -> Option<&ExecutionStats> {
+        self.execution_stats.as_ref()
+    }
+}
+
+impl TryFrom<api::ExplainMetrics> for ExplainMetrics {
+    type Error = ConvertError;
+
+    fn try_from(metrics: api::ExplainMetrics) -> Result<ExplainMetrics, ConvertError> {
+        let indexes_used = metrics
+            .plan_summary
+            .map(|summary| summary.indexes_used)
+            .unwrap_or_default();
+        let execution_stats = metrics
+            .execution_stats
+            .map(ExecutionStats::try_from)
+            .transpose()?;
+
+        Ok(ExplainMetrics {
+            indexes_used: indexes_used.into_iter().map(struct_to_json).collect(),
+            execution_stats,
+        })
+    }
+}
+
+/// Represents the statistics of the execution of a query.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExecutionStats {
+    pub(crate) results_returned: i64,
+    pub(crate) execution_duration: Duration,
+    pub(crate) read_operations: i64,
+    pub(crate) debug_stats: json::Value,
+}
+
+impl ExecutionStats {
+    /// The number of results returned (entities, projections or keys).
+    pub fn results_returned(&self) -> i64 {
+        self.results_returned
+    }
+
+    /// The time spent running the query in the backend.
+    pub fn execution_duration(&self) -> Duration {
+        self.execution_duration
+    }
+
+    /// The number of billed read operations.
+    pub fn read_operations(&self) -> i64 {
+        self.read_operations
+    }
+
+    /// Debugging statistics, such as the numbers of index entries and entities scanned.
+    ///
+    /// Their format is not stable, and may change over time.
+    pub fn debug_stats(&self) -> &json::Value {
+        &self.debug_stats
+    }
+}
+
+impl TryFrom<api::ExecutionStats> for ExecutionStats {
+    type Error = ConvertError;
+
+    fn try_from(stats: api::ExecutionStats) -> Result<ExecutionStats, ConvertError> {
+        let execution_duration = stats
+            .execution_duration
+            .map(types::duration_to_std)
+            .transpose()?
+            .unwrap_or_default();
+
+        Ok(ExecutionStats {
+            results_returned: stats.results_returned,
+            execution_duration,
+            read_operations: stats.read_operations,
+            debug_stats: stats.debug_stats.map(struct_to_json).unwrap_or_default(),
+        })
+    }
+}
+
+fn struct_to_json(value: prost_types::Struct) -> json::Value {
+    let fields = value.fields.into_iter();
+    json::Value::Object(
+        fields
+            .map(|(name, value)| (name, value_to_json(value)))
+            .collect(),
+    )
+}
+
+fn value_to_json(value: prost_types::Value) -> json::Value {
+    use prost_types::value::Kind;
+
+    match value.kind {
+        None | Some(Kind::NullValue(_)) => json::Value::Null,
+        Some(Kind::NumberValue(number)) => json::Number::from_f64(number)
+            .map(json::Value::Number)
+            .unwrap_or(json::Value::Null),
+        Some(Kind::StringValue(string)) => json::Value::String(string),
+        Some(Kind::BoolValue(boolean)) => json::Value::Bool(boolean),
+        Some(Kind::StructValue(value)) => struct_to_json(value),
+        Some(Kind::ListValue(list)) => {
+            json::Value::Array(list.values.into_iter().map(value_to_json).collect())
+        }
+    }
+}
@@ -0,0 +1,290 @@
+use std::collections::HashMap;
+
+use crate::datastore::{Client, Entity, Error, Filter, IntoEntity, Query, Value};
+use crate::error::ConvertError;
+
+//? Mean radius of the Earth, in meters.
+const EARTH_RADIUS: f64 = 6_371_008.8;
+
+const BASE32: &[u8; 32] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+
+/// Encodes a location (latitude and longitude, in degrees) as a geohash of the given length.
+///
+/// Every character narrows the cell down (from about 5000 km wide for 1 character, to about 5 m for 9),
+/// and the locations within a cell share its geohash as a prefix.
+///
+/// ```
+/// # use google_cloud::datastore::geohash;
+/// assert_eq!(geohash(48.8584, 2.2945, 6), "u09tun");
+/// ```
+pub fn geohash(latitude: f64, longitude: f64, len: usize) -> String {
+    let (mut lat_range, mut lng_range) = ((-90.0, 90.0), (-180.0, 180.0));
+    let mut hash = String::with_capacity(len);
+    let mut even = true;
+    for _ in 0..len {
+        let mut index = 0;
+        for _ in 0..5 {
+            let (range, value): (&mut (f64, f64), f64) = match even {
+                true => (&mut lng_range, longitude),
+                false => (&mut lat_range, latitude),
+            };
+            let mid = (range.0 + range.1) / 2.0;
+            index <<= 1;
+            if value >= mid {
+                index |= 1;
+                range.0 = mid;
+            } else {
+                range.1 = mid;
+            }
+            even = !even;
+        }
+        hash.push(BASE32[index] as char);
+    }
+    hash
+}
+
+/// The great-circle distance between two locations (latitude and longitude, in degrees), in meters.
+///
+/// ```
+/// # use google_cloud::datastore::geo_distance;
+/// let distance = geo_distance((48.8584, 2.2945), (48.8606, 2.3376));
+/// assert!((distance - 3162.5).abs() < 1.0);
+/// ```
+pub fn geo_distance(a: (f64, f64), b: (f64, f64)) -> f64 {
+    let (lat_a, lat_b) = (a.0.to_radians(), b.0.to_radians());
+    let d_lat = lat_b - lat_a;
+    let d_lng = (b.1 - a.1).to_radians();
+    let h = (d_lat / 2.0).sin().powi(2) + lat_a.cos() * lat_b.cos() * (d_lng / 2.0).sin().powi(2);
+    2.0 * EARTH_RADIUS * h.sqrt().min(1.0).asin()
+}
+
+/// Represents a geohash index on a geographic point property, allowing to query entities by distance.
+///
+/// Datastore has no native geographic queries: the index stores the geohashes of the point
+/// (one per precision) in a companion array property, named after the point property with a `_geohash` suffix.
+/// Radius queries then match the cells around their center with an `IN` filter on it, before refining
+/// the results by their actual distance.
+///
+/// The index property must be written along with the point, by passing the entities through `GeoIndex::index`:
+///
+/// ```no_run
+/// # use google_cloud::datastore::{Client, Error, GeoIndex, Key, Query, Value};
+/// # async fn run(mut client: Client) -> Result<(), Error> {
+/// let index = GeoIndex::new("location");
+/// let mut properties = std::collections::HashMap::new();
+/// properties.insert(String::from("location"), Value::GeoPointValue(48.8584, 2.2945));
+/// client.put(index.index((Key::new("Place").id("eiffel-tower"), properties))?).await?;
+///
+/// let query = Query::new("Place").within_radius(&index, (48.8606, 2.3376), 5000.0);
+/// let places = client.query_within(query).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GeoIndex {
+    pub(crate) property: String,
+    pub(crate) precision: usize,
+}
+
+impl GeoIndex {
+    /// Index the given geographic point property, up to 9 characters of geohash (cells of about 5 m).
+    pub fn new(property: impl Into<String>) -> GeoIndex {
+        GeoIndex {
+            property: property.into(),
+            precision: 9,
+        }
+    }
+
+    /// Set the length of the longest geohash stored (between 1 and 12).
+    ///
+    /// Radius queries are most selective when the radius is about the size of the smallest cells,
+    /// longer geohashes only make the index larger.
+    pub fn precision(mut self, precision: usize) -> GeoIndex {
+        self.precision = precision.clamp(1, 12);
+        self
+    }
+
+    /// The name of the indexed geographic point property.
+    pub fn property(&self) -> &str {
+        self.property.as_str()
+    }
+
+    /// The name of the companion property holding the geohashes.
+    pub fn index_property(&self) -> String {
+        format!("{}_geohash", self.property)
+    }
+
+    /// Add the geohashes of the point to the properties of an entity (or remove them if it has no point).
+    pub fn index(&self, entity: impl IntoEntity) -> Result<Entity, ConvertError> {
+        let mut entity = entity.into_entity()?;
+        let properties = match entity.properties_mut() {
+            Value::EntityValue(properties) => properties,
+            value => {
+                return Err(ConvertError::UnexpectedPropertyType {
+                    expected: String::from("entity"),
+                    got: String::from(value.type_name()),
+                })
+            }
+        };
+        let point = match properties.get(&self.property).map(unindexed) {
+            Some(Value::GeoPointValue(latitude, longitude)) => Some((*latitude, *longitude)),
+            None => None,
+            Some(value) => {
+                return Err(ConvertError::UnexpectedPropertyType {
+                    expected: String::from("geopoint"),
+                    got: String::from(value.type_name()),
+                })
+            }
+        };
+        match point {
+            Some(point) => {
+                let hashes = self.hashes(point);
+                properties.insert(self.index_property(), Value::ArrayValue(hashes));
+            }
+            None => {
+                properties.remove(&self.index_property());
+            }
+        }
+
+        Ok(entity)
+    }
+
+    fn hashes(&self, (latitude, longitude): (f64, f64)) -> Vec<Value> {
+        let hash = geohash(latitude, longitude, self.precision);
+        let prefixes = (1..=hash.len()).map(|len| Value::StringValue(hash[..len].to_string()));
+        prefixes.collect()
+    }
+
+    /// The geohashes of the cells covering a circle: the cell of the center and its 8 neighbours,
+    /// at the longest length whose cells are at least as large as the radius.
+    ///
+    /// Returns `None` if even the largest cells are too small (for very large radiuses, or near the poles).
+    pub(crate) fn covering_cells(&self, center: (f64, f64), meters: f64) -> Option<Vec<String>> {
+        //? Cells get narrower away from the equator, so their width is measured at the edge of the circle
+        //? closest to a pole.
+        let edge_latitude = (center.0.abs() + (meters / EARTH_RADIUS).to_degrees()).min(90.0);
+        let len = (1..=self.precision).rev().find(|len| {
+            let (height, width) = cell_size(*len);
+            let height = height.to_radians() * EARTH_RADIUS;
+            let width = width.to_radians() * EARTH_RADIUS * edge_latitude.to_radians().cos();
+            height >= meters && width >= meters
+        })?;
+
+        let (height, width) = cell_size(len);
+        let mut cells = Vec::with_capacity(9);
+        for d_lat in [-1.0, 0.0, 1.0] {
+            for d_lng in [-1.0, 0.0, 1.0] {
+                let latitude = (center.0 + d_lat * height).clamp(-90.0, 90.0);
+                let longitude = wrap_longitude(center.1 + d_lng * width);
+                let cell = geohash(latitude, longitude, len);
+                if !cells.contains(&cell) {
+                    cells.push(cell);
+                }
+            }
+        }
+        Some(cells)
+    }
+}
+
+/// The height and width of the cells of geohashes of the given length, in degrees.
+fn cell_size(len: usize) -> (f64, f64) {
+    let bits = 5 * len as i32;
+    let lng_bits = (bits + 1) / 2;
+    let lat_bits = bits / 2;
+    (180.0 / 2f64.powi(lat_bits), 360.0 / 2f64.powi(lng_bits))
+}
+
+fn wrap_longitude(longitude: f64) -> f64 {
+    (longitude + 180.0).rem_euclid(360.0) - 180.0
+}
+
+fn unindexed(value: &Value) -> &Value {
+    match value {
+        Value::IndexedValue(value, _) => unindexed(value),
+        value => value,
+    }
+}
+
+/// Represents a query of the entities within a radius of a location, built by `Query::within_radius`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeoQuery {
+    pub(crate) query: Query,
+    pub(crate) property: String,
+    pub(crate) center: (f64, f64),
+    pub(crate) meters: f64,
+}
+
+impl GeoQuery {
+    /// The query sent to Datastore, matching the cells around the center.
+    pub fn query(&self) -> &Query {
+        &self.query
+    }
+
+    /// The center of the circle (latitude and longitude, in degrees).
+    pub fn center(&self) -> (f64, f64) {
+        self.center
+    }
+
+    /// The radius of the circle, in meters.
+    pub fn radius(&self) -> f64 {
+        self.meters
+    }
+
+    /// The distance of the entity to the center, if it is within the circle.
+    pub fn distance_of(&self, entity: &Entity) -> Option<f64> {
+        let properties = match entity.properties() {
+            Value::EntityValue(properties) => properties,
+            _ => return None,
+        };
+        let point = point_of(properties, self.property.as_str())?;
+        let distance = geo_distance(self.center, point);
+        Some(distance).filter(|distance| *distance <= self.meters)
+    }
+}
+
+fn point_of(properties: &HashMap<String, Value>, property: &str) -> Option<(f64, f64)> {
+    match properties.get(property).map(unindexed) {
+        Some(Value::GeoPointValue(latitude, longitude)) => Some((*latitude, *longitude)),
+        _ => None,
+    }
+}
+
+impl Query {
+    /// Restrict the query to the entities within the given distance (in meters) of a location
+    /// (latitude and longitude, in degrees), using a geohash index (see `GeoIndex`).
+    ///
+    /// The query is run with `Client::query_within`, which drops the entities of the matched cells
+    /// that are out of the circle: limits and offsets apply before this refinement, and the query
+    /// must return the indexed property (so it can't be keys-only, nor a projection without it).
+    pub fn within_radius(self, index: &GeoIndex, center: (f64, f64), meters: f64) -> GeoQuery {
+        let query = match index.covering_cells(center, meters) {
+            Some(cells) => {
+                let cells = cells.into_iter().map(Value::StringValue).collect();
+                self.filter(Filter::In(index.index_property(), Value::ArrayValue(cells)))
+            }
+            None => self,
+        };
+
+        GeoQuery {
+            query,
+            property: index.property.clone(),
+            center,
+            meters,
+        }
+    }
+}
+
+impl Client {
+    /// Runs a radius query (see `Query::within_radius`), returning the entities within the circle,
+    /// from the closest to the farthest.
+    pub async fn query_within(&mut self, query: GeoQuery) -> Result<Vec<Entity>, Error> {
+        let entities = self.query(query.query.clone()).await?;
+        let mut entities: Vec<(f64, Entity)> = entities
+            .into_iter()
+            .filter_map(|entity| Some((query.distance_of(&entity)?, entity)))
+            .collect();
+        entities.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+
+        Ok(entities.into_iter().map(|(_, entity)| entity).collect())
+    }
+}
@@ -0,0 +1,323 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, SecondsFormat, Utc};
+
+use crate::datastore::{Entity, Key, KeyID, Value};
+use crate::error::ConvertError;
+
+impl Entity {
+    /// Convert the entity to JSON, to log, diff or ship it to other systems.
+    ///
+    /// The mapping is the one of the Datastore REST API, so that other tools understand it:
+    /// - the entity is an object with its `key` and its `properties` (an object of property names to values).
+    /// - keys are objects with their `path` (an array of `kind` and `id` or `name` objects, from the root ancestor),
+    ///   and their namespace as `partitionId.namespaceId` (if any).
+    /// - values are objects with a single field named after their type, such as `{ "stringValue": "hello" }`.
+    /// - integers are decimal strings (`integerValue`), to not lose precision in JSON parsers using doubles.
+    /// - non-finite doubles are the strings `"NaN"`, `"Infinity"` and `"-Infinity"` (`doubleValue`).
+    /// - timestamps are RFC 3339 strings in UTC, such as `"2021-03-24T10:00:00.5Z"` (`timestampValue`).
+    /// - blobs are standard base64 strings (`blobValue`).
+    /// - geo points are objects with their `latitude` and `longitude` (`geoPointValue`).
+    /// - embedded entities are objects with their `properties` (`entityValue`).
+    /// - arrays are objects with their `values` (`arrayValue`).
+    /// - values excluded from indexes have an additional `"excludeFromIndexes": true` field.
+    ///
+    /// Converting the JSON back with `Entity::from_json` gives the canonical form of the entity
+    /// (see `Value::canonicalize`).
+    ///
+    /// ```
+    /// # use google_cloud::datastore::{Entity, Key, Value};
+    /// # use std::collections::HashMap;
+    /// let mut properties = HashMap::new();
+    /// properties.insert(String::from("age"), Value::IntegerValue(42));
+    /// let entity = Entity::new(Key::new("user").id("jane"), properties).unwrap();
+    ///
+    /// let json = entity.to_json();
+    /// assert_eq!(json["key"]["path"][0]["name"], "jane");
+    /// assert_eq!(json["properties"]["age"]["integerValue"], "42");
+    /// assert_eq!(Entity::from_json(json).unwrap(), entity);
+    /// ```
+    pub fn to_json(&self) -> json::Value {
+        let properties = match &self.properties {
+            Value::EntityValue(properties) => properties_to_json(properties, None),
+            _ => json::Value::Object(json::Map::new()),
+        };
+        json::json!({
+            "key": key_to_json(&self.key),
+            "properties": properties,
+        })
+    }
+
+    /// Parse an entity from JSON, with the mapping of `Entity::to_json`.
+    ///
+    /// Integers are also accepted as JSON numbers, and key IDs as either numbers or decimal strings.
+    pub fn from_json(value: json::Value) -> Result<Entity, ConvertError> {
+        let mut object = into_object(value, "entity")?;
+        let key = object
+            .remove("key")
+            .ok_or_else(|| invalid("entity without a key"))?;
+        let properties = properties_from_json(object.remove("properties"))?;
+        Ok(Entity {
+            key: key_from_json(key)?,
+            properties: Value::EntityValue(properties),
+        })
+    }
+}
+
+fn invalid(reason: impl Into<String>) -> ConvertError {
+    ConvertError::InvalidJson(reason.into())
+}
+
+fn into_object(
+    value: json::Value,
+    expected: &str,
+) -> Result<json::Map<String, json::Value>, ConvertError> {
+    match value {
+        json::Value::Object(object) => Ok(object),
+        _ => Err(invalid(format!("{} is not an object", expected))),
+    }
+}
+
+fn key_to_json(key: &Key) -> json::Value {
+    let mut path = Vec::new();
+    let mut current = Some(key);
+    while let Some(key) = current {
+        let mut element = json::Map::new();
+        element.insert(String::from("kind"), json::Value::from(key.kind.as_str()));
+        match &key.id {
+            KeyID::IntID(id) => {
+                element.insert(String::from("id"), json::Value::from(id.to_string()))
+            }
+            KeyID::StringID(name) => {
+                element.insert(String::from("name"), json::Value::from(name.as_str()))
+            }
+            KeyID::Incomplete => None,
+        };
+        path.push(json::Value::Object(element));
+        current = key.get_parent();
+    }
+    path.reverse();
+
+    let mut object = json::Map::new();
+    //? Only the namespace of the key itself matters, its ancestors share it.
+    if let Some(namespace) = key.namespace.as_deref().filter(|ns| !ns.is_empty()) {
+        object.insert(
+            String::from("partitionId"),
+            json::json!({ "namespaceId": namespace }),
+        );
+    }
+    object.insert(String::from("path"), json::Value::Array(path));
+    json::Value::Object(object)
+}
+
+fn key_from_json(value: json::Value) -> Result<Key, ConvertError> {
+    let mut object = into_object(value, "key")?;
+    let namespace = object
+        .remove("partitionId")
+        .and_then(|partition| partition.get("namespaceId").cloned());
+    let namespace = match namespace {
+        None => None,
+        Some(json::Value::String(namespace)) if namespace.is_empty() => None,
+        Some(json::Value::String(namespace)) => Some(namespace),
+        Some(_) => return Err(invalid("namespace is not a string")),
+    };
+    let path = match object.remove("path") {
+        Some(json::Value::Array(path)) => path,
+        _ => return Err(invalid("key without a path")),
+    };
+
+    let mut key: Option<Key> = None;
+    for element in path {
+        let mut element = into_object(element, "key path element")?;
+        let kind = match element.remove("kind") {
+            Some(json::Value::String(kind)) => kind,
+            _ => return Err(invalid("key path element without a kind")),
+        };
+        let id = match (element.remove("id"), element.remove("name")) {
+            (Some(id), _) => KeyID::IntID(parse_integer(&id)?),
+            (None, Some(json::Value::String(name))) => KeyID::StringID(name),
+            (None, Some(_)) => return Err(invalid("key name is not a string")),
+            (None, None) => KeyID::Incomplete,
+        };
+        let mut current = Key::new(kind).id(id);
+        if let Some(namespace) = namespace.as_deref() {
+            current = current.namespace(namespace);
+        }
+        key = Some(match key {
+            Some(ancestor) => current.parent(ancestor),
+            None => current,
+        });
+    }
+    key.ok_or_else(|| invalid("key with an empty path"))
+}
+
+fn properties_to_json(properties: &HashMap<String, Value>, indexed: Option<bool>) -> json::Value {
+    let properties = properties
+        .iter()
+        .map(|(name, value)| (name.clone(), value_to_json(value, indexed)));
+    json::Value::Object(properties.collect())
+}
+
+fn properties_from_json(
+    properties: Option<json::Value>,
+) -> Result<HashMap<String, Value>, ConvertError> {
+    match properties {
+        None => Ok(HashMap::new()),
+        Some(properties) => into_object(properties, "properties")?
+            .into_iter()
+            .map(|(name, value)| Ok((name, value_from_json(value)?)))
+            .collect(),
+    }
+}
+
+/// Converts a value to JSON, with the index flag of its outermost `IndexedValue` (if any),
+/// which applies to the elements of arrays, and to the properties of entities excluded from indexes
+/// (like in `Value::canonicalize`).
+fn value_to_json(value: &Value, indexed: Option<bool>) -> json::Value {
+    let (kind, encoded) = match value {
+        Value::IndexedValue(inner, flag) => {
+            return value_to_json(inner, indexed.or(Some(*flag)));
+        }
+        Value::ArrayValue(values) => {
+            let values = values.iter().map(|value| value_to_json(value, indexed));
+            let values = json::Value::Array(values.collect());
+            return json::json!({ "arrayValue": { "values": values } });
+        }
+        Value::BooleanValue(value) => ("booleanValue", json::Value::from(*value)),
+        Value::IntegerValue(value) => ("integerValue", json::Value::from(value.to_string())),
+        Value::DoubleValue(value) => ("doubleValue", double_to_json(*value)),
+        Value::TimestampValue(value) => {
+            let timestamp = DateTime::<Utc>::from_naive_utc_and_offset(*value, Utc);
+            let timestamp = timestamp.to_rfc3339_opts(SecondsFormat::AutoSi, true);
+            ("timestampValue", json::Value::from(timestamp))
+        }
+        Value::KeyValue(key) => ("keyValue", key_to_json(key)),
+        Value::StringValue(value) => ("stringValue", json::Value::from(value.as_str())),
+        Value::BlobValue(value) => ("blobValue", json::Value::from(base64::encode(value))),
+        Value::GeoPointValue(latitude, longitude) => (
+            "geoPointValue",
+            json::json!({
+                "latitude": double_to_json(*latitude),
+                "longitude": double_to_json(*longitude),
+            }),
+        ),
+        Value::EntityValue(properties) => (
+            "entityValue",
+            json::json!({ "properties": properties_to_json(properties, indexed.filter(|indexed| !indexed)) }),
+        ),
+    };
+
+    let mut object = json::Map::new();
+    object.insert(String::from(kind), encoded);
+    if indexed == Some(false) {
+        object.insert(String::from("excludeFromIndexes"), json::Value::Bool(true));
+    }
+    json::Value::Object(object)
+}
+
+fn value_from_json(value: json::Value) -> Result<Value, ConvertError> {
+    let mut object = into_object(value, "value")?;
+    let excluded = match object.remove("excludeFromIndexes") {
+        None => false,
+        Some(json::Value::Bool(excluded)) => excluded,
+        Some(_) => return Err(invalid("`excludeFromIndexes` is not a boolean")),
+    };
+    //? Other fields of the REST API (such as `meaning`) are ignored.
+    let field = object.into_iter().find(|(kind, _)| kind.ends_with("Value"));
+    let (kind, value) = match field {
+        Some(field) => field,
+        None => return Err(invalid("value without a type")),
+    };
+    let value = match kind.as_str() {
+        "booleanValue" => Value::BooleanValue(
+            value
+                .as_bool()
+                .ok_or_else(|| invalid("invalid boolean value"))?,
+        ),
+        "integerValue" => Value::IntegerValue(parse_integer(&value)?),
+        "doubleValue" => Value::DoubleValue(parse_double(&value)?),
+        "timestampValue" => {
+            let timestamp = value
+                .as_str()
+                .and_then(|value| DateTime::parse_from_rfc3339(value).ok())
+                .ok_or_else(|| invalid("invalid timestamp value"))?;
+            Value::TimestampValue(timestamp.naive_utc())
+        }
+        "keyValue" => Value::KeyValue(key_from_json(value)?),
+        "stringValue" => match value {
+            json::Value::String(value) => Value::StringValue(value),
+            _ => return Err(invalid("invalid string value")),
+        },
+        "blobValue" => {
+            let blob = value
+                .as_str()
+                .and_then(|value| base64::decode(value).ok())
+                .ok_or_else(|| invalid("invalid blob value"))?;
+            Value::BlobValue(blob)
+        }
+        "geoPointValue" => {
+            let latitude = value.get("latitude").map(parse_double).transpose()?;
+            let longitude = value.get("longitude").map(parse_double).transpose()?;
+            Value::GeoPointValue(latitude.unwrap_or(0.0), longitude.unwrap_or(0.0))
+        }
+        "entityValue" => {
+            //? Embedded entities may have a key in the REST API, which `Value::EntityValue` cannot hold.
+            let mut entity = into_object(value, "entity value")?;
+            Value::EntityValue(properties_from_json(entity.remove("properties"))?)
+        }
+        "arrayValue" => {
+            if excluded {
+                return Err(invalid("arrays cannot be excluded from indexes"));
+            }
+            let values = match into_object(value, "array value")?.remove("values") {
+                None => Vec::new(),
+                Some(json::Value::Array(values)) => values,
+                Some(_) => return Err(invalid("invalid array value")),
+            };
+            let values = values
+                .into_iter()
+                .map(value_from_json)
+                .collect::<Result<_, _>>()?;
+            Value::ArrayValue(values)
+        }
+        kind => return Err(ConvertError::UnsupportedValue(String::from(kind))),
+    };
+
+    if excluded {
+        Ok(Value::IndexedValue(Box::new(value), false))
+    } else {
+        Ok(value)
+    }
+}
+
+fn parse_integer(value: &json::Value) -> Result<i64, ConvertError> {
+    match value {
+        json::Value::String(value) => value.parse().ok(),
+        json::Value::Number(value) => value.as_i64(),
+        _ => None,
+    }
+    .ok_or_else(|| invalid("invalid integer value"))
+}
+
+fn double_to_json(value: f64) -> json::Value {
+    match json::Number::from_f64(value) {
+        Some(number) => json::Value::Number(number),
+        None if value.is_nan() => json::Value::from("NaN"),
+        None if value > 0.0 => json::Value::from("Infinity"),
+        None => json::Value::from("-Infinity"),
+    }
+}
+
+fn parse_double(value: &json::Value) -> Result<f64, ConvertError> {
+    match value {
+        json::Value::Number(value) => value.as_f64(),
+        json::Value::String(value) => match value.as_str() {
+            "NaN" => Some(f64::NAN),
+            "Infinity" => Some(f64::INFINITY),
+            "-Infinity" => Some(f64::NEG_INFINITY),
+            _ => None,
+        },
+        _ => None,
+    }
+    .ok_or_else(|| invalid("invalid double value"))
+}
@@ -1,11 +1,27 @@
 use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+use prost::Message;
 
 use crate::datastore::api;
 use crate::datastore::api::key::path_element::IdType;
+use crate::error::ConvertError;
 
 /// Represents a key's ID.
 ///
 /// It can either be a integer key, a string/named key or an incomplete key.
+///
+/// IDs are ordered like in Datastore: incomplete IDs come first, then integer IDs, then string IDs.
+///
+/// ```
+/// # use google_cloud::datastore::KeyID;
+/// assert!(KeyID::Incomplete < KeyID::IntID(10));
+/// assert!(KeyID::IntID(10) < KeyID::IntID(20));
+/// assert!(KeyID::IntID(20) < KeyID::StringID(String::from("a")));
+/// ```
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum KeyID {
     /// A string/named key ID.
@@ -31,6 +47,26 @@ impl KeyID {
     }
 }
 
+impl Ord for KeyID {
+    fn cmp(&self, other: &KeyID) -> Ordering {
+        match (self, other) {
+            (KeyID::Incomplete, KeyID::Incomplete) => Ordering::Equal,
+            (KeyID::Incomplete, _) => Ordering::Less,
+            (_, KeyID::Incomplete) => Ordering::Greater,
+            (KeyID::IntID(a), KeyID::IntID(b)) => a.cmp(b),
+            (KeyID::IntID(_), KeyID::StringID(_)) => Ordering::Less,
+            (KeyID::StringID(_), KeyID::IntID(_)) => Ordering::Greater,
+            (KeyID::StringID(a), KeyID::StringID(b)) => a.cmp(b),
+        }
+    }
+}
+
+impl PartialOrd for KeyID {
+    fn partial_cmp(&self, other: &KeyID) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl From<i64> for KeyID {
     fn from(id: i64) -> KeyID {
         KeyID::IntID(id)
@@ -49,6 +85,76 @@ impl From<String> for KeyID {
     }
 }
 
+impl<T> From<Option<T>> for KeyID
+where
+    T: Into<KeyID>,
+{
+    fn from(id: Option<T>) -> KeyID {
+        id.map_or(KeyID::Incomplete, Into::into)
+    }
+}
+
+/// Trait for converting a key's ID back into a type (the inverse of `Into<KeyID>`).
+///
+/// ```
+/// # use google_cloud::datastore::{FromKeyID, KeyID};
+/// assert_eq!(i64::from_key_id(KeyID::IntID(10)).unwrap(), 10);
+/// assert_eq!(Option::<i64>::from_key_id(KeyID::Incomplete).unwrap(), None);
+/// assert!(String::from_key_id(KeyID::IntID(10)).is_err());
+/// ```
+pub trait FromKeyID: Sized {
+    /// Attempts to convert the key's ID into the type.
+    fn from_key_id(id: KeyID) -> Result<Self, ConvertError>;
+}
+
+fn unexpected_id(expected: &str, got: &KeyID) -> ConvertError {
+    let got = match got {
+        KeyID::StringID(_) => "string ID",
+        KeyID::IntID(_) => "integer ID",
+        KeyID::Incomplete => "incomplete ID",
+    };
+    ConvertError::UnexpectedPropertyType {
+        expected: String::from(expected),
+        got: String::from(got),
+    }
+}
+
+impl FromKeyID for KeyID {
+    fn from_key_id(id: KeyID) -> Result<KeyID, ConvertError> {
+        Ok(id)
+    }
+}
+
+impl FromKeyID for i64 {
+    fn from_key_id(id: KeyID) -> Result<i64, ConvertError> {
+        match id {
+            KeyID::IntID(id) => Ok(id),
+            id => Err(unexpected_id("integer ID", &id)),
+        }
+    }
+}
+
+impl FromKeyID for String {
+    fn from_key_id(id: KeyID) -> Result<String, ConvertError> {
+        match id {
+            KeyID::StringID(id) => Ok(id),
+            id => Err(unexpected_id("string ID", &id)),
+        }
+    }
+}
+
+impl<T> FromKeyID for Option<T>
+where
+    T: FromKeyID,
+{
+    fn from_key_id(id: KeyID) -> Result<Option<T>, ConvertError> {
+        match id {
+            KeyID::Incomplete => Ok(None),
+            id => Ok(Some(T::from_key_id(id)?)),
+        }
+    }
+}
+
 impl From<IdType> for KeyID {
     fn from(id_type: IdType) -> KeyID {
         match id_type {
@@ -64,7 +170,29 @@ impl From<IdType> for KeyID {
 /// # use google_cloud::datastore::Key;
 /// let key = Key::new("kind").id("entity-name");
 /// ```
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+///
+/// Keys identify entities the same way Datastore does, which defines their equality, hashing and ordering:
+/// - two keys are equal if they have the same namespace and the same path (the kinds and IDs of the key and all its ancestors).
+/// - an empty namespace is the same as no namespace.
+/// - only the namespace of the key itself matters, the namespaces of its ancestors are ignored (Datastore uses a single namespace for the whole path).
+/// - keys are ordered by namespace (keys without namespace first), then by path, element by element from the root ancestor, comparing kinds and then IDs.
+///   An ancestor comes before all of its descendants.
+///
+/// This makes keys usable in both `HashMap`s and `BTreeMap`s:
+///
+/// ```
+/// # use std::collections::BTreeSet;
+/// # use google_cloud::datastore::Key;
+/// let parent = Key::new("parent").id(1);
+/// let child = Key::new("child").id("a").parent(parent.clone());
+/// let other = Key::new("child").id(2).parent(parent.clone());
+///
+/// let keys: BTreeSet<Key> = vec![child.clone(), other.clone(), parent.clone()].into_iter().collect();
+/// assert_eq!(keys.into_iter().collect::<Vec<_>>(), vec![parent, other, child]);
+///
+/// assert_eq!(Key::new("kind").id(1).namespace(""), Key::new("kind").id(1));
+/// ```
+#[derive(Debug, Clone)]
 pub struct Key {
     pub(crate) kind: String,
     pub(crate) id: KeyID,
@@ -73,6 +201,9 @@ pub struct Key {
 }
 
 impl Key {
+    //? The maximum number of elements of a key path, the key itself included.
+    const MAX_PATH_LEN: usize = 100;
+
     /// Create a new incomplete key.
     ///
     /// ```
@@ -134,6 +265,39 @@ impl Key {
         self
     }
 
+    /// Attach a string ID (or name) to the key.
+    ///
+    /// ```
+    /// # use google_cloud::datastore::{Key, KeyID};
+    /// let key = Key::new("kind").name("entity-name");
+    /// assert_eq!(key.get_id(), &KeyID::StringID(String::from("entity-name")));
+    /// ```
+    pub fn name(self, name: impl Into<String>) -> Key {
+        self.id(KeyID::StringID(name.into()))
+    }
+
+    /// Create a new incomplete key of the given kind, as a child of this key (and in its namespace).
+    ///
+    /// This builds key paths from their root ancestor, instead of nesting parent keys:
+    ///
+    /// ```
+    /// # use google_cloud::datastore::Key;
+    /// let key = Key::new("Org").name("acme").child("User").id(42).child("Session").name("abc");
+    /// let nested = Key::new("Session")
+    ///     .name("abc")
+    ///     .parent(Key::new("User").id(42).parent(Key::new("Org").name("acme")));
+    /// assert_eq!(key, nested);
+    /// ```
+    pub fn child(self, kind: impl Into<String>) -> Key {
+        let namespace = self.namespace.clone();
+        Key {
+            kind: kind.into(),
+            id: KeyID::Incomplete,
+            parent: Some(Box::new(self)),
+            namespace,
+        }
+    }
+
     /// Get the key's ancestor key, if any.
     ///
     /// ```
@@ -183,11 +347,238 @@ impl Key {
     pub fn is_incomplete(&self) -> bool {
         self.get_id().is_incomplete()
     }
+
+    /// Encode the key in the URL-safe format of the other Datastore client libraries
+    /// (like `Key.to_legacy_urlsafe` in Python or `Key.Encode` in Go), within the given project.
+    ///
+    /// Some libraries expect the project to be prefixed with its location (like `s~my-project`).
+    ///
+    /// ```
+    /// # use google_cloud::datastore::Key;
+    /// let key = Key::new("Child").id("Feather").parent(Key::new("Parent").id(59));
+    /// let encoded = key.to_url_safe("s~sample-app");
+    /// assert_eq!(encoded, "agxzfnNhbXBsZS1hcHByHgsSBlBhcmVudBg7DAsSBUNoaWxkIgdGZWF0aGVyDA");
+    /// assert_eq!(Key::from_url_safe(&encoded)?, (String::from("sample-app"), key));
+    /// # Ok::<(), google_cloud::error::ConvertError>(())
+    /// ```
+    pub fn to_url_safe(&self, project: &str) -> String {
+        let elements = self.path().into_iter().map(|(kind, id)| {
+            let (id, name) = match id {
+                KeyID::IntID(id) => (Some(*id), None),
+                KeyID::StringID(name) => (None, Some(name.clone())),
+                KeyID::Incomplete => (None, None),
+            };
+            reference::Element {
+                r#type: String::from(kind),
+                id,
+                name,
+            }
+        });
+        let reference = reference::Reference {
+            app: String::from(project),
+            path: Some(reference::Path {
+                element: elements.collect(),
+            }),
+            name_space: self.effective_namespace().map(String::from),
+        };
+
+        base64::encode_config(reference.encode_to_vec(), base64::URL_SAFE_NO_PAD)
+    }
+
+    /// Decode a key from the URL-safe format of the other Datastore client libraries,
+    /// along with its project (stripped of its location prefix, if any).
+    pub fn from_url_safe(encoded: &str) -> Result<(String, Key), ConvertError> {
+        let invalid = || ConvertError::InvalidKey(String::from(encoded));
+        //? Some libraries keep the padding of the base64 encoding.
+        let bytes = base64::decode_config(encoded.trim_end_matches('='), base64::URL_SAFE_NO_PAD)
+            .map_err(|_| invalid())?;
+        let reference = reference::Reference::decode(bytes.as_slice()).map_err(|_| invalid())?;
+        let elements = reference.path.map(|path| path.element).unwrap_or_default();
+        let namespace = reference
+            .name_space
+            .filter(|namespace| !namespace.is_empty());
+        let key = elements.into_iter().fold(None, |acc, el| {
+            let id = match (el.id, el.name) {
+                (Some(id), _) => KeyID::IntID(id),
+                (None, Some(name)) => KeyID::StringID(name),
+                (None, None) => KeyID::Incomplete,
+            };
+            let key = Key::new(el.r#type).id(id);
+            let key = match namespace.as_ref() {
+                Some(namespace) => key.namespace(namespace.as_str()),
+                None => key,
+            };
+            match acc {
+                Some(ancestor) => Some(key.parent(ancestor)),
+                None => Some(key),
+            }
+        });
+        let key = key.ok_or_else(invalid)?;
+        let project = match reference.app.split_once('~') {
+            Some((_, project)) => String::from(project),
+            None => reference.app,
+        };
+
+        Ok((project, key))
+    }
+
+    /// Check the key against the constraints Datastore puts on key paths, before sending it:
+    ///
+    /// - paths have at most 100 elements.
+    /// - kinds are neither empty, nor reserved (like `__kind__`, matching `__.*__`).
+    /// - string IDs are neither empty, nor reserved, and integer IDs are positive.
+    /// - only the key itself may be incomplete, not its ancestors.
+    ///
+    /// ```
+    /// # use google_cloud::datastore::Key;
+    /// assert!(Key::new("Org").name("acme").child("User").validate().is_ok());
+    /// assert!(Key::new("Org").child("User").id(42).validate().is_err());
+    /// assert!(Key::new("__kind__").name("User").validate().is_err());
+    /// ```
+    pub fn validate(&self) -> Result<(), ConvertError> {
+        let path = self.path();
+        let invalid = |reason: String| ConvertError::InvalidKeyPath {
+            key: self.to_string(),
+            reason,
+        };
+        if path.len() > Key::MAX_PATH_LEN {
+            let reason = format!("more than {} path elements", Key::MAX_PATH_LEN);
+            return Err(invalid(reason));
+        }
+        let reserved =
+            |name: &str| name.len() > 4 && name.starts_with("__") && name.ends_with("__");
+        let last = path.len() - 1;
+        for (idx, (kind, id)) in path.into_iter().enumerate() {
+            if kind.is_empty() || reserved(kind) {
+                return Err(invalid(format!("invalid kind `{}`", kind)));
+            }
+            match id {
+                KeyID::StringID(name) if name.is_empty() || reserved(name) => {
+                    return Err(invalid(format!("invalid name `{}`", name)));
+                }
+                KeyID::IntID(id) if *id <= 0 => {
+                    return Err(invalid(format!("invalid ID `{}`", id)));
+                }
+                KeyID::Incomplete if idx < last => {
+                    return Err(invalid(format!("incomplete ancestor of kind `{}`", kind)));
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The effective namespace of the key, an empty one being the same as no namespace.
+    fn effective_namespace(&self) -> Option<&str> {
+        self.get_namespace()
+            .filter(|namespace| !namespace.is_empty())
+    }
+
+    /// The path elements (kind and ID) of the key, starting from the root ancestor.
+    fn path(&self) -> Vec<(&str, &KeyID)> {
+        let mut path = Vec::new();
+        let mut current = Some(self);
+        while let Some(key) = current {
+            path.push((key.get_kind(), key.get_id()));
+            current = key.get_parent();
+        }
+        path.reverse();
+        path
+    }
+
+    /// Returns the key such as it would be returned by Datastore.
+    ///
+    /// Datastore only stores a single namespace for the whole key path, so every ancestor
+    /// takes the namespace of the key itself, and empty namespaces are removed.
+    pub(crate) fn canonicalize(self) -> Key {
+        let namespace = self.namespace.filter(|namespace| !namespace.is_empty());
+        Key::with_namespace(self.kind, self.id, self.parent, namespace)
+    }
+
+    fn with_namespace(
+        kind: String,
+        id: KeyID,
+        parent: Option<Box<Key>>,
+        namespace: Option<String>,
+    ) -> Key {
+        let parent = parent.map(|parent| {
+            let parent = *parent;
+            let parent =
+                Key::with_namespace(parent.kind, parent.id, parent.parent, namespace.clone());
+            Box::new(parent)
+        });
+        Key {
+            kind,
+            id,
+            parent,
+            namespace,
+        }
+    }
+}
+
+impl PartialEq for Key {
+    fn eq(&self, other: &Key) -> bool {
+        self.effective_namespace() == other.effective_namespace() && self.path() == other.path()
+    }
+}
+
+impl Eq for Key {}
+
+impl Hash for Key {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.effective_namespace().hash(state);
+        self.path().hash(state);
+    }
 }
 
-impl From<api::Key> for Key {
-    fn from(key: api::Key) -> Key {
-        let data = key.partition_id.unwrap();
+impl Ord for Key {
+    fn cmp(&self, other: &Key) -> Ordering {
+        self.effective_namespace()
+            .cmp(&other.effective_namespace())
+            .then_with(|| self.path().cmp(&other.path()))
+    }
+}
+
+impl PartialOrd for Key {
+    fn partial_cmp(&self, other: &Key) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Formats the path of the key from its root ancestor, followed by its namespace (as used in error messages).
+///
+/// ```
+/// # use google_cloud::datastore::Key;
+/// let key = Key::new("User").id(42).parent(Key::new("Org").id("acme")).namespace("dev");
+/// assert_eq!(key.to_string(), r#"Kind=Org name="acme" / Kind=User id=42 (namespace=dev)"#);
+/// ```
+impl fmt::Display for Key {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (idx, (kind, id)) in self.path().into_iter().enumerate() {
+            if idx > 0 {
+                write!(f, " / ")?;
+            }
+            match id {
+                KeyID::Incomplete => write!(f, "Kind={} (incomplete)", kind)?,
+                KeyID::IntID(id) => write!(f, "Kind={} id={}", kind, id)?,
+                KeyID::StringID(name) => write!(f, "Kind={} name={:?}", kind, name)?,
+            }
+        }
+        match self.effective_namespace() {
+            Some(namespace) => write!(f, " (namespace={})", namespace),
+            None => Ok(()),
+        }
+    }
+}
+
+impl TryFrom<api::Key> for Key {
+    type Error = ConvertError;
+
+    fn try_from(key: api::Key) -> Result<Key, ConvertError> {
+        let data = key
+            .partition_id
+            .ok_or_else(|| ConvertError::MissingField(String::from("partition_id")))?;
         let key = key.path.into_iter().fold(None, |acc, el| {
             let key_id = match el.id_type {
                 None => KeyID::Incomplete,
@@ -209,6 +600,35 @@ impl From<api::Key> for Key {
         });
 
         //? There should always be at least one.
-        key.unwrap()
+        key.ok_or_else(|| ConvertError::MissingField(String::from("path")))
+    }
+}
+
+/// The `Reference` message of the legacy App Engine Datastore API, which URL-safe keys encode.
+mod reference {
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct Reference {
+        #[prost(string, tag = "13")]
+        pub app: String,
+        #[prost(message, optional, tag = "14")]
+        pub path: Option<Path>,
+        #[prost(string, optional, tag = "20")]
+        pub name_space: Option<String>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct Path {
+        #[prost(group, repeated, tag = "1")]
+        pub element: Vec<Element>,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct Element {
+        #[prost(string, tag = "2")]
+        pub r#type: String,
+        #[prost(int64, optional, tag = "3")]
+        pub id: Option<i64>,
+        #[prost(string, optional, tag = "4")]
+        pub name: Option<String>,
     }
 }
@@ -0,0 +1,314 @@
+use std::collections::HashMap;
+use std::future::Future;
+
+use futures::stream::{self, StreamExt};
+
+use crate::datastore::{Client, Cursor, Entity, Error, Key, MoreResults, Query, Value};
+use crate::error::ConvertError;
+
+/// Represents the progress of a migration, as recorded in its checkpoint entity.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct MigrationProgress {
+    pub(crate) cursor: Option<Cursor>,
+    pub(crate) processed: i64,
+    pub(crate) updated: i64,
+    pub(crate) finished: bool,
+}
+
+impl MigrationProgress {
+    /// The cursor to resume the migration from, if it has started.
+    pub fn cursor(&self) -> Option<&Cursor> {
+        self.cursor.as_ref()
+    }
+
+    /// The number of entities processed so far.
+    pub fn processed(&self) -> i64 {
+        self.processed
+    }
+
+    /// The number of entities written back so far.
+    pub fn updated(&self) -> i64 {
+        self.updated
+    }
+
+    /// Has the migration gone through all the entities ?
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    pub(crate) fn into_value(self) -> Value {
+        let mut properties = HashMap::new();
+        let cursor = match self.cursor {
+            Some(cursor) => Value::BlobValue(cursor.0),
+            None => Value::BlobValue(Vec::new()),
+        };
+        //? Cursors can exceed the size limit of indexed values.
+        properties.insert(
+            String::from("cursor"),
+            Value::IndexedValue(Box::new(cursor), false),
+        );
+        properties.insert(
+            String::from("processed"),
+            Value::IntegerValue(self.processed),
+        );
+        properties.insert(String::from("updated"), Value::IntegerValue(self.updated));
+        properties.insert(String::from("finished"), Value::BooleanValue(self.finished));
+        Value::EntityValue(properties)
+    }
+
+    pub(crate) fn from_value(value: Value) -> Result<MigrationProgress, ConvertError> {
+        fn unwrap_indexed(value: Option<Value>) -> Option<Value> {
+            match value {
+                Some(Value::IndexedValue(value, _)) => Some(*value),
+                value => value,
+            }
+        }
+
+        let mut properties = match unwrap_indexed(Some(value)) {
+            Some(Value::EntityValue(properties)) => properties,
+            value => {
+                return Err(ConvertError::UnexpectedPropertyType {
+                    expected: String::from("entity"),
+                    got: String::from(value.map_or("null", |value| value.type_name())),
+                })
+            }
+        };
+        let mut property = |name: &str| {
+            unwrap_indexed(properties.remove(name))
+                .ok_or_else(|| ConvertError::MissingProperty(String::from(name)))
+        };
+        let unexpected = |expected: &str, value: Value| ConvertError::UnexpectedPropertyType {
+            expected: String::from(expected),
+            got: String::from(value.type_name()),
+        };
+
+        let cursor = match property("cursor")? {
+            Value::BlobValue(bytes) => Cursor::from_api(bytes),
+            value => return Err(unexpected("blob", value)),
+        };
+        let processed = match property("processed")? {
+            Value::IntegerValue(processed) => processed,
+            value => return Err(unexpected("integer", value)),
+        };
+        let updated = match property("updated")? {
+            Value::IntegerValue(updated) => updated,
+            value => return Err(unexpected("integer", value)),
+        };
+        let finished = match property("finished")? {
+            Value::BooleanValue(finished) => finished,
+            value => return Err(unexpected("bool", value)),
+        };
+
+        Ok(MigrationProgress {
+            cursor,
+            processed,
+            updated,
+            finished,
+        })
+    }
+}
+
+/// Describes the outcome of a migration run.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct MigrationReport {
+    pub(crate) progress: MigrationProgress,
+    pub(crate) pages: usize,
+    pub(crate) resumed: bool,
+    pub(crate) dry_run: bool,
+}
+
+impl MigrationReport {
+    /// The progress of the migration at the end of the run (including the previous runs it resumed from).
+    pub fn progress(&self) -> &MigrationProgress {
+        &self.progress
+    }
+
+    /// The number of pages of entities processed during this run.
+    pub fn pages(&self) -> usize {
+        self.pages
+    }
+
+    /// Did the run resume from the checkpoint of a previous one ?
+    pub fn resumed(&self) -> bool {
+        self.resumed
+    }
+
+    /// Was it a dry run (nothing written, the updated entities are the ones that would have been) ?
+    pub fn is_dry_run(&self) -> bool {
+        self.dry_run
+    }
+}
+
+/// Migrates the entities matched by a query, page by page, writing back the ones changed by a transform.
+///
+/// The progress is recorded in a checkpoint entity after every page,
+/// so that an interrupted migration resumes where it left off when run again (under the same name).
+/// Once finished, running it again does nothing.
+///
+/// The transform returns the updated entity, or `None` to leave the entity as it is.
+/// Since an interrupted page gets processed again, it should be idempotent.
+///
+/// ```no_run
+/// # use google_cloud::datastore::{Client, Error, Query, Value};
+/// # async fn run(client: Client) -> Result<(), Error> {
+/// let report = client
+///     .migrate("backfill-user-status", Query::new("User"))
+///     .concurrency(16)
+///     .run(|mut entity| async move {
+///         if let Value::EntityValue(properties) = entity.properties_mut() {
+///             if properties.contains_key("status") {
+///                 return Ok(None);
+///             }
+///             properties.insert(String::from("status"), Value::StringValue(String::from("active")));
+///         }
+///         Ok(Some(entity))
+///     })
+///     .await?;
+/// println!("{} users updated", report.progress().updated());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct Migration {
+    pub(crate) client: Client,
+    pub(crate) name: String,
+    pub(crate) query: Query,
+    pub(crate) checkpoint_kind: String,
+    pub(crate) page_size: i32,
+    pub(crate) concurrency: usize,
+    pub(crate) chunk_size: usize,
+    pub(crate) dry_run: bool,
+}
+
+impl Migration {
+    /// The default kind of the checkpoint entities.
+    pub const DEFAULT_CHECKPOINT_KIND: &'static str = "_migration";
+
+    const MAX_CHUNK_SIZE: usize = Client::MAX_MUTATIONS;
+
+    pub(crate) fn new(client: Client, name: impl Into<String>, query: Query) -> Migration {
+        Migration {
+            client,
+            name: name.into(),
+            query,
+            checkpoint_kind: String::from(Migration::DEFAULT_CHECKPOINT_KIND),
+            page_size: 300,
+            concurrency: 8,
+            chunk_size: Migration::MAX_CHUNK_SIZE,
+            dry_run: false,
+        }
+    }
+
+    /// Set the kind of the checkpoint entity (whose ID is the name of the migration).
+    pub fn checkpoint_kind(mut self, kind: impl Into<String>) -> Migration {
+        self.checkpoint_kind = kind.into();
+        self
+    }
+
+    /// Set the number of entities fetched (and checkpointed) at once.
+    pub fn page_size(mut self, page_size: i32) -> Migration {
+        self.page_size = page_size.max(1);
+        self
+    }
+
+    /// Set the maximum number of entities being transformed concurrently.
+    pub fn concurrency(mut self, concurrency: usize) -> Migration {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Set the maximum number of entities written back per commit (at most 500).
+    pub fn chunk_size(mut self, chunk_size: usize) -> Migration {
+        self.chunk_size = chunk_size.clamp(1, Migration::MAX_CHUNK_SIZE);
+        self
+    }
+
+    /// Enable or disable dry runs, which transform the entities without writing anything (not even the checkpoint).
+    pub fn dry_run(mut self, dry_run: bool) -> Migration {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// The key of the checkpoint entity.
+    pub fn checkpoint_key(&self) -> Key {
+        Key::new(self.checkpoint_kind.as_str()).id(self.name.as_str())
+    }
+
+    /// Get the recorded progress of the migration, if it has started.
+    pub async fn progress(&mut self) -> Result<Option<MigrationProgress>, Error> {
+        let key = self.checkpoint_key();
+        let value: Option<Value> = self.client.get(&key).await?;
+        let progress = value.map(MigrationProgress::from_value).transpose()?;
+        Ok(progress)
+    }
+
+    /// Run (or resume) the migration, until all the entities have been processed.
+    pub async fn run<F, Fut>(&mut self, transform: F) -> Result<MigrationReport, Error>
+    where
+        F: Fn(Entity) -> Fut,
+        Fut: Future<Output = Result<Option<Entity>, Error>>,
+    {
+        let checkpoint = self.progress().await?;
+        let mut report = MigrationReport {
+            resumed: checkpoint.is_some(),
+            progress: checkpoint.unwrap_or_default(),
+            pages: 0,
+            dry_run: self.dry_run,
+        };
+
+        while !report.progress.finished {
+            let mut query = self.query.clone().limit(self.page_size);
+            if let Some(cursor) = report.progress.cursor.clone() {
+                query.start_cursor = Some(cursor);
+            }
+            let results = self.client.query_with_meta(query).await?;
+            let more_results = results.more_results();
+            let end_cursor = results.end_cursor().cloned();
+            let entities = results.into_entities();
+            let processed = entities.len();
+
+            let transformed: Vec<_> = stream::iter(entities.into_iter().map(&transform))
+                .buffered(self.concurrency)
+                .collect()
+                .await;
+            let mut updated = Vec::new();
+            for entity in transformed {
+                updated.extend(entity?);
+            }
+            let updated_count = updated.len();
+
+            if !self.dry_run {
+                while !updated.is_empty() {
+                    let rest = updated.split_off(updated.len().min(self.chunk_size));
+                    self.client.put_all(updated).await?;
+                    updated = rest;
+                }
+            }
+
+            //? A page cut short by the limit means that more entities may follow, after its end cursor.
+            let progress = &mut report.progress;
+            progress.processed += processed as i64;
+            progress.updated += updated_count as i64;
+            progress.finished = processed == 0 || more_results != MoreResults::AfterLimit;
+            if end_cursor.is_some() {
+                progress.cursor = end_cursor;
+            }
+            report.pages += 1;
+
+            if !self.dry_run {
+                let progress = report.progress.clone().into_value();
+                let checkpoint = Entity::new(self.checkpoint_key(), progress)?;
+                self.client.put(checkpoint).await?;
+            }
+        }
+
+        Ok(report)
+    }
+}
+
+impl Client {
+    /// Prepare a migration of the entities matched by the given query, identified by its name (see `Migration`).
+    pub fn migrate(&self, name: impl Into<String>, query: Query) -> Migration {
+        Migration::new(self.clone(), name, query)
+    }
+}
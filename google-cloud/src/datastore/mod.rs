@@ -1,26 +1,68 @@
+/// Datastore administration (exports and imports of entities, and composite indexes).
+pub mod admin;
+mod batch;
 mod client;
+mod decode;
 mod entity;
+mod explain;
+mod geo;
+mod json_format;
 mod key;
+mod migrate;
 mod query;
+mod repository;
+mod retry;
+mod scan;
+mod snapshot;
+mod transaction;
 mod value;
-mod api {
+pub(crate) mod api {
+    #[allow(dead_code, clippy::doc_overindented_list_items)]
+    pub mod rpc {
+        include!("api/google.rpc.rs");
+    }
+    #[allow(dead_code, clippy::doc_lazy_continuation)]
+    pub mod longrunning {
+        include!("api/google.longrunning.rs");
+    }
     pub mod r#type {
         include!("api/google.r#type.rs");
     }
     pub mod datastore {
-        #![allow(clippy::enum_variant_names, clippy::large_enum_variant)]
+        #![allow(
+            clippy::enum_variant_names,
+            clippy::large_enum_variant,
+            clippy::doc_lazy_continuation,
+            clippy::doc_overindented_list_items
+        )]
         pub mod v1 {
             include!("api/google.datastore.v1.rs");
         }
+        pub mod admin {
+            #[allow(dead_code)]
+            pub mod v1 {
+                include!("api/google.datastore.admin.v1.rs");
+            }
+        }
     }
     pub use self::datastore::v1::*;
     pub use self::r#type::*;
 }
 
+pub use self::batch::*;
 pub use self::client::*;
+pub use self::decode::*;
 pub use self::entity::*;
+pub use self::explain::*;
+pub use self::geo::*;
 pub use self::key::*;
+pub use self::migrate::*;
 pub use self::query::*;
+pub use self::repository::*;
+pub use self::retry::*;
+pub use self::scan::*;
+pub use self::snapshot::*;
+pub use self::transaction::*;
 pub use self::value::*;
 
 /// The error type for the Datastore module.
@@ -1,4 +1,12 @@
-use crate::datastore::{Key, Value};
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::NaiveDateTime;
+
+use crate::datastore::api;
+use crate::datastore::{Entity, ExplainMetrics, IntoValue, Key, Value};
+use crate::error::ConvertError;
+use crate::types;
 
 /// Represents Datastore query result orderings.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -28,13 +36,109 @@ pub enum Filter {
     In(String, Value),
     /// Not-in-list filter (NOT IN).
     NotIn(String, Value),
+    /// Ancestor filter (HAS_ANCESTOR), matching the entities having the given key as an ancestor
+    /// (or as their own key), in the same entity group.
+    ///
+    /// ```
+    /// # use google_cloud::datastore::{Filter, Key, Query};
+    /// let org = Key::new("Org").id("acme");
+    /// let query = Query::new("User").filter(Filter::HasAncestor(org));
+    /// ```
+    HasAncestor(Key),
+}
+
+impl Filter {
+    /// The maximum number of values of `In` and `NotIn` filters.
+    pub(crate) const MAX_IN_VALUES: usize = 10;
+
+    /// In-list filter, matching the entities whose property equals any of the given values (at most 10).
+    ///
+    /// ```
+    /// # use google_cloud::datastore::{Filter, Query};
+    /// let query = Query::new("Order").filter(Filter::in_values("status", vec!["paid", "shipped"]));
+    /// ```
+    pub fn in_values<T, I>(name: impl Into<String>, values: I) -> Filter
+    where
+        I: IntoIterator<Item = T>,
+        T: IntoValue,
+    {
+        Filter::In(name.into(), values.into_iter().collect())
+    }
+
+    /// Not-in-list filter, matching the entities whose property equals none of the given values (at most 10).
+    pub fn not_in_values<T, I>(name: impl Into<String>, values: I) -> Filter
+    where
+        I: IntoIterator<Item = T>,
+        T: IntoValue,
+    {
+        Filter::NotIn(name.into(), values.into_iter().collect())
+    }
+
+    /// Matches the entities whose array property contains the given value.
+    ///
+    /// Datastore matches array properties element by element, so this is an equality filter on the property.
+    ///
+    /// ```
+    /// # use google_cloud::datastore::{Filter, Query};
+    /// let query = Query::new("Post").filter(Filter::array_contains("tags", "rust"));
+    /// ```
+    pub fn array_contains(name: impl Into<String>, value: impl IntoValue) -> Filter {
+        Filter::Equal(name.into(), value.into_value())
+    }
+}
+
+/// Represents the consistency of reads.
+///
+/// ```no_run
+/// # use std::collections::HashMap;
+/// # use google_cloud::datastore::{Client, Error, Key, ReadConsistency, Value};
+/// # async fn run(mut client: Client) -> Result<(), Error> {
+/// let key = Key::new("Config").id("pricing");
+/// let config: Option<HashMap<String, Value>> =
+///     client.get_with(&key, ReadConsistency::Eventual).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ReadConsistency {
+    /// Read the latest committed state (the default).
+    #[default]
+    Strong,
+    /// Read a possibly stale state, which is faster and doesn't contend with ongoing transactions.
+    Eventual,
+    /// Read the state of the database at the given time (in UTC).
+    ///
+    /// The time must be within the retention period of the database (the past hour,
+    /// or the past 7 days at whole minutes with point-in-time recovery enabled).
+    ReadTime(NaiveDateTime),
+}
+
+impl ReadConsistency {
+    pub(crate) fn into_api(self) -> api::ReadOptions {
+        use api::read_options::ConsistencyType;
+
+        let consistency_type = match self {
+            ReadConsistency::Strong => {
+                ConsistencyType::ReadConsistency(api::read_options::ReadConsistency::Strong as i32)
+            }
+            ReadConsistency::Eventual => ConsistencyType::ReadConsistency(
+                api::read_options::ReadConsistency::Eventual as i32,
+            ),
+            ReadConsistency::ReadTime(time) => {
+                ConsistencyType::ReadTime(types::naive_to_timestamp(time))
+            }
+        };
+        api::ReadOptions {
+            consistency_type: Some(consistency_type),
+        }
+    }
 }
 
 /// Represents a Datastore query.
 #[derive(Debug, Clone, PartialEq)]
 pub struct Query {
     pub(crate) kind: String,
-    pub(crate) eventual: bool,
+    pub(crate) consistency: ReadConsistency,
     pub(crate) keys_only: bool,
     pub(crate) offset: i32,
     pub(crate) limit: Option<i32>,
@@ -44,6 +148,7 @@ pub struct Query {
     pub(crate) distinct_on: Vec<String>,
     pub(crate) ordering: Vec<Order>,
     pub(crate) filters: Vec<Filter>,
+    pub(crate) start_cursor: Option<Cursor>,
 }
 
 impl Query {
@@ -56,7 +161,7 @@ impl Query {
     pub fn new(kind: impl Into<String>) -> Query {
         Query {
             kind: kind.into(),
-            eventual: false,
+            consistency: ReadConsistency::Strong,
             keys_only: false,
             offset: 0,
             limit: None,
@@ -66,6 +171,7 @@ impl Query {
             distinct_on: Vec::new(),
             ordering: Vec::new(),
             filters: Vec::new(),
+            start_cursor: None,
         }
     }
 
@@ -78,7 +184,7 @@ impl Query {
     ///     .eventually_consistent();
     /// ```
     pub fn eventually_consistent(mut self) -> Query {
-        self.eventual = true;
+        self.consistency = ReadConsistency::Eventual;
         self
     }
 
@@ -116,7 +222,7 @@ impl Query {
         self
     }
 
-    /// Appends an ancestor filter to the query.
+    /// Appends an ancestor filter to the query (same as `Filter::HasAncestor`).
     ///
     /// ```
     /// # use google_cloud::datastore::Query;
@@ -143,23 +249,23 @@ impl Query {
 
     /// Ask to only yield the given fields.
     ///
+    /// The fields must be indexed, and the results can be decoded into a type of their own using `Client::query_as`.
+    ///
     /// ```
     /// # use google_cloud::datastore::Query;
-    /// let fields: Vec<String> = vec![
-    ///     "firstname".into(),
-    ///     "lastname".into(),
-    ///     "age".into()
-    /// ];
-    /// let query = Query::new("users").project(fields);
+    /// let query = Query::new("users").project(&["firstname", "lastname", "age"]);
     /// ```
     pub fn project<T, I>(mut self, projections: I) -> Query
     where
         I: IntoIterator<Item = T>,
-        T: Into<String>,
+        T: AsRef<str>,
     {
         self.projections.clear();
-        self.projections
-            .extend(projections.into_iter().map(Into::into));
+        self.projections.extend(
+            projections
+                .into_iter()
+                .map(|projection| String::from(projection.as_ref())),
+        );
         self
     }
 
@@ -211,4 +317,327 @@ impl Query {
         self.ordering.push(order);
         self
     }
+
+    /// Resume the query from a cursor, returned along with the results of a previous run of the same query.
+    ///
+    /// Combined with a limit, this allows to page through the results across requests:
+    ///
+    /// ```no_run
+    /// # use google_cloud::datastore::{Client, Cursor, Error, Query};
+    /// # async fn run(mut client: Client, token: Option<String>) -> Result<(), Error> {
+    /// let mut query = Query::new("users").limit(20);
+    /// if let Some(token) = token {
+    ///     query = query.start_cursor(token.parse::<Cursor>()?);
+    /// }
+    /// let results = client.query_with_meta(query).await?;
+    /// let next_token = results.end_cursor().map(|cursor| cursor.to_string());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn start_cursor(mut self, cursor: Cursor) -> Query {
+        self.start_cursor = Some(cursor);
+        self
+    }
+
+    /// Read the results from the snapshot of the database at the given time (in UTC), instead of the latest one.
+    ///
+    /// The time must be within the retention period of the database (the past hour,
+    /// or the past 7 days at whole minutes with point-in-time recovery enabled).
+    /// It has no effects on queries run within transactions, which read from the snapshot of the transaction.
+    ///
+    /// ```
+    /// # use google_cloud::datastore::Query;
+    /// let earlier = chrono::Utc::now().naive_utc() - chrono::Duration::minutes(30);
+    /// let query = Query::new("users").read_time(earlier);
+    /// ```
+    pub fn read_time(self, time: NaiveDateTime) -> Query {
+        self.consistency(ReadConsistency::ReadTime(time))
+    }
+
+    /// Set the consistency of the results (see `ReadConsistency`).
+    ///
+    /// It has no effects on queries run within transactions, which read from the snapshot of the transaction.
+    pub fn consistency(mut self, consistency: ReadConsistency) -> Query {
+        self.consistency = consistency;
+        self
+    }
+
+    /// Ask to count the results, instead of returning them (see `Client::aggregate`).
+    ///
+    /// ```
+    /// # use google_cloud::datastore::Query;
+    /// let query = Query::new("users").count();
+    /// ```
+    pub fn count(self) -> AggregationQuery {
+        AggregationQuery::new(self).count()
+    }
+
+    /// Ask to sum a numeric field over the results, instead of returning them (see `Client::aggregate`).
+    ///
+    /// ```
+    /// # use google_cloud::datastore::Query;
+    /// let query = Query::new("orders").sum("amount");
+    /// ```
+    pub fn sum(self, property: impl Into<String>) -> AggregationQuery {
+        AggregationQuery::new(self).sum(property)
+    }
+
+    /// Ask to average a numeric field over the results, instead of returning them (see `Client::aggregate`).
+    ///
+    /// ```
+    /// # use google_cloud::datastore::Query;
+    /// let query = Query::new("orders").avg("amount");
+    /// ```
+    pub fn avg(self, property: impl Into<String>) -> AggregationQuery {
+        AggregationQuery::new(self).avg(property)
+    }
+}
+
+/// Represents an aggregation computed over the results of a query.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Aggregation {
+    /// The number of results.
+    Count,
+    /// The sum of the numeric values of a field (non-numeric values are skipped).
+    Sum(String),
+    /// The average of the numeric values of a field (non-numeric values are skipped).
+    Avg(String),
+}
+
+/// Represents a Datastore aggregation query, computing aggregations over the results of a query.
+///
+/// Up to five aggregations can be computed at once:
+///
+/// ```
+/// # use google_cloud::datastore::{Aggregation, Query};
+/// let query = Query::new("orders").count().sum("amount").avg("amount");
+/// assert_eq!(query.aggregations(), &[
+///     Aggregation::Count,
+///     Aggregation::Sum("amount".into()),
+///     Aggregation::Avg("amount".into()),
+/// ]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct AggregationQuery {
+    pub(crate) query: Query,
+    pub(crate) aggregations: Vec<Aggregation>,
+}
+
+impl AggregationQuery {
+    /// Construct a new aggregation query over the results of a query, without any aggregation.
+    pub fn new(query: Query) -> AggregationQuery {
+        AggregationQuery {
+            query,
+            aggregations: Vec::new(),
+        }
+    }
+
+    /// Add an aggregation to compute (aggregations already added are ignored).
+    pub fn aggregate(mut self, aggregation: Aggregation) -> AggregationQuery {
+        if !self.aggregations.contains(&aggregation) {
+            self.aggregations.push(aggregation);
+        }
+        self
+    }
+
+    /// Ask to also count the results.
+    pub fn count(self) -> AggregationQuery {
+        self.aggregate(Aggregation::Count)
+    }
+
+    /// Ask to also sum a numeric field over the results.
+    pub fn sum(self, property: impl Into<String>) -> AggregationQuery {
+        self.aggregate(Aggregation::Sum(property.into()))
+    }
+
+    /// Ask to also average a numeric field over the results.
+    pub fn avg(self, property: impl Into<String>) -> AggregationQuery {
+        self.aggregate(Aggregation::Avg(property.into()))
+    }
+
+    /// Get the query to aggregate the results of.
+    pub fn query(&self) -> &Query {
+        &self.query
+    }
+
+    /// Get the aggregations to compute, in the order they were added.
+    pub fn aggregations(&self) -> &[Aggregation] {
+        self.aggregations.as_slice()
+    }
+}
+
+/// Represents the results of an aggregation query.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AggregationResults {
+    pub(crate) values: Vec<(Aggregation, Option<Value>)>,
+}
+
+impl AggregationResults {
+    /// Get the result of an aggregation.
+    ///
+    /// It is `None` if the aggregation was not requested, or if it has no result (such as the average of no values).
+    pub fn get(&self, aggregation: &Aggregation) -> Option<&Value> {
+        self.values
+            .iter()
+            .find(|(requested, _)| requested == aggregation)
+            .and_then(|(_, value)| value.as_ref())
+    }
+
+    /// Get the number of results, if requested.
+    pub fn count(&self) -> Option<i64> {
+        match self.get(&Aggregation::Count) {
+            Some(Value::IntegerValue(count)) => Some(*count),
+            _ => None,
+        }
+    }
+
+    /// Get the sum of a field, if requested.
+    ///
+    /// It is an integer if all the summed values are integers (and the sum does not overflow), or a double otherwise.
+    pub fn sum(&self, property: impl Into<String>) -> Option<&Value> {
+        self.get(&Aggregation::Sum(property.into()))
+    }
+
+    /// Get the average of a field, if requested and if there were values to average.
+    pub fn avg(&self, property: impl Into<String>) -> Option<f64> {
+        match self.get(&Aggregation::Avg(property.into())) {
+            Some(Value::DoubleValue(avg)) => Some(*avg),
+            _ => None,
+        }
+    }
+}
+
+/// Represents a position within the results of a query, from which it can be resumed later.
+///
+/// Cursors are opaque, but can be formatted as (and parsed from) URL-safe strings,
+/// to be handed out to clients:
+///
+/// ```
+/// # use google_cloud::datastore::Cursor;
+/// let cursor = Cursor::from_bytes(vec![0xfb, 0xff]);
+/// assert_eq!(cursor.to_string(), "-_8");
+/// assert_eq!("-_8".parse::<Cursor>().unwrap(), cursor);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Cursor(pub(crate) Vec<u8>);
+
+impl Cursor {
+    /// Create a cursor from its raw bytes.
+    pub fn from_bytes(bytes: impl Into<Vec<u8>>) -> Cursor {
+        Cursor(bytes.into())
+    }
+
+    /// The raw bytes of the cursor.
+    pub fn as_bytes(&self) -> &[u8] {
+        self.0.as_slice()
+    }
+
+    /// Wraps a cursor returned by Datastore, which leaves it empty when unavailable.
+    pub(crate) fn from_api(bytes: Vec<u8>) -> Option<Cursor> {
+        if bytes.is_empty() {
+            None
+        } else {
+            Some(Cursor(bytes))
+        }
+    }
+}
+
+impl fmt::Display for Cursor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let encoded = base64::encode_config(self.0.as_slice(), base64::URL_SAFE_NO_PAD);
+        write!(f, "{}", encoded)
+    }
+}
+
+impl FromStr for Cursor {
+    type Err = ConvertError;
+
+    fn from_str(s: &str) -> Result<Cursor, ConvertError> {
+        let bytes = base64::decode_config(s, base64::URL_SAFE_NO_PAD)
+            .map_err(|_| ConvertError::InvalidCursor(String::from(s)))?;
+        Ok(Cursor(bytes))
+    }
+}
+
+/// Represents whether more results may be available, past the ones returned by a query.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MoreResults {
+    /// The query is finished, but there may be more results after its limit.
+    AfterLimit,
+    /// The query is finished, but there may be more results after its end cursor.
+    AfterCursor,
+    /// The query is finished, and there are no more results.
+    None,
+    /// The query is not finished, and more batches of results follow (see `Client::query_batches`).
+    NotFinished,
+}
+
+impl From<i32> for MoreResults {
+    fn from(more_results: i32) -> MoreResults {
+        use api::query_result_batch::MoreResultsType;
+        match MoreResultsType::from_i32(more_results) {
+            Some(MoreResultsType::MoreResultsAfterLimit) => MoreResults::AfterLimit,
+            Some(MoreResultsType::MoreResultsAfterCursor) => MoreResults::AfterCursor,
+            Some(MoreResultsType::NotFinished) => MoreResults::NotFinished,
+            _ => MoreResults::None,
+        }
+    }
+}
+
+/// Represents the results of a query, along with metadata about them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueryResults {
+    pub(crate) entities: Vec<Entity>,
+    pub(crate) skipped_results: i32,
+    pub(crate) snapshot_version: i64,
+    pub(crate) more_results: MoreResults,
+    pub(crate) end_cursor: Option<Cursor>,
+    pub(crate) skipped_cursor: Option<Cursor>,
+    pub(crate) explain_metrics: Option<ExplainMetrics>,
+}
+
+impl QueryResults {
+    /// Get the entities matched by the query.
+    pub fn entities(&self) -> &[Entity] {
+        self.entities.as_slice()
+    }
+
+    /// Move the matched entities out.
+    pub fn into_entities(self) -> Vec<Entity> {
+        self.entities
+    }
+
+    /// The number of results skipped because of the query's offset.
+    pub fn skipped_results(&self) -> i32 {
+        self.skipped_results
+    }
+
+    /// The version number of the snapshot the results were read from.
+    ///
+    /// Versions increase with every change of the queried entities,
+    /// so it can be compared to detect whether results are stale.
+    /// It is `0` for eventually-consistent queries.
+    pub fn snapshot_version(&self) -> i64 {
+        self.snapshot_version
+    }
+
+    /// Whether more results may be available, past the returned ones.
+    pub fn more_results(&self) -> MoreResults {
+        self.more_results
+    }
+
+    /// The cursor positioned after the last returned result, to resume the query from (see `Query::start_cursor`).
+    pub fn end_cursor(&self) -> Option<&Cursor> {
+        self.end_cursor.as_ref()
+    }
+
+    /// The cursor positioned after the last result skipped because of the query's offset, if any were skipped.
+    pub fn skipped_cursor(&self) -> Option<&Cursor> {
+        self.skipped_cursor.as_ref()
+    }
+
+    /// The planning and execution metrics of the query, if it was explained (see `Client::explain_query`).
+    pub fn explain_metrics(&self) -> Option<&ExplainMetrics> {
+        self.explain_metrics.as_ref()
+    }
 }
@@ -0,0 +1,227 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::{Arc, Mutex};
+
+use crate::datastore::{Client, Error, FromValue, IntoEntity, Key, KeyID, Query};
+use crate::error::ConvertError;
+
+/// Trait for the types stored as the entities of a single kind, to be managed by a `Repository`.
+///
+/// With the `datastore-derive` feature, it is derived along with `IntoEntity` and `FromValue`
+/// by `#[derive(DatastoreEntity)]`, which restores the key field from the key of the entities read back.
+pub trait EntityKind: IntoEntity + FromValue {
+    /// The kind of the entities.
+    const KIND: &'static str;
+
+    /// Restore the parts of a value stored in the key of its entity, rather than in its properties.
+    ///
+    /// The default implementation leaves the value untouched.
+    fn with_key(self, key: &Key) -> Result<Self, ConvertError> {
+        let _ = key;
+        Ok(self)
+    }
+}
+
+/// Trait for the caches of a `Repository`, which it reads through.
+pub trait RepositoryCache<T>: Send + Sync {
+    /// Get the cached value of an entity, if any.
+    fn get(&self, key: &Key) -> Option<T>;
+
+    /// Cache the value of an entity, just read from Datastore.
+    fn insert(&self, key: &Key, value: &T);
+
+    /// Forget the cached value of an entity, which just got written or deleted.
+    fn invalidate(&self, key: &Key);
+}
+
+/// A cache of the entities read through a repository, kept in memory without any eviction.
+pub struct MemoryCache<T> {
+    entries: Mutex<HashMap<Key, T>>,
+}
+
+impl<T> MemoryCache<T> {
+    /// Create a new, empty cache.
+    pub fn new() -> MemoryCache<T> {
+        MemoryCache {
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// The number of cached entities.
+    pub fn len(&self) -> usize {
+        self.entries
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .len()
+    }
+
+    /// Is the cache empty ?
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<T> Default for MemoryCache<T> {
+    fn default() -> MemoryCache<T> {
+        MemoryCache::new()
+    }
+}
+
+impl<T> fmt::Debug for MemoryCache<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MemoryCache")
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+impl<T> RepositoryCache<T> for MemoryCache<T>
+where
+    T: Clone + Send,
+{
+    fn get(&self, key: &Key) -> Option<T> {
+        let entries = self.entries.lock().unwrap_or_else(|err| err.into_inner());
+        entries.get(key).cloned()
+    }
+
+    fn insert(&self, key: &Key, value: &T) {
+        let mut entries = self.entries.lock().unwrap_or_else(|err| err.into_inner());
+        entries.insert(key.clone(), value.clone());
+    }
+
+    fn invalidate(&self, key: &Key) {
+        let mut entries = self.entries.lock().unwrap_or_else(|err| err.into_inner());
+        entries.remove(key);
+    }
+}
+
+/// A typed access to the entities of a kind, identified by the IDs of their keys.
+///
+/// ```ignore
+/// use google_cloud::datastore::{Client, DatastoreEntity, Error, MemoryCache};
+///
+/// #[derive(Clone, DatastoreEntity)]
+/// #[datastore(kind = "User")]
+/// pub struct User {
+///     #[datastore(key)]
+///     id: Option<i64>,
+///     name: String,
+/// }
+///
+/// async fn run(client: Client) -> Result<(), Error> {
+///     let mut users = client.repository::<User>().cache(MemoryCache::new());
+///     let key = users.save(User { id: None, name: String::from("Jane") }).await?;
+///     let jane = users.get(key.get_id().clone()).await?;
+///     let everyone = users.list().await?;
+///     Ok(())
+/// }
+/// ```
+pub struct Repository<T> {
+    pub(crate) client: Client,
+    pub(crate) cache: Option<Arc<dyn RepositoryCache<T>>>,
+    pub(crate) marker: PhantomData<fn() -> T>,
+}
+
+impl<T> Clone for Repository<T> {
+    fn clone(&self) -> Repository<T> {
+        Repository {
+            client: self.client.clone(),
+            cache: self.cache.clone(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Repository<T>
+where
+    T: EntityKind,
+{
+    pub(crate) fn new(client: Client) -> Repository<T> {
+        Repository {
+            client,
+            cache: None,
+            marker: PhantomData,
+        }
+    }
+
+    /// Read the entities through the given cache.
+    ///
+    /// Only lookups by key are served from the cache, queries always hit Datastore.
+    /// The cached entities are invalidated when written or deleted through this repository (or its clones).
+    pub fn cache(mut self, cache: impl RepositoryCache<T> + 'static) -> Repository<T> {
+        self.cache = Some(Arc::new(cache));
+        self
+    }
+
+    /// The key of the entity with the given ID.
+    pub fn key(&self, id: impl Into<KeyID>) -> Key {
+        Key::new(T::KIND).id(id)
+    }
+
+    /// Get the entity with the given ID.
+    pub async fn get(&mut self, id: impl Into<KeyID>) -> Result<Option<T>, Error> {
+        let key = self.key(id);
+        if let Some(value) = self.cache.as_ref().and_then(|cache| cache.get(&key)) {
+            return Ok(Some(value));
+        }
+
+        let value: Option<T> = self.client.get(&key).await?;
+        let value = value.map(|value| value.with_key(&key)).transpose()?;
+        if let (Some(cache), Some(value)) = (self.cache.as_ref(), value.as_ref()) {
+            cache.insert(&key, value);
+        }
+
+        Ok(value)
+    }
+
+    /// List all the entities of the kind.
+    pub async fn list(&mut self) -> Result<Vec<T>, Error> {
+        self.query(Query::new(T::KIND)).await
+    }
+
+    /// Get the entities matched by a query (for the entities of the kind, as created with `Query::new(T::KIND)`).
+    pub async fn query(&mut self, query: Query) -> Result<Vec<T>, Error> {
+        let entities = self.client.query(query).await?;
+        let values = entities
+            .into_iter()
+            .map(|entity| {
+                let value = T::from_value_with(entity.properties, &self.client.decode_options)?;
+                value.with_key(&entity.key)
+            })
+            .collect::<Result<Vec<_>, ConvertError>>()?;
+
+        Ok(values)
+    }
+
+    /// Save an entity, and return its key (generated by the store if the value's key was incomplete).
+    pub async fn save(&mut self, value: T) -> Result<Key, Error> {
+        let entity = value.into_entity()?;
+        let key = entity.key.clone();
+        let generated = self.client.put(entity).await?;
+        let key = generated.unwrap_or(key);
+        if let Some(cache) = self.cache.as_ref() {
+            cache.invalidate(&key);
+        }
+
+        Ok(key)
+    }
+
+    /// Delete the entity with the given ID.
+    pub async fn delete(&mut self, id: impl Into<KeyID>) -> Result<(), Error> {
+        let key = self.key(id);
+        self.client.delete(&key).await?;
+        if let Some(cache) = self.cache.as_ref() {
+            cache.invalidate(&key);
+        }
+
+        Ok(())
+    }
+}
+
+impl Client {
+    /// Create a repository of the entities of the given type (see `Repository`).
+    pub fn repository<T: EntityKind>(&self) -> Repository<T> {
+        Repository::new(self.clone())
+    }
+}
@@ -0,0 +1,116 @@
+use std::collections::hash_map::RandomState;
+use std::future::Future;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+use tonic::Code;
+
+use crate::datastore::Error;
+
+/// Represents how failed requests get retried, with exponential backoff and jitter.
+///
+/// Requests failing with `ABORTED` (contention) or `UNAVAILABLE` are retried, including whole transactions
+/// in `Client::run_in_transaction`, as long as they can safely be applied again (see `Client::retry`).
+///
+/// ```
+/// # use std::time::Duration;
+/// # use google_cloud::datastore::RetryConfig;
+/// let config = RetryConfig::default()
+///     .max_attempts(8)
+///     .initial_backoff(Duration::from_millis(50))
+///     .max_backoff(Duration::from_secs(2));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryConfig {
+    pub(crate) max_attempts: usize,
+    pub(crate) initial_backoff: Duration,
+    pub(crate) max_backoff: Duration,
+    pub(crate) multiplier: f64,
+}
+
+impl Default for RetryConfig {
+    fn default() -> RetryConfig {
+        RetryConfig {
+            max_attempts: 5,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(5),
+            multiplier: 2.0,
+        }
+    }
+}
+
+impl RetryConfig {
+    /// Never retry requests (nor transactions).
+    pub fn disabled() -> RetryConfig {
+        RetryConfig::default().max_attempts(1)
+    }
+
+    /// Set the maximum number of attempts, including the first one.
+    pub fn max_attempts(mut self, attempts: usize) -> RetryConfig {
+        self.max_attempts = attempts.max(1);
+        self
+    }
+
+    /// Set the delay before the first retry.
+    pub fn initial_backoff(mut self, backoff: Duration) -> RetryConfig {
+        self.initial_backoff = backoff;
+        self
+    }
+
+    /// Set the maximum delay between two attempts.
+    pub fn max_backoff(mut self, backoff: Duration) -> RetryConfig {
+        self.max_backoff = backoff;
+        self
+    }
+
+    /// Set the factor by which the delay grows after every retry (at least 1).
+    pub fn multiplier(mut self, multiplier: f64) -> RetryConfig {
+        self.multiplier = multiplier.max(1.0);
+        self
+    }
+
+    /// The delay to wait for after the given (failed) attempt, the first one being `1`.
+    ///
+    /// It is picked at random in the upper half of the exponential backoff,
+    /// so that concurrent clients failing together don't retry in lockstep.
+    pub(crate) fn backoff(&self, attempt: usize) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(i32::MAX as usize) as i32;
+        let backoff = self.initial_backoff.as_secs_f64() * self.multiplier.powi(exponent);
+        let backoff = backoff.min(self.max_backoff.as_secs_f64());
+
+        //? `RandomState` is randomly seeded, which is enough for jitter without depending on `rand`.
+        let mut hasher = RandomState::new().build_hasher();
+        hasher.write_usize(attempt);
+        let jitter = (hasher.finish() as f64) / (u64::MAX as f64);
+        Duration::from_secs_f64(backoff * (0.5 + jitter / 2.0))
+    }
+
+    /// Can the given (failed) attempt be retried ?
+    pub(crate) fn should_retry(&self, attempt: usize, err: &Error) -> bool {
+        attempt < self.max_attempts && is_retryable(err)
+    }
+
+    /// Runs an operation, retrying it as long as it fails with a retryable error.
+    pub(crate) async fn run<T, F, Fut>(&self, mut operation: F) -> Result<T, Error>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, Error>>,
+    {
+        let mut attempt = 1;
+        loop {
+            match operation().await {
+                Err(err) if self.should_retry(attempt, &err) => {
+                    tokio::time::sleep(self.backoff(attempt)).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+/// Is the error transient, so that retrying the request may succeed ?
+pub(crate) fn is_retryable(err: &Error) -> bool {
+    let code = err.status().map(tonic::Status::code);
+    matches!(code, Some(Code::Aborted) | Some(Code::Unavailable))
+}
@@ -0,0 +1,194 @@
+use futures::stream::{self, Stream};
+
+use crate::datastore::{Client, Entity, Error, Filter, Key, Order, Query, Value};
+
+/// Represents a range of keys, from its start (inclusive) to its end (exclusive).
+///
+/// A missing bound leaves the range open on that side.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct KeyRange {
+    pub(crate) start: Option<Key>,
+    pub(crate) end: Option<Key>,
+}
+
+impl KeyRange {
+    /// The first key of the range, if bounded.
+    pub fn start(&self) -> Option<&Key> {
+        self.start.as_ref()
+    }
+
+    /// The key right after the range, if bounded.
+    pub fn end(&self) -> Option<&Key> {
+        self.end.as_ref()
+    }
+
+    /// Does the range contain the given key ?
+    pub fn contains(&self, key: &Key) -> bool {
+        self.start.as_ref().is_none_or(|start| key >= start)
+            && self.end.as_ref().is_none_or(|end| key < end)
+    }
+
+    /// Restrict a query to the entities whose keys are within the range.
+    pub fn restrict(&self, mut query: Query) -> Query {
+        if let Some(start) = self.start.clone() {
+            query = query.filter(Filter::GreaterThanOrEqual(
+                String::from(Scan::KEY_PROPERTY),
+                Value::KeyValue(start),
+            ));
+        }
+        if let Some(end) = self.end.clone() {
+            query = query.filter(Filter::LesserThan(
+                String::from(Scan::KEY_PROPERTY),
+                Value::KeyValue(end),
+            ));
+        }
+        query
+    }
+}
+
+/// Scans all the entities of a kind in parallel, splitting its key space into ranges (or shards).
+///
+/// The split points are picked among a sample of keys, obtained by ordering on the `__scatter__` property
+/// that Datastore sets on a random subset of the entities, so that the ranges hold about as many entities.
+///
+/// ```no_run
+/// # use futures::StreamExt;
+/// # use google_cloud::datastore::{Client, Error};
+/// # async fn run(client: Client) -> Result<(), Error> {
+/// let mut entities = client.scan("Event").shards(16).keys_only().stream().await?;
+/// let mut count = 0;
+/// while let Some(entity) = entities.next().await {
+///     entity?;
+///     count += 1;
+/// }
+/// println!("{} events", count);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct Scan {
+    pub(crate) client: Client,
+    pub(crate) kind: String,
+    pub(crate) namespace: Option<String>,
+    pub(crate) shards: usize,
+    pub(crate) oversampling: usize,
+    pub(crate) keys_only: bool,
+}
+
+impl Scan {
+    /// The name of the property ordering entities by key.
+    pub const KEY_PROPERTY: &'static str = "__key__";
+
+    /// The name of the property Datastore sets on a random subset of the entities, to sample their keys.
+    pub const SCATTER_PROPERTY: &'static str = "__scatter__";
+
+    pub(crate) fn new(client: Client, kind: impl Into<String>) -> Scan {
+        Scan {
+            client,
+            kind: kind.into(),
+            namespace: None,
+            shards: 8,
+            oversampling: 32,
+            keys_only: false,
+        }
+    }
+
+    /// Scan the entities of the given namespace.
+    pub fn namespace(mut self, namespace: impl Into<String>) -> Scan {
+        self.namespace = Some(namespace.into());
+        self
+    }
+
+    /// Set the number of ranges to split the key space into, scanned concurrently.
+    pub fn shards(mut self, shards: usize) -> Scan {
+        self.shards = shards.max(1);
+        self
+    }
+
+    /// Set the number of keys sampled per split point, a higher one giving more evenly sized ranges.
+    pub fn oversampling(mut self, oversampling: usize) -> Scan {
+        self.oversampling = oversampling.max(1);
+        self
+    }
+
+    /// Only fetch the keys of the entities, without their properties.
+    pub fn keys_only(mut self) -> Scan {
+        self.keys_only = true;
+        self
+    }
+
+    fn query(&self) -> Query {
+        let mut query = Query::new(self.kind.as_str());
+        if let Some(namespace) = self.namespace.as_ref() {
+            query = query.namespace(namespace.as_str());
+        }
+        query
+    }
+
+    /// Split the key space of the kind into ranges, covering it entirely.
+    ///
+    /// There may be fewer ranges than requested, for kinds with few entities.
+    pub async fn ranges(&mut self) -> Result<Vec<KeyRange>, Error> {
+        if self.shards == 1 {
+            return Ok(split_ranges(Vec::new(), 1));
+        }
+
+        let limit = (self.shards - 1) * self.oversampling;
+        let query = self
+            .query()
+            .keys_only()
+            .order(Order::Asc(String::from(Scan::SCATTER_PROPERTY)))
+            .limit(limit.min(i32::MAX as usize) as i32);
+        let sample = self.client.query(query).await?;
+        let sample = sample.into_iter().map(Entity::into_key).collect();
+
+        Ok(split_ranges(sample, self.shards))
+    }
+
+    /// Scan the entities, as a single stream merging the ones of all the ranges as they arrive.
+    ///
+    /// The entities are not ordered, and the stream ends after all the ranges have been scanned.
+    /// An error ends the scan of its range only.
+    pub async fn stream(mut self) -> Result<impl Stream<Item = Result<Entity, Error>>, Error> {
+        let ranges = self.ranges().await?;
+        let streams = ranges.into_iter().map(|range| {
+            let mut query = range.restrict(self.query());
+            if self.keys_only {
+                query = query.keys_only();
+            }
+            Box::pin(self.client.query_stream(query))
+        });
+
+        Ok(stream::select_all(streams))
+    }
+}
+
+/// Splits the key space into ranges, using evenly spaced keys of the sample as split points.
+pub(crate) fn split_ranges(mut sample: Vec<Key>, shards: usize) -> Vec<KeyRange> {
+    sample.sort();
+    sample.dedup();
+
+    let mut points: Vec<Key> = (1..shards)
+        .filter_map(|idx| sample.get(idx * sample.len() / shards).cloned())
+        .collect();
+    points.dedup();
+
+    let mut ranges = Vec::with_capacity(points.len() + 1);
+    let mut start = None;
+    for point in points {
+        ranges.push(KeyRange {
+            start: start.take(),
+            end: Some(point.clone()),
+        });
+        start = Some(point);
+    }
+    ranges.push(KeyRange { start, end: None });
+    ranges
+}
+
+impl Client {
+    /// Prepare a parallel scan of the entities of the given kind (see `Scan`).
+    pub fn scan(&self, kind: impl Into<String>) -> Scan {
+        Scan::new(self.clone(), kind)
+    }
+}
@@ -0,0 +1,127 @@
+use std::io::{self, Write};
+
+use chrono::{NaiveDateTime, Timelike, Utc};
+use futures::stream::StreamExt;
+
+use crate::datastore::{Client, Error, Query};
+
+/// Exports the entities of several kinds as they were at a single point in time,
+/// to newline-delimited JSON (one entity per line, see `Entity::to_json`).
+///
+/// All the kinds are read from the same snapshot of the database (with queries at the same read time),
+/// so that the exports are consistent with each other, as long as they complete within the retention period
+/// of the database (see `ReadConsistency::ReadTime`).
+/// Unlike the exports of the admin API, this doesn't require a Cloud Storage bucket:
+/// the entities are streamed to the writers, one per kind, without buffering the whole result sets.
+///
+/// ```no_run
+/// # use std::fs::File;
+/// # use std::io::BufWriter;
+/// # use google_cloud::datastore::{Client, Error};
+/// # async fn run(client: Client) -> Result<(), Error> {
+/// let export = client.snapshot_export(&["User", "Order"]);
+/// let results = export
+///     .write_to(|kind| Ok(BufWriter::new(File::create(format!("{}.ndjson", kind))?)))
+///     .await?;
+/// for (kind, count) in results.counts() {
+///     println!("{}: {} entities as of {}", kind, count, results.read_time());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct SnapshotExport {
+    pub(crate) client: Client,
+    pub(crate) kinds: Vec<String>,
+    pub(crate) namespace: Option<String>,
+    pub(crate) read_time: Option<NaiveDateTime>,
+}
+
+impl SnapshotExport {
+    pub(crate) fn new(client: Client, kinds: Vec<String>) -> SnapshotExport {
+        SnapshotExport {
+            client,
+            kinds,
+            namespace: None,
+            read_time: None,
+        }
+    }
+
+    /// Export the entities of the given namespace.
+    pub fn namespace(mut self, namespace: impl Into<String>) -> SnapshotExport {
+        self.namespace = Some(namespace.into());
+        self
+    }
+
+    /// Export the entities as they were at the given time (in UTC), instead of when the export starts.
+    pub fn read_time(mut self, time: NaiveDateTime) -> SnapshotExport {
+        self.read_time = Some(time);
+        self
+    }
+
+    /// Export the entities, writing those of every kind to the writer returned for it, in order.
+    ///
+    /// The writers are flushed once their kind is exported. The export stops at the first error.
+    pub async fn write_to<W, F>(self, mut writer: F) -> Result<SnapshotResults, Error>
+    where
+        W: Write,
+        F: FnMut(&str) -> io::Result<W>,
+    {
+        //? The snapshot starts a second back (at a whole second), to stay clear of clock skew with the service.
+        let read_time = self.read_time.unwrap_or_else(|| {
+            let now = Utc::now().naive_utc() - chrono::Duration::seconds(1);
+            now.with_nanosecond(0).unwrap_or(now)
+        });
+
+        let mut counts = Vec::with_capacity(self.kinds.len());
+        for kind in self.kinds.iter() {
+            let mut query = Query::new(kind.as_str()).read_time(read_time);
+            if let Some(namespace) = self.namespace.as_ref() {
+                query = query.namespace(namespace.as_str());
+            }
+            let mut output = writer(kind)?;
+            let mut entities = Box::pin(self.client.query_stream(query));
+            let mut count = 0;
+            while let Some(entity) = entities.next().await {
+                json::to_writer(&mut output, &entity?.to_json())?;
+                output.write_all(b"\n")?;
+                count += 1;
+            }
+            output.flush()?;
+            counts.push((kind.clone(), count));
+        }
+
+        Ok(SnapshotResults { read_time, counts })
+    }
+}
+
+/// Represents the outcome of a snapshot export (see `SnapshotExport`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SnapshotResults {
+    pub(crate) read_time: NaiveDateTime,
+    pub(crate) counts: Vec<(String, u64)>,
+}
+
+impl SnapshotResults {
+    /// The time (in UTC) of the snapshot the entities were read from.
+    pub fn read_time(&self) -> NaiveDateTime {
+        self.read_time
+    }
+
+    /// The number of entities exported for every kind, in order.
+    pub fn counts(&self) -> &[(String, u64)] {
+        self.counts.as_slice()
+    }
+}
+
+impl Client {
+    /// Prepare a consistent export of the entities of the given kinds (see `SnapshotExport`).
+    pub fn snapshot_export<I, K>(&self, kinds: I) -> SnapshotExport
+    where
+        I: IntoIterator<Item = K>,
+        K: AsRef<str>,
+    {
+        let kinds = kinds.into_iter().map(|kind| String::from(kind.as_ref()));
+        SnapshotExport::new(self.clone(), kinds.collect())
+    }
+}
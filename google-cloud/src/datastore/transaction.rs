@@ -0,0 +1,376 @@
+use std::borrow::Borrow;
+use std::collections::HashMap;
+
+use futures::stream::{self, Stream, StreamExt};
+
+use crate::datastore::api;
+use crate::datastore::client::{
+    delete_mutations, transaction_read_options, write_mutations, WriteMode,
+};
+use crate::datastore::{
+    AggregationQuery, AggregationResults, Client, Entity, EntityMeta, Error, FromValue, IntoEntity,
+    Key, Query, QueryResults, Value,
+};
+use crate::error::ConvertError;
+
+/// Represents a read-write Datastore transaction.
+///
+/// Reads observe a consistent snapshot of the store, while writes are buffered
+/// and only applied (atomically) when the transaction gets committed.
+/// Lookups by key observe the buffered writes (read-your-writes), but queries and aggregations don't.
+/// A transaction that is dropped without being committed is eventually rolled back by Datastore.
+pub struct Transaction {
+    pub(crate) client: Client,
+    pub(crate) id: Vec<u8>,
+    pub(crate) mutations: Vec<api::Mutation>,
+    /// The last buffered write of every (complete) key, `None` for deletions.
+    pub(crate) pending: HashMap<Key, Option<Value>>,
+}
+
+impl Transaction {
+    pub(crate) fn new(client: Client, id: Vec<u8>) -> Transaction {
+        Transaction {
+            client,
+            id,
+            mutations: Vec::new(),
+            pending: HashMap::new(),
+        }
+    }
+
+    /// Gets an entity from a key, within the transaction.
+    pub async fn get<T, K>(&mut self, key: K) -> Result<Option<T>, Error>
+    where
+        K: Borrow<Key>,
+        T: FromValue,
+    {
+        let results = self.get_all(Some(key.borrow())).await?;
+        Ok(results.into_iter().next())
+    }
+
+    /// Gets multiple entities from multiple keys, within the transaction.
+    ///
+    /// The entities written (or deleted) earlier in the transaction are read back as buffered.
+    pub async fn get_all<T, K, I>(&mut self, keys: I) -> Result<Vec<T>, Error>
+    where
+        I: IntoIterator<Item = K>,
+        K: Borrow<Key>,
+        T: FromValue,
+    {
+        let results = self.get_all_with_meta(keys).await?;
+        Ok(results.into_iter().map(|(value, _)| value).collect())
+    }
+
+    /// Gets an entity from a key within the transaction, along with its metadata (version and update time).
+    pub async fn get_with_meta<T, K>(&mut self, key: K) -> Result<Option<(T, EntityMeta)>, Error>
+    where
+        K: Borrow<Key>,
+        T: FromValue,
+    {
+        let results = self.get_all_with_meta(Some(key.borrow())).await?;
+        Ok(results.into_iter().next())
+    }
+
+    /// Gets multiple entities from multiple keys within the transaction, along with their metadata.
+    ///
+    /// The entities written earlier in the transaction are not stored yet,
+    /// so their metadata has a version of `0` and no update time.
+    pub async fn get_all_with_meta<T, K, I>(
+        &mut self,
+        keys: I,
+    ) -> Result<Vec<(T, EntityMeta)>, Error>
+    where
+        I: IntoIterator<Item = K>,
+        K: Borrow<Key>,
+        T: FromValue,
+    {
+        let partition = self.client.partition();
+        let keys: Vec<Key> = keys
+            .into_iter()
+            .map(|key| partition.scope(key.borrow()))
+            .collect();
+
+        //? Only the keys without buffered writes are looked up, the others reading back their last write.
+        let remote: Vec<Key> = keys
+            .iter()
+            .filter(|key| !self.pending.contains_key(*key))
+            .cloned()
+            .collect();
+        let mut found = if remote.is_empty() {
+            HashMap::new()
+        } else {
+            let read_options = transaction_read_options(&self.id);
+            self.client
+                .lookup_found(&remote, Some(read_options))
+                .await?
+        };
+
+        let unwritten = EntityMeta {
+            version: 0,
+            update_time: None,
+        };
+        let options = &self.client.decode_options;
+        let values = keys
+            .iter()
+            .flat_map(|key| match self.pending.get(key) {
+                Some(value) => value.clone().map(|value| (value, unwritten)),
+                None => found.remove(key),
+            })
+            .map(|(value, meta)| Ok((T::from_value_with(value, options)?, meta)))
+            .collect::<Result<Vec<_>, ConvertError>>()?;
+
+        Ok(values)
+    }
+
+    /// Runs a query within the transaction.
+    ///
+    /// The results reflect the snapshot of the transaction (regardless of `Query::eventually_consistent`),
+    /// which makes ancestor queries suitable for consistent read-modify-write operations.
+    pub async fn query(&mut self, query: Query) -> Result<Vec<Entity>, Error> {
+        let results = self.query_with_meta(query).await?;
+        Ok(results.into_entities())
+    }
+
+    /// Runs a query within the transaction, and returns the results decoded into a user type
+    /// (see `Client::query_as`).
+    pub async fn query_as<T: FromValue>(&mut self, query: Query) -> Result<Vec<T>, Error> {
+        let entities = self.query(query).await?;
+        let options = &self.client.decode_options;
+        let values = entities
+            .into_iter()
+            .map(|entity| T::from_entity_with(&entity.key, entity.properties, options))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(values)
+    }
+
+    /// Runs a query within the transaction, and returns the results along with metadata about them.
+    pub async fn query_with_meta(&mut self, query: Query) -> Result<QueryResults, Error> {
+        self.client
+            .run_query(query, Some(self.id.as_slice()), None)
+            .await
+    }
+
+    /// Runs a query within the transaction, as a stream of its results (see `Client::query_stream`).
+    ///
+    /// Every batch is read from the snapshot of the transaction, so the results stay consistent
+    /// with each other and with the other reads of the transaction, however many batches they span.
+    pub fn query_stream(&self, query: Query) -> impl Stream<Item = Result<Entity, Error>> {
+        let batches = self.client.run_query_batches(query, Some(self.id.clone()));
+        batches.flat_map(|batch| {
+            let entities = match batch {
+                Ok(batch) => batch.entities.into_iter().map(Ok).collect(),
+                Err(err) => vec![Err(err)],
+            };
+            stream::iter(entities)
+        })
+    }
+
+    /// Runs an aggregation query within the transaction.
+    pub async fn aggregate(
+        &mut self,
+        query: AggregationQuery,
+    ) -> Result<AggregationResults, Error> {
+        let transaction = Some(self.id.as_slice());
+        self.client.run_aggregation_query(query, transaction).await
+    }
+
+    /// Inserts a new entity when the transaction gets committed.
+    ///
+    /// The writes are visible to the lookups made afterwards within the transaction (but not to its queries).
+    pub fn put(&mut self, entity: impl IntoEntity) -> Result<(), ConvertError> {
+        self.put_all(Some(entity))
+    }
+
+    /// Inserts new entities when the transaction gets committed.
+    pub fn put_all<T, I>(&mut self, entities: I) -> Result<(), ConvertError>
+    where
+        I: IntoIterator<Item = T>,
+        T: IntoEntity,
+    {
+        self.write_all(entities, WriteMode::Put)
+    }
+
+    /// Inserts a new entity when the transaction gets committed,
+    /// making the commit fail with an `ALREADY_EXISTS` status if an entity already exists under its key.
+    pub fn insert(&mut self, entity: impl IntoEntity) -> Result<(), ConvertError> {
+        self.insert_all(Some(entity))
+    }
+
+    /// Inserts new entities when the transaction gets committed,
+    /// making the commit fail with an `ALREADY_EXISTS` status if an entity already exists under any of their keys.
+    pub fn insert_all<T, I>(&mut self, entities: I) -> Result<(), ConvertError>
+    where
+        I: IntoIterator<Item = T>,
+        T: IntoEntity,
+    {
+        self.write_all(entities, WriteMode::Insert)
+    }
+
+    /// Updates an existing entity when the transaction gets committed,
+    /// making the commit fail with a `NOT_FOUND` status if there is no entity under its key.
+    pub fn update(&mut self, entity: impl IntoEntity) -> Result<(), ConvertError> {
+        self.update_all(Some(entity))
+    }
+
+    /// Updates existing entities when the transaction gets committed,
+    /// making the commit fail with a `NOT_FOUND` status if there is no entity under any of their keys.
+    pub fn update_all<T, I>(&mut self, entities: I) -> Result<(), ConvertError>
+    where
+        I: IntoIterator<Item = T>,
+        T: IntoEntity,
+    {
+        self.write_all(entities, WriteMode::Update)
+    }
+
+    /// Stores an entity when the transaction gets committed, whether an entity already exists under its key or not.
+    pub fn upsert(&mut self, entity: impl IntoEntity) -> Result<(), ConvertError> {
+        self.upsert_all(Some(entity))
+    }
+
+    /// Stores entities when the transaction gets committed, whether entities already exist under their keys or not.
+    pub fn upsert_all<T, I>(&mut self, entities: I) -> Result<(), ConvertError>
+    where
+        I: IntoIterator<Item = T>,
+        T: IntoEntity,
+    {
+        self.write_all(entities, WriteMode::Upsert)
+    }
+
+    fn write_all<T, I>(&mut self, entities: I, mode: WriteMode) -> Result<(), ConvertError>
+    where
+        I: IntoIterator<Item = T>,
+        T: IntoEntity,
+    {
+        let entities = entities
+            .into_iter()
+            .map(IntoEntity::into_entity)
+            .collect::<Result<Vec<Entity>, ConvertError>>()?;
+        let partition = self.client.partition();
+        for entity in entities.iter().filter(|entity| !entity.key.is_incomplete()) {
+            let key = partition.scope(&entity.key);
+            self.pending.insert(key, Some(entity.properties.clone()));
+        }
+
+        let mutations = write_mutations(partition, entities, mode)?;
+        self.mutations.extend(mutations);
+        Ok(())
+    }
+
+    /// Deletes an entity when the transaction gets committed.
+    pub fn delete(&mut self, key: impl Borrow<Key>) {
+        self.delete_all(Some(key.borrow()))
+    }
+
+    /// Deletes multiple entities when the transaction gets committed.
+    pub fn delete_all<T, I>(&mut self, keys: I)
+    where
+        I: IntoIterator<Item = T>,
+        T: Borrow<Key>,
+    {
+        let partition = self.client.partition();
+        let keys: Vec<Key> = keys.into_iter().map(|key| key.borrow().clone()).collect();
+        for key in keys.iter() {
+            self.pending.insert(partition.scope(key), None);
+        }
+
+        let mutations = delete_mutations(partition, keys);
+        self.mutations.extend(mutations);
+    }
+
+    /// Atomically applies the writes of the transaction.
+    ///
+    /// Returns the keys of the written entities (in the order of the writes, `None` for deletions),
+    /// which includes the keys generated by the store for the entities whose key was incomplete.
+    /// Fails with an `ABORTED` status if the transaction conflicted with another one, in which case it can be retried.
+    pub async fn commit(mut self) -> Result<Vec<Option<Key>>, Error> {
+        let mutations = std::mem::take(&mut self.mutations);
+        self.client.commit(mutations, Some(self.id)).await
+    }
+
+    /// Abandons the transaction, discarding all of its writes.
+    pub async fn rollback(self) -> Result<(), Error> {
+        let request = api::RollbackRequest {
+            project_id: self.client.project_name.clone(),
+            database_id: self.client.database_id.clone(),
+            transaction: self.id,
+        };
+        //? Rolling back is idempotent, so it is retried like lookups.
+        self.client
+            .send(request, true, |mut service, request| async move {
+                service.rollback(request).await
+            })
+            .await?;
+
+        Ok(())
+    }
+}
+
+/// Represents a read-only Datastore transaction.
+///
+/// All of its reads observe the same consistent snapshot of the store, taken when it began,
+/// and no writes can be made within it.
+pub struct ReadOnlyTransaction {
+    pub(crate) inner: Transaction,
+}
+
+impl ReadOnlyTransaction {
+    pub(crate) fn new(client: Client, id: Vec<u8>) -> ReadOnlyTransaction {
+        ReadOnlyTransaction {
+            inner: Transaction::new(client, id),
+        }
+    }
+
+    /// Gets an entity from a key, within the transaction.
+    pub async fn get<T, K>(&mut self, key: K) -> Result<Option<T>, Error>
+    where
+        K: Borrow<Key>,
+        T: FromValue,
+    {
+        self.inner.get(key).await
+    }
+
+    /// Gets multiple entities from multiple keys, within the transaction.
+    pub async fn get_all<T, K, I>(&mut self, keys: I) -> Result<Vec<T>, Error>
+    where
+        I: IntoIterator<Item = K>,
+        K: Borrow<Key>,
+        T: FromValue,
+    {
+        self.inner.get_all(keys).await
+    }
+
+    /// Runs a query within the transaction.
+    pub async fn query(&mut self, query: Query) -> Result<Vec<Entity>, Error> {
+        self.inner.query(query).await
+    }
+
+    /// Runs a query within the transaction, and returns the results decoded into a user type.
+    pub async fn query_as<T: FromValue>(&mut self, query: Query) -> Result<Vec<T>, Error> {
+        self.inner.query_as(query).await
+    }
+
+    /// Runs a query within the transaction, and returns the results along with metadata about them.
+    pub async fn query_with_meta(&mut self, query: Query) -> Result<QueryResults, Error> {
+        self.inner.query_with_meta(query).await
+    }
+
+    /// Runs a query within the transaction, as a stream of its results.
+    pub fn query_stream(&self, query: Query) -> impl Stream<Item = Result<Entity, Error>> {
+        self.inner.query_stream(query)
+    }
+
+    /// Runs an aggregation query within the transaction.
+    pub async fn aggregate(
+        &mut self,
+        query: AggregationQuery,
+    ) -> Result<AggregationResults, Error> {
+        self.inner.aggregate(query).await
+    }
+
+    /// Ends the transaction, releasing its snapshot.
+    ///
+    /// A transaction that is dropped without being ended is eventually released by Datastore.
+    pub async fn end(self) -> Result<(), Error> {
+        self.inner.rollback().await
+    }
+}
@@ -1,21 +1,33 @@
 use std::collections::HashMap;
+use std::convert::TryFrom;
 use std::iter::FromIterator;
 
-use chrono::NaiveDateTime;
+use chrono::{DateTime, NaiveDateTime};
 
 #[cfg(feature = "bytes")]
 use bytes::Bytes;
-
+#[cfg(feature = "rust_decimal")]
+use rust_decimal::Decimal;
+#[cfg(feature = "time")]
+use time::OffsetDateTime;
+#[cfg(feature = "uuid")]
+use uuid::Uuid;
+
+use crate::datastore::api;
 use crate::datastore::api::value::ValueType;
-use crate::datastore::Key;
+use crate::datastore::{decode_double, decode_integer, DecodeOptions, Key};
 use crate::error::ConvertError;
+use crate::types::{self, LatLng};
 
 #[cfg(feature = "datastore-derive")]
 #[doc(hidden)]
 pub use google_cloud_derive::{FromValue, IntoValue};
 
 /// A value, as stored in Datastore.
-#[derive(Debug, Clone, PartialEq)]
+///
+/// Two values are equal if they have the same type and contents, with the exception that
+/// all NaN doubles are considered equal to each other (so that values can be compared after a round-trip).
+#[derive(Debug, Clone)]
 pub enum Value {
     /// A boolean value (true or false).
     BooleanValue(bool),
@@ -58,6 +70,148 @@ impl Value {
             Value::ArrayValue(_) => "array",
         }
     }
+
+    /// Returns the canonical form of the value.
+    ///
+    /// The canonical form is the value such as it would be returned by Datastore after being stored,
+    /// so `value.canonicalize()` is equal to the value read back after writing `value`,
+    /// except for timestamps: they are left untouched, while Datastore truncates them to microseconds.
+    ///
+    /// Canonicalization does the following:
+    /// - nested index flags are collapsed into the outermost one.
+    /// - values marked as indexed (which is the default) are unwrapped from `IndexedValue`.
+    /// - the index flag of an array is applied to each of its elements instead.
+    /// - the exclusion of an entity from indexes is applied to each of its properties too.
+    /// - keys have their empty namespaces removed and ancestors share the namespace of the key itself.
+    ///
+    /// ```
+    /// # use google_cloud::datastore::Value;
+    /// let value = Value::IndexedValue(Box::new(Value::IntegerValue(10)), true);
+    /// assert_eq!(value.canonicalize(), Value::IntegerValue(10));
+    ///
+    /// let value = Value::IndexedValue(
+    ///     Box::new(Value::ArrayValue(vec![Value::BooleanValue(true)])),
+    ///     false,
+    /// );
+    /// let expected = Value::ArrayValue(vec![Value::IndexedValue(
+    ///     Box::new(Value::BooleanValue(true)),
+    ///     false,
+    /// )]);
+    /// assert_eq!(value.canonicalize(), expected);
+    /// ```
+    pub fn canonicalize(self) -> Value {
+        match self {
+            Value::IndexedValue(value, indexed) => {
+                let mut value = *value;
+                while let Value::IndexedValue(inner, _) = value {
+                    value = *inner;
+                }
+                match value {
+                    Value::ArrayValue(values) => Value::ArrayValue(
+                        values
+                            .into_iter()
+                            .map(|value| {
+                                Value::IndexedValue(Box::new(value), indexed).canonicalize()
+                            })
+                            .collect(),
+                    ),
+                    value if indexed => value.canonicalize(),
+                    Value::EntityValue(properties) => {
+                        let properties = properties
+                            .into_iter()
+                            .map(|(k, v)| {
+                                (k, Value::IndexedValue(Box::new(v), false).canonicalize())
+                            })
+                            .collect();
+                        Value::IndexedValue(Box::new(Value::EntityValue(properties)), false)
+                    }
+                    value => Value::IndexedValue(Box::new(value.canonicalize()), false),
+                }
+            }
+            Value::KeyValue(key) => Value::KeyValue(key.canonicalize()),
+            Value::EntityValue(values) => Value::EntityValue(
+                values
+                    .into_iter()
+                    .map(|(k, v)| (k, v.canonicalize()))
+                    .collect(),
+            ),
+            Value::ArrayValue(values) => {
+                Value::ArrayValue(values.into_iter().map(Value::canonicalize).collect())
+            }
+            value => value,
+        }
+    }
+
+    /// Excludes the value from indexes, along with the properties of nested entities and the elements of arrays.
+    ///
+    /// Only indexed values count towards the 1500 bytes limit of indexed strings and blobs,
+    /// so this allows to store large values anywhere within the value.
+    ///
+    /// ```
+    /// # use std::collections::HashMap;
+    /// # use google_cloud::datastore::Value;
+    /// let mut properties = HashMap::new();
+    /// properties.insert(String::from("body"), Value::StringValue(String::from("...")));
+    /// let value = Value::ArrayValue(vec![Value::EntityValue(properties)]);
+    ///
+    /// let mut expected = HashMap::new();
+    /// expected.insert(
+    ///     String::from("body"),
+    ///     Value::IndexedValue(Box::new(Value::StringValue(String::from("..."))), false),
+    /// );
+    /// let expected = Value::ArrayValue(vec![Value::IndexedValue(
+    ///     Box::new(Value::EntityValue(expected)),
+    ///     false,
+    /// )]);
+    /// assert_eq!(value.exclude_from_indexes(), expected);
+    /// ```
+    pub fn exclude_from_indexes(self) -> Value {
+        match self {
+            Value::IndexedValue(value, _) => value.exclude_from_indexes(),
+            //? Datastore does not accept index flags on arrays themselves, so only flag their elements.
+            Value::ArrayValue(values) => Value::ArrayValue(
+                values
+                    .into_iter()
+                    .map(Value::exclude_from_indexes)
+                    .collect(),
+            ),
+            Value::EntityValue(properties) => {
+                let properties = properties
+                    .into_iter()
+                    .map(|(name, value)| (name, value.exclude_from_indexes()))
+                    .collect();
+                Value::IndexedValue(Box::new(Value::EntityValue(properties)), false)
+            }
+            value => Value::IndexedValue(Box::new(value), false),
+        }
+    }
+}
+
+impl PartialEq for Value {
+    fn eq(&self, other: &Value) -> bool {
+        fn double_eq(a: f64, b: f64) -> bool {
+            a == b || (a.is_nan() && b.is_nan())
+        }
+
+        match (self, other) {
+            (Value::BooleanValue(a), Value::BooleanValue(b)) => a == b,
+            (Value::IntegerValue(a), Value::IntegerValue(b)) => a == b,
+            (Value::DoubleValue(a), Value::DoubleValue(b)) => double_eq(*a, *b),
+            (Value::TimestampValue(a), Value::TimestampValue(b)) => a == b,
+            (Value::KeyValue(a), Value::KeyValue(b)) => a == b,
+            (Value::StringValue(a), Value::StringValue(b)) => a == b,
+            (Value::IndexedValue(a, a_flag), Value::IndexedValue(b, b_flag)) => {
+                a_flag == b_flag && a == b
+            }
+            (Value::BlobValue(a), Value::BlobValue(b)) => a == b,
+            (Value::GeoPointValue(a_lat, a_lng), Value::GeoPointValue(b_lat, b_lng)) => {
+                double_eq(*a_lat, *b_lat) && double_eq(*a_lng, *b_lng)
+            }
+            (Value::EntityValue(a), Value::EntityValue(b)) => a == b,
+            (Value::ArrayValue(a), Value::ArrayValue(b)) => a == b,
+            _ => false,
+        }
+    }
 }
 
 /// Trait for converting a type to a Datastore value.
@@ -70,6 +224,28 @@ pub trait IntoValue {
 pub trait FromValue: Sized {
     /// Attempts to construct a value of this type from the passed Datastore value.
     fn from_value(value: Value) -> Result<Self, ConvertError>;
+
+    /// Attempts to construct a value of this type from the passed Datastore value,
+    /// decoding the numbers it contains according to the given options.
+    ///
+    /// Types which do not contain numbers can rely on the default implementation, which ignores the options.
+    fn from_value_with(value: Value, options: &DecodeOptions) -> Result<Self, ConvertError> {
+        let _ = options;
+        Self::from_value(value)
+    }
+
+    /// Attempts to construct a value of this type from the properties of an entity, along with its key.
+    ///
+    /// Types storing part of their value in the key of their entity (like the `#[datastore(key)]` field
+    /// of the derived implementations) restore it from the key, the default implementation ignores it.
+    fn from_entity_with(
+        key: &Key,
+        properties: Value,
+        options: &DecodeOptions,
+    ) -> Result<Self, ConvertError> {
+        let _ = key;
+        Self::from_value_with(properties, options)
+    }
 }
 
 impl IntoValue for Value {
@@ -150,6 +326,12 @@ impl IntoValue for NaiveDateTime {
     }
 }
 
+impl IntoValue for LatLng {
+    fn into_value(self) -> Value {
+        Value::GeoPointValue(self.latitude, self.longitude)
+    }
+}
+
 #[cfg(feature = "bytes")]
 impl IntoValue for Bytes {
     fn into_value(self) -> Value {
@@ -157,6 +339,42 @@ impl IntoValue for Bytes {
     }
 }
 
+/// Date-times are stored as timestamps, in UTC and truncated to microseconds.
+///
+/// Date-times beyond the range of `chrono` (about 262,000 years), which are only supported
+/// with the `large-dates` feature of `time`, are clamped to its bounds.
+/// Datastore rejects them when written anyway, only supporting the years 1 to 9999.
+#[cfg(feature = "time")]
+impl IntoValue for OffsetDateTime {
+    fn into_value(self) -> Value {
+        let mut timestamp = types::time_to_timestamp(self);
+        timestamp.nanos -= timestamp.nanos % 1_000;
+        let bound = if timestamp.seconds < 0 {
+            NaiveDateTime::MIN
+        } else {
+            NaiveDateTime::MAX
+        };
+        let datetime = types::timestamp_to_naive(timestamp).unwrap_or(bound);
+        Value::TimestampValue(datetime)
+    }
+}
+
+/// UUIDs are stored as strings, in their hyphenated and lowercase form.
+#[cfg(feature = "uuid")]
+impl IntoValue for Uuid {
+    fn into_value(self) -> Value {
+        Value::StringValue(self.hyphenated().to_string())
+    }
+}
+
+/// Decimals are stored as strings, to keep their exact value and scale (like `"12.50"`).
+#[cfg(feature = "rust_decimal")]
+impl IntoValue for Decimal {
+    fn into_value(self) -> Value {
+        Value::StringValue(self.to_string())
+    }
+}
+
 impl<T> IntoValue for Vec<T>
 where
     T: IntoValue,
@@ -196,6 +414,7 @@ impl FromValue for Value {
 impl FromValue for String {
     fn from_value(value: Value) -> Result<String, ConvertError> {
         match value {
+            Value::IndexedValue(value, _) => FromValue::from_value(*value),
             Value::StringValue(value) => Ok(value),
             _ => Err(ConvertError::UnexpectedPropertyType {
                 expected: String::from("string"),
@@ -207,24 +426,61 @@ impl FromValue for String {
 
 impl FromValue for i64 {
     fn from_value(value: Value) -> Result<i64, ConvertError> {
-        match value {
-            Value::IntegerValue(value) => Ok(value),
-            _ => Err(ConvertError::UnexpectedPropertyType {
-                expected: String::from("integer"),
-                got: String::from(value.type_name()),
-            }),
-        }
+        i64::from_value_with(value, &DecodeOptions::default())
+    }
+
+    fn from_value_with(value: Value, options: &DecodeOptions) -> Result<i64, ConvertError> {
+        decode_integer(value, options)
     }
 }
 
+macro_rules! impl_from_value_for_narrow_integer {
+    ($($ty:ty),*) => {
+        $(
+            impl FromValue for $ty {
+                fn from_value(value: Value) -> Result<$ty, ConvertError> {
+                    <$ty>::from_value_with(value, &DecodeOptions::default())
+                }
+
+                fn from_value_with(value: Value, options: &DecodeOptions) -> Result<$ty, ConvertError> {
+                    let value = decode_integer(value, options)?;
+                    <$ty>::try_from(value).map_err(|_| ConvertError::LossyNumber {
+                        value: value.to_string(),
+                        expected: String::from(stringify!($ty)),
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_from_value_for_narrow_integer!(i8, i16, i32);
+
 impl FromValue for f64 {
     fn from_value(value: Value) -> Result<f64, ConvertError> {
-        match value {
-            Value::DoubleValue(value) => Ok(value),
-            _ => Err(ConvertError::UnexpectedPropertyType {
-                expected: String::from("double"),
-                got: String::from(value.type_name()),
-            }),
+        f64::from_value_with(value, &DecodeOptions::default())
+    }
+
+    fn from_value_with(value: Value, options: &DecodeOptions) -> Result<f64, ConvertError> {
+        decode_double(value, options)
+    }
+}
+
+impl FromValue for f32 {
+    fn from_value(value: Value) -> Result<f32, ConvertError> {
+        f32::from_value_with(value, &DecodeOptions::default())
+    }
+
+    fn from_value_with(value: Value, options: &DecodeOptions) -> Result<f32, ConvertError> {
+        let value = decode_double(value, options)?;
+        let narrowed = value as f32;
+        if f64::from(narrowed) == value || value.is_nan() {
+            Ok(narrowed)
+        } else {
+            Err(ConvertError::LossyNumber {
+                value: value.to_string(),
+                expected: String::from("f32"),
+            })
         }
     }
 }
@@ -232,6 +488,7 @@ impl FromValue for f64 {
 impl FromValue for bool {
     fn from_value(value: Value) -> Result<bool, ConvertError> {
         match value {
+            Value::IndexedValue(value, _) => FromValue::from_value(*value),
             Value::BooleanValue(value) => Ok(value),
             _ => Err(ConvertError::UnexpectedPropertyType {
                 expected: String::from("bool"),
@@ -241,9 +498,23 @@ impl FromValue for bool {
     }
 }
 
+impl FromValue for LatLng {
+    fn from_value(value: Value) -> Result<LatLng, ConvertError> {
+        match value {
+            Value::IndexedValue(value, _) => FromValue::from_value(*value),
+            Value::GeoPointValue(latitude, longitude) => LatLng::new(latitude, longitude),
+            _ => Err(ConvertError::UnexpectedPropertyType {
+                expected: String::from("geopoint"),
+                got: String::from(value.type_name()),
+            }),
+        }
+    }
+}
+
 impl FromValue for Key {
     fn from_value(value: Value) -> Result<Key, ConvertError> {
         match value {
+            Value::IndexedValue(value, _) => FromValue::from_value(*value),
             Value::KeyValue(value) => Ok(value),
             _ => Err(ConvertError::UnexpectedPropertyType {
                 expected: String::from("key"),
@@ -256,6 +527,7 @@ impl FromValue for Key {
 impl FromValue for NaiveDateTime {
     fn from_value(value: Value) -> Result<NaiveDateTime, ConvertError> {
         match value {
+            Value::IndexedValue(value, _) => FromValue::from_value(*value),
             Value::TimestampValue(value) => Ok(value),
             _ => Err(ConvertError::UnexpectedPropertyType {
                 expected: String::from("timestamp"),
@@ -269,6 +541,7 @@ impl FromValue for NaiveDateTime {
 impl FromValue for Bytes {
     fn from_value(value: Value) -> Result<Bytes, ConvertError> {
         match value {
+            Value::IndexedValue(value, _) => FromValue::from_value(*value),
             Value::BlobValue(value) => Ok(Bytes::from(value)),
             _ => Err(ConvertError::UnexpectedPropertyType {
                 expected: String::from("blob"),
@@ -278,16 +551,67 @@ impl FromValue for Bytes {
     }
 }
 
+/// Date-times are decoded from timestamps, at the UTC offset.
+#[cfg(feature = "time")]
+impl FromValue for OffsetDateTime {
+    fn from_value(value: Value) -> Result<OffsetDateTime, ConvertError> {
+        let datetime = NaiveDateTime::from_value(value)?;
+        types::timestamp_to_time(types::naive_to_timestamp(datetime))
+    }
+}
+
+/// UUIDs are decoded from strings (in any of their usual forms) or 16-byte blobs.
+#[cfg(feature = "uuid")]
+impl FromValue for Uuid {
+    fn from_value(value: Value) -> Result<Uuid, ConvertError> {
+        match value {
+            Value::IndexedValue(value, _) => FromValue::from_value(*value),
+            Value::StringValue(value) => {
+                Uuid::parse_str(value.as_str()).map_err(|_| ConvertError::InvalidUuid(value))
+            }
+            Value::BlobValue(value) => Uuid::from_slice(value.as_slice())
+                .map_err(|_| ConvertError::InvalidUuid(format!("{:?}", value))),
+            _ => Err(ConvertError::UnexpectedPropertyType {
+                expected: String::from("string"),
+                got: String::from(value.type_name()),
+            }),
+        }
+    }
+}
+
+/// Decimals are decoded from strings or integers.
+#[cfg(feature = "rust_decimal")]
+impl FromValue for Decimal {
+    fn from_value(value: Value) -> Result<Decimal, ConvertError> {
+        match value {
+            Value::IndexedValue(value, _) => FromValue::from_value(*value),
+            Value::StringValue(value) => value
+                .parse()
+                .map_err(|_| ConvertError::InvalidDecimal(value)),
+            Value::IntegerValue(value) => Ok(Decimal::from(value)),
+            _ => Err(ConvertError::UnexpectedPropertyType {
+                expected: String::from("string"),
+                got: String::from(value.type_name()),
+            }),
+        }
+    }
+}
+
 impl<T> FromValue for Vec<T>
 where
     T: FromValue,
 {
     fn from_value(value: Value) -> Result<Vec<T>, ConvertError> {
+        Vec::from_value_with(value, &DecodeOptions::default())
+    }
+
+    fn from_value_with(value: Value, options: &DecodeOptions) -> Result<Vec<T>, ConvertError> {
         match value {
+            Value::IndexedValue(value, _) => FromValue::from_value_with(*value, options),
             Value::ArrayValue(values) => {
                 let values = values
                     .into_iter()
-                    .map(FromValue::from_value)
+                    .map(|value| T::from_value_with(value, options))
                     .collect::<Result<Vec<T>, ConvertError>>()?;
                 Ok(values)
             }
@@ -304,12 +628,20 @@ where
     T: FromValue,
 {
     fn from_value(value: Value) -> Result<HashMap<String, T>, ConvertError> {
+        HashMap::from_value_with(value, &DecodeOptions::default())
+    }
+
+    fn from_value_with(
+        value: Value,
+        options: &DecodeOptions,
+    ) -> Result<HashMap<String, T>, ConvertError> {
         match value {
+            Value::IndexedValue(value, _) => FromValue::from_value_with(*value, options),
             Value::EntityValue(values) => {
                 let values = values
                     .into_iter()
                     .map(|(k, v)| {
-                        let v = FromValue::from_value(v)?;
+                        let v = FromValue::from_value_with(v, options)?;
                         Ok((k, v))
                     })
                     .collect::<Result<HashMap<String, T>, ConvertError>>()?;
@@ -323,17 +655,52 @@ where
     }
 }
 
-impl From<ValueType> for Value {
-    fn from(value: ValueType) -> Value {
-        match value {
-            ValueType::NullValue(_) => unreachable!(),
+/// The meaning of integer values holding timestamps, as returned by projection queries.
+const MEANING_GD_WHEN: i32 = 7;
+
+impl TryFrom<api::Value> for Value {
+    type Error = ConvertError;
+
+    fn try_from(value: api::Value) -> Result<Value, ConvertError> {
+        let value_type = value
+            .value_type
+            .ok_or_else(|| ConvertError::MissingField(String::from("value_type")))?;
+        let inner = match value_type {
+            //? Projections return timestamps as integers (microseconds since the epoch), flagged by their meaning.
+            ValueType::IntegerValue(micros) if value.meaning == MEANING_GD_WHEN => {
+                let timestamp = DateTime::from_timestamp_micros(micros).ok_or(
+                    ConvertError::InvalidTimestamp {
+                        seconds: micros.div_euclid(1_000_000),
+                        nanos: (micros.rem_euclid(1_000_000) * 1000) as i32,
+                    },
+                )?;
+                Value::TimestampValue(timestamp.naive_utc())
+            }
+            value_type => Value::try_from(value_type)?,
+        };
+        if value.exclude_from_indexes {
+            Ok(Value::IndexedValue(Box::new(inner), false))
+        } else {
+            Ok(inner)
+        }
+    }
+}
+
+impl TryFrom<ValueType> for Value {
+    type Error = ConvertError;
+
+    fn try_from(value: ValueType) -> Result<Value, ConvertError> {
+        let value = match value {
+            ValueType::NullValue(_) => {
+                return Err(ConvertError::UnsupportedValue(String::from("null")));
+            }
             ValueType::BooleanValue(val) => Value::BooleanValue(val),
             ValueType::IntegerValue(val) => Value::IntegerValue(val),
             ValueType::DoubleValue(val) => Value::DoubleValue(val),
             ValueType::TimestampValue(val) => {
-                Value::TimestampValue(NaiveDateTime::from_timestamp(val.seconds, val.nanos as u32))
+                Value::TimestampValue(types::timestamp_to_naive(val)?)
             }
-            ValueType::KeyValue(key) => Value::KeyValue(Key::from(key)),
+            ValueType::KeyValue(key) => Value::KeyValue(Key::try_from(key)?),
             ValueType::StringValue(val) => Value::StringValue(val),
             ValueType::BlobValue(val) => Value::BlobValue(val),
             ValueType::GeoPointValue(val) => Value::GeoPointValue(val.latitude, val.longitude),
@@ -341,15 +708,16 @@ impl From<ValueType> for Value {
                 entity
                     .properties
                     .into_iter()
-                    .map(|(k, v)| (k, Value::from(v.value_type.unwrap())))
-                    .collect()
+                    .map(|(k, v)| Ok((k, Value::try_from(v)?)))
+                    .collect::<Result<_, ConvertError>>()?
             }),
             ValueType::ArrayValue(seq) => Value::ArrayValue(
                 seq.values
                     .into_iter()
-                    .map(|val| Value::from(val.value_type.unwrap()))
-                    .collect(),
+                    .map(Value::try_from)
+                    .collect::<Result<_, _>>()?,
             ),
-        }
+        };
+        Ok(value)
     }
 }
@@ -0,0 +1,947 @@
+/// A `DeliveryPipeline` resource in the Google Cloud Deploy API.
+///
+/// A `DeliveryPipeline` defines a pipeline through which a Skaffold
+/// configuration can progress.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeliveryPipeline {
+    /// Optional. Name of the `DeliveryPipeline`. Format is
+    /// projects/{project}/locations/{location}/deliveryPipelines/\[a-z][a-z0-9\-\]{0,62}.
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    /// Output only. Unique identifier of the `DeliveryPipeline`.
+    #[prost(string, tag = "2")]
+    pub uid: ::prost::alloc::string::String,
+    /// Description of the `DeliveryPipeline`. Max length is 255 characters.
+    #[prost(string, tag = "3")]
+    pub description: ::prost::alloc::string::String,
+    /// User annotations.
+    #[prost(map = "string, string", tag = "4")]
+    pub annotations: ::std::collections::HashMap<
+        ::prost::alloc::string::String,
+        ::prost::alloc::string::String,
+    >,
+    /// Labels are attributes that can be set and used by both the
+    /// user and by Google Cloud Deploy.
+    #[prost(map = "string, string", tag = "5")]
+    pub labels: ::std::collections::HashMap<
+        ::prost::alloc::string::String,
+        ::prost::alloc::string::String,
+    >,
+    /// Output only. Time at which the pipeline was created.
+    #[prost(message, optional, tag = "6")]
+    pub create_time: ::core::option::Option<::prost_types::Timestamp>,
+    /// Output only. Most recent time at which the pipeline was updated.
+    #[prost(message, optional, tag = "7")]
+    pub update_time: ::core::option::Option<::prost_types::Timestamp>,
+    /// This checksum is computed by the server based on the value of other
+    /// fields, and may be sent on update and delete requests to ensure the
+    /// client has an up-to-date value before proceeding.
+    #[prost(string, tag = "10")]
+    pub etag: ::prost::alloc::string::String,
+    /// When suspended, no new releases or rollouts can be created,
+    /// but in-progress ones will complete.
+    #[prost(bool, tag = "12")]
+    pub suspended: bool,
+    /// The ordering configuration of the `DeliveryPipeline`.
+    #[prost(oneof = "delivery_pipeline::Pipeline", tags = "8")]
+    pub pipeline: ::core::option::Option<delivery_pipeline::Pipeline>,
+}
+/// Nested message and enum types in `DeliveryPipeline`.
+pub mod delivery_pipeline {
+    /// The ordering configuration of the `DeliveryPipeline`.
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Pipeline {
+        /// SerialPipeline defines a sequential set of stages for a
+        /// `DeliveryPipeline`.
+        #[prost(message, tag = "8")]
+        SerialPipeline(super::SerialPipeline),
+    }
+}
+/// SerialPipeline defines a sequential set of stages for a `DeliveryPipeline`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SerialPipeline {
+    /// Each stage specifies configuration for a `Target`. The ordering
+    /// of this list defines the promotion flow.
+    #[prost(message, repeated, tag = "1")]
+    pub stages: ::prost::alloc::vec::Vec<Stage>,
+}
+/// Stage specifies a location to which to deploy.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Stage {
+    /// The target_id to which this stage points. This field refers exclusively to
+    /// the last segment of a target name.
+    #[prost(string, tag = "1")]
+    pub target_id: ::prost::alloc::string::String,
+    /// Skaffold profiles to use when rendering the manifest for this stage's
+    /// `Target`.
+    #[prost(string, repeated, tag = "2")]
+    pub profiles: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// The request object for `ListDeliveryPipelines`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListDeliveryPipelinesRequest {
+    /// Required. The parent, which owns this collection of pipelines. Format must
+    /// be projects/{project_id}/locations/{location_name}.
+    #[prost(string, tag = "1")]
+    pub parent: ::prost::alloc::string::String,
+    /// The maximum number of pipelines to return.
+    #[prost(int32, tag = "2")]
+    pub page_size: i32,
+    /// A page token, received from a previous `ListDeliveryPipelines` call.
+    #[prost(string, tag = "3")]
+    pub page_token: ::prost::alloc::string::String,
+    /// Filter pipelines to be returned.
+    #[prost(string, tag = "4")]
+    pub filter: ::prost::alloc::string::String,
+    /// Field to sort by.
+    #[prost(string, tag = "5")]
+    pub order_by: ::prost::alloc::string::String,
+}
+/// The response object from `ListDeliveryPipelines`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListDeliveryPipelinesResponse {
+    /// The `DeliveryPipeline` objects.
+    #[prost(message, repeated, tag = "1")]
+    pub delivery_pipelines: ::prost::alloc::vec::Vec<DeliveryPipeline>,
+    /// A token, which can be sent as `page_token` to retrieve the next page.
+    /// If this field is omitted, there are no subsequent pages.
+    #[prost(string, tag = "2")]
+    pub next_page_token: ::prost::alloc::string::String,
+    /// Locations that could not be reached.
+    #[prost(string, repeated, tag = "3")]
+    pub unreachable: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// The request object for `GetDeliveryPipeline`
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetDeliveryPipelineRequest {
+    /// Required. Name of the `DeliveryPipeline`. Format must be
+    /// projects/{project_id}/locations/{location_name}/deliveryPipelines/{pipeline_name}.
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+}
+/// A `Release` resource in the Google Cloud Deploy API.
+///
+/// A `Release` defines a specific Skaffold configuration instance
+/// that can be deployed.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Release {
+    /// Optional. Name of the `Release`. Format is projects/{project}/
+    /// locations/{location}/deliveryPipelines/{deliveryPipeline}/
+    /// releases/\[a-z][a-z0-9\-\]{0,62}.
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    /// Output only. Unique identifier of the `Release`.
+    #[prost(string, tag = "2")]
+    pub uid: ::prost::alloc::string::String,
+    /// Description of the `Release`. Max length is 255 characters.
+    #[prost(string, tag = "3")]
+    pub description: ::prost::alloc::string::String,
+    /// User annotations.
+    #[prost(map = "string, string", tag = "4")]
+    pub annotations: ::std::collections::HashMap<
+        ::prost::alloc::string::String,
+        ::prost::alloc::string::String,
+    >,
+    /// Labels are attributes that can be set and used by both the
+    /// user and by Google Cloud Deploy.
+    #[prost(map = "string, string", tag = "5")]
+    pub labels: ::std::collections::HashMap<
+        ::prost::alloc::string::String,
+        ::prost::alloc::string::String,
+    >,
+    /// Output only. Time at which the `Release` was created.
+    #[prost(message, optional, tag = "6")]
+    pub create_time: ::core::option::Option<::prost_types::Timestamp>,
+    /// Output only. Time at which the render began.
+    #[prost(message, optional, tag = "7")]
+    pub render_start_time: ::core::option::Option<::prost_types::Timestamp>,
+    /// Output only. Time at which the render completed.
+    #[prost(message, optional, tag = "8")]
+    pub render_end_time: ::core::option::Option<::prost_types::Timestamp>,
+    /// Filepath of the Skaffold config inside of the config URI.
+    #[prost(string, tag = "9")]
+    pub skaffold_config_path: ::prost::alloc::string::String,
+    /// List of artifacts to pass through to Skaffold command.
+    #[prost(message, repeated, tag = "10")]
+    pub build_artifacts: ::prost::alloc::vec::Vec<BuildArtifact>,
+    /// Output only. Current state of the render operation.
+    #[prost(enumeration = "release::RenderState", tag = "13")]
+    pub render_state: i32,
+    /// This checksum is computed by the server based on the value of other
+    /// fields, and may be sent on update and delete requests to ensure the
+    /// client has an up-to-date value before proceeding.
+    #[prost(string, tag = "16")]
+    pub etag: ::prost::alloc::string::String,
+    /// Cloud Storage URI of tar.gz archive containing Skaffold configuration.
+    #[prost(string, tag = "17")]
+    pub skaffold_config_uri: ::prost::alloc::string::String,
+    /// The Skaffold version to use when operating on this release, such as
+    /// "1.20.0". Not all versions are valid; Google Cloud Deploy supports a
+    /// specific set of versions.
+    ///
+    /// If unset, the most recent supported Skaffold version will be used.
+    #[prost(string, tag = "19")]
+    pub skaffold_version: ::prost::alloc::string::String,
+    /// Output only. Indicates whether this is an abandoned release.
+    #[prost(bool, tag = "23")]
+    pub abandoned: bool,
+}
+/// Nested message and enum types in `Release`.
+pub mod release {
+    /// Valid states of the render operation.
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum RenderState {
+        /// The render state is unspecified.
+        Unspecified = 0,
+        /// All rendering operations have completed successfully.
+        Succeeded = 1,
+        /// All rendering operations have completed, and one or more have failed.
+        Failed = 2,
+        /// Rendering has started and is not complete.
+        InProgress = 3,
+    }
+    impl RenderState {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                RenderState::Unspecified => "RENDER_STATE_UNSPECIFIED",
+                RenderState::Succeeded => "SUCCEEDED",
+                RenderState::Failed => "FAILED",
+                RenderState::InProgress => "IN_PROGRESS",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "RENDER_STATE_UNSPECIFIED" => Some(Self::Unspecified),
+                "SUCCEEDED" => Some(Self::Succeeded),
+                "FAILED" => Some(Self::Failed),
+                "IN_PROGRESS" => Some(Self::InProgress),
+                _ => None,
+            }
+        }
+    }
+}
+/// Description of an a image to use during Skaffold rendering.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BuildArtifact {
+    /// Image name in Skaffold configuration.
+    #[prost(string, tag = "3")]
+    pub image: ::prost::alloc::string::String,
+    /// Image tag to use. This will generally be the full path to an image, such
+    /// as "gcr.io/my-project/busybox:1.2.3" or
+    /// "gcr.io/my-project/busybox@sha256:abc123".
+    #[prost(string, tag = "2")]
+    pub tag: ::prost::alloc::string::String,
+}
+/// The request object for `ListReleases`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListReleasesRequest {
+    /// Required. The `DeliveryPipeline` which owns this collection of `Release`
+    /// objects.
+    #[prost(string, tag = "1")]
+    pub parent: ::prost::alloc::string::String,
+    /// Optional. The maximum number of `Release` objects to return.
+    #[prost(int32, tag = "2")]
+    pub page_size: i32,
+    /// Optional. A page token, received from a previous `ListReleases` call.
+    #[prost(string, tag = "3")]
+    pub page_token: ::prost::alloc::string::String,
+    /// Optional. Filter releases to be returned.
+    #[prost(string, tag = "4")]
+    pub filter: ::prost::alloc::string::String,
+    /// Optional. Field to sort by.
+    #[prost(string, tag = "5")]
+    pub order_by: ::prost::alloc::string::String,
+}
+/// The response object from `ListReleases`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListReleasesResponse {
+    /// The `Release` objects.
+    #[prost(message, repeated, tag = "1")]
+    pub releases: ::prost::alloc::vec::Vec<Release>,
+    /// A token, which can be sent as `page_token` to retrieve the next page.
+    /// If this field is omitted, there are no subsequent pages.
+    #[prost(string, tag = "2")]
+    pub next_page_token: ::prost::alloc::string::String,
+    /// Locations that could not be reached.
+    #[prost(string, repeated, tag = "3")]
+    pub unreachable: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// The request object for `GetRelease`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetReleaseRequest {
+    /// Required. Name of the `Release`. Format must be
+    /// projects/{project_id}/locations/{location_name}/deliveryPipelines/{pipeline_name}/releases/{release_name}.
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+}
+/// The request object for `CreateRelease`,
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateReleaseRequest {
+    /// Required. The parent collection in which the `Release` should be created.
+    /// Format should be
+    /// projects/{project_id}/locations/{location_name}/deliveryPipelines/{pipeline_name}.
+    #[prost(string, tag = "1")]
+    pub parent: ::prost::alloc::string::String,
+    /// Required. ID of the `Release`.
+    #[prost(string, tag = "2")]
+    pub release_id: ::prost::alloc::string::String,
+    /// Required. The `Release` to create.
+    #[prost(message, optional, tag = "3")]
+    pub release: ::core::option::Option<Release>,
+    /// Optional. A request ID to identify requests. Specify a unique request ID
+    /// so that if you must retry your request, the server will know to ignore
+    /// the request if it has already been completed.
+    #[prost(string, tag = "4")]
+    pub request_id: ::prost::alloc::string::String,
+    /// Optional. If set to true, the request is validated and the user is provided
+    /// with an expected result, but no actual change is made.
+    #[prost(bool, tag = "5")]
+    pub validate_only: bool,
+}
+/// A `Rollout` resource in the Google Cloud Deploy API.
+///
+/// A `Rollout` contains information around a specific deployment to a `Target`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Rollout {
+    /// Optional. Name of the `Rollout`. Format is projects/{project}/
+    /// locations/{location}/deliveryPipelines/{deliveryPipeline}/
+    /// releases/{release}/rollouts/\[a-z][a-z0-9\-\]{0,62}.
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    /// Output only. Unique identifier of the `Rollout`.
+    #[prost(string, tag = "2")]
+    pub uid: ::prost::alloc::string::String,
+    /// Description of the `Rollout` for user purposes. Max length is 255
+    /// characters.
+    #[prost(string, tag = "3")]
+    pub description: ::prost::alloc::string::String,
+    /// User annotations.
+    #[prost(map = "string, string", tag = "4")]
+    pub annotations: ::std::collections::HashMap<
+        ::prost::alloc::string::String,
+        ::prost::alloc::string::String,
+    >,
+    /// Labels are attributes that can be set and used by both the
+    /// user and by Google Cloud Deploy.
+    #[prost(map = "string, string", tag = "5")]
+    pub labels: ::std::collections::HashMap<
+        ::prost::alloc::string::String,
+        ::prost::alloc::string::String,
+    >,
+    /// Output only. Time at which the `Rollout` was created.
+    #[prost(message, optional, tag = "6")]
+    pub create_time: ::core::option::Option<::prost_types::Timestamp>,
+    /// Output only. Time at which the `Rollout` was approved.
+    #[prost(message, optional, tag = "7")]
+    pub approve_time: ::core::option::Option<::prost_types::Timestamp>,
+    /// Output only. Time at which the `Rollout` was enqueued.
+    #[prost(message, optional, tag = "8")]
+    pub enqueue_time: ::core::option::Option<::prost_types::Timestamp>,
+    /// Output only. Time at which the `Rollout` started deploying.
+    #[prost(message, optional, tag = "9")]
+    pub deploy_start_time: ::core::option::Option<::prost_types::Timestamp>,
+    /// Output only. Time at which the `Rollout` finished deploying.
+    #[prost(message, optional, tag = "10")]
+    pub deploy_end_time: ::core::option::Option<::prost_types::Timestamp>,
+    /// Output only. Approval state of the `Rollout`.
+    #[prost(enumeration = "rollout::ApprovalState", tag = "12")]
+    pub approval_state: i32,
+    /// Output only. Current state of the `Rollout`.
+    #[prost(enumeration = "rollout::State", tag = "13")]
+    pub state: i32,
+    /// Output only. Additional information about the rollout failure, if
+    /// available.
+    #[prost(string, tag = "14")]
+    pub failure_reason: ::prost::alloc::string::String,
+    /// This checksum is computed by the server based on the value of other
+    /// fields, and may be sent on update and delete requests to ensure the
+    /// client has an up-to-date value before proceeding.
+    #[prost(string, tag = "16")]
+    pub etag: ::prost::alloc::string::String,
+    /// Output only. The resource name of the Cloud Build `Build` object that is
+    /// used to deploy the Rollout. Format is
+    /// `projects/{project}/locations/{location}/builds/{build}`.
+    #[prost(string, tag = "17")]
+    pub deploying_build: ::prost::alloc::string::String,
+    /// Required. The ID of Target to which this `Rollout` is deploying.
+    #[prost(string, tag = "18")]
+    pub target_id: ::prost::alloc::string::String,
+    /// Output only. The phases that represent the workflows of this `Rollout`.
+    #[prost(message, repeated, tag = "23")]
+    pub phases: ::prost::alloc::vec::Vec<Phase>,
+}
+/// Nested message and enum types in `Rollout`.
+pub mod rollout {
+    /// Valid approval states of a `Rollout`.
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum ApprovalState {
+        /// The `Rollout` has an unspecified approval state.
+        Unspecified = 0,
+        /// The `Rollout` requires approval.
+        NeedsApproval = 1,
+        /// The `Rollout` does not require approval.
+        DoesNotNeedApproval = 2,
+        /// The `Rollout` has been approved.
+        Approved = 3,
+        /// The `Rollout` has been rejected.
+        Rejected = 4,
+    }
+    impl ApprovalState {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                ApprovalState::Unspecified => "APPROVAL_STATE_UNSPECIFIED",
+                ApprovalState::NeedsApproval => "NEEDS_APPROVAL",
+                ApprovalState::DoesNotNeedApproval => "DOES_NOT_NEED_APPROVAL",
+                ApprovalState::Approved => "APPROVED",
+                ApprovalState::Rejected => "REJECTED",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "APPROVAL_STATE_UNSPECIFIED" => Some(Self::Unspecified),
+                "NEEDS_APPROVAL" => Some(Self::NeedsApproval),
+                "DOES_NOT_NEED_APPROVAL" => Some(Self::DoesNotNeedApproval),
+                "APPROVED" => Some(Self::Approved),
+                "REJECTED" => Some(Self::Rejected),
+                _ => None,
+            }
+        }
+    }
+    /// Valid states of a `Rollout`.
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum State {
+        /// The `Rollout` has an unspecified state.
+        Unspecified = 0,
+        /// The `Rollout` has completed successfully.
+        Succeeded = 1,
+        /// The `Rollout` has failed.
+        Failed = 2,
+        /// The `Rollout` is being deployed.
+        InProgress = 3,
+        /// The `Rollout` needs approval.
+        PendingApproval = 4,
+        /// An approver rejected the `Rollout`.
+        ApprovalRejected = 5,
+        /// The `Rollout` is waiting for an earlier Rollout(s) to complete on this
+        /// `Target`.
+        Pending = 6,
+        /// The `Rollout` is waiting for the `Release` to be fully rendered.
+        PendingRelease = 7,
+        /// The `Rollout` is in the process of being cancelled.
+        Cancelling = 8,
+        /// The `Rollout` has been cancelled.
+        Cancelled = 9,
+        /// The `Rollout` is halted.
+        Halted = 10,
+    }
+    impl State {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                State::Unspecified => "STATE_UNSPECIFIED",
+                State::Succeeded => "SUCCEEDED",
+                State::Failed => "FAILED",
+                State::InProgress => "IN_PROGRESS",
+                State::PendingApproval => "PENDING_APPROVAL",
+                State::ApprovalRejected => "APPROVAL_REJECTED",
+                State::Pending => "PENDING",
+                State::PendingRelease => "PENDING_RELEASE",
+                State::Cancelling => "CANCELLING",
+                State::Cancelled => "CANCELLED",
+                State::Halted => "HALTED",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "STATE_UNSPECIFIED" => Some(Self::Unspecified),
+                "SUCCEEDED" => Some(Self::Succeeded),
+                "FAILED" => Some(Self::Failed),
+                "IN_PROGRESS" => Some(Self::InProgress),
+                "PENDING_APPROVAL" => Some(Self::PendingApproval),
+                "APPROVAL_REJECTED" => Some(Self::ApprovalRejected),
+                "PENDING" => Some(Self::Pending),
+                "PENDING_RELEASE" => Some(Self::PendingRelease),
+                "CANCELLING" => Some(Self::Cancelling),
+                "CANCELLED" => Some(Self::Cancelled),
+                "HALTED" => Some(Self::Halted),
+                _ => None,
+            }
+        }
+    }
+}
+/// Phase represents a collection of jobs that are logically grouped together
+/// for a `Rollout`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Phase {
+    /// Output only. The ID of the Phase.
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// Output only. Current state of the Phase.
+    #[prost(enumeration = "phase::State", tag = "3")]
+    pub state: i32,
+    /// Output only. Additional information on why the Phase was skipped, if
+    /// available.
+    #[prost(string, tag = "6")]
+    pub skip_message: ::prost::alloc::string::String,
+}
+/// Nested message and enum types in `Phase`.
+pub mod phase {
+    /// Valid states of a Phase.
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum State {
+        /// The Phase has an unspecified state.
+        Unspecified = 0,
+        /// The Phase is waiting for an earlier Phase(s) to complete.
+        Pending = 1,
+        /// The Phase is in progress.
+        InProgress = 2,
+        /// The Phase has succeeded.
+        Succeeded = 3,
+        /// The Phase has failed.
+        Failed = 4,
+        /// The Phase was aborted.
+        Aborted = 5,
+        /// The Phase was skipped.
+        Skipped = 6,
+    }
+    impl State {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                State::Unspecified => "STATE_UNSPECIFIED",
+                State::Pending => "PENDING",
+                State::InProgress => "IN_PROGRESS",
+                State::Succeeded => "SUCCEEDED",
+                State::Failed => "FAILED",
+                State::Aborted => "ABORTED",
+                State::Skipped => "SKIPPED",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "STATE_UNSPECIFIED" => Some(Self::Unspecified),
+                "PENDING" => Some(Self::Pending),
+                "IN_PROGRESS" => Some(Self::InProgress),
+                "SUCCEEDED" => Some(Self::Succeeded),
+                "FAILED" => Some(Self::Failed),
+                "ABORTED" => Some(Self::Aborted),
+                "SKIPPED" => Some(Self::Skipped),
+                _ => None,
+            }
+        }
+    }
+}
+/// ListRolloutsRequest is the request object used by `ListRollouts`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListRolloutsRequest {
+    /// Required. The `Release` which owns this collection of `Rollout` objects.
+    #[prost(string, tag = "1")]
+    pub parent: ::prost::alloc::string::String,
+    /// Optional. The maximum number of `Rollout` objects to return.
+    #[prost(int32, tag = "2")]
+    pub page_size: i32,
+    /// Optional. A page token, received from a previous `ListRollouts` call.
+    #[prost(string, tag = "3")]
+    pub page_token: ::prost::alloc::string::String,
+    /// Optional. Filter rollouts to be returned.
+    #[prost(string, tag = "4")]
+    pub filter: ::prost::alloc::string::String,
+    /// Optional. Field to sort by.
+    #[prost(string, tag = "5")]
+    pub order_by: ::prost::alloc::string::String,
+}
+/// ListRolloutsResponse is the response object reutrned by `ListRollouts`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListRolloutsResponse {
+    /// The `Rollout` objects.
+    #[prost(message, repeated, tag = "1")]
+    pub rollouts: ::prost::alloc::vec::Vec<Rollout>,
+    /// A token, which can be sent as `page_token` to retrieve the next page.
+    /// If this field is omitted, there are no subsequent pages.
+    #[prost(string, tag = "2")]
+    pub next_page_token: ::prost::alloc::string::String,
+    /// Locations that could not be reached.
+    #[prost(string, repeated, tag = "3")]
+    pub unreachable: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// GetRolloutRequest is the request object used by `GetRollout`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetRolloutRequest {
+    /// Required. Name of the `Rollout`. Format must be
+    /// projects/{project_id}/locations/{location_name}/deliveryPipelines/{pipeline_name}/releases/{release_name}/rollouts/{rollout_name}.
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+}
+/// The request object used by `ApproveRollout`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ApproveRolloutRequest {
+    /// Required. Name of the Rollout. Format is
+    /// projects/{project}/locations/{location}/deliveryPipelines/{deliveryPipeline}/
+    /// releases/{release}/rollouts/{rollout}.
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    /// Required. True = approve; false = reject
+    #[prost(bool, tag = "2")]
+    pub approved: bool,
+}
+/// The response object from `ApproveRollout`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ApproveRolloutResponse {}
+/// The request object used by `AdvanceRollout`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AdvanceRolloutRequest {
+    /// Required. Name of the Rollout. Format is
+    /// projects/{project}/locations/{location}/deliveryPipelines/{deliveryPipeline}/
+    /// releases/{release}/rollouts/{rollout}.
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    /// Required. The phase ID to advance the `Rollout` to.
+    #[prost(string, tag = "2")]
+    pub phase_id: ::prost::alloc::string::String,
+}
+/// The response object from `AdvanceRollout`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AdvanceRolloutResponse {}
+/// Generated client implementations.
+pub mod cloud_deploy_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    /// CloudDeploy service creates and manages Continuous Delivery operations
+    /// on Google Cloud Platform via Skaffold (https://skaffold.dev).
+    #[derive(Debug, Clone)]
+    pub struct CloudDeployClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl CloudDeployClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: std::convert::TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> CloudDeployClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> CloudDeployClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+            >>::Error: Into<StdError> + Send + Sync,
+        {
+            CloudDeployClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Lists DeliveryPipelines in a given project and location.
+        pub async fn list_delivery_pipelines(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListDeliveryPipelinesRequest>,
+        ) -> Result<
+            tonic::Response<super::ListDeliveryPipelinesResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/google.cloud.deploy.v1.CloudDeploy/ListDeliveryPipelines",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// Gets details of a single DeliveryPipeline.
+        pub async fn get_delivery_pipeline(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetDeliveryPipelineRequest>,
+        ) -> Result<tonic::Response<super::DeliveryPipeline>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/google.cloud.deploy.v1.CloudDeploy/GetDeliveryPipeline",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// Lists Releases in a given project and location.
+        pub async fn list_releases(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListReleasesRequest>,
+        ) -> Result<tonic::Response<super::ListReleasesResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/google.cloud.deploy.v1.CloudDeploy/ListReleases",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// Gets details of a single Release.
+        pub async fn get_release(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetReleaseRequest>,
+        ) -> Result<tonic::Response<super::Release>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/google.cloud.deploy.v1.CloudDeploy/GetRelease",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// Creates a new Release in a given project and location.
+        pub async fn create_release(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CreateReleaseRequest>,
+        ) -> Result<
+            tonic::Response<super::super::super::super::longrunning::Operation>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/google.cloud.deploy.v1.CloudDeploy/CreateRelease",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// Approves a Rollout.
+        pub async fn approve_rollout(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ApproveRolloutRequest>,
+        ) -> Result<tonic::Response<super::ApproveRolloutResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/google.cloud.deploy.v1.CloudDeploy/ApproveRollout",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// Advances a Rollout in a given project and location.
+        pub async fn advance_rollout(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AdvanceRolloutRequest>,
+        ) -> Result<tonic::Response<super::AdvanceRolloutResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/google.cloud.deploy.v1.CloudDeploy/AdvanceRollout",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// Lists Rollouts in a given project and location.
+        pub async fn list_rollouts(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListRolloutsRequest>,
+        ) -> Result<tonic::Response<super::ListRolloutsResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/google.cloud.deploy.v1.CloudDeploy/ListRollouts",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// Gets details of a single Rollout.
+        pub async fn get_rollout(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetRolloutRequest>,
+        ) -> Result<tonic::Response<super::Rollout>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/google.cloud.deploy.v1.CloudDeploy/GetRollout",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+    }
+}
@@ -0,0 +1,364 @@
+use std::env;
+use std::fs::File;
+use std::sync::Arc;
+use std::time::Duration;
+
+use prost::Message;
+use tokio::sync::Mutex;
+use tonic::transport::Channel;
+use tonic::{Code, IntoRequest, Request};
+
+use crate::authorize::{ApplicationCredentials, TokenManager};
+use crate::cancel::{self, CancellationToken};
+use crate::channel::{self, ChannelConfig};
+use crate::deploy::api;
+use crate::deploy::api::cloud_deploy_client::CloudDeployClient;
+use crate::deploy::api::longrunning;
+use crate::deploy::api::longrunning::operations_client::OperationsClient;
+use crate::deploy::{DeliveryPipeline, Error, Release, ReleaseConfig, Rollout};
+use crate::error::ConvertError;
+
+/// The Cloud Deploy client, tied to a specific project.
+#[derive(Clone)]
+pub struct Client {
+    pub(crate) project_name: String,
+    pub(crate) service: CloudDeployClient<Channel>,
+    pub(crate) operations: OperationsClient<Channel>,
+    pub(crate) token_manager: Arc<Mutex<TokenManager>>,
+    pub(crate) cancel: Option<CancellationToken>,
+}
+
+impl Client {
+    pub(crate) const DOMAIN_NAME: &'static str = "clouddeploy.googleapis.com";
+    pub(crate) const ENDPOINT: &'static str = "https://clouddeploy.googleapis.com";
+    pub(crate) const SCOPES: [&'static str; 1] = ["https://www.googleapis.com/auth/cloud-platform"];
+
+    //? Bounds of the delay between two checks of a long-running operation.
+    const MIN_POLL_DELAY: Duration = Duration::from_secs(1);
+    const MAX_POLL_DELAY: Duration = Duration::from_secs(10);
+
+    pub(crate) async fn construct_request<T: IntoRequest<T>>(
+        &mut self,
+        request: T,
+    ) -> Result<Request<T>, Error> {
+        let mut request = request.into_request();
+        let token = self.token_manager.lock().await.token().await?;
+        let metadata = request.metadata_mut();
+        metadata.insert("authorization", token.parse().unwrap());
+        Ok(request)
+    }
+
+    /// Create a new client for the specified project.
+    ///
+    /// Credentials are looked up in the `GOOGLE_APPLICATION_CREDENTIALS` environment variable.
+    pub async fn new(project_name: impl Into<String>) -> Result<Client, Error> {
+        let path = env::var("GOOGLE_APPLICATION_CREDENTIALS")?;
+        let file = File::open(path)?;
+        let creds = json::from_reader(file)?;
+
+        Client::from_credentials(project_name, creds).await
+    }
+
+    /// Create a new client for the specified project with custom credentials.
+    pub async fn from_credentials(
+        project_name: impl Into<String>,
+        creds: ApplicationCredentials,
+    ) -> Result<Client, Error> {
+        Client::from_credentials_with_config(project_name, creds, ChannelConfig::default()).await
+    }
+
+    /// Create a new client for the specified project with custom credentials and channel configuration.
+    pub async fn from_credentials_with_config(
+        project_name: impl Into<String>,
+        creds: ApplicationCredentials,
+        config: ChannelConfig,
+    ) -> Result<Client, Error> {
+        let channel = channel::connect(Client::ENDPOINT, Client::DOMAIN_NAME, &config).await?;
+
+        Ok(Client {
+            project_name: project_name.into(),
+            service: CloudDeployClient::new(channel.clone()),
+            operations: OperationsClient::new(channel),
+            token_manager: Arc::new(Mutex::new(TokenManager::new(
+                creds,
+                Client::SCOPES.as_ref(),
+            ))),
+            cancel: None,
+        })
+    }
+
+    /// Stop waiting for long-running operations once the given token gets cancelled,
+    /// the waiting calls then failing with a `CANCELLED` status.
+    ///
+    /// The operations themselves keep running on the service.
+    pub fn cancel_on(mut self, token: CancellationToken) -> Client {
+        self.cancel = Some(token);
+        self
+    }
+
+    fn location_name(&self, region: &str) -> String {
+        format!(
+            "projects/{0}/locations/{1}",
+            self.project_name.as_str(),
+            region,
+        )
+    }
+
+    fn pipeline_name(&self, region: &str, pipeline_id: &str) -> String {
+        format!(
+            "{0}/deliveryPipelines/{1}",
+            self.location_name(region),
+            pipeline_id,
+        )
+    }
+
+    fn release_name(&self, region: &str, pipeline_id: &str, release_id: &str) -> String {
+        format!(
+            "{0}/releases/{1}",
+            self.pipeline_name(region, pipeline_id),
+            release_id,
+        )
+    }
+
+    /// List all the delivery pipelines of a region.
+    pub async fn pipelines(&mut self, region: &str) -> Result<Vec<DeliveryPipeline>, Error> {
+        let mut pipelines = Vec::new();
+        let page_size = 25;
+        let mut page_token = String::default();
+
+        loop {
+            let request = api::ListDeliveryPipelinesRequest {
+                parent: self.location_name(region),
+                page_size,
+                page_token,
+                filter: String::new(),
+                order_by: String::new(),
+            };
+            let request = self.construct_request(request).await?;
+            let response = self.service.list_delivery_pipelines(request).await?;
+            let response = response.into_inner();
+            page_token = response.next_page_token;
+            pipelines.extend(
+                response
+                    .delivery_pipelines
+                    .into_iter()
+                    .map(DeliveryPipeline::from),
+            );
+            if page_token.is_empty() {
+                break;
+            }
+        }
+
+        Ok(pipelines)
+    }
+
+    /// Get a specific delivery pipeline.
+    pub async fn pipeline(
+        &mut self,
+        region: &str,
+        pipeline_id: &str,
+    ) -> Result<Option<DeliveryPipeline>, Error> {
+        let request = api::GetDeliveryPipelineRequest {
+            name: self.pipeline_name(region, pipeline_id),
+        };
+        let request = self.construct_request(request).await?;
+        let pipeline = match self.service.get_delivery_pipeline(request).await {
+            Ok(response) => response.into_inner(),
+            Err(status) if status.code() == Code::NotFound => return Ok(None),
+            Err(status) => return Err(status.into()),
+        };
+
+        Ok(Some(DeliveryPipeline::from(pipeline)))
+    }
+
+    /// List all the releases of a delivery pipeline.
+    pub async fn releases(&mut self, pipeline: &DeliveryPipeline) -> Result<Vec<Release>, Error> {
+        let mut releases = Vec::new();
+        let page_size = 25;
+        let mut page_token = String::default();
+
+        loop {
+            let request = api::ListReleasesRequest {
+                parent: pipeline.name.clone(),
+                page_size,
+                page_token,
+                filter: String::new(),
+                order_by: String::new(),
+            };
+            let request = self.construct_request(request).await?;
+            let response = self.service.list_releases(request).await?;
+            let response = response.into_inner();
+            page_token = response.next_page_token;
+            releases.extend(response.releases.into_iter().map(Release::from));
+            if page_token.is_empty() {
+                break;
+            }
+        }
+
+        Ok(releases)
+    }
+
+    /// Get a specific release.
+    pub async fn release(
+        &mut self,
+        region: &str,
+        pipeline_id: &str,
+        release_id: &str,
+    ) -> Result<Option<Release>, Error> {
+        let request = api::GetReleaseRequest {
+            name: self.release_name(region, pipeline_id, release_id),
+        };
+        let request = self.construct_request(request).await?;
+        let release = match self.service.get_release(request).await {
+            Ok(response) => response.into_inner(),
+            Err(status) if status.code() == Code::NotFound => return Ok(None),
+            Err(status) => return Err(status.into()),
+        };
+
+        Ok(Some(Release::from(release)))
+    }
+
+    /// Create a new release of a delivery pipeline, and wait for it to be created.
+    ///
+    /// Cloud Deploy then renders the release, and rolls it out to the first stage of the pipeline.
+    pub async fn create_release(
+        &mut self,
+        pipeline: &DeliveryPipeline,
+        release_id: &str,
+        config: ReleaseConfig,
+    ) -> Result<Release, Error> {
+        let request = api::CreateReleaseRequest {
+            parent: pipeline.name.clone(),
+            release_id: release_id.to_string(),
+            release: Some(config.into()),
+            request_id: String::new(),
+            validate_only: false,
+        };
+        let request = self.construct_request(request).await?;
+        let response = self.service.create_release(request).await?;
+        let release: api::Release = self.wait_operation(response.into_inner()).await?;
+
+        Ok(Release::from(release))
+    }
+
+    /// List all the rollouts of a release.
+    pub async fn rollouts(&mut self, release: &Release) -> Result<Vec<Rollout>, Error> {
+        let mut rollouts = Vec::new();
+        let page_size = 25;
+        let mut page_token = String::default();
+
+        loop {
+            let request = api::ListRolloutsRequest {
+                parent: release.name.clone(),
+                page_size,
+                page_token,
+                filter: String::new(),
+                order_by: String::new(),
+            };
+            let request = self.construct_request(request).await?;
+            let response = self.service.list_rollouts(request).await?;
+            let response = response.into_inner();
+            page_token = response.next_page_token;
+            rollouts.extend(response.rollouts.into_iter().map(Rollout::from));
+            if page_token.is_empty() {
+                break;
+            }
+        }
+
+        Ok(rollouts)
+    }
+
+    /// Get a specific rollout.
+    pub async fn rollout(
+        &mut self,
+        region: &str,
+        pipeline_id: &str,
+        release_id: &str,
+        rollout_id: &str,
+    ) -> Result<Option<Rollout>, Error> {
+        let request = api::GetRolloutRequest {
+            name: format!(
+                "{0}/rollouts/{1}",
+                self.release_name(region, pipeline_id, release_id),
+                rollout_id,
+            ),
+        };
+        let request = self.construct_request(request).await?;
+        let rollout = match self.service.get_rollout(request).await {
+            Ok(response) => response.into_inner(),
+            Err(status) if status.code() == Code::NotFound => return Ok(None),
+            Err(status) => return Err(status.into()),
+        };
+
+        Ok(Some(Rollout::from(rollout)))
+    }
+
+    /// Approve (or reject) a rollout pending approval.
+    pub async fn approve_rollout(
+        &mut self,
+        rollout: &Rollout,
+        approved: bool,
+    ) -> Result<(), Error> {
+        let request = api::ApproveRolloutRequest {
+            name: rollout.name.clone(),
+            approved,
+        };
+        let request = self.construct_request(request).await?;
+        self.service.approve_rollout(request).await?;
+
+        Ok(())
+    }
+
+    /// Advance a rollout to the given phase (such as the next step of a canary deployment).
+    pub async fn advance_rollout(
+        &mut self,
+        rollout: &Rollout,
+        phase_id: &str,
+    ) -> Result<(), Error> {
+        let request = api::AdvanceRolloutRequest {
+            name: rollout.name.clone(),
+            phase_id: phase_id.to_string(),
+        };
+        let request = self.construct_request(request).await?;
+        self.service.advance_rollout(request).await?;
+
+        Ok(())
+    }
+
+    /// Polls a long-running operation until it is done, and decodes its response.
+    async fn wait_operation<T: Message + Default>(
+        &mut self,
+        mut operation: longrunning::Operation,
+    ) -> Result<T, Error> {
+        let token = self.cancel.clone();
+        let mut delay = Client::MIN_POLL_DELAY;
+        let poll = async {
+            while !operation.done {
+                tokio::time::sleep(delay).await;
+                delay = Client::MAX_POLL_DELAY.min(delay * 2);
+                let request = longrunning::GetOperationRequest {
+                    name: operation.name.clone(),
+                };
+                let request = self.construct_request(request).await?;
+                let response = self.operations.get_operation(request).await?;
+                operation = response.into_inner();
+            }
+            Ok(())
+        };
+        cancel::cancellable(token.as_ref(), poll).await?;
+
+        match operation.result {
+            Some(longrunning::operation::Result::Response(response)) => {
+                T::decode(response.value.as_slice()).map_err(|err| {
+                    let message = format!("invalid operation response: {}", err);
+                    Error::Status(tonic::Status::internal(message))
+                })
+            }
+            Some(longrunning::operation::Result::Error(status)) => {
+                let code = Code::from(status.code);
+                Err(Error::Status(tonic::Status::new(code, status.message)))
+            }
+            None => Err(ConvertError::MissingField("result".to_string()).into()),
+        }
+    }
+}
@@ -0,0 +1,37 @@
+mod client;
+mod pipeline;
+mod release;
+mod rollout;
+pub(crate) mod api {
+    #![allow(
+        dead_code,
+        clippy::doc_lazy_continuation,
+        clippy::doc_overindented_list_items
+    )]
+    pub mod rpc {
+        include!("api/google.rpc.rs");
+    }
+    #[allow(clippy::module_inception)]
+    pub mod api {
+        include!("api/google.api.rs");
+    }
+    pub mod longrunning {
+        include!("api/google.longrunning.rs");
+    }
+    pub mod cloud {
+        pub mod deploy {
+            pub mod v1 {
+                include!("api/google.cloud.deploy.v1.rs");
+            }
+        }
+    }
+    pub use self::cloud::deploy::v1::*;
+}
+
+pub use self::client::*;
+pub use self::pipeline::*;
+pub use self::release::*;
+pub use self::rollout::*;
+
+/// The error type for the Cloud Deploy module.
+pub type Error = crate::error::Error;
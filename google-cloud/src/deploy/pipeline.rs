@@ -0,0 +1,114 @@
+use std::collections::HashMap;
+
+use chrono::NaiveDateTime;
+
+use crate::deploy::api;
+use crate::deploy::api::delivery_pipeline::Pipeline;
+use crate::types;
+
+/// Represents a stage of a delivery pipeline, deploying to a specific target.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stage {
+    pub(crate) target_id: String,
+    pub(crate) profiles: Vec<String>,
+}
+
+impl Stage {
+    /// The identifier of the target this stage deploys to.
+    pub fn target_id(&self) -> &str {
+        self.target_id.as_str()
+    }
+
+    /// The Skaffold profiles used to render the manifests of this stage.
+    pub fn profiles(&self) -> &[String] {
+        self.profiles.as_slice()
+    }
+}
+
+impl From<api::Stage> for Stage {
+    fn from(stage: api::Stage) -> Stage {
+        Stage {
+            target_id: stage.target_id,
+            profiles: stage.profiles,
+        }
+    }
+}
+
+/// Represents a Cloud Deploy delivery pipeline, through which releases get promoted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeliveryPipeline {
+    pub(crate) name: String,
+    pub(crate) description: String,
+    pub(crate) labels: HashMap<String, String>,
+    pub(crate) stages: Vec<Stage>,
+    pub(crate) suspended: bool,
+    pub(crate) create_time: Option<NaiveDateTime>,
+    pub(crate) update_time: Option<NaiveDateTime>,
+}
+
+impl DeliveryPipeline {
+    /// The full resource name of the pipeline.
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    /// Returns the unique identifier of the pipeline within its region.
+    pub fn id(&self) -> &str {
+        self.name.rsplit('/').next().unwrap()
+    }
+
+    /// The description of the pipeline.
+    pub fn description(&self) -> &str {
+        self.description.as_str()
+    }
+
+    /// The labels attached to the pipeline.
+    pub fn labels(&self) -> &HashMap<String, String> {
+        &self.labels
+    }
+
+    /// The stages of the pipeline, in promotion order.
+    pub fn stages(&self) -> &[Stage] {
+        self.stages.as_slice()
+    }
+
+    /// Whether the pipeline is suspended, preventing new releases and rollouts from being created.
+    pub fn suspended(&self) -> bool {
+        self.suspended
+    }
+
+    /// The creation time of the pipeline.
+    pub fn create_time(&self) -> Option<NaiveDateTime> {
+        self.create_time
+    }
+
+    /// The time of the last update of the pipeline.
+    pub fn update_time(&self) -> Option<NaiveDateTime> {
+        self.update_time
+    }
+}
+
+impl From<api::DeliveryPipeline> for DeliveryPipeline {
+    fn from(pipeline: api::DeliveryPipeline) -> DeliveryPipeline {
+        let stages = match pipeline.pipeline {
+            Some(Pipeline::SerialPipeline(serial)) => {
+                serial.stages.into_iter().map(Stage::from).collect()
+            }
+            None => Vec::new(),
+        };
+
+        DeliveryPipeline {
+            name: pipeline.name,
+            description: pipeline.description,
+            labels: pipeline.labels,
+            stages,
+            suspended: pipeline.suspended,
+            create_time: pipeline.create_time.and_then(convert_timestamp),
+            update_time: pipeline.update_time.and_then(convert_timestamp),
+        }
+    }
+}
+
+pub(crate) fn convert_timestamp(timestamp: prost_types::Timestamp) -> Option<NaiveDateTime> {
+    types::timestamp_to_naive(timestamp).ok()
+}
@@ -0,0 +1,254 @@
+use std::collections::HashMap;
+
+use chrono::NaiveDateTime;
+
+use crate::deploy::api;
+use crate::deploy::pipeline::convert_timestamp;
+
+/// Represents the state of the rendering of a release.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RenderState {
+    /// The manifests are being rendered.
+    InProgress,
+    /// The manifests of all the targets were rendered successfully.
+    Succeeded,
+    /// The rendering failed for one or more targets.
+    Failed,
+    /// The state of the rendering is unknown.
+    Unknown,
+}
+
+impl From<i32> for RenderState {
+    fn from(state: i32) -> RenderState {
+        use api::release::RenderState as State;
+        match State::from_i32(state) {
+            Some(State::InProgress) => RenderState::InProgress,
+            Some(State::Succeeded) => RenderState::Succeeded,
+            Some(State::Failed) => RenderState::Failed,
+            Some(State::Unspecified) | None => RenderState::Unknown,
+        }
+    }
+}
+
+/// Represents an image built outside of Cloud Deploy, substituted in the Skaffold configuration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BuildArtifact {
+    pub(crate) image: String,
+    pub(crate) tag: String,
+}
+
+impl BuildArtifact {
+    /// Create a new artifact, replacing the given image of the Skaffold configuration by a full image reference.
+    pub fn new(image: impl Into<String>, tag: impl Into<String>) -> BuildArtifact {
+        BuildArtifact {
+            image: image.into(),
+            tag: tag.into(),
+        }
+    }
+
+    /// The image name, as used in the Skaffold configuration.
+    pub fn image(&self) -> &str {
+        self.image.as_str()
+    }
+
+    /// The full reference of the image to deploy (such as `gcr.io/my-project/app:1.2.3`).
+    pub fn tag(&self) -> &str {
+        self.tag.as_str()
+    }
+}
+
+impl From<api::BuildArtifact> for BuildArtifact {
+    fn from(artifact: api::BuildArtifact) -> BuildArtifact {
+        BuildArtifact {
+            image: artifact.image,
+            tag: artifact.tag,
+        }
+    }
+}
+
+impl From<BuildArtifact> for api::BuildArtifact {
+    fn from(artifact: BuildArtifact) -> api::BuildArtifact {
+        api::BuildArtifact {
+            image: artifact.image,
+            tag: artifact.tag,
+        }
+    }
+}
+
+/// Represents a release, a specific version of a Skaffold configuration that can be rolled out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Release {
+    pub(crate) name: String,
+    pub(crate) description: String,
+    pub(crate) labels: HashMap<String, String>,
+    pub(crate) render_state: RenderState,
+    pub(crate) skaffold_config_uri: String,
+    pub(crate) skaffold_config_path: String,
+    pub(crate) build_artifacts: Vec<BuildArtifact>,
+    pub(crate) abandoned: bool,
+    pub(crate) create_time: Option<NaiveDateTime>,
+    pub(crate) render_end_time: Option<NaiveDateTime>,
+}
+
+impl Release {
+    /// The full resource name of the release.
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    /// Returns the unique identifier of the release within its pipeline.
+    pub fn id(&self) -> &str {
+        self.name.rsplit('/').next().unwrap()
+    }
+
+    /// Returns the full resource name of the pipeline of the release.
+    pub fn pipeline_name(&self) -> &str {
+        self.name.rsplitn(3, '/').nth(2).unwrap_or_default()
+    }
+
+    /// The description of the release.
+    pub fn description(&self) -> &str {
+        self.description.as_str()
+    }
+
+    /// The labels attached to the release.
+    pub fn labels(&self) -> &HashMap<String, String> {
+        &self.labels
+    }
+
+    /// The state of the rendering of the release's manifests.
+    pub fn render_state(&self) -> RenderState {
+        self.render_state
+    }
+
+    /// The Cloud Storage URI of the archive containing the Skaffold configuration.
+    pub fn skaffold_config_uri(&self) -> &str {
+        self.skaffold_config_uri.as_str()
+    }
+
+    /// The path of the Skaffold configuration file within the archive.
+    pub fn skaffold_config_path(&self) -> &str {
+        self.skaffold_config_path.as_str()
+    }
+
+    /// The images substituted in the Skaffold configuration.
+    pub fn build_artifacts(&self) -> &[BuildArtifact] {
+        self.build_artifacts.as_slice()
+    }
+
+    /// Whether the release was abandoned, preventing new rollouts from being created.
+    pub fn abandoned(&self) -> bool {
+        self.abandoned
+    }
+
+    /// The creation time of the release.
+    pub fn create_time(&self) -> Option<NaiveDateTime> {
+        self.create_time
+    }
+
+    /// The time at which the rendering of the release completed.
+    pub fn render_end_time(&self) -> Option<NaiveDateTime> {
+        self.render_end_time
+    }
+}
+
+impl From<api::Release> for Release {
+    fn from(release: api::Release) -> Release {
+        Release {
+            name: release.name,
+            description: release.description,
+            labels: release.labels,
+            render_state: RenderState::from(release.render_state),
+            skaffold_config_uri: release.skaffold_config_uri,
+            skaffold_config_path: release.skaffold_config_path,
+            build_artifacts: release
+                .build_artifacts
+                .into_iter()
+                .map(BuildArtifact::from)
+                .collect(),
+            abandoned: release.abandoned,
+            create_time: release.create_time.and_then(convert_timestamp),
+            render_end_time: release.render_end_time.and_then(convert_timestamp),
+        }
+    }
+}
+
+/// Represents the configuration of a new release.
+///
+/// ```
+/// # use google_cloud::deploy::ReleaseConfig;
+/// let config = ReleaseConfig::new("gs://my-bucket/source.tgz")
+///     .description("Release 1.2.3")
+///     .build_artifact("app", "gcr.io/my-project/app:1.2.3");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReleaseConfig {
+    pub(crate) skaffold_config_uri: String,
+    pub(crate) skaffold_config_path: Option<String>,
+    pub(crate) skaffold_version: Option<String>,
+    pub(crate) description: Option<String>,
+    pub(crate) labels: HashMap<String, String>,
+    pub(crate) build_artifacts: Vec<BuildArtifact>,
+}
+
+impl ReleaseConfig {
+    /// Create a new release configuration, from a Skaffold configuration archive stored in Cloud Storage.
+    pub fn new(skaffold_config_uri: impl Into<String>) -> ReleaseConfig {
+        ReleaseConfig {
+            skaffold_config_uri: skaffold_config_uri.into(),
+            ..ReleaseConfig::default()
+        }
+    }
+
+    /// Set the path of the Skaffold configuration file within the archive (`skaffold.yaml` by default).
+    pub fn skaffold_config_path(mut self, path: impl Into<String>) -> ReleaseConfig {
+        self.skaffold_config_path = Some(path.into());
+        self
+    }
+
+    /// Set the version of Skaffold used to render and deploy the release.
+    pub fn skaffold_version(mut self, version: impl Into<String>) -> ReleaseConfig {
+        self.skaffold_version = Some(version.into());
+        self
+    }
+
+    /// Set the description of the release.
+    pub fn description(mut self, description: impl Into<String>) -> ReleaseConfig {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Attach a label to the release.
+    pub fn label(mut self, key: impl Into<String>, value: impl Into<String>) -> ReleaseConfig {
+        self.labels.insert(key.into(), value.into());
+        self
+    }
+
+    /// Substitute an image of the Skaffold configuration by the given full image reference.
+    pub fn build_artifact(
+        mut self,
+        image: impl Into<String>,
+        tag: impl Into<String>,
+    ) -> ReleaseConfig {
+        self.build_artifacts.push(BuildArtifact::new(image, tag));
+        self
+    }
+}
+
+impl From<ReleaseConfig> for api::Release {
+    fn from(config: ReleaseConfig) -> api::Release {
+        api::Release {
+            skaffold_config_uri: config.skaffold_config_uri,
+            skaffold_config_path: config.skaffold_config_path.unwrap_or_default(),
+            skaffold_version: config.skaffold_version.unwrap_or_default(),
+            description: config.description.unwrap_or_default(),
+            labels: config.labels,
+            build_artifacts: config
+                .build_artifacts
+                .into_iter()
+                .map(api::BuildArtifact::from)
+                .collect(),
+            ..api::Release::default()
+        }
+    }
+}
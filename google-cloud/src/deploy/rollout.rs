@@ -0,0 +1,249 @@
+use chrono::NaiveDateTime;
+
+use crate::deploy::api;
+use crate::deploy::pipeline::convert_timestamp;
+
+/// Represents the state of a rollout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RolloutState {
+    /// The rollout waits for the rendering of its release to complete.
+    PendingRelease,
+    /// The rollout waits for an approval.
+    PendingApproval,
+    /// The rollout waits for earlier rollouts to the same target to complete.
+    Pending,
+    /// The rollout is being deployed.
+    InProgress,
+    /// The rollout was deployed successfully.
+    Succeeded,
+    /// The rollout failed.
+    Failed,
+    /// An approver rejected the rollout.
+    ApprovalRejected,
+    /// The rollout is being cancelled.
+    Cancelling,
+    /// The rollout was cancelled.
+    Cancelled,
+    /// The rollout is halted.
+    Halted,
+    /// The state of the rollout is unknown.
+    Unknown,
+}
+
+impl RolloutState {
+    /// Whether the rollout has reached a final state, and will not progress anymore.
+    pub fn is_finished(self) -> bool {
+        matches!(
+            self,
+            RolloutState::Succeeded
+                | RolloutState::Failed
+                | RolloutState::ApprovalRejected
+                | RolloutState::Cancelled
+        )
+    }
+}
+
+impl From<i32> for RolloutState {
+    fn from(state: i32) -> RolloutState {
+        use api::rollout::State;
+        match State::from_i32(state) {
+            Some(State::PendingRelease) => RolloutState::PendingRelease,
+            Some(State::PendingApproval) => RolloutState::PendingApproval,
+            Some(State::Pending) => RolloutState::Pending,
+            Some(State::InProgress) => RolloutState::InProgress,
+            Some(State::Succeeded) => RolloutState::Succeeded,
+            Some(State::Failed) => RolloutState::Failed,
+            Some(State::ApprovalRejected) => RolloutState::ApprovalRejected,
+            Some(State::Cancelling) => RolloutState::Cancelling,
+            Some(State::Cancelled) => RolloutState::Cancelled,
+            Some(State::Halted) => RolloutState::Halted,
+            Some(State::Unspecified) | None => RolloutState::Unknown,
+        }
+    }
+}
+
+/// Represents the approval state of a rollout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ApprovalState {
+    /// The rollout requires an approval before being deployed.
+    NeedsApproval,
+    /// The rollout does not require any approval.
+    DoesNotNeedApproval,
+    /// The rollout was approved.
+    Approved,
+    /// The rollout was rejected.
+    Rejected,
+    /// The approval state of the rollout is unknown.
+    Unknown,
+}
+
+impl From<i32> for ApprovalState {
+    fn from(state: i32) -> ApprovalState {
+        use api::rollout::ApprovalState as State;
+        match State::from_i32(state) {
+            Some(State::NeedsApproval) => ApprovalState::NeedsApproval,
+            Some(State::DoesNotNeedApproval) => ApprovalState::DoesNotNeedApproval,
+            Some(State::Approved) => ApprovalState::Approved,
+            Some(State::Rejected) => ApprovalState::Rejected,
+            Some(State::Unspecified) | None => ApprovalState::Unknown,
+        }
+    }
+}
+
+/// Represents the state of a phase of a rollout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PhaseState {
+    /// The phase waits for earlier phases to complete.
+    Pending,
+    /// The phase is in progress.
+    InProgress,
+    /// The phase succeeded.
+    Succeeded,
+    /// The phase failed.
+    Failed,
+    /// The phase was aborted.
+    Aborted,
+    /// The phase was skipped.
+    Skipped,
+    /// The state of the phase is unknown.
+    Unknown,
+}
+
+impl From<i32> for PhaseState {
+    fn from(state: i32) -> PhaseState {
+        use api::phase::State;
+        match State::from_i32(state) {
+            Some(State::Pending) => PhaseState::Pending,
+            Some(State::InProgress) => PhaseState::InProgress,
+            Some(State::Succeeded) => PhaseState::Succeeded,
+            Some(State::Failed) => PhaseState::Failed,
+            Some(State::Aborted) => PhaseState::Aborted,
+            Some(State::Skipped) => PhaseState::Skipped,
+            Some(State::Unspecified) | None => PhaseState::Unknown,
+        }
+    }
+}
+
+/// Represents a phase of a rollout (such as a step of a canary deployment).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Phase {
+    pub(crate) id: String,
+    pub(crate) state: PhaseState,
+    pub(crate) skip_message: String,
+}
+
+impl Phase {
+    /// The identifier of the phase, to pass to `advance_rollout`.
+    pub fn id(&self) -> &str {
+        self.id.as_str()
+    }
+
+    /// The state of the phase.
+    pub fn state(&self) -> PhaseState {
+        self.state
+    }
+
+    /// The reason why the phase was skipped, if any.
+    pub fn skip_message(&self) -> &str {
+        self.skip_message.as_str()
+    }
+}
+
+impl From<api::Phase> for Phase {
+    fn from(phase: api::Phase) -> Phase {
+        Phase {
+            id: phase.id,
+            state: PhaseState::from(phase.state),
+            skip_message: phase.skip_message,
+        }
+    }
+}
+
+/// Represents a rollout, the deployment of a release to a specific target.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rollout {
+    pub(crate) name: String,
+    pub(crate) target_id: String,
+    pub(crate) state: RolloutState,
+    pub(crate) approval_state: ApprovalState,
+    pub(crate) failure_reason: String,
+    pub(crate) deploying_build: String,
+    pub(crate) phases: Vec<Phase>,
+    pub(crate) create_time: Option<NaiveDateTime>,
+    pub(crate) deploy_start_time: Option<NaiveDateTime>,
+    pub(crate) deploy_end_time: Option<NaiveDateTime>,
+}
+
+impl Rollout {
+    /// The full resource name of the rollout.
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    /// Returns the unique identifier of the rollout within its release.
+    pub fn id(&self) -> &str {
+        self.name.rsplit('/').next().unwrap()
+    }
+
+    /// The identifier of the target the rollout deploys to.
+    pub fn target_id(&self) -> &str {
+        self.target_id.as_str()
+    }
+
+    /// The state of the rollout.
+    pub fn state(&self) -> RolloutState {
+        self.state
+    }
+
+    /// The approval state of the rollout.
+    pub fn approval_state(&self) -> ApprovalState {
+        self.approval_state
+    }
+
+    /// The reason of the failure of the rollout, if available.
+    pub fn failure_reason(&self) -> &str {
+        self.failure_reason.as_str()
+    }
+
+    /// The resource name of the Cloud Build build deploying the rollout.
+    pub fn deploying_build(&self) -> &str {
+        self.deploying_build.as_str()
+    }
+
+    /// The phases of the rollout.
+    pub fn phases(&self) -> &[Phase] {
+        self.phases.as_slice()
+    }
+
+    /// The creation time of the rollout.
+    pub fn create_time(&self) -> Option<NaiveDateTime> {
+        self.create_time
+    }
+
+    /// The time at which the deployment of the rollout started.
+    pub fn deploy_start_time(&self) -> Option<NaiveDateTime> {
+        self.deploy_start_time
+    }
+
+    /// The time at which the deployment of the rollout finished.
+    pub fn deploy_end_time(&self) -> Option<NaiveDateTime> {
+        self.deploy_end_time
+    }
+}
+
+impl From<api::Rollout> for Rollout {
+    fn from(rollout: api::Rollout) -> Rollout {
+        Rollout {
+            name: rollout.name,
+            target_id: rollout.target_id,
+            state: RolloutState::from(rollout.state),
+            approval_state: ApprovalState::from(rollout.approval_state),
+            failure_reason: rollout.failure_reason,
+            deploying_build: rollout.deploying_build,
+            phases: rollout.phases.into_iter().map(Phase::from).collect(),
+            create_time: rollout.create_time.and_then(convert_timestamp),
+            deploy_start_time: rollout.deploy_start_time.and_then(convert_timestamp),
+            deploy_end_time: rollout.deploy_end_time.and_then(convert_timestamp),
+        }
+    }
+}
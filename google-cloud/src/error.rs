@@ -1,4 +1,5 @@
 use std::env;
+use std::fmt;
 use std::io;
 
 use thiserror::Error;
@@ -18,19 +19,136 @@ pub enum Error {
     /// A JSON (de)serialization error.
     #[error("JSON error: {0}")]
     JSON(#[from] json::Error),
+    /// An invalid endpoint URL.
+    #[error("invalid endpoint: {0}")]
+    Endpoint(String),
     /// An environment-related error (missing variable).
     #[error("environment error: {0}")]
     Env(#[from] env::VarError),
     /// Reqwest error (HTTP errors).
-    #[cfg(feature = "storage")]
+    #[cfg(any(feature = "storage", feature = "cloudbuild"))]
     #[error("HTTP error: {0}")]
     Reqwest(#[from] reqwest::Error),
+    /// A resumable upload could not proceed.
+    #[cfg(feature = "storage")]
+    #[error("upload error: {0}")]
+    Upload(String),
+    /// A malformed Firestore bundle or document.
+    #[cfg(feature = "firestore")]
+    #[error("invalid Firestore data: {0}")]
+    Bundle(String),
+    /// An invalid or incomplete configuration profile.
+    #[cfg(feature = "config")]
+    #[error("configuration error: {0}")]
+    Config(String),
+    /// A message (de)serialization error.
+    #[cfg(feature = "pubsub")]
+    #[error("codec error: {0}")]
+    Codec(#[from] crate::pubsub::CodecError),
     /// conversion error (`try_from(..)` or `try_into(..)` errors).
     #[error("conversion error: {0}")]
     Convert(#[from] ConvertError),
     /// authentication-related error.
     #[error("authentication error: {0}")]
     Auth(#[from] AuthError),
+    /// An error that occurred while operating on a specific resource.
+    #[error("{context} failed: {source}")]
+    Context {
+        /// The operation and the resource it was operating on.
+        context: ErrorContext,
+        /// The underlying error.
+        source: Box<Error>,
+    },
+}
+
+impl Error {
+    /// The status returned by the service, if the (underlying) error is one.
+    pub fn status(&self) -> Option<&tonic::Status> {
+        match self.root() {
+            Error::Status(status) => Some(status),
+            _ => None,
+        }
+    }
+
+    /// The operation and resource the error occurred with, if known.
+    pub fn context(&self) -> Option<&ErrorContext> {
+        match self {
+            Error::Context { context, .. } => Some(context),
+            _ => None,
+        }
+    }
+
+    /// The underlying error, stripped of its context.
+    pub fn root(&self) -> &Error {
+        match self {
+            Error::Context { source, .. } => source.root(),
+            err => err,
+        }
+    }
+}
+
+/// Describes the operation and the resource an error occurred with (see `Error::Context`).
+///
+/// ```
+/// # use google_cloud::error::Error;
+/// # fn report(err: Error) {
+/// match err.context() {
+///     Some(context) => eprintln!("{} on {} failed", context.operation(), context.resource()),
+///     None => eprintln!("failed: {}", err),
+/// }
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ErrorContext {
+    pub(crate) operation: &'static str,
+    pub(crate) resource: String,
+}
+
+impl ErrorContext {
+    #[cfg(any(feature = "datastore", feature = "pubsub", feature = "storage"))]
+    pub(crate) fn new(operation: &'static str, resource: impl fmt::Display) -> ErrorContext {
+        ErrorContext {
+            operation,
+            resource: resource.to_string(),
+        }
+    }
+
+    /// The name of the operation (like `Lookup` or `Publish`).
+    pub fn operation(&self) -> &str {
+        self.operation
+    }
+
+    /// The description of the resource being operated on (like a key path, a topic name or an object name).
+    pub fn resource(&self) -> &str {
+        self.resource.as_str()
+    }
+}
+
+impl fmt::Display for ErrorContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} on {}", self.operation, self.resource)
+    }
+}
+
+/// Attaches the operation and the resource being operated on to the errors of results.
+#[cfg(any(feature = "datastore", feature = "pubsub", feature = "storage"))]
+#[allow(clippy::result_large_err)]
+pub(crate) trait WithContext<T> {
+    /// Wraps an error with its context (the resource only gets formatted if there is an error).
+    fn context(self, operation: &'static str, resource: impl fmt::Display) -> Result<T, Error>;
+}
+
+#[cfg(any(feature = "datastore", feature = "pubsub", feature = "storage"))]
+impl<T, E> WithContext<T> for Result<T, E>
+where
+    E: Into<Error>,
+{
+    fn context(self, operation: &'static str, resource: impl fmt::Display) -> Result<T, Error> {
+        self.map_err(|err| Error::Context {
+            context: ErrorContext::new(operation, resource),
+            source: Box::new(err.into()),
+        })
+    }
 }
 
 /// The error type for value conversions.
@@ -47,6 +165,95 @@ pub enum ConvertError {
         /// The name of the actual encountered type.
         got: String,
     },
+    /// A field, expected to be always present in API values, was missing.
+    #[error("expected field `{0}` was missing from the API value")]
+    MissingField(String),
+    /// A value of a type that is not supported by this crate was encountered.
+    #[error("unsupported value type `{0}`")]
+    UnsupportedValue(String),
+    /// A timestamp was out of the supported range.
+    #[error("invalid timestamp (seconds: {seconds}, nanos: {nanos})")]
+    InvalidTimestamp {
+        /// The seconds part of the timestamp.
+        seconds: i64,
+        /// The nanoseconds part of the timestamp.
+        nanos: i32,
+    },
+    /// A duration was out of the supported range (such as a negative one, where a positive one is expected).
+    #[error("invalid duration (seconds: {seconds}, nanos: {nanos})")]
+    InvalidDuration {
+        /// The seconds part of the duration.
+        seconds: i64,
+        /// The nanoseconds part of the duration.
+        nanos: i32,
+    },
+    /// An array value directly contained another array value.
+    #[error("arrays cannot directly contain other arrays")]
+    NestedArray,
+    /// A blob value exceeded the maximum allowed size.
+    #[error("blob of {size} bytes exceeds the maximum size of {max} bytes")]
+    BlobTooLarge {
+        /// The size of the blob, in bytes.
+        size: usize,
+        /// The maximum allowed size (which depends on whether the blob is indexed), in bytes.
+        max: usize,
+    },
+    /// A number could not be converted to the expected type without losing information.
+    #[error("number `{value}` cannot be converted to `{expected}` without loss")]
+    LossyNumber {
+        /// The number, as formatted in the original value.
+        value: String,
+        /// The name of the expected type.
+        expected: String,
+    },
+    /// A string value, expected to hold a decimal number, turned out to not hold one.
+    #[error("invalid decimal number `{0}`")]
+    InvalidDecimal(String),
+    /// A string value, expected to hold a UUID, turned out to not hold one.
+    #[error("invalid UUID `{0}`")]
+    InvalidUuid(String),
+    /// A string value, expected to hold a date (or a date and time), turned out to not hold one.
+    #[error("invalid date `{0}`")]
+    InvalidDate(String),
+    /// A query cursor could not be parsed from its string form.
+    #[error("invalid query cursor `{0}`")]
+    InvalidCursor(String),
+    /// A key could not be decoded from its URL-safe string form.
+    #[error("invalid URL-safe key `{0}`")]
+    InvalidKey(String),
+    /// An entity could not be parsed from its JSON form (see `Entity::from_json`).
+    #[error("invalid JSON entity: {0}")]
+    InvalidJson(String),
+    /// A key path was rejected by Datastore's constraints (see `Key::validate`).
+    #[error("invalid key path `{key}`: {reason}")]
+    InvalidKeyPath {
+        /// The key, as formatted in error messages.
+        key: String,
+        /// The constraint it breaks.
+        reason: String,
+    },
+    /// A query filter was rejected by Datastore's constraints (such as the number of values of an `IN` filter).
+    #[error("invalid filter on `{property}`: {reason}")]
+    InvalidFilter {
+        /// The name of the filtered property.
+        property: String,
+        /// The constraint it breaks.
+        reason: String,
+    },
+    /// A geographic location was out of range (see `types::LatLng`).
+    #[error("invalid location (latitude: {latitude}, longitude: {longitude})")]
+    InvalidLatLng {
+        /// The latitude, in degrees.
+        latitude: f64,
+        /// The longitude, in degrees.
+        longitude: f64,
+    },
+    /// A color, or one of its components, was invalid (see `types::Color`).
+    #[error("invalid color `{0}`")]
+    InvalidColor(String),
+    /// An unknown enum variant name was encountered.
+    #[error("unknown enum variant `{0}`")]
+    UnknownVariant(String),
 }
 
 /// The error type for authentication-related errors.
@@ -64,4 +271,14 @@ pub enum AuthError {
     /// Hyper errors
     #[error("Hyper error: {0}")]
     Hyper(#[from] hyper::Error),
+    /// The token endpoint rejected the credentials.
+    #[error("token request rejected with status {status}: {error} ({description})")]
+    Rejected {
+        /// The HTTP status code of the response.
+        status: u16,
+        /// The OAuth error code (like `invalid_grant`).
+        error: String,
+        /// The explanation of the error, as given by the token endpoint.
+        description: String,
+    },
 }
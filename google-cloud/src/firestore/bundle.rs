@@ -0,0 +1,374 @@
+use std::collections::HashMap;
+use std::io::Read;
+
+use chrono::NaiveDateTime;
+
+use crate::firestore::document::{invalid, parse_integer, parse_string, parse_timestamp};
+use crate::firestore::{Document, Error};
+
+/// Represents the metadata of a bundle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BundleMetadata {
+    pub(crate) id: String,
+    pub(crate) create_time: Option<NaiveDateTime>,
+    pub(crate) version: u32,
+    pub(crate) total_documents: u32,
+    pub(crate) total_bytes: u64,
+}
+
+impl BundleMetadata {
+    /// The ID of the bundle.
+    pub fn id(&self) -> &str {
+        self.id.as_str()
+    }
+
+    /// The time at which the bundle was created.
+    pub fn create_time(&self) -> Option<NaiveDateTime> {
+        self.create_time
+    }
+
+    /// The version of the bundle format.
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// The number of documents in the bundle, as announced by its metadata.
+    pub fn total_documents(&self) -> u32 {
+        self.total_documents
+    }
+
+    /// The size of the bundle in bytes (excluding the metadata), as announced by its metadata.
+    pub fn total_bytes(&self) -> u64 {
+        self.total_bytes
+    }
+}
+
+/// Represents a query saved in a bundle.
+///
+/// The query itself is kept as raw JSON, so that it can be replayed later against a live database.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NamedQuery {
+    pub(crate) name: String,
+    pub(crate) parent: String,
+    pub(crate) structured_query: json::Value,
+    pub(crate) limit_type: Option<String>,
+    pub(crate) read_time: Option<NaiveDateTime>,
+}
+
+impl NamedQuery {
+    /// The name of the query.
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    /// The parent resource name the query runs against.
+    pub fn parent(&self) -> &str {
+        self.parent.as_str()
+    }
+
+    /// The structured query, in its JSON representation.
+    pub fn structured_query(&self) -> &json::Value {
+        &self.structured_query
+    }
+
+    /// The limit type of the query (`FIRST` or `LAST`), if any.
+    pub fn limit_type(&self) -> Option<&str> {
+        self.limit_type.as_deref()
+    }
+
+    /// The time at which the results of the query were read.
+    pub fn read_time(&self) -> Option<NaiveDateTime> {
+        self.read_time
+    }
+}
+
+/// Represents a document stored in a bundle, along with its metadata.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BundledDocument {
+    pub(crate) name: String,
+    pub(crate) read_time: Option<NaiveDateTime>,
+    pub(crate) queries: Vec<String>,
+    pub(crate) document: Option<Document>,
+}
+
+impl BundledDocument {
+    /// The full resource name of the document.
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    /// The time at which the document was read.
+    pub fn read_time(&self) -> Option<NaiveDateTime> {
+        self.read_time
+    }
+
+    /// The names of the bundled queries whose results include this document.
+    pub fn queries(&self) -> &[String] {
+        self.queries.as_slice()
+    }
+
+    /// Whether the document existed at read time.
+    pub fn exists(&self) -> bool {
+        self.document.is_some()
+    }
+
+    /// The document, if it existed at read time.
+    pub fn document(&self) -> Option<&Document> {
+        self.document.as_ref()
+    }
+
+    /// Move the document out, if it existed at read time.
+    pub fn into_document(self) -> Option<Document> {
+        self.document
+    }
+}
+
+/// Represents a loaded Firestore data bundle.
+///
+/// Bundles are static snapshots of documents and query results, generated by the server SDKs
+/// and typically served from Cloud Storage or a CDN, that clients can use to bootstrap their data.
+///
+/// ```
+/// # use google_cloud::firestore::Bundle;
+/// let metadata = r#"{"metadata":{"id":"my-bundle","version":1,"totalDocuments":1}}"#;
+/// let document_metadata = r#"{"documentMetadata":{"name":"projects/p/databases/(default)/documents/users/alice","exists":true}}"#;
+/// let document = r#"{"document":{"name":"projects/p/databases/(default)/documents/users/alice","fields":{"age":{"integerValue":"42"}}}}"#;
+/// let data = format!(
+///     "{}{}{}{}{}{}",
+///     metadata.len(), metadata,
+///     document_metadata.len(), document_metadata,
+///     document.len(), document,
+/// );
+///
+/// let bundle = Bundle::from_slice(data.as_bytes()).unwrap();
+/// assert_eq!(bundle.metadata().id(), "my-bundle");
+/// let alice = bundle.document("projects/p/databases/(default)/documents/users/alice").unwrap();
+/// assert_eq!(alice.id(), "alice");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bundle {
+    pub(crate) metadata: BundleMetadata,
+    pub(crate) named_queries: HashMap<String, NamedQuery>,
+    pub(crate) documents: Vec<BundledDocument>,
+}
+
+impl Bundle {
+    /// Loads a bundle from a reader.
+    pub fn from_reader(mut reader: impl Read) -> Result<Bundle, Error> {
+        let mut data = Vec::new();
+        reader.read_to_end(&mut data)?;
+        Bundle::from_slice(data.as_slice())
+    }
+
+    /// Loads a bundle from its raw bytes.
+    ///
+    /// A bundle is a sequence of JSON elements, each prefixed by its length in bytes.
+    /// The first element must be the bundle's metadata.
+    pub fn from_slice(mut data: &[u8]) -> Result<Bundle, Error> {
+        let mut metadata = None;
+        let mut named_queries = HashMap::new();
+        let mut documents: Vec<BundledDocument> = Vec::new();
+
+        while !data.is_empty() {
+            let (element, rest) = next_element(data)?;
+            data = rest;
+
+            let mut element = match element {
+                json::Value::Object(element) if element.len() == 1 => element,
+                _ => return Err(invalid("elements must be objects with a single field")),
+            };
+            let (kind, value) = element.iter_mut().next().unwrap();
+            let value = value.take();
+
+            match (kind.as_str(), metadata.is_some()) {
+                ("metadata", false) => metadata = Some(parse_metadata(value)?),
+                ("metadata", true) => return Err(invalid("duplicate bundle metadata")),
+                (_, false) => return Err(invalid("the first element must be the bundle metadata")),
+                ("namedQuery", true) => {
+                    let query = parse_named_query(value)?;
+                    named_queries.insert(query.name.clone(), query);
+                }
+                ("documentMetadata", true) => documents.push(parse_document_metadata(value)?),
+                ("document", true) => {
+                    let document = Document::from_json(value)?;
+                    match documents.last_mut() {
+                        Some(last) if last.name == document.name && last.document.is_none() => {
+                            last.document = Some(document);
+                        }
+                        _ => return Err(invalid("document not preceded by its metadata")),
+                    }
+                }
+                (kind, true) => {
+                    return Err(invalid(format!("unknown bundle element `{}`", kind)));
+                }
+            }
+        }
+
+        let metadata = metadata.ok_or_else(|| invalid("empty bundle"))?;
+
+        Ok(Bundle {
+            metadata,
+            named_queries,
+            documents,
+        })
+    }
+
+    /// The bundle's metadata.
+    pub fn metadata(&self) -> &BundleMetadata {
+        &self.metadata
+    }
+
+    /// The queries saved in the bundle, by name.
+    pub fn named_queries(&self) -> &HashMap<String, NamedQuery> {
+        &self.named_queries
+    }
+
+    /// Get a saved query by name.
+    pub fn named_query(&self, name: &str) -> Option<&NamedQuery> {
+        self.named_queries.get(name)
+    }
+
+    /// The documents of the bundle, in bundle order.
+    pub fn documents(&self) -> &[BundledDocument] {
+        self.documents.as_slice()
+    }
+
+    /// Get an existing document of the bundle by its full resource name.
+    pub fn document(&self, name: &str) -> Option<&Document> {
+        self.documents
+            .iter()
+            .find(|document| document.name == name)
+            .and_then(BundledDocument::document)
+    }
+
+    /// Get the existing documents returned by a saved query.
+    pub fn query_documents<'a>(&'a self, query: &'a str) -> impl Iterator<Item = &'a Document> {
+        self.documents
+            .iter()
+            .filter(move |document| document.queries.iter().any(|name| name == query))
+            .filter_map(BundledDocument::document)
+    }
+
+    /// Move the documents out.
+    pub fn into_documents(self) -> Vec<BundledDocument> {
+        self.documents
+    }
+}
+
+/// Splits the next length-prefixed element off the data.
+fn next_element(data: &[u8]) -> Result<(json::Value, &[u8]), Error> {
+    let digits = data.iter().take_while(|byte| byte.is_ascii_digit()).count();
+    if digits == 0 {
+        return Err(invalid("missing element length"));
+    }
+    let (length, rest) = data.split_at(digits);
+    let length: usize = std::str::from_utf8(length)
+        .ok()
+        .and_then(|length| length.parse().ok())
+        .ok_or_else(|| invalid("invalid element length"))?;
+    if rest.len() < length {
+        return Err(invalid("truncated element"));
+    }
+    let (element, rest) = rest.split_at(length);
+    let element = json::from_slice(element)?;
+
+    Ok((element, rest))
+}
+
+fn into_object(value: json::Value, what: &str) -> Result<json::Map<String, json::Value>, Error> {
+    match value {
+        json::Value::Object(object) => Ok(object),
+        _ => Err(invalid(format!("{} must be an object", what))),
+    }
+}
+
+fn parse_metadata(value: json::Value) -> Result<BundleMetadata, Error> {
+    let mut object = into_object(value, "metadata")?;
+    let id = object
+        .remove("id")
+        .map(parse_string)
+        .transpose()?
+        .unwrap_or_default();
+    let create_time = object.get("createTime").map(parse_timestamp).transpose()?;
+    let version = object
+        .get("version")
+        .map(parse_integer)
+        .transpose()?
+        .unwrap_or(0);
+    let total_documents = object
+        .get("totalDocuments")
+        .map(parse_integer)
+        .transpose()?
+        .unwrap_or(0);
+    let total_bytes = object
+        .get("totalBytes")
+        .map(parse_integer)
+        .transpose()?
+        .unwrap_or(0);
+
+    Ok(BundleMetadata {
+        id,
+        create_time,
+        version: version as u32,
+        total_documents: total_documents as u32,
+        total_bytes: total_bytes as u64,
+    })
+}
+
+fn parse_named_query(value: json::Value) -> Result<NamedQuery, Error> {
+    let mut object = into_object(value, "named query")?;
+    let name = object
+        .remove("name")
+        .map(parse_string)
+        .transpose()?
+        .ok_or_else(|| invalid("missing named query name"))?;
+    let read_time = object.get("readTime").map(parse_timestamp).transpose()?;
+    let mut bundled_query = object
+        .remove("bundledQuery")
+        .map(|value| into_object(value, "bundled query"))
+        .transpose()?
+        .unwrap_or_default();
+    let parent = bundled_query
+        .remove("parent")
+        .map(parse_string)
+        .transpose()?
+        .unwrap_or_default();
+    let structured_query = bundled_query.remove("structuredQuery").unwrap_or_default();
+    let limit_type = bundled_query
+        .remove("limitType")
+        .map(parse_string)
+        .transpose()?;
+
+    Ok(NamedQuery {
+        name,
+        parent,
+        structured_query,
+        limit_type,
+        read_time,
+    })
+}
+
+fn parse_document_metadata(value: json::Value) -> Result<BundledDocument, Error> {
+    let mut object = into_object(value, "document metadata")?;
+    let name = object
+        .remove("name")
+        .map(parse_string)
+        .transpose()?
+        .ok_or_else(|| invalid("missing document metadata name"))?;
+    let read_time = object.get("readTime").map(parse_timestamp).transpose()?;
+    let queries = match object.remove("queries") {
+        None => Vec::new(),
+        Some(json::Value::Array(queries)) => queries
+            .into_iter()
+            .map(parse_string)
+            .collect::<Result<_, _>>()?,
+        Some(_) => return Err(invalid("document queries must be an array")),
+    };
+
+    Ok(BundledDocument {
+        name,
+        read_time,
+        queries,
+        document: None,
+    })
+}
@@ -0,0 +1,256 @@
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+use chrono::{DateTime, NaiveDateTime};
+
+use crate::firestore::Error;
+
+/// A value, as stored in Firestore.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    /// A null value.
+    NullValue,
+    /// A boolean value (true or false).
+    BooleanValue(bool),
+    /// An integer value.
+    IntegerValue(i64),
+    /// A floating-point value.
+    DoubleValue(f64),
+    /// A timestamp value.
+    TimestampValue(NaiveDateTime),
+    /// A string value.
+    StringValue(String),
+    /// A bytes value.
+    BytesValue(Vec<u8>),
+    /// A reference to a document (its full resource name).
+    ReferenceValue(String),
+    /// An Earth geographic location value (with latitude and longitude).
+    GeoPointValue(f64, f64),
+    /// An array of values.
+    ArrayValue(Vec<Value>),
+    /// A map of values.
+    MapValue(HashMap<String, Value>),
+}
+
+impl Value {
+    /// Gets the static name of the type of the value.
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Value::NullValue => "null",
+            Value::BooleanValue(_) => "bool",
+            Value::IntegerValue(_) => "integer",
+            Value::DoubleValue(_) => "double",
+            Value::TimestampValue(_) => "timestamp",
+            Value::StringValue(_) => "string",
+            Value::BytesValue(_) => "bytes",
+            Value::ReferenceValue(_) => "reference",
+            Value::GeoPointValue(_, _) => "geopoint",
+            Value::ArrayValue(_) => "array",
+            Value::MapValue(_) => "map",
+        }
+    }
+
+    /// Parses a value from its JSON representation (as found in bundles or returned by the REST API).
+    pub(crate) fn from_json(value: json::Value) -> Result<Value, Error> {
+        let mut object = match value {
+            json::Value::Object(object) if object.len() == 1 => object,
+            _ => return Err(invalid("values must be objects with a single field")),
+        };
+        let (kind, value) = object.iter_mut().next().unwrap();
+        let value = value.take();
+        let value = match kind.as_str() {
+            "nullValue" => Value::NullValue,
+            "booleanValue" => Value::BooleanValue(
+                value
+                    .as_bool()
+                    .ok_or_else(|| invalid("invalid boolean value"))?,
+            ),
+            "integerValue" => Value::IntegerValue(parse_integer(&value)?),
+            "doubleValue" => Value::DoubleValue(parse_double(&value)?),
+            "timestampValue" => Value::TimestampValue(parse_timestamp(&value)?),
+            "stringValue" => Value::StringValue(parse_string(value)?),
+            "bytesValue" => {
+                let encoded = parse_string(value)?;
+                let decoded =
+                    base64::decode(encoded).map_err(|_| invalid("invalid base64 bytes value"))?;
+                Value::BytesValue(decoded)
+            }
+            "referenceValue" => Value::ReferenceValue(parse_string(value)?),
+            "geoPointValue" => {
+                let latitude = value.get("latitude").map(parse_double).transpose()?;
+                let longitude = value.get("longitude").map(parse_double).transpose()?;
+                Value::GeoPointValue(latitude.unwrap_or(0.0), longitude.unwrap_or(0.0))
+            }
+            "arrayValue" => {
+                let values = match value {
+                    json::Value::Object(mut object) => object.remove("values"),
+                    _ => return Err(invalid("invalid array value")),
+                };
+                let values = match values {
+                    Some(json::Value::Array(values)) => values,
+                    None => Vec::new(),
+                    Some(_) => return Err(invalid("invalid array value")),
+                };
+                let values = values
+                    .into_iter()
+                    .map(Value::from_json)
+                    .collect::<Result<_, _>>()?;
+                Value::ArrayValue(values)
+            }
+            "mapValue" => {
+                let fields = match value {
+                    json::Value::Object(mut object) => object.remove("fields"),
+                    _ => return Err(invalid("invalid map value")),
+                };
+                Value::MapValue(parse_fields(fields)?)
+            }
+            kind => return Err(invalid(format!("unknown value type `{}`", kind))),
+        };
+
+        Ok(value)
+    }
+}
+
+/// Represents a Firestore document.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Document {
+    pub(crate) name: String,
+    pub(crate) fields: HashMap<String, Value>,
+    pub(crate) create_time: Option<NaiveDateTime>,
+    pub(crate) update_time: Option<NaiveDateTime>,
+}
+
+impl Document {
+    /// The full resource name of the document.
+    ///
+    /// For example: `projects/{project_id}/databases/{database_id}/documents/{document_path}`.
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    /// The document's ID (the last segment of its path).
+    pub fn id(&self) -> &str {
+        self.name.rsplit('/').next().unwrap()
+    }
+
+    /// The document's fields.
+    pub fn fields(&self) -> &HashMap<String, Value> {
+        &self.fields
+    }
+
+    /// Get a single field of the document.
+    pub fn field(&self, name: &str) -> Option<&Value> {
+        self.fields.get(name)
+    }
+
+    /// Move the document's fields out.
+    pub fn into_fields(self) -> HashMap<String, Value> {
+        self.fields
+    }
+
+    /// The time at which the document was created.
+    pub fn create_time(&self) -> Option<NaiveDateTime> {
+        self.create_time
+    }
+
+    /// The time at which the document was last changed.
+    pub fn update_time(&self) -> Option<NaiveDateTime> {
+        self.update_time
+    }
+
+    pub(crate) fn from_json(value: json::Value) -> Result<Document, Error> {
+        let mut object = match value {
+            json::Value::Object(object) => object,
+            _ => return Err(invalid("documents must be objects")),
+        };
+        let name = object
+            .remove("name")
+            .map(parse_string)
+            .transpose()?
+            .ok_or_else(|| invalid("missing document name"))?;
+        let fields = parse_fields(object.remove("fields"))?;
+        let create_time = object.get("createTime").map(parse_timestamp).transpose()?;
+        let update_time = object.get("updateTime").map(parse_timestamp).transpose()?;
+
+        Ok(Document {
+            name,
+            fields,
+            create_time,
+            update_time,
+        })
+    }
+}
+
+pub(crate) fn invalid(message: impl Into<String>) -> Error {
+    Error::Bundle(message.into())
+}
+
+fn parse_fields(fields: Option<json::Value>) -> Result<HashMap<String, Value>, Error> {
+    match fields {
+        None => Ok(HashMap::new()),
+        Some(json::Value::Object(fields)) => fields
+            .into_iter()
+            .map(|(name, value)| Ok((name, Value::from_json(value)?)))
+            .collect(),
+        Some(_) => Err(invalid("fields must be objects")),
+    }
+}
+
+pub(crate) fn parse_string(value: json::Value) -> Result<String, Error> {
+    match value {
+        json::Value::String(value) => Ok(value),
+        _ => Err(invalid("expected a string")),
+    }
+}
+
+//? 64-bit integers are encoded as strings in JSON, but plain numbers are accepted too.
+pub(crate) fn parse_integer(value: &json::Value) -> Result<i64, Error> {
+    match value {
+        json::Value::String(value) => value.parse().ok(),
+        json::Value::Number(value) => value.as_i64(),
+        _ => None,
+    }
+    .ok_or_else(|| invalid("invalid integer value"))
+}
+
+fn parse_double(value: &json::Value) -> Result<f64, Error> {
+    match value {
+        json::Value::Number(value) => value.as_f64(),
+        json::Value::String(value) => match value.as_str() {
+            "NaN" => Some(f64::NAN),
+            "Infinity" => Some(f64::INFINITY),
+            "-Infinity" => Some(f64::NEG_INFINITY),
+            value => value.parse().ok(),
+        },
+        _ => None,
+    }
+    .ok_or_else(|| invalid("invalid double value"))
+}
+
+//? Timestamps are either RFC 3339 strings or `{ "seconds": .., "nanos": .. }` objects.
+pub(crate) fn parse_timestamp(value: &json::Value) -> Result<NaiveDateTime, Error> {
+    let timestamp = match value {
+        json::Value::String(value) => DateTime::parse_from_rfc3339(value)
+            .ok()
+            .map(|timestamp| timestamp.naive_utc()),
+        json::Value::Object(object) => {
+            let seconds = object
+                .get("seconds")
+                .map(parse_integer)
+                .transpose()?
+                .unwrap_or(0);
+            let nanos = object
+                .get("nanos")
+                .map(parse_integer)
+                .transpose()?
+                .unwrap_or(0);
+            u32::try_from(nanos)
+                .ok()
+                .and_then(|nanos| DateTime::from_timestamp(seconds, nanos))
+                .map(|timestamp| timestamp.naive_utc())
+        }
+        _ => None,
+    };
+
+    timestamp.ok_or_else(|| invalid("invalid timestamp value"))
+}
@@ -0,0 +1,11 @@
+//? The crate-wide `Error` type is large because of `tonic::Status`, but is still used here for consistency.
+#![allow(clippy::result_large_err)]
+
+mod bundle;
+mod document;
+
+pub use self::bundle::*;
+pub use self::document::*;
+
+/// The error type for the Firestore module.
+pub type Error = crate::error::Error;
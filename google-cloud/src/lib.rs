@@ -6,18 +6,78 @@ extern crate google_cloud_derive;
 
 /// Authorization/authentication related utilities.
 pub mod authorize;
+/// Cancellation of long-running operations.
+#[cfg(any(
+    feature = "bigtable",
+    feature = "cloudbuild",
+    feature = "datastore",
+    feature = "deploy",
+    feature = "pubsub"
+))]
+pub mod cancel;
+/// gRPC channel configuration.
+#[cfg(any(
+    feature = "bigtable",
+    feature = "cloudbuild",
+    feature = "datastore",
+    feature = "deploy",
+    feature = "pubsub",
+    feature = "redis",
+    feature = "vision"
+))]
+pub mod channel;
 /// Error handling utilities.
 pub mod error;
 
+/// Cloud Bigtable bindings.
+#[cfg(feature = "bigtable")]
+pub mod bigtable;
+/// Pub/Sub to Datastore change-data-capture helper.
+#[cfg(feature = "cdc")]
+pub mod cdc;
+/// Cloud Build bindings.
+#[cfg(feature = "cloudbuild")]
+pub mod cloudbuild;
+/// Per-environment configuration profiles, building consistently configured clients.
+#[cfg(feature = "config")]
+pub mod config;
 /// Datastore bindings.
 #[cfg(feature = "datastore")]
 pub mod datastore;
+/// Cloud Deploy bindings.
+#[cfg(feature = "deploy")]
+pub mod deploy;
+/// Cloud Firestore bindings.
+#[cfg(feature = "firestore")]
+pub mod firestore;
+/// Pub/Sub transactional outbox, backed by Datastore.
+#[cfg(feature = "outbox")]
+pub mod outbox;
 /// Pub/Sub bindings.
 #[cfg(feature = "pubsub")]
 pub mod pubsub;
+/// Memorystore for Redis bindings.
+#[cfg(feature = "redis")]
+pub mod redis;
 /// Cloud Storage bindings.
 #[cfg(feature = "storage")]
 pub mod storage;
+/// Tracking of the background tasks spawned by this crate.
+#[cfg(any(
+    feature = "bigtable",
+    feature = "cloudbuild",
+    feature = "datastore",
+    feature = "deploy",
+    feature = "pubsub",
+    feature = "redis",
+    feature = "vision"
+))]
+pub mod tasks;
+/// Support for integration tests, isolating and cleaning up their resources.
+#[cfg(feature = "testing")]
+pub mod testing;
+/// Conversions between the well-known protobuf types of the raw APIs and standard time types.
+pub mod types;
 /// Cloud Vision bindings.
 #[cfg(feature = "vision")]
 pub mod vision;
@@ -0,0 +1,51 @@
+//! A transactional outbox, publishing Pub/Sub messages atomically with Datastore writes.
+//!
+//! Messages are written to a Datastore kind within the caller's transaction, so that they only exist
+//! if the transaction commits, along with the entity writes they describe.
+//! A relay then publishes the pending messages onto their topics and marks them as sent:
+//!
+//! ```no_run
+//! # use google_cloud::{datastore, outbox, pubsub};
+//! # async fn run() -> Result<(), outbox::Error> {
+//! let mut datastore = datastore::Client::new("my-project").await?;
+//! let pubsub = pubsub::Client::new("my-project").await?;
+//! let outbox = outbox::Outbox::new();
+//!
+//! let mut transaction = datastore.begin_transaction().await?;
+//! // ... entity writes ...
+//! let message = pubsub::PublishMessage::new("order 42 paid").ordering_key("order-42");
+//! outbox.enqueue(&mut transaction, "order-events", message)?;
+//! transaction.commit().await?;
+//!
+//! let mut relay = outbox.relay(datastore, pubsub);
+//! tokio::spawn(async move { relay.run().await });
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Messages are published at least once: a message published right before the relay fails to mark it as sent
+//! gets published again, with the same `outbox-id` attribute (see `Relay::ID_ATTRIBUTE`) for subscribers to deduplicate on.
+//! Messages of a same topic are published in the order they were enqueued in (by enqueue time).
+//!
+//! The relay looks up the pending messages with an equality filter on `sent` ordered by `created`,
+//! which requires a composite index on the outbox kind:
+//!
+//! ```yaml
+//! indexes:
+//!   - kind: _outbox
+//!     properties:
+//!       - name: sent
+//!       - name: created
+//! ```
+
+//? The crate-wide `Error` type is large because of `tonic::Status`, but is still used here for consistency.
+#![allow(clippy::result_large_err)]
+
+mod record;
+mod relay;
+
+pub use self::record::*;
+pub use self::relay::*;
+
+/// The error type for the outbox module.
+pub type Error = crate::error::Error;
@@ -0,0 +1,145 @@
+use std::collections::HashMap;
+
+use bytes::Bytes;
+use chrono::NaiveDateTime;
+
+use crate::datastore::{self, Entity, FromValue, IntoValue, Key, Transaction, Value};
+use crate::error::ConvertError;
+use crate::outbox::Relay;
+use crate::pubsub::{self, PublishMessage};
+
+/// Represents a message stored in the outbox, waiting to be published (or already published).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutboxMessage {
+    pub(crate) key: Option<Key>,
+    pub(crate) topic: String,
+    pub(crate) message: PublishMessage,
+    pub(crate) created: NaiveDateTime,
+    pub(crate) sent: bool,
+}
+
+impl OutboxMessage {
+    /// The key of the message's entity (`None` until stored).
+    pub fn key(&self) -> Option<&Key> {
+        self.key.as_ref()
+    }
+
+    /// The ID of the topic the message is to be published onto.
+    pub fn topic(&self) -> &str {
+        self.topic.as_str()
+    }
+
+    /// The message to publish.
+    pub fn message(&self) -> &PublishMessage {
+        &self.message
+    }
+
+    /// When the message was enqueued.
+    pub fn created(&self) -> NaiveDateTime {
+        self.created
+    }
+
+    /// Has the message been published already ?
+    pub fn is_sent(&self) -> bool {
+        self.sent
+    }
+
+    pub(crate) fn into_entity(self, kind: &str) -> Result<Entity, ConvertError> {
+        let key = self.key.unwrap_or_else(|| Key::new(kind));
+        let unindexed = |value: Value| Value::IndexedValue(Box::new(value), false);
+
+        let mut properties = HashMap::new();
+        properties.insert(String::from("topic"), self.topic.into_value());
+        properties.insert(
+            String::from("data"),
+            unindexed(self.message.data.into_value()),
+        );
+        properties.insert(
+            String::from("attributes"),
+            unindexed(self.message.attributes.into_value()),
+        );
+        properties.insert(
+            String::from("ordering_key"),
+            self.message.ordering_key.into_value(),
+        );
+        properties.insert(String::from("created"), self.created.into_value());
+        properties.insert(String::from("sent"), self.sent.into_value());
+        Entity::new(key, Value::EntityValue(properties))
+    }
+
+    pub(crate) fn from_entity(entity: Entity) -> Result<OutboxMessage, ConvertError> {
+        let key = entity.key;
+        let mut properties: HashMap<String, Value> = FromValue::from_value(entity.properties)?;
+        let mut property = |name: &str| {
+            properties
+                .remove(name)
+                .ok_or_else(|| ConvertError::MissingProperty(String::from(name)))
+        };
+
+        let data: Bytes = FromValue::from_value(property("data")?)?;
+        let attributes: HashMap<String, String> = FromValue::from_value(property("attributes")?)?;
+        let message = PublishMessage::new(data)
+            .attributes(attributes)
+            .ordering_key(String::from_value(property("ordering_key")?)?);
+
+        Ok(OutboxMessage {
+            key: Some(key),
+            topic: FromValue::from_value(property("topic")?)?,
+            message,
+            created: FromValue::from_value(property("created")?)?,
+            sent: FromValue::from_value(property("sent")?)?,
+        })
+    }
+}
+
+/// A transactional outbox, storing the messages to publish in a Datastore kind.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Outbox {
+    pub(crate) kind: String,
+}
+
+impl Outbox {
+    /// The default kind of the outbox entities.
+    pub const DEFAULT_KIND: &'static str = "_outbox";
+
+    /// Create a new outbox, using the default kind.
+    pub fn new() -> Outbox {
+        Outbox {
+            kind: String::from(Outbox::DEFAULT_KIND),
+        }
+    }
+
+    /// Set the kind of the outbox entities.
+    pub fn kind(mut self, kind: impl Into<String>) -> Outbox {
+        self.kind = kind.into();
+        self
+    }
+
+    /// Enqueue a message to publish onto the given topic, once the transaction gets committed.
+    pub fn enqueue(
+        &self,
+        transaction: &mut Transaction,
+        topic: impl Into<String>,
+        message: impl Into<PublishMessage>,
+    ) -> Result<(), ConvertError> {
+        let message = OutboxMessage {
+            key: None,
+            topic: topic.into(),
+            message: message.into(),
+            created: chrono::Utc::now().naive_utc(),
+            sent: false,
+        };
+        transaction.put(message.into_entity(self.kind.as_str())?)
+    }
+
+    /// Create a relay, publishing the messages of this outbox.
+    pub fn relay(&self, datastore: datastore::Client, pubsub: pubsub::Client) -> Relay {
+        Relay::new(self.kind.clone(), datastore, pubsub)
+    }
+}
+
+impl Default for Outbox {
+    fn default() -> Outbox {
+        Outbox::new()
+    }
+}
@@ -0,0 +1,131 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use crate::datastore::{self, Filter, IntoValue, Order, Query};
+use crate::outbox::{Error, OutboxMessage};
+use crate::pubsub::{self, Topic};
+
+/// The counts of messages handled while relaying a batch.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RelayStats {
+    /// Messages published and marked as sent.
+    pub published: usize,
+    /// Messages that could not be published, left pending to be retried.
+    pub failed: usize,
+}
+
+/// Publishes the pending messages of an outbox, marking them as sent.
+pub struct Relay {
+    pub(crate) kind: String,
+    pub(crate) datastore: datastore::Client,
+    pub(crate) pubsub: pubsub::Client,
+    pub(crate) batch_size: i32,
+    pub(crate) poll_interval: Duration,
+    pub(crate) topics: HashMap<String, Topic>,
+}
+
+impl Relay {
+    /// The name of the attribute holding the ID of the outbox entity, added to every published message.
+    pub const ID_ATTRIBUTE: &'static str = "outbox-id";
+
+    pub(crate) fn new(kind: String, datastore: datastore::Client, pubsub: pubsub::Client) -> Relay {
+        Relay {
+            kind,
+            datastore,
+            pubsub,
+            batch_size: 100,
+            poll_interval: Duration::from_secs(1),
+            topics: HashMap::new(),
+        }
+    }
+
+    /// Set the maximum number of messages relayed at once.
+    pub fn batch_size(mut self, batch_size: i32) -> Relay {
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// Set how long to wait before looking for pending messages again, when none were found.
+    pub fn poll_interval(mut self, interval: Duration) -> Relay {
+        self.poll_interval = interval;
+        self
+    }
+
+    /// Relay the messages continuously, until an error occurs.
+    pub async fn run(&mut self) -> Result<(), Error> {
+        loop {
+            let stats = self.process_batch().await?;
+            if stats.published == 0 {
+                tokio::time::sleep(self.poll_interval).await;
+            }
+        }
+    }
+
+    /// Publish the oldest pending messages, and mark them as sent.
+    ///
+    /// The messages of a topic that could not be published are left pending, to be retried by the next batch
+    /// (before any newer message of that topic, preserving their order).
+    /// Fails if the published messages could not be marked as sent, in which case they get published again.
+    pub async fn process_batch(&mut self) -> Result<RelayStats, Error> {
+        let query = Query::new(self.kind.as_str())
+            .filter(Filter::Equal("sent".into(), false.into_value()))
+            .order(Order::Asc("created".into()))
+            .limit(self.batch_size);
+        let entities = self.datastore.query(query).await?;
+
+        //? Messages are grouped by topic, to be published in as few requests as possible.
+        let mut groups: Vec<(String, Vec<OutboxMessage>)> = Vec::new();
+        for entity in entities {
+            let message = OutboxMessage::from_entity(entity)?;
+            match groups.iter_mut().find(|(topic, _)| *topic == message.topic) {
+                Some((_, messages)) => messages.push(message),
+                None => groups.push((message.topic.clone(), vec![message])),
+            }
+        }
+
+        let mut stats = RelayStats::default();
+        for (topic, messages) in groups {
+            let published = messages.len();
+            match self.publish(topic.as_str(), &messages).await {
+                Ok(()) => {}
+                Err(_) => {
+                    stats.failed += published;
+                    continue;
+                }
+            }
+
+            let entities = messages
+                .into_iter()
+                .map(|mut message| {
+                    message.sent = true;
+                    message.into_entity(self.kind.as_str())
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            self.datastore.put_all(entities).await?;
+            stats.published += published;
+        }
+
+        Ok(stats)
+    }
+
+    /// Publishes messages onto a topic, in order, tagging them with the IDs of their entities.
+    async fn publish(&mut self, topic: &str, messages: &[OutboxMessage]) -> Result<(), Error> {
+        if !self.topics.contains_key(topic) {
+            let handle = self.pubsub.topic(topic).await?;
+            let handle = handle.ok_or_else(|| tonic::Status::not_found(topic))?;
+            self.topics.insert(String::from(topic), handle);
+        }
+        let handle = self.topics.get_mut(topic).unwrap();
+
+        let messages = messages.iter().map(|message| {
+            let id = message.key.as_ref().map(ToString::to_string);
+            message
+                .message
+                .clone()
+                .attribute(Relay::ID_ATTRIBUTE, id.unwrap_or_default())
+        });
+        handle.publish_all(messages).await?;
+
+        Ok(())
+    }
+}
@@ -47,8 +47,8 @@ pub struct Topic {
 pub struct PubsubMessage {
     /// The message data field. If this field is empty, the message must contain
     /// at least one attribute.
-    #[prost(bytes="vec", tag="1")]
-    pub data: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="bytes", tag="1")]
+    pub data: ::prost::bytes::Bytes,
     /// Optional attributes for this message.
     #[prost(map="string, string", tag="2")]
     pub attributes: ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
@@ -206,6 +206,19 @@ pub struct DeleteTopicRequest {
     #[prost(string, tag="1")]
     pub topic: ::prost::alloc::string::String,
 }
+/// Request for the DetachSubscription method.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DetachSubscriptionRequest {
+    /// The subscription to detach.
+    /// Format is `projects/{project}/subscriptions/{subscription}`.
+    #[prost(string, tag="1")]
+    pub subscription: ::prost::alloc::string::String,
+}
+/// Response for the DetachSubscription method.
+/// Reserved for future use.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DetachSubscriptionResponse {
+}
 /// A subscription resource.
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Subscription {
@@ -288,6 +301,12 @@ pub struct Subscription {
     /// value for `expiration_policy.ttl` is 1 day.
     #[prost(message, optional, tag="11")]
     pub expiration_policy: ::core::option::Option<ExpirationPolicy>,
+    /// An expression written in the Pub/Sub filter language. If non-empty, then
+    /// only `PubsubMessage`s whose `attributes` field matches the filter are
+    /// delivered on this subscription. If empty, then no messages are filtered
+    /// out.
+    #[prost(string, tag="12")]
+    pub filter: ::prost::alloc::string::String,
     /// A policy that specifies the conditions for dead lettering messages in
     /// this subscription. If dead_letter_policy is not set, dead lettering
     /// is disabled.
@@ -301,6 +320,37 @@ pub struct Subscription {
     /// for production use. It is not subject to any SLA or deprecation policy.
     #[prost(message, optional, tag="13")]
     pub dead_letter_policy: ::core::option::Option<DeadLetterPolicy>,
+    /// A policy that specifies how Pub/Sub retries message delivery for this
+    /// subscription.
+    ///
+    /// If not set, the default retry policy is applied. This generally implies
+    /// that messages will be retried as soon as possible for healthy subscribers.
+    /// RetryPolicy will be triggered on NACKs or acknowledgement deadline exceeded
+    /// events for a given message.
+    #[prost(message, optional, tag="14")]
+    pub retry_policy: ::core::option::Option<RetryPolicy>,
+}
+/// A policy that specifies how Cloud Pub/Sub retries message delivery.
+///
+/// Retry delay will be exponential based on provided minimum and maximum
+/// backoffs. <https://en.wikipedia.org/wiki/Exponential_backoff.>
+///
+/// RetryPolicy will be triggered on NACKs or acknowledgement deadline exceeded
+/// events for a given message.
+///
+/// Retry Policy is implemented on a best effort basis. At times, the delay
+/// between consecutive deliveries may not match the configuration. That is,
+/// delay can be more or less than configured backoff.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RetryPolicy {
+    /// The minimum delay between consecutive deliveries of a given message.
+    /// Value should be between 0 and 600 seconds. Defaults to 10 seconds.
+    #[prost(message, optional, tag="1")]
+    pub minimum_backoff: ::core::option::Option<::prost_types::Duration>,
+    /// The maximum delay between consecutive deliveries of a given message.
+    /// Value should be between 0 and 600 seconds. Defaults to 600 seconds.
+    #[prost(message, optional, tag="2")]
+    pub maximum_backoff: ::core::option::Option<::prost_types::Duration>,
 }
 /// Dead lettering is done on a best effort basis. The same message might be
 /// dead lettered multiple times.
@@ -1034,6 +1084,29 @@ pub mod publisher_client {
             );
             self.inner.unary(request.into_request(), path, codec).await
         }
+        /// Detaches a subscription from this topic. All messages retained in the
+        /// subscription are dropped. Subsequent `Pull` and `StreamingPull` requests
+        /// will return FAILED_PRECONDITION. If the subscription is a push
+        /// subscription, pushes to the endpoint will stop.
+        pub async fn detach_subscription(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DetachSubscriptionRequest>,
+        ) -> Result<tonic::Response<super::DetachSubscriptionResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/google.pubsub.v1.Publisher/DetachSubscription",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
     }
 }
 /// Generated client implementations.
@@ -0,0 +1,76 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+use crate::pubsub::{PublishMessage, Redacted, Redactor};
+
+/// Describes a message about to be published, as reported to the publish hooks of a client.
+///
+/// Its `Debug` output is redacted according to the policy of the client (see `Client::redact_with`).
+#[derive(Clone, Copy)]
+pub struct OutgoingMessage<'a> {
+    pub(crate) topic: &'a str,
+    pub(crate) message: &'a PublishMessage,
+    pub(crate) redactor: &'a Redactor,
+}
+
+impl<'a> OutgoingMessage<'a> {
+    /// The full name of the topic the message is published onto.
+    pub fn topic(&self) -> &'a str {
+        self.topic
+    }
+
+    /// The payload data of the message.
+    pub fn data(&self) -> &'a [u8] {
+        self.message.data.as_ref()
+    }
+
+    /// The attributes of the message.
+    pub fn attributes(&self) -> &'a HashMap<String, String> {
+        &self.message.attributes
+    }
+
+    /// The ordering key of the message (empty if it has none).
+    pub fn ordering_key(&self) -> &'a str {
+        self.message.ordering_key.as_str()
+    }
+
+    /// The contents of the message, redacted according to the policy of the client, to log them.
+    pub fn redacted(&self) -> Redacted<'a> {
+        let message = self.message;
+        self.redactor
+            .redact(message.data.as_ref(), &message.attributes)
+    }
+}
+
+impl fmt::Debug for OutgoingMessage<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OutgoingMessage")
+            .field("topic", &self.topic)
+            .field("contents", &self.redacted())
+            .field("ordering_key", &self.message.ordering_key)
+            .finish()
+    }
+}
+
+/// A hook invoked with every message about to be published.
+pub(crate) type PublishHook = Arc<dyn Fn(&OutgoingMessage<'_>) + Send + Sync>;
+
+/// Reports the messages about to be published onto a topic to the given hooks, in order.
+pub(crate) fn run_publish_hooks(
+    hooks: &[PublishHook],
+    redactor: &Redactor,
+    topic: &str,
+    messages: &[PublishMessage],
+) {
+    for message in messages {
+        let outgoing = OutgoingMessage {
+            topic,
+            message,
+            redactor,
+        };
+        for hook in hooks {
+            hook(&outgoing);
+        }
+    }
+}
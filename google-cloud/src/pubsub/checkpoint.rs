@@ -0,0 +1,101 @@
+use std::sync::Arc;
+
+use tonic::Code;
+
+use crate::pubsub::Error;
+
+/// Describes an acknowledged message, as reported to the checkpoint hooks of a subscription.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AckedMessage {
+    pub(crate) id: String,
+    pub(crate) publish_time: chrono::NaiveDateTime,
+}
+
+impl AckedMessage {
+    /// The message's unique ID.
+    pub fn id(&self) -> &str {
+        self.id.as_str()
+    }
+
+    /// The publication time of the message.
+    pub fn publish_time(&self) -> chrono::NaiveDateTime {
+        self.publish_time
+    }
+}
+
+/// A hook invoked with the messages of every successful acknowledgement request.
+pub(crate) type AckHook = Arc<dyn Fn(&[AckedMessage]) + Send + Sync>;
+
+/// Reports acknowledged messages to the given hooks, in order.
+pub(crate) fn run_hooks(hooks: &[AckHook], acked: &[AckedMessage]) {
+    if acked.is_empty() {
+        return;
+    }
+    for hook in hooks {
+        hook(acked);
+    }
+}
+
+/// Describes messages that could not be acknowledged, as reported to the ack failure hooks of a subscription.
+#[derive(Debug, Clone)]
+pub struct AckFailure {
+    pub(crate) messages: Vec<AckedMessage>,
+    pub(crate) status: tonic::Status,
+}
+
+impl AckFailure {
+    /// The messages that could not be acknowledged, which Pub/Sub will redeliver (if it still can).
+    pub fn messages(&self) -> &[AckedMessage] {
+        self.messages.as_slice()
+    }
+
+    /// The status the acknowledgement failed with.
+    pub fn status(&self) -> &tonic::Status {
+        &self.status
+    }
+}
+
+/// A hook invoked with the messages of every acknowledgement request failing persistently.
+pub(crate) type AckFailureHook = Arc<dyn Fn(&AckFailure) + Send + Sync>;
+
+/// Whether an acknowledgement failed in a way that retrying it would not fix (such as an invalid ack ID).
+pub(crate) fn is_persistent_failure(err: &Error) -> bool {
+    let status = match err.status() {
+        Some(status) => status,
+        None => return false,
+    };
+    !matches!(
+        status.code(),
+        Code::Unavailable
+            | Code::DeadlineExceeded
+            | Code::ResourceExhausted
+            | Code::Aborted
+            | Code::Internal
+            | Code::Cancelled
+            | Code::Unknown
+    )
+}
+
+/// Reports messages to the given ack failure hooks, in order, if the error they failed with is persistent.
+///
+/// Returns whether the hooks were invoked.
+pub(crate) fn run_failure_hooks(
+    hooks: &[AckFailureHook],
+    messages: Vec<AckedMessage>,
+    err: &Error,
+) -> bool {
+    if messages.is_empty() || !is_persistent_failure(err) {
+        return false;
+    }
+    let failure = AckFailure {
+        messages,
+        status: err
+            .status()
+            .cloned()
+            .unwrap_or_else(|| tonic::Status::unknown("")),
+    };
+    for hook in hooks {
+        hook(&failure);
+    }
+    true
+}
@@ -1,25 +1,36 @@
-use hyper::client::connect::Connect;
 use std::env;
 use std::fs::File;
 use std::sync::Arc;
 
 use tokio::sync::Mutex;
-use tonic::transport::{Certificate, Channel, ClientTlsConfig};
-use tonic::{IntoRequest, Request};
+use tonic::transport::Channel;
+use tonic::{Code, IntoRequest, Request};
 
-use crate::authorize::{ApplicationCredentials, TokenManager, TLS_CERTS};
+use crate::authorize::{ApplicationCredentials, TokenManager};
+use crate::channel::{self, ChannelConfig};
+use crate::error::WithContext;
 use crate::pubsub::api;
 use crate::pubsub::api::publisher_client::PublisherClient;
 use crate::pubsub::api::subscriber_client::SubscriberClient;
-use crate::pubsub::{Error, Subscription, Topic, TopicConfig};
+use crate::pubsub::{
+    Error, LabelSelector, OutgoingMessage, PublishHook, RateLimiter, Redactor, Setup, SetupReport,
+    Subscription, TeardownReport, Topic, TopicConfig,
+};
 
 /// The Pub/Sub client, tied to a specific project.
+///
+/// Publishing and subscribing go through the same channel and credentials, which the clones of the client
+/// (as well as its topics and subscriptions) share as well: a single client can serve a whole application.
 #[derive(Clone)]
 pub struct Client {
     pub(crate) project_name: String,
     pub(crate) publisher: PublisherClient<Channel>,
     pub(crate) subscriber: SubscriberClient<Channel>,
     pub(crate) token_manager: Arc<Mutex<TokenManager>>,
+    pub(crate) publish_hooks: Vec<PublishHook>,
+    pub(crate) dry_run: bool,
+    pub(crate) rate_limiter: Option<RateLimiter>,
+    pub(crate) redactor: Redactor,
 }
 
 struct ClientConfiguration {
@@ -29,8 +40,10 @@ struct ClientConfiguration {
 impl ClientConfiguration {
     pub fn new() -> ClientConfiguration {
         ClientConfiguration {
-            endpoint: env::var("PUBSUB_EMULATOR_HOST")
-                .unwrap_or_else(|_| Client::ENDPOINT.to_string()),
+            endpoint: match env::var("PUBSUB_EMULATOR_HOST") {
+                Ok(host) => channel::emulator_endpoint(host.as_str()),
+                Err(_) => Client::ENDPOINT.to_string(),
+            },
         }
     }
 }
@@ -69,17 +82,28 @@ impl Client {
     pub async fn from_credentials(
         project_name: impl Into<String>,
         creds: ApplicationCredentials,
+    ) -> Result<Client, Error> {
+        Client::from_credentials_with_config(project_name, creds, ChannelConfig::default()).await
+    }
+
+    /// Create a new client for the specified project with custom credentials and channel configuration.
+    pub async fn from_credentials_with_config(
+        project_name: impl Into<String>,
+        creds: ApplicationCredentials,
+        config: ChannelConfig,
     ) -> Result<Client, Error> {
         let client_config = ClientConfiguration::new();
-        let mut channel = Channel::from_shared(client_config.endpoint.clone()).unwrap();
-        if client_config.endpoint.starts_with("https://") {
-            let tls_config = ClientTlsConfig::new()
-                .ca_certificate(Certificate::from_pem(TLS_CERTS))
-                .domain_name(Client::DOMAIN_NAME);
-            channel = channel.tls_config(tls_config)?
-        }
+        Client::from_endpoint(project_name, creds, client_config.endpoint, &config).await
+    }
 
-        let channel = channel.connect().await?;
+    /// Create a new client for the specified project, connected to the given endpoint.
+    pub(crate) async fn from_endpoint(
+        project_name: impl Into<String>,
+        creds: ApplicationCredentials,
+        endpoint: String,
+        config: &ChannelConfig,
+    ) -> Result<Client, Error> {
+        let channel = channel::connect(endpoint, Client::DOMAIN_NAME, config).await?;
 
         Ok(Client {
             project_name: project_name.into(),
@@ -89,34 +113,166 @@ impl Client {
                 creds,
                 Client::SCOPES.as_ref(),
             ))),
+            publish_hooks: Vec::new(),
+            dry_run: false,
+            rate_limiter: None,
+            redactor: Redactor::new(),
         })
     }
 
+    /// Register a hook to invoke with every message about to be published through this client
+    /// (and the topics it hands out), such as for audit logging or scanning for personal data.
+    ///
+    /// The hook runs before the messages are sent, in dry-run mode as well.
+    pub fn on_publish(
+        mut self,
+        hook: impl Fn(&OutgoingMessage<'_>) + Send + Sync + 'static,
+    ) -> Client {
+        self.publish_hooks.push(Arc::new(hook));
+        self
+    }
+
+    /// Enable or disable the dry-run mode, in which messages go through the same batching, validation and hooks
+    /// as usual, but are never actually sent to Pub/Sub.
+    ///
+    /// This is useful to rehearse migrations against production topics without publishing anything.
+    /// The IDs returned when publishing in dry-run mode are empty.
+    pub fn dry_run(mut self, enabled: bool) -> Client {
+        self.dry_run = enabled;
+        self
+    }
+
+    /// Limit the rate at which messages get published through this client (and the topics it hands out).
+    ///
+    /// Publish requests are delayed as needed to stay within the limits (see `RateLimiter`),
+    /// except in dry-run mode.
+    pub fn rate_limit(mut self, limiter: RateLimiter) -> Client {
+        self.rate_limiter = Some(limiter);
+        self
+    }
+
+    /// Redact the contents of messages according to the given policy, whenever they get debug-printed
+    /// (received messages, and messages reported to publish hooks).
+    ///
+    /// By default, the payload data is replaced by its size and the attributes are kept (see `Redactor::new`).
+    pub fn redact_with(mut self, redactor: Redactor) -> Client {
+        self.redactor = redactor;
+        self
+    }
+
+    /// Get a client for another project, sharing the channel and the credentials of this one.
+    ///
+    /// The other settings of the client (publish hooks, dry-run mode, rate limits...) are kept,
+    /// and the topics and subscriptions it hands out belong to the other project.
+    /// The credentials must be granted access to the other project.
+    pub fn for_project(&self, project_name: impl Into<String>) -> Client {
+        let mut client = self.clone();
+        client.project_name = project_name.into();
+        client
+    }
+
+    /// The project the client operates on.
+    pub fn project_name(&self) -> &str {
+        self.project_name.as_str()
+    }
+
     /// Create a new topic.
     pub async fn create_topic(
         &mut self,
         topic_id: &str,
         config: TopicConfig,
     ) -> Result<Topic, Error> {
-        let request = api::Topic {
-            name: format!(
-                "projects/{0}/topics/{1}",
-                self.project_name.as_str(),
-                topic_id,
-            ),
-            labels: config.labels,
-            message_storage_policy: None,
-            kms_key_name: String::new(),
-        };
+        let name = format!(
+            "projects/{0}/topics/{1}",
+            self.project_name.as_str(),
+            topic_id,
+        );
+        let request = config.into_api(name);
+        let name = request.name.clone();
         let request = self.construct_request(request).await?;
-        let response = self.publisher.create_topic(request).await?;
-        let topic = response.into_inner();
+        let response = self.publisher.create_topic(request).await;
+        let topic = response.context("CreateTopic", name)?.into_inner();
 
         Ok(Topic::new(self.clone(), topic.name))
     }
 
+    /// Create all the topics and subscriptions of a setup, leaving the already existing ones untouched.
+    ///
+    /// This makes it safe to run repeatedly, but resources that already exist keep their current settings,
+    /// even when they differ from the declared ones.
+    pub async fn setup(&mut self, setup: &Setup) -> Result<SetupReport, Error> {
+        let mut report = SetupReport::default();
+        for (id, config) in setup.topics.iter() {
+            match self.create_topic(id, config.clone()).await {
+                Ok(_) => report.created_topics.push(id.clone()),
+                Err(err) if err.status().map(tonic::Status::code) == Some(Code::AlreadyExists) => {
+                    report.existing_topics.push(id.clone())
+                }
+                Err(err) => return Err(err),
+            }
+        }
+        for (topic_id, id, config) in setup.subscriptions.iter() {
+            let name = format!("projects/{0}/topics/{1}", self.project_name, topic_id);
+            let mut topic = Topic::new(self.clone(), name);
+            match topic.create_subscription(id, config.clone()).await {
+                Ok(_) => report.created_subscriptions.push(id.clone()),
+                Err(err) if err.status().map(tonic::Status::code) == Some(Code::AlreadyExists) => {
+                    report.existing_subscriptions.push(id.clone())
+                }
+                Err(err) => return Err(err),
+            }
+        }
+
+        Ok(report)
+    }
+
+    /// Delete all the topics whose labels match the selector, along with all of their subscriptions.
+    ///
+    /// This is meant for cleaning up ephemeral environments, such as the ones created for CI runs:
+    ///
+    /// ```no_run
+    /// # use google_cloud::pubsub::{Client, Error, LabelSelector};
+    /// # async fn run(mut client: Client, pipeline_id: &str) -> Result<(), Error> {
+    /// let selector = LabelSelector::new().equals("env", "ci").equals("pipeline", pipeline_id);
+    /// let report = client.teardown(&selector).await?;
+    /// println!("deleted {} topics", report.deleted_topics().len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Subscriptions are deleted before their topic, and resources deleted concurrently are skipped.
+    pub async fn teardown(&mut self, selector: &LabelSelector) -> Result<TeardownReport, Error> {
+        let mut report = TeardownReport::default();
+        for topic in self.list_topics().await? {
+            if !selector.matches(&topic.labels) {
+                continue;
+            }
+            let mut topic = Topic::new(self.clone(), topic.name);
+            let subscriptions = topic.delete_subscriptions().await?;
+            report.deleted_subscriptions.extend(subscriptions);
+            let id = String::from(topic.id());
+            match topic.delete().await {
+                Ok(()) => report.deleted_topics.push(id),
+                Err(err) if err.status().map(tonic::Status::code) == Some(Code::NotFound) => {}
+                Err(err) => return Err(err),
+            }
+        }
+
+        Ok(report)
+    }
+
     /// List all exisiting topics.
     pub async fn topics(&mut self) -> Result<Vec<Topic>, Error> {
+        let topics = self.list_topics().await?;
+        let topics = topics
+            .into_iter()
+            .map(|topic| Topic::new(self.clone(), topic.name))
+            .collect();
+
+        Ok(topics)
+    }
+
+    async fn list_topics(&mut self) -> Result<Vec<api::Topic>, Error> {
         let mut topics = Vec::new();
         let page_size = 25;
         let mut page_token = String::default();
@@ -127,16 +283,12 @@ impl Client {
                 page_size,
                 page_token,
             };
+            let project = request.project.clone();
             let request = self.construct_request(request).await?;
-            let response = self.publisher.list_topics(request).await?;
-            let response = response.into_inner();
+            let response = self.publisher.list_topics(request).await;
+            let response = response.context("ListTopics", project)?.into_inner();
             page_token = response.next_page_token;
-            topics.extend(
-                response
-                    .topics
-                    .into_iter()
-                    .map(|topic| Topic::new(self.clone(), topic.name)),
-            );
+            topics.extend(response.topics);
             if page_token.is_empty() {
                 break;
             }
@@ -150,9 +302,10 @@ impl Client {
         let request = api::GetTopicRequest {
             topic: format!("projects/{0}/topics/{1}", self.project_name.as_str(), id),
         };
+        let name = request.topic.clone();
         let request = self.construct_request(request).await?;
-        let response = self.publisher.get_topic(request).await?;
-        let topic = response.into_inner();
+        let response = self.publisher.get_topic(request).await;
+        let topic = response.context("GetTopic", name)?.into_inner();
 
         Ok(Some(Topic::new(self.clone(), topic.name)))
     }
@@ -169,9 +322,10 @@ impl Client {
                 page_size,
                 page_token,
             };
+            let project = request.project.clone();
             let request = self.construct_request(request).await?;
-            let response = self.subscriber.list_subscriptions(request).await?;
-            let response = response.into_inner();
+            let response = self.subscriber.list_subscriptions(request).await;
+            let response = response.context("ListSubscriptions", project)?.into_inner();
             page_token = response.next_page_token;
             subscriptions.extend(
                 response
@@ -196,9 +350,10 @@ impl Client {
                 id,
             ),
         };
+        let name = request.subscription.clone();
         let request = self.construct_request(request).await?;
-        let response = self.subscriber.get_subscription(request).await?;
-        let subscription = response.into_inner();
+        let response = self.subscriber.get_subscription(request).await;
+        let subscription = response.context("GetSubscription", name)?.into_inner();
 
         Ok(Some(Subscription::new(self.clone(), subscription.name)))
     }
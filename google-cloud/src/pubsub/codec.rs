@@ -0,0 +1,144 @@
+use std::fmt;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use thiserror::Error;
+
+/// The encoding of the messages of a topic with a schema.
+///
+/// A topic with a schema only accepts messages encoded as its settings specify,
+/// and tags the messages it delivers with their encoding (see `Message::schema_encoding`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SchemaEncoding {
+    /// The messages are encoded as JSON.
+    Json,
+    /// The messages are encoded in the binary format of the schema.
+    Binary,
+}
+
+impl SchemaEncoding {
+    //? The attribute that Pub/Sub attaches to the messages of topics with a schema.
+    pub(crate) const ATTRIBUTE: &'static str = "googclient_schemaencoding";
+
+    pub(crate) fn from_attribute(value: &str) -> Option<SchemaEncoding> {
+        match value {
+            "JSON" => Some(SchemaEncoding::Json),
+            "BINARY" => Some(SchemaEncoding::Binary),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for SchemaEncoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SchemaEncoding::Json => write!(f, "JSON"),
+            SchemaEncoding::Binary => write!(f, "BINARY"),
+        }
+    }
+}
+
+/// The error type for message serialization.
+#[derive(Debug, Error)]
+pub enum CodecError {
+    /// A JSON (de)serialization error.
+    #[error("JSON error: {0}")]
+    Json(#[from] json::Error),
+    /// A protobuf encoding error.
+    #[error("protobuf encoding error: {0}")]
+    ProtobufEncode(#[from] prost::EncodeError),
+    /// A protobuf decoding error.
+    #[error("protobuf decoding error: {0}")]
+    ProtobufDecode(#[from] prost::DecodeError),
+    /// An Avro (de)serialization error.
+    #[cfg(feature = "avro")]
+    #[error("Avro error: {0}")]
+    Avro(#[from] apache_avro::Error),
+    /// A message was encoded differently than the codec expects, according to its topic's schema.
+    #[error("expected a message encoded as {expected}, got {got}")]
+    EncodingMismatch {
+        /// The encoding of the codec.
+        expected: SchemaEncoding,
+        /// The encoding of the message.
+        got: SchemaEncoding,
+    },
+}
+
+/// A serialization format for the payload data of messages.
+///
+/// Codecs are used as type parameters (like in `Topic::publish_typed::<T, Json>`),
+/// and are implemented for all the types they are able to handle.
+pub trait Codec<T> {
+    /// The encoding of the codec's output, which must match the one of the topic if it has a schema.
+    const ENCODING: SchemaEncoding;
+
+    /// Serialize a value into payload data.
+    fn encode(value: &T) -> Result<Vec<u8>, CodecError>;
+
+    /// Deserialize a value from payload data.
+    fn decode(data: &[u8]) -> Result<T, CodecError>;
+}
+
+/// A codec serializing values (implementing `serde`'s traits) as JSON.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Json;
+
+impl<T> Codec<T> for Json
+where
+    T: Serialize + DeserializeOwned,
+{
+    const ENCODING: SchemaEncoding = SchemaEncoding::Json;
+
+    fn encode(value: &T) -> Result<Vec<u8>, CodecError> {
+        Ok(json::to_vec(value)?)
+    }
+
+    fn decode(data: &[u8]) -> Result<T, CodecError> {
+        Ok(json::from_slice(data)?)
+    }
+}
+
+/// A codec serializing protobuf messages (generated by `prost`) in their binary format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Protobuf;
+
+impl<T> Codec<T> for Protobuf
+where
+    T: prost::Message + Default,
+{
+    const ENCODING: SchemaEncoding = SchemaEncoding::Binary;
+
+    fn encode(value: &T) -> Result<Vec<u8>, CodecError> {
+        let mut data = Vec::with_capacity(value.encoded_len());
+        value.encode(&mut data)?;
+        Ok(data)
+    }
+
+    fn decode(data: &[u8]) -> Result<T, CodecError> {
+        Ok(T::decode(data)?)
+    }
+}
+
+/// A codec serializing values (implementing `serde`'s traits and `AvroSchema`) as single Avro binary datums,
+/// as expected by topics with an Avro schema.
+#[cfg(feature = "avro")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Avro;
+
+#[cfg(feature = "avro")]
+impl<T> Codec<T> for Avro
+where
+    T: Serialize + DeserializeOwned + apache_avro::AvroSchema,
+{
+    const ENCODING: SchemaEncoding = SchemaEncoding::Binary;
+
+    fn encode(value: &T) -> Result<Vec<u8>, CodecError> {
+        let value = apache_avro::to_value(value)?;
+        Ok(apache_avro::to_avro_datum(&T::get_schema(), value)?)
+    }
+
+    fn decode(mut data: &[u8]) -> Result<T, CodecError> {
+        let value = apache_avro::from_avro_datum(&T::get_schema(), &mut data, None)?;
+        Ok(apache_avro::from_value(&value)?)
+    }
+}
@@ -0,0 +1,123 @@
+use std::convert::TryFrom;
+use std::sync::{Arc, Mutex};
+
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+/// A budget of outstanding messages and/or bytes, shared by the subscriptions assigned to it
+/// (see `Subscription::group`).
+///
+/// A message is outstanding from the moment it is handed out by `Subscription::receive`
+/// until it gets acknowledged, negatively acknowledged or dropped (along with all of its clones).
+/// Once the budget of a group is exhausted, its subscriptions stop handing out messages until some get settled,
+/// so that a flood of messages on one group cannot starve the handlers of the subscriptions of other groups.
+/// Since handlers typically run one task per outstanding message, the budget also bounds their tasks.
+///
+/// The group is cheaply cloneable, its clones sharing the same budget.
+///
+/// ```no_run
+/// # use google_cloud::pubsub::{Client, ConcurrencyGroup, Error};
+/// # async fn run(mut client: Client) -> Result<(), Error> {
+/// let orders = ConcurrencyGroup::new().max_outstanding_messages(100);
+/// let audits = ConcurrencyGroup::new()
+///     .max_outstanding_messages(10)
+///     .max_outstanding_bytes(10_000_000);
+///
+/// let subscription = client.subscription("orders").await?.unwrap();
+/// let mut orders = subscription.group(orders);
+/// let subscription = client.subscription("audits").await?.unwrap();
+/// let mut audits = subscription.group(audits);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ConcurrencyGroup {
+    messages: Option<Budget>,
+    bytes: Option<Budget>,
+}
+
+#[derive(Debug, Clone)]
+struct Budget {
+    limit: u32,
+    semaphore: Arc<Semaphore>,
+}
+
+impl Budget {
+    fn new(limit: u32) -> Budget {
+        let limit = limit.max(1);
+        Budget {
+            limit,
+            semaphore: Arc::new(Semaphore::new(limit as usize)),
+        }
+    }
+
+    fn outstanding(&self) -> u32 {
+        self.limit - self.semaphore.available_permits() as u32
+    }
+
+    async fn acquire(&self, amount: u32) -> Option<OwnedSemaphorePermit> {
+        //? A message larger than the whole budget gets it all, rather than waiting forever.
+        let amount = amount.clamp(1, self.limit);
+        let semaphore = Arc::clone(&self.semaphore);
+        semaphore.acquire_many_owned(amount).await.ok()
+    }
+}
+
+impl ConcurrencyGroup {
+    /// Create a new group, without any limit yet.
+    pub fn new() -> ConcurrencyGroup {
+        ConcurrencyGroup::default()
+    }
+
+    /// Limit the number of messages outstanding at once in the group.
+    pub fn max_outstanding_messages(mut self, limit: u32) -> ConcurrencyGroup {
+        self.messages = Some(Budget::new(limit));
+        self
+    }
+
+    /// Limit the total size of the payload data of the messages outstanding at once in the group.
+    ///
+    /// A single message larger than the limit is handed out once no other message is outstanding.
+    pub fn max_outstanding_bytes(mut self, limit: u32) -> ConcurrencyGroup {
+        self.bytes = Some(Budget::new(limit));
+        self
+    }
+
+    /// The number of messages currently outstanding in the group (if limited).
+    pub fn outstanding_messages(&self) -> Option<u32> {
+        self.messages.as_ref().map(Budget::outstanding)
+    }
+
+    /// The number of bytes currently outstanding in the group (if limited).
+    pub fn outstanding_bytes(&self) -> Option<u32> {
+        self.bytes.as_ref().map(Budget::outstanding)
+    }
+
+    /// Waits for the budget to hand out a message of the given size.
+    pub(crate) async fn acquire(&self, size: usize) -> GroupPermit {
+        let mut permits = Vec::new();
+        if let Some(budget) = self.messages.as_ref() {
+            permits.extend(budget.acquire(1).await);
+        }
+        if let Some(budget) = self.bytes.as_ref() {
+            let size = u32::try_from(size).unwrap_or(u32::MAX);
+            permits.extend(budget.acquire(size).await);
+        }
+        GroupPermit {
+            permits: Arc::new(Mutex::new(permits)),
+        }
+    }
+}
+
+/// The share of the budget of a group held by an outstanding message (and its clones).
+#[derive(Debug, Clone)]
+pub(crate) struct GroupPermit {
+    permits: Arc<Mutex<Vec<OwnedSemaphorePermit>>>,
+}
+
+impl GroupPermit {
+    /// Gives the share back to the group, once the message is settled.
+    pub(crate) fn release(&self) {
+        let mut permits = self.permits.lock().unwrap_or_else(|err| err.into_inner());
+        permits.clear();
+    }
+}
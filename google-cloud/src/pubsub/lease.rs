@@ -0,0 +1,278 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, Weak};
+use std::time::Duration as StdDuration;
+
+use chrono::Duration;
+use tokio::time::Instant;
+
+use crate::error::WithContext;
+use crate::pubsub::{api, Client, Error};
+use crate::tasks;
+
+/// Represents the configuration of the automatic extension of the leases of received messages.
+///
+/// The acknowledgement deadline of each received message is periodically extended until it gets acknowledged,
+/// by the time the 99th percentile of the observed processing times (from reception to acknowledgement) takes,
+/// bounded by the minimum and maximum deadlines.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LeaseConfig {
+    pub(crate) min_deadline: Duration,
+    pub(crate) max_deadline: Duration,
+    pub(crate) max_extension: Duration,
+}
+
+impl LeaseConfig {
+    /// Set the minimum acknowledgement deadline (no lower than 10 seconds).
+    ///
+    /// It is also the deadline used until a processing time has been observed.
+    pub fn min_deadline(mut self, duration: Duration) -> LeaseConfig {
+        self.min_deadline = duration;
+        self
+    }
+
+    /// Set the maximum acknowledgement deadline (no higher than 10 minutes).
+    pub fn max_deadline(mut self, duration: Duration) -> LeaseConfig {
+        self.max_deadline = duration;
+        self
+    }
+
+    /// Set for how long a message keeps getting extended, after which it is left for redelivery.
+    pub fn max_extension(mut self, duration: Duration) -> LeaseConfig {
+        self.max_extension = duration;
+        self
+    }
+}
+
+impl Default for LeaseConfig {
+    fn default() -> LeaseConfig {
+        LeaseConfig {
+            min_deadline: Duration::seconds(10),
+            max_deadline: Duration::minutes(10),
+            max_extension: Duration::hours(1),
+        }
+    }
+}
+
+/// Tracks the distribution of processing times, with a precision of one second.
+#[derive(Debug, Clone)]
+pub(crate) struct Distribution {
+    buckets: Vec<u64>,
+    count: u64,
+}
+
+impl Distribution {
+    /// Creates an empty distribution, clipping the values above `max_secs`.
+    pub(crate) fn new(max_secs: u64) -> Distribution {
+        Distribution {
+            buckets: vec![0; max_secs as usize + 1],
+            count: 0,
+        }
+    }
+
+    /// Records a processing time, rounded up to the second.
+    pub(crate) fn record(&mut self, elapsed: StdDuration) {
+        let secs = elapsed.as_secs() + u64::from(elapsed.subsec_nanos() > 0);
+        let idx = (secs as usize).min(self.buckets.len() - 1);
+        self.buckets[idx] += 1;
+        self.count += 1;
+    }
+
+    /// The smallest number of seconds within which the given fraction of the processing times falls.
+    pub(crate) fn percentile(&self, fraction: f64) -> Option<u64> {
+        if self.count == 0 {
+            return None;
+        }
+        let rank = ((self.count as f64) * fraction).ceil().max(1.0) as u64;
+        let mut seen = 0;
+        for (secs, count) in self.buckets.iter().enumerate() {
+            seen += count;
+            if seen >= rank {
+                return Some(secs as u64);
+            }
+        }
+
+        Some(self.buckets.len() as u64 - 1)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Lease {
+    received: Instant,
+    expires: Instant,
+}
+
+/// The leases of the outstanding messages of a subscription, and when they need extending.
+pub(crate) struct LeaseState {
+    config: LeaseConfig,
+    latencies: Distribution,
+    leases: HashMap<String, Lease>,
+}
+
+impl LeaseState {
+    //? Leases are extended ahead of their expiry, to account for the latency of the extension request.
+    pub(crate) const EXTENSION_MARGIN: StdDuration = StdDuration::from_secs(5);
+
+    pub(crate) fn new(config: LeaseConfig) -> LeaseState {
+        let max_secs = config.max_deadline.num_seconds().max(1) as u64;
+        LeaseState {
+            config,
+            latencies: Distribution::new(max_secs),
+            leases: HashMap::new(),
+        }
+    }
+
+    /// The deadline to extend leases by, based on the observed processing times.
+    pub(crate) fn deadline(&self) -> StdDuration {
+        let min = self.config.min_deadline.num_seconds().max(0) as u64;
+        let max = self.config.max_deadline.num_seconds().max(0) as u64;
+        let secs = self.latencies.percentile(0.99).unwrap_or(min);
+
+        StdDuration::from_secs(secs.max(min).min(max))
+    }
+
+    /// Starts tracking the leases of newly received messages, returning the deadline to set on them.
+    pub(crate) fn register(&mut self, ack_ids: &[String], now: Instant) -> StdDuration {
+        let deadline = self.deadline();
+        for ack_id in ack_ids {
+            let lease = Lease {
+                received: now,
+                expires: now + deadline,
+            };
+            self.leases.insert(ack_id.clone(), lease);
+        }
+
+        deadline
+    }
+
+    /// Stops tracking the lease of a message, recording its processing time if it was acknowledged.
+    pub(crate) fn complete(&mut self, ack_id: &str, acked: bool, now: Instant) {
+        if let Some(lease) = self.leases.remove(ack_id) {
+            if acked {
+                self.latencies.record(now.duration_since(lease.received));
+            }
+        }
+    }
+
+    /// Takes the leases about to expire, pushing back their expiry by the returned deadline.
+    ///
+    /// The leases held for longer than the maximum extension are dropped instead.
+    pub(crate) fn take_due(&mut self, now: Instant) -> (Vec<String>, StdDuration) {
+        let deadline = self.deadline();
+        let max_extension = self.config.max_extension.to_std().unwrap_or_default();
+        self.leases
+            .retain(|_, lease| now.duration_since(lease.received) < max_extension);
+
+        let mut due = Vec::new();
+        for (ack_id, lease) in self.leases.iter_mut() {
+            if lease.expires <= now + LeaseState::EXTENSION_MARGIN {
+                lease.expires = now + deadline;
+                due.push(ack_id.clone());
+            }
+        }
+
+        (due, deadline)
+    }
+
+    /// When the next lease will need extending, if any.
+    pub(crate) fn next_due(&self) -> Option<Instant> {
+        let expires = self.leases.values().map(|lease| lease.expires).min()?;
+        Some(
+            expires
+                .checked_sub(LeaseState::EXTENSION_MARGIN)
+                .unwrap_or(expires),
+        )
+    }
+}
+
+/// The lease manager of a subscription, shared with the messages it received.
+pub(crate) struct Leases {
+    pub(crate) state: Mutex<LeaseState>,
+    pub(crate) started: AtomicBool,
+}
+
+impl Leases {
+    //? The size of a request is limited, so the extensions are sent in batches.
+    pub(crate) const MAX_ACK_IDS: usize = 2500;
+
+    pub(crate) fn new(config: LeaseConfig) -> Leases {
+        Leases {
+            state: Mutex::new(LeaseState::new(config)),
+            started: AtomicBool::new(false),
+        }
+    }
+
+    /// Starts leasing newly received messages, setting their initial deadline.
+    pub(crate) async fn register(
+        self: &Arc<Leases>,
+        client: &mut Client,
+        subscription: &str,
+        ack_ids: Vec<String>,
+    ) -> Result<(), Error> {
+        if ack_ids.is_empty() {
+            return Ok(());
+        }
+        let deadline = self
+            .state
+            .lock()
+            .unwrap()
+            .register(&ack_ids, Instant::now());
+        if !self.started.swap(true, Ordering::SeqCst) {
+            let task = extend_leases(
+                Arc::downgrade(self),
+                client.clone(),
+                subscription.to_string(),
+            );
+            tasks::spawn(tasks::PUBSUB_LEASE_EXTENDER, task);
+        }
+
+        modify_deadlines(client, subscription, ack_ids, deadline).await
+    }
+
+    /// Stops leasing a message.
+    pub(crate) fn complete(&self, ack_id: &str, acked: bool) {
+        let mut state = self.state.lock().unwrap();
+        state.complete(ack_id, acked, Instant::now());
+    }
+}
+
+/// Extends the leases as they come due, until the lease manager gets dropped.
+async fn extend_leases(leases: Weak<Leases>, mut client: Client, subscription: String) {
+    //? Bounds the delay before noticing new leases, or the lease manager being dropped.
+    const MAX_SLEEP: StdDuration = StdDuration::from_secs(1);
+
+    while let Some(leases) = leases.upgrade() {
+        let (due, deadline) = leases.state.lock().unwrap().take_due(Instant::now());
+        if !due.is_empty() {
+            //? Extensions are best-effort: a failed one leaves the message for redelivery.
+            let _ = modify_deadlines(&mut client, &subscription, due, deadline).await;
+        }
+        let next_due = leases.state.lock().unwrap().next_due();
+        drop(leases);
+
+        let max_wakeup = Instant::now() + MAX_SLEEP;
+        let wakeup = next_due.map_or(max_wakeup, |next_due| next_due.min(max_wakeup));
+        tokio::time::sleep_until(wakeup).await;
+    }
+}
+
+/// Sets the acknowledgement deadline of the given messages.
+pub(crate) async fn modify_deadlines(
+    client: &mut Client,
+    subscription: &str,
+    ack_ids: Vec<String>,
+    deadline: StdDuration,
+) -> Result<(), Error> {
+    for ack_ids in ack_ids.chunks(Leases::MAX_ACK_IDS) {
+        let request = api::ModifyAckDeadlineRequest {
+            subscription: subscription.to_string(),
+            ack_ids: ack_ids.to_vec(),
+            ack_deadline_seconds: deadline.as_secs() as i32,
+        };
+        let request = client.construct_request(request).await?;
+        let response = client.subscriber.modify_ack_deadline(request).await;
+        response.context("ModifyAckDeadline", subscription)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,146 @@
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+/// A token bucket, refilled continuously at its rate and holding up to a second worth of tokens.
+#[derive(Debug, Clone, PartialEq)]
+struct Bucket {
+    rate: f64,
+    tokens: f64,
+    updated: Instant,
+}
+
+impl Bucket {
+    fn new(rate: f64, now: Instant) -> Bucket {
+        Bucket {
+            rate,
+            tokens: rate,
+            updated: now,
+        }
+    }
+
+    /// Takes tokens from the bucket, returning how long to wait for before they are actually available.
+    ///
+    /// The bucket goes into debt when short of tokens, so that the waits of concurrent reservations add up.
+    fn reserve(&mut self, amount: f64, now: Instant) -> Duration {
+        let elapsed = now.saturating_duration_since(self.updated).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.rate).min(self.rate);
+        self.updated = self.updated.max(now);
+        self.tokens -= amount;
+        if self.tokens >= 0.0 {
+            Duration::from_secs(0)
+        } else {
+            Duration::from_secs_f64(-self.tokens / self.rate)
+        }
+    }
+}
+
+#[derive(Debug)]
+struct LimiterState {
+    messages: Option<Bucket>,
+    bytes: Option<Bucket>,
+    throttled_requests: u64,
+    throttle_time: Duration,
+}
+
+/// Limits the rate at which messages get published, in messages and/or bytes per second.
+///
+/// Publish requests exceeding the rate are delayed until they fit within it,
+/// allowing bursts of up to a second worth of messages.
+/// A single request larger than that is let through, and the following ones are delayed accordingly.
+///
+/// The limiter is cheaply cloneable, its clones sharing the same limits,
+/// so that it can be shared by several clients to stay under a project-wide quota.
+///
+/// ```no_run
+/// # use google_cloud::pubsub::{Client, Error, RateLimiter};
+/// # async fn run(client: Client) -> Result<(), Error> {
+/// let limiter = RateLimiter::new()
+///     .messages_per_second(500)
+///     .bytes_per_second(1_000_000);
+/// let mut client = client.rate_limit(limiter.clone());
+/// let mut topic = client.topic("events").await?.unwrap();
+/// topic.publish("hello").await?;
+/// println!("throttled for {:?}", limiter.throttle_time());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    state: Arc<Mutex<LimiterState>>,
+}
+
+impl Default for RateLimiter {
+    fn default() -> RateLimiter {
+        RateLimiter::new()
+    }
+}
+
+impl RateLimiter {
+    /// Create a new limiter, without any limit yet.
+    pub fn new() -> RateLimiter {
+        RateLimiter {
+            state: Arc::new(Mutex::new(LimiterState {
+                messages: None,
+                bytes: None,
+                throttled_requests: 0,
+                throttle_time: Duration::from_secs(0),
+            })),
+        }
+    }
+
+    /// Limit the number of messages published per second.
+    pub fn messages_per_second(self, rate: u32) -> RateLimiter {
+        let bucket = Bucket::new(f64::from(rate.max(1)), Instant::now());
+        self.lock().messages = Some(bucket);
+        self
+    }
+
+    /// Limit the number of bytes published per second (as encoded in the publish requests).
+    pub fn bytes_per_second(self, rate: u64) -> RateLimiter {
+        let bucket = Bucket::new(rate.max(1) as f64, Instant::now());
+        self.lock().bytes = Some(bucket);
+        self
+    }
+
+    /// The number of publish requests delayed so far.
+    pub fn throttled_requests(&self) -> u64 {
+        self.lock().throttled_requests
+    }
+
+    /// The total time publish requests have been delayed for so far.
+    pub fn throttle_time(&self) -> Duration {
+        self.lock().throttle_time
+    }
+
+    fn lock(&self) -> MutexGuard<'_, LimiterState> {
+        self.state.lock().unwrap_or_else(|err| err.into_inner())
+    }
+
+    /// Reserves the capacity to publish the given messages, returning how long to wait for before sending them.
+    pub(crate) fn reserve(&self, messages: usize, bytes: usize, now: Instant) -> Duration {
+        let mut state = self.lock();
+        let messages = state
+            .messages
+            .as_mut()
+            .map(|bucket| bucket.reserve(messages as f64, now));
+        let bytes = state
+            .bytes
+            .as_mut()
+            .map(|bucket| bucket.reserve(bytes as f64, now));
+        let delay = messages.into_iter().chain(bytes).max();
+        let delay = delay.unwrap_or_else(|| Duration::from_secs(0));
+        if delay > Duration::from_secs(0) {
+            state.throttled_requests += 1;
+            state.throttle_time += delay;
+        }
+        delay
+    }
+
+    /// Waits until the given messages can be published within the limits.
+    pub(crate) async fn acquire(&self, messages: usize, bytes: usize) {
+        let delay = self.reserve(messages, bytes, Instant::now());
+        if delay > Duration::from_secs(0) {
+            tokio::time::sleep(delay).await;
+        }
+    }
+}
@@ -1,20 +1,128 @@
 use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
 
+use bytes::Bytes;
+
+use crate::error::WithContext;
 use crate::pubsub::api;
-use crate::pubsub::{Client, Error};
+#[cfg(feature = "pubsub-metrics")]
+use crate::pubsub::MessageMetrics;
+use crate::pubsub::{
+    run_failure_hooks, run_hooks, AckFailureHook, AckHook, AckedMessage, Client, Codec, CodecError,
+    Error, GroupPermit, Leases, Redacted, SchemaEncoding,
+};
+
+/// Represents a message to be published onto a topic.
+///
+/// Anything convertible into `Bytes` (like `Vec<u8>`, `String` or `&'static str`) can be used wherever
+/// a `PublishMessage` is expected, as a message without attributes.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct PublishMessage {
+    pub(crate) data: Bytes,
+    pub(crate) attributes: HashMap<String, String>,
+    pub(crate) ordering_key: String,
+}
+
+impl PublishMessage {
+    /// Create a new message with the given payload data.
+    pub fn new(data: impl Into<Bytes>) -> PublishMessage {
+        PublishMessage {
+            data: data.into(),
+            attributes: HashMap::new(),
+            ordering_key: String::new(),
+        }
+    }
+
+    /// Create a new message with the given value as payload, serialized using the given codec.
+    pub fn encode<T, C: Codec<T>>(value: &T) -> Result<PublishMessage, CodecError> {
+        let data = C::encode(value)?;
+        Ok(PublishMessage::new(data))
+    }
+
+    /// Attach an attribute to the message.
+    pub fn attribute(
+        mut self,
+        name: impl Into<String>,
+        value: impl Into<String>,
+    ) -> PublishMessage {
+        self.attributes.insert(name.into(), value.into());
+        self
+    }
+
+    /// Attach multiple attributes to the message.
+    pub fn attributes<K, V>(
+        mut self,
+        attributes: impl IntoIterator<Item = (K, V)>,
+    ) -> PublishMessage
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        let attributes = attributes
+            .into_iter()
+            .map(|(name, value)| (name.into(), value.into()));
+        self.attributes.extend(attributes);
+        self
+    }
+
+    /// Set the ordering key of the message.
+    ///
+    /// Messages sharing the same ordering key are delivered in order to subscriptions with message ordering enabled.
+    pub fn ordering_key(mut self, key: impl Into<String>) -> PublishMessage {
+        self.ordering_key = key.into();
+        self
+    }
+}
+
+impl<T: Into<Bytes>> From<T> for PublishMessage {
+    fn from(data: T) -> PublishMessage {
+        PublishMessage::new(data)
+    }
+}
+
+impl From<PublishMessage> for api::PubsubMessage {
+    fn from(message: PublishMessage) -> api::PubsubMessage {
+        api::PubsubMessage {
+            data: message.data,
+            attributes: message.attributes,
+            message_id: String::new(),
+            ordering_key: message.ordering_key,
+            publish_time: None,
+        }
+    }
+}
 
 /// Represents a received message (from a subscription).
 #[derive(Clone)]
 pub struct Message {
     pub(crate) client: Client,
-    pub(crate) data: Vec<u8>,
+    pub(crate) data: Bytes,
     pub(crate) attributes: HashMap<String, String>,
     pub(crate) ack_id: String,
+    pub(crate) leases: Option<Arc<Leases>>,
+    pub(crate) ack_hooks: Vec<AckHook>,
+    pub(crate) ack_failure_hooks: Vec<AckFailureHook>,
+    #[cfg(feature = "pubsub-metrics")]
+    pub(crate) metrics: Option<MessageMetrics>,
+    pub(crate) permit: Option<GroupPermit>,
     pub(crate) message_id: String,
     pub(crate) publish_time: chrono::NaiveDateTime,
     pub(crate) subscription_name: String,
 }
 
+//? The contents of received messages are only ever printed redacted.
+impl fmt::Debug for Message {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Message")
+            .field("id", &self.message_id)
+            .field("contents", &self.redacted())
+            .field("publish_time", &self.publish_time)
+            .field("subscription", &self.subscription_name)
+            .finish()
+    }
+}
+
 impl Message {
     /// The message's unique ID.
     pub fn id(&self) -> &str {
@@ -23,7 +131,19 @@ impl Message {
 
     /// The payload data of the message.
     pub fn data(&self) -> &[u8] {
-        self.data.as_slice()
+        self.data.as_ref()
+    }
+
+    /// The payload data of the message, as a cheaply cloneable buffer.
+    ///
+    /// The returned buffer shares its memory with the received message, no copy is made.
+    pub fn bytes(&self) -> Bytes {
+        self.data.clone()
+    }
+
+    /// Consume the message and return its payload data as an owned `Vec<u8>`.
+    pub fn into_data(self) -> Vec<u8> {
+        self.data.into()
     }
 
     /// The attributes of the message.
@@ -31,11 +151,38 @@ impl Message {
         &self.attributes
     }
 
+    /// The contents of the message, redacted according to the policy of its client (see `Client::redact_with`),
+    /// to log them.
+    pub fn redacted(&self) -> Redacted<'_> {
+        let redactor = &self.client.redactor;
+        redactor.redact(self.data.as_ref(), &self.attributes)
+    }
+
     /// The publication time of the message.
     pub fn publish_time(&self) -> chrono::NaiveDateTime {
         self.publish_time
     }
 
+    /// The encoding of the message, if it was published onto a topic with a schema.
+    pub fn schema_encoding(&self) -> Option<SchemaEncoding> {
+        let encoding = self.attributes.get(SchemaEncoding::ATTRIBUTE)?;
+        SchemaEncoding::from_attribute(encoding)
+    }
+
+    /// Deserialize the payload data of the message, using the given codec.
+    ///
+    /// Fails without attempting to decode the data if the message's schema encoding
+    /// (see `Message::schema_encoding`) does not match the one of the codec.
+    pub fn decode<T, C: Codec<T>>(&self) -> Result<T, CodecError> {
+        match self.schema_encoding() {
+            Some(got) if got != C::ENCODING => Err(CodecError::EncodingMismatch {
+                expected: C::ENCODING,
+                got,
+            }),
+            _ => C::decode(self.data()),
+        }
+    }
+
     /// Indicate that this client processed or will process the message successfully.
     ///
     /// If a message isn't acknowledged, it will be redelivered to other subscribers.
@@ -45,7 +192,25 @@ impl Message {
             ack_ids: vec![self.ack_id.clone()],
         };
         let request = self.client.construct_request(request).await?;
-        self.client.subscriber.acknowledge(request).await?;
+        let response = self.client.subscriber.acknowledge(request).await;
+        let response = response.context("Acknowledge", &self.subscription_name);
+        if let Err(err) = response {
+            //? The message is given up on, so its lease must not be extended anymore.
+            if run_failure_hooks(&self.ack_failure_hooks, vec![self.acked()], &err) {
+                if let Some(leases) = self.leases.as_ref() {
+                    leases.complete(&self.ack_id, false);
+                }
+                self.release();
+                self.record_settled();
+            }
+            return Err(err);
+        }
+        if let Some(leases) = self.leases.as_ref() {
+            leases.complete(&self.ack_id, true);
+        }
+        self.release();
+        self.record_acked();
+        run_hooks(&self.ack_hooks, &[self.acked()]);
 
         Ok(())
     }
@@ -60,8 +225,49 @@ impl Message {
             ack_deadline_seconds: 0,
         };
         let request = self.client.construct_request(request).await?;
-        self.client.subscriber.modify_ack_deadline(request).await?;
+        let response = self.client.subscriber.modify_ack_deadline(request).await;
+        response.context("ModifyAckDeadline", &self.subscription_name)?;
+        if let Some(leases) = self.leases.as_ref() {
+            leases.complete(&self.ack_id, false);
+        }
+        self.release();
+        self.record_settled();
 
         Ok(())
     }
+
+    /// Gives the share of the message back to its concurrency group, if any, once settled.
+    pub(crate) fn release(&self) {
+        if let Some(permit) = self.permit.as_ref() {
+            permit.release();
+        }
+    }
+
+    /// Records the acknowledgement of the message in the subscriber metrics, if enabled.
+    pub(crate) fn record_acked(&self) {
+        #[cfg(feature = "pubsub-metrics")]
+        {
+            if let Some(metrics) = self.metrics.as_ref() {
+                metrics.acked();
+            }
+        }
+    }
+
+    /// Records that the message was given up on in the subscriber metrics, if enabled.
+    pub(crate) fn record_settled(&self) {
+        #[cfg(feature = "pubsub-metrics")]
+        {
+            if let Some(metrics) = self.metrics.as_ref() {
+                metrics.settled();
+            }
+        }
+    }
+
+    /// Describes the message to the checkpoint hooks, once acknowledged.
+    pub(crate) fn acked(&self) -> AckedMessage {
+        AckedMessage {
+            id: self.message_id.clone(),
+            publish_time: self.publish_time,
+        }
+    }
 }
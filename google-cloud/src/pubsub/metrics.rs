@@ -0,0 +1,287 @@
+use std::collections::BTreeMap;
+use std::fmt::{self, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// The upper bounds of the ack latency histogram buckets, in seconds.
+const LATENCY_BUCKETS: [f64; 12] = [
+    0.01, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0, 60.0, 300.0,
+];
+
+/// A family of metrics with a single value per subscription: its name, type, help text and value.
+type MetricFamily = (
+    &'static str,
+    &'static str,
+    &'static str,
+    fn(&SubscriptionSeries) -> u64,
+);
+
+#[derive(Debug, Default, Clone, PartialEq)]
+struct SubscriptionSeries {
+    received: u64,
+    redelivered: u64,
+    outstanding: u64,
+    pull_restarts: u64,
+    latency_buckets: [u64; LATENCY_BUCKETS.len()],
+    latency_count: u64,
+    latency_sum: f64,
+}
+
+/// A registry of metrics about the messages received from subscriptions, in the OpenMetrics text format.
+///
+/// The metrics are labeled by subscription ID:
+/// - `pubsub_subscriber_ack_latency_seconds` (histogram): the time between receiving a message and acknowledging it.
+/// - `pubsub_subscriber_received_messages_total` (counter): the messages received.
+/// - `pubsub_subscriber_redelivered_messages_total` (counter): the messages received more than once,
+///   which Pub/Sub only reports for subscriptions with a dead-letter policy.
+/// - `pubsub_subscriber_outstanding_messages` (gauge): the messages received, and neither acknowledged,
+///   rejected nor dropped yet.
+/// - `pubsub_subscriber_pull_restarts_total` (counter): the pull requests that failed and were sent again.
+///
+/// The registry is cheaply cloneable, its clones sharing the same metrics.
+/// It can be served as is by an existing metrics endpoint:
+///
+/// ```no_run
+/// # use google_cloud::pubsub::{Client, Error, SubscriberMetrics};
+/// # async fn run(mut client: Client) -> Result<(), Error> {
+/// let metrics = SubscriberMetrics::new();
+/// let subscription = client.subscription("orders").await?.unwrap();
+/// let mut subscription = subscription.metrics(metrics.clone());
+///
+/// // In the handler of the metrics endpoint (such as `GET /metrics`):
+/// let content_type = SubscriberMetrics::CONTENT_TYPE;
+/// let body = metrics.encode();
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct SubscriberMetrics {
+    series: Arc<Mutex<BTreeMap<String, SubscriptionSeries>>>,
+}
+
+impl SubscriberMetrics {
+    /// The content type of the encoded metrics.
+    pub const CONTENT_TYPE: &'static str =
+        "application/openmetrics-text; version=1.0.0; charset=utf-8";
+
+    /// Create a new registry, without any metrics yet.
+    pub fn new() -> SubscriberMetrics {
+        SubscriberMetrics::default()
+    }
+
+    /// Encode the metrics in the OpenMetrics text format, terminated by `# EOF`.
+    pub fn encode(&self) -> String {
+        let mut output = String::new();
+        //? Writing into a `String` cannot fail.
+        let _ = self.encode_families(&mut output);
+        output.push_str("# EOF\n");
+        output
+    }
+
+    /// Write the metric families, without the terminating `# EOF`,
+    /// to combine them with the ones of another registry in a single exposition.
+    pub fn encode_families(&self, output: &mut impl Write) -> fmt::Result {
+        let series = self.lock().clone();
+
+        let name = "pubsub_subscriber_ack_latency_seconds";
+        writeln!(output, "# TYPE {} histogram", name)?;
+        writeln!(output, "# UNIT {} seconds", name)?;
+        writeln!(
+            output,
+            "# HELP {} Time between receiving and acknowledging messages.",
+            name
+        )?;
+        for (id, series) in series.iter() {
+            let label = escape_label(id);
+            //? Buckets are cumulative, counting every observation up to their bound.
+            let mut cumulative = 0;
+            for (bound, count) in LATENCY_BUCKETS.iter().zip(series.latency_buckets.iter()) {
+                cumulative += count;
+                writeln!(
+                    output,
+                    "{}_bucket{{subscription=\"{}\",le=\"{}\"}} {}",
+                    name,
+                    label,
+                    format_float(*bound),
+                    cumulative
+                )?;
+            }
+            writeln!(
+                output,
+                "{}_bucket{{subscription=\"{}\",le=\"+Inf\"}} {}",
+                name, label, series.latency_count
+            )?;
+            writeln!(
+                output,
+                "{}_sum{{subscription=\"{}\"}} {}",
+                name,
+                label,
+                format_float(series.latency_sum)
+            )?;
+            writeln!(
+                output,
+                "{}_count{{subscription=\"{}\"}} {}",
+                name, label, series.latency_count
+            )?;
+        }
+
+        let families: [MetricFamily; 4] = [
+            (
+                "pubsub_subscriber_received_messages",
+                "counter",
+                "Messages received.",
+                |series| series.received,
+            ),
+            (
+                "pubsub_subscriber_redelivered_messages",
+                "counter",
+                "Messages received more than once.",
+                |series| series.redelivered,
+            ),
+            (
+                "pubsub_subscriber_outstanding_messages",
+                "gauge",
+                "Messages received and not settled yet.",
+                |series| series.outstanding,
+            ),
+            (
+                "pubsub_subscriber_pull_restarts",
+                "counter",
+                "Pull requests that failed and were sent again.",
+                |series| series.pull_restarts,
+            ),
+        ];
+        for (name, kind, help, value) in families.iter() {
+            writeln!(output, "# TYPE {} {}", name, kind)?;
+            writeln!(output, "# HELP {} {}", name, help)?;
+            let suffix = if *kind == "counter" { "_total" } else { "" };
+            for (id, series) in series.iter() {
+                writeln!(
+                    output,
+                    "{}{}{{subscription=\"{}\"}} {}",
+                    name,
+                    suffix,
+                    escape_label(id),
+                    value(series)
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, BTreeMap<String, SubscriptionSeries>> {
+        self.series.lock().unwrap_or_else(|err| err.into_inner())
+    }
+
+    fn update(&self, subscription: &str, update: impl FnOnce(&mut SubscriptionSeries)) {
+        let id = subscription.rsplit('/').next().unwrap_or(subscription);
+        let mut series = self.lock();
+        update(series.entry(String::from(id)).or_default());
+    }
+
+    /// Records a message handed out by a subscription, and tracks it until settled.
+    pub(crate) fn received(&self, subscription: &str, delivery_attempt: i32) -> MessageMetrics {
+        self.update(subscription, |series| {
+            series.received += 1;
+            series.outstanding += 1;
+            if delivery_attempt > 1 {
+                series.redelivered += 1;
+            }
+        });
+        MessageMetrics {
+            received_at: Instant::now(),
+            outstanding: Arc::new(Outstanding {
+                metrics: self.clone(),
+                subscription: String::from(subscription),
+                settled: AtomicBool::new(false),
+            }),
+        }
+    }
+
+    /// Records a failed pull request, about to be sent again.
+    pub(crate) fn pull_restarted(&self, subscription: &str) {
+        self.update(subscription, |series| series.pull_restarts += 1);
+    }
+
+    fn acked(&self, subscription: &str, latency: Duration) {
+        let latency = latency.as_secs_f64();
+        self.update(subscription, |series| {
+            let bucket = LATENCY_BUCKETS.iter().position(|bound| latency <= *bound);
+            if let Some(bucket) = bucket {
+                series.latency_buckets[bucket] += 1;
+            }
+            series.latency_count += 1;
+            series.latency_sum += latency;
+        });
+    }
+}
+
+/// Keeps a received message counted as outstanding, until settled or dropped (along with all its clones).
+#[derive(Debug)]
+struct Outstanding {
+    metrics: SubscriberMetrics,
+    subscription: String,
+    settled: AtomicBool,
+}
+
+impl Outstanding {
+    fn settle(&self) -> bool {
+        let first = !self.settled.swap(true, Ordering::SeqCst);
+        if first {
+            let metrics = &self.metrics;
+            metrics.update(&self.subscription, |series| {
+                series.outstanding = series.outstanding.saturating_sub(1)
+            });
+        }
+        first
+    }
+}
+
+impl Drop for Outstanding {
+    fn drop(&mut self) {
+        self.settle();
+    }
+}
+
+/// Tracks the metrics of a received message.
+#[derive(Debug, Clone)]
+pub(crate) struct MessageMetrics {
+    received_at: Instant,
+    outstanding: Arc<Outstanding>,
+}
+
+impl MessageMetrics {
+    /// Records the acknowledgement of the message (only the first one, for messages acknowledged repeatedly).
+    pub(crate) fn acked(&self) {
+        if self.outstanding.settle() {
+            let outstanding = &self.outstanding;
+            let latency = self.received_at.elapsed();
+            outstanding
+                .metrics
+                .acked(&outstanding.subscription, latency);
+        }
+    }
+
+    /// Records that the message was given up on, without being acknowledged.
+    pub(crate) fn settled(&self) {
+        self.outstanding.settle();
+    }
+}
+
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+fn format_float(value: f64) -> String {
+    //? OpenMetrics expects a decimal point in the bucket bounds, which `{}` leaves out for whole numbers.
+    if value.fract() == 0.0 {
+        format!("{:.1}", value)
+    } else {
+        format!("{}", value)
+    }
+}
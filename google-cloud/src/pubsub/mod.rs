@@ -1,14 +1,38 @@
+mod audit;
+mod checkpoint;
 mod client;
+mod codec;
+mod group;
+mod lease;
+mod limiter;
 mod message;
+#[cfg(feature = "pubsub-metrics")]
+mod metrics;
+mod preset;
+mod redact;
+mod setup;
 mod subscription;
+mod teardown;
 mod topic;
-mod api {
+pub(crate) mod api {
     include!("api/google.pubsub.v1.rs");
 }
 
+pub use self::audit::*;
+pub use self::checkpoint::*;
 pub use self::client::*;
+pub use self::codec::*;
+pub use self::group::*;
+pub use self::lease::*;
+pub use self::limiter::*;
 pub use self::message::*;
+#[cfg(feature = "pubsub-metrics")]
+pub use self::metrics::*;
+pub use self::preset::*;
+pub use self::redact::*;
+pub use self::setup::*;
 pub use self::subscription::*;
+pub use self::teardown::*;
 pub use self::topic::*;
 
 /// The error type for the PubSub module.
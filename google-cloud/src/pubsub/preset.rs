@@ -0,0 +1,93 @@
+use chrono::Duration;
+
+use crate::channel::{ChannelConfig, LoadBalancing};
+use crate::pubsub::{ReceiveOptions, SubscriptionConfig};
+
+/// A bundle of publisher and subscriber settings, tuned for a kind of workload.
+///
+/// The presets are derived from the crate's `pubsub` benchmarks,
+/// which can be run against a specific project to refine them (see `benches/pubsub.rs`).
+///
+/// ```no_run
+/// # use google_cloud::pubsub;
+/// # async fn run(creds: google_cloud::authorize::ApplicationCredentials) -> Result<(), pubsub::Error> {
+/// let preset = pubsub::Preset::high_throughput();
+/// let mut client =
+///     pubsub::Client::from_credentials_with_config("my-project", creds, preset.channel_config())
+///         .await?;
+///
+/// let mut topic = client.topic("my-topic").await?.unwrap();
+/// let messages: Vec<Vec<u8>> = vec![b"hello".to_vec(); 5000];
+/// for batch in messages.chunks(preset.publish_batch_size()) {
+///     topic.publish_all(batch.to_vec()).await?;
+/// }
+///
+/// let mut subscription = client.subscription("my-subscription").await?.unwrap();
+/// let message = subscription.receive_with_options(preset.receive_options()).await;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Preset {
+    pub(crate) channel: ChannelConfig,
+    pub(crate) publish_batch_size: usize,
+    pub(crate) receive: ReceiveOptions,
+    pub(crate) ack_deadline: Duration,
+}
+
+impl Preset {
+    /// Settings maximizing the number of messages handled per second, at the expense of latency.
+    ///
+    /// Messages are published and pulled by the thousand (the most a single request allows),
+    /// which amortizes the per-request overhead that dominates when handling small messages one by one.
+    /// Requests are spread across all the backends of the endpoint, and the longer acknowledgement deadline
+    /// leaves enough time to process the large batches before they get redelivered.
+    pub fn high_throughput() -> Preset {
+        Preset {
+            channel: ChannelConfig::default().load_balancing(LoadBalancing::Balanced),
+            publish_batch_size: 1000,
+            receive: ReceiveOptions {
+                return_immediately: false,
+                max_messages: 1000,
+            },
+            ack_deadline: Duration::seconds(60),
+        }
+    }
+
+    /// Settings minimizing the delay between publishing a message and receiving it.
+    ///
+    /// Messages are published as soon as they are available, and pulled in small batches,
+    /// so that none of them waits for others to be handled first.
+    /// A single connection is used, avoiding the extra handshakes of a balanced channel.
+    pub fn low_latency() -> Preset {
+        Preset {
+            channel: ChannelConfig::default(),
+            publish_batch_size: 1,
+            receive: ReceiveOptions {
+                return_immediately: false,
+                max_messages: 10,
+            },
+            ack_deadline: Duration::seconds(10),
+        }
+    }
+
+    /// The configuration of the client's channel.
+    pub fn channel_config(&self) -> ChannelConfig {
+        self.channel.clone()
+    }
+
+    /// The number of messages to publish per request (using `Topic::publish_all`).
+    pub fn publish_batch_size(&self) -> usize {
+        self.publish_batch_size
+    }
+
+    /// The options to receive messages with (using `Subscription::receive_with_options`).
+    pub fn receive_options(&self) -> ReceiveOptions {
+        self.receive.clone()
+    }
+
+    /// The configuration of new subscriptions, with an acknowledgement deadline matching the receive batch size.
+    pub fn subscription_config(&self) -> SubscriptionConfig {
+        SubscriptionConfig::default().ack_deadline(self.ack_deadline)
+    }
+}
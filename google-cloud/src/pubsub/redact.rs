@@ -0,0 +1,140 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+/// Represents a policy redacting the contents of messages, before they get debug-printed or logged.
+///
+/// The clients apply their policy (see `Client::redact_with`) to the `Debug` output of received messages
+/// and of the messages reported to publish hooks, which can also log them through `OutgoingMessage::redacted`.
+///
+/// By default, the payload data is replaced by its size and the attributes are kept:
+///
+/// ```
+/// # use std::collections::HashMap;
+/// # use google_cloud::pubsub::Redactor;
+/// let redactor = Redactor::new().hash_data(true).drop_attributes("user-*");
+///
+/// let mut attributes = HashMap::new();
+/// attributes.insert(String::from("user-email"), String::from("jane@example.com"));
+/// attributes.insert(String::from("kind"), String::from("signup"));
+/// let redacted = format!("{:?}", redactor.redact(b"hello", &attributes));
+/// assert!(!redacted.contains("jane@example.com"));
+/// assert!(redacted.contains("signup"));
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Redactor {
+    pub(crate) hash_data: bool,
+    pub(crate) dropped_attributes: Vec<String>,
+}
+
+impl Redactor {
+    /// Construct a new policy, replacing the payload data by its size and keeping all the attributes.
+    pub fn new() -> Redactor {
+        Redactor::default()
+    }
+
+    /// Show the SHA-256 hash of the payload data instead of its size,
+    /// which allows to correlate the occurrences of a message without revealing it.
+    pub fn hash_data(mut self, enabled: bool) -> Redactor {
+        self.hash_data = enabled;
+        self
+    }
+
+    /// Drop the attributes whose names match the given pattern, in which `*` matches any sequence of characters
+    /// (so `"*"` drops all of them).
+    pub fn drop_attributes(mut self, pattern: impl Into<String>) -> Redactor {
+        self.dropped_attributes.push(pattern.into());
+        self
+    }
+
+    /// Whether the attribute of the given name gets dropped.
+    pub fn drops_attribute(&self, name: &str) -> bool {
+        let patterns = self.dropped_attributes.iter();
+        patterns
+            .map(String::as_str)
+            .any(|pattern| matches(pattern, name))
+    }
+
+    /// Redact the contents of a message.
+    pub fn redact<'a>(
+        &'a self,
+        data: &'a [u8],
+        attributes: &'a HashMap<String, String>,
+    ) -> Redacted<'a> {
+        Redacted {
+            redactor: self,
+            data,
+            attributes,
+        }
+    }
+}
+
+/// Represents the contents of a message redacted by a `Redactor`, to debug-print (or display) safely.
+#[derive(Clone, Copy)]
+pub struct Redacted<'a> {
+    redactor: &'a Redactor,
+    data: &'a [u8],
+    attributes: &'a HashMap<String, String>,
+}
+
+impl<'a> Redacted<'a> {
+    /// The attributes left after redaction.
+    pub fn attributes(&self) -> BTreeMap<&'a str, &'a str> {
+        self.attributes
+            .iter()
+            .filter(|(name, _)| !self.redactor.drops_attribute(name))
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+            .collect()
+    }
+
+    /// The description of the payload data, either its size or its hash.
+    pub fn data(&self) -> String {
+        if !self.redactor.hash_data {
+            return format!("<{} bytes>", self.data.len());
+        }
+        let digest = ring::digest::digest(&ring::digest::SHA256, self.data);
+        let hash: String = digest
+            .as_ref()
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect();
+        format!("sha256:{}", hash)
+    }
+}
+
+impl fmt::Debug for Redacted<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Redacted")
+            .field("data", &format_args!("{}", self.data()))
+            .field("attributes", &self.attributes())
+            .finish()
+    }
+}
+
+impl fmt::Display for Redacted<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {:?}", self.data(), self.attributes())
+    }
+}
+
+/// Matches a name against a pattern, in which `*` matches any sequence of characters.
+fn matches(pattern: &str, name: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+    let mut rest = match name.strip_prefix(first) {
+        Some(rest) => rest,
+        None => return false,
+    };
+    let parts: Vec<&str> = parts.collect();
+    let (last, middle) = match parts.split_last() {
+        Some(split) => split,
+        //? Without any `*`, the pattern must match the whole name.
+        None => return rest.is_empty(),
+    };
+    for part in middle {
+        match rest.find(part) {
+            Some(idx) => rest = &rest[idx + part.len()..],
+            None => return false,
+        }
+    }
+    rest.ends_with(last)
+}
@@ -0,0 +1,140 @@
+use std::collections::HashMap;
+
+use chrono::Duration;
+use serde::Deserialize;
+
+use crate::pubsub::{SubscriptionConfig, TopicConfig};
+
+/// Describes a set of topics and subscriptions, to create all at once using `Client::setup`.
+///
+/// It can be built programmatically, or loaded from a JSON document (see `Setup::from_json`),
+/// which makes it suitable for provisioning local development environments against the emulator.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Setup {
+    pub(crate) topics: Vec<(String, TopicConfig)>,
+    pub(crate) subscriptions: Vec<(String, String, SubscriptionConfig)>,
+}
+
+impl Setup {
+    /// Declare a topic.
+    pub fn topic(mut self, id: impl Into<String>, config: TopicConfig) -> Setup {
+        self.topics.push((id.into(), config));
+        self
+    }
+
+    /// Declare a subscription to a topic (which is not required to be declared as well).
+    pub fn subscription(
+        mut self,
+        topic_id: impl Into<String>,
+        id: impl Into<String>,
+        config: SubscriptionConfig,
+    ) -> Setup {
+        self.subscriptions
+            .push((topic_id.into(), id.into(), config));
+        self
+    }
+
+    /// Load a setup from a JSON document, such as:
+    ///
+    /// ```json
+    /// {
+    ///     "topics": [
+    ///         {
+    ///             "id": "events",
+    ///             "labels": { "team": "billing" },
+    ///             "subscriptions": [
+    ///                 { "id": "events-worker", "ack_deadline_seconds": 30, "retention_seconds": 86400 }
+    ///             ]
+    ///         }
+    ///     ]
+    /// }
+    /// ```
+    ///
+    /// All the fields but the IDs are optional.
+    pub fn from_json(data: &[u8]) -> Result<Setup, json::Error> {
+        let payload: SetupPayload = json::from_slice(data)?;
+        let mut setup = Setup::default();
+        for topic in payload.topics {
+            let config = topic
+                .labels
+                .into_iter()
+                .fold(TopicConfig::default(), |config, (name, value)| {
+                    config.label(name, value)
+                });
+            setup = setup.topic(topic.id.clone(), config);
+            for subscription in topic.subscriptions {
+                let mut config = SubscriptionConfig::default();
+                if let Some(seconds) = subscription.ack_deadline_seconds {
+                    config = config.ack_deadline(Duration::seconds(seconds));
+                }
+                if let Some(seconds) = subscription.retention_seconds {
+                    config = config.retain_messages(Duration::seconds(seconds));
+                }
+                let config = subscription
+                    .labels
+                    .into_iter()
+                    .fold(config, |config, (name, value)| config.label(name, value));
+                setup = setup.subscription(topic.id.clone(), subscription.id, config);
+            }
+        }
+
+        Ok(setup)
+    }
+}
+
+/// Describes the outcome of a setup, listing the IDs of the created or already existing resources.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct SetupReport {
+    pub(crate) created_topics: Vec<String>,
+    pub(crate) existing_topics: Vec<String>,
+    pub(crate) created_subscriptions: Vec<String>,
+    pub(crate) existing_subscriptions: Vec<String>,
+}
+
+impl SetupReport {
+    /// The topics that were created.
+    pub fn created_topics(&self) -> &[String] {
+        self.created_topics.as_slice()
+    }
+
+    /// The topics that already existed.
+    pub fn existing_topics(&self) -> &[String] {
+        self.existing_topics.as_slice()
+    }
+
+    /// The subscriptions that were created.
+    pub fn created_subscriptions(&self) -> &[String] {
+        self.created_subscriptions.as_slice()
+    }
+
+    /// The subscriptions that already existed.
+    pub fn existing_subscriptions(&self) -> &[String] {
+        self.existing_subscriptions.as_slice()
+    }
+}
+
+#[derive(Deserialize)]
+struct SetupPayload {
+    #[serde(default)]
+    topics: Vec<TopicPayload>,
+}
+
+#[derive(Deserialize)]
+struct TopicPayload {
+    id: String,
+    #[serde(default)]
+    labels: HashMap<String, String>,
+    #[serde(default)]
+    subscriptions: Vec<SubscriptionPayload>,
+}
+
+#[derive(Deserialize)]
+struct SubscriptionPayload {
+    id: String,
+    #[serde(default)]
+    ack_deadline_seconds: Option<i64>,
+    #[serde(default)]
+    retention_seconds: Option<i64>,
+    #[serde(default)]
+    labels: HashMap<String, String>,
+}
@@ -1,16 +1,35 @@
 use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
 
 use chrono::Duration;
 
+use crate::cancel::{self, CancellationToken};
+use crate::error::WithContext;
 use crate::pubsub::api;
-use crate::pubsub::{Client, Error, Message};
+#[cfg(feature = "pubsub-metrics")]
+use crate::pubsub::SubscriberMetrics;
+use crate::pubsub::{
+    modify_deadlines, run_failure_hooks, run_hooks, AckFailure, AckFailureHook, AckHook,
+    AckedMessage, Client, ConcurrencyGroup, Error, LeaseConfig, Leases, Message, PublishMessage,
+    Topic,
+};
+use crate::types;
 
 /// Represents the subscription's configuration.
+///
+/// The configuration of an existing subscription can be read back with `Subscription::config`,
+/// to compare it with the desired one.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SubscriptionConfig {
     pub(crate) ack_deadline_duration: Duration,
     pub(crate) message_retention_duration: Option<Duration>,
     pub(crate) labels: HashMap<String, String>,
+    pub(crate) message_ordering: bool,
+    pub(crate) push_config: Option<PushConfig>,
+    pub(crate) dead_letter_policy: Option<DeadLetterPolicy>,
+    pub(crate) retry_policy: Option<RetryPolicy>,
+    pub(crate) filter: Option<String>,
+    pub(crate) expiration: Option<Expiration>,
 }
 
 impl SubscriptionConfig {
@@ -35,6 +54,118 @@ impl SubscriptionConfig {
         self.labels.insert(name.into(), value.into());
         self
     }
+
+    /// Deliver the messages sharing an ordering key in the order they were published.
+    pub fn message_ordering(mut self, enabled: bool) -> SubscriptionConfig {
+        self.message_ordering = enabled;
+        self
+    }
+
+    /// Push the messages to an endpoint, instead of having them pulled.
+    pub fn push(mut self, config: PushConfig) -> SubscriptionConfig {
+        self.push_config = Some(config);
+        self
+    }
+
+    /// Forward the messages that could not be delivered to a dead letter topic.
+    pub fn dead_letter(mut self, policy: DeadLetterPolicy) -> SubscriptionConfig {
+        self.dead_letter_policy = Some(policy);
+        self
+    }
+
+    /// Delay the redelivery of messages, instead of redelivering them as soon as possible.
+    pub fn retry(mut self, policy: RetryPolicy) -> SubscriptionConfig {
+        self.retry_policy = Some(policy);
+        self
+    }
+
+    /// Only deliver the messages whose attributes match a filter (like `attributes.region = "eu"`).
+    pub fn filter(mut self, filter: impl Into<String>) -> SubscriptionConfig {
+        self.filter = Some(filter.into());
+        self
+    }
+
+    /// Set when the subscription gets deleted because of its inactivity.
+    pub fn expiration(mut self, expiration: Expiration) -> SubscriptionConfig {
+        self.expiration = Some(expiration);
+        self
+    }
+
+    /// Get the message acknowledgement duration.
+    pub fn get_ack_deadline(&self) -> Duration {
+        self.ack_deadline_duration
+    }
+
+    /// Get the message retention duration, if retention is enabled.
+    pub fn get_message_retention(&self) -> Option<Duration> {
+        self.message_retention_duration
+    }
+
+    /// Get the labels of the subscription.
+    pub fn get_labels(&self) -> &HashMap<String, String> {
+        &self.labels
+    }
+
+    /// Get whether messages are delivered in order of their ordering keys.
+    pub fn get_message_ordering(&self) -> bool {
+        self.message_ordering
+    }
+
+    /// Get the push configuration, if messages get pushed.
+    pub fn get_push(&self) -> Option<&PushConfig> {
+        self.push_config.as_ref()
+    }
+
+    /// Get the dead letter policy, if any.
+    pub fn get_dead_letter(&self) -> Option<&DeadLetterPolicy> {
+        self.dead_letter_policy.as_ref()
+    }
+
+    /// Get the retry policy, if any.
+    pub fn get_retry(&self) -> Option<&RetryPolicy> {
+        self.retry_policy.as_ref()
+    }
+
+    /// Get the message filter, if any.
+    pub fn get_filter(&self) -> Option<&str> {
+        self.filter.as_deref()
+    }
+
+    /// Get the expiration of the subscription, if not left to the default one of Pub/Sub (31 days).
+    pub fn get_expiration(&self) -> Option<Expiration> {
+        self.expiration
+    }
+
+    /// Converts the configuration into the subscription to create.
+    pub(crate) fn into_api(self, name: String, topic: String) -> api::Subscription {
+        api::Subscription {
+            name,
+            topic,
+            ack_deadline_seconds: self.ack_deadline_duration.num_seconds() as i32,
+            retain_acked_messages: self.message_retention_duration.is_some(),
+            message_retention_duration: self
+                .message_retention_duration
+                .map(types::chrono_to_duration),
+            labels: self.labels,
+            enable_message_ordering: self.message_ordering,
+            push_config: self.push_config.map(api::PushConfig::from),
+            expiration_policy: self.expiration.map(|expiration| api::ExpirationPolicy {
+                ttl: match expiration {
+                    Expiration::After(ttl) => Some(types::chrono_to_duration(ttl)),
+                    Expiration::Never => None,
+                },
+            }),
+            filter: self.filter.unwrap_or_default(),
+            dead_letter_policy: self.dead_letter_policy.map(|policy| api::DeadLetterPolicy {
+                dead_letter_topic: policy.topic,
+                max_delivery_attempts: policy.max_delivery_attempts,
+            }),
+            retry_policy: self.retry_policy.map(|policy| api::RetryPolicy {
+                minimum_backoff: Some(types::chrono_to_duration(policy.minimum_backoff)),
+                maximum_backoff: Some(types::chrono_to_duration(policy.maximum_backoff)),
+            }),
+        }
+    }
 }
 
 impl Default for SubscriptionConfig {
@@ -43,10 +174,257 @@ impl Default for SubscriptionConfig {
             ack_deadline_duration: Duration::seconds(10),
             message_retention_duration: None,
             labels: HashMap::new(),
+            message_ordering: false,
+            push_config: None,
+            dead_letter_policy: None,
+            retry_policy: None,
+            filter: None,
+            expiration: None,
+        }
+    }
+}
+
+impl From<api::Subscription> for SubscriptionConfig {
+    fn from(subscription: api::Subscription) -> SubscriptionConfig {
+        //? Pub/Sub always reports a retention duration, which only applies to acknowledged messages when enabled.
+        let message_retention_duration = if subscription.retain_acked_messages {
+            subscription
+                .message_retention_duration
+                .map(convert_api_duration)
+        } else {
+            None
+        };
+        SubscriptionConfig {
+            ack_deadline_duration: Duration::seconds(subscription.ack_deadline_seconds.into()),
+            message_retention_duration,
+            labels: subscription.labels,
+            message_ordering: subscription.enable_message_ordering,
+            //? An empty push configuration means that messages are pulled.
+            push_config: subscription
+                .push_config
+                .filter(|config| !config.push_endpoint.is_empty())
+                .map(PushConfig::from),
+            dead_letter_policy: subscription
+                .dead_letter_policy
+                .map(|policy| DeadLetterPolicy {
+                    topic: policy.dead_letter_topic,
+                    max_delivery_attempts: policy.max_delivery_attempts,
+                }),
+            retry_policy: subscription.retry_policy.map(|policy| RetryPolicy {
+                minimum_backoff: policy
+                    .minimum_backoff
+                    .map(convert_api_duration)
+                    .unwrap_or_else(|| RetryPolicy::default().minimum_backoff),
+                maximum_backoff: policy
+                    .maximum_backoff
+                    .map(convert_api_duration)
+                    .unwrap_or_else(|| RetryPolicy::default().maximum_backoff),
+            }),
+            filter: Some(subscription.filter).filter(|filter| !filter.is_empty()),
+            expiration: subscription
+                .expiration_policy
+                .map(|policy| match policy.ttl {
+                    Some(ttl) => Expiration::After(convert_api_duration(ttl)),
+                    None => Expiration::Never,
+                }),
         }
     }
 }
 
+/// Represents the configuration of the endpoint messages get pushed to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PushConfig {
+    pub(crate) endpoint: String,
+    pub(crate) attributes: HashMap<String, String>,
+    pub(crate) oidc_token: Option<(String, String)>,
+}
+
+impl PushConfig {
+    /// Push messages to the given URL (like `https://example.com/push`).
+    pub fn new(endpoint: impl Into<String>) -> PushConfig {
+        PushConfig {
+            endpoint: endpoint.into(),
+            attributes: HashMap::new(),
+            oidc_token: None,
+        }
+    }
+
+    /// Set an attribute of the delivery (like `x-goog-version`).
+    pub fn attribute(mut self, name: impl Into<String>, value: impl Into<String>) -> PushConfig {
+        self.attributes.insert(name.into(), value.into());
+        self
+    }
+
+    /// Authenticate the push requests with an OIDC token of the given service account,
+    /// for the given audience (the endpoint URL being used if empty).
+    pub fn oidc_token(
+        mut self,
+        service_account_email: impl Into<String>,
+        audience: impl Into<String>,
+    ) -> PushConfig {
+        self.oidc_token = Some((service_account_email.into(), audience.into()));
+        self
+    }
+
+    /// Get the URL messages get pushed to.
+    pub fn get_endpoint(&self) -> &str {
+        self.endpoint.as_str()
+    }
+
+    /// Get the attributes of the delivery.
+    pub fn get_attributes(&self) -> &HashMap<String, String> {
+        &self.attributes
+    }
+
+    /// Get the service account email and the audience of the OIDC token authenticating the push requests, if any.
+    pub fn get_oidc_token(&self) -> Option<(&str, &str)> {
+        self.oidc_token
+            .as_ref()
+            .map(|(email, audience)| (email.as_str(), audience.as_str()))
+    }
+}
+
+impl From<PushConfig> for api::PushConfig {
+    fn from(config: PushConfig) -> api::PushConfig {
+        use api::push_config::{AuthenticationMethod, OidcToken};
+
+        api::PushConfig {
+            push_endpoint: config.endpoint,
+            attributes: config.attributes,
+            authentication_method: config.oidc_token.map(|(service_account_email, audience)| {
+                AuthenticationMethod::OidcToken(OidcToken {
+                    service_account_email,
+                    audience,
+                })
+            }),
+        }
+    }
+}
+
+impl From<api::PushConfig> for PushConfig {
+    fn from(config: api::PushConfig) -> PushConfig {
+        use api::push_config::AuthenticationMethod;
+
+        PushConfig {
+            endpoint: config.push_endpoint,
+            attributes: config.attributes,
+            oidc_token: config.authentication_method.map(|method| match method {
+                AuthenticationMethod::OidcToken(token) => {
+                    (token.service_account_email, token.audience)
+                }
+            }),
+        }
+    }
+}
+
+/// Represents where the messages that could not be delivered get forwarded to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeadLetterPolicy {
+    pub(crate) topic: String,
+    pub(crate) max_delivery_attempts: i32,
+}
+
+impl DeadLetterPolicy {
+    /// Forward messages to the given topic (as `projects/{project}/topics/{topic}`),
+    /// after 5 delivery attempts.
+    pub fn new(topic: impl Into<String>) -> DeadLetterPolicy {
+        DeadLetterPolicy {
+            topic: topic.into(),
+            max_delivery_attempts: 5,
+        }
+    }
+
+    /// Set the number of delivery attempts before forwarding a message (between 5 and 100).
+    pub fn max_delivery_attempts(mut self, attempts: i32) -> DeadLetterPolicy {
+        self.max_delivery_attempts = attempts;
+        self
+    }
+
+    /// Get the name of the topic messages get forwarded to.
+    pub fn get_topic(&self) -> &str {
+        self.topic.as_str()
+    }
+
+    /// Get the number of delivery attempts before forwarding a message.
+    pub fn get_max_delivery_attempts(&self) -> i32 {
+        self.max_delivery_attempts
+    }
+}
+
+/// Represents the exponential backoff between the redeliveries of a message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    pub(crate) minimum_backoff: Duration,
+    pub(crate) maximum_backoff: Duration,
+}
+
+impl RetryPolicy {
+    /// Set the minimum delay between two deliveries of a message (up to 600 seconds).
+    pub fn minimum_backoff(mut self, duration: Duration) -> RetryPolicy {
+        self.minimum_backoff = duration;
+        self
+    }
+
+    /// Set the maximum delay between two deliveries of a message (up to 600 seconds).
+    pub fn maximum_backoff(mut self, duration: Duration) -> RetryPolicy {
+        self.maximum_backoff = duration;
+        self
+    }
+
+    /// Get the minimum delay between two deliveries of a message.
+    pub fn get_minimum_backoff(&self) -> Duration {
+        self.minimum_backoff
+    }
+
+    /// Get the maximum delay between two deliveries of a message.
+    pub fn get_maximum_backoff(&self) -> Duration {
+        self.maximum_backoff
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> RetryPolicy {
+        RetryPolicy {
+            minimum_backoff: Duration::seconds(10),
+            maximum_backoff: Duration::seconds(600),
+        }
+    }
+}
+
+/// Represents when an inactive subscription gets deleted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Expiration {
+    /// The subscription gets deleted after being inactive for the given duration (at least a day).
+    After(Duration),
+    /// The subscription never gets deleted.
+    Never,
+}
+
+fn convert_api_duration(duration: prost_types::Duration) -> Duration {
+    Duration::seconds(duration.seconds) + Duration::nanoseconds(duration.nanos.into())
+}
+
+/// Represents what happens to the messages received too long after their publication
+/// (see `Subscription::skip_stale`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StaleAction {
+    /// Negatively acknowledge the messages, for Pub/Sub to redeliver them
+    /// (until it forwards them to the dead-letter topic of the subscription, if it has one).
+    Nack,
+    /// Acknowledge the messages, dropping them for good.
+    Ack,
+    /// Republish the messages to the given topic (by ID, in the project of the client) and acknowledge them.
+    ///
+    /// The republished messages keep their data and attributes, and the given attribute is set to
+    /// their age when received (in seconds). If republishing fails, the messages are nacked instead.
+    DeadLetter {
+        /// The ID of the topic to republish to.
+        topic: String,
+        /// The name of the attribute holding the age of the messages.
+        attribute: String,
+    },
+}
+
 /// Optional parameters for pull.
 #[derive(Debug, Clone, PartialEq)]
 pub struct ReceiveOptions {
@@ -71,6 +449,14 @@ pub struct Subscription {
     pub(crate) client: Client,
     pub(crate) name: String,
     pub(crate) buffer: VecDeque<api::ReceivedMessage>,
+    pub(crate) leases: Option<Arc<Leases>>,
+    pub(crate) ack_hooks: Vec<AckHook>,
+    pub(crate) ack_failure_hooks: Vec<AckFailureHook>,
+    #[cfg(feature = "pubsub-metrics")]
+    pub(crate) metrics: Option<SubscriberMetrics>,
+    pub(crate) cancel: Option<CancellationToken>,
+    pub(crate) stale: Option<(Duration, StaleAction)>,
+    pub(crate) group: Option<ConcurrencyGroup>,
 }
 
 impl Subscription {
@@ -79,14 +465,146 @@ impl Subscription {
             client,
             name: name.into(),
             buffer: VecDeque::new(),
+            leases: None,
+            ack_hooks: Vec::new(),
+            ack_failure_hooks: Vec::new(),
+            #[cfg(feature = "pubsub-metrics")]
+            metrics: None,
+            cancel: None,
+            stale: None,
+            group: None,
         }
     }
 
+    /// Keep extending the acknowledgement deadline of the received messages, until they get acknowledged.
+    ///
+    /// The deadlines adapt to the observed processing times, so that slow handlers don't get their messages
+    /// redelivered in the meantime, while the messages of crashed handlers get redelivered quickly.
+    /// The clones of the subscription share the same leases, which are released once the subscription
+    /// and all of its received messages are dropped.
+    pub fn extend_leases(mut self, config: LeaseConfig) -> Subscription {
+        self.leases = Some(Arc::new(Leases::new(config)));
+        self
+    }
+
+    /// Register a hook, invoked after every successful acknowledgement of messages of this subscription.
+    ///
+    /// The hook receives the IDs and publication times of the acknowledged messages,
+    /// so that downstream systems can checkpoint their progress in lockstep with the acknowledged work.
+    /// It runs before the acknowledging call returns, and only for the messages received after its registration.
+    pub fn on_ack(
+        mut self,
+        hook: impl Fn(&[AckedMessage]) + Send + Sync + 'static,
+    ) -> Subscription {
+        self.ack_hooks.push(Arc::new(hook));
+        self
+    }
+
+    /// Register a hook, invoked when acknowledging messages of this subscription fails persistently.
+    ///
+    /// Failures that retrying would not fix (such as ack IDs invalidated by a seek, or an expired deadline)
+    /// are reported along with the affected messages, so that the application can compensate for their redelivery.
+    /// Transient failures (such as an unavailable service) are only returned to the caller, to be retried.
+    /// The hook runs before the acknowledging call returns its error,
+    /// and only for the messages received after its registration.
+    ///
+    /// ```no_run
+    /// # use google_cloud::pubsub::{Error, Subscription};
+    /// # async fn run(subscription: Subscription) -> Result<(), Error> {
+    /// let mut subscription = subscription.on_ack_failure(|failure| {
+    ///     for message in failure.messages() {
+    ///         eprintln!("{} will be redelivered: {}", message.id(), failure.status().message());
+    ///     }
+    /// });
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn on_ack_failure(
+        mut self,
+        hook: impl Fn(&AckFailure) + Send + Sync + 'static,
+    ) -> Subscription {
+        self.ack_failure_hooks.push(Arc::new(hook));
+        self
+    }
+
+    /// Record metrics about the messages received from this subscription into the given registry
+    /// (see `SubscriberMetrics`).
+    ///
+    /// Only the messages received after the call are tracked.
+    #[cfg(feature = "pubsub-metrics")]
+    pub fn metrics(mut self, metrics: SubscriberMetrics) -> Subscription {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Stop receiving messages once the given token gets cancelled, receiving then returning `None`.
+    ///
+    /// The pull request in flight is dropped, and the messages pulled but not handed out yet are released,
+    /// for Pub/Sub to redeliver them right away. The messages already handed out are left to their handlers.
+    ///
+    /// ```no_run
+    /// # use google_cloud::cancel::CancellationToken;
+    /// # use google_cloud::pubsub::{Error, Subscription};
+    /// # async fn run(subscription: Subscription) -> Result<(), Error> {
+    /// let token = CancellationToken::new();
+    /// let mut subscription = subscription.cancel_on(token.clone());
+    /// // The shutdown handler of the application then calls `token.cancel()`.
+    /// while let Some(mut message) = subscription.receive().await {
+    ///     message.ack().await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn cancel_on(mut self, token: CancellationToken) -> Subscription {
+        self.cancel = Some(token);
+        self
+    }
+
+    /// Don't hand out the messages received more than the given duration after their publication,
+    /// handling them with the given action instead.
+    ///
+    /// This keeps backlogged subscriptions from processing work that is no longer meaningful,
+    /// such as outdated notifications.
+    ///
+    /// ```no_run
+    /// # use google_cloud::pubsub::{Error, StaleAction, Subscription};
+    /// # async fn run(subscription: Subscription) -> Result<(), Error> {
+    /// let action = StaleAction::DeadLetter {
+    ///     topic: String::from("stale-notifications"),
+    ///     attribute: String::from("age"),
+    /// };
+    /// let mut subscription = subscription.skip_stale(chrono::Duration::minutes(15), action);
+    /// while let Some(mut message) = subscription.receive().await {
+    ///     message.ack().await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn skip_stale(mut self, max_age: Duration, action: StaleAction) -> Subscription {
+        self.stale = Some((max_age, action));
+        self
+    }
+
+    /// Assign the subscription to the given concurrency group, limiting the messages it hands out
+    /// to the budget of the group (see `ConcurrencyGroup`).
+    ///
+    /// Subscriptions of different groups are isolated from each other, while the subscriptions of the same group
+    /// (and the clones of a subscription) share its budget.
+    pub fn group(mut self, group: ConcurrencyGroup) -> Subscription {
+        self.group = Some(group);
+        self
+    }
+
     /// Returns the unique identifier within its project
     pub fn id(&self) -> &str {
         self.name.rsplit('/').next().unwrap()
     }
 
+    fn is_cancelled(&self) -> bool {
+        let token = self.cancel.as_ref();
+        token.is_some_and(CancellationToken::is_cancelled)
+    }
+
     /// Receive the next message from the subscription.
     pub async fn receive(&mut self) -> Option<Message> {
         self.receive_with_options(Default::default()).await
@@ -95,7 +613,32 @@ impl Subscription {
     /// Receive the next message from the subscription with options.
     pub async fn receive_with_options(&mut self, opts: ReceiveOptions) -> Option<Message> {
         loop {
+            if self.is_cancelled() {
+                self.release_buffer().await;
+                break None;
+            }
+            let permit = match (self.group.clone(), self.buffer.front()) {
+                (Some(group), Some(handle)) => {
+                    let size = handle
+                        .message
+                        .as_ref()
+                        .map_or(0, |message| message.data.len());
+                    let token = self.cancel.clone();
+                    let acquired = async { Ok(group.acquire(size).await) };
+                    match cancel::cancellable(token.as_ref(), acquired).await {
+                        Ok(permit) => Some(permit),
+                        //? The buffered messages get released at the next iteration.
+                        Err(_) => continue,
+                    }
+                }
+                _ => None,
+            };
             if let Some(handle) = self.buffer.pop_front() {
+                #[cfg(feature = "pubsub-metrics")]
+                let metrics = self
+                    .metrics
+                    .as_ref()
+                    .map(|metrics| metrics.received(&self.name, handle.delivery_attempt));
                 let message = handle.message.unwrap();
                 let timestamp = message.publish_time.unwrap();
                 let message = Message {
@@ -104,20 +647,176 @@ impl Subscription {
                     data: message.data,
                     message_id: message.message_id,
                     ack_id: handle.ack_id,
+                    leases: self.leases.clone(),
+                    ack_hooks: self.ack_hooks.clone(),
+                    ack_failure_hooks: self.ack_failure_hooks.clone(),
+                    #[cfg(feature = "pubsub-metrics")]
+                    metrics,
+                    permit,
                     attributes: message.attributes,
-                    publish_time: chrono::NaiveDateTime::from_timestamp(
+                    publish_time: chrono::DateTime::from_timestamp(
                         timestamp.seconds,
                         timestamp.nanos as u32,
-                    ),
+                    )
+                    .unwrap()
+                    .naive_utc(),
                 };
+                if let Some((max_age, action)) = self.stale.clone() {
+                    let age = chrono::Utc::now().naive_utc() - message.publish_time;
+                    if age > max_age {
+                        self.settle_stale(message, age, action).await;
+                        continue;
+                    }
+                }
                 break Some(message);
-            } else if let Ok(messages) = self.pull(&opts).await {
+            }
+
+            let token = self.cancel.clone();
+            let pulled = cancel::cancellable(token.as_ref(), self.pull(&opts)).await;
+            if let Ok(messages) = pulled {
                 if messages.is_empty() && opts.return_immediately {
                     break None;
                 }
+                if let Some(leases) = self.leases.clone() {
+                    let ack_ids = messages.iter().map(|handle| handle.ack_id.clone());
+                    //? Failing to set the initial deadline only makes the messages expire sooner.
+                    let _ = leases
+                        .register(&mut self.client, &self.name, ack_ids.collect())
+                        .await;
+                }
                 self.buffer.extend(messages);
+            } else if !self.is_cancelled() {
+                #[cfg(feature = "pubsub-metrics")]
+                {
+                    if let Some(metrics) = self.metrics.as_ref() {
+                        metrics.pull_restarted(&self.name);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Handles a message received too long after its publication.
+    async fn settle_stale(&mut self, mut message: Message, age: Duration, action: StaleAction) {
+        //? Settling is best-effort: the messages left unsettled get redelivered once their deadline expires.
+        let _ = match action {
+            StaleAction::Nack => message.nack().await,
+            StaleAction::Ack => message.ack().await,
+            StaleAction::DeadLetter { topic, attribute } => {
+                let name = format!("projects/{0}/topics/{1}", self.client.project_name, topic);
+                let republished = PublishMessage::new(message.bytes())
+                    .attributes(message.attributes().clone())
+                    .attribute(attribute, age.num_seconds().to_string());
+                match Topic::new(self.client.clone(), name)
+                    .publish(republished)
+                    .await
+                {
+                    Ok(_) => message.ack().await,
+                    Err(_) => message.nack().await,
+                }
+            }
+        };
+    }
+
+    /// Releases the messages pulled but not handed out yet, for Pub/Sub to redeliver them right away.
+    async fn release_buffer(&mut self) {
+        let ack_ids: Vec<String> = self.buffer.drain(..).map(|handle| handle.ack_id).collect();
+        if let Some(leases) = self.leases.as_ref() {
+            for ack_id in ack_ids.iter() {
+                leases.complete(ack_id, false);
             }
         }
+        //? Releasing is best-effort: the messages get redelivered once their deadline expires anyway.
+        let deadline = std::time::Duration::from_secs(0);
+        let _ = modify_deadlines(&mut self.client, &self.name, ack_ids, deadline).await;
+    }
+
+    /// Acknowledge multiple messages received from this subscription, in as few requests as possible.
+    ///
+    /// The messages must have been received from this subscription.
+    /// The checkpoint hooks are invoked once per successful request.
+    pub async fn ack_all<I>(&mut self, messages: I) -> Result<(), Error>
+    where
+        I: IntoIterator<Item = Message>,
+    {
+        let messages: Vec<Message> = messages.into_iter().collect();
+        for messages in messages.chunks(Leases::MAX_ACK_IDS) {
+            let request = api::AcknowledgeRequest {
+                subscription: self.name.clone(),
+                ack_ids: messages
+                    .iter()
+                    .map(|message| message.ack_id.clone())
+                    .collect(),
+            };
+            let request = self.client.construct_request(request).await?;
+            let response = self.client.subscriber.acknowledge(request).await;
+            let response = response.context("Acknowledge", &self.name);
+            if let Err(err) = response {
+                let failed: Vec<AckedMessage> = messages.iter().map(Message::acked).collect();
+                if run_failure_hooks(&self.ack_failure_hooks, failed, &err) {
+                    for message in messages {
+                        if let Some(leases) = message.leases.as_ref() {
+                            leases.complete(&message.ack_id, false);
+                        }
+                        message.release();
+                        message.record_settled();
+                    }
+                }
+                return Err(err);
+            }
+            for message in messages {
+                if let Some(leases) = message.leases.as_ref() {
+                    leases.complete(&message.ack_id, true);
+                }
+                message.release();
+                message.record_acked();
+            }
+            let acked: Vec<AckedMessage> = messages.iter().map(Message::acked).collect();
+            run_hooks(&self.ack_hooks, &acked);
+        }
+
+        Ok(())
+    }
+
+    /// Get the current configuration of the subscription.
+    ///
+    /// ```no_run
+    /// # use google_cloud::pubsub::{Error, Subscription, SubscriptionConfig};
+    /// # async fn run(mut subscription: Subscription) -> Result<(), Error> {
+    /// let desired = SubscriptionConfig::default().filter(r#"attributes.region = "eu""#);
+    /// let actual = subscription.config().await?;
+    /// if actual.get_filter() != desired.get_filter() {
+    ///     eprintln!("{} has drifted: {:?}", subscription.id(), actual);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn config(&mut self) -> Result<SubscriptionConfig, Error> {
+        let request = api::GetSubscriptionRequest {
+            subscription: self.name.clone(),
+        };
+        let request = self.client.construct_request(request).await?;
+        let response = self.client.subscriber.get_subscription(request).await;
+        let subscription = response.context("GetSubscription", &self.name)?;
+
+        Ok(SubscriptionConfig::from(subscription.into_inner()))
+    }
+
+    /// Drop all the messages of the subscription published up to now, by marking them as acknowledged.
+    ///
+    /// This seeks the subscription to the current time: messages published afterwards are still delivered.
+    /// The messages already received are not affected, but acknowledging them has no effect anymore.
+    pub async fn purge(&mut self) -> Result<(), Error> {
+        let now = types::system_time_to_timestamp(std::time::SystemTime::now());
+        let request = api::SeekRequest {
+            subscription: self.name.clone(),
+            target: Some(api::seek_request::Target::Time(now)),
+        };
+        let request = self.client.construct_request(request).await?;
+        let response = self.client.subscriber.seek(request).await;
+        response.context("Seek", &self.name)?;
+
+        Ok(())
     }
 
     /// Delete the subscription.
@@ -126,7 +825,8 @@ impl Subscription {
             subscription: self.name.clone(),
         };
         let request = self.client.construct_request(request).await?;
-        self.client.subscriber.delete_subscription(request).await?;
+        let response = self.client.subscriber.delete_subscription(request).await;
+        response.context("DeleteSubscription", &self.name)?;
 
         Ok(())
     }
@@ -141,8 +841,8 @@ impl Subscription {
             max_messages: opts.max_messages,
         };
         let request = self.client.construct_request(request).await?;
-        let response = self.client.subscriber.pull(request).await?;
-        let response = response.into_inner();
+        let response = self.client.subscriber.pull(request).await;
+        let response = response.context("Pull", &self.name)?.into_inner();
 
         Ok(response.received_messages)
     }
@@ -0,0 +1,70 @@
+use std::collections::HashMap;
+
+/// Selects resources by their labels, for tearing them down with `Client::teardown`.
+///
+/// A resource is selected if it matches all the requirements of the selector.
+///
+/// ```
+/// # use std::collections::HashMap;
+/// # use google_cloud::pubsub::LabelSelector;
+/// let selector = LabelSelector::new().equals("env", "ci").exists("pipeline");
+///
+/// let mut labels = HashMap::new();
+/// labels.insert(String::from("env"), String::from("ci"));
+/// assert!(!selector.matches(&labels));
+/// labels.insert(String::from("pipeline"), String::from("1234"));
+/// assert!(selector.matches(&labels));
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct LabelSelector {
+    pub(crate) requirements: Vec<(String, Option<String>)>,
+}
+
+impl LabelSelector {
+    /// Construct a new selector, selecting every resource.
+    pub fn new() -> LabelSelector {
+        LabelSelector::default()
+    }
+
+    /// Require a label to have the given value.
+    pub fn equals(mut self, name: impl Into<String>, value: impl Into<String>) -> LabelSelector {
+        self.requirements.push((name.into(), Some(value.into())));
+        self
+    }
+
+    /// Require a label to be present, whatever its value.
+    pub fn exists(mut self, name: impl Into<String>) -> LabelSelector {
+        self.requirements.push((name.into(), None));
+        self
+    }
+
+    /// Whether a set of labels matches all the requirements of the selector.
+    pub fn matches(&self, labels: &HashMap<String, String>) -> bool {
+        self.requirements
+            .iter()
+            .all(|(name, expected)| match (labels.get(name), expected) {
+                (Some(value), Some(expected)) => value == expected,
+                (Some(_), None) => true,
+                (None, _) => false,
+            })
+    }
+}
+
+/// Describes the outcome of a teardown, listing the IDs of the deleted resources.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct TeardownReport {
+    pub(crate) deleted_topics: Vec<String>,
+    pub(crate) deleted_subscriptions: Vec<String>,
+}
+
+impl TeardownReport {
+    /// The topics that were deleted.
+    pub fn deleted_topics(&self) -> &[String] {
+        self.deleted_topics.as_slice()
+    }
+
+    /// The subscriptions that were deleted.
+    pub fn deleted_subscriptions(&self) -> &[String] {
+        self.deleted_subscriptions.as_slice()
+    }
+}
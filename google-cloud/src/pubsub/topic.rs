@@ -1,12 +1,20 @@
 use std::collections::HashMap;
 
+use prost::Message;
+use tonic::Code;
+
+use crate::error::WithContext;
 use crate::pubsub::api;
-use crate::pubsub::{Client, Error, Subscription, SubscriptionConfig};
+use crate::pubsub::{
+    run_publish_hooks, Client, Codec, Error, PublishMessage, Subscription, SubscriptionConfig,
+};
 
 /// Represents the topic's configuration.
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct TopicConfig {
     pub(crate) labels: HashMap<String, String>,
+    pub(crate) allowed_persistence_regions: Vec<String>,
+    pub(crate) kms_key_name: Option<String>,
 }
 
 impl TopicConfig {
@@ -15,6 +23,53 @@ impl TopicConfig {
         self.labels.insert(name.into(), value.into());
         self
     }
+
+    /// Allow the messages of the topic to be persisted in the given region (such as `europe-west1`).
+    ///
+    /// Once a region is allowed, messages are only stored in the allowed ones.
+    /// Otherwise, the storage policy of the organization applies.
+    pub fn allowed_persistence_region(mut self, region: impl Into<String>) -> TopicConfig {
+        self.allowed_persistence_regions.push(region.into());
+        self
+    }
+
+    /// Protect the messages of the topic with a customer-managed Cloud KMS key,
+    /// named like `projects/*/locations/*/keyRings/*/cryptoKeys/*`.
+    pub fn kms_key_name(mut self, name: impl Into<String>) -> TopicConfig {
+        self.kms_key_name = Some(name.into());
+        self
+    }
+
+    /// Converts the configuration into the topic to create.
+    pub(crate) fn into_api(self, name: String) -> api::Topic {
+        //? An empty list of regions is rejected, so the policy is only sent when a region is allowed.
+        let message_storage_policy = if self.allowed_persistence_regions.is_empty() {
+            None
+        } else {
+            Some(api::MessageStoragePolicy {
+                allowed_persistence_regions: self.allowed_persistence_regions,
+            })
+        };
+        api::Topic {
+            name,
+            labels: self.labels,
+            message_storage_policy,
+            kms_key_name: self.kms_key_name.unwrap_or_default(),
+        }
+    }
+}
+
+impl From<api::Topic> for TopicConfig {
+    fn from(topic: api::Topic) -> TopicConfig {
+        let regions = topic
+            .message_storage_policy
+            .map(|policy| policy.allowed_persistence_regions);
+        TopicConfig {
+            labels: topic.labels,
+            allowed_persistence_regions: regions.unwrap_or_default(),
+            kms_key_name: Some(topic.kms_key_name).filter(|name| !name.is_empty()),
+        }
+    }
 }
 
 /// Represents a topic.
@@ -43,54 +98,175 @@ impl Topic {
         id: &str,
         config: SubscriptionConfig,
     ) -> Result<Subscription, Error> {
-        let request = api::Subscription {
-            name: format!(
+        let name = format!(
+            "projects/{0}/subscriptions/{1}",
+            self.client.project_name.as_str(),
+            id,
+        );
+        let request = config.into_api(name.clone(), self.name.clone());
+        let request = self.client.construct_request(request).await?;
+        let response = self.client.subscriber.create_subscription(request).await;
+        let subscription = response.context("CreateSubscription", name)?.into_inner();
+
+        Ok(Subscription::new(self.client.clone(), subscription.name))
+    }
+
+    /// List the subscriptions attached to this topic.
+    pub async fn subscriptions(&mut self) -> Result<Vec<Subscription>, Error> {
+        let mut subscriptions = Vec::new();
+        let page_size = 25;
+        let mut page_token = String::default();
+
+        loop {
+            let request = api::ListTopicSubscriptionsRequest {
+                topic: self.name.clone(),
+                page_size,
+                page_token,
+            };
+            let request = self.client.construct_request(request).await?;
+            let response = self
+                .client
+                .publisher
+                .list_topic_subscriptions(request)
+                .await;
+            let response = response
+                .context("ListTopicSubscriptions", &self.name)?
+                .into_inner();
+            page_token = response.next_page_token;
+            subscriptions.extend(
+                response
+                    .subscriptions
+                    .into_iter()
+                    .map(|name| Subscription::new(self.client.clone(), name)),
+            );
+            if page_token.is_empty() {
+                break;
+            }
+        }
+
+        Ok(subscriptions)
+    }
+
+    /// Delete all the subscriptions attached to this topic.
+    ///
+    /// Subscriptions deleted concurrently (by another process) are skipped.
+    ///
+    /// Returns the IDs of the deleted subscriptions.
+    pub async fn delete_subscriptions(&mut self) -> Result<Vec<String>, Error> {
+        let mut deleted = Vec::new();
+        for subscription in self.subscriptions().await? {
+            let id = String::from(subscription.id());
+            match subscription.delete().await {
+                Ok(()) => deleted.push(id),
+                Err(err) if err.status().map(tonic::Status::code) == Some(Code::NotFound) => {}
+                Err(err) => return Err(err),
+            }
+        }
+
+        Ok(deleted)
+    }
+
+    /// Detach a subscription from this topic, given its ID (or its full name, for a subscription of another project).
+    ///
+    /// The messages retained by the subscription are dropped, and its pulls then fail with a `FAILED_PRECONDITION` status,
+    /// but the subscription itself is kept (so that its configuration can still be inspected) until it gets deleted.
+    pub async fn detach_subscription(&mut self, name: &str) -> Result<(), Error> {
+        let name = match name.starts_with("projects/") {
+            true => String::from(name),
+            false => format!(
                 "projects/{0}/subscriptions/{1}",
                 self.client.project_name.as_str(),
-                id,
+                name,
             ),
-            topic: self.name.clone(),
-            ack_deadline_seconds: config.ack_deadline_duration.num_seconds() as i32,
-            retain_acked_messages: config.message_retention_duration.is_some(),
-            message_retention_duration: config.message_retention_duration.map(|mut dur| {
-                let seconds = dur.num_seconds();
-                dur = dur - chrono::Duration::seconds(seconds);
-                let nanos = dur.num_nanoseconds().unwrap_or(0) as i32;
-                prost_types::Duration { seconds, nanos }
-            }),
-            labels: config.labels,
-            enable_message_ordering: false,
-            push_config: None,
-            expiration_policy: None,
-            dead_letter_policy: None,
+        };
+        let request = api::DetachSubscriptionRequest {
+            subscription: name.clone(),
         };
         let request = self.client.construct_request(request).await?;
-        let response = self.client.subscriber.create_subscription(request).await?;
-        let subscription = response.into_inner();
+        let response = self.client.publisher.detach_subscription(request).await;
+        response.context("DetachSubscription", name)?;
 
-        Ok(Subscription::new(self.client.clone(), subscription.name))
+        Ok(())
     }
 
     /// Publish a message onto this topic.
-    pub async fn publish(
-        &mut self,
-        data: impl Into<Vec<u8>>,
-        attributes: Option<HashMap<String, String>>,
-    ) -> Result<(), Error> {
-        let request = api::PublishRequest {
-            topic: self.name.clone(),
-            messages: vec![api::PubsubMessage {
-                data: data.into(),
-                attributes: attributes.unwrap_or_default(),
-                message_id: String::new(),
-                ordering_key: String::new(),
-                publish_time: None,
-            }],
-        };
-        let request = self.client.construct_request(request).await?;
-        self.client.publisher.publish(request).await?;
+    ///
+    /// The message can be a `PublishMessage` or anything convertible into `Bytes`, such as a `Vec<u8>`,
+    /// a `String` or a `Bytes` buffer.
+    ///
+    /// Returns the server-assigned ID of the published message.
+    pub async fn publish(&mut self, message: impl Into<PublishMessage>) -> Result<String, Error> {
+        let ids = self.publish_all(Some(message)).await?;
+        Ok(ids.into_iter().next().unwrap_or_default())
+    }
 
-        Ok(())
+    /// Publish a value onto this topic, serialized using the given codec.
+    ///
+    /// If the topic has a schema, the encoding of the codec must match the one of its settings.
+    ///
+    /// ```no_run
+    /// # use serde::{Deserialize, Serialize};
+    /// # use google_cloud::pubsub::{Error, Json, Topic};
+    /// #[derive(Serialize, Deserialize)]
+    /// struct Order {
+    ///     id: u64,
+    ///     amount: f64,
+    /// }
+    ///
+    /// # async fn run(mut topic: Topic) -> Result<(), Error> {
+    /// let order = Order { id: 42, amount: 9.99 };
+    /// topic.publish_typed::<_, Json>(&order).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Returns the server-assigned ID of the published message.
+    pub async fn publish_typed<T, C: Codec<T>>(&mut self, value: &T) -> Result<String, Error> {
+        let message = PublishMessage::encode::<T, C>(value)?;
+        self.publish(message).await
+    }
+
+    /// Publish multiple messages onto this topic, in as few requests as possible.
+    ///
+    /// The messages are validated before anything gets sent, so that none is published if any of them is invalid.
+    ///
+    /// Returns the server-assigned IDs of the published messages, in the same order as the messages.
+    pub async fn publish_all<T, I>(&mut self, messages: I) -> Result<Vec<String>, Error>
+    where
+        T: Into<PublishMessage>,
+        I: IntoIterator<Item = T>,
+    {
+        let messages: Vec<PublishMessage> = messages.into_iter().map(Into::into).collect();
+        //? Invalid messages are rejected the same way Pub/Sub itself would.
+        let batches = batch_messages(messages.iter()).map_err(tonic::Status::invalid_argument)?;
+        let client = &self.client;
+        run_publish_hooks(
+            &client.publish_hooks,
+            &client.redactor,
+            &self.name,
+            &messages,
+        );
+        if self.client.dry_run {
+            return Ok(vec![String::new(); messages.len()]);
+        }
+
+        let mut ids = Vec::with_capacity(messages.len());
+        for batch in batches {
+            let request = api::PublishRequest {
+                topic: self.name.clone(),
+                messages: batch,
+            };
+            if let Some(limiter) = self.client.rate_limiter.as_ref() {
+                let bytes = request.messages.iter().map(Message::encoded_len).sum();
+                limiter.acquire(request.messages.len(), bytes).await;
+            }
+            let request = self.client.construct_request(request).await?;
+            let response = self.client.publisher.publish(request).await;
+            let response = response.context("Publish", &self.name)?;
+            ids.extend(response.into_inner().message_ids);
+        }
+
+        Ok(ids)
     }
 
     /// Delete the topic.
@@ -99,8 +275,46 @@ impl Topic {
             topic: self.name.clone(),
         };
         let request = self.client.construct_request(request).await?;
-        self.client.publisher.delete_topic(request).await?;
+        let response = self.client.publisher.delete_topic(request).await;
+        response.context("DeleteTopic", &self.name)?;
 
         Ok(())
     }
 }
+
+//? The limits of a single publish request.
+const MAX_BATCH_MESSAGES: usize = 1000;
+const MAX_BATCH_BYTES: usize = 10_000_000;
+
+/// Validates messages and splits them into batches fitting within a publish request each,
+/// or describes why a message is invalid.
+pub(crate) fn batch_messages<'a>(
+    messages: impl Iterator<Item = &'a PublishMessage>,
+) -> Result<Vec<Vec<api::PubsubMessage>>, &'static str> {
+    let mut batches: Vec<Vec<api::PubsubMessage>> = Vec::new();
+    let mut batch_bytes = 0;
+    for message in messages {
+        if message.data.is_empty() && message.attributes.is_empty() {
+            return Err("a message must contain either data or attributes");
+        }
+        let message = api::PubsubMessage::from(message.clone());
+        let bytes = message.encoded_len();
+        if bytes > MAX_BATCH_BYTES {
+            return Err("a message exceeds the maximum request size");
+        }
+        match batches.last_mut() {
+            Some(batch)
+                if batch.len() < MAX_BATCH_MESSAGES && batch_bytes + bytes <= MAX_BATCH_BYTES =>
+            {
+                batch_bytes += bytes;
+                batch.push(message);
+            }
+            _ => {
+                batch_bytes = bytes;
+                batches.push(vec![message]);
+            }
+        }
+    }
+
+    Ok(batches)
+}
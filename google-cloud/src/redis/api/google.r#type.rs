@@ -0,0 +1,74 @@
+/// Represents a day of week.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum DayOfWeek {
+    /// The unspecified day-of-week.
+    Unspecified = 0,
+    /// The day-of-week of Monday.
+    Monday = 1,
+    /// The day-of-week of Tuesday.
+    Tuesday = 2,
+    /// The day-of-week of Wednesday.
+    Wednesday = 3,
+    /// The day-of-week of Thursday.
+    Thursday = 4,
+    /// The day-of-week of Friday.
+    Friday = 5,
+    /// The day-of-week of Saturday.
+    Saturday = 6,
+    /// The day-of-week of Sunday.
+    Sunday = 7,
+}
+impl DayOfWeek {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            DayOfWeek::Unspecified => "DAY_OF_WEEK_UNSPECIFIED",
+            DayOfWeek::Monday => "MONDAY",
+            DayOfWeek::Tuesday => "TUESDAY",
+            DayOfWeek::Wednesday => "WEDNESDAY",
+            DayOfWeek::Thursday => "THURSDAY",
+            DayOfWeek::Friday => "FRIDAY",
+            DayOfWeek::Saturday => "SATURDAY",
+            DayOfWeek::Sunday => "SUNDAY",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "DAY_OF_WEEK_UNSPECIFIED" => Some(Self::Unspecified),
+            "MONDAY" => Some(Self::Monday),
+            "TUESDAY" => Some(Self::Tuesday),
+            "WEDNESDAY" => Some(Self::Wednesday),
+            "THURSDAY" => Some(Self::Thursday),
+            "FRIDAY" => Some(Self::Friday),
+            "SATURDAY" => Some(Self::Saturday),
+            "SUNDAY" => Some(Self::Sunday),
+            _ => None,
+        }
+    }
+}
+/// Represents a time of day. The date and time zone are either not significant
+/// or are specified elsewhere. An API may choose to allow leap seconds. Related
+/// types are \[google.type.Date][google.type.Date\] and `google.protobuf.Timestamp`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TimeOfDay {
+    /// Hours of day in 24 hour format. Should be from 0 to 23. An API may choose
+    /// to allow the value "24:00:00" for scenarios like business closing time.
+    #[prost(int32, tag = "1")]
+    pub hours: i32,
+    /// Minutes of hour of day. Must be from 0 to 59.
+    #[prost(int32, tag = "2")]
+    pub minutes: i32,
+    /// Seconds of minutes of the time. Must normally be from 0 to 59. An API may
+    /// allow the value 60 if it allows leap-seconds.
+    #[prost(int32, tag = "3")]
+    pub seconds: i32,
+    /// Fractions of seconds in nanoseconds. Must be from 0 to 999,999,999.
+    #[prost(int32, tag = "4")]
+    pub nanos: i32,
+}
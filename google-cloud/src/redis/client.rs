@@ -0,0 +1,236 @@
+use std::env;
+use std::fs::File;
+use std::sync::Arc;
+use std::time::Duration;
+
+use prost::Message;
+use tokio::sync::Mutex;
+use tonic::transport::Channel;
+use tonic::{Code, IntoRequest, Request};
+
+use crate::authorize::{ApplicationCredentials, TokenManager};
+use crate::channel::{self, ChannelConfig};
+use crate::error::ConvertError;
+use crate::redis::api;
+use crate::redis::api::cloud_redis_client::CloudRedisClient;
+use crate::redis::api::longrunning;
+use crate::redis::api::longrunning::operations_client::OperationsClient;
+use crate::redis::{Error, Instance, InstanceConfig, InstanceUpdate};
+
+/// The Memorystore for Redis client, tied to a specific project.
+#[derive(Clone)]
+pub struct Client {
+    pub(crate) project_name: String,
+    pub(crate) service: CloudRedisClient<Channel>,
+    pub(crate) operations: OperationsClient<Channel>,
+    pub(crate) token_manager: Arc<Mutex<TokenManager>>,
+}
+
+impl Client {
+    pub(crate) const DOMAIN_NAME: &'static str = "redis.googleapis.com";
+    pub(crate) const ENDPOINT: &'static str = "https://redis.googleapis.com";
+    pub(crate) const SCOPES: [&'static str; 1] = ["https://www.googleapis.com/auth/cloud-platform"];
+
+    //? Bounds of the delay between two checks of a long-running operation.
+    const MIN_POLL_DELAY: Duration = Duration::from_secs(1);
+    const MAX_POLL_DELAY: Duration = Duration::from_secs(30);
+
+    pub(crate) async fn construct_request<T: IntoRequest<T>>(
+        &mut self,
+        request: T,
+    ) -> Result<Request<T>, Error> {
+        let mut request = request.into_request();
+        let token = self.token_manager.lock().await.token().await?;
+        let metadata = request.metadata_mut();
+        metadata.insert("authorization", token.parse().unwrap());
+        Ok(request)
+    }
+
+    /// Create a new client for the specified project.
+    ///
+    /// Credentials are looked up in the `GOOGLE_APPLICATION_CREDENTIALS` environment variable.
+    pub async fn new(project_name: impl Into<String>) -> Result<Client, Error> {
+        let path = env::var("GOOGLE_APPLICATION_CREDENTIALS")?;
+        let file = File::open(path)?;
+        let creds = json::from_reader(file)?;
+
+        Client::from_credentials(project_name, creds).await
+    }
+
+    /// Create a new client for the specified project with custom credentials.
+    pub async fn from_credentials(
+        project_name: impl Into<String>,
+        creds: ApplicationCredentials,
+    ) -> Result<Client, Error> {
+        Client::from_credentials_with_config(project_name, creds, ChannelConfig::default()).await
+    }
+
+    /// Create a new client for the specified project with custom credentials and channel configuration.
+    pub async fn from_credentials_with_config(
+        project_name: impl Into<String>,
+        creds: ApplicationCredentials,
+        config: ChannelConfig,
+    ) -> Result<Client, Error> {
+        let channel = channel::connect(Client::ENDPOINT, Client::DOMAIN_NAME, &config).await?;
+
+        Ok(Client {
+            project_name: project_name.into(),
+            service: CloudRedisClient::new(channel.clone()),
+            operations: OperationsClient::new(channel),
+            token_manager: Arc::new(Mutex::new(TokenManager::new(
+                creds,
+                Client::SCOPES.as_ref(),
+            ))),
+        })
+    }
+
+    fn location_name(&self, region: &str) -> String {
+        format!(
+            "projects/{0}/locations/{1}",
+            self.project_name.as_str(),
+            region,
+        )
+    }
+
+    fn instance_name(&self, region: &str, id: &str) -> String {
+        format!("{0}/instances/{1}", self.location_name(region), id)
+    }
+
+    /// List all existing instances of a region.
+    ///
+    /// A region of `-` lists the instances of all the regions.
+    pub async fn instances(&mut self, region: &str) -> Result<Vec<Instance>, Error> {
+        let mut instances = Vec::new();
+        let page_size = 25;
+        let mut page_token = String::default();
+
+        loop {
+            let request = api::ListInstancesRequest {
+                parent: self.location_name(region),
+                page_size,
+                page_token,
+            };
+            let request = self.construct_request(request).await?;
+            let response = self.service.list_instances(request).await?;
+            let response = response.into_inner();
+            page_token = response.next_page_token;
+            instances.extend(response.instances.into_iter().map(Instance::from));
+            if page_token.is_empty() {
+                break;
+            }
+        }
+
+        Ok(instances)
+    }
+
+    /// Get a specific instance.
+    pub async fn instance(&mut self, region: &str, id: &str) -> Result<Option<Instance>, Error> {
+        let request = api::GetInstanceRequest {
+            name: self.instance_name(region, id),
+        };
+        let request = self.construct_request(request).await?;
+        let instance = match self.service.get_instance(request).await {
+            Ok(response) => response.into_inner(),
+            Err(status) if status.code() == Code::NotFound => return Ok(None),
+            Err(status) => return Err(status.into()),
+        };
+
+        Ok(Some(Instance::from(instance)))
+    }
+
+    /// Get the AUTH string of an instance.
+    ///
+    /// Returns `None` if AUTH is not enabled on the instance.
+    pub async fn auth_string(&mut self, region: &str, id: &str) -> Result<Option<String>, Error> {
+        let request = api::GetInstanceAuthStringRequest {
+            name: self.instance_name(region, id),
+        };
+        let request = self.construct_request(request).await?;
+        let response = self.service.get_instance_auth_string(request).await?;
+        let auth_string = response.into_inner().auth_string;
+
+        Ok(Some(auth_string).filter(|auth_string| !auth_string.is_empty()))
+    }
+
+    /// Create a new instance, and wait for it to be ready.
+    pub async fn create_instance(
+        &mut self,
+        region: &str,
+        id: &str,
+        config: InstanceConfig,
+    ) -> Result<Instance, Error> {
+        let request = api::CreateInstanceRequest {
+            parent: self.location_name(region),
+            instance_id: id.to_string(),
+            instance: Some(config.into()),
+        };
+        let request = self.construct_request(request).await?;
+        let response = self.service.create_instance(request).await?;
+        let instance: api::Instance = self.wait_operation(response.into_inner()).await?;
+
+        Ok(Instance::from(instance))
+    }
+
+    /// Update an existing instance, and wait for the update to complete.
+    pub async fn update_instance(
+        &mut self,
+        region: &str,
+        id: &str,
+        update: InstanceUpdate,
+    ) -> Result<Instance, Error> {
+        let (instance, paths) = update.into_api(self.instance_name(region, id));
+        let request = api::UpdateInstanceRequest {
+            update_mask: Some(prost_types::FieldMask { paths }),
+            instance: Some(instance),
+        };
+        let request = self.construct_request(request).await?;
+        let response = self.service.update_instance(request).await?;
+        let instance: api::Instance = self.wait_operation(response.into_inner()).await?;
+
+        Ok(Instance::from(instance))
+    }
+
+    /// Delete an instance, along with all of its data, and wait for the deletion to complete.
+    pub async fn delete_instance(&mut self, region: &str, id: &str) -> Result<(), Error> {
+        let request = api::DeleteInstanceRequest {
+            name: self.instance_name(region, id),
+        };
+        let request = self.construct_request(request).await?;
+        let response = self.service.delete_instance(request).await?;
+        let _: () = self.wait_operation(response.into_inner()).await?;
+
+        Ok(())
+    }
+
+    /// Polls a long-running operation until it is done, and decodes its response.
+    async fn wait_operation<T: Message + Default>(
+        &mut self,
+        mut operation: longrunning::Operation,
+    ) -> Result<T, Error> {
+        let mut delay = Client::MIN_POLL_DELAY;
+        while !operation.done {
+            tokio::time::sleep(delay).await;
+            delay = Client::MAX_POLL_DELAY.min(delay * 2);
+            let request = longrunning::GetOperationRequest {
+                name: operation.name.clone(),
+            };
+            let request = self.construct_request(request).await?;
+            let response = self.operations.get_operation(request).await?;
+            operation = response.into_inner();
+        }
+
+        match operation.result {
+            Some(longrunning::operation::Result::Response(response)) => {
+                T::decode(response.value.as_slice()).map_err(|err| {
+                    let message = format!("invalid operation response: {}", err);
+                    Error::Status(tonic::Status::internal(message))
+                })
+            }
+            Some(longrunning::operation::Result::Error(status)) => {
+                let code = Code::from(status.code);
+                Err(Error::Status(tonic::Status::new(code, status.message)))
+            }
+            None => Err(ConvertError::MissingField("result".to_string()).into()),
+        }
+    }
+}
@@ -0,0 +1,417 @@
+use std::collections::HashMap;
+
+use chrono::NaiveDateTime;
+
+use crate::redis::api;
+use crate::redis::maintenance::convert_timestamp;
+use crate::redis::{MaintenanceSchedule, MaintenanceWindow};
+
+/// Represents the service tier of an instance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Tier {
+    /// A single Redis node, without replication.
+    Basic,
+    /// A highly available primary/replica setup, with automatic failover.
+    StandardHa,
+}
+
+impl From<Tier> for api::instance::Tier {
+    fn from(tier: Tier) -> api::instance::Tier {
+        match tier {
+            Tier::Basic => api::instance::Tier::Basic,
+            Tier::StandardHa => api::instance::Tier::StandardHa,
+        }
+    }
+}
+
+/// Represents the state of an instance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InstanceState {
+    /// The instance is being created.
+    Creating,
+    /// The instance is ready to serve requests.
+    Ready,
+    /// The instance is being updated.
+    Updating,
+    /// The instance is being deleted.
+    Deleting,
+    /// The instance is being repaired, and may be unusable.
+    Repairing,
+    /// The instance is undergoing maintenance.
+    Maintenance,
+    /// Data is being imported into the instance.
+    Importing,
+    /// The instance is failing over to its replica.
+    FailingOver,
+    /// The state of the instance is unknown.
+    Unknown,
+}
+
+impl From<i32> for InstanceState {
+    fn from(state: i32) -> InstanceState {
+        use api::instance::State;
+        match State::from_i32(state) {
+            Some(State::Creating) => InstanceState::Creating,
+            Some(State::Ready) => InstanceState::Ready,
+            Some(State::Updating) => InstanceState::Updating,
+            Some(State::Deleting) => InstanceState::Deleting,
+            Some(State::Repairing) => InstanceState::Repairing,
+            Some(State::Maintenance) => InstanceState::Maintenance,
+            Some(State::Importing) => InstanceState::Importing,
+            Some(State::FailingOver) => InstanceState::FailingOver,
+            Some(State::Unspecified) | None => InstanceState::Unknown,
+        }
+    }
+}
+
+/// Represents a Memorystore for Redis instance.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Instance {
+    pub(crate) name: String,
+    pub(crate) display_name: String,
+    pub(crate) labels: HashMap<String, String>,
+    pub(crate) location_id: String,
+    pub(crate) redis_version: String,
+    pub(crate) redis_configs: HashMap<String, String>,
+    pub(crate) tier: Option<Tier>,
+    pub(crate) memory_size_gb: i32,
+    pub(crate) host: String,
+    pub(crate) port: i32,
+    pub(crate) state: InstanceState,
+    pub(crate) create_time: Option<NaiveDateTime>,
+    pub(crate) auth_enabled: bool,
+    pub(crate) maintenance_windows: Vec<MaintenanceWindow>,
+    pub(crate) maintenance_schedule: Option<MaintenanceSchedule>,
+}
+
+impl Instance {
+    /// The full resource name of the instance.
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    /// Returns the unique identifier of the instance within its region.
+    pub fn id(&self) -> &str {
+        self.name.rsplit('/').next().unwrap()
+    }
+
+    /// The human-readable name of the instance.
+    pub fn display_name(&self) -> &str {
+        self.display_name.as_str()
+    }
+
+    /// The labels attached to the instance.
+    pub fn labels(&self) -> &HashMap<String, String> {
+        &self.labels
+    }
+
+    /// The zone the instance was provisioned in.
+    pub fn location_id(&self) -> &str {
+        self.location_id.as_str()
+    }
+
+    /// The version of Redis the instance runs (such as `REDIS_5_0`).
+    pub fn redis_version(&self) -> &str {
+        self.redis_version.as_str()
+    }
+
+    /// The Redis configuration parameters of the instance.
+    pub fn redis_configs(&self) -> &HashMap<String, String> {
+        &self.redis_configs
+    }
+
+    /// The service tier of the instance.
+    pub fn tier(&self) -> Option<Tier> {
+        self.tier
+    }
+
+    /// The memory size of the instance, in GiB.
+    pub fn memory_size_gb(&self) -> i32 {
+        self.memory_size_gb
+    }
+
+    /// The hostname or IP address of the instance's endpoint.
+    pub fn host(&self) -> &str {
+        self.host.as_str()
+    }
+
+    /// The port of the instance's endpoint.
+    pub fn port(&self) -> i32 {
+        self.port
+    }
+
+    /// The current state of the instance.
+    pub fn state(&self) -> InstanceState {
+        self.state
+    }
+
+    /// The time at which the instance was created.
+    pub fn create_time(&self) -> Option<NaiveDateTime> {
+        self.create_time
+    }
+
+    /// Whether Redis AUTH is enabled on the instance.
+    pub fn auth_enabled(&self) -> bool {
+        self.auth_enabled
+    }
+
+    /// The weekly windows during which maintenance can occur.
+    ///
+    /// If empty, maintenance can occur at any time.
+    pub fn maintenance_windows(&self) -> &[MaintenanceWindow] {
+        self.maintenance_windows.as_slice()
+    }
+
+    /// The upcoming scheduled maintenance of the instance, if any.
+    pub fn maintenance_schedule(&self) -> Option<MaintenanceSchedule> {
+        self.maintenance_schedule
+    }
+}
+
+impl From<api::Instance> for Instance {
+    fn from(instance: api::Instance) -> Instance {
+        let tier = match api::instance::Tier::from_i32(instance.tier) {
+            Some(api::instance::Tier::Basic) => Some(Tier::Basic),
+            Some(api::instance::Tier::StandardHa) => Some(Tier::StandardHa),
+            _ => None,
+        };
+        let maintenance_windows = instance
+            .maintenance_policy
+            .map(|policy| {
+                policy
+                    .weekly_maintenance_window
+                    .into_iter()
+                    .filter_map(MaintenanceWindow::from_api)
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Instance {
+            name: instance.name,
+            display_name: instance.display_name,
+            labels: instance.labels,
+            location_id: instance.location_id,
+            redis_version: instance.redis_version,
+            redis_configs: instance.redis_configs,
+            tier,
+            memory_size_gb: instance.memory_size_gb,
+            host: instance.host,
+            port: instance.port,
+            state: InstanceState::from(instance.state),
+            create_time: instance.create_time.and_then(convert_timestamp),
+            auth_enabled: instance.auth_enabled,
+            maintenance_windows,
+            maintenance_schedule: instance.maintenance_schedule.map(MaintenanceSchedule::from),
+        }
+    }
+}
+
+/// Represents the configuration of an instance to create.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InstanceConfig {
+    pub(crate) tier: Tier,
+    pub(crate) memory_size_gb: i32,
+    pub(crate) display_name: Option<String>,
+    pub(crate) labels: HashMap<String, String>,
+    pub(crate) location_id: Option<String>,
+    pub(crate) redis_version: Option<String>,
+    pub(crate) redis_configs: HashMap<String, String>,
+    pub(crate) authorized_network: Option<String>,
+    pub(crate) auth_enabled: bool,
+    pub(crate) maintenance_window: Option<MaintenanceWindow>,
+}
+
+impl InstanceConfig {
+    /// Create a configuration for an instance of the given tier and memory size (in GiB).
+    pub fn new(tier: Tier, memory_size_gb: i32) -> InstanceConfig {
+        InstanceConfig {
+            tier,
+            memory_size_gb,
+            display_name: None,
+            labels: HashMap::new(),
+            location_id: None,
+            redis_version: None,
+            redis_configs: HashMap::new(),
+            authorized_network: None,
+            auth_enabled: false,
+            maintenance_window: None,
+        }
+    }
+
+    /// Set the human-readable name of the instance.
+    pub fn display_name(mut self, display_name: impl Into<String>) -> InstanceConfig {
+        self.display_name = Some(display_name.into());
+        self
+    }
+
+    /// Attach a label to the instance.
+    pub fn label(mut self, name: impl Into<String>, value: impl Into<String>) -> InstanceConfig {
+        self.labels.insert(name.into(), value.into());
+        self
+    }
+
+    /// Set the zone in which to provision the instance.
+    ///
+    /// If not set, the service picks a zone within the region.
+    pub fn location_id(mut self, location_id: impl Into<String>) -> InstanceConfig {
+        self.location_id = Some(location_id.into());
+        self
+    }
+
+    /// Set the version of Redis to run (such as `REDIS_5_0`).
+    pub fn redis_version(mut self, redis_version: impl Into<String>) -> InstanceConfig {
+        self.redis_version = Some(redis_version.into());
+        self
+    }
+
+    /// Set a Redis configuration parameter (such as `maxmemory-policy`).
+    pub fn redis_config(
+        mut self,
+        name: impl Into<String>,
+        value: impl Into<String>,
+    ) -> InstanceConfig {
+        self.redis_configs.insert(name.into(), value.into());
+        self
+    }
+
+    /// Set the full name of the Compute Engine network the instance is connected to.
+    pub fn authorized_network(mut self, network: impl Into<String>) -> InstanceConfig {
+        self.authorized_network = Some(network.into());
+        self
+    }
+
+    /// Enable or disable Redis AUTH on the instance.
+    pub fn auth_enabled(mut self, enabled: bool) -> InstanceConfig {
+        self.auth_enabled = enabled;
+        self
+    }
+
+    /// Restrict maintenance to a weekly window.
+    pub fn maintenance_window(mut self, window: MaintenanceWindow) -> InstanceConfig {
+        self.maintenance_window = Some(window);
+        self
+    }
+}
+
+impl From<InstanceConfig> for api::Instance {
+    fn from(config: InstanceConfig) -> api::Instance {
+        api::Instance {
+            tier: api::instance::Tier::from(config.tier) as i32,
+            memory_size_gb: config.memory_size_gb,
+            display_name: config.display_name.unwrap_or_default(),
+            labels: config.labels,
+            location_id: config.location_id.unwrap_or_default(),
+            redis_version: config.redis_version.unwrap_or_default(),
+            redis_configs: config.redis_configs,
+            authorized_network: config.authorized_network.unwrap_or_default(),
+            auth_enabled: config.auth_enabled,
+            maintenance_policy: config.maintenance_window.map(maintenance_policy),
+            ..api::Instance::default()
+        }
+    }
+}
+
+/// Represents a set of changes to apply to an existing instance.
+///
+/// Only the fields that are explicitly set get updated.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct InstanceUpdate {
+    pub(crate) display_name: Option<String>,
+    pub(crate) labels: Option<HashMap<String, String>>,
+    pub(crate) memory_size_gb: Option<i32>,
+    pub(crate) redis_configs: Option<HashMap<String, String>>,
+    pub(crate) auth_enabled: Option<bool>,
+    pub(crate) maintenance_window: Option<Option<MaintenanceWindow>>,
+}
+
+impl InstanceUpdate {
+    /// Change the human-readable name of the instance.
+    pub fn display_name(mut self, display_name: impl Into<String>) -> InstanceUpdate {
+        self.display_name = Some(display_name.into());
+        self
+    }
+
+    /// Replace all the labels of the instance.
+    pub fn labels(
+        mut self,
+        labels: impl IntoIterator<Item = (impl Into<String>, impl Into<String>)>,
+    ) -> InstanceUpdate {
+        let labels = labels
+            .into_iter()
+            .map(|(name, value)| (name.into(), value.into()));
+        self.labels = Some(labels.collect());
+        self
+    }
+
+    /// Resize the instance, in GiB.
+    pub fn memory_size_gb(mut self, memory_size_gb: i32) -> InstanceUpdate {
+        self.memory_size_gb = Some(memory_size_gb);
+        self
+    }
+
+    /// Set a Redis configuration parameter, leaving the other parameters untouched.
+    pub fn redis_config(
+        mut self,
+        name: impl Into<String>,
+        value: impl Into<String>,
+    ) -> InstanceUpdate {
+        self.redis_configs
+            .get_or_insert_with(HashMap::new)
+            .insert(name.into(), value.into());
+        self
+    }
+
+    /// Enable or disable Redis AUTH on the instance.
+    pub fn auth_enabled(mut self, enabled: bool) -> InstanceUpdate {
+        self.auth_enabled = Some(enabled);
+        self
+    }
+
+    /// Change the weekly maintenance window (or remove it, with `None`).
+    pub fn maintenance_window(mut self, window: Option<MaintenanceWindow>) -> InstanceUpdate {
+        self.maintenance_window = Some(window);
+        self
+    }
+
+    /// Converts the update into an instance, along with the paths of its updated fields.
+    pub(crate) fn into_api(self, name: String) -> (api::Instance, Vec<String>) {
+        let mut instance = api::Instance {
+            name,
+            ..api::Instance::default()
+        };
+        let mut paths = Vec::new();
+        if let Some(display_name) = self.display_name {
+            instance.display_name = display_name;
+            paths.push("display_name".to_string());
+        }
+        if let Some(labels) = self.labels {
+            instance.labels = labels;
+            paths.push("labels".to_string());
+        }
+        if let Some(memory_size_gb) = self.memory_size_gb {
+            instance.memory_size_gb = memory_size_gb;
+            paths.push("memory_size_gb".to_string());
+        }
+        if let Some(redis_configs) = self.redis_configs {
+            instance.redis_configs = redis_configs;
+            paths.push("redis_configs".to_string());
+        }
+        if let Some(auth_enabled) = self.auth_enabled {
+            instance.auth_enabled = auth_enabled;
+            paths.push("auth_enabled".to_string());
+        }
+        if let Some(window) = self.maintenance_window {
+            instance.maintenance_policy = window.map(maintenance_policy);
+            paths.push("maintenance_policy".to_string());
+        }
+
+        (instance, paths)
+    }
+}
+
+fn maintenance_policy(window: MaintenanceWindow) -> api::MaintenancePolicy {
+    api::MaintenancePolicy {
+        weekly_maintenance_window: vec![window.into()],
+        ..api::MaintenancePolicy::default()
+    }
+}
@@ -0,0 +1,126 @@
+use std::convert::TryFrom;
+
+use chrono::{NaiveDateTime, NaiveTime, Timelike, Weekday};
+
+use crate::redis::api;
+use crate::redis::api::r#type::{DayOfWeek, TimeOfDay};
+use crate::types;
+
+/// Represents a weekly window of one hour, during which disruptive maintenance updates can occur.
+///
+/// Times are expressed in UTC.
+///
+/// ```
+/// # use google_cloud::redis::MaintenanceWindow;
+/// use chrono::{NaiveTime, Weekday};
+///
+/// let window = MaintenanceWindow::new(Weekday::Sun, NaiveTime::from_hms_opt(3, 0, 0).unwrap());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MaintenanceWindow {
+    pub(crate) day: Weekday,
+    pub(crate) start_time: NaiveTime,
+}
+
+impl MaintenanceWindow {
+    /// Create a maintenance window starting every week at the given day and time.
+    pub fn new(day: Weekday, start_time: NaiveTime) -> MaintenanceWindow {
+        MaintenanceWindow { day, start_time }
+    }
+
+    /// The day of the week the window starts at.
+    pub fn day(&self) -> Weekday {
+        self.day
+    }
+
+    /// The time of the day the window starts at.
+    pub fn start_time(&self) -> NaiveTime {
+        self.start_time
+    }
+
+    pub(crate) fn from_api(window: api::WeeklyMaintenanceWindow) -> Option<MaintenanceWindow> {
+        let day = match DayOfWeek::from_i32(window.day)? {
+            DayOfWeek::Monday => Weekday::Mon,
+            DayOfWeek::Tuesday => Weekday::Tue,
+            DayOfWeek::Wednesday => Weekday::Wed,
+            DayOfWeek::Thursday => Weekday::Thu,
+            DayOfWeek::Friday => Weekday::Fri,
+            DayOfWeek::Saturday => Weekday::Sat,
+            DayOfWeek::Sunday => Weekday::Sun,
+            DayOfWeek::Unspecified => return None,
+        };
+        let start_time = window.start_time.unwrap_or_default();
+        let start_time = NaiveTime::from_hms_nano_opt(
+            u32::try_from(start_time.hours).ok()?,
+            u32::try_from(start_time.minutes).ok()?,
+            u32::try_from(start_time.seconds).ok()?,
+            u32::try_from(start_time.nanos).ok()?,
+        )?;
+
+        Some(MaintenanceWindow { day, start_time })
+    }
+}
+
+impl From<MaintenanceWindow> for api::WeeklyMaintenanceWindow {
+    fn from(window: MaintenanceWindow) -> api::WeeklyMaintenanceWindow {
+        let day = match window.day {
+            Weekday::Mon => DayOfWeek::Monday,
+            Weekday::Tue => DayOfWeek::Tuesday,
+            Weekday::Wed => DayOfWeek::Wednesday,
+            Weekday::Thu => DayOfWeek::Thursday,
+            Weekday::Fri => DayOfWeek::Friday,
+            Weekday::Sat => DayOfWeek::Saturday,
+            Weekday::Sun => DayOfWeek::Sunday,
+        };
+
+        api::WeeklyMaintenanceWindow {
+            day: day as i32,
+            start_time: Some(TimeOfDay {
+                hours: window.start_time.hour() as i32,
+                minutes: window.start_time.minute() as i32,
+                seconds: window.start_time.second() as i32,
+                nanos: window.start_time.nanosecond() as i32,
+            }),
+            duration: None,
+        }
+    }
+}
+
+/// Represents the upcoming scheduled maintenance of an instance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MaintenanceSchedule {
+    pub(crate) start_time: Option<NaiveDateTime>,
+    pub(crate) end_time: Option<NaiveDateTime>,
+    pub(crate) deadline: Option<NaiveDateTime>,
+}
+
+impl MaintenanceSchedule {
+    /// The time at which the maintenance starts.
+    pub fn start_time(&self) -> Option<NaiveDateTime> {
+        self.start_time
+    }
+
+    /// The time at which the maintenance ends.
+    pub fn end_time(&self) -> Option<NaiveDateTime> {
+        self.end_time
+    }
+
+    /// The time beyond which the maintenance cannot be rescheduled.
+    pub fn deadline(&self) -> Option<NaiveDateTime> {
+        self.deadline
+    }
+}
+
+impl From<api::MaintenanceSchedule> for MaintenanceSchedule {
+    fn from(schedule: api::MaintenanceSchedule) -> MaintenanceSchedule {
+        MaintenanceSchedule {
+            start_time: schedule.start_time.and_then(convert_timestamp),
+            end_time: schedule.end_time.and_then(convert_timestamp),
+            deadline: schedule.schedule_deadline_time.and_then(convert_timestamp),
+        }
+    }
+}
+
+pub(crate) fn convert_timestamp(timestamp: prost_types::Timestamp) -> Option<NaiveDateTime> {
+    types::timestamp_to_naive(timestamp).ok()
+}
@@ -0,0 +1,38 @@
+mod client;
+mod instance;
+mod maintenance;
+pub(crate) mod api {
+    #![allow(
+        dead_code,
+        clippy::doc_lazy_continuation,
+        clippy::doc_overindented_list_items
+    )]
+    pub mod rpc {
+        include!("api/google.rpc.rs");
+    }
+    #[allow(clippy::module_inception)]
+    pub mod api {
+        include!("api/google.api.rs");
+    }
+    pub mod longrunning {
+        include!("api/google.longrunning.rs");
+    }
+    pub mod r#type {
+        include!("api/google.r#type.rs");
+    }
+    pub mod cloud {
+        pub mod redis {
+            pub mod v1 {
+                include!("api/google.cloud.redis.v1.rs");
+            }
+        }
+    }
+    pub use self::cloud::redis::v1::*;
+}
+
+pub use self::client::*;
+pub use self::instance::*;
+pub use self::maintenance::*;
+
+/// The error type for the Memorystore for Redis module.
+pub type Error = crate::error::Error;
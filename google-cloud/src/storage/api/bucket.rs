@@ -133,11 +133,14 @@ pub struct BucketRuleAction {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BucketRuleCondition {
+    #[serde(default)]
     pub age: i32,
     pub created_before: Option<String>,
     pub is_live: Option<bool>,
     pub matches_storage_class: Option<Vec<String>>,
     pub num_newer_versions: Option<i32>,
+    pub matches_prefix: Option<Vec<String>>,
+    pub matches_suffix: Option<Vec<String>>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
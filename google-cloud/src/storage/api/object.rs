@@ -41,6 +41,16 @@ pub struct ObjectResource {
     pub kms_key_name: Option<String>,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ObjectResources {
+    /// Value: "storage#objects"
+    pub kind: String,
+    #[serde(default)]
+    pub items: Vec<ObjectResource>,
+    pub next_page_token: Option<String>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ObjectOwner {
@@ -1,7 +1,14 @@
+use std::convert::TryFrom;
+
+use bytes::Bytes;
 use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
 
+use crate::error::WithContext;
 use crate::storage::api::object::ObjectResource;
-use crate::storage::{Client, Error, Object};
+use crate::storage::{
+    list_page, list_parallel, Client, Error, Lifecycle, LifecycleObject, LifecycleOutcome,
+    NameRange, Object,
+};
 
 /// Represents a Cloud Storage bucket.
 #[derive(Clone)]
@@ -27,7 +34,7 @@ impl Bucket {
     pub async fn create_object(
         &mut self,
         name: &str,
-        data: impl Into<Vec<u8>>,
+        data: impl Into<Bytes>,
         mime_type: impl AsRef<str>,
     ) -> Result<Object, Error> {
         let client = &mut self.client;
@@ -48,20 +55,61 @@ impl Bucket {
             .header("content-length", data.len())
             .body(data)
             .send();
-        let response = request.await?;
-        let string = response.error_for_status()?.text().await?;
+        let response = request
+            .await
+            .and_then(|response| response.error_for_status());
+        let response =
+            response.context("InsertObject", format_args!("gs://{}/{}", self.name, name))?;
+        let string = response.text().await?;
         let resource = json::from_str::<ObjectResource>(string.as_str())?;
+        let name = resource.name.clone();
+        if let Some(cache) = client.cache.as_ref() {
+            cache.insert_object(resource);
+        }
 
+        Ok(Object::new(client.clone(), self.name.clone(), name))
+    }
+
+    /// Get an object stored in the bucket.
+    pub async fn object(&mut self, name: &str) -> Result<Object, Error> {
+        let resource = self.object_resource(name).await?;
         Ok(Object::new(
-            client.clone(),
+            self.client.clone(),
             self.name.clone(),
             resource.name,
         ))
     }
 
-    /// Get an object stored in the bucket.
-    pub async fn object(&mut self, name: &str) -> Result<Object, Error> {
+    /// Get the lifecycle configuration of the bucket (without any rule, if it has none).
+    pub async fn lifecycle(&mut self) -> Result<Lifecycle, Error> {
+        let name = self.name.clone();
+        let bucket = self.client.bucket_resource(name.as_str()).await?;
+        let lifecycle = bucket.lifecycle.as_ref().map(Lifecycle::try_from);
+        Ok(lifecycle.transpose()?.unwrap_or_default())
+    }
+
+    /// Determine which rule of the bucket's lifecycle configuration would apply to one of its objects, and when, if any.
+    ///
+    /// See `Lifecycle::evaluate` for how the rule is picked.
+    pub async fn evaluate_lifecycle(
+        &mut self,
+        name: &str,
+    ) -> Result<Option<LifecycleOutcome>, Error> {
+        let lifecycle = self.lifecycle().await?;
+        let resource = self.object_resource(name).await?;
+        let object = LifecycleObject::try_from(&resource)?;
+        Ok(lifecycle.evaluate(&object))
+    }
+
+    /// Get the metadata of an object stored in the bucket, from the cache if enabled.
+    async fn object_resource(&mut self, name: &str) -> Result<ObjectResource, Error> {
+        let cache = self.client.cache.as_ref();
+        if let Some(resource) = cache.and_then(|cache| cache.object(&self.name, name)) {
+            return Ok(resource);
+        }
+
         let client = &mut self.client;
+
         let inner = &client.client;
         let uri = format!(
             "{}/b/{}/o/{}",
@@ -75,15 +123,84 @@ impl Bucket {
             .get(uri.as_str())
             .header("authorization", token)
             .send();
-        let response = request.await?;
-        let string = response.error_for_status()?.text().await?;
+        let response = request
+            .await
+            .and_then(|response| response.error_for_status());
+        let response =
+            response.context("GetObject", format_args!("gs://{}/{}", self.name, name))?;
+        let string = response.text().await?;
         let resource = json::from_str::<ObjectResource>(string.as_str())?;
+        if let Some(cache) = client.cache.as_ref() {
+            cache.insert_object(resource.clone());
+        }
 
-        Ok(Object::new(
-            client.clone(),
-            self.name.clone(),
-            resource.name,
-        ))
+        Ok(resource)
+    }
+
+    /// List the objects of the bucket whose names start with the given prefix, ordered by name.
+    pub async fn objects(&mut self, prefix: &str) -> Result<Vec<Object>, Error> {
+        let range = NameRange::full();
+        let mut resources = Vec::new();
+        let mut page_token = None;
+        loop {
+            let page = list_page(
+                &self.client,
+                &self.name,
+                prefix,
+                &range,
+                page_token.as_deref(),
+            );
+            let page = page.await?;
+            resources.extend(page.items);
+            page_token = match page.next_page_token {
+                Some(token) => Some(token),
+                None => break,
+            };
+        }
+
+        Ok(self.object_handles(resources))
+    }
+
+    /// List the objects of the bucket whose names start with the given prefix, ordered by name,
+    /// using up to `concurrency` concurrent listings.
+    ///
+    /// The names are split into lexicographic ranges which are listed concurrently,
+    /// and the ranges that turn out to hold many names are split again as listings go.
+    /// This makes enumerating large buckets many times faster than paginating through them serially,
+    /// at the cost of some more requests.
+    ///
+    /// ```no_run
+    /// # use google_cloud::storage::Client;
+    /// # async fn example(client: &mut Client) -> Result<(), google_cloud::error::Error> {
+    /// let mut bucket = client.bucket("my-bucket").await?;
+    /// let objects = bucket.objects_parallel("logs/", 32).await?;
+    /// for object in objects {
+    ///     println!("{}", object.name());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn objects_parallel(
+        &mut self,
+        prefix: &str,
+        concurrency: usize,
+    ) -> Result<Vec<Object>, Error> {
+        let resources = list_parallel(&self.client, &self.name, prefix, concurrency).await?;
+
+        Ok(self.object_handles(resources))
+    }
+
+    fn object_handles(&self, resources: Vec<ObjectResource>) -> Vec<Object> {
+        resources
+            .into_iter()
+            .map(|resource| {
+                let name = resource.name.clone();
+                if let Some(cache) = self.client.cache.as_ref() {
+                    cache.insert_object(resource);
+                }
+                Object::new(self.client.clone(), self.name.clone(), name)
+            })
+            .collect()
     }
 
     /// Delete the bucket.
@@ -101,8 +218,13 @@ impl Bucket {
             .delete(uri.as_str())
             .header("authorization", token)
             .send();
-        let response = request.await?;
-        response.error_for_status()?;
+        let response = request
+            .await
+            .and_then(|response| response.error_for_status());
+        response.context("DeleteBucket", format_args!("gs://{}", self.name))?;
+        if let Some(cache) = client.cache.as_ref() {
+            cache.invalidate_bucket(&self.name);
+        }
 
         Ok(())
     }
@@ -0,0 +1,113 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::storage::api::bucket::BucketResource;
+use crate::storage::api::object::ObjectResource;
+
+/// A bounded map whose entries expire after a fixed duration.
+pub(crate) struct TtlMap<K, V> {
+    ttl: Duration,
+    capacity: usize,
+    entries: HashMap<K, (Instant, V)>,
+}
+
+impl<K: Clone + Eq + Hash, V: Clone> TtlMap<K, V> {
+    pub(crate) fn new(ttl: Duration, capacity: usize) -> TtlMap<K, V> {
+        TtlMap {
+            ttl,
+            capacity,
+            entries: HashMap::new(),
+        }
+    }
+
+    /// Gets the value of a key, unless it expired.
+    pub(crate) fn get(&mut self, key: &K) -> Option<V> {
+        let (inserted, value) = self.entries.get(key)?;
+        if inserted.elapsed() < self.ttl {
+            return Some(value.clone());
+        }
+        self.entries.remove(key);
+        None
+    }
+
+    /// Inserts a value, evicting the expired entries (or else the oldest one) if the map is full.
+    pub(crate) fn insert(&mut self, key: K, value: V) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.entries.len() >= self.capacity && !self.entries.contains_key(&key) {
+            let ttl = self.ttl;
+            self.entries
+                .retain(|_, (inserted, _)| inserted.elapsed() < ttl);
+            if self.entries.len() >= self.capacity {
+                let oldest = self
+                    .entries
+                    .iter()
+                    .min_by_key(|(_, (inserted, _))| *inserted)
+                    .map(|(key, _)| key.clone());
+                if let Some(oldest) = oldest {
+                    self.entries.remove(&oldest);
+                }
+            }
+        }
+        self.entries.insert(key, (Instant::now(), value));
+    }
+
+    /// Removes the value of a key.
+    pub(crate) fn remove(&mut self, key: &K) {
+        self.entries.remove(key);
+    }
+
+    /// Removes all the entries whose key matches the predicate.
+    pub(crate) fn remove_where(&mut self, mut predicate: impl FnMut(&K) -> bool) {
+        self.entries.retain(|key, _| !predicate(key));
+    }
+}
+
+/// Caches the metadata of buckets and objects, shared by all the handles of a client.
+pub(crate) struct MetadataCache {
+    pub(crate) buckets: Mutex<TtlMap<String, BucketResource>>,
+    pub(crate) objects: Mutex<TtlMap<(String, String), ObjectResource>>,
+}
+
+impl MetadataCache {
+    pub(crate) fn new(ttl: Duration, capacity: usize) -> MetadataCache {
+        MetadataCache {
+            buckets: Mutex::new(TtlMap::new(ttl, capacity)),
+            objects: Mutex::new(TtlMap::new(ttl, capacity)),
+        }
+    }
+
+    pub(crate) fn bucket(&self, name: &str) -> Option<BucketResource> {
+        self.buckets.lock().unwrap().get(&name.to_string())
+    }
+
+    pub(crate) fn insert_bucket(&self, resource: BucketResource) {
+        let name = resource.name.clone();
+        self.buckets.lock().unwrap().insert(name, resource);
+    }
+
+    /// Forgets a bucket, along with all of its objects.
+    pub(crate) fn invalidate_bucket(&self, name: &str) {
+        self.buckets.lock().unwrap().remove(&name.to_string());
+        let mut objects = self.objects.lock().unwrap();
+        objects.remove_where(|(bucket, _)| bucket == name);
+    }
+
+    pub(crate) fn object(&self, bucket: &str, name: &str) -> Option<ObjectResource> {
+        let key = (bucket.to_string(), name.to_string());
+        self.objects.lock().unwrap().get(&key)
+    }
+
+    pub(crate) fn insert_object(&self, resource: ObjectResource) {
+        let key = (resource.bucket.clone(), resource.name.clone());
+        self.objects.lock().unwrap().insert(key, resource);
+    }
+
+    pub(crate) fn invalidate_object(&self, bucket: &str, name: &str) {
+        let key = (bucket.to_string(), name.to_string());
+        self.objects.lock().unwrap().remove(&key);
+    }
+}
@@ -1,14 +1,16 @@
 use std::env;
 use std::fs::File;
 use std::sync::Arc;
+use std::time::Duration;
 
 use json::json;
 use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
 use tokio::sync::Mutex;
 
 use crate::authorize::{ApplicationCredentials, TokenManager};
+use crate::error::WithContext;
 use crate::storage::api::bucket::{BucketResource, BucketResources};
-use crate::storage::{Bucket, Error};
+use crate::storage::{Bucket, Error, MetadataCache, RetryConfig};
 
 /// The Cloud Storage client, tied to a specific project.
 #[derive(Clone)]
@@ -16,6 +18,8 @@ pub struct Client {
     pub(crate) project_name: String,
     pub(crate) client: Arc<reqwest::Client>,
     pub(crate) token_manager: Arc<Mutex<TokenManager>>,
+    pub(crate) cache: Option<Arc<MetadataCache>>,
+    pub(crate) upload_retry: RetryConfig,
 }
 
 impl Client {
@@ -66,11 +70,55 @@ impl Client {
                 creds,
                 Client::SCOPES.as_ref(),
             ))),
+            cache: None,
+            upload_retry: RetryConfig::default(),
         })
     }
 
+    /// Cache the metadata of buckets and objects, to avoid fetching it again when getting the same handles.
+    ///
+    /// Cached entries expire after the given duration, and at most `capacity` buckets and objects are cached.
+    /// The cache is shared by all the handles obtained from this client (and its clones),
+    /// and changes made through them are reflected immediately, but changes made by other clients
+    /// are only noticed once the entries expire.
+    pub fn metadata_cache(mut self, ttl: Duration, capacity: usize) -> Client {
+        self.cache = Some(Arc::new(MetadataCache::new(ttl, capacity)));
+        self
+    }
+
+    /// Set how resumable uploads recover from transient failures (see `Bucket::create_object_resumable`).
+    pub fn upload_retry(mut self, config: RetryConfig) -> Client {
+        self.upload_retry = config;
+        self
+    }
+
+    /// Get a client for another project, sharing the HTTP client, the credentials and the metadata cache of this one.
+    ///
+    /// The project only matters for listing and creating buckets, whose names are global.
+    /// The credentials must be granted access to the other project.
+    pub fn for_project(&self, project_name: impl Into<String>) -> Client {
+        let mut client = self.clone();
+        client.project_name = project_name.into();
+        client
+    }
+
+    /// The project the client operates on.
+    pub fn project_name(&self) -> &str {
+        self.project_name.as_str()
+    }
+
     /// Get a handle to a specific bucket.
     pub async fn bucket(&mut self, name: &str) -> Result<Bucket, Error> {
+        let bucket = self.bucket_resource(name).await?;
+        Ok(Bucket::new(self.clone(), bucket.name))
+    }
+
+    /// Get the metadata of a bucket, from the cache if enabled.
+    pub(crate) async fn bucket_resource(&mut self, name: &str) -> Result<BucketResource, Error> {
+        if let Some(bucket) = self.cache.as_ref().and_then(|cache| cache.bucket(name)) {
+            return Ok(bucket);
+        }
+
         let inner = &self.client;
         let uri = format!(
             "{}/b/{}",
@@ -83,13 +131,16 @@ impl Client {
             .get(uri.as_str())
             .header("authorization", token)
             .send();
-        let response = request.await?;
-        let bucket = response
-            .error_for_status()?
-            .json::<BucketResource>()
-            .await?;
+        let response = request
+            .await
+            .and_then(|response| response.error_for_status());
+        let response = response.context("GetBucket", format_args!("gs://{}", name))?;
+        let bucket = response.json::<BucketResource>().await?;
+        if let Some(cache) = self.cache.as_ref() {
+            cache.insert_bucket(bucket.clone());
+        }
 
-        Ok(Bucket::new(self.clone(), bucket.name))
+        Ok(bucket)
     }
 
     /// List all existing buckets of the current project.
@@ -112,7 +163,13 @@ impl Client {
         let buckets = resources
             .items
             .into_iter()
-            .map(|resource| Bucket::new(self.clone(), resource.name))
+            .map(|resource| {
+                let name = resource.name.clone();
+                if let Some(cache) = self.cache.as_ref() {
+                    cache.insert_bucket(resource);
+                }
+                Bucket::new(self.clone(), name)
+            })
             .collect();
 
         Ok(buckets)
@@ -134,12 +191,16 @@ impl Client {
             .header("authorization", token)
             .json(&body)
             .send();
-        let response = request.await?;
-        let bucket = response
-            .error_for_status()?
-            .json::<BucketResource>()
-            .await?;
+        let response = request
+            .await
+            .and_then(|response| response.error_for_status());
+        let response = response.context("InsertBucket", format_args!("gs://{}", name))?;
+        let bucket = response.json::<BucketResource>().await?;
+        let name = bucket.name.clone();
+        if let Some(cache) = self.cache.as_ref() {
+            cache.insert_bucket(bucket);
+        }
 
-        Ok(Bucket::new(self.clone(), bucket.name))
+        Ok(Bucket::new(self.clone(), name))
     }
 }
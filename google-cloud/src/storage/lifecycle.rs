@@ -0,0 +1,347 @@
+use std::convert::TryFrom;
+
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime};
+
+use crate::error::ConvertError;
+use crate::storage::api::bucket::{BucketLifecycle, BucketRule};
+use crate::storage::api::object::ObjectResource;
+
+/// Represents the action of a lifecycle rule.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum LifecycleAction {
+    /// Delete the object.
+    Delete,
+    /// Change the storage class of the object (such as `NEARLINE` or `COLDLINE`).
+    SetStorageClass(String),
+}
+
+/// Represents the conditions under which a lifecycle rule applies to an object.
+///
+/// An object must meet all the conditions for the rule to apply.
+///
+/// ```
+/// # use google_cloud::storage::LifecycleCondition;
+/// let condition = LifecycleCondition::new()
+///     .age(30)
+///     .matches_storage_class("STANDARD")
+///     .matches_prefix("logs/");
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct LifecycleCondition {
+    pub(crate) age: Option<u32>,
+    pub(crate) created_before: Option<NaiveDate>,
+    pub(crate) is_live: Option<bool>,
+    pub(crate) matches_storage_class: Vec<String>,
+    pub(crate) num_newer_versions: Option<u32>,
+    pub(crate) matches_prefix: Vec<String>,
+    pub(crate) matches_suffix: Vec<String>,
+}
+
+impl LifecycleCondition {
+    /// Construct a new condition, met by every object.
+    pub fn new() -> LifecycleCondition {
+        LifecycleCondition::default()
+    }
+
+    /// Require the object to be at least this many days old.
+    pub fn age(mut self, days: u32) -> LifecycleCondition {
+        self.age = Some(days);
+        self
+    }
+
+    /// Require the object to have been created before the given date (at midnight UTC).
+    pub fn created_before(mut self, date: NaiveDate) -> LifecycleCondition {
+        self.created_before = Some(date);
+        self
+    }
+
+    /// Require the object to be live (or noncurrent, if `false`), in a versioned bucket.
+    pub fn is_live(mut self, live: bool) -> LifecycleCondition {
+        self.is_live = Some(live);
+        self
+    }
+
+    /// Require the object to be in the given storage class (or any of the given ones, if called several times).
+    pub fn matches_storage_class(mut self, class: impl Into<String>) -> LifecycleCondition {
+        self.matches_storage_class.push(class.into());
+        self
+    }
+
+    /// Require the object to have at least this many newer versions, in a versioned bucket.
+    pub fn num_newer_versions(mut self, versions: u32) -> LifecycleCondition {
+        self.num_newer_versions = Some(versions);
+        self
+    }
+
+    /// Require the name of the object to start with the given prefix (or any of the given ones, if called several times).
+    pub fn matches_prefix(mut self, prefix: impl Into<String>) -> LifecycleCondition {
+        self.matches_prefix.push(prefix.into());
+        self
+    }
+
+    /// Require the name of the object to end with the given suffix (or any of the given ones, if called several times).
+    pub fn matches_suffix(mut self, suffix: impl Into<String>) -> LifecycleCondition {
+        self.matches_suffix.push(suffix.into());
+        self
+    }
+
+    /// The time at which an object meets the condition, or `None` if it never does (in its current state).
+    fn applies_at(&self, object: &LifecycleObject) -> Option<NaiveDateTime> {
+        fn matches_any(candidates: &[String], matches: impl Fn(&str) -> bool) -> bool {
+            candidates.is_empty() || candidates.iter().any(|candidate| matches(candidate))
+        }
+
+        if let Some(date) = self.created_before {
+            if object.created >= date.and_hms_opt(0, 0, 0)? {
+                return None;
+            }
+        }
+        if self.is_live.is_some_and(|live| live != object.is_live()) {
+            return None;
+        }
+        if self
+            .num_newer_versions
+            .is_some_and(|versions| object.num_newer_versions < versions)
+        {
+            return None;
+        }
+        let matches =
+            matches_any(&self.matches_storage_class, |class| {
+                class == object.storage_class
+            }) && matches_any(&self.matches_prefix, |prefix| {
+                object.name.starts_with(prefix)
+            }) && matches_any(&self.matches_suffix, |suffix| object.name.ends_with(suffix));
+        if !matches {
+            return None;
+        }
+
+        //? The age is the only condition depending on time, all the others are met right away (or never).
+        let age = Duration::days(self.age.unwrap_or(0).into());
+        object.created.checked_add_signed(age)
+    }
+}
+
+/// Represents a lifecycle rule, applying an action to the objects meeting a condition.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LifecycleRule {
+    pub(crate) action: LifecycleAction,
+    pub(crate) condition: LifecycleCondition,
+}
+
+impl LifecycleRule {
+    /// Construct a new rule.
+    pub fn new(action: LifecycleAction, condition: LifecycleCondition) -> LifecycleRule {
+        LifecycleRule { action, condition }
+    }
+
+    /// Get the action of the rule.
+    pub fn action(&self) -> &LifecycleAction {
+        &self.action
+    }
+
+    /// Get the condition of the rule.
+    pub fn condition(&self) -> &LifecycleCondition {
+        &self.condition
+    }
+}
+
+impl TryFrom<&BucketRule> for LifecycleRule {
+    type Error = ConvertError;
+
+    fn try_from(rule: &BucketRule) -> Result<LifecycleRule, ConvertError> {
+        let action = match rule.action.action_type.as_str() {
+            "Delete" => LifecycleAction::Delete,
+            "SetStorageClass" => {
+                let class = rule.action.storage_class.clone();
+                let class = class
+                    .ok_or_else(|| ConvertError::MissingField(String::from("storageClass")))?;
+                LifecycleAction::SetStorageClass(class)
+            }
+            action => return Err(ConvertError::UnknownVariant(String::from(action))),
+        };
+
+        let api = &rule.condition;
+        let mut condition = LifecycleCondition::new();
+        if api.age > 0 {
+            condition = condition.age(api.age as u32);
+        }
+        if let Some(date) = api.created_before.as_ref() {
+            let date = NaiveDate::parse_from_str(date, "%Y-%m-%d")
+                .map_err(|_| ConvertError::InvalidDate(date.clone()))?;
+            condition = condition.created_before(date);
+        }
+        condition.is_live = api.is_live;
+        condition.num_newer_versions = api
+            .num_newer_versions
+            .map(|versions| versions.max(0) as u32);
+        condition.matches_storage_class = api.matches_storage_class.clone().unwrap_or_default();
+        condition.matches_prefix = api.matches_prefix.clone().unwrap_or_default();
+        condition.matches_suffix = api.matches_suffix.clone().unwrap_or_default();
+
+        Ok(LifecycleRule::new(action, condition))
+    }
+}
+
+/// Represents the lifecycle configuration of a bucket.
+///
+/// It can be evaluated against objects locally, to check which rule would apply to them and when,
+/// before applying the configuration to a bucket (or read from a bucket, see `Bucket::lifecycle`):
+///
+/// ```
+/// # use google_cloud::storage::{Lifecycle, LifecycleAction, LifecycleCondition, LifecycleObject, LifecycleRule};
+/// let lifecycle = Lifecycle::new()
+///     .rule(LifecycleRule::new(
+///         LifecycleAction::SetStorageClass(String::from("NEARLINE")),
+///         LifecycleCondition::new().age(30).matches_storage_class("STANDARD"),
+///     ))
+///     .rule(LifecycleRule::new(
+///         LifecycleAction::Delete,
+///         LifecycleCondition::new().age(365),
+///     ));
+///
+/// let created = chrono::NaiveDate::from_ymd_opt(2024, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+/// let object = LifecycleObject::new("logs/app.log", created);
+/// let outcome = lifecycle.evaluate(&object).unwrap();
+/// assert_eq!(outcome.rule_index(), 0);
+/// assert_eq!(outcome.applies_at().date(), chrono::NaiveDate::from_ymd_opt(2024, 1, 31).unwrap());
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Lifecycle {
+    pub(crate) rules: Vec<LifecycleRule>,
+}
+
+impl Lifecycle {
+    /// Construct a new lifecycle configuration, without any rule.
+    pub fn new() -> Lifecycle {
+        Lifecycle::default()
+    }
+
+    /// Add a rule to the configuration.
+    pub fn rule(mut self, rule: LifecycleRule) -> Lifecycle {
+        self.rules.push(rule);
+        self
+    }
+
+    /// Get the rules of the configuration.
+    pub fn rules(&self) -> &[LifecycleRule] {
+        self.rules.as_slice()
+    }
+
+    /// Determine the first rule that would apply to an object (in its current state), and when, if any.
+    ///
+    /// When several rules would apply at the same time, deletions take precedence over storage class changes,
+    /// like Cloud Storage does, and otherwise the first one in the configuration is reported.
+    pub fn evaluate(&self, object: &LifecycleObject) -> Option<LifecycleOutcome> {
+        self.rules
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, rule)| {
+                let at = rule.condition.applies_at(object)?;
+                Some(LifecycleOutcome {
+                    rule_index: idx,
+                    action: rule.action.clone(),
+                    applies_at: at,
+                })
+            })
+            .min_by_key(|outcome| {
+                let is_deletion = outcome.action == LifecycleAction::Delete;
+                (outcome.applies_at, !is_deletion, outcome.rule_index)
+            })
+    }
+}
+
+impl TryFrom<&BucketLifecycle> for Lifecycle {
+    type Error = ConvertError;
+
+    fn try_from(lifecycle: &BucketLifecycle) -> Result<Lifecycle, ConvertError> {
+        let rules = lifecycle.rule.iter().map(LifecycleRule::try_from);
+        Ok(Lifecycle {
+            rules: rules.collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/// Represents the metadata of an object that lifecycle rules depend on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LifecycleObject {
+    pub(crate) name: String,
+    pub(crate) created: NaiveDateTime,
+    pub(crate) storage_class: String,
+    pub(crate) live: bool,
+    pub(crate) num_newer_versions: u32,
+}
+
+impl LifecycleObject {
+    /// Construct the metadata of a live object, in the `STANDARD` storage class.
+    pub fn new(name: impl Into<String>, created: NaiveDateTime) -> LifecycleObject {
+        LifecycleObject {
+            name: name.into(),
+            created,
+            storage_class: String::from("STANDARD"),
+            live: true,
+            num_newer_versions: 0,
+        }
+    }
+
+    /// Set the storage class of the object.
+    pub fn storage_class(mut self, class: impl Into<String>) -> LifecycleObject {
+        self.storage_class = class.into();
+        self
+    }
+
+    /// Mark the object as noncurrent, with the given number of newer versions (at least one).
+    pub fn noncurrent(mut self, num_newer_versions: u32) -> LifecycleObject {
+        self.live = false;
+        self.num_newer_versions = num_newer_versions.max(1);
+        self
+    }
+
+    /// Is the object live (as opposed to noncurrent) ?
+    pub fn is_live(&self) -> bool {
+        self.live
+    }
+}
+
+impl TryFrom<&ObjectResource> for LifecycleObject {
+    type Error = ConvertError;
+
+    fn try_from(resource: &ObjectResource) -> Result<LifecycleObject, ConvertError> {
+        let created = DateTime::parse_from_rfc3339(resource.time_created.as_str())
+            .map_err(|_| ConvertError::InvalidDate(resource.time_created.clone()))?;
+        let object = LifecycleObject::new(resource.name.clone(), created.naive_utc())
+            .storage_class(resource.storage_class.clone());
+
+        //? The metadata of noncurrent objects doesn't tell how many newer versions they have, but there is one at least.
+        match resource.time_deleted {
+            Some(_) => Ok(object.noncurrent(1)),
+            None => Ok(object),
+        }
+    }
+}
+
+/// Represents the rule of a lifecycle configuration that would apply to an object.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LifecycleOutcome {
+    pub(crate) rule_index: usize,
+    pub(crate) action: LifecycleAction,
+    pub(crate) applies_at: NaiveDateTime,
+}
+
+impl LifecycleOutcome {
+    /// The position of the rule within the configuration.
+    pub fn rule_index(&self) -> usize {
+        self.rule_index
+    }
+
+    /// The action that would be taken.
+    pub fn action(&self) -> &LifecycleAction {
+        &self.action
+    }
+
+    /// The time (in UTC) from which the rule applies, which is in the past if it already does.
+    ///
+    /// Cloud Storage evaluates rules asynchronously, so the action may only be taken some time after.
+    pub fn applies_at(&self) -> NaiveDateTime {
+        self.applies_at
+    }
+}
@@ -0,0 +1,246 @@
+use std::collections::VecDeque;
+
+use futures::stream::{FuturesUnordered, StreamExt};
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+
+use crate::storage::api::object::{ObjectResource, ObjectResources};
+use crate::storage::{Client, Error};
+
+/// A lexicographic range of object names sharing a prefix, which can be listed independently of the others.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct NameRange {
+    /// The lower bound of the range (the prefix itself if unset).
+    pub(crate) start: Option<String>,
+    /// Whether the lower bound is excluded from the range (when it was already listed).
+    pub(crate) start_exclusive: bool,
+    /// The (excluded) upper bound of the range (unbounded within the prefix if unset).
+    pub(crate) end: Option<String>,
+}
+
+impl NameRange {
+    /// The range of all the names sharing a prefix.
+    pub(crate) fn full() -> NameRange {
+        NameRange {
+            start: None,
+            start_exclusive: false,
+            end: None,
+        }
+    }
+
+    /// Splits the range into two halves, if it can be.
+    pub(crate) fn split(&self, prefix: &str) -> Option<(NameRange, NameRange)> {
+        let start = self.start.as_deref().unwrap_or(prefix);
+        let mid = midpoint(prefix, start, self.end.as_deref())?;
+        let lower = NameRange {
+            end: Some(mid.clone()),
+            ..self.clone()
+        };
+        let upper = NameRange {
+            start: Some(mid),
+            start_exclusive: false,
+            end: self.end.clone(),
+        };
+
+        Some((lower, upper))
+    }
+}
+
+/// Finds a name strictly between `lower` and `upper`, both starting with `prefix`.
+///
+/// Names are compared by code points (which is how Cloud Storage orders them, as UTF-8 preserves that ordering).
+/// An unbounded `upper` stands for the end of the names sharing the prefix,
+/// and most names being ASCII, the midpoints are then chosen within the ASCII range first.
+pub(crate) fn midpoint(prefix: &str, lower: &str, upper: Option<&str>) -> Option<String> {
+    //? Upper bounds of the code points, within ASCII and overall.
+    const ASCII_CEILING: u32 = 0x80;
+    const CEILING: u32 = 0x11_0000;
+    fn ceiling(digit: u32) -> u32 {
+        if digit + 1 < ASCII_CEILING {
+            ASCII_CEILING
+        } else {
+            CEILING
+        }
+    }
+
+    let lower: Vec<u32> = lower.chars().map(u32::from).collect();
+    let (upper, unbounded): (Vec<u32>, bool) = match upper {
+        Some(upper) => (upper.chars().map(u32::from).collect(), false),
+        None => (prefix.chars().map(u32::from).collect(), true),
+    };
+    let common = lower
+        .iter()
+        .zip(upper.iter())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let lo = lower.get(common).copied();
+    let hi = match upper.get(common).copied() {
+        Some(hi) => hi,
+        None if unbounded => ceiling(lo.unwrap_or(0)),
+        //? The upper bound is a prefix of (so not above) the lower one.
+        None => return None,
+    };
+
+    let mut digits = lower[..common].to_vec();
+    match (lo, between(lo, hi)) {
+        (_, Some(digit)) => digits.push(digit),
+        (Some(lo), None) if lo < hi => {
+            //? Nothing fits at this position, so find room further down, above the rest of the lower bound.
+            digits.push(lo);
+            let rest = &lower[common + 1..];
+            let room = rest
+                .iter()
+                .enumerate()
+                .find_map(|(idx, digit)| Some((idx, between(Some(*digit), ceiling(*digit))?)));
+            match room {
+                Some((idx, digit)) => {
+                    digits.extend_from_slice(&rest[..idx]);
+                    digits.push(digit);
+                }
+                None => {
+                    digits.extend_from_slice(rest);
+                    digits.push(ASCII_CEILING / 2);
+                }
+            }
+        }
+        _ => return None,
+    }
+
+    digits.into_iter().map(char::from_u32).collect()
+}
+
+/// Finds a code point strictly between `lo` (or any code point, if unset) and `hi`, skipping surrogates.
+fn between(lo: Option<u32>, hi: u32) -> Option<u32> {
+    const SURROGATES: std::ops::RangeInclusive<u32> = 0xD800..=0xDFFF;
+
+    let min = lo.map_or(0, |lo| lo + 1);
+    if min >= hi {
+        return None;
+    }
+    let digit = min + (hi - min) / 2;
+    if !SURROGATES.contains(&digit) {
+        Some(digit)
+    } else if SURROGATES.end() + 1 < hi {
+        Some(SURROGATES.end() + 1)
+    } else if *SURROGATES.start() > min {
+        Some(SURROGATES.start() - 1)
+    } else {
+        None
+    }
+}
+
+/// Lists a single page of the objects within a range of names.
+pub(crate) async fn list_page(
+    client: &Client,
+    bucket: &str,
+    prefix: &str,
+    range: &NameRange,
+    page_token: Option<&str>,
+) -> Result<ObjectResources, Error> {
+    let uri = format!(
+        "{}/b/{}/o",
+        Client::ENDPOINT,
+        utf8_percent_encode(bucket, NON_ALPHANUMERIC),
+    );
+    let mut query = vec![("prefix", prefix)];
+    if let Some(start) = range.start.as_deref() {
+        query.push(("startOffset", start));
+    }
+    if let Some(end) = range.end.as_deref() {
+        query.push(("endOffset", end));
+    }
+    if let Some(page_token) = page_token {
+        query.push(("pageToken", page_token));
+    }
+
+    let token = client.token_manager.lock().await.token().await?;
+    let request = client
+        .client
+        .get(uri.as_str())
+        .query(&query)
+        .header("authorization", token)
+        .send();
+    let response = request.await?;
+    let mut resources = response
+        .error_for_status()?
+        .json::<ObjectResources>()
+        .await?;
+    if let (true, Some(start)) = (range.start_exclusive, range.start.as_deref()) {
+        resources.items.retain(|item| item.name != start);
+    }
+
+    Ok(resources)
+}
+
+/// Lists all the objects sharing a prefix, using up to `concurrency` concurrent listings.
+///
+/// The names are first split into as many ranges as there are listings, and whenever a listing
+/// has more pages to go while another one is idle, the rest of its range gets split again.
+/// This keeps all listings busy even when the names are unevenly distributed.
+pub(crate) async fn list_parallel(
+    client: &Client,
+    bucket: &str,
+    prefix: &str,
+    concurrency: usize,
+) -> Result<Vec<ObjectResource>, Error> {
+    let concurrency = concurrency.max(1);
+    let mut pending = VecDeque::new();
+    pending.push_back((NameRange::full(), None));
+    let mut attempts = 0;
+    while pending.len() < concurrency && attempts < concurrency {
+        let (range, token): (NameRange, Option<String>) = pending.pop_front().unwrap();
+        match range.split(prefix) {
+            Some((lower, upper)) => {
+                pending.push_back((lower, None));
+                pending.push_back((upper, None));
+            }
+            None => pending.push_back((range, token)),
+        }
+        attempts += 1;
+    }
+
+    let mut items = Vec::new();
+    let mut running = FuturesUnordered::new();
+    loop {
+        while running.len() < concurrency {
+            let (range, token) = match pending.pop_front() {
+                Some(next) => next,
+                None => break,
+            };
+            running.push(async move {
+                let page = list_page(client, bucket, prefix, &range, token.as_deref()).await;
+                page.map(|page| (range, page))
+            });
+        }
+        let (range, page) = match running.next().await {
+            Some(result) => result?,
+            None => break,
+        };
+
+        let last = page.items.last().map(|item| item.name.clone());
+        items.extend(page.items);
+        let token = match page.next_page_token {
+            Some(token) => token,
+            None => continue,
+        };
+        let idle = running.len() + pending.len() < concurrency;
+        let remaining = last.map(|last| NameRange {
+            start: Some(last),
+            start_exclusive: true,
+            end: range.end.clone(),
+        });
+        match remaining
+            .as_ref()
+            .filter(|_| idle)
+            .and_then(|r| r.split(prefix))
+        {
+            Some((lower, upper)) => {
+                pending.push_back((lower, None));
+                pending.push_back((upper, None));
+            }
+            None => pending.push_back((range, Some(token))),
+        }
+    }
+    items.sort_by(|a, b| a.name.cmp(&b.name));
+
+    Ok(items)
+}
@@ -1,11 +1,19 @@
-mod api;
+pub(crate) mod api;
 mod bucket;
+mod cache;
 mod client;
+mod lifecycle;
+mod listing;
 mod object;
+mod upload;
 
 pub use self::bucket::*;
+pub(crate) use self::cache::*;
 pub use self::client::*;
+pub use self::lifecycle::*;
+pub(crate) use self::listing::*;
 pub use self::object::*;
+pub use self::upload::*;
 
 /// The error type for the Cloud Storage module.
 pub type Error = crate::error::Error;
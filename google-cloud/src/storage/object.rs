@@ -1,5 +1,7 @@
+use bytes::Bytes;
 use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
 
+use crate::error::WithContext;
 use crate::storage::{Client, Error};
 
 /// Represents a Cloud Storage bucket.
@@ -44,7 +46,10 @@ impl Object {
     // }
 
     /// Get the entire contents of the object.
-    pub async fn get(&mut self) -> Result<Vec<u8>, Error> {
+    ///
+    /// The contents are returned as the buffer received from the HTTP client, without copying.
+    /// Use `Vec::from` (or `.to_vec()`) to obtain an owned `Vec<u8>`.
+    pub async fn get(&mut self) -> Result<Bytes, Error> {
         let client = &mut self.client;
         let inner = &client.client;
         let uri = format!(
@@ -60,8 +65,14 @@ impl Object {
             .query(&[("alt", "media")])
             .header("authorization", token)
             .send();
-        let response = request.await?;
-        let bytes = response.error_for_status()?.bytes().await?.to_vec();
+        let response = request
+            .await
+            .and_then(|response| response.error_for_status());
+        let response = response.context(
+            "GetObject",
+            format_args!("gs://{}/{}", self.bucket, self.name),
+        )?;
+        let bytes = response.bytes().await?;
 
         Ok(bytes)
     }
@@ -82,8 +93,16 @@ impl Object {
             .delete(uri.as_str())
             .header("authorization", token)
             .send();
-        let response = request.await?;
-        response.error_for_status()?;
+        let response = request
+            .await
+            .and_then(|response| response.error_for_status());
+        response.context(
+            "DeleteObject",
+            format_args!("gs://{}/{}", self.bucket, self.name),
+        )?;
+        if let Some(cache) = client.cache.as_ref() {
+            cache.invalidate_object(&self.bucket, &self.name);
+        }
 
         Ok(())
     }
@@ -0,0 +1,345 @@
+use std::collections::hash_map::RandomState;
+use std::future::Future;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+use bytes::Bytes;
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+use reqwest::{Response, StatusCode};
+
+use crate::error::WithContext;
+use crate::storage::api::object::ObjectResource;
+use crate::storage::{Bucket, Client, Error, Object};
+
+/// Represents how resumable uploads recover from transient failures, with exponential backoff and jitter.
+///
+/// Requests failing with a `408`, `429` or `5xx` status (or without reaching the service) are retried:
+/// the status of the upload session is queried, and the upload resumes from the last byte persisted by the service.
+/// The attempts are counted from the last progress of the upload, so long uploads aren't given up
+/// because of unrelated failures spread over their duration.
+/// Chunks accepted by the service without persisting any new byte count as failed attempts too.
+///
+/// ```
+/// # use std::time::Duration;
+/// # use google_cloud::storage::RetryConfig;
+/// let config = RetryConfig::default()
+///     .max_attempts(8)
+///     .initial_backoff(Duration::from_millis(500))
+///     .max_backoff(Duration::from_secs(30));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryConfig {
+    pub(crate) max_attempts: usize,
+    pub(crate) initial_backoff: Duration,
+    pub(crate) max_backoff: Duration,
+    pub(crate) multiplier: f64,
+}
+
+impl Default for RetryConfig {
+    fn default() -> RetryConfig {
+        RetryConfig {
+            max_attempts: 5,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(32),
+            multiplier: 2.0,
+        }
+    }
+}
+
+impl RetryConfig {
+    /// Never retry requests, surfacing every failure to the caller.
+    pub fn disabled() -> RetryConfig {
+        RetryConfig::default().max_attempts(1)
+    }
+
+    /// Set the maximum number of consecutive attempts without progress, including the first one.
+    pub fn max_attempts(mut self, attempts: usize) -> RetryConfig {
+        self.max_attempts = attempts.max(1);
+        self
+    }
+
+    /// Set the delay before the first retry.
+    pub fn initial_backoff(mut self, backoff: Duration) -> RetryConfig {
+        self.initial_backoff = backoff;
+        self
+    }
+
+    /// Set the maximum delay between two attempts.
+    pub fn max_backoff(mut self, backoff: Duration) -> RetryConfig {
+        self.max_backoff = backoff;
+        self
+    }
+
+    /// Set the factor by which the delay grows after every retry (at least 1).
+    pub fn multiplier(mut self, multiplier: f64) -> RetryConfig {
+        self.multiplier = multiplier.max(1.0);
+        self
+    }
+
+    /// The delay to wait for after the given (failed) attempt, the first one being `1`.
+    ///
+    /// It is picked at random in the upper half of the exponential backoff,
+    /// so that concurrent uploads failing together don't retry in lockstep.
+    pub(crate) fn backoff(&self, attempt: usize) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(i32::MAX as usize) as i32;
+        let backoff = self.initial_backoff.as_secs_f64() * self.multiplier.powi(exponent);
+        let backoff = backoff.min(self.max_backoff.as_secs_f64());
+
+        //? `RandomState` is randomly seeded, which is enough for jitter without depending on `rand`.
+        let mut hasher = RandomState::new().build_hasher();
+        hasher.write_usize(attempt);
+        let jitter = (hasher.finish() as f64) / (u64::MAX as f64);
+        Duration::from_secs_f64(backoff * (0.5 + jitter / 2.0))
+    }
+
+    /// Can the given (failed) attempt be retried ?
+    pub(crate) fn should_retry(&self, attempt: usize, err: &Error) -> bool {
+        attempt < self.max_attempts && is_retryable(err)
+    }
+}
+
+/// Is the error transient, so that retrying the request may succeed ?
+pub(crate) fn is_retryable(err: &Error) -> bool {
+    match err.root() {
+        Error::Reqwest(err) => match err.status() {
+            Some(status) => is_retryable_status(status),
+            None => err.is_timeout() || err.is_connect() || err.is_request(),
+        },
+        _ => false,
+    }
+}
+
+/// Is the status transient, so that retrying the request may succeed ?
+pub(crate) fn is_retryable_status(status: StatusCode) -> bool {
+    status == StatusCode::REQUEST_TIMEOUT
+        || status == StatusCode::TOO_MANY_REQUESTS
+        || status.is_server_error()
+}
+
+/// Parses the `Range` header of an upload session status, returning the number of bytes persisted so far.
+pub(crate) fn persisted_bytes(range: &str) -> Option<u64> {
+    let (start, end) = range.strip_prefix("bytes=")?.split_once('-')?;
+    if start.trim() != "0" {
+        return None;
+    }
+    end.trim().parse::<u64>().ok().map(|end| end + 1)
+}
+
+/// The state of an upload session, as reported by the service.
+pub(crate) enum UploadStatus {
+    /// The upload is incomplete, with the given number of bytes persisted so far.
+    Persisted(u64),
+    /// The upload is complete, and the object got created.
+    Done(Box<ObjectResource>),
+}
+
+impl Bucket {
+    /// Insert a new object into the bucket, with a resumable upload.
+    ///
+    /// The data is sent in chunks, and the upload recovers from transient failures (see `RetryConfig`)
+    /// by resuming from the last byte persisted by the service, instead of starting over or failing mid-upload.
+    /// This makes it better suited than `Bucket::create_object` to large objects and unreliable networks.
+    ///
+    /// ```no_run
+    /// # use google_cloud::storage::{Client, RetryConfig};
+    /// # async fn example(client: Client) -> Result<(), google_cloud::error::Error> {
+    /// let mut client = client.upload_retry(RetryConfig::default().max_attempts(10));
+    /// let mut bucket = client.bucket("my-bucket").await?;
+    /// let data = std::fs::read("backup.tar.gz")?;
+    /// bucket
+    ///     .create_object_resumable("backups/latest.tar.gz", data, "application/gzip")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_object_resumable(
+        &mut self,
+        name: &str,
+        data: impl Into<Bytes>,
+        mime_type: impl AsRef<str>,
+    ) -> Result<Object, Error> {
+        let data = data.into();
+        let resource = format!("gs://{}/{}", self.name, name);
+        let session = self.start_upload(name, mime_type.as_ref(), data.len());
+        let session = session.await.context("InsertObject", &resource)?;
+        let resource = upload(&self.client, &session, &data)
+            .await
+            .context("InsertObject", &resource)?;
+        let name = resource.name.clone();
+        if let Some(cache) = self.client.cache.as_ref() {
+            cache.insert_object(resource);
+        }
+
+        Ok(Object::new(self.client.clone(), self.name.clone(), name))
+    }
+
+    /// Starts a resumable upload session, returning its URI.
+    async fn start_upload(
+        &self,
+        name: &str,
+        mime_type: &str,
+        size: usize,
+    ) -> Result<String, Error> {
+        let client = &self.client;
+        let uri = format!(
+            "{}/b/{}/o",
+            Client::UPLOAD_ENDPOINT,
+            utf8_percent_encode(&self.name, NON_ALPHANUMERIC),
+        );
+
+        let mut attempt = 1;
+        loop {
+            let token = client.token_manager.lock().await.token().await?;
+            let request = client
+                .client
+                .post(uri.as_str())
+                .query(&[("uploadType", "resumable"), ("name", name)])
+                .header("authorization", token)
+                .header("x-upload-content-type", mime_type)
+                .header("x-upload-content-length", size)
+                .header("content-length", 0)
+                .send();
+            let response = request
+                .await
+                .and_then(|response| response.error_for_status())
+                .map_err(Error::from);
+            match response {
+                Ok(response) => {
+                    let location = response.headers().get("location");
+                    let location = location.and_then(|location| location.to_str().ok());
+                    let location = location.ok_or_else(|| {
+                        Error::Upload(String::from("the upload session has no location"))
+                    })?;
+                    return Ok(String::from(location));
+                }
+                Err(err) if client.upload_retry.should_retry(attempt, &err) => {
+                    tokio::time::sleep(client.upload_retry.backoff(attempt)).await;
+                    attempt += 1;
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    }
+}
+
+/// The size of the chunks of resumable uploads, which must be a multiple of 256 KiB.
+pub(crate) const CHUNK_SIZE: usize = 32 * 256 * 1024;
+
+/// Sends the data to an upload session, resuming from the last persisted byte after transient failures.
+async fn upload(client: &Client, session: &str, data: &Bytes) -> Result<ObjectResource, Error> {
+    let size = data.len();
+    drive_upload(
+        &client.upload_retry,
+        size,
+        |offset, recovering| async move {
+            //? After a failure, the bytes persisted by the service are unknown until its status is queried.
+            match recovering {
+                true => query_status(client, session, size).await,
+                false => send_chunk(client, session, data, offset).await,
+            }
+        },
+    )
+    .await
+}
+
+/// Drives an upload of the given size, requesting the chunk starting at the given offset to be sent
+/// (or the status of the session to be queried, when recovering from a failure) until the upload is done.
+///
+/// Requests which fail, or which persist no new bytes, count as failed attempts:
+/// the upload is given up after too many of them in a row.
+pub(crate) async fn drive_upload<F, Fut>(
+    config: &RetryConfig,
+    size: usize,
+    mut request: F,
+) -> Result<ObjectResource, Error>
+where
+    F: FnMut(usize, bool) -> Fut,
+    Fut: Future<Output = Result<UploadStatus, Error>>,
+{
+    let mut offset = 0;
+    let mut attempt = 1;
+    let mut recovering = false;
+    loop {
+        match request(offset, recovering).await {
+            Ok(UploadStatus::Done(resource)) => return Ok(*resource),
+            Ok(UploadStatus::Persisted(persisted)) => {
+                let persisted = (persisted as usize).min(size);
+                //? Recovering from a failure (already counted) may find no new bytes, sending a chunk may not.
+                let stalled = persisted < offset || (persisted == offset && !recovering);
+                if persisted > offset {
+                    attempt = 1;
+                }
+                offset = persisted;
+                recovering = false;
+                if stalled {
+                    if attempt >= config.max_attempts {
+                        return Err(Error::Upload(format!(
+                            "no progress after {} attempts (at byte {} of {})",
+                            attempt, offset, size
+                        )));
+                    }
+                    tokio::time::sleep(config.backoff(attempt)).await;
+                    attempt += 1;
+                }
+            }
+            Err(err) if config.should_retry(attempt, &err) => {
+                tokio::time::sleep(config.backoff(attempt)).await;
+                attempt += 1;
+                recovering = true;
+            }
+            Err(err) => return Err(err),
+        }
+    }
+}
+
+/// Sends the chunk of data starting at the given offset.
+async fn send_chunk(
+    client: &Client,
+    session: &str,
+    data: &Bytes,
+    offset: usize,
+) -> Result<UploadStatus, Error> {
+    let end = (offset + CHUNK_SIZE).min(data.len());
+    let range = if data.is_empty() {
+        String::from("bytes */0")
+    } else {
+        format!("bytes {}-{}/{}", offset, end - 1, data.len())
+    };
+    let token = client.token_manager.lock().await.token().await?;
+    let request = client
+        .client
+        .put(session)
+        .header("authorization", token)
+        .header("content-range", range)
+        .header("content-length", end - offset)
+        .body(data.slice(offset..end))
+        .send();
+    upload_status(request.await?).await
+}
+
+/// Queries the status of an upload session.
+async fn query_status(client: &Client, session: &str, size: usize) -> Result<UploadStatus, Error> {
+    let token = client.token_manager.lock().await.token().await?;
+    let request = client
+        .client
+        .put(session)
+        .header("authorization", token)
+        .header("content-range", format!("bytes */{}", size))
+        .header("content-length", 0)
+        .send();
+    upload_status(request.await?).await
+}
+
+async fn upload_status(response: Response) -> Result<UploadStatus, Error> {
+    let response = response.error_for_status()?;
+    if response.status() != StatusCode::PERMANENT_REDIRECT {
+        let resource = response.json::<ObjectResource>().await?;
+        return Ok(UploadStatus::Done(Box::new(resource)));
+    }
+    //? Without a `Range` header, no bytes were persisted yet.
+    let range = response.headers().get("range");
+    let range = range.and_then(|range| range.to_str().ok());
+    Ok(UploadStatus::Persisted(
+        range.and_then(persisted_bytes).unwrap_or(0),
+    ))
+}
@@ -0,0 +1,108 @@
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+/// The name of the task periodically resolving the endpoints of balanced channels again
+/// (see `channel::LoadBalancing::Balanced`), one per channel.
+pub const CHANNEL_RESOLVER: &str = "google-cloud:channel-resolver";
+
+/// The name of the task extending the leases of the messages received from a subscription, one per subscription.
+pub const PUBSUB_LEASE_EXTENDER: &str = "google-cloud:pubsub-lease-extender";
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+struct TaskCount {
+    spawned: usize,
+    finished: usize,
+}
+
+//? A `BTreeMap` can be built in a constant, unlike a `HashMap`, and keeps the stats ordered by name.
+static TASKS: Mutex<BTreeMap<&'static str, TaskCount>> = Mutex::new(BTreeMap::new());
+
+/// Represents a snapshot of the background tasks spawned by this crate, by name.
+///
+/// The running counts are meant for leak detection: they should go back to zero once the clients
+/// (and the values they handed out, such as received messages) have been dropped.
+///
+/// ```
+/// # use google_cloud::tasks;
+/// let stats = tasks::stats();
+/// for name in stats.names() {
+///     println!("{}: {} running", name, stats.running(name));
+/// }
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct TaskStats {
+    counts: BTreeMap<&'static str, TaskCount>,
+}
+
+impl TaskStats {
+    /// The names of the tasks spawned so far.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.counts.keys().copied()
+    }
+
+    /// The number of tasks with this name spawned so far.
+    pub fn spawned(&self, name: &str) -> usize {
+        self.counts.get(name).map_or(0, |count| count.spawned)
+    }
+
+    /// The number of tasks with this name still running.
+    pub fn running(&self, name: &str) -> usize {
+        self.counts
+            .get(name)
+            .map_or(0, |count| count.spawned - count.finished)
+    }
+
+    /// The number of tasks still running, whatever their names.
+    pub fn total_running(&self) -> usize {
+        self.counts
+            .values()
+            .map(|count| count.spawned - count.finished)
+            .sum()
+    }
+}
+
+/// Takes a snapshot of the background tasks spawned by this crate.
+pub fn stats() -> TaskStats {
+    let counts = TASKS.lock().unwrap_or_else(|err| err.into_inner()).clone();
+    TaskStats { counts }
+}
+
+/// Counts a task as finished when dropped, which also covers the tasks cancelled with their runtime.
+struct Finished(&'static str);
+
+impl Drop for Finished {
+    fn drop(&mut self) {
+        let mut tasks = TASKS.lock().unwrap_or_else(|err| err.into_inner());
+        if let Some(count) = tasks.get_mut(self.0) {
+            count.finished += 1;
+        }
+    }
+}
+
+/// Spawns a named background task, counted in the task stats.
+///
+/// With `--cfg tokio_unstable`, the name is also given to the tokio task itself,
+/// which makes it identifiable in `tokio-console` and runtime dumps.
+pub(crate) fn spawn<F>(name: &'static str, task: F)
+where
+    F: std::future::Future<Output = ()> + Send + 'static,
+{
+    let mut tasks = TASKS.lock().unwrap_or_else(|err| err.into_inner());
+    tasks.entry(name).or_default().spawned += 1;
+    drop(tasks);
+
+    let finished = Finished(name);
+    let task = async move {
+        let _finished = finished;
+        task.await
+    };
+
+    #[cfg(tokio_unstable)]
+    {
+        let spawned = tokio::task::Builder::new().name(name).spawn(task);
+        //? Spawning only fails outside of a runtime, where `tokio::spawn` panics as well.
+        spawned.expect("failed to spawn a background task");
+    }
+    #[cfg(not(tokio_unstable))]
+    tokio::spawn(task);
+}
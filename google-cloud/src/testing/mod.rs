@@ -0,0 +1,31 @@
+//! Each `Namespace` has a unique run ID, which it prefixes to the names of the resources it hands out,
+//! so that concurrent test runs against the same project never step on each other's resources:
+//!
+//! ```no_run
+//! # use google_cloud::{pubsub, testing};
+//! # async fn run() -> Result<(), testing::Error> {
+//! let namespace = testing::Namespace::new("my-project")?;
+//! let mut client = pubsub::Client::new("my-project").await?;
+//!
+//! let config = pubsub::TopicConfig::default();
+//! let topic = client.create_topic(&namespace.topic("events"), config).await?;
+//! // ...
+//!
+//! //? Deletes the topic (dropping the namespace does the same, even when the test panics).
+//! namespace.cleanup().await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Only the resources whose names were requested from the namespace get deleted,
+//! and the ones that were never created are ignored.
+
+//? The crate-wide `Error` type is large because of `tonic::Status`, but is still used here for consistency.
+#![allow(clippy::result_large_err)]
+
+mod namespace;
+
+pub use self::namespace::*;
+
+/// The error type for the testing module.
+pub type Error = crate::error::Error;
@@ -0,0 +1,355 @@
+use std::env;
+use std::fs::File;
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::authorize::ApplicationCredentials;
+use crate::testing::Error;
+
+#[cfg(feature = "datastore")]
+use crate::datastore;
+#[cfg(feature = "pubsub")]
+use crate::pubsub;
+#[cfg(feature = "storage")]
+use crate::storage;
+
+//? Distinguishes the namespaces created by a same process within the same millisecond.
+static NAMESPACE_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+/// A resource handed out by a namespace, to delete during cleanup.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Resource {
+    #[cfg(feature = "pubsub")]
+    Topic(String),
+    #[cfg(feature = "pubsub")]
+    Subscription(String),
+    #[cfg(feature = "storage")]
+    Bucket(String),
+    #[cfg(feature = "storage")]
+    Object(String, String),
+    #[cfg(feature = "datastore")]
+    Kind(String),
+}
+
+impl Resource {
+    /// The resources of a lower rank must be deleted first,
+    /// as they would otherwise prevent deleting (or outlive) the others.
+    pub(crate) fn rank(&self) -> u8 {
+        match *self {
+            #[cfg(feature = "pubsub")]
+            Resource::Subscription(_) => 0,
+            #[cfg(feature = "pubsub")]
+            Resource::Topic(_) => 1,
+            #[cfg(feature = "storage")]
+            Resource::Object(_, _) => 0,
+            #[cfg(feature = "storage")]
+            Resource::Bucket(_) => 1,
+            #[cfg(feature = "datastore")]
+            Resource::Kind(_) => 0,
+        }
+    }
+}
+
+/// A set of uniquely-named test resources, deleted on cleanup.
+///
+/// The resources are deleted either by calling `cleanup`, or when the namespace gets dropped
+/// (including while unwinding from a panic), on a dedicated thread.
+pub struct Namespace {
+    pub(crate) project_name: String,
+    pub(crate) creds: ApplicationCredentials,
+    pub(crate) prefix: String,
+    pub(crate) run_id: String,
+    pub(crate) resources: Mutex<Vec<Resource>>,
+}
+
+impl Namespace {
+    /// The default prefix of the names handed out by a namespace.
+    pub const DEFAULT_PREFIX: &'static str = "test";
+
+    /// Create a new namespace for the specified project.
+    ///
+    /// Credentials are looked up in the `GOOGLE_APPLICATION_CREDENTIALS` environment variable.
+    pub fn new(project_name: impl Into<String>) -> Result<Namespace, Error> {
+        let path = env::var("GOOGLE_APPLICATION_CREDENTIALS")?;
+        let file = File::open(path)?;
+        let creds = json::from_reader(file)?;
+
+        Ok(Namespace::from_credentials(project_name, creds))
+    }
+
+    /// Create a new namespace for the specified project with custom credentials.
+    ///
+    /// The credentials are used to delete the resources on cleanup.
+    pub fn from_credentials(
+        project_name: impl Into<String>,
+        creds: ApplicationCredentials,
+    ) -> Namespace {
+        let millis = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_millis());
+        let count = NAMESPACE_COUNT.fetch_add(1, Ordering::Relaxed);
+
+        Namespace {
+            project_name: project_name.into(),
+            creds,
+            prefix: String::from(Namespace::DEFAULT_PREFIX),
+            run_id: format!("{:x}-{:x}-{}", millis, process::id(), count),
+            resources: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Set the prefix of the names handed out by the namespace.
+    pub fn prefix(mut self, prefix: impl Into<String>) -> Namespace {
+        self.prefix = prefix.into();
+        self
+    }
+
+    /// The unique ID of the namespace.
+    pub fn run_id(&self) -> &str {
+        self.run_id.as_str()
+    }
+
+    /// Get a unique name derived from the given one, without registering any resource for cleanup.
+    pub fn name(&self, base: &str) -> String {
+        format!("{}-{}-{}", self.prefix, self.run_id, base)
+    }
+
+    /// Get the ID of a unique topic, deleted on cleanup.
+    #[cfg(feature = "pubsub")]
+    pub fn topic(&self, base: &str) -> String {
+        let id = self.name(base);
+        self.register(Resource::Topic(id.clone()));
+        id
+    }
+
+    /// Get the ID of a unique subscription, deleted on cleanup (before any topic).
+    #[cfg(feature = "pubsub")]
+    pub fn subscription(&self, base: &str) -> String {
+        let id = self.name(base);
+        self.register(Resource::Subscription(id.clone()));
+        id
+    }
+
+    /// Get the name of a unique bucket, deleted on cleanup.
+    ///
+    /// Bucket names must be lowercase, so the name is lowercased.
+    /// The bucket must be empty by then, so its objects must be registered using `object`.
+    #[cfg(feature = "storage")]
+    pub fn bucket(&self, base: &str) -> String {
+        let name = self.name(base).to_lowercase();
+        self.register(Resource::Bucket(name.clone()));
+        name
+    }
+
+    /// Register an object for deletion on cleanup (before any bucket), returning its name unchanged.
+    ///
+    /// Object names only need to be unique within their bucket, so they are left as-is.
+    #[cfg(feature = "storage")]
+    pub fn object(&self, bucket: &str, name: &str) -> String {
+        self.register(Resource::Object(bucket.to_string(), name.to_string()));
+        name.to_string()
+    }
+
+    /// Get the name of a unique entity kind, whose entities are all deleted on cleanup.
+    #[cfg(feature = "datastore")]
+    pub fn kind(&self, base: &str) -> String {
+        let kind = self.name(base);
+        self.register(Resource::Kind(kind.clone()));
+        kind
+    }
+
+    /// Delete all the registered resources.
+    ///
+    /// The deletion continues past failures, and the first of them is returned.
+    pub async fn cleanup(self) -> Result<(), Error> {
+        let resources = self.take_resources();
+        delete_resources(self.project_name.clone(), self.creds.clone(), resources).await
+    }
+
+    #[allow(dead_code)]
+    fn register(&self, resource: Resource) {
+        let mut resources = self.resources.lock().unwrap_or_else(|err| err.into_inner());
+        if !resources.contains(&resource) {
+            resources.push(resource);
+        }
+    }
+
+    fn take_resources(&self) -> Vec<Resource> {
+        let mut resources = self.resources.lock().unwrap_or_else(|err| err.into_inner());
+        resources.drain(..).collect()
+    }
+}
+
+impl Drop for Namespace {
+    fn drop(&mut self) {
+        let resources = self.take_resources();
+        if resources.is_empty() {
+            return;
+        }
+
+        //? `drop` cannot await, and blocking within the current runtime could stall the connections it drives,
+        //? so the resources are deleted using fresh clients, on a runtime of their own.
+        let project_name = self.project_name.clone();
+        let creds = self.creds.clone();
+        let handle = thread::spawn(move || {
+            let runtime = tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()?;
+            runtime.block_on(delete_resources(project_name, creds, resources))
+        });
+        match handle.join() {
+            Ok(Ok(())) => {}
+            Ok(Err(err)) => eprintln!(
+                "failed to clean up the resources of namespace {}: {}",
+                self.run_id, err
+            ),
+            Err(_) => eprintln!(
+                "failed to clean up the resources of namespace {}: cleanup panicked",
+                self.run_id
+            ),
+        }
+    }
+}
+
+/// The clients used to delete resources, connected on first use.
+#[derive(Default)]
+struct Clients {
+    #[cfg(feature = "pubsub")]
+    pubsub: Option<pubsub::Client>,
+    #[cfg(feature = "storage")]
+    storage: Option<storage::Client>,
+    #[cfg(feature = "datastore")]
+    datastore: Option<datastore::Client>,
+}
+
+#[allow(unused_mut, unused_variables)]
+async fn delete_resources(
+    project_name: String,
+    creds: ApplicationCredentials,
+    mut resources: Vec<Resource>,
+) -> Result<(), Error> {
+    //? Resources of a same rank are deleted in the reverse order of their registration.
+    resources.reverse();
+    resources.sort_by_key(Resource::rank);
+
+    let mut clients = Clients::default();
+    let mut first_err = None;
+    for resource in resources {
+        let result = delete_resource(&mut clients, &project_name, &creds, resource).await;
+        match result {
+            Err(err) if !is_not_found(&err) => {
+                first_err.get_or_insert(err);
+            }
+            _ => {}
+        }
+    }
+
+    first_err.map_or(Ok(()), Err)
+}
+
+#[allow(unused_variables)]
+async fn delete_resource(
+    clients: &mut Clients,
+    project_name: &str,
+    creds: &ApplicationCredentials,
+    resource: Resource,
+) -> Result<(), Error> {
+    match resource {
+        #[cfg(feature = "pubsub")]
+        Resource::Topic(id) => {
+            let client = pubsub_client(clients, project_name, creds).await?;
+            let name = format!("projects/{0}/topics/{1}", project_name, id);
+            pubsub::Topic::new(client.clone(), name).delete().await
+        }
+        #[cfg(feature = "pubsub")]
+        Resource::Subscription(id) => {
+            let client = pubsub_client(clients, project_name, creds).await?;
+            let name = format!("projects/{0}/subscriptions/{1}", project_name, id);
+            pubsub::Subscription::new(client.clone(), name)
+                .delete()
+                .await
+        }
+        #[cfg(feature = "storage")]
+        Resource::Bucket(name) => {
+            let client = storage_client(clients, project_name, creds).await?;
+            storage::Bucket::new(client.clone(), name).delete().await
+        }
+        #[cfg(feature = "storage")]
+        Resource::Object(bucket, name) => {
+            let client = storage_client(clients, project_name, creds).await?;
+            storage::Object::new(client.clone(), bucket, name)
+                .delete()
+                .await
+        }
+        #[cfg(feature = "datastore")]
+        Resource::Kind(kind) => {
+            let client = datastore_client(clients, project_name, creds).await?;
+            loop {
+                //? A commit holds at most 500 mutations.
+                let query = datastore::Query::new(kind.as_str()).keys_only().limit(500);
+                let entities = client.query(query).await?;
+                if entities.is_empty() {
+                    break Ok(());
+                }
+                client
+                    .delete_all(entities.into_iter().map(datastore::Entity::into_key))
+                    .await?;
+            }
+        }
+    }
+}
+
+#[cfg(feature = "pubsub")]
+async fn pubsub_client<'a>(
+    clients: &'a mut Clients,
+    project_name: &str,
+    creds: &ApplicationCredentials,
+) -> Result<&'a mut pubsub::Client, Error> {
+    if clients.pubsub.is_none() {
+        let client = pubsub::Client::from_credentials(project_name, creds.clone()).await?;
+        clients.pubsub = Some(client);
+    }
+
+    Ok(clients.pubsub.as_mut().unwrap())
+}
+
+#[cfg(feature = "storage")]
+async fn storage_client<'a>(
+    clients: &'a mut Clients,
+    project_name: &str,
+    creds: &ApplicationCredentials,
+) -> Result<&'a mut storage::Client, Error> {
+    if clients.storage.is_none() {
+        let client = storage::Client::from_credentials(project_name, creds.clone()).await?;
+        clients.storage = Some(client);
+    }
+
+    Ok(clients.storage.as_mut().unwrap())
+}
+
+#[cfg(feature = "datastore")]
+async fn datastore_client<'a>(
+    clients: &'a mut Clients,
+    project_name: &str,
+    creds: &ApplicationCredentials,
+) -> Result<&'a mut datastore::Client, Error> {
+    if clients.datastore.is_none() {
+        let client = datastore::Client::from_credentials(project_name, creds.clone()).await?;
+        clients.datastore = Some(client);
+    }
+
+    Ok(clients.datastore.as_mut().unwrap())
+}
+
+/// Whether the error reports a missing resource, which is then deemed already cleaned up.
+pub(crate) fn is_not_found(err: &Error) -> bool {
+    match err.root() {
+        Error::Status(status) => status.code() == tonic::Code::NotFound,
+        #[cfg(any(feature = "storage", feature = "cloudbuild"))]
+        Error::Reqwest(err) => err.status() == Some(reqwest::StatusCode::NOT_FOUND),
+        _ => false,
+    }
+}
@@ -0,0 +1,112 @@
+use std::ops::Bound;
+
+use crate::bigtable::api::read_rows_response::cell_chunk::RowStatus;
+use crate::bigtable::api::read_rows_response::CellChunk;
+use crate::bigtable::{Row, RowMerger, RowRange};
+
+fn chunk(key: &str, family: Option<&str>, qualifier: Option<&str>, value: &str) -> CellChunk {
+    CellChunk {
+        row_key: key.as_bytes().to_vec(),
+        family_name: family.map(String::from),
+        qualifier: qualifier.map(|qualifier| qualifier.as_bytes().to_vec()),
+        timestamp_micros: 0,
+        labels: Vec::new(),
+        value: value.as_bytes().to_vec(),
+        value_size: 0,
+        row_status: None,
+    }
+}
+
+fn commit(mut chunk: CellChunk) -> CellChunk {
+    chunk.row_status = Some(RowStatus::CommitRow(true));
+    chunk
+}
+
+fn merge(chunks: Vec<CellChunk>) -> Option<Vec<Row>> {
+    let mut merger = RowMerger::new();
+    let mut rows = Vec::new();
+    for chunk in chunks {
+        rows.extend(merger.push(chunk).ok()?);
+    }
+    merger.finish().ok()?;
+    Some(rows)
+}
+
+#[test]
+fn bigtable_merges_chunks_into_rows() {
+    let mut first = chunk("b", Some("f"), Some("q"), "hel");
+    first.value_size = 5;
+    let rows = merge(vec![
+        chunk("a", Some("f"), Some("x"), "1"),
+        chunk("", None, Some("y"), "2"),
+        commit(chunk("", Some("g"), Some("z"), "3")),
+        first,
+        commit(chunk("", None, None, "lo")),
+    ])
+    .unwrap();
+
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[0].key(), b"a");
+    assert_eq!(rows[0].cells().len(), 3);
+    assert_eq!(rows[0].cell("f", "y").unwrap().value(), b"2");
+    assert_eq!(rows[0].cell("g", "z").unwrap().value(), b"3");
+    assert_eq!(rows[1].cell("f", "q").unwrap().value(), b"hello");
+}
+
+#[test]
+fn bigtable_resets_rows() {
+    let mut reset = chunk("", None, None, "");
+    reset.row_status = Some(RowStatus::ResetRow(true));
+    let rows = merge(vec![
+        chunk("a", Some("f"), Some("q"), "stale"),
+        reset,
+        commit(chunk("a", Some("f"), Some("q"), "fresh")),
+    ])
+    .unwrap();
+
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].cells().len(), 1);
+    assert_eq!(rows[0].cell("f", "q").unwrap().value(), b"fresh");
+}
+
+#[test]
+fn bigtable_rejects_invalid_chunks() {
+    assert!(merge(vec![chunk("a", Some("f"), Some("q"), "1")]).is_none());
+    assert!(merge(vec![commit(chunk("", Some("f"), Some("q"), "1"))]).is_none());
+    assert!(merge(vec![commit(chunk("a", None, None, "1"))]).is_none());
+    assert!(merge(vec![
+        commit(chunk("b", Some("f"), Some("q"), "1")),
+        commit(chunk("a", Some("f"), Some("q"), "1")),
+    ])
+    .is_none());
+}
+
+#[test]
+fn bigtable_computes_prefix_ranges() {
+    let range = RowRange::prefix("ab");
+    assert_eq!(range.start, Bound::Included(b"ab".to_vec()));
+    assert_eq!(range.end, Bound::Excluded(b"ac".to_vec()));
+
+    let range = RowRange::prefix(vec![b'a', 0xFF]);
+    assert_eq!(range.end, Bound::Excluded(b"b".to_vec()));
+
+    let range = RowRange::prefix(vec![0xFF]);
+    assert_eq!(range.end, Bound::Unbounded);
+}
+
+#[test]
+fn bigtable_gc_rules_survive_round_trip() {
+    use crate::bigtable::admin::GcRule;
+    use crate::bigtable::api::bigtable::admin::v2 as admin_api;
+
+    let rule = GcRule::Union(vec![
+        GcRule::MaxVersions(3),
+        GcRule::Intersection(vec![
+            GcRule::MaxAge(chrono::Duration::milliseconds(1500)),
+            GcRule::MaxVersions(1),
+        ]),
+    ]);
+    let converted = admin_api::GcRule::from(rule.clone());
+    assert_eq!(GcRule::from_api(converted), Some(rule));
+    assert_eq!(GcRule::from_api(admin_api::GcRule::default()), None);
+}
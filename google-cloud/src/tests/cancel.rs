@@ -0,0 +1,51 @@
+use std::time::Duration;
+
+use futures::future;
+use futures::stream::{self, StreamExt};
+
+use crate::cancel::{self, CancellationToken};
+use crate::error::Error;
+
+#[tokio::test]
+async fn cancel_interrupts_pending_operations() {
+    let token = CancellationToken::new();
+    let result = cancel::cancellable(Some(&token), async { Ok::<_, Error>(42) }).await;
+    assert_eq!(result.unwrap(), 42);
+
+    //? An operation that would never complete fails as soon as its token gets cancelled.
+    let cancelling = token.clone();
+    tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        cancelling.cancel();
+    });
+    let pending = future::pending::<Result<(), Error>>();
+    let result = tokio::time::timeout(
+        Duration::from_secs(5),
+        cancel::cancellable(Some(&token), pending),
+    )
+    .await
+    .expect("the operation was never cancelled");
+    let err = result.unwrap_err();
+    assert_eq!(err.status().unwrap().code(), tonic::Code::Cancelled);
+
+    let result = cancel::cancellable(None, async { Ok::<_, Error>(()) }).await;
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn cancel_ends_streams() {
+    let items: Vec<i32> = cancel::until_cancelled(stream::iter(0..3), None)
+        .collect()
+        .await;
+    assert_eq!(items, vec![0, 1, 2]);
+
+    //? Items are no longer pulled from a cancelled stream, even if more would follow.
+    let token = CancellationToken::new();
+    let mut items = Box::pin(cancel::until_cancelled(
+        stream::iter(0..).chain(stream::pending()),
+        Some(token.clone()),
+    ));
+    assert_eq!(items.next().await, Some(0));
+    token.cancel();
+    assert_eq!(items.next().await, None);
+}
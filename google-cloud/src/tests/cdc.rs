@@ -0,0 +1,85 @@
+use std::collections::HashMap;
+
+use crate::cdc::{is_outdated, ChangeEvent, ChangeOp};
+use crate::datastore::{Key, Value};
+
+#[test]
+fn cdc_decodes_change_events() {
+    let data = br#"{
+        "op": "upsert",
+        "key": { "kind": "User", "name": "alice", "parent": { "kind": "Team", "id": 42 } },
+        "version": 17,
+        "properties": { "age": 31, "score": 0.5, "nickname": null, "roles": ["admin", null] }
+    }"#;
+    let event = ChangeEvent::decode(data).unwrap();
+
+    let key = Key::new("User").id("alice").parent(Key::new("Team").id(42));
+    assert_eq!(event.key(), &key);
+    assert_eq!(event.version(), 17);
+    let properties = match event.op() {
+        ChangeOp::Upsert(properties) => properties,
+        ChangeOp::Delete => panic!("expected an upsert"),
+    };
+    assert_eq!(properties.len(), 3);
+    assert_eq!(properties["age"], Value::IntegerValue(31));
+    assert_eq!(properties["score"], Value::DoubleValue(0.5));
+    assert_eq!(
+        properties["roles"],
+        Value::ArrayValue(vec![Value::StringValue(String::from("admin"))]),
+    );
+
+    let event = ChangeEvent::decode(br#"{"op":"delete","key":{"kind":"User","id":7},"version":3}"#);
+    assert_eq!(event.unwrap().op(), &ChangeOp::Delete);
+
+    assert!(ChangeEvent::decode(br#"{"op":"delete","key":{"kind":"User"},"version":3}"#).is_err());
+    assert!(
+        ChangeEvent::decode(br#"{"op":"rename","key":{"kind":"User","id":7},"version":3}"#)
+            .is_err()
+    );
+}
+
+#[test]
+fn cdc_change_events_survive_round_trip() {
+    let mut properties = HashMap::new();
+    properties.insert(
+        String::from("name"),
+        Value::StringValue(String::from("Alice")),
+    );
+    properties.insert(String::from("active"), Value::BooleanValue(true));
+    let key = Key::new("User").id(7).namespace("prod");
+    let event = ChangeEvent::upsert(key, 5, Value::EntityValue(properties)).unwrap();
+
+    let decoded = ChangeEvent::decode(&event.encode().unwrap()).unwrap();
+    assert_eq!(decoded, event);
+
+    let timestamp = chrono::DateTime::from_timestamp(0, 0).unwrap().naive_utc();
+    let mut properties = HashMap::new();
+    properties.insert(String::from("at"), Value::TimestampValue(timestamp));
+    let event = ChangeEvent::upsert(Key::new("User").id(7), 5, Value::EntityValue(properties));
+    assert!(event.unwrap().encode().is_err());
+}
+
+#[test]
+fn cdc_derives_ordering_keys_from_entity_keys() {
+    let key = Key::new("User").id("alice").parent(Key::new("Team").id(42));
+    assert_eq!(
+        ChangeEvent::delete(key, 1).ordering_key(),
+        "Team:42/User:'alice'"
+    );
+
+    let key = Key::new("User").id(42).namespace("prod");
+    assert_eq!(ChangeEvent::delete(key, 1).ordering_key(), "prod/User:42");
+}
+
+#[test]
+fn cdc_skips_outdated_events() {
+    let mut properties = HashMap::new();
+    properties.insert(String::from("_cdc_version"), Value::IntegerValue(5));
+    let stored = Value::EntityValue(properties);
+
+    assert!(is_outdated(Some(&stored), "_cdc_version", 4));
+    assert!(is_outdated(Some(&stored), "_cdc_version", 5));
+    assert!(!is_outdated(Some(&stored), "_cdc_version", 6));
+    assert!(!is_outdated(Some(&stored), "version", 4));
+    assert!(!is_outdated(None, "_cdc_version", 1));
+}
@@ -0,0 +1,29 @@
+use crate::channel::{self, ChannelConfig};
+use crate::error::Error;
+
+#[tokio::test]
+async fn channel_rejects_invalid_endpoints() {
+    assert!(channel::parse_endpoint("https://datastore.googleapis.com").is_ok());
+    assert!(channel::parse_endpoint("http://localhost:8081").is_ok());
+
+    //? Misconfigured endpoints fail before connecting, rather than with TLS or transport errors.
+    let config = ChannelConfig::default();
+    for endpoint in [
+        "localhost:8081",
+        "grpc://localhost:8081",
+        "http://",
+        "not a url",
+    ] {
+        let result = channel::connect(endpoint, "datastore.googleapis.com", &config).await;
+        assert!(matches!(result, Err(Error::Endpoint(_))), "{}", endpoint);
+    }
+}
+
+#[cfg(any(feature = "bigtable", feature = "datastore", feature = "pubsub"))]
+#[test]
+fn channel_emulator_endpoints_are_plaintext() {
+    use crate::channel::emulator_endpoint;
+
+    assert_eq!(emulator_endpoint("localhost:8081"), "http://localhost:8081");
+    assert_eq!(emulator_endpoint("http://[::1]:8081"), "http://[::1]:8081");
+}
@@ -0,0 +1,73 @@
+use crate::cloudbuild::api;
+use crate::cloudbuild::{Build, BuildConfig, BuildStatus, BuildStep, LogLocation};
+
+fn build(id: &str, logs_bucket: &str) -> Build {
+    Build::from(api::Build {
+        id: id.to_string(),
+        logs_bucket: logs_bucket.to_string(),
+        status: api::build::Status::Working as i32,
+        ..api::Build::default()
+    })
+}
+
+#[test]
+fn cloudbuild_locates_build_logs() {
+    let location = LogLocation::from_build(&build("1234", "gs://my-logs")).unwrap();
+    assert_eq!(location.bucket, "my-logs");
+    assert_eq!(location.object, "log-1234.txt");
+
+    let location = LogLocation::from_build(&build("1234", "gs://my-logs/ci/builds/")).unwrap();
+    assert_eq!(location.bucket, "my-logs");
+    assert_eq!(location.object, "ci/builds/log-1234.txt");
+    assert_eq!(
+        location.uri(),
+        "https://storage.googleapis.com/storage/v1/b/my%2Dlogs/o/ci%2Fbuilds%2Flog%2D1234%2Etxt?alt=media",
+    );
+
+    assert!(LogLocation::from_build(&build("1234", "")).is_none());
+}
+
+#[test]
+fn cloudbuild_maps_build_statuses() {
+    let running = build("1234", "");
+    assert_eq!(running.status(), BuildStatus::Working);
+    assert!(!running.status().is_finished());
+    assert!(BuildStatus::Cancelled.is_finished());
+    assert!(BuildStatus::Success.is_finished());
+    assert!(!BuildStatus::Queued.is_finished());
+
+    let unknown = Build::from(api::Build {
+        status: 42,
+        ..api::Build::default()
+    });
+    assert_eq!(unknown.status(), BuildStatus::Unknown);
+    assert_eq!(unknown.trigger_id(), None);
+}
+
+#[test]
+fn cloudbuild_converts_build_configs() {
+    let config = BuildConfig::new()
+        .step(
+            BuildStep::new("gcr.io/cloud-builders/docker")
+                .args(vec!["build", "."])
+                .env("DOCKER_BUILDKIT", "1"),
+        )
+        .image("gcr.io/my-project/my-image")
+        .timeout(chrono::Duration::milliseconds(90_500))
+        .substitution("_TARGET", "release");
+    let build = api::Build::from(config);
+
+    assert_eq!(build.steps.len(), 1);
+    assert_eq!(build.steps[0].args, vec!["build", "."]);
+    assert_eq!(build.steps[0].env, vec!["DOCKER_BUILDKIT=1"]);
+    assert_eq!(build.images, vec!["gcr.io/my-project/my-image"]);
+    assert_eq!(
+        build.timeout,
+        Some(prost_types::Duration {
+            seconds: 90,
+            nanos: 500_000_000,
+        })
+    );
+    assert_eq!(build.substitutions["_TARGET"], "release");
+    assert!(build.logs_bucket.is_empty());
+}
@@ -0,0 +1,90 @@
+use std::time::Duration;
+
+#[cfg(any(feature = "datastore", feature = "pubsub"))]
+use crate::channel::ChannelConfig;
+use crate::config::{Config, Profile, RetryProfile};
+
+macro_rules! assert_ok {
+    ($expr:expr) => {
+        match $expr {
+            Ok(value) => value,
+            Err(err) => {
+                panic!("asserted result is an error: {}", err);
+            }
+        }
+    };
+}
+
+const PROFILES: &str = r#"
+    [dev]
+    project = "my-project"
+    emulator = true
+    endpoints = { datastore = "localhost:8081" }
+
+    [prod]
+    project = "my-project"
+    credentials = "/etc/gcp/service-account.json"
+    endpoints = { pubsub = "https://europe-west1-pubsub.googleapis.com" }
+    timeout_ms = 30000
+    connect_timeout_ms = 5000
+    scopes = ["https://www.googleapis.com/auth/cloud-platform"]
+
+    [prod.retry]
+    max_attempts = 3
+    initial_backoff_ms = 200
+"#;
+
+#[test]
+fn config_parses_profiles() {
+    let config = assert_ok!(Config::from_toml(PROFILES));
+    let mut names: Vec<&str> = config.profile_names().collect();
+    names.sort_unstable();
+    assert_eq!(names, ["dev", "prod"]);
+    assert!(config.profile("staging").is_none());
+
+    let prod = config.profile("prod").expect("no prod profile");
+    let expected = Profile::new()
+        .with_project("my-project")
+        .credentials_file("/etc/gcp/service-account.json")
+        .endpoint("pubsub", "https://europe-west1-pubsub.googleapis.com")
+        .timeout(Duration::from_secs(30))
+        .connect_timeout(Duration::from_secs(5))
+        .retry(
+            RetryProfile::new()
+                .max_attempts(3)
+                .initial_backoff(Duration::from_millis(200)),
+        )
+        .scope("https://www.googleapis.com/auth/cloud-platform");
+    assert_eq!(prod, &expected);
+    #[cfg(any(feature = "datastore", feature = "pubsub"))]
+    assert_eq!(
+        prod.channel_config(),
+        ChannelConfig::default()
+            .timeout(Duration::from_secs(30))
+            .connect_timeout(Duration::from_secs(5)),
+    );
+
+    //? Unknown settings are most likely typos, which should not go unnoticed.
+    assert!(Config::from_toml("[dev]\nprojet = \"my-project\"").is_err());
+}
+
+#[cfg(any(feature = "datastore", feature = "pubsub"))]
+#[test]
+fn config_resolves_endpoints() {
+    let config = assert_ok!(Config::from_toml(PROFILES));
+
+    let dev = config.profile("dev").expect("no dev profile");
+    let endpoint = assert_ok!(dev.endpoint_of("datastore", "https://datastore.googleapis.com"));
+    assert_eq!(endpoint, "http://localhost:8081");
+    assert!(dev
+        .endpoint_of("pubsub", "https://pubsub.googleapis.com")
+        .is_err());
+    let creds = assert_ok!(dev.credentials());
+    assert_eq!(creds.project_id, "my-project");
+
+    let prod = config.profile("prod").expect("no prod profile");
+    let endpoint = assert_ok!(prod.endpoint_of("pubsub", "https://pubsub.googleapis.com"));
+    assert_eq!(endpoint, "https://europe-west1-pubsub.googleapis.com");
+    let endpoint = assert_ok!(prod.endpoint_of("datastore", "https://datastore.googleapis.com"));
+    assert_eq!(endpoint, "https://datastore.googleapis.com");
+}
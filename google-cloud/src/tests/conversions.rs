@@ -0,0 +1,1460 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+//? Number of random cases generated for each round-trip property.
+const CASES: usize = 2000;
+
+fn rng() -> StdRng {
+    StdRng::seed_from_u64(0x6763_7273)
+}
+
+fn random_string(rng: &mut StdRng) -> String {
+    const SAMPLES: &[&str] = &[
+        "a",
+        "Z",
+        "0",
+        "_",
+        " ",
+        "é",
+        "ß",
+        "日本",
+        "🦀",
+        "\u{0}",
+        "\u{10FFFF}",
+    ];
+    let len = rng.gen_range(0..8);
+    (0..len)
+        .map(|_| {
+            if rng.gen_bool(0.5) {
+                SAMPLES[rng.gen_range(0..SAMPLES.len())].to_string()
+            } else {
+                rng.gen::<char>().to_string()
+            }
+        })
+        .collect()
+}
+
+#[cfg(feature = "datastore")]
+mod datastore {
+    use std::collections::HashMap;
+    use std::convert::TryFrom;
+
+    use prost::Message;
+    use rand::rngs::StdRng;
+    use rand::Rng;
+
+    use crate::datastore::api;
+    use crate::datastore::{convert_key, convert_value, Partition};
+    use crate::datastore::{DecodeOptions, Entity, FromValue, Key, KeyID, Value};
+    use crate::error::ConvertError;
+
+    use super::{random_string, rng, CASES};
+
+    const PARTITION: Partition<'static> = Partition {
+        project_name: "test-project",
+        database_id: "",
+        namespace: None,
+    };
+
+    fn random_double(rng: &mut StdRng) -> f64 {
+        const SPECIALS: &[f64] = &[
+            f64::NAN,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+            0.0,
+            -0.0,
+            f64::MIN,
+            f64::MAX,
+            f64::MIN_POSITIVE,
+            f64::EPSILON,
+        ];
+        if rng.gen_bool(0.3) {
+            SPECIALS[rng.gen_range(0..SPECIALS.len())]
+        } else {
+            f64::from_bits(rng.gen())
+        }
+    }
+
+    fn random_key(rng: &mut StdRng, depth: usize) -> Key {
+        let key = Key::new(random_string(rng)).id(match rng.gen_range(0..3) {
+            0 => KeyID::Incomplete,
+            1 => KeyID::IntID(rng.gen()),
+            _ => KeyID::StringID(random_string(rng)),
+        });
+        let key = match rng.gen_range(0..3) {
+            0 => key,
+            1 => key.namespace(""),
+            _ => key.namespace(random_string(rng)),
+        };
+        if depth > 0 && rng.gen_bool(0.5) {
+            key.parent(random_key(rng, depth - 1))
+        } else {
+            key
+        }
+    }
+
+    fn random_value(rng: &mut StdRng, depth: usize) -> Value {
+        let max = if depth > 0 { 11 } else { 8 };
+        match rng.gen_range(0..max) {
+            0 => Value::BooleanValue(rng.gen()),
+            1 => Value::IntegerValue(rng.gen()),
+            2 => Value::DoubleValue(random_double(rng)),
+            3 => {
+                //? Datastore timestamps range from year 1 to year 9999.
+                let seconds = rng.gen_range(-62_135_596_800..253_402_300_800);
+                let nanos = rng.gen_range(0..1_000_000_000);
+                let timestamp = chrono::DateTime::from_timestamp(seconds, nanos).unwrap();
+                Value::TimestampValue(timestamp.naive_utc())
+            }
+            4 => Value::KeyValue(random_key(rng, 3)),
+            5 => Value::StringValue(random_string(rng)),
+            6 => {
+                let len = rng.gen_range(0..16);
+                Value::BlobValue((0..len).map(|_| rng.gen()).collect())
+            }
+            7 => Value::GeoPointValue(random_double(rng), random_double(rng)),
+            8 => Value::IndexedValue(Box::new(random_value(rng, depth - 1)), rng.gen()),
+            9 => {
+                let len = rng.gen_range(0..4);
+                Value::EntityValue(
+                    (0..len)
+                        .map(|_| (random_string(rng), random_value(rng, depth - 1)))
+                        .collect::<HashMap<_, _>>(),
+                )
+            }
+            _ => {
+                let len = rng.gen_range(0..4);
+                Value::ArrayValue((0..len).map(|_| random_element(rng, depth - 1)).collect())
+            }
+        }
+    }
+
+    //? Arrays cannot directly contain other arrays.
+    fn random_element(rng: &mut StdRng, depth: usize) -> Value {
+        loop {
+            match random_value(rng, depth) {
+                Value::ArrayValue(_) => continue,
+                Value::IndexedValue(value, _) if matches!(*value, Value::ArrayValue(_)) => continue,
+                value => break value,
+            }
+        }
+    }
+
+    fn round_trip(value: Value) -> Value {
+        let encoded = convert_value(PARTITION, value).unwrap().encode_to_vec();
+        let decoded = api::Value::decode(encoded.as_slice()).unwrap();
+        Value::try_from(decoded).unwrap()
+    }
+
+    #[test]
+    fn datastore_values_survive_round_trip() {
+        let mut rng = rng();
+        for _ in 0..CASES {
+            let value = random_value(&mut rng, 3);
+            let expected = value.clone().canonicalize();
+            let got = round_trip(value.clone());
+            assert_eq!(got, expected, "round-trip mismatch for {:?}", value);
+        }
+    }
+
+    #[test]
+    fn datastore_canonical_values_are_stable() {
+        let mut rng = rng();
+        for _ in 0..CASES {
+            let canonical = random_value(&mut rng, 3).canonicalize();
+            assert_eq!(canonical.clone().canonicalize(), canonical);
+            assert_eq!(round_trip(canonical.clone()), canonical);
+        }
+    }
+
+    #[test]
+    fn datastore_rejects_invalid_values() {
+        let nested = Value::ArrayValue(vec![Value::IndexedValue(
+            Box::new(Value::ArrayValue(Vec::new())),
+            false,
+        )]);
+        assert!(matches!(
+            convert_value(PARTITION, nested),
+            Err(ConvertError::NestedArray)
+        ));
+
+        let blob = Value::BlobValue(vec![0; 1501]);
+        assert!(matches!(
+            convert_value(PARTITION, blob.clone()),
+            Err(ConvertError::BlobTooLarge {
+                size: 1501,
+                max: 1500
+            })
+        ));
+        assert!(convert_value(PARTITION, Value::IndexedValue(Box::new(blob), false)).is_ok());
+
+        let timestamp = api::Value {
+            meaning: 0,
+            exclude_from_indexes: false,
+            value_type: Some(api::value::ValueType::TimestampValue(
+                prost_types::Timestamp {
+                    seconds: i64::MAX,
+                    nanos: -1,
+                },
+            )),
+        };
+        assert!(matches!(
+            Value::try_from(timestamp),
+            Err(ConvertError::InvalidTimestamp { .. })
+        ));
+
+        let missing = api::Value {
+            meaning: 0,
+            exclude_from_indexes: false,
+            value_type: None,
+        };
+        assert!(matches!(
+            Value::try_from(missing),
+            Err(ConvertError::MissingField(_))
+        ));
+    }
+
+    #[test]
+    fn datastore_numbers_decode_strictly_by_default() {
+        assert_eq!(i64::from_value(Value::IntegerValue(7)).unwrap(), 7);
+        assert_eq!(f64::from_value(Value::DoubleValue(0.5)).unwrap(), 0.5);
+        assert!(matches!(
+            f64::from_value(Value::IntegerValue(7)),
+            Err(ConvertError::UnexpectedPropertyType { .. })
+        ));
+        assert!(matches!(
+            i64::from_value(Value::StringValue(String::from("7"))),
+            Err(ConvertError::UnexpectedPropertyType { .. })
+        ));
+
+        //? Narrower types reject values they cannot hold, instead of truncating them.
+        assert_eq!(i32::from_value(Value::IntegerValue(-7)).unwrap(), -7);
+        assert!(matches!(
+            i32::from_value(Value::IntegerValue(1 << 40)),
+            Err(ConvertError::LossyNumber { .. })
+        ));
+        assert_eq!(f32::from_value(Value::DoubleValue(0.5)).unwrap(), 0.5);
+        assert!(matches!(
+            f32::from_value(Value::DoubleValue(0.1)),
+            Err(ConvertError::LossyNumber { .. })
+        ));
+    }
+
+    #[test]
+    fn datastore_numbers_decode_losslessly_with_options() {
+        let options = DecodeOptions::default()
+            .widen_integers(true)
+            .integral_doubles(true)
+            .decimal_strings(true);
+        let integer = |value: Value| i64::from_value_with(value, &options);
+        let double = |value: Value| f64::from_value_with(value, &options);
+        let string = |value: &str| Value::StringValue(String::from(value));
+
+        assert_eq!(
+            double(Value::IntegerValue(1 << 53)).unwrap(),
+            9_007_199_254_740_992.0
+        );
+        assert!(double(Value::IntegerValue((1 << 53) + 1)).is_err());
+        assert!(double(Value::IntegerValue(i64::MAX)).is_err());
+        assert_eq!(
+            double(Value::IntegerValue(i64::MIN)).unwrap(),
+            -9_223_372_036_854_775_808.0
+        );
+
+        assert_eq!(integer(Value::DoubleValue(-42.0)).unwrap(), -42);
+        assert!(integer(Value::DoubleValue(4.2)).is_err());
+        assert!(integer(Value::DoubleValue(f64::NAN)).is_err());
+        assert!(integer(Value::DoubleValue(9_223_372_036_854_775_808.0)).is_err());
+
+        assert_eq!(integer(string("-12")).unwrap(), -12);
+        assert_eq!(integer(string("12.000")).unwrap(), 12);
+        assert!(matches!(
+            integer(string("12.5")),
+            Err(ConvertError::LossyNumber { .. })
+        ));
+        assert!(matches!(
+            integer(string("99999999999999999999")),
+            Err(ConvertError::LossyNumber { .. })
+        ));
+        assert_eq!(double(string("-12.50")).unwrap(), -12.5);
+        assert_eq!(double(string("0.1")).unwrap(), 0.1);
+        assert!(matches!(
+            double(string("0.10000000000000000001")),
+            Err(ConvertError::LossyNumber { .. })
+        ));
+        assert!(matches!(
+            double(string("1e3")),
+            Err(ConvertError::InvalidDecimal(_))
+        ));
+        assert!(matches!(
+            double(string("")),
+            Err(ConvertError::InvalidDecimal(_))
+        ));
+        assert!(matches!(
+            integer(string("abc")),
+            Err(ConvertError::InvalidDecimal(_))
+        ));
+
+        //? Options propagate to the elements of containers.
+        let values = Value::ArrayValue(vec![Value::DoubleValue(1.0), string("2")]);
+        assert_eq!(
+            Vec::<i32>::from_value_with(values, &options).unwrap(),
+            vec![1, 2]
+        );
+    }
+
+    #[test]
+    fn datastore_keys_get_the_default_namespace() {
+        let partition = Partition {
+            project_name: "test-project",
+            database_id: "",
+            namespace: Some("staging"),
+        };
+        let namespace = |key: &Key| {
+            let key = convert_key(partition, key);
+            key.partition_id.unwrap().namespace_id
+        };
+
+        //? Keys without a namespace get the default one, in every (nested) value.
+        let key = Key::new("User").id(1);
+        assert_eq!(namespace(&key), "staging");
+        assert_eq!(partition.scope(&key), key.clone().namespace("staging"));
+        let value = convert_value(partition, Value::KeyValue(key)).unwrap();
+        match value.value_type {
+            Some(api::value::ValueType::KeyValue(key)) => {
+                assert_eq!(key.partition_id.unwrap().namespace_id, "staging")
+            }
+            value => panic!("unexpected value: {:?}", value),
+        }
+
+        //? Explicit namespaces are kept, an empty one being the project's default namespace.
+        let key = Key::new("User").id(1).namespace("dev");
+        assert_eq!(namespace(&key), "dev");
+        assert_eq!(partition.scope(&key), key);
+        let key = Key::new("User").id(1).namespace("");
+        assert_eq!(namespace(&key), "");
+        assert_eq!(partition.scope(&key), key);
+    }
+
+    #[test]
+    fn datastore_keys_are_bound_to_the_database() {
+        let partition = Partition {
+            project_name: "test-project",
+            database_id: "tenants",
+            namespace: None,
+        };
+
+        //? Keys (and the keys they hold) belong to the database of the client.
+        let key = Key::new("User").id(1);
+        let value = Value::EntityValue(
+            vec![(String::from("friend"), Value::KeyValue(key.clone()))]
+                .into_iter()
+                .collect(),
+        );
+        let converted = convert_key(partition, &key);
+        assert_eq!(converted.partition_id.unwrap().database_id, "tenants");
+        let value = convert_value(partition, value).unwrap();
+        match value.value_type {
+            Some(api::value::ValueType::EntityValue(entity)) => {
+                match &entity.properties["friend"].value_type {
+                    Some(api::value::ValueType::KeyValue(key)) => {
+                        let partition_id = key.partition_id.as_ref().unwrap();
+                        assert_eq!(partition_id.database_id, "tenants");
+                    }
+                    value => panic!("unexpected value: {:?}", value),
+                }
+            }
+            value => panic!("unexpected value: {:?}", value),
+        }
+    }
+
+    #[test]
+    fn datastore_projected_timestamps_are_decoded() {
+        //? Projections return timestamps as microseconds, flagged with the `GD_WHEN` meaning.
+        let projected = api::Value {
+            meaning: 7,
+            exclude_from_indexes: false,
+            value_type: Some(api::value::ValueType::IntegerValue(1_500_000_000_250_000)),
+        };
+        let expected = chrono::DateTime::from_timestamp(1_500_000_000, 250_000_000)
+            .unwrap()
+            .naive_utc();
+        assert_eq!(
+            Value::try_from(projected).unwrap(),
+            Value::TimestampValue(expected)
+        );
+
+        //? Plain integers are left untouched.
+        let integer = api::Value {
+            meaning: 0,
+            exclude_from_indexes: false,
+            value_type: Some(api::value::ValueType::IntegerValue(42)),
+        };
+        assert_eq!(Value::try_from(integer).unwrap(), Value::IntegerValue(42));
+    }
+
+    #[test]
+    fn datastore_ancestor_filters_match_the_key_property() {
+        use crate::datastore::{convert_filter, Filter};
+
+        let ancestor = Key::new("Org").id("acme");
+        let filter = convert_filter(PARTITION, vec![Filter::HasAncestor(ancestor.clone())])
+            .unwrap()
+            .unwrap();
+        let filters = match filter.filter_type {
+            Some(api::filter::FilterType::CompositeFilter(composite)) => composite.filters,
+            filter => panic!("unexpected filter: {:?}", filter),
+        };
+        match filters.as_slice() {
+            [api::Filter {
+                filter_type: Some(api::filter::FilterType::PropertyFilter(filter)),
+            }] => {
+                assert_eq!(
+                    filter.op,
+                    api::property_filter::Operator::HasAncestor as i32
+                );
+                assert_eq!(filter.property.as_ref().unwrap().name, "__key__");
+                let value = Value::try_from(filter.value.clone().unwrap()).unwrap();
+                assert_eq!(value, Value::KeyValue(ancestor));
+            }
+            filters => panic!("unexpected filters: {:?}", filters),
+        }
+    }
+
+    #[test]
+    fn datastore_in_filters_are_validated() {
+        use crate::datastore::{convert_filter, Filter, IntoValue};
+
+        let filter = Filter::in_values("status", vec!["paid", "shipped"]);
+        assert_eq!(
+            filter,
+            Filter::In(
+                String::from("status"),
+                Value::ArrayValue(vec!["paid".into_value(), "shipped".into_value()])
+            )
+        );
+        assert!(convert_filter(PARTITION, vec![filter]).is_ok());
+        assert_eq!(
+            Filter::array_contains("tags", "rust"),
+            Filter::Equal(String::from("tags"), "rust".into_value())
+        );
+
+        //? Invalid lists are rejected before reaching Datastore.
+        let invalid = vec![
+            Filter::in_values("id", 0..11_i64),
+            Filter::not_in_values("id", Vec::<i64>::new()),
+            Filter::In(String::from("id"), Value::IntegerValue(1)),
+        ];
+        for filter in invalid {
+            assert!(matches!(
+                convert_filter(PARTITION, vec![filter]),
+                Err(ConvertError::InvalidFilter { .. })
+            ));
+        }
+        assert!(convert_filter(PARTITION, vec![Filter::not_in_values("id", 0..10_i64)]).is_ok());
+    }
+
+    #[test]
+    fn datastore_aggregation_results_are_matched_to_their_aggregations() {
+        use crate::datastore::{convert_aggregation_result, Aggregation, Query};
+
+        let query = Query::new("Order")
+            .count()
+            .sum("amount")
+            .avg("amount")
+            .avg("missing")
+            .count();
+        assert_eq!(query.aggregations().len(), 4);
+
+        let value = |value_type| api::Value {
+            meaning: 0,
+            exclude_from_indexes: false,
+            value_type: Some(value_type),
+        };
+        let mut properties = HashMap::new();
+        properties.insert(
+            String::from("aggregation_0"),
+            value(api::value::ValueType::IntegerValue(3)),
+        );
+        properties.insert(
+            String::from("aggregation_1"),
+            value(api::value::ValueType::IntegerValue(60)),
+        );
+        properties.insert(
+            String::from("aggregation_2"),
+            value(api::value::ValueType::DoubleValue(20.0)),
+        );
+        properties.insert(
+            String::from("aggregation_3"),
+            value(api::value::ValueType::NullValue(0)),
+        );
+        let batch = api::AggregationResultBatch {
+            aggregation_results: vec![api::AggregationResult {
+                aggregate_properties: properties,
+            }],
+            more_results: api::query_result_batch::MoreResultsType::NoMoreResults as i32,
+            read_time: None,
+        };
+
+        let results =
+            convert_aggregation_result(query.aggregations.clone(), batch.clone()).unwrap();
+        assert_eq!(results.count(), Some(3));
+        assert_eq!(results.sum("amount"), Some(&Value::IntegerValue(60)));
+        assert_eq!(results.avg("amount"), Some(20.0));
+        //? Averages of no values and aggregations that were not requested have no result.
+        assert_eq!(results.avg("missing"), None);
+        assert_eq!(results.get(&Aggregation::Sum(String::from("other"))), None);
+
+        //? A result missing from the response is an error.
+        let mut aggregations = query.aggregations;
+        aggregations.push(Aggregation::Sum(String::from("other")));
+        assert!(matches!(
+            convert_aggregation_result(aggregations, batch),
+            Err(ConvertError::MissingField(alias)) if alias == "aggregation_4"
+        ));
+    }
+
+    #[test]
+    fn datastore_queries_read_at_the_requested_time() {
+        use crate::datastore::{query_read_options, Query};
+        use api::read_options::{ConsistencyType, ReadConsistency};
+
+        let read_time = chrono::DateTime::from_timestamp(1_600_000_000, 0)
+            .unwrap()
+            .naive_utc();
+        let query = Query::new("User").read_time(read_time);
+        assert_eq!(
+            query_read_options(&query, None).consistency_type,
+            Some(ConsistencyType::ReadTime(prost_types::Timestamp {
+                seconds: 1_600_000_000,
+                nanos: 0,
+            }))
+        );
+
+        //? Transactions read from their own snapshot.
+        assert_eq!(
+            query_read_options(&query, Some(b"tx")).consistency_type,
+            Some(ConsistencyType::Transaction(b"tx".to_vec()))
+        );
+        assert_eq!(
+            query_read_options(&Query::new("User"), None).consistency_type,
+            Some(ConsistencyType::ReadConsistency(
+                ReadConsistency::Strong as i32
+            ))
+        );
+
+        let query = Query::new("User").consistency(crate::datastore::ReadConsistency::Eventual);
+        assert_eq!(query, Query::new("User").eventually_consistent());
+        assert_eq!(
+            query_read_options(&query, None).consistency_type,
+            Some(ConsistencyType::ReadConsistency(
+                ReadConsistency::Eventual as i32
+            ))
+        );
+    }
+
+    #[test]
+    fn datastore_writes_use_the_requested_operations() {
+        use crate::datastore::{write_mutations, WriteMode};
+        use api::mutation::Operation;
+
+        let entities = || {
+            vec![
+                (
+                    Key::new("User").id("alice"),
+                    Value::EntityValue(HashMap::new()),
+                ),
+                (Key::new("User"), Value::EntityValue(HashMap::new())),
+            ]
+        };
+        let operations = |mode| -> Vec<&'static str> {
+            write_mutations(PARTITION, entities(), mode)
+                .unwrap()
+                .into_iter()
+                .map(|mutation| match mutation.operation {
+                    Some(Operation::Insert(_)) => "insert",
+                    Some(Operation::Update(_)) => "update",
+                    Some(Operation::Upsert(_)) => "upsert",
+                    operation => panic!("unexpected operation: {:?}", operation),
+                })
+                .collect()
+        };
+
+        //? Puts only insert entities whose key is incomplete, the other operations are applied as requested.
+        assert_eq!(operations(WriteMode::Put), vec!["upsert", "insert"]);
+        assert_eq!(operations(WriteMode::Insert), vec!["insert", "insert"]);
+        assert_eq!(operations(WriteMode::Update), vec!["update", "update"]);
+        assert_eq!(operations(WriteMode::Upsert), vec!["upsert", "upsert"]);
+    }
+
+    #[test]
+    fn datastore_only_idempotent_commits_are_retried() {
+        use crate::datastore::{delete_mutations, is_idempotent, write_mutations, WriteMode};
+
+        let entities = || {
+            vec![
+                (
+                    Key::new("User").id("alice"),
+                    Value::EntityValue(HashMap::new()),
+                ),
+                (Key::new("User"), Value::EntityValue(HashMap::new())),
+            ]
+        };
+        let idempotent = |mode| -> Vec<bool> {
+            write_mutations(PARTITION, entities(), mode)
+                .unwrap()
+                .iter()
+                .map(is_idempotent)
+                .collect()
+        };
+
+        //? Replaying inserts would fail on (or duplicate) the entities created by the first attempt.
+        assert_eq!(idempotent(WriteMode::Put), vec![true, false]);
+        assert_eq!(idempotent(WriteMode::Insert), vec![false, false]);
+        assert_eq!(idempotent(WriteMode::Update), vec![false, false]);
+        assert_eq!(idempotent(WriteMode::Upsert), vec![true, false]);
+
+        let deletions = delete_mutations(PARTITION, vec![Key::new("User").id(42)]);
+        assert!(deletions.iter().all(is_idempotent));
+        let mut conditional = deletions[0].clone();
+        conditional.conflict_detection_strategy =
+            Some(api::mutation::ConflictDetectionStrategy::BaseVersion(1));
+        assert!(!is_idempotent(&conditional));
+    }
+
+    #[test]
+    fn datastore_entity_metadata_is_read_from_results() {
+        use crate::datastore::EntityMeta;
+
+        let result = api::EntityResult {
+            version: 42,
+            update_time: Some(prost_types::Timestamp {
+                seconds: 1_600_000_000,
+                nanos: 500_000_000,
+            }),
+            ..api::EntityResult::default()
+        };
+        let meta = EntityMeta::from_api(&result).unwrap();
+        assert_eq!(meta.version(), 42);
+        let update_time = chrono::DateTime::from_timestamp(1_600_000_000, 500_000_000)
+            .map(|time| time.naive_utc());
+        assert_eq!(meta.update_time(), update_time);
+
+        let meta = EntityMeta::from_api(&api::EntityResult::default()).unwrap();
+        assert_eq!(meta.version(), 0);
+        assert_eq!(meta.update_time(), None);
+    }
+
+    #[test]
+    fn datastore_repository_caches_are_keyed_by_entity() {
+        use crate::datastore::{MemoryCache, RepositoryCache};
+
+        let cache = MemoryCache::new();
+        let jane = Key::new("User").id(1);
+        let john = Key::new("User").id(2);
+        cache.insert(&jane, &String::from("Jane"));
+        cache.insert(&john, &String::from("John"));
+        assert_eq!(cache.get(&jane), Some(String::from("Jane")));
+        //? An empty namespace is the same as no namespace.
+        assert_eq!(
+            cache.get(&jane.clone().namespace("")),
+            Some(String::from("Jane"))
+        );
+        assert_eq!(cache.get(&Key::new("Admin").id(1)), None);
+
+        cache.invalidate(&jane);
+        assert_eq!(cache.get(&jane), None);
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn datastore_scans_split_the_key_space_evenly() {
+        use crate::datastore::{split_ranges, Filter, Query, Scan};
+
+        //? The sampled keys come in the order of their scatter property, not of their keys.
+        let sample: Vec<Key> = (0..12).rev().map(|id| Key::new("Event").id(id)).collect();
+        let ranges = split_ranges(sample, 4);
+        let bounds: Vec<_> = ranges
+            .iter()
+            .map(|range| {
+                let id = |key: Option<&Key>| key.map(|key| key.get_id().clone());
+                (id(range.start()), id(range.end()))
+            })
+            .collect();
+        assert_eq!(
+            bounds,
+            vec![
+                (None, Some(KeyID::IntID(3))),
+                (Some(KeyID::IntID(3)), Some(KeyID::IntID(6))),
+                (Some(KeyID::IntID(6)), Some(KeyID::IntID(9))),
+                (Some(KeyID::IntID(9)), None),
+            ]
+        );
+        //? Every key belongs to exactly one range.
+        for id in -5..20 {
+            let key = Key::new("Event").id(id);
+            let containing = ranges.iter().filter(|range| range.contains(&key)).count();
+            assert_eq!(containing, 1);
+        }
+
+        //? Small samples give fewer ranges, and empty ones a single range over the whole key space.
+        assert_eq!(split_ranges(vec![Key::new("Event").id(1)], 4).len(), 2);
+        let ranges = split_ranges(Vec::new(), 4);
+        assert_eq!(ranges.len(), 1);
+        assert_eq!(ranges[0].restrict(Query::new("Event")), Query::new("Event"));
+
+        let query = split_ranges(vec![Key::new("Event").id(1)], 2)[1].restrict(Query::new("Event"));
+        assert_eq!(
+            query,
+            Query::new("Event").filter(Filter::GreaterThanOrEqual(
+                String::from(Scan::KEY_PROPERTY),
+                Value::KeyValue(Key::new("Event").id(1))
+            ))
+        );
+    }
+
+    #[test]
+    fn datastore_batches_resume_within_the_limit_and_offset() {
+        use crate::datastore::{Cursor, Query, QueryPaging};
+
+        fn batch(skipped: i32, returned: usize, end_cursor: &[u8]) -> api::QueryResultBatch {
+            api::QueryResultBatch {
+                skipped_results: skipped,
+                skipped_cursor: Vec::new(),
+                entity_results: vec![api::EntityResult::default(); returned],
+                end_cursor: end_cursor.to_vec(),
+                more_results: api::query_result_batch::MoreResultsType::NotFinished as i32,
+                ..Default::default()
+            }
+        }
+
+        let query = Query::new("Event").offset(10).limit(100);
+        let mut paging = QueryPaging::new(query.clone());
+        let next = paging.advance(&batch(4, 0, b"first")).unwrap();
+        assert_eq!(next.offset, 6);
+        assert_eq!(next.limit, Some(100));
+        assert_eq!(
+            next.start_cursor,
+            Some(Cursor::from_bytes(b"first".to_vec()))
+        );
+
+        //? The limit applies to the results of all the batches, not to every batch.
+        let next = paging.advance(&batch(6, 60, b"second")).unwrap();
+        assert_eq!(next.offset, 0);
+        assert_eq!(next.limit, Some(40));
+        //? The offset only applies until it is fully skipped.
+        let next = paging.advance(&batch(0, 25, b"third")).unwrap();
+        assert_eq!(next.offset, 0);
+        assert_eq!(next.limit, Some(15));
+        assert_eq!(paging.advance(&batch(0, 15, b"fourth")), None);
+
+        let unlimited = Query::new("Event");
+        let next = QueryPaging::new(unlimited)
+            .advance(&batch(0, 5000, b"fifth"))
+            .unwrap();
+        assert_eq!(next.limit, None);
+
+        //? Batches that only skipped results resume after them.
+        let mut skipping = batch(1000, 0, b"");
+        skipping.skipped_cursor = b"skipped".to_vec();
+        let next = QueryPaging::new(query.clone()).advance(&skipping).unwrap();
+        assert_eq!(next.offset, 0);
+        assert_eq!(
+            next.start_cursor,
+            Some(Cursor::from_bytes(b"skipped".to_vec()))
+        );
+
+        let mut finished = batch(10, 20, b"sixth");
+        finished.more_results = api::query_result_batch::MoreResultsType::NoMoreResults as i32;
+        assert_eq!(QueryPaging::new(query).advance(&finished), None);
+    }
+
+    #[test]
+    fn datastore_explain_metrics_convert_to_json() {
+        use prost_types::value::Kind;
+
+        use crate::datastore::ExplainMetrics;
+
+        let value = |kind| prost_types::Value { kind: Some(kind) };
+        let index = prost_types::Struct {
+            fields: vec![
+                (
+                    String::from("query_scope"),
+                    value(Kind::StringValue("Collection".into())),
+                ),
+                (
+                    String::from("properties"),
+                    value(Kind::StringValue("(done ASC)".into())),
+                ),
+            ]
+            .into_iter()
+            .collect(),
+        };
+        let billing = prost_types::Struct {
+            fields: vec![(
+                String::from("min_query_cost"),
+                value(Kind::NumberValue(0.0)),
+            )]
+            .into_iter()
+            .collect(),
+        };
+        let debug_stats = prost_types::Struct {
+            fields: vec![(
+                String::from("billing_details"),
+                value(Kind::StructValue(billing)),
+            )]
+            .into_iter()
+            .collect(),
+        };
+        let metrics = api::ExplainMetrics {
+            plan_summary: Some(api::PlanSummary {
+                indexes_used: vec![index],
+            }),
+            execution_stats: Some(api::ExecutionStats {
+                results_returned: 12,
+                execution_duration: Some(prost_types::Duration {
+                    seconds: 1,
+                    nanos: 500_000_000,
+                }),
+                read_operations: 13,
+                debug_stats: Some(debug_stats),
+            }),
+        };
+
+        let metrics = ExplainMetrics::try_from(metrics).unwrap();
+        assert_eq!(
+            metrics.indexes_used(),
+            &[json::json!({"query_scope": "Collection", "properties": "(done ASC)"})]
+        );
+        let stats = metrics.execution_stats().unwrap();
+        assert_eq!(stats.results_returned(), 12);
+        assert_eq!(stats.read_operations(), 13);
+        assert_eq!(
+            stats.execution_duration(),
+            std::time::Duration::from_millis(1500)
+        );
+        assert_eq!(
+            stats.debug_stats(),
+            &json::json!({"billing_details": {"min_query_cost": 0.0}})
+        );
+
+        //? Queries which are only planned have no execution statistics.
+        let planned = ExplainMetrics::try_from(api::ExplainMetrics::default()).unwrap();
+        assert!(planned.indexes_used().is_empty());
+        assert_eq!(planned.execution_stats(), None);
+    }
+
+    #[cfg(feature = "uuid")]
+    #[test]
+    fn datastore_uuids_survive_round_trip() {
+        use uuid::Uuid;
+
+        use crate::datastore::IntoValue;
+
+        let id = Uuid::from_u128(0x9a3c_51f2_0b7e_4d6a_8c1f_2e5d_7a9b_3c4d);
+        let value = round_trip(id.into_value());
+        assert_eq!(
+            value,
+            Value::StringValue(String::from("9a3c51f2-0b7e-4d6a-8c1f-2e5d7a9b3c4d"))
+        );
+        assert_eq!(Uuid::from_value(value).unwrap(), id);
+
+        //? Other writers may store UUIDs as raw bytes, or in another textual form.
+        let bytes = Value::BlobValue(id.as_bytes().to_vec());
+        assert_eq!(Uuid::from_value(bytes).unwrap(), id);
+        let simple = Value::StringValue(id.simple().to_string().to_uppercase());
+        assert_eq!(Uuid::from_value(simple).unwrap(), id);
+        assert!(matches!(
+            Uuid::from_value(Value::StringValue(String::from("not-a-uuid"))),
+            Err(ConvertError::InvalidUuid(_))
+        ));
+        assert!(matches!(
+            Uuid::from_value(Value::IntegerValue(1)),
+            Err(ConvertError::UnexpectedPropertyType { .. })
+        ));
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[test]
+    fn datastore_decimals_keep_their_scale() {
+        use rust_decimal::Decimal;
+
+        use crate::datastore::IntoValue;
+
+        let amount = Decimal::new(1250, 2);
+        let value = round_trip(amount.into_value());
+        assert_eq!(value, Value::StringValue(String::from("12.50")));
+        let decoded = Decimal::from_value(value).unwrap();
+        assert_eq!(decoded, amount);
+        assert_eq!(decoded.scale(), 2);
+
+        assert_eq!(
+            Decimal::from_value(Value::IntegerValue(-3)).unwrap(),
+            Decimal::from(-3)
+        );
+        assert!(matches!(
+            Decimal::from_value(Value::StringValue(String::from("twelve"))),
+            Err(ConvertError::InvalidDecimal(_))
+        ));
+        assert!(matches!(
+            Decimal::from_value(Value::DoubleValue(12.5)),
+            Err(ConvertError::UnexpectedPropertyType { .. })
+        ));
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn datastore_time_datetimes_survive_round_trip() {
+        use time::{OffsetDateTime, UtcOffset};
+
+        use crate::datastore::IntoValue;
+
+        let datetime = OffsetDateTime::from_unix_timestamp_nanos(1_500_000_000_123_456_789)
+            .unwrap()
+            .to_offset(UtcOffset::from_hms(2, 0, 0).unwrap());
+        let value = round_trip(datetime.into_value());
+
+        //? Timestamps are stored in UTC, with a precision of a microsecond.
+        let decoded = OffsetDateTime::from_value(value).unwrap();
+        assert_eq!(decoded.offset(), UtcOffset::UTC);
+        assert_eq!(decoded.unix_timestamp_nanos(), 1_500_000_000_123_456_000);
+        assert_eq!(decoded, datetime.replace_nanosecond(123_456_000).unwrap());
+    }
+
+    #[test]
+    fn datastore_large_requests_are_split_in_order() {
+        use crate::datastore::{split_chunks, Client};
+
+        let chunks = split_chunks((0..1201).collect::<Vec<_>>(), Client::MAX_MUTATIONS);
+        let sizes: Vec<usize> = chunks.iter().map(Vec::len).collect();
+        assert_eq!(sizes, vec![500, 500, 201]);
+        assert_eq!(chunks.concat(), (0..1201).collect::<Vec<_>>());
+
+        assert_eq!(
+            split_chunks(vec![1, 2], Client::MAX_LOOKUP_KEYS),
+            vec![vec![1, 2]]
+        );
+        assert!(split_chunks(Vec::<i32>::new(), Client::MAX_LOOKUP_KEYS).is_empty());
+    }
+
+    #[test]
+    fn datastore_migration_progress_survives_round_trip() {
+        use crate::datastore::{Cursor, MigrationProgress};
+
+        let progress = MigrationProgress {
+            cursor: Some(Cursor::from_bytes(vec![1, 2, 3])),
+            processed: 600,
+            updated: 42,
+            finished: false,
+        };
+        //? The checkpoint is written then read back from the store.
+        let stored = round_trip(progress.clone().into_value());
+        assert_eq!(MigrationProgress::from_value(stored).unwrap(), progress);
+
+        //? A migration checkpointed before its first page has no cursor yet.
+        let progress = MigrationProgress::default();
+        let stored = round_trip(progress.clone().into_value());
+        assert_eq!(
+            MigrationProgress::from_value(stored).unwrap().cursor(),
+            None
+        );
+
+        assert!(matches!(
+            MigrationProgress::from_value(Value::EntityValue(HashMap::new())),
+            Err(ConvertError::MissingProperty(name)) if name == "cursor"
+        ));
+    }
+
+    #[test]
+    fn datastore_errors_describe_their_keys() {
+        use crate::error::{Error, WithContext};
+
+        let key = Key::new("User").id(42);
+        assert_eq!(key.to_string(), "Kind=User id=42");
+        let key = Key::new("Post").id("hello").parent(key).namespace("dev");
+        assert_eq!(
+            key.to_string(),
+            r#"Kind=User id=42 / Kind=Post name="hello" (namespace=dev)"#
+        );
+
+        //? The context is prepended to the message, while the status remains reachable.
+        let result: Result<(), tonic::Status> = Err(tonic::Status::not_found("no entity"));
+        let err = result
+            .context("Lookup", Key::new("User").id(42))
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("Lookup on Kind=User id=42 failed: "));
+        let context = err.context().unwrap();
+        assert_eq!(context.operation(), "Lookup");
+        assert_eq!(context.resource(), "Kind=User id=42");
+        assert_eq!(err.status().unwrap().code(), tonic::Code::NotFound);
+        assert!(matches!(err.root(), Error::Status(_)));
+    }
+
+    #[test]
+    fn datastore_retries_back_off_exponentially() {
+        use std::time::Duration;
+
+        use crate::datastore::{is_retryable, RetryConfig};
+        use crate::error::{Error, WithContext};
+
+        let config = RetryConfig::default()
+            .initial_backoff(Duration::from_millis(100))
+            .max_backoff(Duration::from_secs(1));
+        //? The jitter picks each delay within the upper half of its exponential backoff.
+        for (attempt, backoff) in [
+            (1, 100),
+            (2, 200),
+            (3, 400),
+            (4, 800),
+            (5, 1000),
+            (50, 1000),
+        ] {
+            let backoff = Duration::from_millis(backoff);
+            let delay = config.backoff(attempt);
+            assert!(delay >= backoff / 2 && delay <= backoff, "{:?}", delay);
+        }
+
+        let aborted: Error = tonic::Status::aborted("contention").into();
+        let unavailable = Err::<(), _>(tonic::Status::unavailable("overloaded"))
+            .context("Commit", "Kind=User id=42")
+            .unwrap_err();
+        let not_found: Error = tonic::Status::not_found("no entity").into();
+        assert!(is_retryable(&aborted));
+        assert!(is_retryable(&unavailable));
+        assert!(!is_retryable(&not_found));
+
+        assert!(config.should_retry(4, &aborted));
+        assert!(!config.should_retry(5, &aborted));
+        assert!(!RetryConfig::disabled().should_retry(1, &aborted));
+    }
+
+    #[tokio::test]
+    async fn datastore_retries_stop_at_the_first_success() {
+        use std::time::Duration;
+
+        use crate::datastore::RetryConfig;
+        use crate::error::Error;
+
+        let config = RetryConfig::default().initial_backoff(Duration::from_millis(1));
+        let mut attempts = 0;
+        let result = config
+            .run(|| {
+                attempts += 1;
+                let attempt = attempts;
+                async move {
+                    match attempt {
+                        1 | 2 => Err(Error::from(tonic::Status::aborted("contention"))),
+                        _ => Ok(attempt),
+                    }
+                }
+            })
+            .await;
+        assert_eq!(result.unwrap(), 3);
+
+        let mut attempts = 0;
+        let result: Result<(), Error> = config
+            .run(|| {
+                attempts += 1;
+                async { Err(tonic::Status::invalid_argument("bad key").into()) }
+            })
+            .await;
+        assert!(result.is_err());
+        assert_eq!(attempts, 1);
+    }
+
+    #[test]
+    fn datastore_admin_operations_decode_their_metadata() {
+        use prost_types::{Any, Timestamp};
+
+        use crate::datastore::admin::{ExportConfig, Operation, OperationKind, OperationState};
+        use crate::datastore::api::datastore::admin::v1 as admin;
+        use crate::datastore::api::longrunning;
+
+        let request = ExportConfig::new("gs://backups/datastore")
+            .kind("User")
+            .label("trigger", "nightly")
+            .into_api(String::from("test-project"));
+        let filter = request.entity_filter.clone().unwrap();
+        assert_eq!(filter.kinds, vec![String::from("User")]);
+        assert!(filter.namespace_ids.is_empty());
+        assert_eq!(request.labels["trigger"], "nightly");
+        let request = ExportConfig::new("gs://backups").into_api(String::from("test-project"));
+        assert_eq!(request.entity_filter, None);
+
+        let metadata = admin::ExportEntitiesMetadata {
+            common: Some(admin::CommonMetadata {
+                start_time: Some(Timestamp {
+                    seconds: 1_600_000_000,
+                    nanos: 0,
+                }),
+                end_time: None,
+                operation_type: admin::OperationType::ExportEntities as i32,
+                labels: HashMap::new(),
+                state: admin::common_metadata::State::Successful as i32,
+            }),
+            progress_entities: Some(admin::Progress {
+                work_completed: 120,
+                work_estimated: 100,
+            }),
+            progress_bytes: None,
+            entity_filter: Some(filter),
+            output_url_prefix: String::from("gs://backups/datastore"),
+        };
+        let response = admin::ExportEntitiesResponse {
+            output_url: String::from("gs://backups/datastore/2020/export.overall_export_metadata"),
+        };
+        let operation = Operation::from(longrunning::Operation {
+            name: String::from("projects/test-project/operations/export-1"),
+            metadata: Some(Any {
+                type_url: String::from(
+                    "type.googleapis.com/google.datastore.admin.v1.ExportEntitiesMetadata",
+                ),
+                value: metadata.encode_to_vec(),
+            }),
+            done: true,
+            result: Some(longrunning::operation::Result::Response(Any {
+                type_url: String::from(
+                    "type.googleapis.com/google.datastore.admin.v1.ExportEntitiesResponse",
+                ),
+                value: response.encode_to_vec(),
+            })),
+        });
+        assert!(operation.is_done());
+        assert_eq!(operation.kind(), OperationKind::Export);
+        assert_eq!(operation.state(), OperationState::Successful);
+        assert!(operation.start_time().is_some());
+        assert_eq!(operation.end_time(), None);
+        assert_eq!(operation.entities().completed(), 120);
+        assert_eq!(operation.bytes().estimated(), 0);
+        assert_eq!(operation.filter().kinds(), ["User"]);
+        assert_eq!(
+            operation.output_url(),
+            Some("gs://backups/datastore/2020/export.overall_export_metadata"),
+        );
+
+        let operation = Operation::from(longrunning::Operation {
+            name: String::from("projects/test-project/operations/import-1"),
+            metadata: None,
+            done: false,
+            result: None,
+        });
+        assert_eq!(operation.kind(), OperationKind::Other);
+        assert_eq!(operation.state(), OperationState::Unknown);
+        assert_eq!(operation.output_url(), None);
+    }
+
+    #[test]
+    fn datastore_admin_indexes_convert_both_ways() {
+        use crate::datastore::admin::{Index, IndexConfig, IndexDirection, IndexState};
+        use crate::datastore::api::datastore::admin::v1 as admin;
+
+        let config = IndexConfig::new("Order")
+            .ancestor()
+            .ascending("customer")
+            .descending("created_at");
+        let mut index = config.into_api(String::from("test-project"));
+        assert_eq!(
+            index.ancestor,
+            admin::index::AncestorMode::AllAncestors as i32
+        );
+        assert_eq!(index.properties.len(), 2);
+        assert_eq!(
+            index.properties[1].direction,
+            admin::index::Direction::Descending as i32,
+        );
+        assert_eq!(index.state, admin::index::State::Unspecified as i32);
+
+        index.index_id = String::from("CICAgJiUpoMK");
+        index.state = admin::index::State::Ready as i32;
+        let index = Index::from(index);
+        assert_eq!(index.id(), "CICAgJiUpoMK");
+        assert_eq!(index.kind(), "Order");
+        assert!(index.ancestor());
+        assert_eq!(index.state(), IndexState::Ready);
+        let properties: Vec<_> = index
+            .properties()
+            .iter()
+            .map(|property| (property.name(), property.direction()))
+            .collect();
+        assert_eq!(
+            properties,
+            vec![
+                ("customer", IndexDirection::Ascending),
+                ("created_at", IndexDirection::Descending),
+            ],
+        );
+
+        let index = IndexConfig::new("Order").ascending("customer");
+        let index = Index::from(index.into_api(String::from("test-project")));
+        assert!(!index.ancestor());
+        assert_eq!(index.state(), IndexState::Unknown);
+    }
+
+    #[test]
+    fn datastore_geo_queries_cover_their_circle() {
+        use crate::datastore::{geo_distance, geohash, Entity, Filter, GeoIndex, Query};
+
+        let eiffel_tower = (48.8584, 2.2945);
+        assert_eq!(geohash(eiffel_tower.0, eiffel_tower.1, 9), "u09tunquc");
+
+        let index = GeoIndex::new("location").precision(6);
+        let mut properties = HashMap::new();
+        properties.insert(
+            String::from("location"),
+            Value::GeoPointValue(eiffel_tower.0, eiffel_tower.1),
+        );
+        let entity = Entity::new(Key::new("Place").id("eiffel-tower"), properties).unwrap();
+        let entity = index.index(entity).unwrap();
+        let hashes = ["u", "u0", "u09", "u09t", "u09tu", "u09tun"];
+        let hashes = hashes
+            .iter()
+            .map(|hash| Value::StringValue(hash.to_string()));
+        let indexed = match entity.properties() {
+            Value::EntityValue(properties) => properties.get("location_geohash").cloned(),
+            _ => None,
+        };
+        assert_eq!(indexed, Some(Value::ArrayValue(hashes.collect())));
+
+        //? The cells around the center are at least as large as the radius, so they cover the whole circle.
+        let center = (48.8606, 2.3376);
+        let query = Query::new("Place").within_radius(&index, center, 5000.0);
+        let cells = match query.query().filters.as_slice() {
+            [Filter::In(name, Value::ArrayValue(cells))] if name == "location_geohash" => cells,
+            filters => panic!("unexpected filters: {:?}", filters),
+        };
+        assert_eq!(cells.len(), 9);
+        let mut rng = rng();
+        for _ in 0..CASES {
+            let point = (
+                center.0 + rng.gen_range(-0.05..0.05),
+                center.1 + rng.gen_range(-0.07..0.07),
+            );
+            if geo_distance(center, point) > 5000.0 {
+                continue;
+            }
+            let cell = |len| Value::StringValue(geohash(point.0, point.1, len));
+            assert!((1..=6).any(|len| cells.contains(&cell(len))));
+        }
+        let distance = query.distance_of(&entity).unwrap();
+        assert!((distance - geo_distance(center, eiffel_tower)).abs() < 1e-6);
+        let query = Query::new("Place").within_radius(&index, center, 1000.0);
+        assert_eq!(query.distance_of(&entity), None);
+
+        //? Larger circles than the largest cells can't be narrowed down by the index.
+        let query = Query::new("Place").within_radius(&index, center, 10_000_000.0);
+        assert_eq!(query.query(), &Query::new("Place"));
+    }
+
+    #[test]
+    fn datastore_keys_survive_url_safe_round_trip() {
+        let mut rng = rng();
+        for _ in 0..CASES {
+            let key = random_key(&mut rng, 3);
+            let encoded = key.to_url_safe("test-project");
+            assert!(!encoded.contains(['+', '/', '=']));
+            let (project, decoded) = Key::from_url_safe(&encoded).unwrap();
+            assert_eq!(project, "test-project");
+            assert_eq!(decoded, key);
+            let namespace = key
+                .get_namespace()
+                .filter(|namespace| !namespace.is_empty());
+            assert_eq!(decoded.get_namespace(), namespace);
+        }
+
+        //? Padded encodings (as produced by some libraries) and location prefixes are accepted too.
+        let key = Key::new("User").id(42).namespace("dev");
+        let encoded = format!("{}==", key.to_url_safe("e~test-project"));
+        let decoded = Key::from_url_safe(&encoded).unwrap();
+        assert_eq!(decoded, (String::from("test-project"), key));
+
+        assert!(matches!(
+            Key::from_url_safe("not a key"),
+            Err(ConvertError::InvalidKey(_))
+        ));
+        assert!(matches!(
+            Key::from_url_safe(&Key::new("User").to_url_safe("")[..4]),
+            Err(ConvertError::InvalidKey(_))
+        ));
+    }
+
+    #[test]
+    fn datastore_key_paths_are_validated() {
+        let key = Key::new("Org")
+            .name("acme")
+            .namespace("dev")
+            .child("User")
+            .id(42);
+        assert_eq!(key.get_namespace(), Some("dev"));
+        assert_eq!(
+            key.get_parent(),
+            Some(&Key::new("Org").name("acme").namespace("dev"))
+        );
+        assert!(key.validate().is_ok());
+        assert!(key.clone().child("Session").validate().is_ok());
+
+        let reason = |key: Key| match key.validate() {
+            Err(ConvertError::InvalidKeyPath { reason, .. }) => reason,
+            result => panic!("unexpected validation result: {:?}", result),
+        };
+        assert_eq!(reason(Key::new("").id(1)), "invalid kind ``");
+        assert_eq!(
+            reason(Key::new("__Stat_Kind__").id(1)),
+            "invalid kind `__Stat_Kind__`"
+        );
+        assert_eq!(
+            reason(key.clone().child("Session").name("")),
+            "invalid name ``"
+        );
+        assert_eq!(
+            reason(key.clone().child("Session").name("__x__")),
+            "invalid name `__x__`"
+        );
+        assert_eq!(reason(key.clone().child("Session").id(0)), "invalid ID `0`");
+        assert_eq!(
+            reason(Key::new("Org").child("User").id(42)),
+            "incomplete ancestor of kind `Org`"
+        );
+        //? Names merely starting or ending with underscores are not reserved.
+        assert!(Key::new("_User").name("__x").validate().is_ok());
+
+        let deep = (1..100).fold(Key::new("Node").id(1), |key, id| key.child("Node").id(id));
+        assert!(deep.validate().is_ok());
+        assert_eq!(
+            reason(deep.child("Node").id(100)),
+            "more than 100 path elements"
+        );
+    }
+
+    #[test]
+    fn datastore_index_exclusion_is_recursive() {
+        fn assert_excluded(value: &api::Value) {
+            match value.value_type.as_ref() {
+                Some(api::value::ValueType::ArrayValue(array)) => {
+                    assert!(!value.exclude_from_indexes);
+                    array.values.iter().for_each(assert_excluded);
+                }
+                Some(api::value::ValueType::EntityValue(entity)) => {
+                    assert!(value.exclude_from_indexes);
+                    entity.properties.values().for_each(assert_excluded);
+                }
+                _ => assert!(value.exclude_from_indexes, "indexed value: {:?}", value),
+            }
+        }
+
+        let mut rng = rng();
+        for _ in 0..CASES {
+            let value = random_value(&mut rng, 3).exclude_from_indexes();
+            assert_excluded(&convert_value(PARTITION, value.clone()).unwrap());
+            assert_eq!(round_trip(value.clone()), value.canonicalize());
+        }
+
+        //? Large blobs are accepted anywhere within excluded values.
+        let mut properties = HashMap::new();
+        properties.insert(String::from("blob"), Value::BlobValue(vec![0; 1501]));
+        let value = Value::ArrayValue(vec![Value::EntityValue(properties)]);
+        assert!(convert_value(PARTITION, value.clone()).is_err());
+        assert!(convert_value(PARTITION, value.clone().exclude_from_indexes()).is_ok());
+
+        //? The same goes for entities flagged as excluded, without flagging their properties.
+        let value = Value::IndexedValue(Box::new(value), false);
+        let converted = convert_value(PARTITION, value.clone()).unwrap();
+        assert_excluded(&converted);
+        assert_eq!(round_trip(value.clone()), value.canonicalize());
+    }
+
+    #[test]
+    fn datastore_entities_survive_json_round_trip() {
+        let mut rng = rng();
+        for _ in 0..CASES {
+            let properties = (0..rng.gen_range(0..4))
+                .map(|_| (random_string(&mut rng), random_value(&mut rng, 3)))
+                .collect::<HashMap<_, _>>();
+            let entity = Entity::new(random_key(&mut rng, 3), properties).unwrap();
+            let expected = Entity {
+                key: entity.key().clone(),
+                properties: entity.properties().clone().canonicalize(),
+            };
+            assert_eq!(Entity::from_json(entity.to_json()).unwrap(), expected);
+        }
+
+        //? Integers are strings, to survive parsers using doubles, and excluded values are flagged.
+        let mut properties = HashMap::new();
+        properties.insert(String::from("id"), Value::IntegerValue(i64::MAX));
+        let bio = Value::StringValue(String::from("...")).exclude_from_indexes();
+        properties.insert(String::from("bio"), bio);
+        let entity = Entity::new(Key::new("user").id(7).namespace("eu"), properties).unwrap();
+        let expected = json::json!({
+            "key": {
+                "partitionId": { "namespaceId": "eu" },
+                "path": [{ "kind": "user", "id": "7" }],
+            },
+            "properties": {
+                "id": { "integerValue": "9223372036854775807" },
+                "bio": { "stringValue": "...", "excludeFromIndexes": true },
+            },
+        });
+        assert_eq!(entity.to_json(), expected);
+
+        let invalid = json::json!({ "key": { "path": [] }, "properties": {} });
+        assert!(matches!(
+            Entity::from_json(invalid),
+            Err(ConvertError::InvalidJson(_))
+        ));
+    }
+
+    #[test]
+    fn datastore_transaction_reads_are_send() {
+        //? Only checks that this compiles: the futures of transactions must be `Send` to be boxed.
+        #[allow(dead_code)]
+        async fn visits(mut client: crate::datastore::Client, key: Key) -> Option<i64> {
+            let result = client
+                .run_in_transaction(|tx| {
+                    let key = key.clone();
+                    Box::pin(async move {
+                        let counter: Option<HashMap<String, i64>> = tx.get(&key).await?;
+                        let others: Vec<HashMap<String, i64>> = tx.get_all(Some(&key)).await?;
+                        let counter = counter.or_else(|| others.into_iter().next());
+                        Ok(counter.and_then(|counter| counter.get("visits").copied()))
+                    })
+                })
+                .await;
+            result.ok().flatten()
+        }
+    }
+}
+
+#[cfg(feature = "pubsub")]
+mod pubsub {
+    use prost::Message;
+    use rand::Rng;
+
+    use crate::pubsub::api;
+    use crate::pubsub::PublishMessage;
+
+    use super::{random_string, rng, CASES};
+
+    #[test]
+    fn pubsub_messages_survive_round_trip() {
+        let mut rng = rng();
+        for _ in 0..CASES {
+            let len = rng.gen_range(0..64);
+            let data: Vec<u8> = (0..len).map(|_| rng.gen()).collect();
+            let attributes: Vec<(String, String)> = (0..rng.gen_range(0..4))
+                .map(|_| (random_string(&mut rng), random_string(&mut rng)))
+                .collect();
+            let ordering_key = random_string(&mut rng);
+            let message = PublishMessage::new(data.clone())
+                .attributes(attributes.clone())
+                .ordering_key(ordering_key.clone());
+
+            let encoded = api::PubsubMessage::from(message).encode_to_vec();
+            let decoded = api::PubsubMessage::decode(encoded.as_slice()).unwrap();
+
+            assert_eq!(decoded.data.as_ref(), data.as_slice());
+            assert_eq!(decoded.attributes, attributes.into_iter().collect());
+            assert_eq!(decoded.ordering_key, ordering_key);
+        }
+    }
+}
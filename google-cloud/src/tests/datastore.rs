@@ -48,3 +48,771 @@ async fn datastore_puts_data_successfully() {
     //? Delete that value from Datastore.
     assert_ok!(client.delete(key).await);
 }
+
+#[tokio::test]
+async fn datastore_queries_with_metadata() {
+    //? Setup test client.
+    let mut client = assert_ok!(setup_client().await);
+
+    //? Store a few values in Datastore.
+    let keys: Vec<_> = (0..3)
+        .map(|idx| {
+            datastore::Key::new("google-cloud-tests-query")
+                .namespace("test")
+                .id(format!("test-id-{}", idx))
+        })
+        .collect();
+    for key in keys.iter() {
+        let mut properties = HashMap::new();
+        properties.insert(String::from("hello"), "world !".into_value());
+        assert_ok!(client.put((key.clone(), properties)).await);
+    }
+
+    //? Query them back, skipping the first one and limiting to a single result.
+    let query = datastore::Query::new("google-cloud-tests-query")
+        .namespace("test")
+        .offset(1)
+        .limit(1);
+    let results = assert_ok!(client.query_with_meta(query).await);
+    assert_eq!(results.entities().len(), 1);
+    assert_eq!(results.skipped_results(), 1);
+    assert_eq!(results.more_results(), datastore::MoreResults::AfterLimit);
+    assert!(results.snapshot_version() > 0);
+
+    //? Delete the values from Datastore.
+    assert_ok!(client.delete_all(keys).await);
+}
+
+#[tokio::test]
+async fn datastore_deletes_only_unchanged_entities() {
+    //? Setup test client.
+    let mut client = assert_ok!(setup_client().await);
+    let key = datastore::Key::new("google-cloud-tests-conditional-delete")
+        .namespace("test")
+        .id("test-id");
+    let entity = |hello: &str| {
+        let mut properties = HashMap::new();
+        properties.insert(String::from("hello"), hello.into_value());
+        (key.clone(), properties)
+    };
+
+    //? Store a value, and read its version back.
+    assert_ok!(client.put(entity("world !")).await);
+    let read = assert_ok!(client.get_with_meta::<datastore::Value, _>(&key).await);
+    let (_, meta) = read.expect("the entity was just stored");
+
+    //? A concurrent write makes the delete conflict, leaving the entity in place.
+    assert_ok!(client.put(entity("everyone !")).await);
+    let result = assert_ok!(client.delete_if_version(&key, meta.version()).await);
+    assert!(result.conflict_detected());
+    assert_eq!(result.key(), &key);
+    let read = assert_ok!(client.get_with_meta::<datastore::Value, _>(&key).await);
+    let (_, meta) = read.expect("the conflicting delete was not applied");
+
+    //? The delete goes through at the current version.
+    let result = assert_ok!(client.delete_if_version(&key, meta.version()).await);
+    assert!(!result.conflict_detected());
+    assert!(result.version() > meta.version());
+    let read = assert_ok!(client.get::<datastore::Value, _>(&key).await);
+    assert!(read.is_none());
+
+    //? Deleting several entities reports a result for every key, in order.
+    let other = datastore::Key::new("google-cloud-tests-conditional-delete")
+        .namespace("test")
+        .id("other-id");
+    let results = assert_ok!(client.delete_all(vec![key.clone(), other.clone()]).await);
+    let keys: Vec<_> = results.iter().map(|result| result.key().clone()).collect();
+    assert_eq!(keys, vec![key, other]);
+    assert!(results.iter().all(|result| !result.conflict_detected()));
+}
+
+#[tokio::test]
+async fn datastore_gets_entities_by_key() {
+    //? Setup test client.
+    let mut client = assert_ok!(setup_client().await);
+    let key = |id: &str| {
+        datastore::Key::new("google-cloud-tests-get-map")
+            .namespace("test")
+            .id(id)
+    };
+
+    //? Store a value in Datastore, leaving another one missing.
+    let mut properties = HashMap::new();
+    properties.insert(String::from("hello"), "world !".into_value());
+    assert_ok!(client.put((key("stored"), properties)).await);
+
+    //? Only the stored value is found, under the key it was requested with.
+    let keys = vec![key("missing"), key("stored")];
+    let found = assert_ok!(client.get_map::<HashMap<String, String>, _, _>(&keys).await);
+    assert_eq!(found.len(), 1);
+    assert!(!found.contains_key(&key("missing")));
+    assert_eq!(found[&key("stored")]["hello"], "world !");
+
+    //? The same goes for existence checks, which don't fetch the value.
+    assert!(assert_ok!(client.exists(key("stored")).await));
+    assert!(!assert_ok!(client.exists(key("missing")).await));
+
+    //? Delete the value from Datastore.
+    assert_ok!(client.delete(key("stored")).await);
+}
+
+#[tokio::test]
+async fn datastore_runs_transactions() {
+    //? Setup test client.
+    let mut client = assert_ok!(setup_client().await);
+    let key = datastore::Key::new("google-cloud-tests-transaction")
+        .namespace("test")
+        .id("counter");
+
+    //? Increment a counter twice, within transactions.
+    for expected in 1..=2 {
+        let visits = client
+            .run_in_transaction(|tx| {
+                let key = key.clone();
+                Box::pin(async move {
+                    let counter: Option<HashMap<String, i64>> = tx.get(&key).await?;
+                    let visits = counter
+                        .and_then(|counter| counter.get("visits").copied())
+                        .unwrap_or(0)
+                        + 1;
+                    let mut properties = HashMap::new();
+                    properties.insert(String::from("visits"), visits);
+                    tx.put((key, properties))?;
+                    Ok(visits)
+                })
+            })
+            .await;
+        assert_eq!(assert_ok!(visits), expected);
+    }
+
+    //? A failing transaction gets rolled back.
+    let result: Result<(), _> = client
+        .run_in_transaction(|tx| {
+            let key = key.clone();
+            Box::pin(async move {
+                tx.delete(key);
+                Err(crate::error::ConvertError::MissingProperty(String::from("visits")).into())
+            })
+        })
+        .await;
+    assert!(result.is_err());
+    let counter = assert_ok!(client.get::<HashMap<String, i64>, _>(&key).await);
+    assert_eq!(
+        counter.and_then(|counter| counter.get("visits").copied()),
+        Some(2)
+    );
+
+    //? Delete the counter from Datastore.
+    assert_ok!(client.delete(key).await);
+}
+
+#[tokio::test]
+async fn datastore_queries_within_transactions() {
+    //? Setup test client.
+    let mut client = assert_ok!(setup_client().await);
+    let parent = datastore::Key::new("google-cloud-tests-ancestor")
+        .namespace("test")
+        .id("list");
+    let item = {
+        let parent = parent.clone();
+        move |idx: i64| {
+            datastore::Key::new("google-cloud-tests-item")
+                .namespace("test")
+                .id(idx)
+                .parent(parent.clone())
+        }
+    };
+
+    //? Store a couple of items under a common ancestor.
+    for idx in 1..=2 {
+        let mut properties = HashMap::new();
+        properties.insert(String::from("position"), idx);
+        assert_ok!(client.put((item(idx), properties)).await);
+    }
+
+    //? Append an item after the existing ones, based on an ancestor query.
+    let position = client
+        .run_in_transaction(|tx| {
+            let parent = parent.clone();
+            let item = item.clone();
+            Box::pin(async move {
+                let query = datastore::Query::new("google-cloud-tests-item")
+                    .namespace("test")
+                    .ancestor(parent);
+                let results = tx.query_with_meta(query).await?;
+                let position = results.entities().len() as i64 + 1;
+                let mut properties = HashMap::new();
+                properties.insert(String::from("position"), position);
+                tx.put((item(position), properties))?;
+                Ok(position)
+            })
+        })
+        .await;
+    assert_eq!(assert_ok!(position), 3);
+
+    //? Delete the items from Datastore.
+    assert_ok!(client.delete_all((1..=3).map(item)).await);
+}
+
+#[tokio::test]
+async fn datastore_reads_within_read_only_transactions() {
+    //? Setup test client.
+    let mut client = assert_ok!(setup_client().await);
+    let key = datastore::Key::new("google-cloud-tests-snapshot")
+        .namespace("test")
+        .id("counter");
+    let counter = |value: i64| {
+        let mut properties = HashMap::new();
+        properties.insert(String::from("value"), value);
+        properties
+    };
+    assert_ok!(client.put((key.clone(), counter(1))).await);
+
+    //? Read the entity, then update it from outside of the transaction.
+    let mut tx = assert_ok!(client.begin_read_only_transaction().await);
+    let before: Option<HashMap<String, i64>> = assert_ok!(tx.get(&key).await);
+    assert_ok!(client.put((key.clone(), counter(2))).await);
+
+    //? The transaction keeps observing its snapshot.
+    let after: Option<HashMap<String, i64>> = assert_ok!(tx.get(&key).await);
+    assert_eq!(before, Some(counter(1)));
+    assert_eq!(after, Some(counter(1)));
+
+    //? So do its queries, whether buffered or streamed.
+    let query = datastore::Query::new("google-cloud-tests-snapshot").namespace("test");
+    let values: Vec<HashMap<String, i64>> = assert_ok!(tx.query_as(query.clone()).await);
+    assert_eq!(values, vec![counter(1)]);
+    let streamed = {
+        use futures::TryStreamExt;
+        let stream = tx.query_stream(query);
+        assert_ok!(stream.try_collect::<Vec<datastore::Entity>>().await)
+    };
+    let keys: Vec<&datastore::Key> = streamed.iter().map(|entity| entity.key()).collect();
+    assert_eq!(keys, vec![&key]);
+    assert_ok!(tx.end().await);
+
+    //? Delete the entity from Datastore.
+    assert_ok!(client.delete(key).await);
+}
+
+#[tokio::test]
+async fn datastore_pages_through_query_results() {
+    //? Setup test client.
+    let mut client = assert_ok!(setup_client().await);
+    let parent = datastore::Key::new("google-cloud-tests-ancestor")
+        .namespace("test")
+        .id("pages");
+    let item = {
+        let parent = parent.clone();
+        move |idx: i64| {
+            datastore::Key::new("google-cloud-tests-page")
+                .namespace("test")
+                .id(idx)
+                .parent(parent.clone())
+        }
+    };
+    for idx in 1..=3 {
+        let mut properties = HashMap::new();
+        properties.insert(String::from("position"), idx);
+        assert_ok!(client.put((item(idx), properties)).await);
+    }
+    let query = datastore::Query::new("google-cloud-tests-page")
+        .namespace("test")
+        .ancestor(parent)
+        .order(datastore::Order::Asc(String::from("position")))
+        .limit(2);
+
+    //? Fetch the first page, and hand out its cursor as a string.
+    let first = assert_ok!(client.query_with_meta(query.clone()).await);
+    assert_eq!(first.entities().len(), 2);
+    let token = first.end_cursor().expect("missing end cursor").to_string();
+
+    //? Resume the query from the cursor, as a later request would.
+    let cursor: datastore::Cursor = assert_ok!(token.parse());
+    let second = assert_ok!(client.query_with_meta(query.start_cursor(cursor)).await);
+    let ids: Vec<&datastore::KeyID> = second.entities().iter().map(|e| e.key().get_id()).collect();
+    assert_eq!(ids, vec![&datastore::KeyID::IntID(3)]);
+
+    //? Delete the items from Datastore.
+    assert_ok!(client.delete_all((1..=3).map(item)).await);
+}
+
+#[tokio::test]
+async fn datastore_streams_query_results() {
+    use futures::TryStreamExt;
+
+    //? Setup test client.
+    let mut client = assert_ok!(setup_client().await);
+    let parent = datastore::Key::new("google-cloud-tests-ancestor")
+        .namespace("test")
+        .id("stream");
+    let item = {
+        let parent = parent.clone();
+        move |idx: i64| {
+            datastore::Key::new("google-cloud-tests-streamed")
+                .namespace("test")
+                .id(idx)
+                .parent(parent.clone())
+        }
+    };
+    let entities: Vec<(datastore::Key, HashMap<String, i64>)> = (1..=5)
+        .map(|idx| {
+            let mut properties = HashMap::new();
+            properties.insert(String::from("position"), idx);
+            (item(idx), properties)
+        })
+        .collect();
+    assert_ok!(client.put_all(entities).await);
+
+    //? Stream the items past the offset, up to the limit.
+    let query = datastore::Query::new("google-cloud-tests-streamed")
+        .namespace("test")
+        .ancestor(parent)
+        .order(datastore::Order::Asc(String::from("position")))
+        .offset(1)
+        .limit(3);
+    let streamed: Vec<datastore::Entity> =
+        assert_ok!(client.query_stream(query).try_collect().await);
+    let ids: Vec<&datastore::KeyID> = streamed.iter().map(|e| e.key().get_id()).collect();
+    let expected: Vec<datastore::KeyID> = (2..=4).map(datastore::KeyID::IntID).collect();
+    assert_eq!(ids, expected.iter().collect::<Vec<_>>());
+
+    //? Delete the items from Datastore.
+    assert_ok!(client.delete_all((1..=5).map(item)).await);
+}
+
+#[tokio::test]
+async fn datastore_allocates_ids() {
+    //? Setup test client.
+    let mut client = assert_ok!(setup_client().await);
+    let parent = datastore::Key::new("google-cloud-tests-ancestor")
+        .namespace("test")
+        .id("allocated");
+    let incomplete = datastore::Key::new("google-cloud-tests-allocated")
+        .namespace("test")
+        .parent(parent);
+
+    //? Every key gets its own ID, keeping the rest of its path.
+    let keys = assert_ok!(client.allocate_ids(vec![incomplete.clone(); 2]).await);
+    assert_eq!(keys.len(), 2);
+    for key in keys.iter() {
+        assert!(!key.is_incomplete());
+        assert_eq!(key.get_kind(), incomplete.get_kind());
+        assert_eq!(
+            key.clone().canonicalize().get_parent(),
+            incomplete.clone().canonicalize().get_parent()
+        );
+    }
+    assert_ne!(keys[0], keys[1]);
+}
+
+#[tokio::test]
+async fn datastore_reserves_ids() {
+    //? Setup test client.
+    let mut client = assert_ok!(setup_client().await);
+    let keys: Vec<_> = (1_000_000..1_000_003_i64)
+        .map(|id| {
+            datastore::Key::new("google-cloud-tests-reserved")
+                .namespace("test")
+                .id(id)
+        })
+        .collect();
+
+    //? Complete keys get their IDs reserved.
+    assert_ok!(client.reserve_ids(&keys).await);
+
+    //? Incomplete keys are rejected.
+    let incomplete = datastore::Key::new("google-cloud-tests-reserved").namespace("test");
+    assert!(client.reserve_ids(Some(incomplete)).await.is_err());
+}
+
+#[tokio::test]
+async fn datastore_executes_batches() {
+    //? Setup test client.
+    let mut client = assert_ok!(setup_client().await);
+    let key = |id: &str| {
+        datastore::Key::new("google-cloud-tests-batch")
+            .namespace("test")
+            .id(id.to_string())
+    };
+    let properties = || {
+        let mut properties = HashMap::new();
+        properties.insert(String::from("hello"), "world !".into_value());
+        properties
+    };
+    assert_ok!(client.put((key("existing"), properties())).await);
+
+    //? Mix lookups and writes in a single batch.
+    let mut batch = client.batch();
+    batch.get(key("existing"));
+    batch.get(key("missing"));
+    assert_ok!(batch.put((key("created"), properties())));
+    batch.delete(key("existing"));
+    let outcomes = assert_ok!(batch.execute().await);
+
+    //? Lookups happen before the writes, and every operation gets its own outcome.
+    assert_eq!(outcomes.len(), 4);
+    match &outcomes[0] {
+        datastore::BatchOutcome::Found(entity) => assert_eq!(entity.key(), &key("existing")),
+        outcome => panic!("unexpected outcome: {:?}", outcome),
+    }
+    assert_eq!(
+        outcomes[1],
+        datastore::BatchOutcome::Missing(key("missing"))
+    );
+    match &outcomes[2] {
+        datastore::BatchOutcome::Put(result) => {
+            assert_eq!(result.key(), &key("created"));
+            assert!(result.version() > 0);
+        }
+        outcome => panic!("unexpected outcome: {:?}", outcome),
+    }
+    match &outcomes[3] {
+        datastore::BatchOutcome::Deleted(result) => assert_eq!(result.key(), &key("existing")),
+        outcome => panic!("unexpected outcome: {:?}", outcome),
+    }
+    let existing = assert_ok!(client.get::<datastore::Value, _>(key("existing")).await);
+    assert!(existing.is_none());
+
+    //? Delete the created value from Datastore.
+    assert_ok!(client.delete(key("created")).await);
+}
+
+#[tokio::test]
+async fn datastore_uses_default_namespaces() {
+    //? Setup test client, with a default namespace.
+    let client = assert_ok!(setup_client().await);
+    let mut client = client.namespace("test");
+    let key = datastore::Key::new("google-cloud-tests-namespace").id("default");
+    let mut properties = HashMap::new();
+    properties.insert(String::from("hello"), "world !".into_value());
+
+    //? Keys without a namespace are stored in the default one.
+    assert_ok!(client.put((key.clone(), properties)).await);
+    let stored = assert_ok!(
+        client
+            .get::<datastore::Value, _>(key.clone().namespace("test"))
+            .await
+    );
+    assert!(stored.is_some());
+
+    //? As are queries.
+    let query = datastore::Query::new("google-cloud-tests-namespace");
+    let entities = assert_ok!(client.query(query).await);
+    assert!(entities
+        .iter()
+        .any(|entity| entity.key().get_namespace() == Some("test")));
+
+    //? Unless they have their own.
+    let elsewhere = assert_ok!(
+        client
+            .get::<datastore::Value, _>(key.clone().namespace(""))
+            .await
+    );
+    assert!(elsewhere.is_none());
+
+    //? Delete the value from Datastore.
+    assert_ok!(client.delete(key).await);
+}
+
+#[tokio::test]
+async fn datastore_serves_tenants_from_their_namespaces() {
+    //? Setup test client, deriving a client per tenant.
+    let client = assert_ok!(setup_client().await);
+    let mut tenant_a = client.for_namespace("test-tenant-a");
+    let mut tenant_b = client.for_namespace("test-tenant-b");
+    assert_eq!(client.default_namespace(), None);
+    assert_eq!(tenant_a.default_namespace(), Some("test-tenant-a"));
+    let key = datastore::Key::new("google-cloud-tests-tenants").id("settings");
+    let mut properties = HashMap::new();
+    properties.insert(String::from("tenant"), "a".into_value());
+
+    //? The same key refers to a different entity for every tenant.
+    assert_ok!(tenant_a.put((key.clone(), properties)).await);
+    let stored = assert_ok!(tenant_a.get::<datastore::Value, _>(&key).await);
+    assert!(stored.is_some());
+    let elsewhere = assert_ok!(tenant_b.get::<datastore::Value, _>(&key).await);
+    assert!(elsewhere.is_none());
+
+    //? Delete the value from Datastore.
+    assert_ok!(tenant_a.delete(key).await);
+}
+
+#[tokio::test]
+async fn datastore_reads_its_writes_within_transactions() {
+    //? Setup test client.
+    let mut client = assert_ok!(setup_client().await);
+    let key = |id: &str| {
+        datastore::Key::new("google-cloud-tests-read-your-writes")
+            .namespace("test")
+            .id(id)
+    };
+    let mut properties = HashMap::new();
+    properties.insert(String::from("stored"), true);
+    assert_ok!(client.put((key("stored"), properties)).await);
+
+    //? Lookups observe the buffered writes, merged with the stored entities.
+    let result = client
+        .run_in_transaction(|tx| {
+            Box::pin(async move {
+                let mut properties = HashMap::new();
+                properties.insert(String::from("stored"), false);
+                tx.put((key("buffered"), properties))?;
+                let both: Vec<HashMap<String, bool>> =
+                    tx.get_all(&[key("stored"), key("buffered")]).await?;
+                tx.delete(key("stored"));
+                let deleted: Option<HashMap<String, bool>> = tx.get(key("stored")).await?;
+                tx.delete(key("buffered"));
+                Ok((both, deleted))
+            })
+        })
+        .await;
+    let (both, deleted) = assert_ok!(result);
+    assert_eq!(both.len(), 2);
+    assert_eq!(both[0].get("stored"), Some(&true));
+    assert_eq!(both[1].get("stored"), Some(&false));
+    assert!(deleted.is_none());
+
+    //? The writes got committed.
+    let stored = assert_ok!(client.get::<datastore::Value, _>(key("stored")).await);
+    assert!(stored.is_none());
+}
+
+#[tokio::test]
+async fn datastore_lists_kinds_and_namespaces() {
+    //? Setup test client, with a default namespace.
+    let client = assert_ok!(setup_client().await);
+    let mut client = client.namespace("test");
+    let key = datastore::Key::new("google-cloud-tests-metadata").id("listed");
+    let mut properties = HashMap::new();
+    properties.insert(String::from("listed"), true);
+    assert_ok!(client.put((key.clone(), properties)).await);
+
+    //? Kinds are listed within the namespace of the client.
+    let kinds = assert_ok!(client.list_kinds().await);
+    assert!(kinds
+        .iter()
+        .any(|kind| kind == "google-cloud-tests-metadata"));
+    assert!(kinds.iter().all(|kind| !kind.starts_with("__")));
+
+    //? Namespaces are listed regardless of it.
+    let namespaces = assert_ok!(client.list_namespaces().await);
+    assert!(namespaces.iter().any(|namespace| namespace == "test"));
+
+    //? Delete the entity from Datastore.
+    assert_ok!(client.delete(key).await);
+}
+
+#[tokio::test]
+async fn datastore_decodes_query_results_with_their_keys() {
+    use crate::datastore::{DecodeOptions, FromKeyID, FromValue, Key, Query, Value};
+    use crate::error::ConvertError;
+
+    struct Item {
+        id: String,
+        rank: i64,
+    }
+
+    impl FromValue for Item {
+        fn from_value(value: Value) -> Result<Item, ConvertError> {
+            let mut properties: HashMap<String, Value> = FromValue::from_value(value)?;
+            let rank = properties
+                .remove("rank")
+                .ok_or_else(|| ConvertError::MissingProperty(String::from("rank")))?;
+            Ok(Item {
+                id: String::new(),
+                rank: FromValue::from_value(rank)?,
+            })
+        }
+
+        fn from_entity_with(
+            key: &Key,
+            properties: Value,
+            options: &DecodeOptions,
+        ) -> Result<Item, ConvertError> {
+            let item = Item::from_value_with(properties, options)?;
+            let id = FromKeyID::from_key_id(key.get_id().clone())?;
+            Ok(Item { id, ..item })
+        }
+    }
+
+    //? Setup test client.
+    let mut client = assert_ok!(setup_client().await);
+
+    //? Store a few values in Datastore.
+    let keys: Vec<_> = (0..3)
+        .map(|idx| {
+            datastore::Key::new("google-cloud-tests-query-as")
+                .namespace("test")
+                .id(format!("item-{}", idx))
+        })
+        .collect();
+    for (idx, key) in keys.iter().enumerate() {
+        let mut properties = HashMap::new();
+        properties.insert(String::from("rank"), idx as i64);
+        assert_ok!(client.put((key.clone(), properties)).await);
+    }
+
+    //? The values are decoded along with the IDs of their keys.
+    let query = Query::new("google-cloud-tests-query-as").namespace("test");
+    let mut items = assert_ok!(client.query_as::<Item>(query).await);
+    items.sort_by_key(|item| item.rank);
+    let items: Vec<_> = items
+        .iter()
+        .map(|item| (item.id.as_str(), item.rank))
+        .collect();
+    assert_eq!(items, vec![("item-0", 0), ("item-1", 1), ("item-2", 2)]);
+
+    //? Delete the values from Datastore.
+    assert_ok!(client.delete_all(keys).await);
+}
+
+#[tokio::test]
+async fn datastore_operates_on_other_projects() {
+    //? Setup a test client, for a placeholder project.
+    let creds = super::load_creds();
+    let client =
+        assert_ok!(datastore::Client::from_credentials("placeholder-project", creds).await);
+
+    //? The requests of the derived client target the test project instead.
+    let mut client = client
+        .namespace("test")
+        .for_project(env!("GCP_TEST_PROJECT"));
+    assert_eq!(client.project_name(), env!("GCP_TEST_PROJECT"));
+    let key = datastore::Key::new("google-cloud-tests-projects").id("overridden");
+    let mut properties = HashMap::new();
+    properties.insert(String::from("overridden"), true);
+    assert_ok!(client.put((key.clone(), properties)).await);
+    let stored = assert_ok!(client.get::<datastore::Value, _>(&key).await);
+    assert!(stored.is_some());
+
+    //? Delete the entity from Datastore.
+    assert_ok!(client.delete(key).await);
+}
+
+#[tokio::test]
+async fn datastore_honors_limits_across_batches() {
+    //? Runs against a local emulator, if one is available, with enough entities to span several batches.
+    let host = match std::env::var("DATASTORE_EMULATOR_HOST") {
+        Ok(host) => host,
+        Err(_) => return,
+    };
+    let mut client = assert_ok!(datastore::Client::for_emulator("test-project", host).await);
+    let item = |idx: i64| datastore::Key::new("google-cloud-tests-batches").id(idx);
+    let entities: Vec<(datastore::Key, HashMap<String, i64>)> = (1..=1000)
+        .map(|idx| {
+            let mut properties = HashMap::new();
+            properties.insert(String::from("position"), idx);
+            (item(idx), properties)
+        })
+        .collect();
+    assert_ok!(client.put_all(entities).await);
+
+    //? The offset and limit apply to the whole result set, however many batches it spans.
+    let query = datastore::Query::new("google-cloud-tests-batches")
+        .order(datastore::Order::Asc(String::from("position")))
+        .offset(150)
+        .limit(700);
+    let results = assert_ok!(client.query_with_meta(query.clone()).await);
+    let ids: Vec<&datastore::KeyID> = results
+        .entities()
+        .iter()
+        .map(|e| e.key().get_id())
+        .collect();
+    let expected: Vec<datastore::KeyID> = (151..=850).map(datastore::KeyID::IntID).collect();
+    assert_eq!(ids, expected.iter().collect::<Vec<_>>());
+    assert_eq!(results.more_results(), datastore::MoreResults::AfterLimit);
+
+    //? The same goes for streamed results.
+    let streamed = {
+        use futures::TryStreamExt;
+        let stream = client.query_stream(query.clone());
+        assert_ok!(stream.try_collect::<Vec<datastore::Entity>>().await)
+    };
+    assert_eq!(streamed.len(), 700);
+    assert_eq!(results.skipped_results(), 150);
+
+    //? Counting the results honors the offset and limit too, without fetching the entities.
+    assert_eq!(assert_ok!(client.count(query.clone()).await), 700);
+
+    //? Every batch reports what it skipped and returned on its own.
+    let batches = {
+        use futures::TryStreamExt;
+        let stream = client.query_batches(query);
+        assert_ok!(stream.try_collect::<Vec<datastore::QueryResults>>().await)
+    };
+    let skipped: i32 = batches.iter().map(|batch| batch.skipped_results()).sum();
+    let returned: usize = batches.iter().map(|batch| batch.entities().len()).sum();
+    assert_eq!((skipped, returned), (150, 700));
+    let last = batches.last().map(|batch| batch.more_results());
+    assert_eq!(last, Some(datastore::MoreResults::AfterLimit));
+
+    //? Delete the items from the emulator, the results of the split commits being merged in order.
+    let results = assert_ok!(client.delete_all_with_meta((1..=1000).map(item)).await);
+    assert_eq!(results.mutation_results().len(), 1000);
+    assert_eq!(results.mutation_results()[999].key(), &item(1000));
+    assert!(results.index_updates() > 0);
+}
+
+#[tokio::test]
+async fn datastore_exports_kinds_from_a_single_snapshot() {
+    //? Runs against a local emulator, if one is available.
+    let host = match std::env::var("DATASTORE_EMULATOR_HOST") {
+        Ok(host) => host,
+        Err(_) => return,
+    };
+    let mut client = assert_ok!(datastore::Client::for_emulator("test-project", host).await);
+    let user = |idx: i64| datastore::Key::new("google-cloud-tests-export-users").id(idx);
+    let order = |idx: i64| datastore::Key::new("google-cloud-tests-export-orders").id(idx);
+    let entities: Vec<(datastore::Key, HashMap<String, i64>)> = (1..=3)
+        .map(|idx| (user(idx), idx))
+        .chain((1..=5).map(|idx| (order(idx), idx)))
+        .map(|(key, idx)| {
+            let mut properties = HashMap::new();
+            properties.insert(String::from("position"), idx);
+            (key, properties)
+        })
+        .collect();
+    assert_ok!(client.put_all(entities).await);
+
+    //? Let the default snapshot (a second back) include the entities.
+    tokio::time::sleep(std::time::Duration::from_secs(2)).await;
+
+    //? Export both kinds, one entity per line, in a file per kind.
+    let path = |kind: &str| std::env::temp_dir().join(format!("{}.ndjson", kind));
+    let export = client.snapshot_export([
+        "google-cloud-tests-export-users",
+        "google-cloud-tests-export-orders",
+    ]);
+    let results = assert_ok!(
+        export
+            .write_to(|kind| std::fs::File::create(path(kind)))
+            .await
+    );
+    assert_eq!(
+        results.counts(),
+        &[
+            (String::from("google-cloud-tests-export-users"), 3),
+            (String::from("google-cloud-tests-export-orders"), 5),
+        ]
+    );
+
+    //? Every line is an entity, as converted by `Entity::to_json`.
+    let orders = assert_ok!(std::fs::read_to_string(path(
+        "google-cloud-tests-export-orders"
+    )));
+    let keys: Vec<datastore::Key> = orders
+        .lines()
+        .map(|line| {
+            let value = assert_ok!(json::from_str(line));
+            assert_ok!(datastore::Entity::from_json(value))
+                .key()
+                .clone()
+        })
+        .collect();
+    assert_eq!(keys, (1..=5).map(order).collect::<Vec<_>>());
+
+    //? Delete the items from the emulator.
+    let keys = (1..=3).map(user).chain((1..=5).map(order));
+    assert_ok!(client.delete_all(keys).await);
+}
@@ -0,0 +1,80 @@
+use crate::deploy::api;
+use crate::deploy::{DeliveryPipeline, PhaseState, Release, ReleaseConfig, Rollout, RolloutState};
+
+#[test]
+fn deploy_reads_pipeline_stages() {
+    let pipeline = DeliveryPipeline::from(api::DeliveryPipeline {
+        name: "projects/p/locations/us-central1/deliveryPipelines/web".to_string(),
+        pipeline: Some(api::delivery_pipeline::Pipeline::SerialPipeline(
+            api::SerialPipeline {
+                stages: vec![
+                    api::Stage {
+                        target_id: "staging".to_string(),
+                        profiles: vec![],
+                    },
+                    api::Stage {
+                        target_id: "prod".to_string(),
+                        profiles: vec!["prod".to_string()],
+                    },
+                ],
+            },
+        )),
+        ..api::DeliveryPipeline::default()
+    });
+
+    assert_eq!(pipeline.id(), "web");
+    let targets: Vec<_> = pipeline.stages().iter().map(|s| s.target_id()).collect();
+    assert_eq!(targets, vec!["staging", "prod"]);
+    assert_eq!(pipeline.stages()[1].profiles(), ["prod"]);
+}
+
+#[test]
+fn deploy_locates_release_pipelines() {
+    let release = Release::from(api::Release {
+        name: "projects/p/locations/us-central1/deliveryPipelines/web/releases/r-1".to_string(),
+        render_state: api::release::RenderState::InProgress as i32,
+        ..api::Release::default()
+    });
+
+    assert_eq!(release.id(), "r-1");
+    assert_eq!(
+        release.pipeline_name(),
+        "projects/p/locations/us-central1/deliveryPipelines/web",
+    );
+}
+
+#[test]
+fn deploy_converts_release_configs() {
+    let release = api::Release::from(
+        ReleaseConfig::new("gs://bucket/source.tgz")
+            .skaffold_config_path("deploy/skaffold.yaml")
+            .build_artifact("app", "gcr.io/p/app:1.0.0")
+            .label("team", "web"),
+    );
+
+    assert_eq!(release.skaffold_config_uri, "gs://bucket/source.tgz");
+    assert_eq!(release.skaffold_config_path, "deploy/skaffold.yaml");
+    assert_eq!(release.build_artifacts.len(), 1);
+    assert_eq!(release.build_artifacts[0].tag, "gcr.io/p/app:1.0.0");
+    assert_eq!(release.labels["team"], "web");
+    assert!(release.description.is_empty());
+}
+
+#[test]
+fn deploy_maps_rollout_states() {
+    let rollout = Rollout::from(api::Rollout {
+        state: api::rollout::State::PendingApproval as i32,
+        phases: vec![api::Phase {
+            id: "canary-25".to_string(),
+            state: api::phase::State::Succeeded as i32,
+            skip_message: String::new(),
+        }],
+        ..api::Rollout::default()
+    });
+
+    assert_eq!(rollout.state(), RolloutState::PendingApproval);
+    assert!(!rollout.state().is_finished());
+    assert_eq!(rollout.phases()[0].state(), PhaseState::Succeeded);
+    assert!(RolloutState::ApprovalRejected.is_finished());
+    assert_eq!(RolloutState::from(42), RolloutState::Unknown);
+}
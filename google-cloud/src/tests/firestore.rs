@@ -0,0 +1,59 @@
+use crate::firestore::{Bundle, Value};
+
+fn bundle(elements: &[&str]) -> Vec<u8> {
+    elements
+        .iter()
+        .flat_map(|element| format!("{}{}", element.len(), element).into_bytes())
+        .collect()
+}
+
+#[test]
+fn firestore_loads_bundle_successfully() {
+    let data = bundle(&[
+        r#"{"metadata":{"id":"test","createTime":{"seconds":"1600000000","nanos":0},"version":1,"totalDocuments":2,"totalBytes":"1024"}}"#,
+        r#"{"namedQuery":{"name":"latest","bundledQuery":{"parent":"projects/p/databases/(default)/documents","structuredQuery":{"from":[{"collectionId":"posts"}]},"limitType":"FIRST"},"readTime":"2020-09-13T12:26:40Z"}}"#,
+        r#"{"documentMetadata":{"name":"projects/p/databases/(default)/documents/posts/a","readTime":"2020-09-13T12:26:40Z","exists":true,"queries":["latest"]}}"#,
+        r#"{"document":{"name":"projects/p/databases/(default)/documents/posts/a","fields":{"title":{"stringValue":"héllo 🦀"},"views":{"integerValue":"12"},"score":{"doubleValue":"NaN"},"draft":{"booleanValue":false},"blob":{"bytesValue":"AQID"},"at":{"timestampValue":"2020-09-13T12:26:40.5Z"},"place":{"geoPointValue":{"latitude":48.8,"longitude":2.3}},"tags":{"arrayValue":{"values":[{"stringValue":"a"},{"nullValue":null}]}},"meta":{"mapValue":{"fields":{"author":{"referenceValue":"projects/p/databases/(default)/documents/users/b"}}}},"empty":{"arrayValue":{}}},"createTime":"2020-09-13T12:26:40Z","updateTime":"2020-09-13T12:26:40Z"}}"#,
+        r#"{"documentMetadata":{"name":"projects/p/databases/(default)/documents/posts/deleted","exists":false,"queries":["latest"]}}"#,
+    ]);
+
+    let bundle = Bundle::from_slice(data.as_slice()).unwrap();
+    assert_eq!(bundle.metadata().id(), "test");
+    assert_eq!(bundle.metadata().total_bytes(), 1024);
+    assert_eq!(
+        bundle.named_query("latest").unwrap().limit_type(),
+        Some("FIRST")
+    );
+    assert_eq!(bundle.documents().len(), 2);
+    assert!(!bundle.documents()[1].exists());
+    assert_eq!(bundle.query_documents("latest").count(), 1);
+
+    let document = bundle
+        .document("projects/p/databases/(default)/documents/posts/a")
+        .unwrap();
+    assert_eq!(
+        document.field("title"),
+        Some(&Value::StringValue(String::from("héllo 🦀")))
+    );
+    assert_eq!(document.field("views"), Some(&Value::IntegerValue(12)));
+    assert_eq!(
+        document.field("blob"),
+        Some(&Value::BytesValue(vec![1, 2, 3]))
+    );
+    assert_eq!(
+        document.field("empty"),
+        Some(&Value::ArrayValue(Vec::new()))
+    );
+    assert!(matches!(document.field("score"), Some(Value::DoubleValue(score)) if score.is_nan()));
+}
+
+#[test]
+fn firestore_rejects_malformed_bundles() {
+    let document = r#"{"document":{"name":"projects/p/databases/(default)/documents/posts/a"}}"#;
+    let metadata = r#"{"metadata":{"id":"test"}}"#;
+
+    assert!(Bundle::from_slice(b"").is_err());
+    assert!(Bundle::from_slice(bundle(&[document]).as_slice()).is_err());
+    assert!(Bundle::from_slice(bundle(&[metadata, document]).as_slice()).is_err());
+    assert!(Bundle::from_slice(format!("99{}", metadata).as_bytes()).is_err());
+}
@@ -1,9 +1,51 @@
+mod authorize;
+#[cfg(feature = "bigtable")]
+mod bigtable;
+#[cfg(any(
+    feature = "bigtable",
+    feature = "cloudbuild",
+    feature = "datastore",
+    feature = "deploy",
+    feature = "pubsub"
+))]
+mod cancel;
+#[cfg(feature = "cdc")]
+mod cdc;
+#[cfg(any(
+    feature = "bigtable",
+    feature = "cloudbuild",
+    feature = "datastore",
+    feature = "deploy",
+    feature = "pubsub",
+    feature = "redis",
+    feature = "vision"
+))]
+mod channel;
+#[cfg(feature = "cloudbuild")]
+mod cloudbuild;
+#[cfg(feature = "config")]
+mod config;
+#[cfg(any(feature = "datastore", feature = "pubsub"))]
+mod conversions;
 #[cfg(feature = "datastore")]
 mod datastore;
+#[cfg(feature = "deploy")]
+mod deploy;
+#[cfg(feature = "firestore")]
+mod firestore;
+#[cfg(feature = "outbox")]
+mod outbox;
 #[cfg(feature = "pubsub")]
 mod pubsub;
+#[cfg(feature = "redis")]
+mod redis;
 #[cfg(feature = "storage")]
 mod storage;
+#[cfg(feature = "pubsub")]
+mod tasks;
+#[cfg(feature = "testing")]
+mod testing;
+mod types;
 #[cfg(feature = "vision")]
 mod vision;
 
@@ -0,0 +1,42 @@
+use crate::datastore::{Key, Value};
+use crate::outbox::OutboxMessage;
+use crate::pubsub::PublishMessage;
+
+#[test]
+fn outbox_messages_survive_round_trip() {
+    let created = chrono::DateTime::from_timestamp(1_700_000_000, 0)
+        .unwrap()
+        .naive_utc();
+    let message = OutboxMessage {
+        key: Some(Key::new("_outbox").id(12)),
+        topic: String::from("order-events"),
+        message: PublishMessage::new("order 42 paid")
+            .attribute("type", "paid")
+            .ordering_key("order-42"),
+        created,
+        sent: false,
+    };
+
+    let entity = message.clone().into_entity("_outbox").unwrap();
+    let properties = match entity.properties() {
+        Value::EntityValue(properties) => properties,
+        properties => panic!("unexpected properties: {:?}", properties),
+    };
+    //? The payload is not indexed, as it could exceed the size limit of indexed values.
+    assert!(matches!(properties["data"], Value::IndexedValue(_, false)));
+    assert_eq!(properties["sent"], Value::BooleanValue(false));
+
+    let decoded = OutboxMessage::from_entity(entity).unwrap();
+    assert_eq!(decoded, message);
+    assert_eq!(decoded.topic(), "order-events");
+    assert!(!decoded.is_sent());
+
+    //? Enqueued messages get their key once stored.
+    let pending = OutboxMessage {
+        key: None,
+        ..message
+    };
+    let entity = pending.into_entity("events").unwrap();
+    assert_eq!(entity.key().get_kind(), "events");
+    assert!(entity.key().is_incomplete());
+}
@@ -1,8 +1,13 @@
 use std::io::{self, Write};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
 use serde::{Deserialize, Serialize};
 
-use crate::pubsub;
+use crate::pubsub::{
+    self, batch_messages, run_failure_hooks, run_hooks, run_publish_hooks, AckFailure,
+    AckFailureHook, AckHook, AckedMessage, Distribution, LeaseState, OutgoingMessage, PublishHook,
+};
 
 macro_rules! assert_ok {
     ($expr:expr) => {
@@ -40,6 +45,18 @@ async fn pubsub_lists_topics() {
     assert_ok!(client.topics().await);
 }
 
+#[tokio::test]
+async fn pubsub_operates_on_other_projects() {
+    //? Setup a test client, for a placeholder project.
+    let creds = super::load_creds();
+    let client = assert_ok!(pubsub::Client::from_credentials("placeholder-project", creds).await);
+
+    //? The derived client lists the topics of the test project instead.
+    let mut client = client.for_project(env!("GCP_TEST_PROJECT"));
+    assert_eq!(client.project_name(), env!("GCP_TEST_PROJECT"));
+    assert_ok!(client.topics().await);
+}
+
 #[tokio::test]
 async fn pubsub_sends_and_receives_message_successfully() {
     //? Setup test client.
@@ -88,7 +105,7 @@ async fn pubsub_sends_and_receives_message_successfully() {
     //? Publish that message onto the topic.
     print!("sending message... ");
     io::stdout().flush().unwrap();
-    assert_ok!(topic.publish(message, None).await);
+    assert_ok!(topic.publish(message).await);
     println!("OK !");
 
     //? Receive it back from the subscription.
@@ -115,7 +132,7 @@ async fn pubsub_sends_and_receives_message_successfully() {
             max_messages: 1,
         })
         .await;
-    assert_eq!(received.is_none(), true);
+    assert!(received.is_none());
     println!("OK !");
 
     //? Delete the subscription.
@@ -130,3 +147,787 @@ async fn pubsub_sends_and_receives_message_successfully() {
     assert_ok!(topic.delete().await);
     println!("OK !");
 }
+
+#[test]
+fn pubsub_presets_trade_latency_for_throughput() {
+    let throughput = pubsub::Preset::high_throughput();
+    let latency = pubsub::Preset::low_latency();
+
+    //? Batches must fit within a single request.
+    assert!(throughput.publish_batch_size() <= 1000);
+    assert!(throughput.receive_options().max_messages <= 1000);
+
+    assert!(throughput.publish_batch_size() > latency.publish_batch_size());
+    assert!(throughput.receive_options().max_messages > latency.receive_options().max_messages);
+    assert_eq!(latency.publish_batch_size(), 1);
+    assert_ne!(throughput.channel_config(), latency.channel_config());
+    assert_ne!(
+        throughput.subscription_config(),
+        latency.subscription_config()
+    );
+}
+
+#[test]
+fn pubsub_lease_distribution_tracks_percentiles() {
+    let mut distribution = Distribution::new(600);
+    assert_eq!(distribution.percentile(0.99), None);
+
+    //? Processing times are rounded up to the second.
+    distribution.record(Duration::from_millis(500));
+    assert_eq!(distribution.percentile(0.99), Some(1));
+
+    for _ in 0..97 {
+        distribution.record(Duration::from_secs(1));
+    }
+    distribution.record(Duration::from_secs(30));
+    distribution.record(Duration::from_secs(3600));
+    assert_eq!(distribution.percentile(0.5), Some(1));
+    assert_eq!(distribution.percentile(0.99), Some(30));
+    //? Processing times past the maximum are clipped.
+    assert_eq!(distribution.percentile(1.0), Some(600));
+}
+
+#[test]
+fn pubsub_lease_deadline_adapts_to_processing_times() {
+    let now = tokio::time::Instant::now();
+    let mut state = LeaseState::new(pubsub::LeaseConfig::default());
+
+    //? The minimum deadline is used until a processing time is observed.
+    assert_eq!(state.deadline(), Duration::from_secs(10));
+
+    //? Slow handlers get longer deadlines.
+    let ack_ids = vec![String::from("a"), String::from("b")];
+    state.register(&ack_ids, now);
+    state.complete("a", true, now + Duration::from_secs(45));
+    assert_eq!(state.deadline(), Duration::from_secs(45));
+
+    //? Only acknowledged messages count.
+    state.complete("b", false, now + Duration::from_secs(300));
+    assert_eq!(state.deadline(), Duration::from_secs(45));
+
+    //? The deadline stays within bounds.
+    let config = pubsub::LeaseConfig::default().max_deadline(chrono::Duration::seconds(20));
+    let mut state = LeaseState::new(config);
+    state.register(&ack_ids, now);
+    state.complete("a", true, now + Duration::from_secs(45));
+    assert_eq!(state.deadline(), Duration::from_secs(20));
+}
+
+#[test]
+fn pubsub_lease_extensions_are_scheduled_per_message() {
+    let now = tokio::time::Instant::now();
+    let config = pubsub::LeaseConfig::default().max_extension(chrono::Duration::seconds(30));
+    let mut state = LeaseState::new(config);
+    let deadline = state.register(&[String::from("a")], now);
+    state.register(&[String::from("b")], now + Duration::from_secs(3));
+    assert_eq!(deadline, Duration::from_secs(10));
+
+    //? Leases are extended shortly before they expire.
+    let (due, _) = state.take_due(now);
+    assert!(due.is_empty());
+    assert_eq!(state.next_due(), Some(now + Duration::from_secs(5)));
+    let (due, deadline) = state.take_due(now + Duration::from_secs(6));
+    assert_eq!(due, vec![String::from("a")]);
+    assert_eq!(deadline, Duration::from_secs(10));
+    assert_eq!(state.next_due(), Some(now + Duration::from_secs(8)));
+
+    //? Acknowledged messages are not extended anymore.
+    state.complete("b", true, now + Duration::from_secs(7));
+    assert_eq!(state.next_due(), Some(now + Duration::from_secs(11)));
+
+    //? Messages held past the maximum extension are given up on.
+    let (due, _) = state.take_due(now + Duration::from_secs(31));
+    assert!(due.is_empty());
+    assert_eq!(state.next_due(), None);
+}
+
+#[test]
+fn pubsub_checkpoint_hooks_report_acked_messages() {
+    let checkpoints = Arc::new(Mutex::new(Vec::new()));
+    let hooks: Vec<AckHook> = (0..2)
+        .map(|idx| {
+            let checkpoints = checkpoints.clone();
+            let hook: AckHook = Arc::new(move |acked: &[AckedMessage]| {
+                let ids: Vec<String> = acked.iter().map(|msg| msg.id().to_string()).collect();
+                checkpoints.lock().unwrap().push((idx, ids));
+            });
+            hook
+        })
+        .collect();
+    let publish_time = chrono::DateTime::from_timestamp(1_600_000_000, 0)
+        .unwrap()
+        .naive_utc();
+    let acked: Vec<AckedMessage> = ["1", "2"]
+        .iter()
+        .map(|id| AckedMessage {
+            id: id.to_string(),
+            publish_time,
+        })
+        .collect();
+
+    //? Every hook sees the whole flush, in registration order.
+    run_hooks(&hooks, &acked);
+    let ids = vec![String::from("1"), String::from("2")];
+    let expected = vec![(0, ids.clone()), (1, ids)];
+    assert_eq!(*checkpoints.lock().unwrap(), expected);
+    assert_eq!(acked[0].publish_time(), publish_time);
+
+    //? Empty flushes are not reported.
+    run_hooks(&hooks, &[]);
+    assert_eq!(checkpoints.lock().unwrap().len(), 2);
+}
+
+#[test]
+fn pubsub_ack_failure_hooks_report_persistent_failures() {
+    let failures = Arc::new(Mutex::new(Vec::new()));
+    let hook: AckFailureHook = {
+        let failures = failures.clone();
+        Arc::new(move |failure: &AckFailure| {
+            let ids: Vec<String> = failure
+                .messages()
+                .iter()
+                .map(|msg| msg.id().to_string())
+                .collect();
+            failures
+                .lock()
+                .unwrap()
+                .push((failure.status().code(), ids));
+        })
+    };
+    let hooks = vec![hook];
+    let publish_time = chrono::DateTime::from_timestamp(1_600_000_000, 0)
+        .unwrap()
+        .naive_utc();
+    let messages = || {
+        vec![AckedMessage {
+            id: String::from("1"),
+            publish_time,
+        }]
+    };
+
+    //? Invalid ack IDs (such as after a seek) will never be accepted.
+    let err = pubsub::Error::from(tonic::Status::invalid_argument("invalid ack ID"));
+    assert!(run_failure_hooks(&hooks, messages(), &err));
+    let expected = vec![(tonic::Code::InvalidArgument, vec![String::from("1")])];
+    assert_eq!(*failures.lock().unwrap(), expected);
+
+    //? Transient failures are left for the caller to retry, as are errors that did not come from the service.
+    let err = pubsub::Error::from(tonic::Status::unavailable("try again"));
+    assert!(!run_failure_hooks(&hooks, messages(), &err));
+    let err = pubsub::Error::from(std::io::Error::from(std::io::ErrorKind::Other));
+    assert!(!run_failure_hooks(&hooks, messages(), &err));
+    assert_eq!(failures.lock().unwrap().len(), 1);
+}
+
+#[test]
+fn pubsub_setup_loads_from_json() {
+    let data = br#"{
+        "topics": [
+            {
+                "id": "events",
+                "labels": { "team": "billing" },
+                "subscriptions": [
+                    { "id": "events-worker", "ack_deadline_seconds": 30, "retention_seconds": 600 },
+                    { "id": "events-audit" }
+                ]
+            },
+            { "id": "dead-letters" }
+        ]
+    }"#;
+    let setup = assert_ok!(pubsub::Setup::from_json(data));
+
+    let expected = pubsub::Setup::default()
+        .topic(
+            "events",
+            pubsub::TopicConfig::default().label("team", "billing"),
+        )
+        .subscription(
+            "events",
+            "events-worker",
+            pubsub::SubscriptionConfig::default()
+                .ack_deadline(chrono::Duration::seconds(30))
+                .retain_messages(chrono::Duration::minutes(10)),
+        )
+        .subscription(
+            "events",
+            "events-audit",
+            pubsub::SubscriptionConfig::default(),
+        )
+        .topic("dead-letters", pubsub::TopicConfig::default());
+    assert_eq!(setup, expected);
+
+    //? The IDs are required.
+    assert!(pubsub::Setup::from_json(br#"{ "topics": [{ "labels": {} }] }"#).is_err());
+}
+
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn pubsub_setup_is_idempotent() {
+    //? Setup test client and namespace.
+    let mut client = assert_ok!(setup_client().await);
+    let namespace =
+        crate::testing::Namespace::from_credentials(env!("GCP_TEST_PROJECT"), super::load_creds());
+    let topic_id = namespace.topic("setup");
+    let subscription_id = namespace.subscription("setup-sub");
+    let setup = pubsub::Setup::default()
+        .topic(topic_id.as_str(), pubsub::TopicConfig::default())
+        .subscription(
+            topic_id.as_str(),
+            subscription_id.as_str(),
+            pubsub::SubscriptionConfig::default(),
+        );
+
+    //? The first run creates the resources.
+    let report = assert_ok!(client.setup(&setup).await);
+    assert_eq!(report.created_topics(), [topic_id.as_str()]);
+    assert_eq!(report.created_subscriptions(), [subscription_id.as_str()]);
+
+    //? The second one finds them already existing.
+    let report = assert_ok!(client.setup(&setup).await);
+    assert_eq!(report.existing_topics(), [topic_id.as_str()]);
+    assert_eq!(report.existing_subscriptions(), [subscription_id.as_str()]);
+
+    assert_ok!(namespace.cleanup().await);
+}
+
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn pubsub_teardown_deletes_selected_topics() {
+    //? Setup test client and namespace.
+    let mut client = assert_ok!(setup_client().await);
+    let namespace =
+        crate::testing::Namespace::from_credentials(env!("GCP_TEST_PROJECT"), super::load_creds());
+    let selected_id = namespace.topic("teardown");
+    let kept_id = namespace.topic("teardown-kept");
+    let subscription_ids = [
+        namespace.subscription("teardown-sub-1"),
+        namespace.subscription("teardown-sub-2"),
+    ];
+    let setup = pubsub::Setup::default()
+        .topic(
+            selected_id.as_str(),
+            pubsub::TopicConfig::default()
+                .label("env", "ci")
+                .label("run", namespace.run_id()),
+        )
+        .topic(
+            kept_id.as_str(),
+            pubsub::TopicConfig::default().label("run", namespace.run_id()),
+        )
+        .subscription(
+            selected_id.as_str(),
+            subscription_ids[0].as_str(),
+            pubsub::SubscriptionConfig::default(),
+        )
+        .subscription(
+            selected_id.as_str(),
+            subscription_ids[1].as_str(),
+            pubsub::SubscriptionConfig::default(),
+        );
+    assert_ok!(client.setup(&setup).await);
+
+    let mut topic = assert_some!(assert_ok!(client.topic(selected_id.as_str()).await));
+    assert_eq!(assert_ok!(topic.subscriptions().await).len(), 2);
+
+    //? Only the topic matching all the labels gets deleted, along with its subscriptions.
+    let selector = pubsub::LabelSelector::new()
+        .equals("run", namespace.run_id())
+        .exists("env");
+    let report = assert_ok!(client.teardown(&selector).await);
+    assert_eq!(report.deleted_topics(), [selected_id.as_str()]);
+    let mut deleted_subscriptions = report.deleted_subscriptions().to_vec();
+    deleted_subscriptions.sort();
+    assert_eq!(deleted_subscriptions, subscription_ids);
+    assert!(client.topic(kept_id.as_str()).await.is_ok());
+
+    assert_ok!(namespace.cleanup().await);
+}
+
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn pubsub_stale_messages_are_dead_lettered() {
+    //? Setup test client and namespace.
+    let mut client = assert_ok!(setup_client().await);
+    let namespace =
+        crate::testing::Namespace::from_credentials(env!("GCP_TEST_PROJECT"), super::load_creds());
+    let topic_id = namespace.topic("stale");
+    let stale_id = namespace.topic("stale-dead-letter");
+    let subscription_id = namespace.subscription("stale-sub");
+    let stale_subscription_id = namespace.subscription("stale-dead-letter-sub");
+    let setup = pubsub::Setup::default()
+        .topic(topic_id.as_str(), pubsub::TopicConfig::default())
+        .topic(stale_id.as_str(), pubsub::TopicConfig::default())
+        .subscription(
+            topic_id.as_str(),
+            subscription_id.as_str(),
+            pubsub::SubscriptionConfig::default(),
+        )
+        .subscription(
+            stale_id.as_str(),
+            stale_subscription_id.as_str(),
+            pubsub::SubscriptionConfig::default(),
+        );
+    assert_ok!(client.setup(&setup).await);
+
+    let mut topic = assert_some!(assert_ok!(client.topic(topic_id.as_str()).await));
+    let message = pubsub::PublishMessage::new("outdated").attribute("kind", "notification");
+    assert_ok!(topic.publish(message).await);
+
+    //? Every message is older than a zero age, so none gets handed out.
+    let action = pubsub::StaleAction::DeadLetter {
+        topic: stale_id.clone(),
+        attribute: String::from("age"),
+    };
+    let subscription = client.subscription(subscription_id.as_str()).await;
+    let mut subscription =
+        assert_some!(assert_ok!(subscription)).skip_stale(chrono::Duration::zero(), action);
+    let received = tokio::time::timeout(Duration::from_secs(10), subscription.receive()).await;
+    assert!(received.is_err());
+
+    //? The message got republished along with its age instead.
+    let stale_subscription = client.subscription(stale_subscription_id.as_str()).await;
+    let mut stale_subscription = assert_some!(assert_ok!(stale_subscription));
+    let received = tokio::time::timeout(Duration::from_secs(30), stale_subscription.receive());
+    let mut message = assert_some!(assert_ok!(received.await));
+    assert_eq!(message.data(), b"outdated");
+    assert_eq!(message.attributes()["kind"], "notification");
+    assert!(message.attributes()["age"].parse::<i64>().is_ok());
+    assert_ok!(message.ack().await);
+
+    assert_ok!(namespace.cleanup().await);
+}
+
+#[cfg(feature = "testing")]
+#[tokio::test]
+async fn pubsub_subscriptions_are_purged_and_detached() {
+    //? Setup test client and namespace.
+    let mut client = assert_ok!(setup_client().await);
+    let namespace =
+        crate::testing::Namespace::from_credentials(env!("GCP_TEST_PROJECT"), super::load_creds());
+    let topic_id = namespace.topic("maintenance");
+    let subscription_id = namespace.subscription("maintenance-sub");
+    let setup = pubsub::Setup::default()
+        .topic(topic_id.as_str(), pubsub::TopicConfig::default())
+        .subscription(
+            topic_id.as_str(),
+            subscription_id.as_str(),
+            pubsub::SubscriptionConfig::default(),
+        );
+    assert_ok!(client.setup(&setup).await);
+
+    let mut topic = assert_some!(assert_ok!(client.topic(topic_id.as_str()).await));
+    let subscription = client.subscription(subscription_id.as_str()).await;
+    let mut subscription = assert_some!(assert_ok!(subscription));
+
+    //? Messages published before the purge are never delivered, unlike the ones published after it.
+    assert_ok!(topic.publish("before").await);
+    assert_ok!(subscription.purge().await);
+    assert_ok!(topic.publish("after").await);
+    let received = tokio::time::timeout(Duration::from_secs(30), subscription.receive());
+    let mut message = assert_some!(assert_ok!(received.await));
+    assert_eq!(message.data(), b"after");
+    assert_ok!(message.ack().await);
+
+    //? Detached subscriptions are kept, but no longer attached to the topic.
+    assert_ok!(topic.detach_subscription(subscription_id.as_str()).await);
+    assert!(assert_ok!(topic.subscriptions().await).is_empty());
+    assert!(assert_ok!(client.subscription(subscription_id.as_str()).await).is_some());
+
+    assert_ok!(namespace.cleanup().await);
+}
+
+#[test]
+fn pubsub_codecs_round_trip_values() {
+    use pubsub::{Codec, Json, Protobuf, SchemaEncoding};
+
+    #[derive(Clone, PartialEq, Serialize, Deserialize, prost::Message)]
+    struct Order {
+        #[prost(uint64, tag = "1")]
+        id: u64,
+        #[prost(string, tag = "2")]
+        item: String,
+    }
+
+    let order = Order {
+        id: 42,
+        item: String::from("book"),
+    };
+
+    let data = assert_ok!(<Json as Codec<Order>>::encode(&order));
+    assert_eq!(data, br#"{"id":42,"item":"book"}"#);
+    assert_eq!(assert_ok!(<Json as Codec<Order>>::decode(&data)), order);
+
+    let data = assert_ok!(<Protobuf as Codec<Order>>::encode(&order));
+    assert_eq!(data, b"\x08\x2a\x12\x04book");
+    assert_eq!(assert_ok!(<Protobuf as Codec<Order>>::decode(&data)), order);
+
+    assert_eq!(<Json as Codec<Order>>::ENCODING, SchemaEncoding::Json);
+    assert_eq!(<Protobuf as Codec<Order>>::ENCODING, SchemaEncoding::Binary);
+    assert!(<Json as Codec<Order>>::decode(b"\x08\x2a").is_err());
+}
+
+#[cfg(feature = "avro")]
+#[test]
+fn pubsub_avro_codec_encodes_single_datums() {
+    use pubsub::{Avro, Codec};
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Order {
+        id: i64,
+        item: String,
+    }
+
+    impl apache_avro::AvroSchema for Order {
+        fn get_schema() -> apache_avro::Schema {
+            let schema = r#"{
+                "type": "record",
+                "name": "Order",
+                "fields": [
+                    { "name": "id", "type": "long" },
+                    { "name": "item", "type": "string" }
+                ]
+            }"#;
+            apache_avro::Schema::parse_str(schema).unwrap()
+        }
+    }
+
+    let order = Order {
+        id: 42,
+        item: String::from("book"),
+    };
+
+    //? The datum is written without any container header, as topics with an Avro schema expect.
+    let data = assert_ok!(<Avro as Codec<Order>>::encode(&order));
+    assert_eq!(data, b"\x54\x08book");
+    assert_eq!(assert_ok!(<Avro as Codec<Order>>::decode(&data)), order);
+}
+
+#[test]
+fn pubsub_schema_encodings_are_parsed_from_attributes() {
+    use pubsub::SchemaEncoding;
+
+    assert_eq!(
+        SchemaEncoding::from_attribute("JSON"),
+        Some(SchemaEncoding::Json)
+    );
+    assert_eq!(
+        SchemaEncoding::from_attribute("BINARY"),
+        Some(SchemaEncoding::Binary)
+    );
+    assert_eq!(SchemaEncoding::from_attribute("XML"), None);
+    assert_eq!(SchemaEncoding::Json.to_string(), "JSON");
+}
+
+#[test]
+fn pubsub_publish_batches_fit_request_limits() {
+    //? Small messages are batched by the thousand.
+    let messages: Vec<pubsub::PublishMessage> = (0..2500)
+        .map(|idx| pubsub::PublishMessage::new(idx.to_string()))
+        .collect();
+    let batches = assert_ok!(batch_messages(messages.iter()));
+    let sizes: Vec<usize> = batches.iter().map(Vec::len).collect();
+    assert_eq!(sizes, vec![1000, 1000, 500]);
+
+    //? Large messages are batched by size.
+    let messages: Vec<pubsub::PublishMessage> = (0..3)
+        .map(|_| pubsub::PublishMessage::new(vec![0u8; 4_000_000]))
+        .collect();
+    let batches = assert_ok!(batch_messages(messages.iter()));
+    let sizes: Vec<usize> = batches.iter().map(Vec::len).collect();
+    assert_eq!(sizes, vec![2, 1]);
+
+    //? Invalid messages are rejected before anything is sent.
+    let empty = [
+        pubsub::PublishMessage::new("ok"),
+        pubsub::PublishMessage::default(),
+    ];
+    assert!(batch_messages(empty.iter()).is_err());
+    let oversized = [pubsub::PublishMessage::new(vec![0u8; 10_000_001])];
+    assert!(batch_messages(oversized.iter()).is_err());
+    let attributes_only = [pubsub::PublishMessage::default().attribute("kind", "ping")];
+    assert!(batch_messages(attributes_only.iter()).is_ok());
+}
+
+#[test]
+fn pubsub_publish_hooks_see_every_message() {
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let hook: PublishHook = {
+        let seen = seen.clone();
+        Arc::new(move |message: &OutgoingMessage<'_>| {
+            let data = String::from_utf8(message.data().to_vec()).unwrap();
+            let user = message.attributes().get("user").cloned();
+            seen.lock().unwrap().push((
+                message.topic().to_string(),
+                data,
+                user,
+                message.ordering_key().to_string(),
+            ));
+        })
+    };
+    let messages = vec![
+        pubsub::PublishMessage::new("a").attribute("user", "alice"),
+        pubsub::PublishMessage::new("b").ordering_key("orders"),
+    ];
+
+    let redactor = pubsub::Redactor::new();
+    run_publish_hooks(&[hook], &redactor, "projects/p/topics/t", &messages);
+    let topic = String::from("projects/p/topics/t");
+    let expected = vec![
+        (
+            topic.clone(),
+            String::from("a"),
+            Some(String::from("alice")),
+            String::new(),
+        ),
+        (topic, String::from("b"), None, String::from("orders")),
+    ];
+    assert_eq!(*seen.lock().unwrap(), expected);
+}
+
+#[test]
+fn pubsub_redactors_hide_message_contents() {
+    let message = pubsub::PublishMessage::new("card 4242 4242 4242 4242")
+        .attribute("user-email", "jane@example.com")
+        .attribute("user-id", "42")
+        .attribute("kind", "payment");
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let hook: PublishHook = {
+        let seen = seen.clone();
+        Arc::new(move |message: &OutgoingMessage<'_>| {
+            seen.lock().unwrap().push(format!("{:?}", message));
+        })
+    };
+
+    //? By default, only the size of the data is shown.
+    let redactor = pubsub::Redactor::new();
+    run_publish_hooks(
+        std::slice::from_ref(&hook),
+        &redactor,
+        "t",
+        std::slice::from_ref(&message),
+    );
+    let redacted = redactor.redact(message.data.as_ref(), &message.attributes);
+    assert_eq!(redacted.data(), "<24 bytes>");
+    assert_eq!(redacted.attributes().len(), 3);
+
+    //? Hashes allow to correlate messages, and patterns drop attributes.
+    let redactor = pubsub::Redactor::new()
+        .hash_data(true)
+        .drop_attributes("user-*");
+    run_publish_hooks(&[hook], &redactor, "t", std::slice::from_ref(&message));
+    let redacted = redactor.redact(message.data.as_ref(), &message.attributes);
+    let other = redactor.redact(b"card 4242 4242 4242 4242", &message.attributes);
+    assert!(redacted.data().starts_with("sha256:"));
+    assert_eq!(redacted.data(), other.data());
+    let attributes: Vec<_> = redacted.attributes().into_iter().collect();
+    assert_eq!(attributes, vec![("kind", "payment")]);
+
+    for output in seen.lock().unwrap().iter() {
+        assert!(
+            !output.contains("card 4242"),
+            "unredacted output: {}",
+            output
+        );
+    }
+    assert!(!seen.lock().unwrap()[1].contains("jane@example.com"));
+
+    assert!(pubsub::Redactor::new()
+        .drop_attributes("*")
+        .drops_attribute("kind"));
+    assert!(!pubsub::Redactor::new()
+        .drop_attributes("user")
+        .drops_attribute("user-id"));
+    assert!(pubsub::Redactor::new()
+        .drop_attributes("*-id")
+        .drops_attribute("user-id"));
+}
+
+#[test]
+fn pubsub_subscription_configs_are_read_back() {
+    use crate::pubsub::api;
+
+    let config = pubsub::SubscriptionConfig::default()
+        .ack_deadline(chrono::Duration::seconds(30))
+        .retain_messages(chrono::Duration::hours(12))
+        .label("team", "billing")
+        .message_ordering(true)
+        .push(
+            pubsub::PushConfig::new("https://example.com/push")
+                .attribute("x-goog-version", "v1")
+                .oidc_token("pusher@project.iam.gserviceaccount.com", ""),
+        )
+        .dead_letter(
+            pubsub::DeadLetterPolicy::new("projects/p/topics/dead-letters")
+                .max_delivery_attempts(10),
+        )
+        .retry(pubsub::RetryPolicy::default().minimum_backoff(chrono::Duration::seconds(5)))
+        .filter(r#"attributes.region = "eu""#)
+        .expiration(pubsub::Expiration::Never);
+    let subscription = config.clone().into_api(
+        String::from("projects/p/subscriptions/s"),
+        String::from("projects/p/topics/t"),
+    );
+    assert_eq!(pubsub::SubscriptionConfig::from(subscription), config);
+
+    //? Pub/Sub reports defaults, which map back to an unconfigured subscription.
+    let subscription = api::Subscription {
+        ack_deadline_seconds: 10,
+        message_retention_duration: Some(prost_types::Duration {
+            seconds: 7 * 24 * 3600,
+            nanos: 0,
+        }),
+        push_config: Some(api::PushConfig::default()),
+        ..api::Subscription::default()
+    };
+    assert_eq!(
+        pubsub::SubscriptionConfig::from(subscription),
+        pubsub::SubscriptionConfig::default()
+    );
+}
+
+#[test]
+fn pubsub_topic_configs_are_read_back() {
+    use crate::pubsub::api;
+
+    let config = pubsub::TopicConfig::default()
+        .label("team", "billing")
+        .allowed_persistence_region("europe-west1")
+        .allowed_persistence_region("europe-west4")
+        .kms_key_name("projects/p/locations/europe/keyRings/r/cryptoKeys/k");
+    let topic = config.clone().into_api(String::from("projects/p/topics/t"));
+    assert_eq!(
+        topic
+            .message_storage_policy
+            .as_ref()
+            .map(|policy| policy.allowed_persistence_regions.len()),
+        Some(2)
+    );
+    assert_eq!(pubsub::TopicConfig::from(topic), config);
+
+    //? Without any allowed region, no storage policy is sent (an empty one would be rejected).
+    let topic = pubsub::TopicConfig::default().into_api(String::from("projects/p/topics/t"));
+    assert_eq!(topic.message_storage_policy, None);
+    assert_eq!(topic.kms_key_name, "");
+    assert_eq!(
+        pubsub::TopicConfig::from(api::Topic::default()),
+        pubsub::TopicConfig::default()
+    );
+}
+
+#[cfg(feature = "pubsub-metrics")]
+#[test]
+fn pubsub_subscriber_metrics_are_encoded() {
+    let metrics = pubsub::SubscriberMetrics::new();
+    let name = "projects/p/subscriptions/orders";
+
+    let acked = metrics.received(name, 0);
+    let rejected = metrics.received(name, 2);
+    let pending = metrics.received(name, 0);
+    let dropped = metrics.received(name, 0);
+    metrics.pull_restarted(name);
+    acked.acked();
+    //? Only the first settlement of a message counts.
+    acked.acked();
+    rejected.settled();
+    drop(dropped);
+
+    let encoded = metrics.encode();
+    let lines: Vec<&str> = encoded.lines().collect();
+    for line in [
+        r#"pubsub_subscriber_ack_latency_seconds_bucket{subscription="orders",le="+Inf"} 1"#,
+        r#"pubsub_subscriber_ack_latency_seconds_count{subscription="orders"} 1"#,
+        r#"pubsub_subscriber_received_messages_total{subscription="orders"} 4"#,
+        r#"pubsub_subscriber_redelivered_messages_total{subscription="orders"} 1"#,
+        r#"pubsub_subscriber_outstanding_messages{subscription="orders"} 1"#,
+        r#"pubsub_subscriber_pull_restarts_total{subscription="orders"} 1"#,
+    ]
+    .iter()
+    {
+        assert!(lines.contains(line), "missing {:?} in:\n{}", line, encoded);
+    }
+    assert!(lines.contains(
+        &r#"pubsub_subscriber_ack_latency_seconds_bucket{subscription="orders",le="300.0"} 1"#
+    ));
+    assert_eq!(lines.last(), Some(&"# EOF"));
+
+    //? Clones share the same metrics, and settle the message once all of them are dropped.
+    let clone = pending.clone();
+    drop(pending);
+    assert!(metrics
+        .encode()
+        .contains(r#"pubsub_subscriber_outstanding_messages{subscription="orders"} 1"#));
+    drop(clone);
+    assert!(metrics
+        .encode()
+        .contains(r#"pubsub_subscriber_outstanding_messages{subscription="orders"} 0"#));
+}
+
+#[test]
+fn pubsub_publish_rate_limits_delay_requests() {
+    use std::time::Instant;
+
+    let limiter = pubsub::RateLimiter::new()
+        .messages_per_second(100)
+        .bytes_per_second(1000);
+    let now = Instant::now();
+
+    //? A second worth of messages goes through at once, the following ones wait for the bucket to refill.
+    assert_eq!(limiter.reserve(100, 10, now), Duration::from_secs(0));
+    assert_eq!(limiter.reserve(50, 10, now), Duration::from_millis(500));
+    assert_eq!(
+        limiter.reserve(50, 10, now + Duration::from_millis(500)),
+        Duration::from_millis(500)
+    );
+
+    //? The largest delay applies, and large requests put the buckets into debt.
+    let limiter = pubsub::RateLimiter::new()
+        .messages_per_second(100)
+        .bytes_per_second(1000);
+    assert_eq!(limiter.reserve(1, 3000, now), Duration::from_secs(2));
+    assert_eq!(limiter.reserve(1, 500, now), Duration::from_millis(2500));
+    assert_eq!(limiter.throttled_requests(), 2);
+    assert_eq!(limiter.throttle_time(), Duration::from_millis(4500));
+
+    let unlimited = pubsub::RateLimiter::new();
+    assert_eq!(
+        unlimited.reserve(10_000, 1 << 30, now),
+        Duration::from_secs(0)
+    );
+    assert_eq!(unlimited.throttled_requests(), 0);
+}
+
+#[tokio::test]
+async fn pubsub_concurrency_groups_are_isolated() {
+    use futures::FutureExt;
+
+    let orders = pubsub::ConcurrencyGroup::new()
+        .max_outstanding_messages(2)
+        .max_outstanding_bytes(100);
+    let audits = pubsub::ConcurrencyGroup::new().max_outstanding_messages(1);
+
+    //? Exhausting the budget of a group blocks it, but not the other ones.
+    let first = orders.acquire(10).await;
+    let second = orders.acquire(10).await;
+    assert_eq!(orders.outstanding_messages(), Some(2));
+    assert_eq!(orders.outstanding_bytes(), Some(20));
+    assert!(orders.acquire(10).now_or_never().is_none());
+    let audit = assert_some!(audits.acquire(1 << 20).now_or_never());
+    assert_eq!(audits.outstanding_bytes(), None);
+
+    //? Releasing a permit (or dropping all of its clones) gives its share back.
+    first.release();
+    drop(second.clone());
+    assert_eq!(orders.outstanding_messages(), Some(1));
+    drop(second);
+    assert_eq!(orders.outstanding_messages(), Some(0));
+    drop(audit);
+    assert_eq!(audits.outstanding_messages(), Some(0));
+
+    //? A message larger than the byte budget waits for all of it.
+    let small = orders.acquire(10).await;
+    assert!(orders.acquire(1000).now_or_never().is_none());
+    small.release();
+    let large = assert_some!(orders.acquire(1000).now_or_never());
+    assert_eq!(orders.outstanding_bytes(), Some(100));
+    large.release();
+}
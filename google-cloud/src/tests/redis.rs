@@ -0,0 +1,36 @@
+use chrono::{NaiveTime, Weekday};
+
+use crate::redis::api;
+use crate::redis::{Instance, InstanceUpdate, MaintenanceWindow};
+
+#[test]
+fn redis_maintenance_windows_survive_round_trip() {
+    let window = MaintenanceWindow::new(Weekday::Sun, NaiveTime::from_hms_opt(3, 30, 0).unwrap());
+    let converted = api::WeeklyMaintenanceWindow::from(window);
+    assert_eq!(MaintenanceWindow::from_api(converted), Some(window));
+
+    let unspecified = api::WeeklyMaintenanceWindow::default();
+    assert_eq!(MaintenanceWindow::from_api(unspecified), None);
+}
+
+#[test]
+fn redis_updates_only_set_fields() {
+    let name = "projects/p/locations/europe-west1/instances/cache".to_string();
+    let (instance, paths) = InstanceUpdate::default()
+        .memory_size_gb(4)
+        .maintenance_window(None)
+        .into_api(name.clone());
+    assert_eq!(paths, vec!["memory_size_gb", "maintenance_policy"]);
+    assert_eq!(instance.name, name);
+    assert_eq!(instance.memory_size_gb, 4);
+    assert!(instance.maintenance_policy.is_none());
+
+    let window = MaintenanceWindow::new(Weekday::Mon, NaiveTime::from_hms_opt(22, 0, 0).unwrap());
+    let (instance, paths) = InstanceUpdate::default()
+        .maintenance_window(Some(window))
+        .into_api(name);
+    assert_eq!(paths, vec!["maintenance_policy"]);
+    let instance = Instance::from(instance);
+    assert_eq!(instance.id(), "cache");
+    assert_eq!(instance.maintenance_windows(), &[window]);
+}
@@ -1,4 +1,8 @@
+use std::thread;
+use std::time::Duration;
+
 use crate::storage;
+use crate::storage::{midpoint, NameRange, TtlMap};
 
 macro_rules! assert_ok {
     ($expr:expr) => {
@@ -60,7 +64,7 @@ async fn storage_create_and_delete_bucket() {
     //? Read the object's data back.
     let data = assert_ok!(object.get().await);
     let expected: json::Value = assert_ok!(json::from_str(object_data));
-    let got: json::Value = assert_ok!(json::from_slice(data.as_slice()));
+    let got: json::Value = assert_ok!(json::from_slice(&data));
     assert_eq!(expected, got);
     println!("object contents are identical.");
 
@@ -70,3 +74,393 @@ async fn storage_create_and_delete_bucket() {
     //? Delete the bucket.
     assert_ok!(bucket.delete().await);
 }
+
+#[tokio::test]
+async fn storage_lists_objects_in_parallel() {
+    //? Setup test client.
+    let mut client = assert_ok!(setup_client().await);
+    let bucket_name = env!("GCP_TEST_BUCKET").to_lowercase();
+    let mut bucket = assert_ok!(client.bucket(bucket_name.as_str()).await);
+
+    //? Create a few objects under a common prefix.
+    let names: Vec<String> = (0..25).map(|idx| format!("listing/{:02}", idx)).collect();
+    for name in names.iter() {
+        assert_ok!(bucket.create_object(name, "{}", "application/json").await);
+    }
+
+    //? Both listings find the same objects, in order.
+    let serial = assert_ok!(bucket.objects("listing/").await);
+    let parallel = assert_ok!(bucket.objects_parallel("listing/", 8).await);
+    let serial: Vec<&str> = serial.iter().map(|object| object.name()).collect();
+    let parallel: Vec<&str> = parallel.iter().map(|object| object.name()).collect();
+    assert_eq!(serial, names);
+    assert_eq!(parallel, names);
+
+    //? Delete the objects.
+    for name in names.iter() {
+        let object = assert_ok!(bucket.object(name).await);
+        assert_ok!(object.delete().await);
+    }
+}
+
+#[tokio::test]
+async fn storage_uploads_objects_resumably() {
+    //? Setup test client.
+    let client = assert_ok!(setup_client().await);
+    let mut client = client.upload_retry(storage::RetryConfig::default().max_attempts(3));
+    let bucket_name = env!("GCP_TEST_BUCKET").to_lowercase();
+    let mut bucket = assert_ok!(client.bucket(bucket_name.as_str()).await);
+
+    //? Upload an object spanning several chunks, and an empty one.
+    let data: Vec<u8> = (0..storage::CHUNK_SIZE * 2 + 42)
+        .map(|idx| idx as u8)
+        .collect();
+    let upload = bucket.create_object_resumable("resumable/large", data.clone(), "text/plain");
+    let mut object = assert_ok!(upload.await);
+    assert_eq!(assert_ok!(object.get().await).as_ref(), data.as_slice());
+    let upload = bucket.create_object_resumable("resumable/empty", Vec::new(), "text/plain");
+    let mut empty = assert_ok!(upload.await);
+    assert!(assert_ok!(empty.get().await).is_empty());
+
+    //? Delete the objects.
+    assert_ok!(object.delete().await);
+    assert_ok!(empty.delete().await);
+}
+
+#[test]
+fn storage_cache_expires_entries() {
+    //? Entries are served until their time-to-live elapses.
+    let mut map = TtlMap::new(Duration::from_secs(60), 8);
+    map.insert("bucket", 1);
+    assert_eq!(map.get(&"bucket"), Some(1));
+
+    //? Entries without a time-to-live are never served.
+    let mut map = TtlMap::new(Duration::ZERO, 8);
+    map.insert("bucket", 1);
+    assert_eq!(map.get(&"bucket"), None);
+
+    let mut map = TtlMap::new(Duration::from_millis(10), 8);
+    map.insert("bucket", 1);
+    thread::sleep(Duration::from_millis(20));
+    assert_eq!(map.get(&"bucket"), None);
+}
+
+#[test]
+fn storage_cache_evicts_oldest_entries() {
+    //? Inserting past the capacity evicts the oldest entry.
+    let mut map = TtlMap::new(Duration::from_secs(60), 2);
+    map.insert("first", 1);
+    thread::sleep(Duration::from_millis(1));
+    map.insert("second", 2);
+    thread::sleep(Duration::from_millis(1));
+    map.insert("third", 3);
+    assert_eq!(map.get(&"first"), None);
+    assert_eq!(map.get(&"second"), Some(2));
+    assert_eq!(map.get(&"third"), Some(3));
+
+    //? Replacing an entry does not evict anything.
+    map.insert("second", 4);
+    assert_eq!(map.get(&"second"), Some(4));
+    assert_eq!(map.get(&"third"), Some(3));
+
+    //? A cache without capacity stores nothing.
+    let mut map = TtlMap::new(Duration::from_secs(60), 0);
+    map.insert("first", 1);
+    assert_eq!(map.get(&"first"), None);
+}
+
+#[test]
+fn storage_cache_invalidates_entries() {
+    let mut map = TtlMap::new(Duration::from_secs(60), 8);
+    map.insert(("bucket", "a"), 1);
+    map.insert(("bucket", "b"), 2);
+    map.insert(("other", "a"), 3);
+
+    //? Entries can be removed individually.
+    map.remove(&("bucket", "a"));
+    assert_eq!(map.get(&("bucket", "a")), None);
+
+    //? Or all the objects of a bucket at once.
+    map.remove_where(|(bucket, _)| *bucket == "bucket");
+    assert_eq!(map.get(&("bucket", "b")), None);
+    assert_eq!(map.get(&("other", "a")), Some(3));
+}
+
+#[test]
+fn storage_listing_midpoints_fall_between_bounds() {
+    //? Unbounded ranges are split within the ASCII names sharing the prefix.
+    let mid = midpoint("logs/", "logs/", None).expect("no midpoint");
+    assert!(mid.starts_with("logs/") && mid.as_str() > "logs/");
+    assert!(mid.is_ascii());
+
+    //? Bounded ranges are split strictly between their bounds.
+    let bounds = [
+        ("logs/a", "logs/z"),
+        ("logs/a", "logs/b"),
+        ("logs/a", "logs/a0"),
+        ("logs/a\u{7f}", "logs/b"),
+        ("logs/", "logs/\u{1}"),
+        ("logs/\u{e9}", "logs/\u{10ffff}"),
+        ("logs/\u{d7ff}", "logs/\u{e000}\u{1}"),
+        ("logs/\u{d000}", "logs/\u{e001}"),
+    ];
+    for (lower, upper) in bounds {
+        let mid = midpoint("logs/", lower, Some(upper)).expect("no midpoint");
+        assert!(lower < mid.as_str() && mid.as_str() < upper, "{:?}", mid);
+    }
+
+    //? Empty ranges cannot be split.
+    assert_eq!(midpoint("logs/", "logs/a", Some("logs/a")), None);
+    assert_eq!(midpoint("logs/", "logs/a", Some("logs/a\u{0}")), None);
+    assert_eq!(midpoint("logs/", "logs/b", Some("logs/a")), None);
+}
+
+#[test]
+fn storage_listing_ranges_split_into_adjacent_halves() {
+    let range = NameRange {
+        start: Some(String::from("logs/a")),
+        start_exclusive: true,
+        end: Some(String::from("logs/z")),
+    };
+    let (lower, upper) = range.split("logs/").expect("no split");
+
+    //? The halves keep the bounds of the range and meet at the midpoint.
+    assert_eq!(lower.start, range.start);
+    assert!(lower.start_exclusive);
+    assert_eq!(upper.end, range.end);
+    assert_eq!(lower.end, upper.start);
+    assert!(!upper.start_exclusive);
+
+    //? Splitting repeatedly keeps narrowing the ranges, even past the ASCII names.
+    let mut range = NameRange::full();
+    for _ in 0..64 {
+        let (_, upper) = range.split("logs/").expect("no split");
+        assert!(upper.start > range.start);
+        assert!(upper.start.as_deref().unwrap().starts_with("logs/"));
+        range = upper;
+    }
+}
+
+#[test]
+fn storage_lifecycle_rules_are_evaluated_locally() {
+    use chrono::NaiveDate;
+    use storage::{Lifecycle, LifecycleAction, LifecycleCondition, LifecycleObject, LifecycleRule};
+
+    let date = |year, month, day| {
+        NaiveDate::from_ymd_opt(year, month, day)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap()
+    };
+    let lifecycle = Lifecycle::new()
+        .rule(LifecycleRule::new(
+            LifecycleAction::SetStorageClass(String::from("NEARLINE")),
+            LifecycleCondition::new()
+                .age(30)
+                .matches_storage_class("STANDARD"),
+        ))
+        .rule(LifecycleRule::new(
+            LifecycleAction::Delete,
+            LifecycleCondition::new().age(30).matches_prefix("tmp/"),
+        ))
+        .rule(LifecycleRule::new(
+            LifecycleAction::Delete,
+            LifecycleCondition::new()
+                .is_live(false)
+                .num_newer_versions(2),
+        ))
+        .rule(LifecycleRule::new(
+            LifecycleAction::Delete,
+            LifecycleCondition::new().created_before(NaiveDate::from_ymd_opt(2020, 1, 1).unwrap()),
+        ));
+
+    //? The age is counted from the creation of the object.
+    let object = LifecycleObject::new("logs/app.log", date(2024, 1, 1));
+    let outcome = lifecycle.evaluate(&object).unwrap();
+    assert_eq!(outcome.rule_index(), 0);
+    assert_eq!(outcome.applies_at(), date(2024, 1, 31));
+
+    //? Deletions take precedence over storage class changes applying at the same time.
+    let object = LifecycleObject::new("tmp/upload", date(2024, 1, 1));
+    let outcome = lifecycle.evaluate(&object).unwrap();
+    assert_eq!(outcome.rule_index(), 1);
+    assert_eq!(outcome.action(), &LifecycleAction::Delete);
+
+    //? Conditions without an age apply from the creation of the object.
+    let object = LifecycleObject::new("logs/app.log", date(2024, 1, 1)).noncurrent(2);
+    let outcome = lifecycle.evaluate(&object).unwrap();
+    assert_eq!(outcome.rule_index(), 2);
+    assert_eq!(outcome.applies_at(), date(2024, 1, 1));
+    let object = LifecycleObject::new("logs/app.log", date(2019, 6, 1)).storage_class("COLDLINE");
+    assert_eq!(lifecycle.evaluate(&object).unwrap().rule_index(), 3);
+
+    //? Objects not meeting any condition are left alone.
+    let object = LifecycleObject::new("logs/app.log", date(2024, 1, 1))
+        .storage_class("COLDLINE")
+        .noncurrent(1);
+    assert_eq!(lifecycle.evaluate(&object), None);
+    assert_eq!(Lifecycle::new().evaluate(&object), None);
+}
+
+#[test]
+fn storage_lifecycle_rules_are_read_from_buckets() {
+    use std::convert::TryFrom;
+
+    use chrono::NaiveDate;
+    use storage::api::bucket::BucketResource;
+    use storage::api::object::ObjectResource;
+    use storage::{Lifecycle, LifecycleAction, LifecycleObject};
+
+    let bucket: BucketResource = assert_ok!(json::from_value(json::json!({
+        "kind": "storage#bucket",
+        "id": "my-bucket",
+        "selfLink": "https://www.googleapis.com/storage/v1/b/my-bucket",
+        "projectNumber": "1234",
+        "name": "my-bucket",
+        "timeCreated": "2020-01-01T00:00:00.000Z",
+        "updated": "2020-01-01T00:00:00.000Z",
+        "metageneration": "1",
+        "location": "EU",
+        "locationType": "multi-region",
+        "storageClass": "STANDARD",
+        "etag": "CAE=",
+        "lifecycle": {
+            "rule": [
+                {
+                    "action": { "type": "SetStorageClass", "storageClass": "NEARLINE" },
+                    "condition": { "age": 30, "matchesStorageClass": ["STANDARD"] }
+                },
+                {
+                    "action": { "type": "Delete" },
+                    "condition": { "createdBefore": "2020-01-01", "matchesPrefix": ["tmp/"] }
+                }
+            ]
+        }
+    })));
+    let lifecycle = assert_ok!(Lifecycle::try_from(bucket.lifecycle.as_ref().unwrap()));
+    assert_eq!(lifecycle.rules().len(), 2);
+    assert_eq!(
+        lifecycle.rules()[0].action(),
+        &LifecycleAction::SetStorageClass(String::from("NEARLINE"))
+    );
+
+    let object = |name: &str, created: &str| -> ObjectResource {
+        assert_ok!(json::from_value(json::json!({
+            "kind": "storage#object",
+            "id": format!("my-bucket/{}/1", name),
+            "selfLink": "",
+            "name": name,
+            "bucket": "my-bucket",
+            "generation": "1",
+            "metageneration": "1",
+            "contentType": "text/plain",
+            "timeCreated": created,
+            "updated": created,
+            "storageClass": "STANDARD",
+            "size": "42",
+            "md5Hash": "",
+            "mediaLink": "",
+            "crc32c": "",
+            "etag": ""
+        })))
+    };
+
+    //? Objects are evaluated from their metadata, as listed or fetched.
+    let logs = assert_ok!(LifecycleObject::try_from(&object(
+        "logs/app.log",
+        "2024-01-01T12:00:00.000Z"
+    )));
+    let outcome = lifecycle.evaluate(&logs).unwrap();
+    assert_eq!(outcome.rule_index(), 0);
+    let expected = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+    assert_eq!(
+        outcome.applies_at(),
+        expected.and_hms_opt(12, 0, 0).unwrap()
+    );
+    let upload = assert_ok!(LifecycleObject::try_from(&object(
+        "tmp/upload",
+        "2019-06-01T00:00:00.000Z"
+    )));
+    assert_eq!(lifecycle.evaluate(&upload).unwrap().rule_index(), 1);
+
+    //? Malformed configurations and metadata are rejected.
+    let mut invalid = bucket.lifecycle.clone().unwrap();
+    invalid.rule[1].condition.created_before = Some(String::from("01/01/2020"));
+    assert!(Lifecycle::try_from(&invalid).is_err());
+    invalid.rule[0].action.action_type = String::from("AbortIncompleteMultipartUpload");
+    assert!(Lifecycle::try_from(&invalid).is_err());
+    assert!(LifecycleObject::try_from(&object("logs/app.log", "yesterday")).is_err());
+}
+
+#[test]
+fn storage_uploads_resume_from_persisted_bytes() {
+    use reqwest::StatusCode;
+
+    //? The `Range` header holds the inclusive range of the persisted bytes.
+    assert_eq!(storage::persisted_bytes("bytes=0-0"), Some(1));
+    assert_eq!(storage::persisted_bytes("bytes=0-262143"), Some(262_144));
+    assert_eq!(storage::persisted_bytes("bytes=10-20"), None);
+    assert_eq!(storage::persisted_bytes("garbage"), None);
+
+    //? Only timeouts, throttling and server errors are transient.
+    assert!(storage::is_retryable_status(StatusCode::REQUEST_TIMEOUT));
+    assert!(storage::is_retryable_status(StatusCode::TOO_MANY_REQUESTS));
+    assert!(storage::is_retryable_status(
+        StatusCode::SERVICE_UNAVAILABLE
+    ));
+    assert!(!storage::is_retryable_status(StatusCode::NOT_FOUND));
+    assert!(!storage::is_retryable_status(
+        StatusCode::PERMANENT_REDIRECT
+    ));
+
+    //? Chunks must be multiples of 256 KiB, and the backoff stays within its bounds.
+    assert_eq!(storage::CHUNK_SIZE % (256 * 1024), 0);
+    let config = storage::RetryConfig::default()
+        .initial_backoff(Duration::from_millis(100))
+        .max_backoff(Duration::from_secs(1));
+    for attempt in 1..10 {
+        let expected =
+            Duration::from_millis(100 * (1 << (attempt - 1))).min(Duration::from_secs(1));
+        let backoff = config.backoff(attempt);
+        assert!(
+            backoff >= expected / 2 && backoff <= expected,
+            "{:?}",
+            backoff
+        );
+    }
+}
+
+#[tokio::test]
+async fn storage_uploads_give_up_without_progress() {
+    use std::cell::RefCell;
+
+    use crate::storage::{drive_upload, Error, UploadStatus};
+
+    let config = storage::RetryConfig::default()
+        .max_attempts(3)
+        .initial_backoff(Duration::from_millis(1));
+
+    //? Chunks accepted without persisting anything count as failed attempts, progress resets the count.
+    let persisted = [0, 0, 512, 512, 512, 512];
+    let offsets = RefCell::new(Vec::new());
+    let result = drive_upload(&config, 1024, |offset, recovering| {
+        let mut offsets = offsets.borrow_mut();
+        let idx = offsets.len();
+        offsets.push(offset);
+        assert!(!recovering);
+        async move { Ok(UploadStatus::Persisted(persisted[idx])) }
+    })
+    .await;
+    assert!(matches!(result, Err(Error::Upload(_))));
+    assert_eq!(offsets.into_inner(), vec![0, 0, 0, 512, 512, 512]);
+
+    //? Without any progress, the upload is given up after the maximum number of attempts.
+    let mut requests = 0;
+    let result = drive_upload(&config, 1024, |_, _| {
+        requests += 1;
+        async { Ok(UploadStatus::Persisted(0)) }
+    })
+    .await;
+    assert!(matches!(result, Err(Error::Upload(_))));
+    assert_eq!(requests, 3);
+}
@@ -0,0 +1,27 @@
+use std::time::Duration;
+
+use crate::tasks;
+
+#[tokio::test]
+async fn tasks_are_counted_until_they_finish() {
+    //? The stats are global, so the test uses its own task name.
+    const NAME: &str = "google-cloud:test-task";
+
+    tasks::spawn(NAME, tokio::time::sleep(Duration::from_millis(10)));
+
+    let stats = tasks::stats();
+    assert!(stats.names().any(|name| name == NAME));
+    assert_eq!(stats.spawned(NAME), 1);
+    assert_eq!(stats.running(NAME), 1);
+    assert!(stats.total_running() >= 1);
+
+    tokio::time::timeout(Duration::from_secs(5), async {
+        while tasks::stats().running(NAME) > 0 {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+    })
+    .await
+    .expect("the task was never counted as finished");
+    assert_eq!(tasks::stats().spawned(NAME), 1);
+    assert_eq!(tasks::stats().running("google-cloud:unknown-task"), 0);
+}
@@ -0,0 +1,110 @@
+use std::collections::HashSet;
+
+use crate::authorize::ApplicationCredentials;
+use crate::testing::Namespace;
+
+#[cfg(feature = "pubsub")]
+macro_rules! assert_ok {
+    ($expr:expr) => {
+        match $expr {
+            Ok(value) => value,
+            Err(err) => {
+                panic!("asserted result is an error: {}", err);
+            }
+        }
+    };
+}
+
+fn dummy_creds() -> ApplicationCredentials {
+    ApplicationCredentials {
+        cred_type: String::from("service_account"),
+        project_id: String::from("my-project"),
+        private_key_id: String::new(),
+        private_key: String::new(),
+        client_email: String::new(),
+        client_id: String::new(),
+        auth_uri: String::new(),
+        token_uri: String::new(),
+        auth_provider_x509_cert_url: String::new(),
+        client_x509_cert_url: String::new(),
+    }
+}
+
+#[test]
+fn testing_namespaces_names_uniquely() {
+    //? Every namespace gets its own run ID, even within the same process.
+    let run_ids: HashSet<String> = (0..16)
+        .map(|_| Namespace::from_credentials("my-project", dummy_creds()))
+        .map(|namespace| namespace.run_id().to_string())
+        .collect();
+    assert_eq!(run_ids.len(), 16);
+
+    //? Names are prefixed with the run ID.
+    let namespace = Namespace::from_credentials("my-project", dummy_creds()).prefix("ci");
+    let expected = format!("ci-{}-events", namespace.run_id());
+    assert_eq!(namespace.name("events"), expected);
+}
+
+#[cfg(feature = "storage")]
+#[test]
+fn testing_lowercases_bucket_names() {
+    let namespace = Namespace::from_credentials("my-project", dummy_creds()).prefix("CI");
+    let bucket = namespace.bucket("Uploads");
+    assert_eq!(bucket, format!("ci-{}-uploads", namespace.run_id()));
+
+    //? Object names are only registered, and kept as-is.
+    assert_eq!(namespace.object(&bucket, "Data.json"), "Data.json");
+
+    //? Forget the resources, as these dummy credentials cannot delete them.
+    namespace.resources.lock().unwrap().clear();
+}
+
+#[cfg(all(feature = "pubsub", feature = "storage"))]
+#[test]
+fn testing_orders_resources_for_cleanup() {
+    use crate::testing::Resource;
+
+    let namespace = Namespace::from_credentials("my-project", dummy_creds());
+    let topic = namespace.topic("events");
+    let bucket = namespace.bucket("uploads");
+    let subscription = namespace.subscription("events-sub");
+    let object = namespace.object(&bucket, "data.json");
+    //? Requesting a name twice registers its resource once.
+    namespace.topic("events");
+
+    let mut resources = std::mem::take(&mut *namespace.resources.lock().unwrap());
+    resources.reverse();
+    resources.sort_by_key(Resource::rank);
+    let expected = vec![
+        Resource::Object(bucket.clone(), object),
+        Resource::Subscription(subscription),
+        Resource::Bucket(bucket),
+        Resource::Topic(topic),
+    ];
+    assert_eq!(resources, expected);
+}
+
+#[cfg(feature = "pubsub")]
+#[tokio::test]
+async fn testing_cleans_up_pubsub_resources() {
+    use crate::pubsub;
+
+    //? Setup test namespace and client.
+    let creds = super::load_creds();
+    let namespace = Namespace::from_credentials(env!("GCP_TEST_PROJECT"), creds.clone());
+    let mut client =
+        assert_ok!(pubsub::Client::from_credentials(env!("GCP_TEST_PROJECT"), creds).await);
+
+    //? Create a topic and a subscription within the namespace.
+    let topic_id = namespace.topic("events");
+    let subscription_id = namespace.subscription("events-sub");
+    let config = pubsub::TopicConfig::default();
+    let mut topic = assert_ok!(client.create_topic(&topic_id, config).await);
+    let config = pubsub::SubscriptionConfig::default();
+    assert_ok!(topic.create_subscription(&subscription_id, config).await);
+
+    //? Clean up the namespace, and check that both are gone.
+    assert_ok!(namespace.cleanup().await);
+    assert!(client.topic(&topic_id).await.is_err());
+    assert!(client.subscription(&subscription_id).await.is_err());
+}
@@ -0,0 +1,144 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use prost_types::Timestamp;
+
+use crate::error::ConvertError;
+use crate::types;
+
+fn timestamp(seconds: i64, nanos: i32) -> Timestamp {
+    Timestamp { seconds, nanos }
+}
+
+fn duration(seconds: i64, nanos: i32) -> prost_types::Duration {
+    prost_types::Duration { seconds, nanos }
+}
+
+#[test]
+fn types_timestamps_survive_round_trip() {
+    let cases = [
+        timestamp(0, 0),
+        timestamp(1_500_000_000, 250_000_000),
+        timestamp(-1, 999_999_999),
+        timestamp(-62_135_596_800, 0),
+        timestamp(253_402_300_799, 999_999_999),
+    ];
+    for case in cases.iter() {
+        let time = types::timestamp_to_system_time(case.clone()).unwrap();
+        assert_eq!(&types::system_time_to_timestamp(time), case);
+        let datetime = types::timestamp_to_chrono(case.clone()).unwrap();
+        assert_eq!(&types::chrono_to_timestamp(&datetime), case);
+        let datetime = types::timestamp_to_naive(case.clone()).unwrap();
+        assert_eq!(&types::naive_to_timestamp(datetime), case);
+    }
+
+    let now = SystemTime::now();
+    let timestamp = types::system_time_to_timestamp(now);
+    assert_eq!(types::timestamp_to_system_time(timestamp).unwrap(), now);
+}
+
+#[test]
+fn types_timestamps_are_normalized() {
+    //? Out-of-range nanoseconds carry over into the seconds.
+    let expected = UNIX_EPOCH + Duration::from_millis(1_500);
+    let cases = [
+        timestamp(1, 500_000_000),
+        timestamp(2, -500_000_000),
+        timestamp(0, 1_500_000_000),
+    ];
+    for case in cases.iter().cloned() {
+        assert_eq!(
+            types::timestamp_to_system_time(case.clone()).unwrap(),
+            expected
+        );
+        assert_eq!(
+            types::timestamp_to_chrono(case).unwrap().timestamp_millis(),
+            1_500
+        );
+    }
+
+    let invalid = timestamp(i64::MAX, 1_000_000_000);
+    assert!(matches!(
+        types::timestamp_to_system_time(invalid.clone()),
+        Err(ConvertError::InvalidTimestamp {
+            seconds: i64::MAX,
+            nanos: 1_000_000_000
+        })
+    ));
+    assert!(types::timestamp_to_chrono(invalid).is_err());
+    assert!(types::timestamp_to_chrono(timestamp(i64::MIN, 0)).is_err());
+}
+
+#[test]
+fn types_durations_keep_their_sign() {
+    let cases = [
+        (duration(0, 0), 0),
+        (duration(1, 500_000_000), 1_500_000_000),
+        (duration(-1, -500_000_000), -1_500_000_000),
+        (duration(0, -1), -1),
+    ];
+    for (case, nanos) in cases.iter().cloned() {
+        let converted = types::duration_to_chrono(case.clone()).unwrap();
+        assert_eq!(converted.num_nanoseconds(), Some(nanos));
+        assert_eq!(types::chrono_to_duration(converted), case);
+
+        match types::duration_to_std(case.clone()) {
+            Ok(std) => {
+                assert_eq!(std.as_nanos() as i64, nanos);
+                assert_eq!(types::std_to_duration(std).unwrap(), case);
+            }
+            Err(ConvertError::InvalidDuration { .. }) => assert!(nanos < 0),
+            Err(err) => panic!("unexpected error: {}", err),
+        }
+    }
+
+    assert!(types::duration_to_chrono(duration(i64::MAX, 0)).is_err());
+    assert!(types::duration_to_chrono(duration(i64::MIN, 0)).is_err());
+    assert!(types::std_to_duration(Duration::from_secs(u64::MAX)).is_err());
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn types_time_conversions_survive_round_trip() {
+    let case = timestamp(-1, 250_000_000);
+    let datetime = types::timestamp_to_time(case.clone()).unwrap();
+    assert_eq!(datetime.unix_timestamp_nanos(), -750_000_000);
+    assert_eq!(types::time_to_timestamp(datetime), case);
+
+    let case = duration(-90, -5);
+    let converted = types::duration_to_time(case.clone()).unwrap();
+    assert_eq!(converted.whole_nanoseconds(), -90_000_000_005);
+    assert_eq!(types::time_to_duration(converted), case);
+}
+
+#[test]
+fn types_locations_and_colors_are_validated() {
+    use types::{Color, LatLng};
+
+    //? Locations must be within range, in degrees and in radians.
+    assert!(LatLng::new(-90.0, 180.0).is_ok());
+    assert!(matches!(
+        LatLng::new(0.0, 180.5),
+        Err(ConvertError::InvalidLatLng { .. })
+    ));
+    assert!(LatLng::new(f64::NAN, 0.0).is_err());
+    let antimeridian = LatLng::from_radians(0.0, -std::f64::consts::PI).unwrap();
+    assert_eq!(antimeridian.longitude(), -180.0);
+    let (latitude, longitude) = LatLng::new(45.0, -90.0).unwrap().to_radians();
+    assert!((latitude - std::f64::consts::FRAC_PI_4).abs() < 1e-12);
+    assert!((longitude + std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+
+    //? Colors survive round-trips through their 8-bit components and hexadecimal notations.
+    for rgba in [[0, 0, 0, 255], [255, 128, 0, 255], [18, 52, 86, 120]].iter() {
+        let color = Color::from_rgba8(*rgba);
+        assert_eq!(&color.to_rgba8(), rgba);
+        assert_eq!(Color::from_hex(&color.to_hex()).unwrap(), color);
+    }
+    assert_eq!(Color::from_hex("123456").unwrap().to_hex(), "#123456");
+    assert!(matches!(
+        Color::from_hex("#12345"),
+        Err(ConvertError::InvalidColor(_))
+    ));
+    assert!(Color::from_hex("#12345g").is_err());
+    assert!(Color::rgba(0.0, 0.0, 0.0, -0.1).is_err());
+    assert!(Color::rgb(0.0, f32::NAN, 0.0).is_err());
+}
@@ -36,3 +36,79 @@ async fn vision_detects_text_successfully() {
     let config = vision::TextDetectionConfig::default();
     assert_ok!(client.detect_document_text(image, config).await);
 }
+
+#[test]
+fn vision_requests_share_features_and_contexts() {
+    let features = vision::FeatureSet::new()
+        .feature(vision::Feature::DocumentTextDetection)
+        .feature(vision::Feature::FaceDetection { max_results: 5 });
+    let context = vision::ImageContext::new().language_hint("fr");
+
+    let requests: Vec<_> = ["a.png", "b.png"]
+        .iter()
+        .map(|url| {
+            vision::AnnotateRequest::new(vision::Image::from_url(*url))
+                .features(&features)
+                .context(&context)
+        })
+        .collect();
+    let expected = vision::AnnotateRequest::new(vision::Image::from_url("b.png"))
+        .feature(vision::Feature::DocumentTextDetection)
+        .feature(vision::Feature::FaceDetection { max_results: 5 })
+        .context(&vision::ImageContext::new().language_hint("fr"));
+    assert_eq!(requests[1], expected);
+    assert_eq!(features.features().len(), 2);
+}
+
+#[test]
+fn vision_normalized_polys_convert_to_pixels() {
+    let vertex = vision::NormalizedVertex::new(0.25, 0.5);
+    assert_eq!(vertex.to_pixels(640, 480), (160, 240));
+
+    let poly = vision::NormalizedBoundingPoly {
+        vertices: vec![
+            vision::NormalizedVertex::new(0.1, 0.2),
+            vision::NormalizedVertex::new(0.6, 0.2),
+            vision::NormalizedVertex::new(0.6, 0.9),
+            vision::NormalizedVertex::new(0.1, 0.9),
+        ],
+    };
+    assert_eq!(poly.to_pixels(100, 200)[2], (60, 180));
+    assert_eq!(
+        poly.bounding_box(100, 200),
+        Some(vision::BoundingBox::new(10, 40, 50, 140)),
+    );
+    assert_eq!(
+        vision::NormalizedBoundingPoly::default().bounding_box(100, 200),
+        None,
+    );
+}
+
+#[test]
+fn vision_failed_images_are_reported_individually() {
+    use crate::vision::api;
+
+    let response = api::AnnotateImageResponse {
+        error: Some(api::rpc::Status {
+            code: tonic::Code::InvalidArgument as i32,
+            message: String::from("Bad image data."),
+            details: Vec::new(),
+        }),
+        ..Default::default()
+    };
+    let err = vision::Annotations::from_response(3, response).unwrap_err();
+    assert_eq!(err.index(), 3);
+    assert_eq!(err.code(), tonic::Code::InvalidArgument);
+    assert_eq!(err.message(), "Bad image data.");
+    match vision::Error::from(err) {
+        vision::Error::Status(status) => assert_eq!(status.code(), tonic::Code::InvalidArgument),
+        err => panic!("unexpected error: {}", err),
+    }
+
+    //? An `OK` status means the image was annotated.
+    let response = api::AnnotateImageResponse {
+        error: Some(api::rpc::Status::default()),
+        ..Default::default()
+    };
+    assert!(vision::Annotations::from_response(0, response).is_ok());
+}
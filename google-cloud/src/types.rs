@@ -0,0 +1,420 @@
+use std::convert::TryFrom;
+use std::time::{Duration as StdDuration, SystemTime, UNIX_EPOCH};
+
+use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
+use prost_types::{Duration, Timestamp};
+
+use crate::error::ConvertError;
+
+const NANOS_PER_SECOND: i64 = 1_000_000_000;
+
+//? `chrono::Duration` holds up to `i64::MAX` milliseconds, and panics when built past that.
+const MAX_CHRONO_SECONDS: i64 = i64::MAX / 1_000;
+
+/// Normalizes a timestamp, so that its nanoseconds are positive and less than a second.
+///
+/// The API may send timestamps with out-of-range nanoseconds, which still describe a valid instant.
+fn normalize_timestamp(timestamp: &Timestamp) -> Option<(i64, u32)> {
+    let nanos = i64::from(timestamp.nanos);
+    let seconds = timestamp
+        .seconds
+        .checked_add(nanos.div_euclid(NANOS_PER_SECOND))?;
+    Some((seconds, nanos.rem_euclid(NANOS_PER_SECOND) as u32))
+}
+
+/// The total number of nanoseconds of a duration, whatever the signs of its parts.
+fn total_nanos(duration: &Duration) -> i128 {
+    i128::from(duration.seconds) * i128::from(NANOS_PER_SECOND) + i128::from(duration.nanos)
+}
+
+fn invalid_timestamp(timestamp: &Timestamp) -> ConvertError {
+    ConvertError::InvalidTimestamp {
+        seconds: timestamp.seconds,
+        nanos: timestamp.nanos,
+    }
+}
+
+fn invalid_duration(duration: &Duration) -> ConvertError {
+    ConvertError::InvalidDuration {
+        seconds: duration.seconds,
+        nanos: duration.nanos,
+    }
+}
+
+/// Converts a timestamp into a `SystemTime`.
+///
+/// ```
+/// # use std::time::{Duration, UNIX_EPOCH};
+/// # use google_cloud::types;
+/// let timestamp = prost_types::Timestamp { seconds: -1, nanos: 250_000_000 };
+/// let time = types::timestamp_to_system_time(timestamp).unwrap();
+/// assert_eq!(time, UNIX_EPOCH - Duration::from_millis(750));
+/// ```
+pub fn timestamp_to_system_time(timestamp: Timestamp) -> Result<SystemTime, ConvertError> {
+    let (seconds, nanos) =
+        normalize_timestamp(&timestamp).ok_or_else(|| invalid_timestamp(&timestamp))?;
+    let time = if seconds >= 0 {
+        UNIX_EPOCH.checked_add(StdDuration::new(seconds as u64, nanos))
+    } else {
+        UNIX_EPOCH
+            .checked_sub(StdDuration::new(seconds.unsigned_abs(), 0))
+            .and_then(|time| time.checked_add(StdDuration::new(0, nanos)))
+    };
+    time.ok_or_else(|| invalid_timestamp(&timestamp))
+}
+
+/// Converts a `SystemTime` into a timestamp.
+///
+/// ```
+/// # use std::time::{Duration, UNIX_EPOCH};
+/// # use google_cloud::types;
+/// let timestamp = types::system_time_to_timestamp(UNIX_EPOCH - Duration::from_millis(750));
+/// assert_eq!(timestamp, prost_types::Timestamp { seconds: -1, nanos: 250_000_000 });
+/// ```
+pub fn system_time_to_timestamp(time: SystemTime) -> Timestamp {
+    let (seconds, nanos) = match time.duration_since(UNIX_EPOCH) {
+        Ok(elapsed) => (
+            i64::try_from(elapsed.as_secs()).unwrap_or(i64::MAX),
+            elapsed.subsec_nanos(),
+        ),
+        //? Timestamps before the epoch still count their nanoseconds forward.
+        Err(err) => {
+            let before = err.duration();
+            let seconds = i64::try_from(before.as_secs()).unwrap_or(i64::MAX);
+            match before.subsec_nanos() {
+                0 => (-seconds, 0),
+                nanos => (-seconds - 1, NANOS_PER_SECOND as u32 - nanos),
+            }
+        }
+    };
+    Timestamp {
+        seconds,
+        nanos: nanos as i32,
+    }
+}
+
+/// Converts a timestamp into a `chrono` UTC date-time.
+///
+/// ```
+/// # use google_cloud::types;
+/// let timestamp = prost_types::Timestamp { seconds: 1_500_000_000, nanos: 0 };
+/// let datetime = types::timestamp_to_chrono(timestamp).unwrap();
+/// assert_eq!(datetime.to_rfc3339(), "2017-07-14T02:40:00+00:00");
+/// ```
+pub fn timestamp_to_chrono(timestamp: Timestamp) -> Result<DateTime<Utc>, ConvertError> {
+    normalize_timestamp(&timestamp)
+        .and_then(|(seconds, nanos)| DateTime::from_timestamp(seconds, nanos))
+        .ok_or_else(|| invalid_timestamp(&timestamp))
+}
+
+/// Converts a `chrono` date-time, in any timezone, into a timestamp.
+pub fn chrono_to_timestamp<Tz: TimeZone>(datetime: &DateTime<Tz>) -> Timestamp {
+    //? Leap seconds are represented as nanoseconds past a second, which timestamps cannot hold.
+    let nanos = datetime.timestamp_subsec_nanos().min(999_999_999);
+    Timestamp {
+        seconds: datetime.timestamp(),
+        nanos: nanos as i32,
+    }
+}
+
+/// Converts a timestamp into a naive date-time, in UTC (as used throughout this crate).
+pub fn timestamp_to_naive(timestamp: Timestamp) -> Result<NaiveDateTime, ConvertError> {
+    timestamp_to_chrono(timestamp).map(|datetime| datetime.naive_utc())
+}
+
+/// Converts a naive date-time, taken to be in UTC, into a timestamp.
+///
+/// ```
+/// # use google_cloud::types;
+/// let datetime = chrono::DateTime::from_timestamp(1_500_000_000, 5).unwrap().naive_utc();
+/// let timestamp = types::naive_to_timestamp(datetime);
+/// assert_eq!(timestamp, prost_types::Timestamp { seconds: 1_500_000_000, nanos: 5 });
+/// assert_eq!(types::timestamp_to_naive(timestamp).unwrap(), datetime);
+/// ```
+pub fn naive_to_timestamp(datetime: NaiveDateTime) -> Timestamp {
+    chrono_to_timestamp(&datetime.and_utc())
+}
+
+/// Converts a duration into a standard (and thus positive) duration.
+///
+/// ```
+/// # use google_cloud::types;
+/// let duration = prost_types::Duration { seconds: 2, nanos: -500_000_000 };
+/// assert_eq!(types::duration_to_std(duration).unwrap().as_millis(), 1500);
+///
+/// let negative = prost_types::Duration { seconds: -1, nanos: 0 };
+/// assert!(types::duration_to_std(negative).is_err());
+/// ```
+pub fn duration_to_std(duration: Duration) -> Result<StdDuration, ConvertError> {
+    let nanos = total_nanos(&duration);
+    let seconds = u64::try_from(nanos.div_euclid(i128::from(NANOS_PER_SECOND)))
+        .map_err(|_| invalid_duration(&duration))?;
+    let nanos = nanos.rem_euclid(i128::from(NANOS_PER_SECOND)) as u32;
+    Ok(StdDuration::new(seconds, nanos))
+}
+
+/// Converts a standard duration into a duration.
+///
+/// Fails for durations too long to be represented (more than `i64::MAX` seconds).
+pub fn std_to_duration(duration: StdDuration) -> Result<Duration, ConvertError> {
+    let seconds = i64::try_from(duration.as_secs()).map_err(|_| ConvertError::LossyNumber {
+        value: duration.as_secs().to_string(),
+        expected: String::from("i64"),
+    })?;
+    Ok(Duration {
+        seconds,
+        nanos: duration.subsec_nanos() as i32,
+    })
+}
+
+/// Converts a duration into a `chrono` duration.
+///
+/// ```
+/// # use google_cloud::types;
+/// let duration = prost_types::Duration { seconds: -90, nanos: -5 };
+/// let converted = types::duration_to_chrono(duration.clone()).unwrap();
+/// assert_eq!(converted, chrono::Duration::seconds(-90) - chrono::Duration::nanoseconds(5));
+/// assert_eq!(types::chrono_to_duration(converted), duration);
+/// ```
+pub fn duration_to_chrono(duration: Duration) -> Result<chrono::Duration, ConvertError> {
+    if duration
+        .seconds
+        .checked_abs()
+        .is_none_or(|seconds| seconds >= MAX_CHRONO_SECONDS)
+    {
+        return Err(invalid_duration(&duration));
+    }
+    chrono::Duration::seconds(duration.seconds)
+        .checked_add(&chrono::Duration::nanoseconds(duration.nanos.into()))
+        .ok_or_else(|| invalid_duration(&duration))
+}
+
+/// Converts a `chrono` duration into a duration.
+pub fn chrono_to_duration(mut duration: chrono::Duration) -> Duration {
+    //? Whole seconds are rounded towards zero, so that both parts share the same sign, as expected by the API.
+    let seconds = duration.num_seconds();
+    duration -= chrono::Duration::seconds(seconds);
+    let nanos = duration.num_nanoseconds().unwrap_or(0) as i32;
+    Duration { seconds, nanos }
+}
+
+/// Converts a timestamp into a `time` UTC date-time.
+#[cfg(feature = "time")]
+pub fn timestamp_to_time(timestamp: Timestamp) -> Result<::time::OffsetDateTime, ConvertError> {
+    let nanos =
+        i128::from(timestamp.seconds) * i128::from(NANOS_PER_SECOND) + i128::from(timestamp.nanos);
+    ::time::OffsetDateTime::from_unix_timestamp_nanos(nanos)
+        .map_err(|_| invalid_timestamp(&timestamp))
+}
+
+/// Converts a `time` date-time, at any offset, into a timestamp.
+#[cfg(feature = "time")]
+pub fn time_to_timestamp(datetime: ::time::OffsetDateTime) -> Timestamp {
+    Timestamp {
+        seconds: datetime.unix_timestamp(),
+        nanos: datetime.nanosecond() as i32,
+    }
+}
+
+/// Converts a duration into a `time` duration.
+#[cfg(feature = "time")]
+pub fn duration_to_time(duration: Duration) -> Result<::time::Duration, ConvertError> {
+    ::time::Duration::seconds(duration.seconds)
+        .checked_add(::time::Duration::nanoseconds(duration.nanos.into()))
+        .ok_or_else(|| invalid_duration(&duration))
+}
+
+/// Converts a `time` duration into a duration.
+#[cfg(feature = "time")]
+pub fn time_to_duration(duration: ::time::Duration) -> Duration {
+    Duration {
+        seconds: duration.whole_seconds(),
+        nanos: duration.subsec_nanoseconds(),
+    }
+}
+
+/// A geographic location, in degrees (as in the `google.type.LatLng` of the APIs).
+///
+/// Locations are validated on construction: the latitude must be within -90 and +90 degrees,
+/// and the longitude within -180 and +180 degrees.
+///
+/// ```
+/// # use google_cloud::types::LatLng;
+/// let eiffel_tower = LatLng::new(48.8584, 2.2945).unwrap();
+/// assert_eq!(eiffel_tower.latitude(), 48.8584);
+/// assert!(LatLng::new(91.0, 0.0).is_err());
+///
+/// let north_pole = LatLng::from_radians(std::f64::consts::FRAC_PI_2, 0.0).unwrap();
+/// assert_eq!(north_pole.latitude(), 90.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LatLng {
+    pub(crate) latitude: f64,
+    pub(crate) longitude: f64,
+}
+
+impl LatLng {
+    /// Construct a new location, from its latitude and longitude in degrees.
+    pub fn new(latitude: f64, longitude: f64) -> Result<LatLng, ConvertError> {
+        let valid = (-90.0..=90.0).contains(&latitude) && (-180.0..=180.0).contains(&longitude);
+        if valid {
+            Ok(LatLng {
+                latitude,
+                longitude,
+            })
+        } else {
+            Err(ConvertError::InvalidLatLng {
+                latitude,
+                longitude,
+            })
+        }
+    }
+
+    /// Construct a new location, from its latitude and longitude in radians.
+    pub fn from_radians(latitude: f64, longitude: f64) -> Result<LatLng, ConvertError> {
+        //? Rounding may push the bounds slightly past their range once converted to degrees.
+        let snap = |degrees: f64, bound: f64| {
+            if degrees.abs() > bound && degrees.abs() - bound < 1e-9 {
+                bound.copysign(degrees)
+            } else {
+                degrees
+            }
+        };
+        LatLng::new(
+            snap(latitude.to_degrees(), 90.0),
+            snap(longitude.to_degrees(), 180.0),
+        )
+    }
+
+    /// The latitude, in degrees from -90 to +90.
+    pub fn latitude(&self) -> f64 {
+        self.latitude
+    }
+
+    /// The longitude, in degrees from -180 to +180.
+    pub fn longitude(&self) -> f64 {
+        self.longitude
+    }
+
+    /// The latitude and longitude, in radians.
+    pub fn to_radians(&self) -> (f64, f64) {
+        (self.latitude.to_radians(), self.longitude.to_radians())
+    }
+}
+
+/// A color in the RGBA color space, with components from 0 to 1 (as in the `google.type.Color` of the APIs).
+///
+/// Colors are validated on construction, and convert to and from 8-bit components and hexadecimal notations.
+///
+/// ```
+/// # use google_cloud::types::Color;
+/// let color = Color::from_hex("#ff8000").unwrap();
+/// assert_eq!(color.to_rgba8(), [255, 128, 0, 255]);
+/// assert_eq!(color.to_hex(), "#ff8000");
+///
+/// let translucent = Color::rgba(1.0, 0.5, 0.0, 0.5).unwrap();
+/// assert_eq!(translucent.to_hex(), "#ff800080");
+/// assert!(Color::rgb(1.5, 0.0, 0.0).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Color {
+    pub(crate) red: f32,
+    pub(crate) green: f32,
+    pub(crate) blue: f32,
+    pub(crate) alpha: f32,
+}
+
+impl Color {
+    /// Construct a new opaque color, from its components from 0 to 1.
+    pub fn rgb(red: f32, green: f32, blue: f32) -> Result<Color, ConvertError> {
+        Color::rgba(red, green, blue, 1.0)
+    }
+
+    /// Construct a new color, from its components from 0 to 1 (an alpha of 1 being opaque).
+    pub fn rgba(red: f32, green: f32, blue: f32, alpha: f32) -> Result<Color, ConvertError> {
+        let components = [red, green, blue, alpha];
+        match components.iter().find(|c| !(0.0..=1.0).contains(*c)) {
+            Some(component) => Err(ConvertError::InvalidColor(component.to_string())),
+            None => Ok(Color {
+                red,
+                green,
+                blue,
+                alpha,
+            }),
+        }
+    }
+
+    /// Construct a new color, from its 8-bit components.
+    pub fn from_rgba8([red, green, blue, alpha]: [u8; 4]) -> Color {
+        let component = |value: u8| f32::from(value) / 255.0;
+        Color {
+            red: component(red),
+            green: component(green),
+            blue: component(blue),
+            alpha: component(alpha),
+        }
+    }
+
+    /// Parse a color from its hexadecimal notation, such as `#ff8000` (opaque) or `#ff800080` (with alpha).
+    ///
+    /// The leading `#` is optional.
+    pub fn from_hex(hex: &str) -> Result<Color, ConvertError> {
+        let invalid = || ConvertError::InvalidColor(String::from(hex));
+        let digits = hex.strip_prefix('#').unwrap_or(hex);
+        if !(digits.len() == 6 || digits.len() == 8) || !digits.is_ascii() {
+            return Err(invalid());
+        }
+        let component = |idx: usize| {
+            let digits = digits.get(idx * 2..idx * 2 + 2);
+            digits.map_or(Ok(255), |digits| u8::from_str_radix(digits, 16))
+        };
+        let components = [component(0), component(1), component(2), component(3)];
+        let mut rgba = [0; 4];
+        for (value, component) in rgba.iter_mut().zip(components) {
+            *value = component.map_err(|_| invalid())?;
+        }
+
+        Ok(Color::from_rgba8(rgba))
+    }
+
+    /// The red component, from 0 to 1.
+    pub fn red(&self) -> f32 {
+        self.red
+    }
+
+    /// The green component, from 0 to 1.
+    pub fn green(&self) -> f32 {
+        self.green
+    }
+
+    /// The blue component, from 0 to 1.
+    pub fn blue(&self) -> f32 {
+        self.blue
+    }
+
+    /// The alpha component, from 0 (transparent) to 1 (opaque).
+    pub fn alpha(&self) -> f32 {
+        self.alpha
+    }
+
+    /// The 8-bit components, rounded to the nearest value.
+    pub fn to_rgba8(&self) -> [u8; 4] {
+        let component = |value: f32| (value * 255.0).round() as u8;
+        [
+            component(self.red),
+            component(self.green),
+            component(self.blue),
+            component(self.alpha),
+        ]
+    }
+
+    /// The hexadecimal notation, such as `#ff8000`, with the alpha component only if the color isn't opaque.
+    pub fn to_hex(&self) -> String {
+        let [red, green, blue, alpha] = self.to_rgba8();
+        if alpha == 255 {
+            format!("#{:02x}{:02x}{:02x}", red, green, blue)
+        } else {
+            format!("#{:02x}{:02x}{:02x}{:02x}", red, green, blue, alpha)
+        }
+    }
+}
@@ -40,3 +40,83 @@ impl From<api::BoundingPoly> for BoundingBox {
         BoundingBox::new(lx, ly, gx - lx, gy - ly)
     }
 }
+
+/// Converts a bounding polygon (in pixels) into a bounding box, if it has any vertex.
+pub(crate) fn convert_bounding_poly(poly: Option<api::BoundingPoly>) -> Option<BoundingBox> {
+    poly.filter(|poly| !poly.vertices.is_empty())
+        .map(BoundingBox::from)
+}
+
+/// A vertex of a bounding polygon, with coordinates relative to the dimensions of the image (from 0 to 1).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NormalizedVertex {
+    /// The X-axis coordinate, as a fraction of the image's width.
+    pub x: f32,
+    /// The Y-axis coordinate, as a fraction of the image's height.
+    pub y: f32,
+}
+
+impl NormalizedVertex {
+    /// Creates a new normalized vertex.
+    pub fn new(x: f32, y: f32) -> NormalizedVertex {
+        NormalizedVertex { x, y }
+    }
+
+    /// Converts the vertex into pixel coordinates `(x, y)`, given the dimensions of the image.
+    pub fn to_pixels(self, width: u32, height: u32) -> (i32, i32) {
+        let x = (f64::from(self.x) * f64::from(width)).round();
+        let y = (f64::from(self.y) * f64::from(height)).round();
+        (x as i32, y as i32)
+    }
+}
+
+impl From<api::NormalizedVertex> for NormalizedVertex {
+    fn from(vertex: api::NormalizedVertex) -> NormalizedVertex {
+        NormalizedVertex::new(vertex.x, vertex.y)
+    }
+}
+
+/// A bounding polygon with normalized vertices, delimiting annotations regardless of the image's dimensions.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct NormalizedBoundingPoly {
+    pub(crate) vertices: Vec<NormalizedVertex>,
+}
+
+impl NormalizedBoundingPoly {
+    /// Get the vertices of the polygon.
+    pub fn vertices(&self) -> &[NormalizedVertex] {
+        self.vertices.as_slice()
+    }
+
+    /// Converts the vertices into pixel coordinates, given the dimensions of the image.
+    pub fn to_pixels(&self, width: u32, height: u32) -> Vec<(i32, i32)> {
+        self.vertices
+            .iter()
+            .map(|vertex| vertex.to_pixels(width, height))
+            .collect()
+    }
+
+    /// Get the smallest bounding box (in pixels) containing the polygon, given the dimensions of the image.
+    ///
+    /// Returns `None` if the polygon has no vertex.
+    pub fn bounding_box(&self, width: u32, height: u32) -> Option<BoundingBox> {
+        let pixels = self.to_pixels(width, height);
+        let (lx, ly, gx, gy) = pixels.into_iter().fold(None, |acc, (vx, vy)| match acc {
+            None => Some((vx, vy, vx, vy)),
+            Some((x, y, w, h)) => Some((vx.min(x), vy.min(y), vx.max(w), vy.max(h))),
+        })?;
+        Some(BoundingBox::new(lx, ly, gx - lx, gy - ly))
+    }
+}
+
+impl From<api::BoundingPoly> for NormalizedBoundingPoly {
+    fn from(poly: api::BoundingPoly) -> NormalizedBoundingPoly {
+        NormalizedBoundingPoly {
+            vertices: poly
+                .normalized_vertices
+                .into_iter()
+                .map(NormalizedVertex::from)
+                .collect(),
+        }
+    }
+}
@@ -1,18 +1,22 @@
-use std::convert::TryFrom;
 use std::env;
 use std::fs::File;
 use std::sync::Arc;
 
 use tokio::sync::Mutex;
-use tonic::transport::{Certificate, Channel, ClientTlsConfig};
+use tonic::transport::Channel;
 use tonic::{IntoRequest, Request};
 
-use crate::authorize::{ApplicationCredentials, TokenManager, TLS_CERTS};
+use crate::authorize::{ApplicationCredentials, TokenManager};
+use crate::channel::{self, ChannelConfig};
+use crate::error::ConvertError;
 use crate::vision::api;
 use crate::vision::api::image_annotator_client::ImageAnnotatorClient;
 use crate::vision::api::product_search_client::ProductSearchClient;
 use crate::vision::{
-    Error, FaceAnnotation, FaceDetectionConfig, Image, TextAnnotation, TextDetectionConfig,
+    AnnotateRequest, Annotations, Error, FaceAnnotation, FaceDetectionConfig, Feature, Image,
+    ImageContext, ItemError, LandmarkAnnotation, LandmarkDetectionConfig,
+    LocalizedObjectAnnotation, LogoAnnotation, LogoDetectionConfig, ObjectLocalizationConfig,
+    TextAnnotation, TextDetectionConfig,
 };
 
 /// The Cloud Vision client, tied to a specific project.
@@ -33,6 +37,9 @@ impl Client {
         "https://www.googleapis.com/auth/cloud-vision",
     ];
 
+    //? The maximum number of images annotated by a single request.
+    const MAX_BATCH_IMAGES: usize = 16;
+
     pub(crate) async fn construct_request<T: IntoRequest<T>>(
         &mut self,
         request: T,
@@ -60,14 +67,16 @@ impl Client {
         project_name: impl Into<String>,
         creds: ApplicationCredentials,
     ) -> Result<Client, Error> {
-        let tls_config = ClientTlsConfig::new()
-            .ca_certificate(Certificate::from_pem(TLS_CERTS))
-            .domain_name(Client::DOMAIN_NAME);
+        Client::from_credentials_with_config(project_name, creds, ChannelConfig::default()).await
+    }
 
-        let channel = Channel::from_static(Client::ENDPOINT)
-            .tls_config(tls_config)?
-            .connect()
-            .await?;
+    /// Create a new client for the specified project with custom credentials and channel configuration.
+    pub async fn from_credentials_with_config(
+        project_name: impl Into<String>,
+        creds: ApplicationCredentials,
+        config: ChannelConfig,
+    ) -> Result<Client, Error> {
+        let channel = channel::connect(Client::ENDPOINT, Client::DOMAIN_NAME, &config).await?;
 
         Ok(Client {
             project_name: project_name.into(),
@@ -86,60 +95,118 @@ impl Client {
         image: Image,
         config: TextDetectionConfig,
     ) -> Result<Vec<TextAnnotation>, Error> {
-        let request = api::AnnotateImageRequest {
-            image: Some(image.into()),
-            features: vec![api::Feature {
-                r#type: api::feature::Type::TextDetection as i32,
-                max_results: 0, // Does not apply for TEXT_DETECTION, so set it to zero.
-                model: String::from("builtin/stable"),
-            }],
-            image_context: Some(config.into()),
-        };
-        let request = api::BatchAnnotateImagesRequest {
-            requests: vec![request],
-            parent: String::default(), // TODO: Make this configurable (specifying computation region).
-        };
-        let request = self.construct_request(request).await?;
-        let response = self.img_annotator.batch_annotate_images(request).await?;
-        let response = response.into_inner();
-        let response = response.responses.into_iter().next().unwrap();
-        let annotations = response
-            .text_annotations
-            .into_iter()
-            .map(TextAnnotation::from)
-            .collect();
+        let request = AnnotateRequest::new(image)
+            .feature(Feature::TextDetection)
+            .context(&ImageContext::from(config));
+        let annotations = self.annotate(request).await?;
 
-        Ok(annotations)
+        Ok(annotations.into_text_annotations())
     }
 
-    /// Perform text detection on the given image.
+    /// Perform face detection on the given image.
     pub async fn detect_faces(
         &mut self,
         image: Image,
         config: FaceDetectionConfig,
     ) -> Result<Vec<FaceAnnotation>, Error> {
-        let request = api::AnnotateImageRequest {
-            image: Some(image.into()),
-            features: vec![api::Feature {
-                r#type: api::feature::Type::FaceDetection as i32,
-                max_results: config.max_results,
-                model: String::from("builtin/stable"),
-            }],
-            image_context: None,
-        };
-        let request = api::BatchAnnotateImagesRequest {
-            requests: vec![request],
-            parent: String::default(), // TODO: Make this configurable (specifying computation region).
-        };
-        let request = self.construct_request(request).await?;
-        let response = self.img_annotator.batch_annotate_images(request).await?;
-        let response = response.into_inner();
-        let response = response.responses.into_iter().next().unwrap();
-        let annotations = response
-            .face_annotations
-            .into_iter()
-            .flat_map(FaceAnnotation::try_from)
-            .collect();
+        let request = AnnotateRequest::new(image).feature(Feature::FaceDetection {
+            max_results: config.max_results,
+        });
+        let annotations = self.annotate(request).await?;
+
+        Ok(annotations.into_face_annotations())
+    }
+
+    /// Perform landmark detection on the given image.
+    pub async fn detect_landmarks(
+        &mut self,
+        image: Image,
+        config: LandmarkDetectionConfig,
+    ) -> Result<Vec<LandmarkAnnotation>, Error> {
+        let request = AnnotateRequest::new(image).feature(Feature::LandmarkDetection {
+            max_results: config.max_results,
+        });
+        let annotations = self.annotate(request).await?;
+
+        Ok(annotations.into_landmark_annotations())
+    }
+
+    /// Perform logo detection on the given image.
+    pub async fn detect_logos(
+        &mut self,
+        image: Image,
+        config: LogoDetectionConfig,
+    ) -> Result<Vec<LogoAnnotation>, Error> {
+        let request = AnnotateRequest::new(image).feature(Feature::LogoDetection {
+            max_results: config.max_results,
+        });
+        let annotations = self.annotate(request).await?;
+
+        Ok(annotations.into_logo_annotations())
+    }
+
+    /// Localize the objects within the given image.
+    pub async fn localize_objects(
+        &mut self,
+        image: Image,
+        config: ObjectLocalizationConfig,
+    ) -> Result<Vec<LocalizedObjectAnnotation>, Error> {
+        let request = AnnotateRequest::new(image).feature(Feature::ObjectLocalization {
+            max_results: config.max_results,
+        });
+        let annotations = self.annotate(request).await?;
+
+        Ok(annotations.into_localized_object_annotations())
+    }
+
+    /// Annotate an image with all the features of the request.
+    ///
+    /// Fails with the status of the annotation if the image could not be annotated.
+    pub async fn annotate(&mut self, request: AnnotateRequest) -> Result<Annotations, Error> {
+        let annotations = self.annotate_all(Some(request)).await?;
+        let annotations = annotations.into_iter().next();
+        let annotations = annotations.unwrap_or_else(|| Ok(Annotations::default()))?;
+
+        Ok(annotations)
+    }
+
+    /// Annotate multiple images, in as few requests as possible.
+    ///
+    /// Returns the annotations of the images, in the same order as the requests.
+    /// Images failing on their own (such as unreadable ones) get an `ItemError` in place of their annotations,
+    /// whereas the whole call only fails if a request does.
+    pub async fn annotate_all<I>(
+        &mut self,
+        requests: I,
+    ) -> Result<Vec<Result<Annotations, ItemError>>, Error>
+    where
+        I: IntoIterator<Item = AnnotateRequest>,
+    {
+        let mut requests: Vec<api::AnnotateImageRequest> =
+            requests.into_iter().map(From::from).collect();
+        let mut annotations = Vec::with_capacity(requests.len());
+        while !requests.is_empty() {
+            let rest = requests.split_off(requests.len().min(Client::MAX_BATCH_IMAGES));
+            let batch = std::mem::replace(&mut requests, rest);
+            let count = batch.len();
+            let request = api::BatchAnnotateImagesRequest {
+                requests: batch,
+                parent: String::default(), // TODO: Make this configurable (specifying computation region).
+            };
+            let request = self.construct_request(request).await?;
+            let response = self.img_annotator.batch_annotate_images(request).await?;
+            let responses = response.into_inner().responses;
+            if responses.len() != count {
+                return Err(ConvertError::MissingField(String::from("responses")).into());
+            }
+            let offset = annotations.len();
+            annotations.extend(
+                responses
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, response)| Annotations::from_response(offset + index, response)),
+            );
+        }
 
         Ok(annotations)
     }
@@ -0,0 +1,69 @@
+use crate::vision::api;
+use crate::vision::bounding_box::convert_bounding_poly;
+use crate::vision::BoundingBox;
+
+pub use crate::types::LatLng;
+
+/// Represents a landmark annotation, from the landmark detector.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LandmarkAnnotation {
+    pub(crate) mid: String,
+    pub(crate) description: String,
+    pub(crate) score: f32,
+    pub(crate) bounding_box: Option<BoundingBox>,
+    pub(crate) locations: Vec<LatLng>,
+}
+
+impl LandmarkAnnotation {
+    /// Get the detected landmark's entity ID, from the Google Knowledge Graph Search API (such as `/m/0k3p`).
+    pub fn mid(&self) -> &str {
+        self.mid.as_str()
+    }
+
+    /// Get the detected landmark's name.
+    pub fn description(&self) -> &str {
+        self.description.as_str()
+    }
+
+    /// Get the detected landmark's confidence score, from 0 to 1.
+    pub fn score(&self) -> f32 {
+        self.score
+    }
+
+    /// Get the detected landmark's bounding box.
+    pub fn bounding_box(&self) -> Option<BoundingBox> {
+        self.bounding_box
+    }
+
+    /// Get the detected landmark's locations (the place it stands, and possibly the one the image was taken from).
+    pub fn locations(&self) -> &[LatLng] {
+        self.locations.as_slice()
+    }
+}
+
+//? The locations of the API are trusted to be within range.
+impl From<api::r#type::LatLng> for LatLng {
+    fn from(lat_lng: api::r#type::LatLng) -> LatLng {
+        LatLng {
+            latitude: lat_lng.latitude,
+            longitude: lat_lng.longitude,
+        }
+    }
+}
+
+impl From<api::EntityAnnotation> for LandmarkAnnotation {
+    fn from(ann: api::EntityAnnotation) -> LandmarkAnnotation {
+        LandmarkAnnotation {
+            mid: ann.mid,
+            description: ann.description,
+            score: ann.score,
+            bounding_box: convert_bounding_poly(ann.bounding_poly),
+            locations: ann
+                .locations
+                .into_iter()
+                .flat_map(|location| location.lat_lng)
+                .map(LatLng::from)
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,19 @@
+/// Represents the landmark detection's configuration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LandmarkDetectionConfig {
+    pub(crate) max_results: i32,
+}
+
+impl LandmarkDetectionConfig {
+    /// Set the maximum number of landmarks to detect.
+    pub fn max_results(mut self, max_results: i32) -> LandmarkDetectionConfig {
+        self.max_results = max_results;
+        self
+    }
+}
+
+impl Default for LandmarkDetectionConfig {
+    fn default() -> LandmarkDetectionConfig {
+        LandmarkDetectionConfig { max_results: 10 }
+    }
+}
@@ -0,0 +1,45 @@
+use crate::vision::api;
+use crate::vision::bounding_box::convert_bounding_poly;
+use crate::vision::BoundingBox;
+
+/// Represents a logo annotation, from the logo detector.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogoAnnotation {
+    pub(crate) mid: String,
+    pub(crate) description: String,
+    pub(crate) score: f32,
+    pub(crate) bounding_box: Option<BoundingBox>,
+}
+
+impl LogoAnnotation {
+    /// Get the detected logo's entity ID, from the Google Knowledge Graph Search API.
+    pub fn mid(&self) -> &str {
+        self.mid.as_str()
+    }
+
+    /// Get the detected logo's brand name.
+    pub fn description(&self) -> &str {
+        self.description.as_str()
+    }
+
+    /// Get the detected logo's confidence score, from 0 to 1.
+    pub fn score(&self) -> f32 {
+        self.score
+    }
+
+    /// Get the detected logo's bounding box.
+    pub fn bounding_box(&self) -> Option<BoundingBox> {
+        self.bounding_box
+    }
+}
+
+impl From<api::EntityAnnotation> for LogoAnnotation {
+    fn from(ann: api::EntityAnnotation) -> LogoAnnotation {
+        LogoAnnotation {
+            mid: ann.mid,
+            description: ann.description,
+            score: ann.score,
+            bounding_box: convert_bounding_poly(ann.bounding_poly),
+        }
+    }
+}
@@ -0,0 +1,19 @@
+/// Represents the logo detection's configuration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LogoDetectionConfig {
+    pub(crate) max_results: i32,
+}
+
+impl LogoDetectionConfig {
+    /// Set the maximum number of logos to detect.
+    pub fn max_results(mut self, max_results: i32) -> LogoDetectionConfig {
+        self.max_results = max_results;
+        self
+    }
+}
+
+impl Default for LogoDetectionConfig {
+    fn default() -> LogoDetectionConfig {
+        LogoDetectionConfig { max_results: 10 }
+    }
+}
@@ -0,0 +1,5 @@
+mod annotation;
+mod config;
+
+pub use self::annotation::*;
+pub use self::config::*;
@@ -2,9 +2,18 @@ mod bounding_box;
 mod client;
 mod face;
 mod image;
+mod landmark;
 mod likelihood;
+mod logo;
+mod object;
+mod request;
 mod text;
-mod api {
+pub(crate) mod api {
+    #![allow(
+        dead_code,
+        clippy::doc_lazy_continuation,
+        clippy::doc_overindented_list_items
+    )]
     pub mod rpc {
         include!("api/google.rpc.rs");
     }
@@ -29,14 +38,17 @@ mod api {
         }
     }
     pub use self::cloud::vision::v1::*;
-    pub use self::r#type::*;
 }
 
 pub use self::bounding_box::*;
 pub use self::client::*;
 pub use self::face::*;
 pub use self::image::*;
+pub use self::landmark::*;
 pub use self::likelihood::*;
+pub use self::logo::*;
+pub use self::object::*;
+pub use self::request::*;
 pub use self::text::*;
 
 /// The error type for the Cloud Vision module.
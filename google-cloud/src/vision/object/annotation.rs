@@ -0,0 +1,57 @@
+use crate::vision::api;
+use crate::vision::NormalizedBoundingPoly;
+
+/// Represents a localized object, from the object localizer.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LocalizedObjectAnnotation {
+    pub(crate) mid: String,
+    pub(crate) name: String,
+    pub(crate) score: f32,
+    pub(crate) bounding_poly: NormalizedBoundingPoly,
+}
+
+impl LocalizedObjectAnnotation {
+    /// Get the detected object's entity ID, from the Google Knowledge Graph Search API.
+    pub fn mid(&self) -> &str {
+        self.mid.as_str()
+    }
+
+    /// Get the detected object's name (such as `Bicycle`).
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    /// Get the detected object's confidence score, from 0 to 1.
+    pub fn score(&self) -> f32 {
+        self.score
+    }
+
+    /// Get the detected object's bounding polygon, relative to the dimensions of the image.
+    ///
+    /// ```
+    /// # use google_cloud::vision::LocalizedObjectAnnotation;
+    /// # fn run(object: LocalizedObjectAnnotation) {
+    /// // For a 640x480 image.
+    /// if let Some(bounds) = object.bounding_poly().bounding_box(640, 480) {
+    ///     println!("{} at ({}, {})", object.name(), bounds.x, bounds.y);
+    /// }
+    /// # }
+    /// ```
+    pub fn bounding_poly(&self) -> &NormalizedBoundingPoly {
+        &self.bounding_poly
+    }
+}
+
+impl From<api::LocalizedObjectAnnotation> for LocalizedObjectAnnotation {
+    fn from(ann: api::LocalizedObjectAnnotation) -> LocalizedObjectAnnotation {
+        LocalizedObjectAnnotation {
+            mid: ann.mid,
+            name: ann.name,
+            score: ann.score,
+            bounding_poly: ann
+                .bounding_poly
+                .map(NormalizedBoundingPoly::from)
+                .unwrap_or_default(),
+        }
+    }
+}
@@ -0,0 +1,19 @@
+/// Represents the object localization's configuration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ObjectLocalizationConfig {
+    pub(crate) max_results: i32,
+}
+
+impl ObjectLocalizationConfig {
+    /// Set the maximum number of objects to detect.
+    pub fn max_results(mut self, max_results: i32) -> ObjectLocalizationConfig {
+        self.max_results = max_results;
+        self
+    }
+}
+
+impl Default for ObjectLocalizationConfig {
+    fn default() -> ObjectLocalizationConfig {
+        ObjectLocalizationConfig { max_results: 10 }
+    }
+}
@@ -0,0 +1,347 @@
+use std::convert::TryFrom;
+
+use thiserror::Error;
+
+use crate::vision::api;
+use crate::vision::{
+    Error as VisionError, FaceAnnotation, Image, LandmarkAnnotation, LocalizedObjectAnnotation,
+    LogoAnnotation, TextAnnotation,
+};
+
+/// Represents a detection to perform on an image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Feature {
+    /// Text detection (OCR), suited to sparse text within images.
+    TextDetection,
+    /// Text detection (OCR), suited to dense text within documents.
+    DocumentTextDetection,
+    /// Face detection.
+    FaceDetection {
+        /// The maximum number of faces to detect.
+        max_results: i32,
+    },
+    /// Landmark detection.
+    LandmarkDetection {
+        /// The maximum number of landmarks to detect.
+        max_results: i32,
+    },
+    /// Logo detection.
+    LogoDetection {
+        /// The maximum number of logos to detect.
+        max_results: i32,
+    },
+    /// Object localization.
+    ObjectLocalization {
+        /// The maximum number of objects to localize.
+        max_results: i32,
+    },
+}
+
+impl From<Feature> for api::Feature {
+    fn from(feature: Feature) -> api::Feature {
+        use api::feature::Type;
+
+        let (r#type, max_results) = match feature {
+            // `max_results` does not apply to text detections, so set it to zero.
+            Feature::TextDetection => (Type::TextDetection, 0),
+            Feature::DocumentTextDetection => (Type::DocumentTextDetection, 0),
+            Feature::FaceDetection { max_results } => (Type::FaceDetection, max_results),
+            Feature::LandmarkDetection { max_results } => (Type::LandmarkDetection, max_results),
+            Feature::LogoDetection { max_results } => (Type::LogoDetection, max_results),
+            Feature::ObjectLocalization { max_results } => (Type::ObjectLocalization, max_results),
+        };
+        api::Feature {
+            r#type: r#type as i32,
+            max_results,
+            model: String::from("builtin/stable"),
+        }
+    }
+}
+
+/// Represents a set of features, to reuse across requests.
+///
+/// ```
+/// # use google_cloud::vision::{Feature, FeatureSet};
+/// let features = FeatureSet::new()
+///     .feature(Feature::TextDetection)
+///     .feature(Feature::FaceDetection { max_results: 5 });
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct FeatureSet {
+    pub(crate) features: Vec<Feature>,
+}
+
+impl FeatureSet {
+    /// Construct a new empty feature set.
+    pub fn new() -> FeatureSet {
+        FeatureSet::default()
+    }
+
+    /// Add a feature to the set.
+    pub fn feature(mut self, feature: Feature) -> FeatureSet {
+        self.features.push(feature);
+        self
+    }
+
+    /// Get the features of the set.
+    pub fn features(&self) -> &[Feature] {
+        self.features.as_slice()
+    }
+}
+
+/// Represents the context of an image, guiding its detections.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ImageContext {
+    pub(crate) language_hints: Vec<String>,
+    pub(crate) crop_aspect_ratios: Vec<f32>,
+}
+
+impl ImageContext {
+    /// Construct a new empty image context.
+    pub fn new() -> ImageContext {
+        ImageContext::default()
+    }
+
+    /// Add a language hint for text detection.
+    /// Language detection is automatic if none specified.
+    pub fn language_hint(mut self, lang: impl Into<String>) -> ImageContext {
+        self.language_hints.push(lang.into());
+        self
+    }
+
+    /// Add a desired aspect ratio (width over height) for crop hints.
+    pub fn crop_aspect_ratio(mut self, ratio: f32) -> ImageContext {
+        self.crop_aspect_ratios.push(ratio);
+        self
+    }
+}
+
+impl From<ImageContext> for api::ImageContext {
+    fn from(context: ImageContext) -> api::ImageContext {
+        api::ImageContext {
+            lat_long_rect: None,
+            crop_hints_params: if context.crop_aspect_ratios.is_empty() {
+                None
+            } else {
+                Some(api::CropHintsParams {
+                    aspect_ratios: context.crop_aspect_ratios,
+                })
+            },
+            product_search_params: None,
+            web_detection_params: None,
+            language_hints: context.language_hints,
+        }
+    }
+}
+
+/// Represents the annotation of an image with several features at once.
+///
+/// Feature sets and image contexts are borrowed, so that they can be shared by many requests:
+///
+/// ```no_run
+/// # use google_cloud::vision::{AnnotateRequest, Client, Error, Feature, FeatureSet, Image, ImageContext};
+/// # async fn run(mut client: Client, images: Vec<Image>) -> Result<(), Error> {
+/// let features = FeatureSet::new()
+///     .feature(Feature::DocumentTextDetection)
+///     .feature(Feature::FaceDetection { max_results: 10 });
+/// let context = ImageContext::new().language_hint("fr");
+///
+/// let requests = images
+///     .into_iter()
+///     .map(|image| AnnotateRequest::new(image).features(&features).context(&context));
+/// for annotations in client.annotate_all(requests).await? {
+///     println!("{} faces", annotations?.face_annotations().len());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnnotateRequest {
+    pub(crate) image: Image,
+    pub(crate) features: Vec<Feature>,
+    pub(crate) context: Option<ImageContext>,
+}
+
+impl AnnotateRequest {
+    /// Construct a new request for the given image, without any feature.
+    pub fn new(image: Image) -> AnnotateRequest {
+        AnnotateRequest {
+            image,
+            features: Vec::new(),
+            context: None,
+        }
+    }
+
+    /// Add a feature to detect.
+    pub fn feature(mut self, feature: Feature) -> AnnotateRequest {
+        self.features.push(feature);
+        self
+    }
+
+    /// Add all the features of a set.
+    pub fn features(mut self, features: &FeatureSet) -> AnnotateRequest {
+        self.features.extend(features.features.iter().copied());
+        self
+    }
+
+    /// Set the context of the image.
+    pub fn context(mut self, context: &ImageContext) -> AnnotateRequest {
+        self.context = Some(context.clone());
+        self
+    }
+}
+
+impl From<AnnotateRequest> for api::AnnotateImageRequest {
+    fn from(request: AnnotateRequest) -> api::AnnotateImageRequest {
+        api::AnnotateImageRequest {
+            image: Some(request.image.into()),
+            features: request.features.into_iter().map(From::from).collect(),
+            image_context: request.context.map(From::from),
+        }
+    }
+}
+
+/// Represents the annotations of an image, for all the requested features.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Annotations {
+    pub(crate) text_annotations: Vec<TextAnnotation>,
+    pub(crate) face_annotations: Vec<FaceAnnotation>,
+    pub(crate) landmark_annotations: Vec<LandmarkAnnotation>,
+    pub(crate) logo_annotations: Vec<LogoAnnotation>,
+    pub(crate) localized_object_annotations: Vec<LocalizedObjectAnnotation>,
+}
+
+impl Annotations {
+    /// Get the detected texts (if text detection was requested).
+    pub fn text_annotations(&self) -> &[TextAnnotation] {
+        self.text_annotations.as_slice()
+    }
+
+    /// Get the detected faces (if face detection was requested).
+    pub fn face_annotations(&self) -> &[FaceAnnotation] {
+        self.face_annotations.as_slice()
+    }
+
+    /// Get the detected landmarks (if landmark detection was requested).
+    pub fn landmark_annotations(&self) -> &[LandmarkAnnotation] {
+        self.landmark_annotations.as_slice()
+    }
+
+    /// Get the detected logos (if logo detection was requested).
+    pub fn logo_annotations(&self) -> &[LogoAnnotation] {
+        self.logo_annotations.as_slice()
+    }
+
+    /// Get the localized objects (if object localization was requested).
+    pub fn localized_object_annotations(&self) -> &[LocalizedObjectAnnotation] {
+        self.localized_object_annotations.as_slice()
+    }
+
+    /// Move the detected texts out.
+    pub fn into_text_annotations(self) -> Vec<TextAnnotation> {
+        self.text_annotations
+    }
+
+    /// Move the detected faces out.
+    pub fn into_face_annotations(self) -> Vec<FaceAnnotation> {
+        self.face_annotations
+    }
+
+    /// Move the detected landmarks out.
+    pub fn into_landmark_annotations(self) -> Vec<LandmarkAnnotation> {
+        self.landmark_annotations
+    }
+
+    /// Move the detected logos out.
+    pub fn into_logo_annotations(self) -> Vec<LogoAnnotation> {
+        self.logo_annotations
+    }
+
+    /// Move the localized objects out.
+    pub fn into_localized_object_annotations(self) -> Vec<LocalizedObjectAnnotation> {
+        self.localized_object_annotations
+    }
+}
+
+impl From<api::AnnotateImageResponse> for Annotations {
+    fn from(response: api::AnnotateImageResponse) -> Annotations {
+        Annotations {
+            text_annotations: response
+                .text_annotations
+                .into_iter()
+                .map(TextAnnotation::from)
+                .collect(),
+            face_annotations: response
+                .face_annotations
+                .into_iter()
+                .flat_map(FaceAnnotation::try_from)
+                .collect(),
+            landmark_annotations: response
+                .landmark_annotations
+                .into_iter()
+                .map(LandmarkAnnotation::from)
+                .collect(),
+            logo_annotations: response
+                .logo_annotations
+                .into_iter()
+                .map(LogoAnnotation::from)
+                .collect(),
+            localized_object_annotations: response
+                .localized_object_annotations
+                .into_iter()
+                .map(LocalizedObjectAnnotation::from)
+                .collect(),
+        }
+    }
+}
+
+impl Annotations {
+    /// Converts the response for the image at the given index of a batch, failing if its annotation failed.
+    pub(crate) fn from_response(
+        index: usize,
+        response: api::AnnotateImageResponse,
+    ) -> Result<Annotations, ItemError> {
+        match response.error.as_ref() {
+            Some(status) if status.code != 0 => Err(ItemError {
+                index,
+                code: tonic::Code::from(status.code),
+                message: status.message.clone(),
+            }),
+            _ => Ok(Annotations::from(response)),
+        }
+    }
+}
+
+/// Represents the failure of the annotation of a single image within a batch (see `Client::annotate_all`).
+///
+/// The other images of the batch are annotated regardless, so that only the failed ones need to be retried.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("annotation of image {index} failed ({code:?}): {message}")]
+pub struct ItemError {
+    pub(crate) index: usize,
+    pub(crate) code: tonic::Code,
+    pub(crate) message: String,
+}
+
+impl ItemError {
+    /// The index of the image, in the order of the requests.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The status code of the failure (such as `InvalidArgument` for an unreadable image).
+    pub fn code(&self) -> tonic::Code {
+        self.code
+    }
+
+    /// The message describing the failure.
+    pub fn message(&self) -> &str {
+        self.message.as_str()
+    }
+}
+
+impl From<ItemError> for VisionError {
+    fn from(err: ItemError) -> VisionError {
+        VisionError::Status(tonic::Status::new(err.code, err.message))
+    }
+}
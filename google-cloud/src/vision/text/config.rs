@@ -1,4 +1,4 @@
-use crate::vision::api;
+use crate::vision::ImageContext;
 
 /// Represents the text detection's configuration.
 #[derive(Default)]
@@ -15,14 +15,11 @@ impl TextDetectionConfig {
     }
 }
 
-impl From<TextDetectionConfig> for api::ImageContext {
-    fn from(config: TextDetectionConfig) -> api::ImageContext {
-        api::ImageContext {
-            lat_long_rect: None,
-            crop_hints_params: None,
-            product_search_params: None,
-            web_detection_params: None,
+impl From<TextDetectionConfig> for ImageContext {
+    fn from(config: TextDetectionConfig) -> ImageContext {
+        ImageContext {
             language_hints: config.language_hints,
+            ..ImageContext::default()
         }
     }
 }